@@ -0,0 +1,251 @@
+/*
+ * gen_vectors - Generate deterministic test vectors (JSON) for the rule engine
+ * cargo run --bin gen_vectors > tests/vectors/rule_vectors.json
+ *
+ * Every value comes from the program's own validation and hashing code, so the
+ * TypeScript rule engine and the Rust tests check themselves against the same cases.
+ * The output is fixed for a given program version (no clock, no OS randomness).
+ */
+
+use anchor_lang::prelude::Pubkey;
+use solana_games_program::replay::{self, MatchHeader, ReplayMove};
+use solana_games_program::state::RunRules;
+use solana_games_program::validation;
+
+/// Bump when the vector layout changes.
+const VECTORS_VERSION: u32 = 1;
+const RNG_SEED: u64 = 0x6f63_656e_7472_6121;
+
+const SUITS: u8 = 4;
+const VALUES: std::ops::RangeInclusive<u8> = 2..=14; // Ace = 14
+
+/// xorshift64*: small, portable and stable across platforms.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    fn bytes<const N: usize>(&mut self) -> [u8; N] {
+        let mut out = [0u8; N];
+        for chunk in out.chunks_mut(8) {
+            let word = self.next().to_le_bytes();
+            chunk.copy_from_slice(&word[..chunk.len()]);
+        }
+        out
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn cards_json(cards: &[(u8, u8)]) -> String {
+    let cards: Vec<String> = cards.iter().map(|(suit, value)| format!("[{}, {}]", suit, value)).collect();
+    format!("[{}]", cards.join(", "))
+}
+
+fn full_deck() -> Vec<(u8, u8)> {
+    (0..SUITS).flat_map(|suit| VALUES.map(move |value| (suit, value))).collect()
+}
+
+/// commit_hand: SHA-256 of the hand sorted by suit then value. Cards are listed as dealt.
+fn hand_commitments(rng: &mut Rng) -> Vec<String> {
+    (0..16)
+        .map(|_| {
+            let mut deck = full_deck();
+            let size = 3 + rng.below(8) as usize;
+            let hand: Vec<(u8, u8)> = (0..size)
+                .map(|_| deck.swap_remove(rng.below(deck.len() as u64) as usize))
+                .collect();
+            format!(
+                "{{\"cards\": {}, \"hand_hash\": \"{}\"}}",
+                cards_json(&hand),
+                hex(&validation::hand_hash(&hand))
+            )
+        })
+        .collect()
+}
+
+/// reveal_floor_card / pick-up checks: SHA-256([suit, value]) for every card.
+fn floor_card_hashes() -> Vec<String> {
+    full_deck()
+        .into_iter()
+        .map(|(suit, value)| {
+            let hash = anchor_lang::solana_program::hash::hash(&[suit, value]).to_bytes();
+            format!("{{\"suit\": {}, \"value\": {}, \"card_hash\": \"{}\"}}", suit, value, hex(&hash))
+        })
+        .collect()
+}
+
+fn run_case(rules_bits: u8, cards: &[(u8, u8)]) -> String {
+    let run_rules = RunRules::from_bits(rules_bits);
+    let payload: Vec<u8> = cards.iter().flat_map(|&(suit, value)| [suit, value]).collect();
+    let outcome = match validation::parse_run(&payload, run_rules) {
+        Ok(run) => {
+            let (suit, top) = validation::run_suit_and_top(&run, run_rules);
+            format!("\"valid\": true, \"suit\": {}, \"top\": {}", suit, top)
+        }
+        Err(_) => "\"valid\": false".to_string(),
+    };
+    format!("{{\"run_rules\": {}, \"cards\": {}, {}}}", rules_bits, cards_json(cards), outcome)
+}
+
+/// parse_run / run_suit_and_top under each run variant: the edge cases, then runs
+/// near the valid ones (consecutive, sometimes with one card's suit or value changed).
+fn run_cases(rng: &mut Rng) -> Vec<String> {
+    let variants = [
+        RunRules::CLASSIC,
+        0,
+        RunRules::ACES_LOW,
+        RunRules::ACES_LOW | RunRules::WRAPAROUND,
+        RunRules::CLASSIC | (4 << RunRules::RUN_LENGTH_SHIFT),
+        RunRules::ACES_LOW | (5 << RunRules::RUN_LENGTH_SHIFT),
+    ];
+    let edge_cases: [&[(u8, u8)]; 10] = [
+        &[(0, 2), (0, 3), (0, 4)],
+        &[(1, 12), (1, 13), (1, 14)],
+        &[(2, 13), (2, 14), (2, 2)],
+        &[(3, 14), (3, 2), (3, 3)],
+        &[(0, 4), (0, 2), (0, 3)],
+        &[(0, 5), (0, 5), (0, 6)],
+        &[(0, 5), (1, 6), (0, 7)],
+        &[(0, 1), (0, 2), (0, 3)],
+        &[(0, 13), (0, 14), (0, 15)],
+        &[(0, 2), (0, 4), (0, 6)],
+    ];
+
+    let mut cases = Vec::new();
+    for &rules_bits in &variants {
+        let run_length = RunRules::from_bits(rules_bits).run_length as usize;
+        for &cards in &edge_cases {
+            if cards.len() == run_length {
+                cases.push(run_case(rules_bits, cards));
+            }
+        }
+        for _ in 0..24 {
+            let suit = rng.below(SUITS as u64) as u8;
+            let start = rng.below(13) as u8;
+            let mut cards: Vec<(u8, u8)> = (0..run_length as u8)
+                .map(|i| (suit, 2 + (start + i) % 13))
+                .collect();
+            match rng.below(4) {
+                0 => {
+                    let i = rng.below(run_length as u64) as usize;
+                    cards[i].0 = (suit + 1) % SUITS;
+                }
+                1 => {
+                    let i = rng.below(run_length as u64) as usize;
+                    cards[i].1 = 2 + rng.below(13) as u8;
+                }
+                _ => {}
+            }
+            // Payload order is not significant
+            for i in (1..cards.len()).rev() {
+                cards.swap(i, rng.below(i as u64 + 1) as usize);
+            }
+            cases.push(run_case(rules_bits, &cards));
+        }
+    }
+    cases
+}
+
+/// Canonical match hash (replay module): the header hash, each h_i and the final hash.
+fn match_transcripts(rng: &mut Rng) -> Vec<String> {
+    (0..4)
+        .map(|t| {
+            let id = rng.bytes::<16>();
+            let match_id = format!(
+                "{}-{}-4{}-a{}-{}",
+                hex(&id[..4]),
+                hex(&id[4..6]),
+                &hex(&id[6..8])[1..],
+                &hex(&id[8..10])[1..],
+                hex(&id[10..])
+            );
+            let player_count = 2 + t % 3;
+            let user_ids: Vec<String> = (0..player_count).map(|i| format!("vector-user-{}-{}", t, i)).collect();
+            let players: Vec<Pubkey> = (0..player_count).map(|_| Pubkey::new_from_array(rng.bytes())).collect();
+            let header = MatchHeader {
+                match_id: match_id.as_bytes().try_into().unwrap(),
+                game_type: (t % 4) as u8,
+                seed: rng.next(),
+                authority: Pubkey::new_from_array(rng.bytes()),
+                player_ids: user_ids
+                    .iter()
+                    .map(|user_id| {
+                        let mut padded = [0u8; 64];
+                        padded[..user_id.len()].copy_from_slice(user_id.as_bytes());
+                        padded
+                    })
+                    .collect(),
+            };
+            let moves: Vec<ReplayMove> = (0..(t * 5) as u32)
+                .map(|move_index| {
+                    let payload_len = rng.below(12) as usize;
+                    ReplayMove {
+                        move_index,
+                        player: players[move_index as usize % players.len()],
+                        action_type: rng.below(5) as u8,
+                        payload: rng.bytes::<12>()[..payload_len].to_vec(),
+                    }
+                })
+                .collect();
+
+            let mut transcript = replay::header_hash(&header);
+            let header_hash = transcript;
+            let mut move_json = Vec::new();
+            for m in &moves {
+                transcript = replay::chain_move(&transcript, m.move_index, &m.player, m.action_type, &m.payload);
+                move_json.push(format!(
+                    "{{\"move_index\": {}, \"player\": \"{}\", \"action_type\": {}, \"payload\": \"{}\", \"transcript_hash\": \"{}\"}}",
+                    m.move_index,
+                    m.player,
+                    m.action_type,
+                    hex(&m.payload),
+                    hex(&transcript)
+                ));
+            }
+            let match_hash = replay::compute_match_hash(&moves, &header);
+            assert_eq!(match_hash, replay::finalize(&transcript, moves.len() as u32));
+
+            let user_ids: Vec<String> = user_ids.iter().map(|user_id| format!("\"{}\"", user_id)).collect();
+            format!(
+                "{{\"match_id\": \"{}\", \"game_type\": {}, \"seed\": \"{}\", \"authority\": \"{}\", \"player_ids\": [{}], \
+                 \"header_hash\": \"{}\", \"moves\": [{}], \"match_hash\": \"{}\"}}",
+                match_id,
+                header.game_type,
+                header.seed,
+                header.authority,
+                user_ids.join(", "),
+                hex(&header_hash),
+                move_json.join(", "),
+                hex(&match_hash)
+            )
+        })
+        .collect()
+}
+
+fn section(name: &str, entries: &[String]) -> String {
+    format!("  \"{}\": [\n    {}\n  ]", name, entries.join(",\n    "))
+}
+
+fn main() {
+    let mut rng = Rng(RNG_SEED);
+    let sections = [
+        format!("  \"version\": {}", VECTORS_VERSION),
+        section("hand_commitments", &hand_commitments(&mut rng)),
+        section("floor_card_hashes", &floor_card_hashes()),
+        section("run_cases", &run_cases(&mut rng)),
+        section("match_transcripts", &match_transcripts(&mut rng)),
+    ];
+    println!("{{\n{}\n}}", sections.join(",\n"));
+}
@@ -0,0 +1,158 @@
+/*
+ * inspect - Derive PDAs and decode account data (`cli` feature)
+ *
+ *   cargo run --features cli --bin inspect -- pda match <match_id>
+ *   cargo run --features cli --bin inspect -- pda move <match_id> <move_index>
+ *   cargo run --features cli --bin inspect -- pda user <user_id>
+ *   cargo run --features cli --bin inspect -- decode [base64 account data]
+ *
+ * `decode` takes the base64 `data` of getAccountInfo (argument or stdin), picks the
+ * account type from its discriminator and prints it as JSON.
+ */
+
+use std::io::Read;
+use std::process::exit;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Discriminator;
+use base64::Engine;
+use serde_json::{json, Value};
+use solana_games_program::state::{fixed_bytes_to_string, Match, Move, UserAccount};
+use solana_games_program::{client, pda};
+
+const USAGE: &str = "usage:
+  inspect pda match <match_id>
+  inspect pda move <match_id> <move_index>
+  inspect pda user <user_id>
+  inspect decode [base64 account data, default: stdin]";
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn pda_json((address, bump): (Pubkey, u8)) -> Value {
+    json!({
+        "program_id": solana_games_program::ID.to_string(),
+        "address": address.to_string(),
+        "bump": bump,
+    })
+}
+
+fn match_json(m: &Match) -> Value {
+    let players: Vec<String> = (0..m.player_count as usize)
+        .filter_map(|i| m.player_id_str(i))
+        .collect();
+    json!({
+        "account": "Match",
+        "match_id": m.match_id_str(),
+        "version": m.version_str(),
+        "game_name": m.game_name_str(),
+        "game_type": m.game_type,
+        "seed": m.seed.to_string(),
+        "phase": m.phase,
+        "current_player": m.current_player,
+        "players": players,
+        "move_count": m.move_count,
+        "round_number": m.round_number,
+        "rounds_to_play": m.rounds_to_play,
+        "cumulative_scores": &m.cumulative_scores[..m.player_count.min(10) as usize],
+        "created_at": m.created_at,
+        "ended_at": m.ended_at,
+        "match_hash": m.has_match_hash().then(|| hex(&m.match_hash)),
+        "hot_url": m.hot_url_str(),
+        "cold_uri": m.cold_uri_str(),
+        "authority": m.authority.to_string(),
+        "visibility": m.visibility,
+        "flags": m.flags,
+        "anomaly_flags": m.anomaly_flags,
+        "outcome": format!("{:?}", m.get_outcome()),
+    })
+}
+
+fn move_json(m: &Move) -> Value {
+    json!({
+        "account": "Move",
+        "match_id": m.match_id_str(),
+        "player": m.player.to_string(),
+        "move_index": m.move_index,
+        "action_type": format!("{:?}", m.get_action_type()),
+        "payload": hex(m.get_payload_slice()),
+        "timestamp": m.timestamp,
+        "rent_payer": m.rent_payer.to_string(),
+        "envelope_hash": m.has_envelope_hash().then(|| hex(&m.envelope_hash)),
+    })
+}
+
+fn user_json(u: &UserAccount) -> Value {
+    json!({
+        "account": "UserAccount",
+        "user_id": fixed_bytes_to_string(&u.user_id),
+        "schema_version": u.schema_version,
+        "wallet": (u.wallet != Pubkey::default()).then(|| u.wallet.to_string()),
+        "subscription_tier": u.subscription_tier,
+        "subscription_expiry": u.subscription_expiry,
+        "lifetime_gp_earned": u.lifetime_gp_earned,
+        "games_played": u.games_played,
+        "games_won": u.games_won,
+        "win_streak": u.win_streak,
+        "current_tier": u.current_tier,
+        "current_season_id": u.current_season_id,
+        "season_score": u.season_score,
+        "leaderboard_rank": u.leaderboard_rank,
+        "guild": fixed_bytes_to_string(&u.guild),
+        "cheat_strikes": u.cheat_strikes,
+        "created_at": u.created_at,
+    })
+}
+
+fn decode(data: &[u8]) -> Result<Value, String> {
+    let discriminator = data.get(..8).ok_or("account data is shorter than a discriminator")?;
+    let decoded = if discriminator == Match::DISCRIMINATOR {
+        client::decode_match(data).map(|m| match_json(&m))
+    } else if discriminator == Move::DISCRIMINATOR {
+        client::decode_move(data).map(|m| move_json(&m))
+    } else if discriminator == UserAccount::DISCRIMINATOR {
+        client::decode_user_account(data).map(|u| user_json(&u))
+    } else {
+        return Err(format!("unsupported account discriminator {} (expected Match, Move or UserAccount)", hex(discriminator)));
+    };
+    decoded.map_err(|e| e.to_string())
+}
+
+fn run(args: &[String]) -> Result<Value, String> {
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    match args.as_slice() {
+        ["pda", "match", match_id] => Ok(pda_json(pda::match_pda(match_id))),
+        ["pda", "move", match_id, move_index] => {
+            let move_index = move_index.parse().map_err(|_| "move_index must be a u32")?;
+            Ok(pda_json(pda::move_pda(match_id, move_index)))
+        }
+        ["pda", "user", user_id] => Ok(pda_json(pda::user_account_pda(user_id))),
+        ["decode", rest @ ..] if rest.len() <= 1 => {
+            let encoded = match rest.first() {
+                Some(encoded) => encoded.to_string(),
+                None => {
+                    let mut input = String::new();
+                    std::io::stdin().read_to_string(&mut input).map_err(|e| e.to_string())?;
+                    input
+                }
+            };
+            let data = base64::engine::general_purpose::STANDARD
+                .decode(encoded.trim())
+                .map_err(|e| format!("invalid base64: {}", e))?;
+            decode(&data)
+        }
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(value) => println!("{}", serde_json::to_string_pretty(&value).unwrap()),
+        Err(message) => {
+            eprintln!("{}", message);
+            exit(1);
+        }
+    }
+}
@@ -0,0 +1,395 @@
+/*
+ * scenario - Play a scripted match end to end (`scenario` feature)
+ *
+ *   cargo run --features scenario --bin scenario -- scenarios/claim_showdown.json
+ *   cargo run --features scenario --bin scenario -- scenarios/claim_showdown.json --runs 50
+ *   cargo run --features scenario --bin scenario -- <file> --url http://127.0.0.1:8899 [--keypair <path>]
+ *
+ * Each run creates a match, joins one bot per scenario player (co-signed by the
+ * coordinator), commits the bots' hands, starts the match, plays the steps, ends the
+ * match and anchors its record. Without --url it runs in-process under
+ * solana-program-test with the config, registry and signer registry preloaded. With
+ * --url the program must already be deployed and set up there, and the keypair
+ * (default ~/.config/solana/id.json) pays for everything and acts as coordinator.
+ *
+ * Scenario file (JSON):
+ *   {
+ *     "game_type": 0, "seed": 42, "rounds_to_play": 1,       (seed and rounds optional)
+ *     "match_id": "<uuid>",                                 (optional, random per run)
+ *     "hot_url": "https://...",                             (optional, for anchor_match_record)
+ *     "players": [{ "user_id": "bot-1", "hand": [[0, 9], [0, 10], [0, 11]] }, ...],
+ *     "steps": [
+ *       { "reveal_floor_card": [2, 7] },
+ *       { "move": { "player": 0, "action_type": 0, "payload": "floor_card" } },
+ *       { "move": { "player": 0, "action_type": 3, "payload": "0009000a000b" } },
+ *       { "wait": 60 }
+ *     ]
+ *   }
+ *
+ * Move payloads are hex, or "floor_card" for the hash of the last revealed floor card.
+ * Nonces count up per player from 1. `wait` warps the clock under program-test and
+ * sleeps against a real cluster.
+ */
+
+use std::time::{Duration, Instant};
+
+use anchor_lang::AccountDeserialize;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use solana_games_program::client;
+use solana_games_program::state::{
+    ConfigAccount, GameRegistry, GlobalStats, Match, SignerRegistry, SignerRole,
+};
+use solana_games_program::validation;
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{
+    account::Account,
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    hash::Hash,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_instruction, system_program,
+    transaction::Transaction,
+};
+
+const USAGE: &str = "usage: scenario <scenario.json> [--runs N] [--url <rpc url>] [--keypair <path>]";
+const BOT_FUNDING_LAMPORTS: u64 = 100_000_000;
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Scenario {
+    game_type: u8,
+    #[serde(default)]
+    seed: u64,
+    #[serde(default = "default_rounds")]
+    rounds_to_play: u8,
+    match_id: Option<String>,
+    hot_url: Option<String>,
+    players: Vec<Player>,
+    #[serde(default)]
+    steps: Vec<Step>,
+}
+
+fn default_rounds() -> u8 {
+    1
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Player {
+    user_id: String,
+    hand: Option<Vec<(u8, u8)>>, // [suit, value] cards, committed before start
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+enum Step {
+    RevealFloorCard((u8, u8)),
+    Move {
+        player: usize,
+        action_type: u8,
+        #[serde(default)]
+        payload: String,
+    },
+    Wait(u64),
+}
+
+// Anchor's entry wants the account slice borrowed for 'info; program-test hands out a shorter borrow
+fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    solana_games_program::entry(program_id, accounts, data)
+}
+
+fn program_account<T: anchor_lang::AccountSerialize>(value: &T, space: usize) -> Account {
+    let mut data = Vec::with_capacity(space);
+    value.try_serialize(&mut data).unwrap();
+    data.resize(space, 0);
+    Account {
+        lamports: 1_000_000_000,
+        data,
+        owner: solana_games_program::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn zeroed<T: anchor_lang::AnchorDeserialize>(space: usize) -> T {
+    let data = vec![0u8; space - 8];
+    T::deserialize(&mut &data[..]).unwrap()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn parse_hex(text: &str) -> Result<Vec<u8>, String> {
+    text.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            std::str::from_utf8(pair)
+                .ok()
+                .filter(|pair| pair.len() == 2)
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or_else(|| format!("invalid hex payload: {}", text))
+        })
+        .collect()
+}
+
+enum Cluster {
+    ProgramTest(Box<ProgramTestContext>),
+    Rpc(RpcClient),
+}
+
+impl Cluster {
+    async fn latest_blockhash(&mut self) -> Result<Hash, String> {
+        match self {
+            // Fresh blockhash so otherwise-identical transactions aren't deduplicated
+            Cluster::ProgramTest(context) => context.get_new_latest_blockhash().await.map_err(|e| e.to_string()),
+            Cluster::Rpc(rpc) => rpc.get_latest_blockhash().await.map_err(|e| e.to_string()),
+        }
+    }
+
+    async fn process(&mut self, tx: Transaction) -> Result<(), String> {
+        match self {
+            Cluster::ProgramTest(context) => context.banks_client.process_transaction(tx).await.map_err(|e| e.to_string()),
+            Cluster::Rpc(rpc) => rpc.send_and_confirm_transaction(&tx).await.map(|_| ()).map_err(|e| e.to_string()),
+        }
+    }
+
+    async fn account_data(&mut self, address: Pubkey) -> Result<Vec<u8>, String> {
+        match self {
+            Cluster::ProgramTest(context) => match context.banks_client.get_account(address).await {
+                Ok(Some(account)) => Ok(account.data),
+                Ok(None) => Err(format!("account {} missing", address)),
+                Err(e) => Err(e.to_string()),
+            },
+            Cluster::Rpc(rpc) => rpc.get_account_data(&address).await.map_err(|e| e.to_string()),
+        }
+    }
+
+    async fn wait(&mut self, seconds: u64) -> Result<(), String> {
+        match self {
+            Cluster::ProgramTest(context) => {
+                let mut clock: Clock = context.banks_client.get_sysvar().await.map_err(|e| e.to_string())?;
+                clock.unix_timestamp += seconds as i64;
+                context.set_sysvar(&clock);
+            }
+            Cluster::Rpc(_) => tokio::time::sleep(Duration::from_secs(seconds)).await,
+        }
+        Ok(())
+    }
+}
+
+struct Runner {
+    cluster: Cluster,
+    payer: Keypair,
+    coordinator: Keypair,
+    transactions: u64,
+}
+
+impl Runner {
+    // In-process cluster with the accounts that have no init instruction preloaded
+    // (no rate limit, no registered games, coordinator as registry admin)
+    async fn program_test() -> Self {
+        let mut program_test = ProgramTest::new(
+            "solana_games_program",
+            solana_games_program::ID,
+            processor!(process_instruction),
+        );
+        let coordinator = Keypair::new();
+        let config: ConfigAccount = zeroed(ConfigAccount::MAX_SIZE);
+        program_test.add_account(client::config_pda().0, program_account(&config, ConfigAccount::MAX_SIZE));
+        let mut registry: GameRegistry = zeroed(GameRegistry::MAX_SIZE);
+        registry.authority = coordinator.pubkey();
+        program_test.add_account(client::game_registry_pda().0, program_account(&registry, GameRegistry::MAX_SIZE));
+        let stats: GlobalStats = zeroed(GlobalStats::MAX_SIZE);
+        program_test.add_account(client::global_stats_pda().0, program_account(&stats, GlobalStats::MAX_SIZE));
+        let signers = SignerRegistry {
+            signers: vec![coordinator.pubkey()],
+            roles: vec![SignerRole::Coordinator],
+            authority: coordinator.pubkey(),
+        };
+        program_test.add_account(client::signer_registry_pda().0, program_account(&signers, SignerRegistry::MAX_SIZE));
+        program_test.add_account(coordinator.pubkey(), Account::new(10_000_000_000, 0, &system_program::ID));
+
+        let context = program_test.start_with_context().await;
+        let payer = context.payer.insecure_clone();
+        Runner { cluster: Cluster::ProgramTest(Box::new(context)), payer, coordinator, transactions: 0 }
+    }
+
+    fn rpc(url: String, keypair: Keypair) -> Self {
+        Runner {
+            cluster: Cluster::Rpc(RpcClient::new(url)),
+            payer: keypair.insecure_clone(),
+            coordinator: keypair,
+            transactions: 0,
+        }
+    }
+
+    async fn send(&mut self, ixs: &[Instruction], signers: &[&Keypair]) -> Result<(), String> {
+        let blockhash = self.cluster.latest_blockhash().await?;
+        let mut all_signers = vec![&self.payer];
+        all_signers.extend(signers.iter().filter(|signer| signer.pubkey() != self.payer.pubkey()));
+        let tx = Transaction::new_signed_with_payer(ixs, Some(&self.payer.pubkey()), &all_signers, blockhash);
+        self.cluster.process(tx).await?;
+        self.transactions += 1;
+        Ok(())
+    }
+
+    async fn fetch_match(&mut self, match_id: &str) -> Result<Match, String> {
+        let data = self.cluster.account_data(client::match_pda(match_id).0).await?;
+        Match::try_deserialize(&mut data.as_slice()).map_err(|e| e.to_string())
+    }
+
+    async fn play(&mut self, scenario: &Scenario, match_id: &str) -> Result<Value, String> {
+        let started = Instant::now();
+        let transactions_before = self.transactions;
+        let coordinator = self.coordinator.insecure_clone();
+        let authority = coordinator.pubkey();
+
+        let ix = client::create_match_ix(authority, match_id, scenario.game_type, scenario.seed, scenario.rounds_to_play, None, false);
+        self.send(&[ix], &[&coordinator]).await.map_err(|e| format!("create_match: {}", e))?;
+
+        let bots: Vec<Keypair> = scenario.players.iter().map(|_| Keypair::new()).collect();
+        for (bot, player) in bots.iter().zip(&scenario.players) {
+            let fund = system_instruction::transfer(&self.payer.pubkey(), &bot.pubkey(), BOT_FUNDING_LAMPORTS);
+            let join = client::join_match_ix(bot.pubkey(), match_id, &player.user_id, Some(authority), true, None);
+            self.send(&[fund, join], &[bot, &coordinator])
+                .await
+                .map_err(|e| format!("join_match ({}): {}", player.user_id, e))?;
+        }
+        for (bot, player) in bots.iter().zip(&scenario.players) {
+            if let Some(hand) = &player.hand {
+                let ix = client::commit_hand_ix(bot.pubkey(), match_id, &player.user_id, validation::hand_hash(hand), hand.len() as u8);
+                self.send(&[ix], &[bot]).await.map_err(|e| format!("commit_hand ({}): {}", player.user_id, e))?;
+            }
+        }
+        let ix = client::start_match_ix(authority, match_id, None);
+        self.send(&[ix], &[&coordinator]).await.map_err(|e| format!("start_match: {}", e))?;
+
+        let mut nonces = vec![0u64; bots.len()];
+        let mut floor_card_hash = None;
+        for (i, step) in scenario.steps.iter().enumerate() {
+            let result = match step {
+                Step::RevealFloorCard((suit, value)) => {
+                    let hash = anchor_lang::solana_program::hash::hash(&[*suit, *value]).to_bytes();
+                    floor_card_hash = Some(hash);
+                    let ix = client::reveal_floor_card_ix(authority, match_id, hash);
+                    self.send(&[ix], &[&coordinator]).await
+                }
+                Step::Move { player, action_type, payload } => {
+                    let (bot, user_id) = match (bots.get(*player), scenario.players.get(*player)) {
+                        (Some(bot), Some(p)) => (bot, &p.user_id),
+                        _ => return Err(format!("step {}: no player {}", i, player)),
+                    };
+                    let payload = if payload == "floor_card" {
+                        floor_card_hash.ok_or(format!("step {}: no floor card revealed yet", i))?.to_vec()
+                    } else {
+                        parse_hex(payload)?
+                    };
+                    nonces[*player] += 1;
+                    let move_index = self.fetch_match(match_id).await?.move_count;
+                    let ix = client::submit_move_ix(bot.pubkey(), match_id, user_id, move_index, *action_type, payload, nonces[*player]);
+                    self.send(&[ix], &[bot]).await
+                }
+                Step::Wait(seconds) => self.cluster.wait(*seconds).await,
+            };
+            result.map_err(|e| format!("step {}: {}", i, e))?;
+        }
+
+        let ix = client::end_match_ix(authority, match_id, None, None, false);
+        self.send(&[ix], &[&coordinator]).await.map_err(|e| format!("end_match: {}", e))?;
+        let m = self.fetch_match(match_id).await?;
+        let ix = client::anchor_match_record_ix(authority, match_id, m.match_hash, scenario.hot_url.clone(), None);
+        self.send(&[ix], &[&coordinator]).await.map_err(|e| format!("anchor_match_record: {}", e))?;
+
+        Ok(json!({
+            "match_id": match_id,
+            "match_account": client::match_pda(match_id).0.to_string(),
+            "moves": m.move_count,
+            "match_hash": hex(&m.match_hash),
+            "outcome": format!("{:?}", m.get_outcome()),
+            "transactions": self.transactions - transactions_before,
+            "elapsed_ms": started.elapsed().as_millis() as u64,
+        }))
+    }
+}
+
+struct Options {
+    scenario_path: String,
+    runs: u32,
+    url: Option<String>,
+    keypair_path: Option<String>,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options { scenario_path: String::new(), runs: 1, url: None, keypair_path: None };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().cloned().ok_or_else(|| USAGE.to_string());
+        match arg.as_str() {
+            "--runs" => options.runs = value()?.parse().map_err(|_| "--runs must be a positive number")?,
+            "--url" => options.url = Some(value()?),
+            "--keypair" => options.keypair_path = Some(value()?),
+            path if options.scenario_path.is_empty() && !path.starts_with("--") => options.scenario_path = path.to_string(),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    if options.scenario_path.is_empty() || options.runs == 0 {
+        return Err(USAGE.to_string());
+    }
+    Ok(options)
+}
+
+async fn run(options: Options) -> Result<Value, String> {
+    let text = std::fs::read_to_string(&options.scenario_path).map_err(|e| format!("{}: {}", options.scenario_path, e))?;
+    let scenario: Scenario = serde_json::from_str(&text).map_err(|e| format!("{}: {}", options.scenario_path, e))?;
+    if scenario.match_id.is_some() && options.runs > 1 {
+        return Err("a fixed match_id can only be played once (drop it to use --runs)".to_string());
+    }
+
+    let mut runner = match options.url {
+        Some(url) => {
+            let path = options.keypair_path.unwrap_or_else(|| {
+                format!("{}/.config/solana/id.json", std::env::var("HOME").unwrap_or_default())
+            });
+            let keypair = read_keypair_file(&path).map_err(|e| format!("{}: {}", path, e))?;
+            Runner::rpc(url, keypair)
+        }
+        None => Runner::program_test().await,
+    };
+
+    let started = Instant::now();
+    let mut runs = Vec::new();
+    for _ in 0..options.runs {
+        let match_id = scenario.match_id.clone().unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        runs.push(runner.play(&scenario, &match_id).await.map_err(|e| format!("{}: {}", match_id, e))?);
+    }
+    let elapsed = started.elapsed();
+    Ok(json!({
+        "runs": runs,
+        "transactions": runner.transactions,
+        "elapsed_ms": elapsed.as_millis() as u64,
+        "transactions_per_second": runner.transactions as f64 / elapsed.as_secs_f64().max(f64::EPSILON),
+    }))
+}
+
+#[tokio::main]
+async fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match parse_args(&args) {
+        Ok(options) => run(options).await,
+        Err(message) => Err(message),
+    };
+    match result {
+        Ok(report) => println!("{}", serde_json::to_string_pretty(&report).unwrap()),
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,478 @@
+//! Off-chain client helpers (enabled with the `client` feature).
+//!
+//! Instruction builders and account decoders for Rust backends and bots, so seeds,
+//! account order, and layouts are not copied by hand. Builders derive every PDA;
+//! callers supply only signers and instruction arguments.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{ed25519_program, system_program, sysvar};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use crate::state::{Match, Move, UserAccount, Dispute, ConfigAccount, GameRegistry, OpenLobbyIndex};
+use crate::instructions::{apply_batch_state, apply_move_state, BatchMove};
+use crate::relay::{ed25519_instruction_data, EnvelopeSignature, MoveEnvelope, SignedMoveEnvelope};
+use crate::{accounts, instruction};
+
+pub use crate::pda::*;
+
+/// Unlisted match, or private with `join_secret_hash` (see create_public_match_ix).
+pub fn create_match_ix(
+    authority: Pubkey,
+    match_id: &str,
+    game_type: u8,
+    seed: u64,
+    rounds_to_play: u8,
+    join_secret_hash: Option<[u8; 32]>,
+    player_consent_start: bool,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: create_match_accounts(authority, match_id, None),
+        data: instruction::CreateMatch {
+            match_id: match_id.to_string(),
+            game_type,
+            seed,
+            rounds_to_play,
+            join_secret_hash,
+            player_consent_start,
+        }
+        .data(),
+    }
+}
+
+/// Public match, listed in the game's open lobby index. Pass `Some(game_type)` as
+/// `listed_game_type` when joining or starting it.
+pub fn create_public_match_ix(
+    authority: Pubkey,
+    match_id: &str,
+    game_type: u8,
+    seed: u64,
+    rounds_to_play: u8,
+    player_consent_start: bool,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: create_match_accounts(authority, match_id, Some(open_lobby_index_pda(game_type).0)),
+        data: instruction::CreateMatch {
+            match_id: match_id.to_string(),
+            game_type,
+            seed,
+            rounds_to_play,
+            join_secret_hash: None,
+            player_consent_start,
+        }
+        .data(),
+    }
+}
+
+/// Counted match: the match_id is `counted_match_id(&authority, match_counter)`, where
+/// `match_counter` is the authority's MatchCreator.match_counter (0 if it has none yet).
+pub fn create_match_counted_ix(
+    authority: Pubkey,
+    match_counter: u64,
+    game_type: u8,
+    seed: u64,
+    rounds_to_play: u8,
+    join_secret_hash: Option<[u8; 32]>,
+    player_consent_start: bool,
+) -> Instruction {
+    let match_id = counted_match_id(&authority, match_counter);
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts::CreateMatchCounted {
+            match_creator: match_creator_pda(&authority).0,
+            match_account: match_pda(&match_id).0,
+            global_stats: global_stats_pda().0,
+            game_registry: game_registry_pda().0,
+            lobby_index: None,
+            authority,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::CreateMatchCounted {
+            match_counter,
+            game_type,
+            seed,
+            rounds_to_play,
+            join_secret_hash,
+            player_consent_start,
+        }
+        .data(),
+    }
+}
+
+fn create_match_accounts(authority: Pubkey, match_id: &str, lobby_index: Option<Pubkey>) -> Vec<AccountMeta> {
+    accounts::CreateMatch {
+        match_account: match_pda(match_id).0,
+        global_stats: global_stats_pda().0,
+        game_registry: game_registry_pda().0,
+        lobby_index,
+        authority,
+        system_program: system_program::ID,
+    }
+    .to_account_metas(None)
+}
+
+/// Remaining accounts are the match PDAs of `matches`, in order.
+pub fn create_matches_batch_ix(
+    authority: Pubkey,
+    game_type: u8,
+    rounds_to_play: u8,
+    matches: Vec<crate::instructions::BatchMatch>,
+) -> Instruction {
+    let mut account_metas = accounts::CreateMatchesBatch {
+        global_stats: global_stats_pda().0,
+        game_registry: game_registry_pda().0,
+        authority,
+        system_program: system_program::ID,
+    }
+    .to_account_metas(None);
+    account_metas.extend(
+        matches.iter().map(|m| AccountMeta::new(match_pda(&m.match_id).0, false)),
+    );
+    Instruction {
+        program_id: crate::ID,
+        accounts: account_metas,
+        data: instruction::CreateMatchesBatch {
+            game_type,
+            rounds_to_play,
+            matches,
+        }
+        .data(),
+    }
+}
+
+/// `coordinator` co-signs private match joins (after checking the join code off-chain).
+/// `listed_game_type` is the game type of a public match (None otherwise).
+pub fn join_match_ix(
+    player: Pubkey,
+    match_id: &str,
+    user_id: &str,
+    coordinator: Option<Pubkey>,
+    is_bot: bool,
+    listed_game_type: Option<u8>,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts::JoinMatch {
+            match_account: match_pda(match_id).0,
+            sanction: sanction_pda(user_id).0,
+            player,
+            lobby_index: listed_game_type.map(|game_type| open_lobby_index_pda(game_type).0),
+            authority: coordinator,
+        }
+        .to_account_metas(None),
+        data: instruction::JoinMatch {
+            match_id: match_id.to_string(),
+            user_id: user_id.to_string(),
+            is_bot,
+        }
+        .data(),
+    }
+}
+
+/// `hand_hash` is `validation::hand_hash` of the dealt cards.
+pub fn commit_hand_ix(player: Pubkey, match_id: &str, user_id: &str, hand_hash: [u8; 32], hand_size: u8) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts::CommitHand {
+            match_account: match_pda(match_id).0,
+            player,
+        }
+        .to_account_metas(None),
+        data: instruction::CommitHand {
+            match_id: match_id.to_string(),
+            user_id: user_id.to_string(),
+            hand_hash,
+            hand_size,
+        }
+        .data(),
+    }
+}
+
+/// `listed_game_type` is the game type of a public match (None otherwise).
+pub fn start_match_ix(authority: Pubkey, match_id: &str, listed_game_type: Option<u8>) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts::StartMatch {
+            match_account: match_pda(match_id).0,
+            deck_commitment: None,
+            lobby_index: listed_game_type.map(|game_type| open_lobby_index_pda(game_type).0),
+            authority,
+        }
+        .to_account_metas(None),
+        data: instruction::StartMatch {
+            match_id: match_id.to_string(),
+        }
+        .data(),
+    }
+}
+
+/// `floor_card_hash` is SHA-256([suit, value]) of the revealed card.
+pub fn reveal_floor_card_ix(authority: Pubkey, match_id: &str, floor_card_hash: [u8; 32]) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts::RevealFloorCard {
+            match_account: match_pda(match_id).0,
+            authority,
+        }
+        .to_account_metas(None),
+        data: instruction::RevealFloorCard {
+            match_id: match_id.to_string(),
+            floor_card_hash,
+        }
+        .data(),
+    }
+}
+
+/// `move_index` is the match's current `move_count` (the new Move PDA's index).
+pub fn submit_move_ix(
+    player: Pubkey,
+    match_id: &str,
+    user_id: &str,
+    move_index: u32,
+    action_type: u8,
+    payload: Vec<u8>,
+    nonce: u64,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts::SubmitMove {
+            match_account: match_pda(match_id).0,
+            config_account: config_pda().0,
+            game_registry: game_registry_pda().0,
+            sanction: sanction_pda(user_id).0,
+            move_account: move_pda(match_id, move_index).0,
+            player,
+            instructions: None,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::SubmitMove {
+            match_id: match_id.to_string(),
+            user_id: user_id.to_string(),
+            action_type,
+            payload,
+            nonce,
+            envelope_signature: None,
+        }
+        .data(),
+    }
+}
+
+/// Move submitted with its player-signed envelope (the Move keeps the envelope hash):
+/// the Ed25519 verify instruction followed by submit_move. `signed.signature` is the
+/// player's signature over `signed.message()`; the player also signs the transaction.
+pub fn submit_signed_move_ixs(player: Pubkey, signed: &SignedMoveEnvelope, move_index: u32) -> Result<[Instruction; 2]> {
+    let verify_ix = Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data: ed25519_instruction_data(&player, &signed.signature, &signed.message()?),
+    };
+    let envelope = &signed.envelope;
+    let move_ix = Instruction {
+        program_id: crate::ID,
+        accounts: accounts::SubmitMove {
+            match_account: match_pda(&envelope.match_id).0,
+            config_account: config_pda().0,
+            game_registry: game_registry_pda().0,
+            sanction: sanction_pda(&envelope.user_id).0,
+            move_account: move_pda(&envelope.match_id, move_index).0,
+            player,
+            instructions: Some(sysvar::instructions::ID),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::SubmitMove {
+            match_id: envelope.match_id.clone(),
+            user_id: envelope.user_id.clone(),
+            action_type: envelope.action_type,
+            payload: envelope.payload.clone(),
+            nonce: envelope.nonce,
+            envelope_signature: Some(EnvelopeSignature { slot: signed.slot, signature: signed.signature }),
+        }
+        .data(),
+    };
+    Ok([verify_ix, move_ix])
+}
+
+/// Coordinator-paid move: the Ed25519 verify instruction followed by submit_relayed_move.
+/// `signature` is the player's signature over `envelope.message()`.
+pub fn submit_relayed_move_ixs(
+    coordinator: Pubkey,
+    player: Pubkey,
+    signature: &[u8; 64],
+    envelope: MoveEnvelope,
+    move_index: u32,
+) -> Result<[Instruction; 2]> {
+    let message = envelope.message()?;
+    let verify_ix = Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data: ed25519_instruction_data(&player, signature, &message),
+    };
+    let move_ix = Instruction {
+        program_id: crate::ID,
+        accounts: accounts::SubmitRelayedMove {
+            match_account: match_pda(&envelope.match_id).0,
+            config_account: config_pda().0,
+            game_registry: game_registry_pda().0,
+            sanction: sanction_pda(&envelope.user_id).0,
+            move_account: move_pda(&envelope.match_id, move_index).0,
+            signer_registry: signer_registry_pda().0,
+            instructions: sysvar::instructions::ID,
+            coordinator,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::SubmitRelayedMove { envelope, player }.data(),
+    };
+    Ok([verify_ix, move_ix])
+}
+
+/// `failover`: the signer is a CoordinatorSet coordinator ending another instance's match.
+pub fn end_match_ix(
+    authority: Pubkey,
+    match_id: &str,
+    match_hash: Option<[u8; 32]>,
+    hot_url: Option<String>,
+    failover: bool,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts::EndMatch {
+            match_account: match_pda(match_id).0,
+            coordinator_set: failover.then(|| coordinator_set_pda().0),
+            global_stats: global_stats_pda().0,
+            authority,
+        }
+        .to_account_metas(None),
+        data: instruction::EndMatch {
+            match_id: match_id.to_string(),
+            match_hash,
+            hot_url,
+        }
+        .data(),
+    }
+}
+
+/// `cold` is the archive location and the SHA-256 of the archived record.
+pub fn anchor_match_record_ix(
+    authority: Pubkey,
+    match_id: &str,
+    match_hash: [u8; 32],
+    hot_url: Option<String>,
+    cold: Option<(String, [u8; 32])>,
+) -> Instruction {
+    let (cold_uri, cold_content_hash) = cold.unzip();
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts::AnchorMatchRecord {
+            match_account: match_pda(match_id).0,
+            coordinator_set: None,
+            authority,
+        }
+        .to_account_metas(None),
+        data: instruction::AnchorMatchRecord {
+            match_id: match_id.to_string(),
+            match_hash,
+            hot_url,
+            cold_uri,
+            cold_content_hash,
+        }
+        .data(),
+    }
+}
+
+pub fn set_congestion_level_ix(coordinator: Pubkey, congestion_level: u8) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts::SetCongestionLevel {
+            config_account: config_pda().0,
+            signer_registry: signer_registry_pda().0,
+            coordinator,
+        }
+        .to_account_metas(None),
+        data: instruction::SetCongestionLevel { congestion_level }.data(),
+    }
+}
+
+/// Decodes raw account data (discriminator checked) into any of this program's accounts.
+pub fn decode_account<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_match(data: &[u8]) -> Result<Match> {
+    decode_account(data)
+}
+
+pub fn decode_move(data: &[u8]) -> Result<Move> {
+    decode_account(data)
+}
+
+pub fn decode_user_account(data: &[u8]) -> Result<UserAccount> {
+    decode_account(data)
+}
+
+pub fn decode_dispute(data: &[u8]) -> Result<Dispute> {
+    decode_account(data)
+}
+
+pub fn decode_config(data: &[u8]) -> Result<ConfigAccount> {
+    decode_account(data)
+}
+
+pub fn decode_game_registry(data: &[u8]) -> Result<GameRegistry> {
+    decode_account(data)
+}
+
+/// Match ids listed in a game's open lobby index (at `open_lobby_index_pda(game_type)`),
+/// oldest first. Check each match's phase before joining.
+pub fn open_lobby_match_ids(index_data: &[u8]) -> Result<Vec<String>> {
+    let index: OpenLobbyIndex = decode_account(index_data)?;
+    Ok(index.entries[..index.entry_count as usize]
+        .iter()
+        .map(|entry| String::from_utf8_lossy(&entry.match_id).into_owned())
+        .collect())
+}
+
+/// Congestion level from raw ConfigAccount data (0 = normal, 1 = elevated, 2 = high).
+/// At high congestion, batch at least `ConfigAccount::min_batch_moves` moves per
+/// submit_batch_moves and raise priority fees.
+pub fn congestion_level(config_data: &[u8]) -> Result<u8> {
+    Ok(decode_config(config_data)?.congestion_level)
+}
+
+/// Pre-flights a submit_move (or relayed move) against fetched account state without
+/// sending it: the same validation pipeline and state transition the program runs.
+/// Returns the match as it would be after the move, or the error the program would
+/// return. Signatures and sanctions are not checked; `clock` should be a recent
+/// Clock sysvar (time banks, pauses and rate limits depend on it).
+pub fn simulate_move(
+    match_account: &Match,
+    config: &ConfigAccount,
+    game_registry: &GameRegistry,
+    player: Pubkey,
+    envelope: &MoveEnvelope,
+    clock: &Clock,
+) -> Result<Match> {
+    let mut simulated = match_account.clone();
+    apply_move_state(&mut simulated, config, game_registry, player, envelope, clock)?;
+    Ok(simulated)
+}
+
+/// Pre-flights a submit_batch_moves the same way as [`simulate_move`]. The batch is
+/// all-or-nothing on-chain, so the first illegal move's error is returned.
+pub fn simulate_batch(
+    match_account: &Match,
+    config: &ConfigAccount,
+    game_registry: &GameRegistry,
+    player: Pubkey,
+    user_id: &str,
+    moves: &[BatchMove],
+    clock: &Clock,
+) -> Result<Match> {
+    let mut simulated = match_account.clone();
+    apply_batch_state(&mut simulated, game_registry, config, player, user_id, moves, clock)?;
+    Ok(simulated)
+}
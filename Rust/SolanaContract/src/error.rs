@@ -104,5 +104,451 @@ pub enum GameError {
     
     #[msg("GP balance exceeds maximum cap")]
     GPBalanceExceeded,
+    
+    #[msg("No active subscription")]
+    SubscriptionNotActive,
+    
+    #[msg("Subscription is already set to cancel at period end")]
+    SubscriptionAlreadyCancelled,
+    
+    #[msg("Refund amount exceeds total AC spent")]
+    RefundExceedsSpent,
+    
+    #[msg("Usage meter period does not match current billing period")]
+    InvalidUsagePeriod,
+    
+    #[msg("Model token quota exceeded for this billing period")]
+    ModelQuotaExceeded,
+    
+    #[msg("Move rate limit exceeded - too many moves in this slot")]
+    MoveRateLimited,
+    
+    #[msg("Floor card already revealed")]
+    FloorCardAlreadyRevealed,
+    
+    #[msg("No rounds remaining in this match")]
+    NoRoundsRemaining,
+    
+    #[msg("Invalid team assignment")]
+    InvalidTeam,
+    
+    #[msg("All players must be assigned to a team with at least one partner")]
+    TeamsIncomplete,
+    
+    #[msg("Private match - joining needs the coordinator's admission")]
+    InvalidJoinSecret,
+    
+    #[msg("Player is already in the matchmaking queue")]
+    AlreadyQueued,
+    
+    #[msg("Player is not in the matchmaking queue")]
+    NotQueued,
+    
+    #[msg("Matchmaking queue is full")]
+    QueueFull,
+    
+    #[msg("Not enough queued players within tier band to form a match")]
+    NotEnoughQueuedPlayers,
+    
+    #[msg("Ratings already recorded for this match")]
+    RatingsAlreadyRecorded,
+    
+    #[msg("Invalid player rating account")]
+    InvalidRatingAccount,
+    
+    #[msg("Invalid or missing leaderboard shard")]
+    InvalidLeaderboardShard,
+    
+    #[msg("Season leaderboard is finalized")]
+    SeasonFinalized,
+    
+    #[msg("Season has not ended yet")]
+    SeasonNotEnded,
+    
+    #[msg("Placement matches not yet completed")]
+    PlacementIncomplete,
+    
+    #[msg("Account too new for high-stakes matches")]
+    AccountTooNew,
+    
+    #[msg("User is banned or suspended")]
+    UserSanctioned,
+    
+    #[msg("Sanction is not active")]
+    SanctionNotActive,
+    
+    #[msg("Card was already discarded this round")]
+    CardAlreadyDiscarded,
+    
+    #[msg("Invalid time control settings")]
+    InvalidTimeControl,
+    
+    #[msg("Player's time bank is exhausted")]
+    TimeBankExhausted,
+    
+    #[msg("Player still has time remaining")]
+    TimeBankNotExhausted,
+    
+    #[msg("No draw offer pending")]
+    NoDrawOffer,
+    
+    #[msg("Match is paused")]
+    MatchPaused,
+    
+    #[msg("Match is not paused")]
+    MatchNotPaused,
+    
+    #[msg("Invalid pause duration")]
+    InvalidPauseDuration,
+    
+    #[msg("GP cost does not match the game's fee")]
+    InvalidGameFee,
+    
+    #[msg("Invalid promo code")]
+    InvalidPromoCode,
+    
+    #[msg("Promo code has expired")]
+    PromoExpired,
+    
+    #[msg("Promo code has no redemptions left")]
+    PromoExhausted,
+    
+    #[msg("Promo code already redeemed by this user")]
+    PromoAlreadyRedeemed,
+    
+    #[msg("Daily gift limit exceeded")]
+    GiftLimitExceeded,
+    
+    #[msg("Match has no single winner")]
+    NoWinner,
+    
+    #[msg("Invalid or disabled cosmetic item")]
+    InvalidCosmetic,
+    
+    #[msg("User is already in a guild")]
+    AlreadyInGuild,
+    
+    #[msg("Guild is full")]
+    GuildFull,
+    
+    #[msg("User is not a member of this guild")]
+    NotGuildMember,
+    
+    #[msg("Guild owner can't leave or be kicked")]
+    GuildOwnerCannotLeave,
+    
+    #[msg("Invitation already used")]
+    InvalidInvitation,
+    
+    #[msg("Invitation has expired")]
+    InvitationExpired,
+    
+    #[msg("Match hash does not match the replay transcript")]
+    MatchHashMismatch,
+    
+    #[msg("Archive URI is already set")]
+    ColdUriAlreadySet,
+    
+    #[msg("Price feed account doesn't match the configured oracle")]
+    InvalidPriceFeed,
+    
+    #[msg("Oracle price is stale, not trading or too uncertain")]
+    PriceUnavailable,
+    
+    #[msg("Relayed move is missing a matching ed25519 signature")]
+    InvalidRelaySignature,
+    
+    #[msg("Match ID is malformed or doesn't match the match account")]
+    MatchIdMismatch,
+    
+    #[msg("User ID must be 1-64 bytes")]
+    UserIdTooLong,
+    
+    #[msg("Move payload exceeds 128 bytes")]
+    PayloadTooLarge,
+    
+    #[msg("Move payload is too short for this action")]
+    PayloadTooShort,
+    
+    #[msg("Move payload doesn't match the registered payload schema")]
+    PayloadSchemaMismatch,
+    
+    #[msg("Too many (or no) moves in one instruction")]
+    BatchTooLarge,
+    
+    #[msg("URL is empty or longer than 200 bytes")]
+    UrlTooLong,
+    
+    #[msg("Hash must not be all zeros")]
+    EmptyHash,
+    
+    #[msg("Card hash doesn't match the floor card")]
+    CardHashMismatchFloor,
+    
+    #[msg("Hand is full")]
+    HandFull,
+    
+    #[msg("Hand is empty")]
+    EmptyHand,
+    
+    #[msg("Suit must be 0-3")]
+    InvalidSuit,
+    
+    #[msg("Player has already declared a suit")]
+    SuitAlreadyDeclared,
+    
+    #[msg("Suit is locked by another player's declaration")]
+    SuitLocked,
+    
+    #[msg("Cards don't form a valid 3-card run")]
+    InvalidRun,
+    
+    #[msg("Rebuttal run doesn't beat the declaration")]
+    RebuttalTooLow,
+    
+    #[msg("Game registry is full")]
+    GameRegistryFull,
+    
+    #[msg("Game is already registered")]
+    GameAlreadyRegistered,
+    
+    #[msg("Game is not registered")]
+    GameNotRegistered,
+    
+    #[msg("Every player must commit a hand before the match starts")]
+    HandsNotCommitted,
+    
+    #[msg("Committed hand sizes exceed the deck size")]
+    DealExceedsDeck,
+    
+    #[msg("Match was not created with player-consent start")]
+    ConsentStartDisabled,
+    
+    #[msg("New match authority must be set and differ from the current one")]
+    InvalidNewAuthority,
+    
+    #[msg("Match account is not the expected uninitialized match PDA")]
+    InvalidMatchAccount,
+    
+    #[msg("Action repeated before its cooldown elapsed")]
+    ActionOnCooldown,
+    
+    #[msg("Showdown rebuttal window has closed")]
+    RebuttalWindowClosed,
+    
+    #[msg("Showdown rebuttal window is still open")]
+    ShowdownInProgress,
+    
+    #[msg("Invalid rules variant: unknown bits or run length out of range")]
+    InvalidRulesVariant,
+    
+    #[msg("Turn deadline has passed")]
+    TurnDeadlinePassed,
+    
+    #[msg("Leaderboard decay is disabled")]
+    LeaderboardDecayDisabled,
+    
+    #[msg("Account schema version is newer than this program supports")]
+    UnsupportedSchemaVersion,
+    
+    #[msg("Daily match cap reached for this game")]
+    DailyMatchCapReached,
+    
+    #[msg("Rent refund account is not the Move account's rent payer")]
+    RentPayerMismatch,
+    
+    #[msg("Too few moves in one batch while the network is congested")]
+    BatchTooSmall,
+    
+    #[msg("Congestion level out of range")]
+    InvalidCongestionLevel,
+    
+    #[msg("Dispute window for this match has closed")]
+    DisputeWindowClosed,
+    
+    #[msg("Too many unresolved disputes filed by this user")]
+    TooManyActiveDisputes,
+    
+    #[msg("Validator already has a slash awaiting appeal or finality")]
+    SlashPending,
+    
+    #[msg("No slash to appeal, or it was already appealed")]
+    NoAppealableSlash,
+    
+    #[msg("Slash appeal window has closed")]
+    AppealWindowClosed,
+    
+    #[msg("No pending slash appeal")]
+    NoPendingAppeal,
+    
+    #[msg("Inactivity evidence must be unresolved disputes past the response window, oldest first and not yet counted")]
+    InvalidInactivityEvidence,
+    
+    #[msg("Validator is active and has not missed enough disputes")]
+    ValidatorNotInactive,
+    
+    #[msg("Coordinator is already in the coordinator set")]
+    CoordinatorAlreadyInSet,
+    
+    #[msg("Coordinator set is full")]
+    CoordinatorSetFull,
+    
+    #[msg("Coordinator is not in the coordinator set")]
+    CoordinatorNotInSet,
+    
+    #[msg("Match metadata is full")]
+    MatchMetadataFull,
+    
+    #[msg("Instruction does not apply to this match's game type")]
+    WrongGameType,
+    
+    #[msg("Invalid tile placement")]
+    InvalidTilePlacement,
+    
+    #[msg("Tile is not in the player's rack")]
+    TileNotInRack,
+    
+    #[msg("Tile reveal does not match the committed bag")]
+    InvalidTileReveal,
+    
+    #[msg("Rack is full")]
+    RackFull,
+    
+    #[msg("Word search grid does not match the commitment or is not revealed")]
+    InvalidGridReveal,
+    
+    #[msg("Word is not in the committed word list")]
+    WordNotInList,
+    
+    #[msg("Word has already been found")]
+    WordAlreadyFound,
+    
+    #[msg("Word does not appear at the given grid coordinates")]
+    WordNotInGrid,
+    
+    #[msg("Answer does not match the committed answer for this clue")]
+    WrongAnswer,
+    
+    #[msg("Clue has already been solved")]
+    ClueAlreadySolved,
+    
+    #[msg("Invalid meld: must be a set or run of 3+ cards with more natural cards than jokers")]
+    InvalidMeld,
+    
+    #[msg("Card has already been melded this round")]
+    CardAlreadyMelded,
+    
+    #[msg("Bet is below the current stake")]
+    BetTooLow,
+    
+    #[msg("Player has folded")]
+    PlayerFolded,
+    
+    #[msg("Player has already seen their cards")]
+    AlreadySeen,
+    
+    #[msg("Player must see their cards first")]
+    HandNotSeen,
+    
+    #[msg("Show is only possible between the last two players")]
+    ShowNotAvailable,
+    
+    #[msg("Shown hand does not match the committed hand")]
+    HandRevealMismatch,
+    
+    #[msg("Betting is not enabled for this game")]
+    BettingDisabled,
+    
+    #[msg("Rake exceeds the maximum")]
+    RakeTooHigh,
+    
+    #[msg("Series must be best of 1, 3, 5 or 7")]
+    InvalidSeriesLength,
+    
+    #[msg("Match players do not match the series roster")]
+    SeriesRosterMismatch,
+    
+    #[msg("Series is already complete")]
+    SeriesAlreadyComplete,
+    
+    #[msg("Match has already been reported to this series")]
+    MatchAlreadyReported,
+    
+    #[msg("Match is scheduled to start later")]
+    ScheduledStartNotReached,
+    
+    #[msg("Match missed its scheduled start window")]
+    ScheduledStartMissed,
+    
+    #[msg("Invalid rule params")]
+    InvalidRuleParams,
+    
+    #[msg("Hand size does not match the match's rule params")]
+    HandSizeMismatch,
+    
+    #[msg("Bot seats need the match authority's attestation")]
+    BotAttestationRequired,
+    
+    #[msg("AI credit consumption exceeds the user's daily spending limit")]
+    AiSpendLimitExceeded,
+    
+    #[msg("Subscription allowance already accrued for this period")]
+    AllowanceAlreadyAccrued,
+    
+    #[msg("No subscription allowance to accrue")]
+    NoSubscriptionAllowance,
+    
+    #[msg("Purchase is missing a valid payment attestation")]
+    InvalidPaymentAttestation,
+    
+    #[msg("Payment attestation has expired")]
+    PaymentAttestationExpired,
+    
+    #[msg("Unknown payment product")]
+    InvalidPaymentProduct,
+    
+    #[msg("User must leave their guild and have no open disputes before erasure")]
+    ErasureBlocked,
+    
+    #[msg("Ownership proof is missing or doesn't match a registered coordinator's attestation")]
+    InvalidOwnershipProof,
+    
+    #[msg("Ownership proof has expired")]
+    OwnershipProofExpired,
+    
+    #[msg("Open lobby index is full")]
+    LobbyIndexFull,
+    
+    #[msg("Public matches must pass the game's open lobby index (and only public matches can)")]
+    LobbyIndexMismatch,
+    
+    #[msg("Match id is in the derived range reserved for create_match_counted")]
+    ReservedMatchId,
+    
+    #[msg("Match counter doesn't match the creator's next counter")]
+    MatchCounterMismatch,
+    
+    #[msg("Batch doesn't reference the previous batch's root")]
+    BatchChainMismatch,
+    
+    #[msg("Batch sequence number isn't the next in the chain")]
+    BatchSequenceMismatch,
+    
+    #[msg("Batch period is empty, overlaps the previous batch or ends in the future")]
+    InvalidBatchPeriod,
+    
+    #[msg("Signed move envelope slot is after the current slot")]
+    InvalidEnvelopeSlot,
+    
+    #[msg("Gift fee exceeds 10000 bps or minimum account age is negative")]
+    InvalidGiftPolicy,
+    
+    #[msg("An active sanction is stronger or lasts longer")]
+    SanctionDowngrade,
+    
+    #[msg("Reversal exceeds the receipt's unreversed amount or doesn't match its user and product")]
+    ReversalExceedsReceipt,
+    
+    #[msg("Account passed to erasure doesn't name the user, or its match or dispute is still open")]
+    InvalidErasureAccount,
 }
-
@@ -0,0 +1,283 @@
+//! Typed program events.
+//!
+//! Emitted with `emit!` (borsh-encoded `Program data:` log lines) instead of formatted
+//! `msg!` strings: indexers decode them with the IDL, and they carry account keys, seat
+//! indices and hashes rather than text. Human-readable logs are behind the `telemetry`
+//! feature (see the `telemetry!` macro) so production builds can drop the formatting cost.
+
+use anchor_lang::prelude::*;
+use crate::state::Match;
+
+#[event]
+pub struct MatchCreated {
+    pub match_account: Pubkey,
+    pub game_type: u8,
+    pub authority: Pubkey,
+    pub rounds_to_play: u8,
+}
+
+#[event]
+pub struct PlayerJoined {
+    pub match_account: Pubkey,
+    pub player_index: u8,
+    pub player_count: u8,
+    pub invited: bool,
+    pub is_bot: bool,
+}
+
+#[event]
+pub struct HandCommitted {
+    pub match_account: Pubkey,
+    pub player_index: u8,
+    pub hand_hash: [u8; 32],
+    pub hand_size: u8,
+}
+
+#[event]
+pub struct PlayerReady {
+    pub match_account: Pubkey,
+    pub player_index: u8,
+    pub ready: bool,
+    pub ready_count: u8,
+}
+
+#[event]
+pub struct MatchStarted {
+    pub match_account: Pubkey,
+    pub round_number: u8,
+    pub player_count: u8,
+    pub transcript_hash: [u8; 32],
+}
+
+#[event]
+pub struct FloorCardRevealed {
+    pub match_account: Pubkey,
+    pub floor_card_hash: [u8; 32],
+    pub current_player: u8,
+}
+
+#[event]
+pub struct MoveSubmitted {
+    pub match_account: Pubkey,
+    pub move_index: u32,
+    pub player_index: u8,
+    pub player: Pubkey,
+    pub action_type: u8,
+    pub nonce: u64,
+    pub transcript_hash: [u8; 32], // Running replay hash after this move
+}
+
+#[event]
+pub struct ShowdownClosed {
+    pub match_account: Pubkey,
+    pub showdown_caller: u8,
+    pub rebuttal_responses: u16, // Bit i set = player i rebutted
+    pub ended_at: i64,
+}
+
+#[event]
+pub struct RoundStarted {
+    pub match_account: Pubkey,
+    pub round_number: u8,
+    pub rounds_to_play: u8,
+    pub cumulative_scores: [i32; 10],
+}
+
+#[event]
+pub struct MatchEnded {
+    pub match_account: Pubkey,
+    pub outcome: u8,        // EndReason as u8
+    pub outcome_player: u8,
+    pub round_number: u8,
+    pub cumulative_scores: [i32; 10],
+    pub match_hash: [u8; 32],
+    pub ended_at: i64,
+    pub bot_seats: u16,     // Match::is_bot; season leaderboards count only matches without bots
+}
+
+impl MatchEnded {
+    pub fn from_match(match_account: Pubkey, m: &Match) -> Self {
+        Self {
+            match_account,
+            outcome: m.outcome,
+            outcome_player: m.outcome_player,
+            round_number: m.round_number,
+            cumulative_scores: m.cumulative_scores,
+            match_hash: m.match_hash,
+            ended_at: m.ended_at,
+            bot_seats: m.is_bot,
+        }
+    }
+}
+
+#[event]
+pub struct WordPlaced {
+    pub match_account: Pubkey,
+    pub player_index: u8,
+    pub tiles_placed: u8,
+    pub words_formed: u8,
+    pub score: u16,
+    pub total_score: u16,
+}
+
+#[event]
+pub struct WordFound {
+    pub match_account: Pubkey,
+    pub player_index: u8,
+    pub word_index: u8,
+    pub row: u8,
+    pub col: u8,
+    pub direction: u8,
+    pub points: u16,
+    pub total_score: u16,
+    pub words_remaining: u8,
+}
+
+#[event]
+pub struct AnswerAccepted {
+    pub match_account: Pubkey,
+    pub player_index: u8,
+    pub clue_index: u8,
+    pub points: u16,
+    pub total_score: u16,
+    pub clues_remaining: u8,
+}
+
+#[event]
+pub struct SeriesGameReported {
+    pub series: Pubkey,
+    pub match_account: Pubkey,
+    pub game_number: u8,
+    pub game_winner: u8,    // Roster seat, Series::NO_WINNER for a game without a winner
+    pub wins: [u8; 10],
+    pub completed: bool,
+    pub series_winner: u8,  // Roster seat, Series::NO_WINNER until decided
+}
+
+#[event]
+pub struct ScheduledMatchExpired {
+    pub match_account: Pubkey,
+    pub scheduled_start: i64,
+    pub player_count: u8,   // Joined seats to refund
+    pub ended_at: i64,
+}
+
+#[event]
+pub struct MatchForceEnded {
+    pub match_account: Pubkey,
+    pub council: Pubkey,
+    pub reason: u8,         // Match::FORCE_END_* code
+    pub previous_phase: u8,
+    pub player_count: u8,
+    pub ended_at: i64,
+}
+
+#[event]
+pub struct MatchAuthorityTransferred {
+    pub match_account: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub by_coordinator: bool,  // false = player quorum
+    pub authority_votes: u16,  // Player votes behind a quorum transfer (0 for coordinator transfers)
+}
+
+#[event]
+pub struct MatchRecordAnchored {
+    pub match_account: Pubkey,
+    pub match_hash: [u8; 32],
+    pub cold_content_hash: [u8; 32], // All zeros = no permanent archive recorded
+}
+
+#[event]
+pub struct DisputeFlagged {
+    pub dispute: Pubkey,
+    pub reason: u8,
+    pub has_defendant: bool,
+    pub evidence_hash: [u8; 32],
+    pub gp_deposit: u32,
+    pub assigned_validator: Pubkey,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub dispute: Pubkey,
+    pub resolution: u8,
+    pub gp_refunded: bool,
+}
+
+#[event]
+pub struct AiMoveAttested {
+    pub match_account: Pubkey,
+    pub move_index: u32,
+    pub player: Pubkey,
+    pub model_id: u8,
+    pub consumption_id: [u8; 32], // All zeros = not billed
+}
+
+#[event]
+pub struct AiSpendLimitSet {
+    pub user_account: Pubkey,
+    pub previous_limit: u64,
+    pub daily_limit: u64,  // 0 = no limit
+    pub effective_at: i64, // Later than now for a raise
+}
+
+#[event]
+pub struct SubscriptionCreditsAccrued {
+    pub user_account: Pubkey,
+    pub period_id: u32,
+    pub tier: u8,
+    pub allowance: u64,
+    pub rolled_over: u64,
+}
+
+#[event]
+pub struct PaymentReversed {
+    pub user_account: Pubkey,
+    pub product: u8,
+    pub amount: u64,
+    pub chargeback: bool,
+    pub subscription_expiry: i64, // After the rollback (0 = lapsed)
+}
+
+#[event]
+pub struct UserAccountErased {
+    pub erased_account: Pubkey,
+    pub anon_key: [u8; 32],
+    pub entries_renamed: u8,
+    pub records_renamed: u8,
+    pub accounts_closed: u8,
+}
+
+#[event]
+pub struct WalletLinked {
+    pub user_account: Pubkey,
+    pub wallet: Pubkey,
+    pub previous_wallet: Pubkey,
+    pub attestor: Pubkey,
+    pub wallet_link_count: u32,
+}
+
+#[event]
+pub struct MatchListingChanged {
+    pub game_type: u8,
+    pub match_id: [u8; 36],
+    pub listed: bool,
+}
+
+#[event]
+pub struct TurnChanged {
+    pub match_id: [u8; 36],
+    pub new_player_index: u8,
+    pub deadline: i64,
+}
+
+impl TurnChanged {
+    pub fn from_match(m: &Match) -> Self {
+        Self {
+            match_id: m.match_id,
+            new_player_index: m.current_player,
+            deadline: m.turn_deadline,
+        }
+    }
+}
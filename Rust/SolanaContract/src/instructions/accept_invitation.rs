@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, Invitation, require_not_sanctioned, match_id_seed};
+use crate::events::PlayerJoined;
+use crate::error::GameError;
+
+/// Joins a match through an invitation addressed to `user_id`.
+/// The invitation PDA is derived from the match and user_id, so only the invited user can
+/// use it, once, before it expires. Private matches need no join code on this path.
+pub fn handler(ctx: Context<AcceptInvitation>, match_id: String, _user_id: String) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    let invitation = &mut ctx.accounts.invitation;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Invitation must be live
+    require!(!invitation.accepted, GameError::InvalidInvitation);
+    require!(!invitation.is_expired(&clock), GameError::InvitationExpired);
+
+    // Security: Validate match can accept players
+    require!(match_account.can_join(), GameError::MatchFull);
+    require!(match_account.phase == 0, GameError::InvalidPhase);
+
+    // Security: Banned or suspended users can't join matches
+    require_not_sanctioned(&ctx.accounts.sanction, &clock)?;
+
+    // Security: Rejects duplicate players (anti-cheat) and full matches, then adds the player
+    let player_index = match_account.add_player(invitation.invitee, ctx.accounts.player.key())?;
+    invitation.accepted = true;
+
+    emit!(PlayerJoined {
+        match_account: match_account.key(),
+        player_index: player_index as u8,
+        player_count: match_account.player_count,
+        invited: true,
+        is_bot: false,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String, user_id: String)]
+pub struct AcceptInvitation<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    #[account(
+        mut,
+        seeds = [b"invitation", match_account.key().as_ref(), user_id.as_bytes()],
+        bump
+    )]
+    pub invitation: Account<'info, Invitation>,
+    
+    /// CHECK: User's sanction PDA (may not exist); checked by require_not_sanctioned
+    #[account(
+        seeds = [b"sanction", user_id.as_bytes()],
+        bump
+    )]
+    pub sanction: UncheckedAccount<'info>,
+    
+    pub player: Signer<'info>,
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use crate::state::{UserAccount, ConfigAccount, UsageMeter};
+use crate::events::SubscriptionCreditsAccrued;
+use crate::error::GameError;
+
+/// Credits a subscriber's AC allowance for the current billing period to their UsageMeter,
+/// where consume_ai_credits draws it down before purchased AC.
+/// The allowance comes from ConfigAccount.ac_allowance_per_period for the user's active tier.
+/// Unused allowance from the previous period (pass its meter) rolls over, up to the tier's
+/// ac_rollover_cap, so roll-over can't stack across periods.
+/// Permissionless crank (the backend runs it at the start of each period, before the
+/// user's first AI call): once per user per period, and the payer funds a new meter.
+pub fn handler(
+    ctx: Context<AccrueSubscriptionCredits>,
+    user_id: String,
+    period_id: u32,  // Billing period (must equal UsageMeter::current_period_id)
+) -> Result<()> {
+    // Convert String to fixed-size array immediately (optimization)
+    let user_id_bytes = user_id.as_bytes();
+    require!(
+        user_id_bytes.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    let mut user_id_array = [0u8; 64];
+    let copy_len = user_id_bytes.len().min(64);
+    user_id_array[..copy_len].copy_from_slice(&user_id_bytes[..copy_len]);
+    
+    let config = &ctx.accounts.config_account;
+    let clock = Clock::get()?;
+    
+    // Meter PDA must be for the current billing period
+    require!(
+        period_id == UsageMeter::current_period_id(&clock),
+        GameError::InvalidUsagePeriod
+    );
+    
+    let usage_meter = &mut ctx.accounts.usage_meter;
+    require!(
+        !usage_meter.allowance_accrued(),
+        GameError::AllowanceAlreadyAccrued
+    );
+    
+    // Allowance follows the tier in force now (a downgrade past its date applies first)
+    let user_account = &mut ctx.accounts.user_account;
+    user_account.apply_pending_subscription_changes(&clock);
+    let tier = if user_account.has_active_subscription(&clock) {
+        user_account.subscription_tier
+    } else {
+        0
+    };
+    
+    let allowance = config.ac_allowance_for_tier(tier);
+    let rolled_over = match &ctx.accounts.previous_meter {
+        Some(previous) => previous.allowance_remaining().min(config.ac_rollover_cap_for_tier(tier)),
+        None => 0,
+    };
+    require!(
+        allowance > 0 || rolled_over > 0,
+        GameError::NoSubscriptionAllowance
+    );
+    
+    // Initialize meter if no AI call has been made in this period yet
+    if !usage_meter.is_initialized() {
+        usage_meter.user_id = user_id_array;
+        usage_meter.period_id = period_id;
+        usage_meter.created_at = clock.unix_timestamp;
+    }
+    usage_meter.ac_allowance = allowance
+        .checked_add(rolled_over)
+        .ok_or(GameError::Overflow)?;
+    usage_meter.ac_rolled_over = rolled_over;
+    usage_meter.allowance_accrued_at = clock.unix_timestamp;
+    
+    emit!(SubscriptionCreditsAccrued {
+        user_account: user_account.key(),
+        period_id,
+        tier,
+        allowance,
+        rolled_over,
+    });
+    
+    telemetry!("Subscription credits accrued for {}: {} AC + {} rolled over (tier={}, period={})",
+         user_id, allowance, rolled_over, tier, period_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(user_id: String, period_id: u32)]
+pub struct AccrueSubscriptionCredits<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UsageMeter::MAX_SIZE,
+        seeds = [b"usage_meter", user_id.as_bytes(), period_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub usage_meter: Account<'info, UsageMeter>,
+    
+    /// Previous period's meter (omit if the user had none): source of the roll-over
+    #[account(
+        seeds = [b"usage_meter", user_id.as_bytes(), period_id.saturating_sub(1).to_le_bytes().as_ref()],
+        bump
+    )]
+    pub previous_meter: Option<Account<'info, UsageMeter>>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
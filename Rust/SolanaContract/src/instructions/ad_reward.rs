@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::{UserAccount, ConfigAccount};
+use crate::state::{UserAccount, ConfigAccount, GlobalStats, require_not_sanctioned};
 use crate::error::GameError;
 
 /// Claims ad reward (GP).
@@ -9,18 +9,26 @@ pub fn handler(
     ctx: Context<ClaimAdReward>,
     user_id: String,
     ad_verification_signature: Vec<u8>,  // Off-chain oracle signature
+    op_nonce: u64,  // Per-user operation nonce (idempotency key)
 ) -> Result<()> {
     // Convert String to fixed-size array immediately (optimization)
     let user_id_bytes = user_id.as_bytes();
     require!(
         user_id_bytes.len() <= 64,
-        GameError::InvalidPayload
+        GameError::UserIdTooLong
     );
     
     let user_account = &mut ctx.accounts.user_account;
+    
+    // Idempotency: a retried operation can't be applied twice
+    user_account.use_op_nonce(op_nonce)?;
+    
     let config = &ctx.accounts.config_account;
     let clock = Clock::get()?;
     
+    // Security: Banned or suspended users can't claim rewards
+    require_not_sanctioned(&ctx.accounts.sanction, &clock)?;
+    
     // Verify ad was watched (off-chain oracle signature)
     // In production, verify signature from ad verification service
     // For now, we require non-empty signature
@@ -46,8 +54,9 @@ pub fn handler(
     user_account.lifetime_gp_earned = user_account.lifetime_gp_earned
         .checked_add(gp_reward)
         .ok_or(GameError::Overflow)?;
+    ctx.accounts.global_stats.record_gp_issued(gp_reward, clock.unix_timestamp);
     
-    msg!("Ad reward claimed: {} GP", gp_reward);
+    telemetry!("Ad reward claimed: {} GP", gp_reward);
     Ok(())
 }
 
@@ -68,6 +77,20 @@ pub struct ClaimAdReward<'info> {
     )]
     pub config_account: Account<'info, ConfigAccount>,
     
+    /// CHECK: User's sanction PDA (may not exist); checked by require_not_sanctioned
+    #[account(
+        seeds = [b"sanction", user_id.as_bytes()],
+        bump
+    )]
+    pub sanction: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_stats"],
+        bump
+    )]
+    pub global_stats: Box<Account<'info, GlobalStats>>,
+    
     pub system_program: Program<'info, System>,
 }
 
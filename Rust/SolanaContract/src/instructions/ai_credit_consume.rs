@@ -1,26 +1,49 @@
 use anchor_lang::prelude::*;
-use crate::state::{UserAccount, ConfigAccount};
+use crate::state::{UserAccount, ConfigAccount, UsageMeter};
 use crate::error::GameError;
 
 /// Records AI credit (AC) consumption.
 /// Per spec Section 20.1.6: AI credit consumption for API calls.
 /// Note: AC balance check happens off-chain in database. This instruction only updates stats.
 /// Note: String params converted to fixed arrays immediately for performance.
+/// Usage is also metered per model in the user's UsageMeter for the current billing period,
+/// and per-model token quotas from ConfigAccount are enforced here.
+/// The user's daily AC limit (UserAccount.ac_daily_limit, see set_ai_spend_limit) is enforced
+/// here too, so the backend can't spend past a cap the user set.
+/// Subscription allowance credited to the meter (accrue_subscription_credits) covers the cost
+/// first; only the rest comes out of the user's purchased AC.
 pub fn handler(
     ctx: Context<ConsumeAICredits>,
     user_id: String,
     model_id: u8,  // Model ID (0-9, corresponds to ai_model_costs array index)
     tokens_used: u32,  // Number of tokens used (in thousands)
+    period_id: u32,  // Billing period (must equal UsageMeter::current_period_id)
+    op_nonce: u64,  // Per-user operation nonce (idempotency key)
 ) -> Result<()> {
     // Convert String to fixed-size array immediately (optimization)
     let user_id_bytes = user_id.as_bytes();
     require!(
         user_id_bytes.len() <= 64,
-        GameError::InvalidPayload
+        GameError::UserIdTooLong
     );
+    let mut user_id_array = [0u8; 64];
+    let copy_len = user_id_bytes.len().min(64);
+    user_id_array[..copy_len].copy_from_slice(&user_id_bytes[..copy_len]);
     
     let user_account = &mut ctx.accounts.user_account;
+    
+    // Idempotency: a retried operation can't be applied twice
+    user_account.use_op_nonce(op_nonce)?;
+    
+    let usage_meter = &mut ctx.accounts.usage_meter;
     let config = &ctx.accounts.config_account;
+    let clock = Clock::get()?;
+    
+    // Meter PDA must be for the current billing period
+    require!(
+        period_id == UsageMeter::current_period_id(&clock),
+        GameError::InvalidUsagePeriod
+    );
     
     // Validate model_id
     require!(
@@ -34,6 +57,38 @@ pub fn handler(
         .checked_mul(tokens_used as u64)
         .ok_or(GameError::Overflow)?;
     
+    // Enforce the user's daily spending limit
+    user_account.record_ac_spend(ac_cost, &clock)?;
+    
+    // Initialize meter on first consumption in this period
+    if !usage_meter.is_initialized() {
+        usage_meter.user_id = user_id_array;
+        usage_meter.period_id = period_id;
+        usage_meter.created_at = clock.unix_timestamp;
+    }
+    
+    // Enforce per-model quota (0 = unlimited)
+    let model_index = model_id as usize;
+    let tokens_after = usage_meter.tokens_used[model_index]
+        .checked_add(tokens_used as u64)
+        .ok_or(GameError::Overflow)?;
+    let quota = config.ai_model_token_quotas[model_index];
+    require!(
+        quota == 0 || tokens_after <= quota as u64,
+        GameError::ModelQuotaExceeded
+    );
+    
+    // Record per-model usage
+    usage_meter.tokens_used[model_index] = tokens_after;
+    usage_meter.ac_spent[model_index] = usage_meter.ac_spent[model_index]
+        .checked_add(ac_cost)
+        .ok_or(GameError::Overflow)?;
+    usage_meter.call_counts[model_index] = usage_meter.call_counts[model_index]
+        .checked_add(1)
+        .ok_or(GameError::Overflow)?;
+    usage_meter.last_updated = clock.unix_timestamp;
+    let covered = usage_meter.use_allowance(ac_cost);
+    
     // Update stats (AC balance deducted in database before calling this)
     user_account.api_calls_made = user_account.api_calls_made
         .checked_add(1)
@@ -43,12 +98,13 @@ pub fn handler(
         .checked_add(ac_cost)
         .ok_or(GameError::Overflow)?;
     
-    msg!("AI credits consumed: {} AC (model_id={}, tokens={}k)", ac_cost, model_id, tokens_used);
+    telemetry!("AI credits consumed: {} AC, {} from allowance (model_id={}, tokens={}k)",
+         ac_cost, covered, model_id, tokens_used);
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(user_id: String)]
+#[instruction(user_id: String, model_id: u8, tokens_used: u32, period_id: u32)]
 pub struct ConsumeAICredits<'info> {
     #[account(
         mut,
@@ -57,6 +113,15 @@ pub struct ConsumeAICredits<'info> {
     )]
     pub user_account: Account<'info, UserAccount>,
     
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UsageMeter::MAX_SIZE,
+        seeds = [b"usage_meter", user_id.as_bytes(), period_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub usage_meter: Account<'info, UsageMeter>,
+    
     /// CHECK: Config account (read-only)
     #[account(
         seeds = [b"config_account"],
@@ -64,6 +129,9 @@ pub struct ConsumeAICredits<'info> {
     )]
     pub config_account: Account<'info, ConfigAccount>,
     
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
     pub system_program: Program<'info, System>,
 }
 
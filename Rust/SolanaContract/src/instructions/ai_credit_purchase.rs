@@ -1,38 +1,128 @@
 use anchor_lang::prelude::*;
-use crate::state::UserAccount;
+use anchor_lang::system_program;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use crate::state::{UserAccount, ConfigAccount, PaymentReceipt, SignerRegistry};
+use crate::oracle::OraclePrice;
+use crate::payment::{self, PaymentAttestation, PaymentProduct};
 use crate::error::GameError;
 
 /// Records AI credit (AC) purchase.
 /// Per spec Section 20.1.6: AI credit purchase system.
-/// Note: Payment processed via Stripe off-chain. This instruction only records the purchase.
+/// Pricing (on-chain payments only): when ConfigAccount.sol_usd_price_feed is set, the lamport
+/// cost is ac_price_usd converted at the live Pyth SOL/USD price (staleness and confidence
+/// checked); otherwise ac_price_lamports is used as a fallback.
+/// Payment: with a buyer signer the cost is transferred to ConfigAccount.ac_treasury on-chain.
+/// Without one, payment is processed via Stripe off-chain and this instruction only records it,
+/// against the payment oracle's signed receipt for this user and amount (see crate::payment),
+/// redeemed once into a PaymentReceipt.
 /// Note: String params converted to fixed arrays immediately for performance.
 pub fn handler(
     ctx: Context<PurchaseAICredits>,
     user_id: String,
     ac_amount: u64,  // Amount of AC purchased
+    op_nonce: u64,  // Per-user operation nonce (idempotency key)
+    attestation: Option<PaymentAttestation>,  // Required for Stripe purchases (no buyer)
 ) -> Result<()> {
     // Convert String to fixed-size array immediately (optimization)
     let user_id_bytes = user_id.as_bytes();
     require!(
         user_id_bytes.len() <= 64,
-        GameError::InvalidPayload
+        GameError::UserIdTooLong
     );
+    require!(ac_amount > 0, GameError::InvalidPayload);
+    
+    // Idempotency: a retried operation can't be applied twice
+    ctx.accounts.user_account.use_op_nonce(op_nonce)?;
     
-    let user_account = &mut ctx.accounts.user_account;
+    let config = &ctx.accounts.config_account;
+    let clock = Clock::get()?;
     
-    // Payment processed via Stripe (off-chain)
-    // In production: Call Stripe API to process payment
-    // After successful payment, AC balance updated in database
+    // On-chain payment (otherwise Stripe, off-chain, attested by the payment oracle)
+    if ctx.accounts.buyer.is_none() {
+        let attestation = attestation.as_ref().ok_or(GameError::InvalidPaymentAttestation)?;
+        attestation.verify(
+            &user_id,
+            PaymentProduct::AiCredits,
+            ac_amount,
+            &ctx.accounts.signer_registry,
+            &ctx.accounts.instructions,
+            &clock,
+        )?;
+        let receipt = ctx.accounts.payment_receipt.as_mut().ok_or(GameError::InvalidPaymentAttestation)?;
+        receipt.payment_intent_hash = attestation.payment_intent_hash;
+        receipt.user_id[..user_id_bytes.len()].copy_from_slice(user_id_bytes);
+        receipt.product = PaymentProduct::AiCredits as u8;
+        receipt.amount = ac_amount;
+        receipt.attestor = attestation.attestor;
+        receipt.created_at = clock.unix_timestamp;
+        telemetry!("AI credits purchased: {} AC via Stripe", ac_amount);
+    }
+    if let Some(buyer) = ctx.accounts.buyer.as_ref() {
+        let treasury = ctx.accounts.treasury.as_ref().ok_or(GameError::InvalidPayload)?;
+        require!(
+            config.ac_treasury != Pubkey::default() && treasury.key() == config.ac_treasury,
+            GameError::InvalidPayload
+        );
+        let cost_lamports = cost_lamports(config, ctx.accounts.price_feed.as_ref(), ac_amount, &clock)?;
+        require!(cost_lamports > 0, GameError::InvalidPayload);
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: buyer.to_account_info(),
+                    to: treasury.to_account_info(),
+                },
+            ),
+            cost_lamports,
+        )?;
+        telemetry!("AI credits purchased: {} AC for {} lamports", ac_amount, cost_lamports);
+    }
     
     // Update stats (AC balance updated in database, not on-chain)
     // This instruction just records the purchase for tracking
-    
-    msg!("AI credits purchased: {} AC", ac_amount);
     Ok(())
 }
 
+/// Lamport cost of `ac_amount` AC for an on-chain payment. Stripe purchases are already paid,
+/// so the price feed is never read for them (a stale or halted feed can't block recording).
+fn cost_lamports(
+    config: &ConfigAccount,
+    price_feed: Option<&UncheckedAccount>,
+    ac_amount: u64,
+    clock: &Clock,
+) -> Result<u64> {
+    if !config.has_price_feed() {
+        return Ok(config.ac_price_lamports
+            .checked_mul(ac_amount)
+            .ok_or(GameError::Overflow)?);
+    }
+    
+    // Security: Only the configured oracle account is trusted
+    let feed = price_feed.ok_or(GameError::InvalidPriceFeed)?;
+    require!(
+        feed.key() == config.sol_usd_price_feed,
+        GameError::InvalidPriceFeed
+    );
+    let price = OraclePrice::from_pyth_account(&feed.try_borrow_data()?)?;
+    price.check(
+        clock.unix_timestamp,
+        config.max_price_staleness(),
+        config.max_price_confidence_bps,
+    )?;
+    
+    let ac_price_usd = config.get_ac_price_usd();
+    require!(
+        ac_price_usd.is_finite() && ac_price_usd > 0.0,
+        GameError::InvalidPayload
+    );
+    let usd_micros = ((ac_price_usd * 1_000_000.0).round() as u128)
+        .checked_mul(ac_amount as u128)
+        .ok_or(GameError::Overflow)?;
+    price.lamports_for_usd_micros(usd_micros)
+}
+
 #[derive(Accounts)]
-#[instruction(user_id: String)]
+#[instruction(user_id: String, ac_amount: u64, op_nonce: u64, attestation: Option<PaymentAttestation>)]
 pub struct PurchaseAICredits<'info> {
     #[account(
         mut,
@@ -41,6 +131,45 @@ pub struct PurchaseAICredits<'info> {
     )]
     pub user_account: Account<'info, UserAccount>,
     
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    /// CHECK: Pyth SOL/USD price account, checked against ConfigAccount.sol_usd_price_feed
+    pub price_feed: Option<UncheckedAccount<'info>>,
+    
+    /// Pays for the credits on-chain (omit for Stripe purchases)
+    #[account(mut)]
+    pub buyer: Option<Signer<'info>>,
+    
+    /// CHECK: Checked against ConfigAccount.ac_treasury
+    #[account(mut)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+    
+    /// Redeemed Stripe receipt (Stripe purchases only); fails to init if already redeemed
+    #[account(
+        init,
+        payer = payer,
+        space = PaymentReceipt::MAX_SIZE,
+        seeds = [b"payment_receipt", payment::receipt_seed(&attestation)],
+        bump
+    )]
+    pub payment_receipt: Option<Account<'info, PaymentReceipt>>,
+    
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    /// CHECK: Instructions sysvar (holds the preceding Ed25519 instruction)
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
     pub system_program: Program<'info, System>,
 }
-
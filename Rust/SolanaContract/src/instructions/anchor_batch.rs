@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::BatchAnchor;
+use crate::state::{BatchAnchor, BatchHead, BatchLink};
 use crate::error::GameError;
 
 pub fn handler(
@@ -9,6 +9,7 @@ pub fn handler(
     count: u64,
     first_match_id: String,
     last_match_id: String,
+    link: BatchLink, // Previous root, sequence and covered period
 ) -> Result<()> {
     let batch_anchor = &mut ctx.accounts.batch_anchor;
     let clock = Clock::get()?;
@@ -51,6 +52,11 @@ pub fn handler(
     let mut last_match_array = [0u8; 36];
     last_match_array[..36].copy_from_slice(&last_match_bytes[..36.min(last_match_bytes.len())]);
 
+    // Integrity: Chain onto the authority's previous batch, in sequence and time order
+    let batch_head = &mut ctx.accounts.batch_head;
+    batch_head.authority = ctx.accounts.authority.key();
+    batch_head.extend(&link, merkle_root, clock.unix_timestamp)?;
+
     // Initialize batch anchor
     batch_anchor.batch_id = batch_id_array;
     batch_anchor.merkle_root = merkle_root;
@@ -59,8 +65,12 @@ pub fn handler(
     batch_anchor.last_match_id = last_match_array;
     batch_anchor.timestamp = clock.unix_timestamp;
     batch_anchor.authority = ctx.accounts.authority.key();
+    batch_anchor.previous_batch_root = link.previous_batch_root;
+    batch_anchor.sequence = link.sequence;
+    batch_anchor.period_start = link.period_start;
+    batch_anchor.period_end = link.period_end;
 
-    msg!("Batch anchored: {} with {} matches, merkle root: {:?}", 
+    telemetry!("Batch anchored: {} with {} matches, merkle root: {:?}", 
          batch_id, count, merkle_root);
     Ok(())
 }
@@ -77,6 +87,15 @@ pub struct AnchorBatch<'info> {
     )]
     pub batch_anchor: Account<'info, BatchAnchor>,
     
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = BatchHead::MAX_SIZE,
+        seeds = [b"batch_head", authority.key().as_ref()],
+        bump
+    )]
+    pub batch_head: Account<'info, BatchHead>,
+    
     #[account(mut)]
     pub authority: Signer<'info>,
     
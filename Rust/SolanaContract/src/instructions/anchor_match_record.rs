@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::state::Match;
+use crate::state::{Match, CoordinatorSet, match_id_seed};
+use crate::events::MatchRecordAnchored;
 use crate::error::GameError;
 
 pub fn handler(
@@ -7,6 +8,8 @@ pub fn handler(
     match_id: String,
     match_hash: [u8; 32],
     hot_url: Option<String>,
+    cold_uri: Option<String>, // Arweave tx id / IPFS CID (write-once)
+    cold_content_hash: Option<[u8; 32]>, // SHA-256 of the archived record (required with cold_uri)
 ) -> Result<()> {
     let match_account = &mut ctx.accounts.match_account;
 
@@ -15,7 +18,7 @@ pub fn handler(
     require!(
         match_id_bytes.len() == 36 && 
         match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
-        GameError::InvalidPayload
+        GameError::MatchIdMismatch
     );
 
     // Security: Validate authority is signer and matches
@@ -23,8 +26,12 @@ pub fn handler(
         ctx.accounts.authority.is_signer,
         GameError::Unauthorized
     );
+    // Failover: any active coordinator in the CoordinatorSet may act for the match authority
+    let is_active_coordinator = ctx.accounts.coordinator_set
+        .as_ref()
+        .is_some_and(|set| set.is_active(&ctx.accounts.authority.key()));
     require!(
-        ctx.accounts.authority.key() == match_account.authority,
+        ctx.accounts.authority.key() == match_account.authority || is_active_coordinator,
         GameError::Unauthorized
     );
 
@@ -34,10 +41,10 @@ pub fn handler(
         GameError::InvalidPhase
     );
 
-    // Security: Validate match_hash is not all zeros
+    // Security: Only the canonical replay hash of the recorded moves can be anchored
     require!(
-        match_hash.iter().any(|&b| b != 0),
-        GameError::InvalidPayload
+        match_hash == match_account.replay_match_hash(),
+        GameError::MatchHashMismatch
     );
 
     // Update match hash and hot_url
@@ -47,7 +54,7 @@ pub fn handler(
     if let Some(url) = hot_url {
         require!(
             url.len() <= 200,
-            GameError::InvalidPayload
+            GameError::UrlTooLong
         );
         let url_bytes = url.as_bytes();
         let mut url_array = [0u8; 200];
@@ -56,7 +63,31 @@ pub fn handler(
         match_account.hot_url = url_array;
     }
 
-    msg!("Match record anchored: {} with hash {:?}", match_id, match_hash);
+    // Security: The permanent archive location is write-once and pinned to a content hash
+    if let Some(uri) = cold_uri {
+        require!(!match_account.has_cold_uri(), GameError::ColdUriAlreadySet);
+        require!(
+            !uri.is_empty() && uri.len() <= 64,
+            GameError::InvalidPayload
+        );
+        let content_hash = cold_content_hash.ok_or(GameError::InvalidPayload)?;
+        require!(
+            content_hash.iter().any(|&b| b != 0),
+            GameError::InvalidPayload
+        );
+        let mut uri_array = [0u8; 64];
+        uri_array[..uri.len()].copy_from_slice(uri.as_bytes());
+        match_account.cold_uri = uri_array;
+        match_account.cold_content_hash = content_hash;
+    } else {
+        require!(cold_content_hash.is_none(), GameError::InvalidPayload);
+    }
+
+    emit!(MatchRecordAnchored {
+        match_account: match_account.key(),
+        match_hash,
+        cold_content_hash: match_account.cold_content_hash,
+    });
     Ok(())
 }
 
@@ -65,11 +96,18 @@ pub fn handler(
 pub struct AnchorMatchRecord<'info> {
     #[account(
         mut,
-        seeds = [b"match", match_id.as_bytes()],
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
         bump
     )]
     pub match_account: Account<'info, Match>,
     
+    /// Required only when the signer is a failover coordinator rather than the match authority
+    #[account(
+        seeds = [b"coordinator_set"],
+        bump
+    )]
+    pub coordinator_set: Option<Box<Account<'info, CoordinatorSet>>>,
+    
     pub authority: Signer<'info>,
 }
 
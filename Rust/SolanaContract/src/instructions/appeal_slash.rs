@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::state::ValidatorReputation;
+use crate::error::GameError;
+
+/// Appeals the validator's pending slash within SLASH_APPEAL_WINDOW_SECONDS of it.
+/// The validator locks an appeal bond (APPEAL_BOND_BPS of the escrowed slash, in lamports)
+/// on its reputation account; the slashed stake stays in escrow until resolve_slash_appeal,
+/// which refunds the bond if the slash is overturned and forfeits it otherwise.
+pub fn handler(ctx: Context<AppealSlash>) -> Result<()> {
+    let clock = Clock::get()?;
+    let validator_account = &mut ctx.accounts.validator_reputation;
+
+    // Security: One appeal per slash
+    require!(
+        validator_account.has_pending_slash()
+            && validator_account.appeal_status == ValidatorReputation::APPEAL_NONE,
+        GameError::NoAppealableSlash
+    );
+    require!(
+        clock.unix_timestamp < validator_account.appeal_deadline(),
+        GameError::AppealWindowClosed
+    );
+
+    // Lock the bond on the reputation account
+    let bond = validator_account.appeal_bond_for_slash();
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.validator.to_account_info(),
+                to: validator_account.to_account_info(),
+            },
+        ),
+        bond,
+    )?;
+    validator_account.appeal_bond = bond;
+    validator_account.appeal_status = ValidatorReputation::APPEAL_PENDING;
+
+    telemetry!("Validator {} appealed a slash of {} (bond {} lamports)",
+         validator_account.validator, validator_account.slashed_escrow, bond);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AppealSlash<'info> {
+    #[account(
+        mut,
+        seeds = [b"validator", validator.key().as_ref()],
+        bump
+    )]
+    pub validator_reputation: Account<'info, ValidatorReputation>,
+    
+    #[account(mut)]
+    pub validator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, Move, AiMoveAttestation, match_id_seed};
+use crate::error::GameError;
+use crate::events::AiMoveAttested;
+
+/// Attaches an AiMoveAttestation to a move an AI made, either on behalf of a user or as an
+/// AI opponent, so post-hoc audits can check which model produced it and what the call cost.
+/// Match authority only, once per move, while the Move account still exists (before
+/// compact_match_moves). The attestation outlives compaction.
+pub fn handler(
+    ctx: Context<AttestAiMove>,
+    match_id: String,
+    move_index: u32,
+    model_id: u8,  // Model ID (0-9, corresponds to ai_model_costs array index)
+    prompt_hash: [u8; 32],
+    response_hash: [u8; 32],
+    consumption_id: [u8; 32],  // SHA-256 of the off-chain consumption record id (all zeros = not billed)
+) -> Result<()> {
+    let match_account = &ctx.accounts.match_account;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate authority is match authority
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Validate model_id
+    require!(
+        model_id < 10,
+        GameError::InvalidPayload
+    );
+    
+    let move_account = &ctx.accounts.move_account;
+    let attestation = &mut ctx.accounts.attestation;
+    attestation.match_id = match_account.match_id;
+    attestation.move_index = move_index;
+    attestation.player = move_account.player;
+    attestation.model_id = model_id;
+    attestation.prompt_hash = prompt_hash;
+    attestation.response_hash = response_hash;
+    attestation.consumption_id = consumption_id;
+    attestation.authority = ctx.accounts.authority.key();
+    attestation.created_at = Clock::get()?.unix_timestamp;
+    
+    emit!(AiMoveAttested {
+        match_account: match_account.key(),
+        move_index,
+        player: move_account.player,
+        model_id,
+        consumption_id,
+    });
+    
+    telemetry!("AI move attested for match {}: move {} by model {}", match_id, move_index, model_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String, move_index: u32)]
+pub struct AttestAiMove<'info> {
+    #[account(
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    #[account(
+        seeds = [b"move", match_id_seed(match_id.as_bytes()), move_index.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub move_account: Account<'info, Move>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = AiMoveAttestation::MAX_SIZE,
+        seeds = [b"ai_attestation", match_id_seed(match_id.as_bytes()), move_index.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub attestation: Account<'info, AiMoveAttestation>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -89,3 +89,88 @@ pub fn calculate_scores_from_moves(
     Ok(scores)
 }
 
+
+/**
+ * Calculates round scores from on-chain match state only.
+ * Used by end_match and start_next_round, which cannot query Move accounts.
+ * Full detailed scoring (sequences, bonuses, penalties) is done off-chain in MatchCoordinator.
+ */
+pub fn calculate_scores_from_state(match_account: &Match) -> [i32; 10] {
+    let mut scores: [i32; 10] = [0; 10];
+    
+    // Count declarations and activity per player
+    let mut declarations_count = 0u32;
+    let total_activity = match_account.move_count;
+    
+    // Calculate scores for each player based on game state
+    // Per CLAIM game rules: declared players get positive scores, undeclared get penalties
+    for (i, score) in scores.iter_mut().enumerate().take(match_account.player_count as usize) {
+        if match_account.has_declared_suit(i) {
+            declarations_count += 1;
+            
+            // Declared players: positive scoring based on CLAIM game rules
+            // Base score: 20 points for declaring a suit
+            let base_score = 20i32;
+            
+            // Bonus: Activity points (more moves = more engagement)
+            // Normalize by player count to avoid bias
+            let avg_moves_per_player = if match_account.player_count > 0 {
+                total_activity / match_account.player_count as u32
+            } else {
+                0
+            };
+            let activity_score = avg_moves_per_player as i32;
+            
+            // Bonus: Early declaration bonus (simplified - first declarer gets bonus)
+            // In full implementation, would track declaration order from Move accounts
+            let declaration_bonus = if declarations_count == 1 { 5i32 } else { 0i32 };
+            
+            *score = base_score + activity_score + declaration_bonus;
+        } else {
+            // Undeclared players: penalty for not declaring
+            // Penalty increases with game length (more opportunities missed)
+            let penalty_per_round = 2i32;
+            let rounds = if match_account.player_count > 0 {
+                (total_activity / match_account.player_count as u32).max(1) as i32
+            } else {
+                1
+            };
+            *score = -(penalty_per_round * rounds);
+        }
+    }
+    
+    // Normalize scores to ensure they're reasonable (prevent overflow)
+    for score in &mut scores {
+        *score = (*score).clamp(-100, 200); // Reasonable bounds
+    }
+    
+    // Partner games: every team member is credited with the team's combined score
+    if match_account.has_teams() {
+        scores = apply_team_scoring(match_account, &scores);
+    }
+    
+    scores
+}
+
+/**
+ * Replaces each teamed player's score with the sum of their team's scores.
+ * Players without a team (team 0) keep their individual score.
+ */
+pub fn apply_team_scoring(match_account: &Match, scores: &[i32; 10]) -> [i32; 10] {
+    let mut team_totals = [0i32; Match::MAX_TEAMS as usize + 1];
+    for (i, score) in scores.iter().enumerate().take(match_account.player_count as usize) {
+        let team = match_account.get_team(i) as usize;
+        if team != 0 && team < team_totals.len() {
+            team_totals[team] = team_totals[team].saturating_add(*score);
+        }
+    }
+    
+    let mut team_scores = *scores;
+    for (i, score) in team_scores.iter_mut().enumerate().take(match_account.player_count as usize) {
+        let team = match_account.get_team(i) as usize;
+        if team != 0 && team < team_totals.len() {
+            *score = team_totals[team];
+        }
+    }
+    team_scores
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::{UserAccount, ConfigAccount};
+use crate::error::GameError;
+
+/// Cancels a pro subscription at the end of the current billing period.
+/// Sets the no-renew flag only; benefits remain until subscription_expiry.
+/// Authority-gated (ConfigAccount.authority).
+/// Note: Stripe subscription is cancelled off-chain. This instruction only records the state.
+/// Note: String params converted to fixed arrays immediately for performance.
+pub fn handler(ctx: Context<CancelSubscription>, user_id: String, op_nonce: u64) -> Result<()> {
+    // Convert String to fixed-size array immediately (optimization)
+    let user_id_bytes = user_id.as_bytes();
+    require!(
+        user_id_bytes.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    
+    let user_account = &mut ctx.accounts.user_account;
+    
+    // Idempotency: a retried operation can't be applied twice
+    user_account.use_op_nonce(op_nonce)?;
+    
+    let clock = Clock::get()?;
+    
+    // Apply any downgrade whose effective date has already passed
+    user_account.apply_pending_subscription_changes(&clock);
+    
+    require!(
+        user_account.has_active_subscription(&clock),
+        GameError::SubscriptionNotActive
+    );
+    require!(
+        !user_account.cancel_at_period_end(),
+        GameError::SubscriptionAlreadyCancelled
+    );
+    
+    // Keep benefits until expiry, but do not renew.
+    // A pending downgrade is moot once the subscription will not renew.
+    user_account.set_cancel_at_period_end(true);
+    user_account.clear_pending_downgrade();
+    
+    telemetry!("Subscription cancelled: tier={}, benefits until {}", 
+         user_account.subscription_tier, user_account.subscription_expiry);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(user_id: String)]
+pub struct CancelSubscription<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    // Security: Only the platform authority records Stripe-side subscription changes
+    #[account(constraint = authority.key() == config_account.authority @ GameError::Unauthorized)]
+    pub authority: Signer<'info>,
+}
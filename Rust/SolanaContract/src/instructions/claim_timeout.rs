@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, EndReason, match_id_seed};
+use crate::events::MatchEnded;
+use crate::error::GameError;
+
+/// Forfeits the player on turn once their turn deadline has passed, ending the match.
+/// The deadline is checked against the on-chain clock, so anyone may submit this
+/// (typically an opponent or the coordinator) - no authority signature is needed.
+pub fn handler(ctx: Context<ClaimTimeout>, match_id: String) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Only a match in play can time out
+    require!(
+        match_account.phase == 1 && !match_account.is_ended(),
+        GameError::InvalidPhase
+    );
+
+    // Security: The clock is frozen while paused
+    match_account.settle_expired_pause(clock.unix_timestamp);
+    require!(
+        !match_account.is_paused(clock.unix_timestamp),
+        GameError::MatchPaused
+    );
+
+    require!(
+        match_account.current_player_out_of_time(clock.unix_timestamp),
+        GameError::TimeBankNotExhausted
+    );
+
+    let player_index = match_account.current_player;
+    match_account.time_bank[player_index as usize] = 0;
+    match_account.end_with_outcome(EndReason::Timeout, player_index, clock.unix_timestamp);
+
+    emit!(MatchEnded::from_match(match_account.key(), match_account));
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct ClaimTimeout<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    pub claimer: Signer<'info>,
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::Match;
+use crate::state::{Match, match_id_seed};
 use crate::error::GameError;
 
 /**
@@ -8,6 +8,7 @@ use crate::error::GameError;
  * 
  * Only the match authority or the account closer can close the account.
  * The account must be in Ended phase (phase 2).
+ * Rent goes back to the wallet that paid it (Match.rent_payer), not to the closer.
  */
 pub fn handler(
     ctx: Context<CloseMatchAccount>,
@@ -20,7 +21,7 @@ pub fn handler(
     require!(
         match_id_bytes.len() == 36 && 
         match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
-        GameError::InvalidPayload
+        GameError::MatchIdMismatch
     );
     
     // Security: Must be in Ended phase
@@ -40,24 +41,10 @@ pub fn handler(
         GameError::Unauthorized
     );
     
-    // Calculate rent to refund
-    let rent = Rent::get()?;
-    let account_info = ctx.accounts.match_account.to_account_info();
-    let lamports = account_info.lamports();
-    let rent_exempt_minimum = rent.minimum_balance(Match::MAX_SIZE);
-    
-    // Refund excess rent to closer
-    if lamports > rent_exempt_minimum {
-        let refund = lamports
-            .checked_sub(rent_exempt_minimum)
-            .ok_or(GameError::InsufficientFunds)?;
-        
-        **account_info.try_borrow_mut_lamports()? -= refund;
-        **ctx.accounts.closer.to_account_info().try_borrow_mut_lamports()? += refund;
-        
-        msg!("Closed match account {} and refunded {} lamports to {}", 
-             match_id, refund, ctx.accounts.closer.key());
-    }
+    // All lamports (rent plus any excess) go to the recorded payer via `close`
+    let refund = ctx.accounts.match_account.to_account_info().lamports();
+    telemetry!("Closed match account {} and refunded {} lamports to {}", 
+         match_id, refund, ctx.accounts.rent_payer.key());
     
     Ok(())
 }
@@ -67,14 +54,17 @@ pub fn handler(
 pub struct CloseMatchAccount<'info> {
     #[account(
         mut,
-        seeds = [b"match", match_id.as_bytes()],
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
         bump,
-        close = closer // Close account and send rent to closer
+        close = rent_payer // Close account and send rent to whoever paid it
     )]
     pub match_account: Account<'info, Match>,
     
     /// CHECK: Closer can be authority or any account (for rent reclamation)
-    #[account(mut)]
     pub closer: Signer<'info>,
+    
+    /// CHECK: Must be the wallet recorded as the match's rent payer
+    #[account(mut, address = match_account.rent_payer @ GameError::RentPayerMismatch)]
+    pub rent_payer: UncheckedAccount<'info>,
 }
 
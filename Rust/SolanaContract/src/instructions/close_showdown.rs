@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, match_id_seed};
+use crate::events::ShowdownClosed;
+use crate::error::GameError;
+
+/// Ends a showdown round once its rebuttal window has closed.
+/// The deadline is checked against the on-chain clock, so anyone may submit this
+/// (typically the showdown caller or the coordinator) - no authority signature is needed.
+/// Scores are computed as usual by end_match / start_next_round.
+pub fn handler(ctx: Context<CloseShowdown>, match_id: String) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Only a match in showdown can be closed
+    require!(
+        match_account.phase == 3,
+        GameError::InvalidPhase
+    );
+
+    require!(
+        match_account.showdown_closed(clock.unix_timestamp),
+        GameError::ShowdownInProgress
+    );
+
+    match_account.finish_showdown(clock.unix_timestamp);
+
+    emit!(ShowdownClosed {
+        match_account: match_account.key(),
+        showdown_caller: match_account.showdown_caller,
+        rebuttal_responses: match_account.rebuttal_responses,
+        ended_at: match_account.ended_at,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct CloseShowdown<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    pub closer: Signer<'info>,
+}
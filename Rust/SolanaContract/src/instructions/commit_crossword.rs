@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, CrosswordPuzzle, GameType, match_id_seed};
+use crate::error::GameError;
+
+/// Creates the CrosswordPuzzle for a Crosswords match and commits the Merkle root of the
+/// clue answers (see CrosswordPuzzle).
+/// Must be called by the match authority during the Dealing phase.
+pub fn handler(
+    ctx: Context<CommitCrossword>,
+    match_id: String,
+    answer_root: [u8; 32],
+    clue_count: u8,
+) -> Result<()> {
+    let match_account = &ctx.accounts.match_account;
+    let puzzle = &mut ctx.accounts.crossword_puzzle;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Only the match authority (coordinator)
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Security: Crosswords matches only, committed before play starts
+    require!(
+        match_account.game_type == GameType::Crosswords as u8,
+        GameError::WrongGameType
+    );
+    require!(
+        match_account.phase == 0,
+        GameError::InvalidPhase
+    );
+
+    puzzle.match_id = match_account.match_id;
+    puzzle.commit(answer_root, clue_count)?;
+    puzzle.authority = ctx.accounts.authority.key();
+    puzzle.committed_at = clock.unix_timestamp;
+
+    telemetry!("Crossword committed for match {}: {} clues", match_id, clue_count);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct CommitCrossword<'info> {
+    #[account(
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Box<Account<'info, Match>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = CrosswordPuzzle::MAX_SIZE,
+        seeds = [b"crossword", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub crossword_puzzle: Box<Account<'info, CrosswordPuzzle>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
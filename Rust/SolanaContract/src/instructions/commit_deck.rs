@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, DeckCommitment, match_id_seed};
+use crate::error::GameError;
+
+/// Commits the Merkle root of the shuffled deck for a match.
+/// Must be called by the coordinator during the Dealing phase, before any cards are
+/// dealt, so hands and floor cards can later be proven against the shuffle.
+pub fn handler(
+    ctx: Context<CommitDeck>,
+    match_id: String,
+    deck_root: [u8; 32],
+    deck_size: u8,
+) -> Result<()> {
+    let match_account = &ctx.accounts.match_account;
+    let deck_commitment = &mut ctx.accounts.deck_commitment;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate authority is signer and matches
+    require!(
+        ctx.accounts.authority.is_signer,
+        GameError::Unauthorized
+    );
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Security: Deck must be committed before play starts
+    require!(
+        match_account.phase == 0,
+        GameError::InvalidPhase
+    );
+
+    // Security: Validate root and deck size
+    require!(
+        deck_root.iter().any(|&b| b != 0),
+        GameError::InvalidPayload
+    );
+    require!(
+        deck_size > 0,
+        GameError::InvalidPayload
+    );
+
+    deck_commitment.match_id = match_account.match_id;
+    deck_commitment.deck_root = deck_root;
+    deck_commitment.deck_size = deck_size;
+    deck_commitment.seed = match_account.seed;
+    deck_commitment.authority = ctx.accounts.authority.key();
+    deck_commitment.committed_at = clock.unix_timestamp;
+
+    telemetry!("Deck committed for match {}: {} cards", match_id, deck_size);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct CommitDeck<'info> {
+    #[account(
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = DeckCommitment::MAX_SIZE,
+        seeds = [b"deck_commitment", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub deck_commitment: Account<'info, DeckCommitment>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
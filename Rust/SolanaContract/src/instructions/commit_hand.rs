@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::state::Match;
+use crate::state::{Match, match_id_seed};
+use crate::events::HandCommitted;
 use crate::error::GameError;
 
 /// Commit a player's hand hash during the Dealing phase.
@@ -20,7 +21,7 @@ pub fn handler(
     require!(
         match_id_bytes.len() == 36 && 
         match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
-        GameError::InvalidPayload
+        GameError::MatchIdMismatch
     );
 
     // Security: Validate player is signer
@@ -39,7 +40,7 @@ pub fn handler(
     let user_id_bytes = user_id.as_bytes();
     require!(
         user_id_bytes.len() <= 64,
-        GameError::InvalidPayload
+        GameError::UserIdTooLong
     );
     let mut user_id_array = [0u8; 64];
     let copy_len = user_id_bytes.len().min(64);
@@ -52,7 +53,7 @@ pub fn handler(
     // Security: Validate hand hash is not all zeros (empty hash)
     require!(
         !hand_hash.iter().all(|&b| b == 0),
-        GameError::InvalidPayload
+        GameError::EmptyHash
     );
     
     // Per critique Issue #1: Validate hand size is reasonable
@@ -62,13 +63,26 @@ pub fn handler(
         GameError::InvalidPayload
     );
 
+    // Variant: a fixed starting hand size (RuleParams)
+    let required_hand_size = match_account.rule_params().hand_size;
+    require!(
+        required_hand_size == 0 || hand_size == required_hand_size,
+        GameError::HandSizeMismatch
+    );
+
     // Set committed hand hash for this player
     match_account.set_committed_hand_hash(player_index, hand_hash);
     
     // Per critique Issue #1: Set hand size for validation
     match_account.set_hand_size(player_index, hand_size);
+    match_account.cards_dealt_total = match_account.dealt_card_count(); // A re-commit replaces the hand
 
-    msg!("Player {} committed hand hash for match {}", user_id, match_id);
+    emit!(HandCommitted {
+        match_account: match_account.key(),
+        player_index: player_index as u8,
+        hand_hash,
+        hand_size,
+    });
     Ok(())
 }
 
@@ -77,7 +91,7 @@ pub fn handler(
 pub struct CommitHand<'info> {
     #[account(
         mut,
-        seeds = [b"match", match_id.as_bytes()],
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
         bump
     )]
     pub match_account: Account<'info, Match>,
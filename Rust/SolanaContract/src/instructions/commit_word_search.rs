@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, WordSearchPuzzle, GameType, match_id_seed};
+use crate::error::GameError;
+
+/// Creates the WordSearchPuzzle for a WordSearch match and commits the puzzle: the grid hash
+/// and the Merkle root of the hidden word list (see WordSearchPuzzle).
+/// Must be called by the match authority during the Dealing phase, before anyone sees the grid.
+pub fn handler(
+    ctx: Context<CommitWordSearch>,
+    match_id: String,
+    grid_hash: [u8; 32],
+    word_root: [u8; 32],
+    rows: u8,
+    cols: u8,
+    word_count: u8,
+) -> Result<()> {
+    let match_account = &ctx.accounts.match_account;
+    let puzzle = &mut ctx.accounts.word_search_puzzle;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Only the match authority (coordinator)
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Security: WordSearch matches only, committed before play starts
+    require!(
+        match_account.game_type == GameType::WordSearch as u8,
+        GameError::WrongGameType
+    );
+    require!(
+        match_account.phase == 0,
+        GameError::InvalidPhase
+    );
+
+    puzzle.match_id = match_account.match_id;
+    puzzle.commit(grid_hash, word_root, rows, cols, word_count)?;
+    puzzle.authority = ctx.accounts.authority.key();
+    puzzle.committed_at = clock.unix_timestamp;
+
+    telemetry!("Word search committed for match {}: {}x{} grid, {} words", match_id, rows, cols, word_count);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct CommitWordSearch<'info> {
+    #[account(
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Box<Account<'info, Match>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = WordSearchPuzzle::MAX_SIZE,
+        seeds = [b"word_search", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub word_search_puzzle: Box<Account<'info, WordSearchPuzzle>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, Move, match_id_seed};
+use crate::error::GameError;
+use crate::replay;
+
+/// Closes the Move accounts of a finalized match once its record is anchored (match_hash set),
+/// refunding each account's rent to the wallet that paid it (Move.rent_payer). The transcript
+/// stays verifiable from the archive: the Match keeps move_count and match_hash.
+/// Remaining accounts: (Move account, rent payer) pairs, both writable, for moves
+/// start_index, start_index + 1, ... in order. start_index must be Match.compacted_moves, so
+/// segments run in order and compaction_hash chains the transcript across them; the segment
+/// that reaches move_count must reproduce transcript_hash and match_hash.
+/// Permissionless: rent only ever goes back to its payers.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CompactMatchMoves<'info>>,
+    match_id: String,
+    start_index: u32,
+) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Only finalized matches whose record is anchored drop their Move accounts
+    require!(
+        match_account.phase == 2 && match_account.has_match_hash(),
+        GameError::InvalidPhase
+    );
+
+    let pairs = ctx.remaining_accounts;
+    require!(
+        !pairs.is_empty() && pairs.len() & 1 == 0,
+        GameError::InvalidPayload
+    );
+    require!(start_index == match_account.compacted_moves, GameError::InvalidPayload);
+    let end_index = start_index
+        .checked_add((pairs.len() / 2) as u32)
+        .ok_or(GameError::Overflow)?;
+    require!(end_index <= match_account.move_count, GameError::InvalidPayload);
+
+    // Segments are contiguous: start from the header or continue the compacted prefix
+    let mut hash = if start_index == 0 {
+        replay::header_hash(&replay::MatchHeader::from_match(match_account))
+    } else {
+        match_account.compaction_hash
+    };
+
+    let mut refunded = 0u64;
+    for (offset, pair) in pairs.chunks(2).enumerate() {
+        let move_index = start_index + offset as u32;
+        let (move_info, payer_info) = (&pair[0], &pair[1]);
+
+        // Security: Program-owned Move of this match at this index (owner + discriminator checked)
+        let move_account = Account::<Move>::try_from(move_info)?;
+        require!(
+            move_account.match_id == match_account.match_id && move_account.move_index == move_index,
+            GameError::InvalidMatchAccount
+        );
+        require!(
+            payer_info.key() == move_account.rent_payer,
+            GameError::RentPayerMismatch
+        );
+
+        hash = replay::chain_move(
+            &hash,
+            move_index,
+            &move_account.player,
+            move_account.action_type,
+            move_account.get_payload_slice(),
+        );
+        refunded += move_info.lamports();
+        move_account.close(payer_info.clone())?;
+    }
+
+    // Security: The compacted moves are exactly the anchored transcript
+    if end_index == match_account.move_count {
+        require!(
+            hash == match_account.transcript_hash
+                && replay::finalize(&hash, end_index) == match_account.match_hash,
+            GameError::MatchHashMismatch
+        );
+    }
+    match_account.compacted_moves = end_index;
+    match_account.compaction_hash = hash;
+
+    telemetry!(
+        "Compacted moves {}..{} of {} for match {} ({} lamports refunded)",
+        start_index,
+        end_index,
+        match_account.move_count,
+        match_id,
+        refunded
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct CompactMatchMoves<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Box<Account<'info, Match>>,
+    
+    pub cranker: Signer<'info>,
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::state::{Guild, UserAccount, ConfigAccount};
+use crate::error::GameError;
+
+/// Creates a guild owned by `owner_user_id`, who becomes its first member.
+/// Authority-gated (ConfigAccount.authority): users are identified by Firebase UID, so the
+/// backend submits guild membership changes on their behalf.
+pub fn handler(ctx: Context<CreateGuild>, name: String, owner_user_id: String) -> Result<()> {
+    require!(
+        !name.is_empty() && name.len() <= 32,
+        GameError::InvalidPayload
+    );
+    require!(
+        !owner_user_id.is_empty() && owner_user_id.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    
+    let config = &ctx.accounts.config_account;
+    let clock = Clock::get()?;
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        GameError::Unauthorized
+    );
+    
+    let owner = &mut ctx.accounts.owner_user_account;
+    require!(!owner.in_guild(), GameError::AlreadyInGuild);
+    
+    let mut name_array = [0u8; 32];
+    name_array[..name.len()].copy_from_slice(name.as_bytes());
+    let season_id = (clock.unix_timestamp / 604800) as u64; // 7 days in seconds
+    
+    let guild = &mut ctx.accounts.guild;
+    guild.name = name_array;
+    guild.owner = owner.user_id;
+    guild.member_count = 0;
+    guild.start_season(season_id);
+    guild.add_member(owner.user_id, owner.season_score_for(season_id));
+    guild.created_at = clock.unix_timestamp;
+    
+    owner.guild = name_array;
+    
+    telemetry!("Guild created: {} (owner {})", name, owner_user_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(name: String, owner_user_id: String)]
+pub struct CreateGuild<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = Guild::MAX_SIZE,
+        seeds = [b"guild", name.as_bytes()],
+        bump
+    )]
+    pub guild: Box<Account<'info, Guild>>,
+    
+    #[account(
+        mut,
+        seeds = [b"user_account", owner_user_id.as_bytes()],
+        bump
+    )]
+    pub owner_user_account: Account<'info, UserAccount>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
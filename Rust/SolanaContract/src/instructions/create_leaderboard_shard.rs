@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameLeaderboard, LeaderboardIndex, SignerRegistry, SignerRole, Guild};
+use crate::error::GameError;
+
+pub fn handler(
+    ctx: Context<CreateLeaderboardShard>,
+    game_type: u8,
+    season_id: u64,
+    shard_index: u16,
+) -> Result<()> {
+    let index = &mut ctx.accounts.leaderboard_index;
+    let shard = &mut ctx.accounts.leaderboard_shard;
+    let clock = Clock::get()?;
+
+    // Security: Only registered coordinators manage leaderboards
+    require!(
+        ctx.accounts.signer_registry.get_role(&ctx.accounts.coordinator.key()) == Some(SignerRole::Coordinator),
+        GameError::Unauthorized
+    );
+
+    // Security: Validate game_type bounds (or the guild leaderboard)
+    require!(
+        game_type <= 7 || game_type == Guild::GUILD_LEADERBOARD_TYPE, // Max game type enum value
+        GameError::InvalidPayload
+    );
+
+    // Security: Season leaderboard is frozen once reward distribution starts
+    require!(!index.rewards_finalized, GameError::SeasonFinalized);
+
+    // Security: Shards are appended in rank order
+    require!(
+        shard_index == index.shard_count && shard_index < LeaderboardIndex::MAX_SHARDS,
+        GameError::InvalidLeaderboardShard
+    );
+
+    if index.shard_count == 0 {
+        index.game_type = game_type;
+        index.season_id = season_id;
+    }
+
+    shard.game_type = game_type;
+    shard.season_id = season_id;
+    shard.shard_index = shard_index;
+    shard.entry_count = 0;
+    shard.last_updated = clock.unix_timestamp;
+
+    index.shard_count += 1;
+    index.refresh_shard(shard);
+    index.last_updated = clock.unix_timestamp;
+
+    telemetry!(
+        "Leaderboard shard {} created: game_type={}, season={} (ranks {}-{})",
+        shard_index,
+        game_type,
+        season_id,
+        shard_index as u32 * 100 + 1,
+        shard_index as u32 * 100 + 100
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(game_type: u8, season_id: u64, shard_index: u16)]
+pub struct CreateLeaderboardShard<'info> {
+    #[account(
+        init_if_needed,
+        payer = coordinator,
+        space = LeaderboardIndex::MAX_SIZE,
+        seeds = [b"leaderboard_index".as_ref(), &[game_type], &season_id.to_le_bytes()],
+        bump
+    )]
+    pub leaderboard_index: Account<'info, LeaderboardIndex>,
+    
+    #[account(
+        init,
+        payer = coordinator,
+        space = GameLeaderboard::MAX_SIZE,
+        seeds = [b"leaderboard".as_ref(), &[game_type], &season_id.to_le_bytes(), &shard_index.to_le_bytes()],
+        bump
+    )]
+    pub leaderboard_shard: Box<Account<'info, GameLeaderboard>>,
+    
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    #[account(mut)]
+    pub coordinator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::state::{Match, GameType};
+use crate::state::{Match, MatchCreator, GameType, GameRegistry, GlobalStats, OpenLobbyIndex, match_id_seed};
+use crate::events::{MatchCreated, MatchListingChanged};
 use crate::error::GameError;
 
 pub fn handler(
@@ -7,100 +8,201 @@ pub fn handler(
     match_id: String,
     game_type: u8,
     seed: u64,
+    rounds_to_play: u8, // Number of rounds (0 or 1 = single-round match)
+    join_secret_hash: Option<[u8; 32]>, // Match::join_secret_hash of the join code for private matches (None = public)
+    player_consent_start: bool, // Let a set_ready quorum start the match without the authority
 ) -> Result<()> {
-    let match_account = &mut ctx.accounts.match_account;
-    let clock = Clock::get()?;
-
-    // Security: Validate match_id length (UUID v4 is exactly 36 chars)
+    // Security: Validate authority is signer
     require!(
-        match_id.len() == 36,
-        GameError::InvalidPayload
+        ctx.accounts.authority.is_signer,
+        GameError::Unauthorized
     );
 
-    // Security: Validate game_type bounds
-    require!(
-        game_type <= 7, // Max game type enum value
-        GameError::InvalidPayload
+    let match_id_array = parse_match_id(&match_id)?;
+
+    let accounts = ctx.accounts;
+    init_match(
+        &mut accounts.match_account,
+        &mut accounts.global_stats,
+        &accounts.game_registry,
+        accounts.lobby_index.as_mut(),
+        accounts.authority.key(),
+        match_id_array,
+        MatchSettings { game_type, seed, rounds_to_play, join_secret_hash, player_consent_start },
+    )
+}
+
+/// Creator-chosen settings of a new match (create_match, create_match_counted and
+/// form_match_from_queue).
+pub(crate) struct MatchSettings {
+    pub game_type: u8,
+    pub seed: u64,
+    pub rounds_to_play: u8,
+    pub join_secret_hash: Option<[u8; 32]>,
+    pub player_consent_start: bool,
+}
+
+/// Initializes a freshly created match account: settings, visibility (listing it if public)
+/// and the game's daily cap. Shared by create_match, create_match_counted and
+/// form_match_from_queue.
+pub(crate) fn init_match(
+    match_account: &mut Account<Match>,
+    global_stats: &mut GlobalStats,
+    game_registry: &GameRegistry,
+    lobby_index: Option<&mut Box<Account<OpenLobbyIndex>>>,
+    authority: Pubkey,
+    match_id_array: [u8; 36],
+    settings: MatchSettings,
+) -> Result<()> {
+    let MatchSettings { game_type, seed, rounds_to_play, join_secret_hash, player_consent_start } = settings;
+    let clock = Clock::get()?;
+
+    // Security: Private match secret hash must not be all zeros (zeros = public)
+    if let Some(hash) = join_secret_hash {
+        require!(
+            hash.iter().any(|&b| b != 0),
+            GameError::InvalidPayload
+        );
+    }
+
+    let game_type_enum = parse_game_type(game_type)?;
+
+    // Initialize match with optimized struct
+    match_account.initialize(
+        match_id_array,
+        game_type_enum,
+        seed,
+        authority,
+        clock.unix_timestamp,
+        rounds_to_play,
     );
+    match_account.join_secret_hash = join_secret_hash.unwrap_or([0u8; 32]); // All zeros = public
+    match_account.set_player_consent_start(player_consent_start);
 
-    // Security: Validate authority is signer
+    // Visibility: private with a join code, public (listed) when the game's open lobby index is
+    // passed, unlisted otherwise
     require!(
-        ctx.accounts.authority.is_signer,
-        GameError::Unauthorized
+        join_secret_hash.is_none() || lobby_index.is_none(),
+        GameError::LobbyIndexMismatch
     );
-
-    let game_type_enum = match game_type {
-        0 => GameType::Claim,
-        1 => GameType::ThreeCardBrag,
-        2 => GameType::Poker,
-        3 => GameType::Bridge,
-        4 => GameType::Rummy,
-        5 => GameType::Scrabble,
-        6 => GameType::WordSearch,
-        7 => GameType::Crosswords,
-        _ => return Err(GameError::InvalidPayload.into()),
+    match_account.visibility = if join_secret_hash.is_some() {
+        Match::VISIBILITY_PRIVATE
+    } else if lobby_index.is_some() {
+        Match::VISIBILITY_PUBLIC
+    } else {
+        Match::VISIBILITY_UNLISTED
     };
+    if let Some(lobby_index) = lobby_index {
+        lobby_index.game_type = game_type;
+        if let Some(evicted) = lobby_index.list(match_id_array, clock.unix_timestamp)? {
+            emit!(MatchListingChanged { game_type, match_id: evicted, listed: false });
+        }
+        emit!(MatchListingChanged { game_type, match_id: match_id_array, listed: true });
+    }
 
-    // Convert String to fixed-size array (null-padded)
-    let match_id_bytes = match_id.as_bytes();
-    let mut match_id_array = [0u8; 36];
-    let copy_len = match_id_bytes.len().min(36);
-    match_id_array[..copy_len].copy_from_slice(&match_id_bytes[..copy_len]);
+    // Security: Per-game daily cap (throttles one game during an incident)
+    let daily_cap = game_registry.daily_match_cap_for(game_type);
+    global_stats.record_match_created(game_type, daily_cap, clock.unix_timestamp)?;
 
-    // Convert game name to fixed-size array (null-padded)
-    let game_name_str = game_type_enum.get_name();
-    let game_name_bytes = game_name_str.as_bytes();
-    let mut game_name_array = [0u8; 20];
-    let name_copy_len = game_name_bytes.len().min(20);
-    game_name_array[..name_copy_len].copy_from_slice(&game_name_bytes[..name_copy_len]);
+    emit!(MatchCreated {
+        match_account: match_account.key(),
+        game_type: match_account.game_type,
+        authority: match_account.authority,
+        rounds_to_play: match_account.rounds_to_play,
+    });
+    Ok(())
+}
 
-    // Initialize match with optimized struct
-    match_account.match_id = match_id_array;
-    
-    // Per critique Phase 2.4: Initialize version field (default to "1.0.0")
-    let version_str = "1.0.0";
-    let version_bytes = version_str.as_bytes();
-    let mut version_array = [0u8; 10];
-    let version_copy_len = version_bytes.len().min(10);
-    version_array[..version_copy_len].copy_from_slice(&version_bytes[..version_copy_len]);
-    match_account.version = version_array;
-    
-    match_account.game_type = game_type;
-    match_account.game_name = game_name_array;
-    match_account.seed = seed;
-    match_account.phase = 0; // Dealing
-    match_account.current_player = 0;
-    match_account.player_ids = [[0u8; 64]; 10]; // Initialize all player_ids to empty
-    match_account.player_count = 0;
-    match_account.move_count = 0;
-    match_account.created_at = clock.unix_timestamp;
-    match_account.ended_at = 0; // 0 = not ended
-    match_account.match_hash = [0u8; 32]; // All zeros = not set
-    match_account.hot_url = [0u8; 200]; // All zeros = not set
-    match_account.authority = ctx.accounts.authority.key();
-    match_account.declared_suits = [0u8; 5]; // All zeros = no suits declared
-    match_account.flags = 0; // All flags false
-    match_account.floor_card_hash = [0u8; 32]; // All zeros = no floor card - per critique Issue #1
-    match_account.hand_sizes = [0u8; 10]; // All zeros = no hands committed yet - per critique Issue #1
-    match_account.committed_hand_hashes = [0u8; 320]; // All zeros = not committed yet
-    match_account.last_nonce = [0u64; 10]; // All zeros = no moves yet
-
-    msg!("Match created: {}", match_id);
+/// Validates game_type bounds and converts it to the enum.
+/// Shared by create_match, create_matches_batch and form_match_from_queue.
+pub(crate) fn parse_game_type(game_type: u8) -> Result<GameType> {
+    match game_type {
+        0 => Ok(GameType::Claim),
+        1 => Ok(GameType::ThreeCardBrag),
+        2 => Ok(GameType::Poker),
+        3 => Ok(GameType::Bridge),
+        4 => Ok(GameType::Rummy),
+        5 => Ok(GameType::Scrabble),
+        6 => Ok(GameType::WordSearch),
+        7 => Ok(GameType::Crosswords),
+        _ => Err(GameError::InvalidPayload.into()),
+    }
+}
+
+/// Validates a match_id (UUID v4 is exactly 36 chars) and converts it to the stored array.
+/// Ids in the derived range are reserved for create_match_counted.
+pub(crate) fn parse_match_id(match_id: &str) -> Result<[u8; 36]> {
+    require!(
+        match_id.len() == 36,
+        GameError::MatchIdMismatch
+    );
+    require!(
+        !MatchCreator::is_derived_match_id(match_id.as_bytes()),
+        GameError::ReservedMatchId
+    );
+    let mut match_id_array = [0u8; 36];
+    match_id_array.copy_from_slice(match_id.as_bytes());
+    Ok(match_id_array)
+}
+
+/// Checks the open lobby index was passed iff the match is public, and delists the match once
+/// it is no longer joinable. Shared by join_match and start_match.
+pub(crate) fn sync_lobby_listing(
+    lobby_index: Option<&mut Account<OpenLobbyIndex>>,
+    match_account: &Match,
+    now: i64,
+) -> Result<()> {
+    require!(
+        lobby_index.is_some() == match_account.is_public(),
+        GameError::LobbyIndexMismatch
+    );
+    if let Some(lobby_index) = lobby_index {
+        if !match_account.can_join() && lobby_index.delist(&match_account.match_id, now) {
+            emit!(MatchListingChanged {
+                game_type: match_account.game_type,
+                match_id: match_account.match_id,
+                listed: false,
+            });
+        }
+    }
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(match_id: String)]
+#[instruction(match_id: String, game_type: u8)]
 pub struct CreateMatch<'info> {
     #[account(
         init,
         payer = authority,
         space = Match::MAX_SIZE,
-        seeds = [b"match", match_id.as_bytes()],
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
         bump
     )]
     pub match_account: Account<'info, Match>,
     
+    #[account(
+        mut,
+        seeds = [b"global_stats"],
+        bump
+    )]
+    pub global_stats: Box<Account<'info, GlobalStats>>,
+    
+    #[account(
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub game_registry: Box<Account<'info, GameRegistry>>,
+    
+    /// The game's open lobby index; passing it makes the match public (listed)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = OpenLobbyIndex::MAX_SIZE,
+        seeds = [b"open_lobbies".as_ref(), &[game_type]],
+        bump
+    )]
+    pub lobby_index: Option<Box<Account<'info, OpenLobbyIndex>>>,
+    
     #[account(mut)]
     pub authority: Signer<'info>,
     
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, MatchCreator, GameRegistry, GlobalStats, OpenLobbyIndex, match_id_seed};
+use crate::instructions::create_match::{init_match, MatchSettings};
+use crate::error::GameError;
+
+/// create_match with an on-chain id: the match_id is MatchCreator::derived_match_id(authority,
+/// match_counter), so the match PDA follows from (authority, counter) and can't collide or be
+/// ground off-chain. `match_counter` must be the creator's next counter (0 for an authority's
+/// first counted match); it guards against creating a different match than the client derived.
+/// Everything else (visibility, lobby listing, daily cap) is as in create_match.
+pub fn handler(
+    ctx: Context<CreateMatchCounted>,
+    match_counter: u64,
+    game_type: u8,
+    seed: u64,
+    rounds_to_play: u8,
+    join_secret_hash: Option<[u8; 32]>,
+    player_consent_start: bool,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let authority = ctx.accounts.authority.key();
+    
+    let creator = &mut ctx.accounts.match_creator;
+    require!(
+        creator.match_counter == match_counter,
+        GameError::MatchCounterMismatch
+    );
+    creator.authority = authority;
+    creator.match_counter = match_counter.checked_add(1).ok_or(GameError::Overflow)?;
+    creator.last_created_at = clock.unix_timestamp;
+    
+    let accounts = ctx.accounts;
+    init_match(
+        &mut accounts.match_account,
+        &mut accounts.global_stats,
+        &accounts.game_registry,
+        accounts.lobby_index.as_mut(),
+        authority,
+        MatchCreator::derived_match_id(&authority, match_counter),
+        MatchSettings { game_type, seed, rounds_to_play, join_secret_hash, player_consent_start },
+    )?;
+    
+    telemetry!("Counted match #{} created by {}", match_counter, authority);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_counter: u64, game_type: u8)]
+pub struct CreateMatchCounted<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = MatchCreator::MAX_SIZE,
+        seeds = [b"match_creator", authority.key().as_ref()],
+        bump
+    )]
+    pub match_creator: Account<'info, MatchCreator>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = Match::MAX_SIZE,
+        seeds = [b"match", match_id_seed(&MatchCreator::derived_match_id(&authority.key(), match_counter))],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_stats"],
+        bump
+    )]
+    pub global_stats: Box<Account<'info, GlobalStats>>,
+    
+    #[account(
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub game_registry: Box<Account<'info, GameRegistry>>,
+    
+    /// The game's open lobby index; passing it makes the match public (listed)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = OpenLobbyIndex::MAX_SIZE,
+        seeds = [b"open_lobbies".as_ref(), &[game_type]],
+        bump
+    )]
+    pub lobby_index: Option<Box<Account<'info, OpenLobbyIndex>>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
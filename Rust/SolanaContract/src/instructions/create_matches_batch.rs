@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use crate::state::{Match, GameRegistry, GlobalStats, match_id_seed};
+use crate::events::MatchCreated;
+use crate::error::GameError;
+use super::create_match::{parse_game_type, parse_match_id};
+
+/// Match to create in a batch.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchMatch {
+    pub match_id: String,
+    pub seed: u64,
+}
+
+/// Most matches created per transaction (bounded by transaction size: each needs
+/// its id, seed and a writable account key)
+pub const MAX_BATCH_MATCHES: usize = 8;
+
+/// Creates up to MAX_BATCH_MATCHES public matches with the same game_type and
+/// round count in one transaction (tournament brackets, load tests).
+/// Remaining accounts: one writable, uninitialized Match PDA per entry, in order.
+/// Each match is initialized exactly like create_match and emits its own MatchCreated.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateMatchesBatch<'info>>,
+    game_type: u8,
+    rounds_to_play: u8, // Number of rounds (0 or 1 = single-round match)
+    matches: Vec<BatchMatch>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let authority = &ctx.accounts.authority;
+
+    // Security: Validate authority is signer
+    require!(
+        authority.is_signer,
+        GameError::Unauthorized
+    );
+
+    require!(
+        !matches.is_empty() && matches.len() <= MAX_BATCH_MATCHES,
+        GameError::BatchTooLarge
+    );
+    require!(
+        ctx.remaining_accounts.len() == matches.len(),
+        GameError::InvalidMatchAccount
+    );
+
+    let game_type_enum = parse_game_type(game_type)?;
+    let rent_lamports = Rent::get()?.minimum_balance(Match::MAX_SIZE);
+    let daily_cap = ctx.accounts.game_registry.daily_match_cap_for(game_type);
+
+    for (entry, account_info) in matches.iter().zip(ctx.remaining_accounts.iter()) {
+        let match_id_array = parse_match_id(&entry.match_id)?;
+
+        // Security: Account must be this match_id's PDA and not yet initialized
+        let seed = match_id_seed(entry.match_id.as_bytes());
+        let (expected, bump) = Pubkey::find_program_address(&[b"match", seed], &crate::ID);
+        require!(
+            account_info.key() == expected
+                && account_info.is_writable
+                && account_info.owner == &system_program::ID
+                && account_info.data_is_empty(),
+            GameError::InvalidMatchAccount
+        );
+
+        let signer_seeds: &[&[&[u8]]] = &[&[b"match", seed, &[bump]]];
+        create_pda(
+            authority.to_account_info(),
+            account_info.clone(),
+            ctx.accounts.system_program.to_account_info(),
+            signer_seeds,
+            rent_lamports,
+        )?;
+
+        let mut match_account = Account::<Match>::try_from_unchecked(account_info)?;
+        match_account.initialize(
+            match_id_array,
+            game_type_enum,
+            entry.seed,
+            authority.key(),
+            clock.unix_timestamp,
+            rounds_to_play,
+        );
+        match_account.exit(&crate::ID)?;
+        ctx.accounts.global_stats.record_match_created(game_type, daily_cap, clock.unix_timestamp)?;
+
+        emit!(MatchCreated {
+            match_account: expected,
+            game_type: match_account.game_type,
+            authority: match_account.authority,
+            rounds_to_play: match_account.rounds_to_play,
+        });
+    }
+
+    telemetry!("Created {} matches (game_type={})", matches.len(), game_type);
+    Ok(())
+}
+
+/// Creates a Match-sized program account at a PDA, topping up any lamports already
+/// sent to the address (a plain create_account would fail on a pre-funded PDA).
+fn create_pda<'info>(
+    payer: AccountInfo<'info>,
+    target: AccountInfo<'info>,
+    system: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    rent_lamports: u64,
+) -> Result<()> {
+    let current_lamports = target.lamports();
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(system, CreateAccount { from: payer, to: target }, signer_seeds),
+            rent_lamports,
+            Match::MAX_SIZE as u64,
+            &crate::ID,
+        );
+    }
+    let shortfall = rent_lamports.saturating_sub(current_lamports);
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(system.clone(), Transfer { from: payer, to: target.clone() }),
+            shortfall,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(system.clone(), Allocate { account_to_allocate: target.clone() }, signer_seeds),
+        Match::MAX_SIZE as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(system, Assign { account_to_assign: target }, signer_seeds),
+        &crate::ID,
+    )
+}
+
+#[derive(Accounts)]
+pub struct CreateMatchesBatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_stats"],
+        bump
+    )]
+    pub global_stats: Box<Account<'info, GlobalStats>>,
+    
+    #[account(
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub game_registry: Box<Account<'info, GameRegistry>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::{PromoCode, ConfigAccount};
+use crate::error::GameError;
+
+/// Creates a promo code redeemable for GP and/or AC.
+/// Authority-gated (ConfigAccount.authority). Only the SHA-256 of the code goes on-chain.
+pub fn handler(
+    ctx: Context<CreatePromo>,
+    promo_index: u16,
+    code_hash: [u8; 32],
+    gp_amount: u64,
+    ac_amount: u64,
+    max_redemptions: u32,
+    expires_at: i64, // 0 = never expires
+) -> Result<()> {
+    let config = &ctx.accounts.config_account;
+    let clock = Clock::get()?;
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        GameError::Unauthorized
+    );
+    
+    require!(
+        promo_index < PromoCode::MAX_PROMOS,
+        GameError::InvalidPayload
+    );
+    require!(
+        code_hash.iter().any(|&b| b != 0),
+        GameError::InvalidPayload
+    );
+    require!(
+        (gp_amount > 0 || ac_amount > 0) && max_redemptions > 0,
+        GameError::InvalidPayload
+    );
+    require!(
+        expires_at == 0 || expires_at > clock.unix_timestamp,
+        GameError::InvalidTimestamp
+    );
+    
+    let promo = &mut ctx.accounts.promo;
+    promo.promo_index = promo_index;
+    promo.code_hash = code_hash;
+    promo.gp_amount = gp_amount;
+    promo.ac_amount = ac_amount;
+    promo.max_redemptions = max_redemptions;
+    promo.redemption_count = 0;
+    promo.expires_at = expires_at;
+    promo.authority = ctx.accounts.authority.key();
+    promo.created_at = clock.unix_timestamp;
+    
+    telemetry!("Promo created: index={}, gp={}, ac={}, max_redemptions={}", 
+         promo_index, gp_amount, ac_amount, max_redemptions);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(promo_index: u16)]
+pub struct CreatePromo<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = PromoCode::MAX_SIZE,
+        seeds = [b"promo", promo_index.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub promo: Account<'info, PromoCode>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
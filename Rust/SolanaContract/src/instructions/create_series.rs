@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::{Series, match_id_seed};
+use crate::error::GameError;
+
+/// Creates a best-of-N series between a fixed roster of user ids (see Series).
+/// The creator becomes the series authority and reports each finished match with
+/// report_series_game.
+pub fn handler(
+    ctx: Context<CreateSeries>,
+    series_id: String,
+    game_type: u8,
+    best_of: u8,
+    player_ids: Vec<String>,
+) -> Result<()> {
+    let series = &mut ctx.accounts.series;
+    let clock = Clock::get()?;
+    
+    // Security: Series ids are UUIDs, like match ids
+    let series_id_bytes = series_id.as_bytes();
+    require!(
+        series_id_bytes.len() == 36,
+        GameError::MatchIdMismatch
+    );
+    crate::instructions::create_match::parse_game_type(game_type)?;
+
+    series.series_id.copy_from_slice(series_id_bytes);
+    series.init(game_type, best_of, &player_ids)?;
+    series.authority = ctx.accounts.authority.key();
+    series.created_at = clock.unix_timestamp;
+
+    telemetry!("Series {} created: best of {}, {} players", series_id, best_of, player_ids.len());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(series_id: String)]
+pub struct CreateSeries<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = Series::MAX_SIZE,
+        seeds = [b"series", match_id_seed(series_id.as_bytes())],
+        bump
+    )]
+    pub series: Box<Account<'info, Series>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
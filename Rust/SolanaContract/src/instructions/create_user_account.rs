@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::{UserAccount, ConfigAccount};
+use crate::error::GameError;
+
+/// Creates a user's on-chain stats account.
+/// Records the creation time and starts the placement period (anti-smurf gating).
+pub fn handler(ctx: Context<CreateUserAccount>, user_id: String) -> Result<()> {
+    // Convert String to fixed-size array immediately (optimization: avoid String operations)
+    let user_id_bytes = user_id.as_bytes();
+    require!(
+        !user_id_bytes.is_empty() && user_id_bytes.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    let mut user_id_array = [0u8; 64];
+    user_id_array[..user_id_bytes.len()].copy_from_slice(user_id_bytes);
+    
+    let user_account = &mut ctx.accounts.user_account;
+    let config = &ctx.accounts.config_account;
+    let clock = Clock::get()?;
+    
+    user_account.user_id = user_id_array;
+    user_account.active_multiplier = 1;
+    user_account.created_at = clock.unix_timestamp;
+    user_account.placement_matches_remaining = config.placement_matches_required;
+    user_account.schema_version = UserAccount::SCHEMA_VERSION;
+    
+    telemetry!(
+        "User account created: {} ({} placement matches)",
+        user_id,
+        user_account.placement_matches_remaining
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(user_id: String)]
+pub struct CreateUserAccount<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = UserAccount::MAX_SIZE,
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -1,25 +1,32 @@
 use anchor_lang::prelude::*;
-use crate::state::{UserAccount, ConfigAccount};
+use crate::state::{UserAccount, ConfigAccount, GlobalStats, require_not_sanctioned};
 use crate::error::GameError;
 
 /// Claims daily login reward (GP).
 /// Per spec Section 20.1.2: Daily login system with 24-hour cooldown.
 /// Note: user_id is String in instruction data (Anchor requirement), but converted to fixed array immediately.
-pub fn handler(ctx: Context<ClaimDailyLogin>, user_id: String) -> Result<()> {
+pub fn handler(ctx: Context<ClaimDailyLogin>, user_id: String, op_nonce: u64) -> Result<()> {
     // Convert String to fixed-size array immediately (optimization: avoid String operations)
     let user_id_bytes = user_id.as_bytes();
     require!(
         user_id_bytes.len() <= 64,
-        GameError::InvalidPayload
+        GameError::UserIdTooLong
     );
     let mut user_id_array = [0u8; 64];
     let copy_len = user_id_bytes.len().min(64);
     user_id_array[..copy_len].copy_from_slice(&user_id_bytes[..copy_len]);
     
     let user_account = &mut ctx.accounts.user_account;
+    
+    // Idempotency: a retried operation can't be applied twice
+    user_account.use_op_nonce(op_nonce)?;
+    
     let config = &ctx.accounts.config_account;
     let clock = Clock::get()?;
     
+    // Security: Banned or suspended users can't claim rewards
+    require_not_sanctioned(&ctx.accounts.sanction, &clock)?;
+
     // Check if 24 hours have passed since last claim
     require!(
         user_account.can_claim_daily(&clock),
@@ -52,8 +59,9 @@ pub fn handler(ctx: Context<ClaimDailyLogin>, user_id: String) -> Result<()> {
     user_account.lifetime_gp_earned = user_account.lifetime_gp_earned
         .checked_add(gp_amount)
         .ok_or(GameError::Overflow)?;
+    ctx.accounts.global_stats.record_gp_issued(gp_amount, clock.unix_timestamp);
     
-    msg!("Daily login claimed: {} GP (multiplier: {}x)", gp_amount, total_multiplier);
+    telemetry!("Daily login claimed: {} GP (multiplier: {}x)", gp_amount, total_multiplier);
     Ok(())
 }
 
@@ -74,6 +82,20 @@ pub struct ClaimDailyLogin<'info> {
     )]
     pub config_account: Account<'info, ConfigAccount>,
     
+    /// CHECK: User's sanction PDA (may not exist); checked by require_not_sanctioned
+    #[account(
+        seeds = [b"sanction", user_id.as_bytes()],
+        bump
+    )]
+    pub sanction: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_stats"],
+        bump
+    )]
+    pub global_stats: Box<Account<'info, GlobalStats>>,
+    
     pub system_program: Program<'info, System>,
 }
 
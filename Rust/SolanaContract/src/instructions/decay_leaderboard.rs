@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameLeaderboard, LeaderboardIndex, ConfigAccount};
+use crate::error::GameError;
+
+/// Inactivity decay crank for one leaderboard shard, so early-season scores can't hold the
+/// top ranks all season. Entries not updated for ConfigAccount.leaderboard_decay_days lose
+/// leaderboard_decay_bps of their score (once per period per entry) and the shard is re-sorted.
+/// The loss is kept on the entry (decay_loss) and taken from every later submission, since
+/// submitted scores are undecayed season totals.
+/// The next shard (required when it exists) is decayed too and the two trade entries across
+/// the boundary until rank order holds. Walk shard_index 0, 1, 2, ... to decay a leaderboard
+/// (an entry that decays past a whole shard moves down one boundary per step).
+/// Permissionless: the outcome depends only on the on-chain clock and config.
+pub fn handler(
+    ctx: Context<DecayLeaderboard>,
+    game_type: u8,
+    season_id: u64,
+    shard_index: u16,
+) -> Result<()> {
+    let index = &mut ctx.accounts.leaderboard_index;
+    let config = &ctx.accounts.config_account;
+    let clock = Clock::get()?;
+
+    require!(
+        config.leaderboard_decay_days > 0 && config.leaderboard_decay_bps > 0,
+        GameError::LeaderboardDecayDisabled
+    );
+
+    // Security: Season leaderboard is frozen once reward distribution starts
+    require!(!index.rewards_finalized, GameError::SeasonFinalized);
+
+    // Security: The boundary with the next shard has to be restored when there is one
+    require!(
+        shard_index + 1 >= index.shard_count || ctx.accounts.next_shard.is_some(),
+        GameError::InvalidLeaderboardShard
+    );
+
+    let stale_after_seconds = config.leaderboard_decay_days as i64 * 86400;
+    let decay_bps = config.leaderboard_decay_bps;
+    let shard = &mut ctx.accounts.leaderboard_shard;
+    let mut decayed = shard.decay_stale_entries(clock.unix_timestamp, stale_after_seconds, decay_bps) as u16;
+    let mut exchanged = 0;
+
+    if let Some(next_shard) = ctx.accounts.next_shard.as_mut() {
+        decayed += next_shard.decay_stale_entries(clock.unix_timestamp, stale_after_seconds, decay_bps) as u16;
+        exchanged = shard.rebalance_with(next_shard);
+        next_shard.last_updated = clock.unix_timestamp;
+        index.refresh_shard(next_shard);
+    }
+    shard.last_updated = clock.unix_timestamp;
+    index.refresh_shard(shard);
+    index.last_updated = clock.unix_timestamp;
+
+    telemetry!(
+        "Leaderboard decay: shard {} (game_type={}, season={}): {} entries decayed, {} moved across shards",
+        shard_index,
+        game_type,
+        season_id,
+        decayed,
+        exchanged
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(game_type: u8, season_id: u64, shard_index: u16)]
+pub struct DecayLeaderboard<'info> {
+    #[account(
+        mut,
+        seeds = [b"leaderboard_index".as_ref(), &[game_type], &season_id.to_le_bytes()],
+        bump
+    )]
+    pub leaderboard_index: Account<'info, LeaderboardIndex>,
+    
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &[game_type], &season_id.to_le_bytes(), &shard_index.to_le_bytes()],
+        bump
+    )]
+    pub leaderboard_shard: Box<Account<'info, GameLeaderboard>>,
+    
+    /// The shard holding the next 100 ranks (None only for the last shard)
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &[game_type], &season_id.to_le_bytes(), &(shard_index + 1).to_le_bytes()],
+        bump
+    )]
+    pub next_shard: Option<Box<Account<'info, GameLeaderboard>>>,
+    
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    pub cranker: Signer<'info>,
+}
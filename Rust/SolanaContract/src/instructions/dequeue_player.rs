@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::{MatchQueue, ConfigAccount};
+use crate::error::GameError;
+
+pub fn handler(
+    ctx: Context<DequeuePlayer>,
+    game_type: u8,
+    user_id: String,
+) -> Result<()> {
+    let queue = &mut ctx.accounts.match_queue;
+    let clock = Clock::get()?;
+
+    // Security: Validate player is signer
+    require!(
+        ctx.accounts.player.is_signer,
+        GameError::Unauthorized
+    );
+
+    // Convert user_id String to fixed-size array
+    let user_id_bytes = user_id.as_bytes();
+    require!(
+        user_id_bytes.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    let mut user_id_array = [0u8; 64];
+    user_id_array[..user_id_bytes.len()].copy_from_slice(user_id_bytes);
+
+    let index = queue.find_entry(&user_id_array)
+        .ok_or(GameError::NotQueued)?;
+    
+    // Security: Only the wallet that enqueued the entry (or the platform authority) removes it
+    let caller = ctx.accounts.player.key();
+    require!(
+        caller == queue.entries[index].wallet || caller == ctx.accounts.config_account.authority,
+        GameError::Unauthorized
+    );
+    queue.remove_at(index);
+    queue.last_updated = clock.unix_timestamp;
+
+    telemetry!("Player {} left queue for game type {} ({} waiting)", user_id, game_type, queue.entry_count);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(game_type: u8)]
+pub struct DequeuePlayer<'info> {
+    #[account(
+        mut,
+        seeds = [b"match_queue".as_ref(), &[game_type]],
+        bump
+    )]
+    pub match_queue: Box<Account<'info, MatchQueue>>,
+    
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    pub player: Signer<'info>,
+}
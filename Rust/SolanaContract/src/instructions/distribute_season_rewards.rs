@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{GameLeaderboard, LeaderboardIndex, UserAccount, ConfigAccount, Guild, GlobalStats, is_sanctioned, require_sanction_pda};
+use crate::error::GameError;
+
+/// Pays end-of-season rewards for the next page of ranks in one leaderboard shard.
+/// Remaining accounts: for ranks rewards_paid_count+1, +2, ... in rank order, the UserAccount PDA
+/// (writable) and the player's sanction PDA (which may not exist). When a treasury signer is
+/// provided and season_reward_lamports > 0, each pair is followed by the player's linked wallet
+/// (UserAccount.wallet, writable) to receive lamports; a player with no linked wallet gets GP only
+/// (the slot is still passed). Sanctioned players forfeit their reward and the cursor moves on.
+/// Idempotent: the index's rewards_paid_count cursor only moves forward.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, DistributeSeasonRewards<'info>>,
+    game_type: u8,
+    season_id: u64,
+    shard_index: u16,
+) -> Result<()> {
+    let index = &mut ctx.accounts.leaderboard_index;
+    let shard = &ctx.accounts.leaderboard_shard;
+    let config = &ctx.accounts.config_account;
+    let clock = Clock::get()?;
+
+    // Security: Only the config authority distributes rewards
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        GameError::Unauthorized
+    );
+
+    // Guild leaderboard entries are guilds, not users
+    require!(
+        game_type != Guild::GUILD_LEADERBOARD_TYPE,
+        GameError::InvalidLeaderboardShard
+    );
+
+    // Security: Season must be over before rewards are paid
+    let current_season_id = (clock.unix_timestamp / 604800) as u64; // 7 days in seconds
+    require!(season_id < current_season_id, GameError::SeasonNotEnded);
+
+    // Freeze the leaderboard on the first page
+    index.rewards_finalized = true;
+
+    let top_n = (config.season_reward_top_n as u32).min(index.total_entries());
+    if index.rewards_paid_count >= top_n {
+        telemetry!("Season {} rewards already distributed (game_type={})", season_id, game_type);
+        return Ok(());
+    }
+
+    // Security: Pages must be paid in rank order from the cursor's shard
+    let shard_start = shard_index as u32 * GameLeaderboard::MAX_ENTRIES as u32;
+    require!(
+        index.rewards_paid_count / GameLeaderboard::MAX_ENTRIES as u32 == shard_index as u32,
+        GameError::InvalidLeaderboardShard
+    );
+
+    let pay_lamports = config.season_reward_lamports > 0 && ctx.accounts.treasury.is_some();
+    let stride = if pay_lamports { 3 } else { 2 };
+
+    let mut paid = 0u32;
+    for accounts in ctx.remaining_accounts.chunks(stride) {
+        let global_rank = index.rewards_paid_count + 1;
+        let position = (global_rank - 1 - shard_start) as usize;
+        if global_rank > top_n || position >= shard.entry_count as usize {
+            break;
+        }
+        let entry = &shard.entries[position];
+
+        // Security: Banned or suspended users get no reward; their rank is still passed
+        let sanction_info = accounts.get(1).ok_or(GameError::InvalidPayload)?;
+        require_sanction_pda(sanction_info, &entry.user_id)?;
+        if is_sanctioned(sanction_info, &clock)? {
+            index.rewards_paid_count = global_rank;
+            telemetry!("Season reward: rank {} skipped (sanctioned)", global_rank);
+            continue;
+        }
+
+        let user_info = &accounts[0];
+        require!(
+            user_info.owner == &crate::ID && user_info.is_writable,
+            GameError::InvalidPayload
+        );
+        let mut user_account = {
+            let data = user_info.try_borrow_data()?;
+            UserAccount::try_deserialize(&mut &data[..])?
+        };
+
+        // Security: UserAccount must belong to the player at this rank
+        require!(
+            user_account.user_id == entry.user_id,
+            GameError::Unauthorized
+        );
+
+        let multiplier = GameLeaderboard::season_reward_multiplier(global_rank);
+        let reward_gp = config.season_reward_base_gp
+            .checked_mul(multiplier)
+            .ok_or(GameError::Overflow)?;
+
+        // GP balance updated in database, not on-chain
+        user_account.lifetime_gp_earned = user_account.lifetime_gp_earned
+            .checked_add(reward_gp)
+            .ok_or(GameError::Overflow)?;
+        user_account.leaderboard_rank = global_rank.min(u16::MAX as u32) as u16;
+        user_account.active_multiplier = UserAccount::calculate_multiplier(user_account.leaderboard_rank);
+
+        {
+            let mut data = user_info.try_borrow_mut_data()?;
+            user_account.try_serialize(&mut &mut data[..])?;
+        }
+
+        // No linked wallet: GP only
+        if pay_lamports && user_account.wallet != Pubkey::default() {
+            let recipient = accounts.get(2).ok_or(GameError::InvalidPayload)?;
+            // Security: Lamports only go to the wallet this rank's user linked
+            require!(
+                recipient.key() == user_account.wallet,
+                GameError::Unauthorized
+            );
+            let reward_lamports = config.season_reward_lamports
+                .checked_mul(multiplier)
+                .ok_or(GameError::Overflow)?;
+            if let Some(treasury) = ctx.accounts.treasury.as_ref() {
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: treasury.to_account_info(),
+                            to: recipient.clone(),
+                        },
+                    ),
+                    reward_lamports,
+                )?;
+            }
+        }
+
+        index.rewards_paid_count = global_rank;
+        ctx.accounts.global_stats.record_gp_issued(reward_gp, clock.unix_timestamp);
+        paid += 1;
+        telemetry!("Season reward: rank {} -> {} GP ({}x)", global_rank, reward_gp, multiplier);
+    }
+
+    index.last_updated = clock.unix_timestamp;
+
+    telemetry!(
+        "Season {} rewards: paid {} ranks ({} of {} done, game_type={})",
+        season_id,
+        paid,
+        index.rewards_paid_count,
+        top_n,
+        game_type
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(game_type: u8, season_id: u64, shard_index: u16)]
+pub struct DistributeSeasonRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"leaderboard_index".as_ref(), &[game_type], &season_id.to_le_bytes()],
+        bump
+    )]
+    pub leaderboard_index: Account<'info, LeaderboardIndex>,
+    
+    #[account(
+        seeds = [b"leaderboard".as_ref(), &[game_type], &season_id.to_le_bytes(), &shard_index.to_le_bytes()],
+        bump
+    )]
+    pub leaderboard_shard: Box<Account<'info, GameLeaderboard>>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_stats"],
+        bump
+    )]
+    pub global_stats: Box<Account<'info, GlobalStats>>,
+    
+    pub authority: Signer<'info>,
+    
+    /// Optional treasury wallet funding lamport rewards
+    #[account(mut)]
+    pub treasury: Option<Signer<'info>>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::{UserAccount, ConfigAccount};
+use crate::error::GameError;
+
+/// Schedules a downgrade to a lower paid tier.
+/// The current tier stays active until subscription_expiry; the new tier
+/// takes effect from that date (applied lazily by subscription instructions).
+/// Authority-gated (ConfigAccount.authority).
+/// Note: Stripe plan change is processed off-chain. This instruction only records the state.
+/// Note: String params converted to fixed arrays immediately for performance.
+pub fn handler(
+    ctx: Context<DowngradeSubscription>,
+    user_id: String,
+    new_tier: u8,  // SubscriptionTier as u8 (must be a lower paid tier; use cancel_subscription for Free)
+    op_nonce: u64,  // Per-user operation nonce (idempotency key)
+) -> Result<()> {
+    // Convert String to fixed-size array immediately (optimization)
+    let user_id_bytes = user_id.as_bytes();
+    require!(
+        user_id_bytes.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    
+    let user_account = &mut ctx.accounts.user_account;
+    
+    // Idempotency: a retried operation can't be applied twice
+    user_account.use_op_nonce(op_nonce)?;
+    
+    let clock = Clock::get()?;
+    
+    // Apply any downgrade whose effective date has already passed
+    user_account.apply_pending_subscription_changes(&clock);
+    
+    require!(
+        user_account.has_active_subscription(&clock),
+        GameError::SubscriptionNotActive
+    );
+    require!(
+        !user_account.cancel_at_period_end(),
+        GameError::SubscriptionAlreadyCancelled
+    );
+    
+    // New tier must be a paid tier below the current one
+    require!(
+        new_tier > 0 && new_tier < user_account.subscription_tier,
+        GameError::InvalidTier
+    );
+    
+    // Effective at the end of the current paid period
+    user_account.pending_tier = new_tier;
+    user_account.pending_tier_effective_at = user_account.subscription_expiry;
+    
+    telemetry!("Subscription downgrade scheduled: tier {} -> {} at {}", 
+         user_account.subscription_tier, new_tier, user_account.pending_tier_effective_at);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(user_id: String)]
+pub struct DowngradeSubscription<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    // Security: Only the platform authority records Stripe-side subscription changes
+    #[account(constraint = authority.key() == config_account.authority @ GameError::Unauthorized)]
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, ScrabbleBoard, TileReveal, require_not_sanctioned, match_id_seed};
+use crate::error::GameError;
+
+/// Deals tiles from the committed bag into a player's rack. Each reveal must hash to the
+/// current bag head (SHA-256(tile || next_head)), so the coordinator can only deal the bag it
+/// committed to, in order. The match authority deals and the seat's wallet co-signs, so tiles
+/// only go to a rack whose player asked for them. During Dealing or Playing.
+pub fn handler(
+    ctx: Context<DrawTiles>,
+    match_id: String,
+    user_id: String,
+    reveals: Vec<TileReveal>,
+) -> Result<()> {
+    let match_account = &ctx.accounts.match_account;
+    let board = &mut ctx.accounts.scrabble_board;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Only the match authority (coordinator)
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    require!(
+        match_account.phase == 0 || match_account.phase == 1,
+        GameError::InvalidPhase
+    );
+    require!(
+        !reveals.is_empty() && reveals.len() <= ScrabbleBoard::RACK_SIZE,
+        GameError::InvalidPayload
+    );
+
+    // Security: Validate player is in match
+    require!(
+        user_id.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    let seat = match_account.find_player_index(user_id.as_bytes())
+        .ok_or(GameError::PlayerNotInMatch)?;
+
+    // Security: The seat's own wallet co-signs the draw, and sanctioned users get no tiles
+    require!(
+        match_account.is_seat_wallet(seat, &ctx.accounts.player.key()),
+        GameError::Unauthorized
+    );
+    require_not_sanctioned(&ctx.accounts.sanction, &clock)?;
+
+    board.draw_tiles(seat, &reveals)?;
+
+    telemetry!("Dealt {} tiles to seat {} in match {} ({} left in bag)",
+         reveals.len(), seat, match_id, board.bag_remaining);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String, user_id: String)]
+pub struct DrawTiles<'info> {
+    #[account(
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Box<Account<'info, Match>>,
+    
+    #[account(
+        mut,
+        seeds = [b"scrabble_board", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub scrabble_board: Box<Account<'info, ScrabbleBoard>>,
+    
+    /// CHECK: User's sanction PDA (may not exist); checked by require_not_sanctioned
+    #[account(
+        seeds = [b"sanction", user_id.as_bytes()],
+        bump
+    )]
+    pub sanction: UncheckedAccount<'info>,
+    
+    pub authority: Signer<'info>,
+    
+    /// Wallet that took the seat being dealt to
+    pub player: Signer<'info>,
+}
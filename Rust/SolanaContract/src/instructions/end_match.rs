@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::state::Match;
+use crate::state::{Match, GlobalStats, CoordinatorSet, match_id_seed};
+use crate::events::MatchEnded;
 use crate::error::GameError;
+use crate::instructions::calculate_scores::calculate_scores_from_state;
 
 pub fn handler(
     ctx: Context<EndMatch>,
@@ -16,7 +18,7 @@ pub fn handler(
     require!(
         match_id_bytes.len() == 36 && 
         match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
-        GameError::InvalidPayload
+        GameError::MatchIdMismatch
     );
 
     // Security: Validate authority is signer and matches
@@ -24,31 +26,43 @@ pub fn handler(
         ctx.accounts.authority.is_signer,
         GameError::Unauthorized
     );
+    // Failover: any active coordinator in the CoordinatorSet may act for the match authority
+    let is_active_coordinator = ctx.accounts.coordinator_set
+        .as_ref()
+        .is_some_and(|set| set.is_active(&ctx.accounts.authority.key()));
     require!(
-        ctx.accounts.authority.key() == match_account.authority,
+        ctx.accounts.authority.key() == match_account.authority || is_active_coordinator,
         GameError::Unauthorized
     );
 
-    // Security: Must be in Playing or Ended phase
+    // Security: Must be in Playing or Ended phase, or a showdown whose rebuttal window closed
     require!(
-        match_account.phase == 1 || match_account.phase == 2,
+        match_account.phase == 1 || match_account.phase == 2 || match_account.phase == 3,
         GameError::InvalidPhase
     );
+    require!(
+        match_account.phase != 3 || match_account.showdown_closed(clock.unix_timestamp),
+        GameError::ShowdownInProgress
+    );
 
-    // Security: Validate match_hash if provided
+    // Security: match_hash is the canonical replay hash of the recorded moves (crate::replay);
+    // a coordinator-supplied hash must agree with it. Recorded once the last round ends.
+    let replay_hash = match_account.replay_match_hash();
     if let Some(hash) = match_hash {
         require!(
-            hash.iter().any(|&b| b != 0), // Not all zeros
-            GameError::InvalidPayload
+            hash == replay_hash,
+            GameError::MatchHashMismatch
         );
         match_account.match_hash = hash;
+    } else if !match_account.has_more_rounds() {
+        match_account.match_hash = replay_hash;
     }
 
     // Security: Validate and set hot_url if provided
     if let Some(url) = hot_url {
         require!(
             url.len() <= 200,
-            GameError::InvalidPayload
+            GameError::UrlTooLong
         );
         let url_bytes = url.as_bytes();
         let mut url_array = [0u8; 200];
@@ -61,71 +75,27 @@ pub fn handler(
     // Note: Full replay with all Move accounts requires off-chain querying (not possible in instruction)
     // On-chain we calculate based on available state: declared suits, move patterns, and game outcomes
     // This provides verifiable on-chain scores, with full detailed scoring done off-chain
-    
-    // Use calculate_scores_from_moves if we had access to Move accounts
-    // Since we can't query Move accounts in an instruction, we calculate from match state
-    let mut scores: [i32; 10] = [0; 10];
-    
-    // Count declarations and activity per player
-    let mut declarations_count = 0u32;
-    let total_activity = match_account.move_count;
-    
-    // Calculate scores for each player based on game state
-    // Per CLAIM game rules: declared players get positive scores, undeclared get penalties
-    for i in 0..match_account.player_count as usize {
-        if match_account.has_declared_suit(i) {
-            declarations_count += 1;
-            
-            // Declared players: positive scoring based on CLAIM game rules
-            // Base score: 20 points for declaring a suit
-            let base_score = 20i32;
-            
-            // Bonus: Activity points (more moves = more engagement)
-            // Normalize by player count to avoid bias
-            let avg_moves_per_player = if match_account.player_count > 0 {
-                total_activity / match_account.player_count as u32
-            } else {
-                0
-            };
-            let activity_score = avg_moves_per_player as i32;
-            
-            // Bonus: Early declaration bonus (simplified - first declarer gets bonus)
-            // In full implementation, would track declaration order from Move accounts
-            let declaration_bonus = if declarations_count == 1 { 5i32 } else { 0i32 };
-            
-            scores[i] = base_score + activity_score + declaration_bonus;
-        } else {
-            // Undeclared players: penalty for not declaring
-            // Penalty increases with game length (more opportunities missed)
-            let penalty_per_round = 2i32;
-            let rounds = if match_account.player_count > 0 {
-                (total_activity / match_account.player_count as u32).max(1) as i32
-            } else {
-                1
-            };
-            scores[i] = -(penalty_per_round * rounds);
-        }
-    }
-    
-    // Additional scoring based on game outcomes
-    // If match ended via showdown (phase 2), give bonus to declarer who called showdown
-    // Note: We can't determine who called showdown without querying Move accounts
-    // Full detailed scoring (sequences, bonuses, penalties) is done off-chain in MatchCoordinator
-    
-    // Normalize scores to ensure they're reasonable (prevent overflow)
-    for score in &mut scores {
-        *score = (*score).clamp(-100, 200); // Reasonable bounds
-    }
+    let round_scores = calculate_scores_from_state(match_account);
     
     // Per critique Issue #2: Store scores in match account for on-chain verification
-    // Note: Match struct doesn't currently have scores field - would need to add it
-    // For now, scores are calculated but not stored (off-chain MatchCoordinator stores in match record)
+    // Final round's scores are added to the cumulative totals (no-op if already scored)
+    match_account.accumulate_round_scores(&round_scores);
 
     // Finalize match
     match_account.phase = 2; // Ended
     match_account.ended_at = clock.unix_timestamp;
 
-    msg!("Match ended: {} with scores: {:?}", match_id, scores);
+    // Count the match (and its moves) once, when it is finalized
+    if match_account.has_match_hash() && !match_account.stats_recorded() {
+        ctx.accounts.global_stats.record_match_ended(
+            match_account.game_type,
+            match_account.move_count as u64,
+            clock.unix_timestamp,
+        );
+        match_account.set_stats_recorded(true);
+    }
+
+    emit!(MatchEnded::from_match(match_account.key(), match_account));
     Ok(())
 }
 
@@ -134,11 +104,25 @@ pub fn handler(
 pub struct EndMatch<'info> {
     #[account(
         mut,
-        seeds = [b"match", match_id.as_bytes()],
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
         bump
     )]
     pub match_account: Account<'info, Match>,
     
+    /// Required only when the signer is a failover coordinator rather than the match authority
+    #[account(
+        seeds = [b"coordinator_set"],
+        bump
+    )]
+    pub coordinator_set: Option<Box<Account<'info, CoordinatorSet>>>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_stats"],
+        bump
+    )]
+    pub global_stats: Box<Account<'info, GlobalStats>>,
+    
     pub authority: Signer<'info>,
 }
 
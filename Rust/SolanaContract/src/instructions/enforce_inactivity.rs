@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::{Dispute, ValidatorReputation};
+use crate::error::GameError;
+
+/// Permissionless crank that applies the inactivity slash (reason 2, INACTIVITY_SLASH_BPS of
+/// stake) without waiting for the council.
+///
+/// A validator is slashed when either:
+/// - it has sent no validator_heartbeat for INACTIVITY_SECONDS (counted from its last slash if
+///   later, so one slash per inactive period), or
+/// - it has missed MAX_MISSED_DISPUTES disputes. flag_dispute assigns each dispute to one
+///   registered validator (Dispute.assigned_validator); a dispute assigned to this validator,
+///   filed after it registered and still unresolved DISPUTE_RESPONSE_SECONDS later is a miss.
+///
+/// Remaining accounts: overdue Dispute accounts as evidence, oldest first. Each dispute counts
+/// once (created_at must be past missed_checked_to); misses accumulate across calls and reset
+/// when the inactivity slash is applied. The slash escrows and is appealable like any other.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, EnforceInactivity<'info>>,
+    validator_pubkey: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let validator_account = &mut ctx.accounts.validator_reputation;
+    
+    // Security: Validate validator matches
+    require!(
+        validator_account.validator == validator_pubkey,
+        GameError::InvalidPayload
+    );
+    
+    // Count overdue disputes passed as evidence
+    let mut newly_missed: u16 = 0;
+    for info in ctx.remaining_accounts.iter() {
+        let dispute: Account<Dispute> = Account::try_from(info)?;
+        require!(
+            !dispute.is_resolved()
+                && dispute.assigned_validator == validator_pubkey
+                && dispute.created_at > validator_account.missed_checked_to
+                && dispute.created_at >= validator_account.created_at
+                && now.saturating_sub(dispute.created_at) >= ValidatorReputation::DISPUTE_RESPONSE_SECONDS,
+            GameError::InvalidInactivityEvidence
+        );
+        validator_account.missed_checked_to = dispute.created_at;
+        newly_missed = newly_missed.saturating_add(1);
+    }
+    validator_account.missed_disputes = validator_account.missed_disputes.saturating_add(newly_missed);
+    
+    let missed_too_many = validator_account.missed_disputes >= ValidatorReputation::MAX_MISSED_DISPUTES;
+    let inactive = validator_account.is_inactive(now);
+    require!(
+        newly_missed > 0 || missed_too_many || inactive,
+        GameError::ValidatorNotInactive
+    );
+    
+    // Slash only when a threshold is met, nothing else is pending, and there is stake left;
+    // otherwise the evidence is just recorded for a later crank
+    validator_account.settle_slash(now);
+    if (missed_too_many || inactive)
+        && !validator_account.has_pending_slash()
+        && validator_account.stake > 0
+    {
+        let amount = validator_account.inactivity_slash_amount();
+        validator_account.apply_slash(amount, ValidatorReputation::SLASH_REASON_INACTIVITY, now)?;
+        validator_account.missed_disputes = 0;
+        
+        telemetry!("Inactivity slash on validator {}: {} lamports (missed disputes: {}, inactive: {}, appealable until {})",
+             validator_pubkey, amount, missed_too_many, inactive, validator_account.appeal_deadline());
+    } else {
+        telemetry!("Validator {} missed {} disputes ({} total)",
+             validator_pubkey, newly_missed, validator_account.missed_disputes);
+    }
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(validator_pubkey: Pubkey)]
+pub struct EnforceInactivity<'info> {
+    #[account(
+        mut,
+        seeds = [b"validator", validator_pubkey.as_ref()],
+        bump
+    )]
+    pub validator_reputation: Account<'info, ValidatorReputation>,
+    
+    pub cranker: Signer<'info>,
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::state::{MatchQueue, QueueEntry, UserAccount, require_not_sanctioned};
+use crate::error::GameError;
+
+pub fn handler(
+    ctx: Context<EnqueuePlayer>,
+    game_type: u8,
+    user_id: String,
+) -> Result<()> {
+    let queue = &mut ctx.accounts.match_queue;
+    let user_account = &ctx.accounts.user_account;
+    let clock = Clock::get()?;
+
+    // Security: Validate game_type bounds
+    require!(
+        game_type <= 7, // Max game type enum value
+        GameError::InvalidPayload
+    );
+
+    // Security: Validate player is signer
+    require!(
+        ctx.accounts.player.is_signer,
+        GameError::Unauthorized
+    );
+
+    // Convert user_id String to fixed-size array
+    let user_id_bytes = user_id.as_bytes();
+    require!(
+        user_id_bytes.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    let mut user_id_array = [0u8; 64];
+    user_id_array[..user_id_bytes.len()].copy_from_slice(user_id_bytes);
+
+    // Security: User account must belong to this user
+    require!(
+        user_account.user_id == user_id_array,
+        GameError::Unauthorized
+    );
+
+    // Security: Banned or suspended users can't queue
+    require_not_sanctioned(&ctx.accounts.sanction, &clock)?;
+
+    // First enqueue initializes the queue for this game type
+    queue.game_type = game_type;
+
+    // Security: Rejects duplicates and a full queue
+    queue.push(QueueEntry {
+        user_id: user_id_array,
+        tier: user_account.current_tier,
+        enqueued_at: clock.unix_timestamp,
+        wallet: ctx.accounts.player.key(),
+    })?;
+    queue.last_updated = clock.unix_timestamp;
+
+    telemetry!(
+        "Player {} queued for game type {} (tier={}, {} waiting)",
+        user_id,
+        game_type,
+        user_account.current_tier,
+        queue.entry_count
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(game_type: u8, user_id: String)]
+pub struct EnqueuePlayer<'info> {
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = MatchQueue::MAX_SIZE,
+        seeds = [b"match_queue".as_ref(), &[game_type]],
+        bump
+    )]
+    pub match_queue: Box<Account<'info, MatchQueue>>,
+    
+    #[account(
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    /// CHECK: User's sanction PDA (may not exist); checked by require_not_sanctioned
+    #[account(
+        seeds = [b"sanction", user_id.as_bytes()],
+        bump
+    )]
+    pub sanction: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::state::{CosmeticRegistry, CosmeticKind, PlayerCosmetics, ConfigAccount};
+use crate::error::GameError;
+
+/// Equips a registered cosmetic (or resets a slot to default with item_id = 0).
+/// Authority-gated (ConfigAccount.authority): ownership is tracked in database until purchases
+/// settle on-chain, so the backend submits the equip after checking the user owns the item.
+pub fn handler(ctx: Context<EquipCosmetic>, user_id: String, kind: u8, item_id: u16) -> Result<()> {
+    let user_id_bytes = user_id.as_bytes();
+    require!(
+        !user_id_bytes.is_empty() && user_id_bytes.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    let mut user_id_array = [0u8; 64];
+    user_id_array[..user_id_bytes.len()].copy_from_slice(user_id_bytes);
+    
+    let config = &ctx.accounts.config_account;
+    let clock = Clock::get()?;
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        GameError::Unauthorized
+    );
+    
+    let slot = CosmeticKind::from_u8(kind).ok_or(GameError::InvalidCosmetic)?;
+    if item_id != 0 {
+        let item = ctx.accounts.registry
+            .find_item(item_id)
+            .ok_or(GameError::InvalidCosmetic)?;
+        require!(
+            item.enabled && item.kind == kind,
+            GameError::InvalidCosmetic
+        );
+    }
+    
+    let cosmetics = &mut ctx.accounts.player_cosmetics;
+    cosmetics.user_id = user_id_array;
+    cosmetics.set_equipped(slot, item_id);
+    cosmetics.updated_at = clock.unix_timestamp;
+    
+    telemetry!("Cosmetic equipped: user={}, kind={}, item_id={}", user_id, kind, item_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(user_id: String)]
+pub struct EquipCosmetic<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PlayerCosmetics::MAX_SIZE,
+        seeds = [b"cosmetics", user_id.as_bytes()],
+        bump
+    )]
+    pub player_cosmetics: Account<'info, PlayerCosmetics>,
+    
+    #[account(
+        seeds = [b"cosmetic_registry"],
+        bump
+    )]
+    pub registry: Box<Account<'info, CosmeticRegistry>>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
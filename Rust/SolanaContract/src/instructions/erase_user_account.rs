@@ -0,0 +1,210 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::{
+    require_not_sanctioned, UserAccount, ConfigAccount, Dispute, GameLeaderboard, Match, PaymentReceipt,
+    PaymentReversal, PlayerRating, Sanction, Trophy,
+};
+use crate::events::UserAccountErased;
+use crate::error::GameError;
+
+/// Right-to-erasure: removes the user's Firebase UID from on-chain state while keeping their
+/// aggregate stats. Authority-gated (ConfigAccount.authority).
+/// The stats move to a tombstone UserAccount at ["erased_user", anon_key] whose user_id is
+/// UserAccount::erased_user_id(anon_key) (anon_key = salted hash of the UID, computed
+/// off-chain), and the original ["user_account", user_id] PDA is closed to the authority, as
+/// are the user's PlayerRating and (lifted or expired) Sanction, whose addresses derive from the UID.
+/// Remaining accounts: writable accounts that still hold the raw UID, renamed to the anonymized
+/// id in place: GameLeaderboard shards (historical ranks and season rewards then resolve to the
+/// tombstone), ended Matches (their transcript and match_hash still commit to the original
+/// id, which only the off-chain archive holds), resolved Disputes, Trophies, PaymentReceipts
+/// and PaymentReversals. The user must have left their guild, have no open disputes and not be
+/// under an active sanction.
+pub fn handler(
+    ctx: Context<EraseUserAccount>,
+    user_id: String,
+    anon_key: [u8; 32],
+) -> Result<()> {
+    // Convert String to fixed-size array immediately (optimization)
+    let user_id_bytes = user_id.as_bytes();
+    require!(
+        user_id_bytes.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    let mut user_id_array = [0u8; 64];
+    user_id_array[..user_id_bytes.len()].copy_from_slice(user_id_bytes);
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config_account.authority,
+        GameError::Unauthorized
+    );
+    
+    let user_account = &ctx.accounts.user_account;
+    require!(
+        !user_account.in_guild() && user_account.active_disputes == 0,
+        GameError::ErasureBlocked
+    );
+    // Security: Erasure can't be used to shed a ban or suspension
+    let clock = Clock::get()?;
+    require_not_sanctioned(&ctx.accounts.sanction, &clock)?;
+    
+    // Tombstone: same stats, anonymized id, no linked wallet
+    let erased_id = UserAccount::erased_user_id(&anon_key);
+    let tombstone = &mut ctx.accounts.erased_account;
+    tombstone.set_inner(UserAccount::clone(user_account));
+    tombstone.user_id = erased_id;
+    tombstone.wallet = Pubkey::default();
+    tombstone.wallet_linked_at = 0;
+    
+    // Companion PDAs derived from the UID are closed rather than renamed
+    let authority_info = ctx.accounts.authority.to_account_info();
+    let mut accounts_closed = 0u8;
+    if close_if_exists::<PlayerRating>(&ctx.accounts.player_rating, &authority_info)? {
+        accounts_closed += 1;
+    }
+    if close_if_exists::<Sanction>(&ctx.accounts.sanction, &authority_info)? {
+        accounts_closed += 1;
+    }
+    
+    let (mut entries_renamed, mut records_renamed) = (0u8, 0u8);
+    for account_info in ctx.remaining_accounts.iter() {
+        require!(
+            account_info.owner == &crate::ID && account_info.is_writable,
+            GameError::InvalidErasureAccount
+        );
+        let discriminator: [u8; 8] = account_info
+            .try_borrow_data()?
+            .get(..8)
+            .and_then(|d| d.try_into().ok())
+            .ok_or(GameError::InvalidErasureAccount)?;
+        let raw_id = &user_id_array;
+        if discriminator == GameLeaderboard::DISCRIMINATOR {
+            require!(
+                rename::<GameLeaderboard>(account_info, |shard| shard.rename_user(raw_id, erased_id))?,
+                GameError::InvalidLeaderboardShard
+            );
+            entries_renamed = entries_renamed.saturating_add(1);
+            continue;
+        }
+        let renamed = if discriminator == Match::DISCRIMINATOR {
+            rename::<Match>(account_info, |m| m.is_ended() && m.rename_player(raw_id, erased_id))?
+        } else if discriminator == Dispute::DISCRIMINATOR {
+            rename::<Dispute>(account_info, |dispute| {
+                if !dispute.is_resolved() {
+                    return false;
+                }
+                let flagger = rename_id(&mut dispute.flagger_user_id, raw_id, erased_id);
+                rename_id(&mut dispute.defendant_user_id, raw_id, erased_id) || flagger
+            })?
+        } else if discriminator == Trophy::DISCRIMINATOR {
+            rename::<Trophy>(account_info, |trophy| rename_id(&mut trophy.user_id, raw_id, erased_id))?
+        } else if discriminator == PaymentReceipt::DISCRIMINATOR {
+            rename::<PaymentReceipt>(account_info, |receipt| rename_id(&mut receipt.user_id, raw_id, erased_id))?
+        } else if discriminator == PaymentReversal::DISCRIMINATOR {
+            rename::<PaymentReversal>(account_info, |reversal| rename_id(&mut reversal.user_id, raw_id, erased_id))?
+        } else {
+            false
+        };
+        require!(renamed, GameError::InvalidErasureAccount);
+        records_renamed = records_renamed.saturating_add(1);
+    }
+    
+    emit!(UserAccountErased {
+        erased_account: tombstone.key(),
+        anon_key,
+        entries_renamed,
+        records_renamed,
+        accounts_closed,
+    });
+    
+    telemetry!("User account erased: tombstone {} ({} leaderboard entries and {} records renamed, {} accounts closed)",
+         tombstone.key(), entries_renamed, records_renamed, accounts_closed);
+    Ok(())
+}
+
+/// Closes a companion PDA to `destination` if it was ever created (type checked by discriminator).
+fn close_if_exists<T: AccountDeserialize>(info: &AccountInfo, destination: &AccountInfo) -> Result<bool> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return Ok(false);
+    }
+    T::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    **destination.try_borrow_mut_lamports()? += info.lamports();
+    **info.try_borrow_mut_lamports()? = 0;
+    info.assign(&System::id());
+    info.realloc(0, false)?;
+    Ok(true)
+}
+
+fn rename_id(id: &mut [u8; 64], raw_id: &[u8; 64], erased_id: [u8; 64]) -> bool {
+    if *id != *raw_id {
+        return false;
+    }
+    *id = erased_id;
+    true
+}
+
+/// Loads a remaining account as `T`, applies `rename` and writes it back when it returns true.
+fn rename<T: AccountSerialize + AccountDeserialize>(
+    account_info: &AccountInfo,
+    rename: impl FnOnce(&mut T) -> bool,
+) -> Result<bool> {
+    let mut account = {
+        let data = account_info.try_borrow_data()?;
+        Box::new(T::try_deserialize(&mut &data[..])?)
+    };
+    if !rename(&mut account) {
+        return Ok(false);
+    }
+    let mut data = account_info.try_borrow_mut_data()?;
+    account.try_serialize(&mut &mut data[..])?;
+    Ok(true)
+}
+
+#[derive(Accounts)]
+#[instruction(user_id: String, anon_key: [u8; 32])]
+pub struct EraseUserAccount<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: Box<Account<'info, UserAccount>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = UserAccount::MAX_SIZE,
+        seeds = [b"erased_user", anon_key.as_ref()],
+        bump
+    )]
+    pub erased_account: Box<Account<'info, UserAccount>>,
+    
+    /// CHECK: The user's ["player_rating", user_id] PDA; closed if it exists
+    #[account(
+        mut,
+        seeds = [b"player_rating", user_id.as_bytes()],
+        bump
+    )]
+    pub player_rating: UncheckedAccount<'info>,
+    
+    /// CHECK: The user's ["sanction", user_id] PDA; closed if it exists (must not be active)
+    #[account(
+        mut,
+        seeds = [b"sanction", user_id.as_bytes()],
+        bump
+    )]
+    pub sanction: UncheckedAccount<'info>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, match_id_seed};
+use crate::events::{MatchEnded, ScheduledMatchExpired};
+use crate::error::GameError;
+
+/// Voids a scheduled match that wasn't started within Match::SCHEDULE_GRACE_SECONDS of its
+/// scheduled_start. Permissionless crank, so players aren't left waiting on the coordinator.
+/// The match ends as EndReason::ForceEnded (reason FORCE_END_SCHEDULE_MISSED) with no winner.
+/// Entry costs and wagers are held off-chain, so the refund to every joined seat is driven by
+/// ScheduledMatchExpired, as with force_end_match.
+pub fn handler(ctx: Context<ExpireScheduledMatch>, match_id: String) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Only scheduled matches still waiting to start, once the grace window has passed
+    require!(
+        match_account.phase == 0 && !match_account.is_ended(),
+        GameError::InvalidPhase
+    );
+    require!(
+        match_account.schedule_missed(clock.unix_timestamp),
+        GameError::ScheduledStartNotReached
+    );
+
+    match_account.force_end(Match::FORCE_END_SCHEDULE_MISSED, clock.unix_timestamp);
+
+    emit!(ScheduledMatchExpired {
+        match_account: match_account.key(),
+        scheduled_start: match_account.scheduled_start,
+        player_count: match_account.player_count,
+        ended_at: match_account.ended_at,
+    });
+    emit!(MatchEnded::from_match(match_account.key(), match_account));
+
+    telemetry!("Scheduled match {} expired unstarted ({} players refunded)", match_id, match_account.player_count);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct ExpireScheduledMatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    pub cranker: Signer<'info>,
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::state::UserAccount;
+use crate::error::GameError;
+
+/// Grows a UserAccount created under an older layout to the current UserAccount::MAX_SIZE.
+/// The added bytes are zeroed, so appended fields start at their zero defaults, and
+/// schema_version is set to UserAccount::SCHEMA_VERSION. Lets new fields ship without a
+/// migrate-everything event: accounts are extended lazily (e.g. by the backend before the
+/// user's next instruction). Permissionless: the payer funds the extra rent and no existing
+/// field changes. A no-op for accounts already on the current schema.
+pub fn handler(ctx: Context<ExtendUserAccount>, user_id: String) -> Result<()> {
+    let info = ctx.accounts.user_account.to_account_info();
+
+    // Security: Must be an initialized UserAccount of this program (PDA checked by seeds)
+    require!(info.owner == &crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+    let from_version = {
+        let data = info.try_borrow_data()?;
+        UserAccount::try_deserialize_versioned(&data)?;
+        UserAccount::schema_version_of(&data)
+    };
+    require!(
+        from_version <= UserAccount::SCHEMA_VERSION,
+        GameError::UnsupportedSchemaVersion
+    );
+    if from_version == UserAccount::SCHEMA_VERSION && info.data_len() >= UserAccount::MAX_SIZE {
+        telemetry!("User account {} already on schema {}", user_id, from_version);
+        return Ok(());
+    }
+
+    // Top up rent for the new size
+    let rent_lamports = Rent::get()?.minimum_balance(UserAccount::MAX_SIZE);
+    let shortfall = rent_lamports.saturating_sub(info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    if info.data_len() < UserAccount::MAX_SIZE {
+        info.realloc(UserAccount::MAX_SIZE, true)?;
+    }
+    UserAccount::stamp_schema_version(&mut info.try_borrow_mut_data()?);
+
+    telemetry!(
+        "User account {} extended: schema {} -> {} ({} bytes)",
+        user_id,
+        from_version,
+        UserAccount::SCHEMA_VERSION,
+        UserAccount::MAX_SIZE
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(user_id: String)]
+pub struct ExtendUserAccount<'info> {
+    /// CHECK: UserAccount of any schema version (Account<UserAccount> can't load one shorter
+    /// than the current layout); owner and discriminator checked in the handler
+    #[account(
+        mut,
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
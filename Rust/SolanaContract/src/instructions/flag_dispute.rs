@@ -1,15 +1,23 @@
 use anchor_lang::prelude::*;
-use crate::state::{Dispute, ValidatorVote, DisputeResolution, ConfigAccount};
+use anchor_lang::solana_program::hash::hashv;
+use crate::state::{Dispute, Match, ValidatorVote, DisputeResolution, ConfigAccount, GlobalStats, UserAccount, SignerRegistry, match_id_seed};
+use crate::events::DisputeFlagged;
 use crate::error::GameError;
 
 /// Flags a dispute with GP deposit.
 /// Per spec Section 23: Dispute deposit system using GP (Game Points) instead of SOL.
 /// GP is deducted off-chain in database before calling this instruction.
 /// This instruction records the GP deposit on-chain for tracking.
+/// Only matches in Showdown or Ended can be disputed, and an ended match only within
+/// ConfigAccount::dispute_window() seconds of ended_at.
+/// Anti-griefing: one dispute per (match, flagger) (the Dispute PDA), at most
+/// ConfigAccount::max_active_disputes() unresolved disputes per user, and a deposit that
+/// doubles with each dispute the user already filed this season (UserAccount::dispute_deposit_for).
 pub fn handler(
     ctx: Context<FlagDispute>,
     match_id: String,
     user_id: String,  // Firebase UID of flagger (for GP tracking)
+    defendant_user_id: String,  // Firebase UID of accused player (empty = match-level dispute)
     reason: u8,
     evidence_hash: [u8; 32],
     gp_deposit: u32,  // GP deposit amount (already deducted off-chain)
@@ -24,12 +32,32 @@ pub fn handler(
         GameError::Unauthorized
     );
 
-    // Security: Validate match_id is valid UUID
+    // Security: Validate match_id matches the (existing) match account
+    let match_account = &ctx.accounts.match_account;
+    let match_id_bytes = match_id.as_bytes();
     require!(
-        match_id.len() == 36,
-        GameError::InvalidPayload
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Disputes follow play: a showdown in progress or an ended match
+    require!(
+        match_account.phase == 2 || match_account.phase == 3,
+        GameError::InvalidPhase
     );
 
+    // Security: Ended matches can only be disputed within the dispute window
+    if match_account.phase == 2 {
+        let window_closes_at = match_account.ended_at
+            .checked_add(config.dispute_window() as i64)
+            .ok_or(GameError::Overflow)?;
+        require!(
+            clock.unix_timestamp <= window_closes_at,
+            GameError::DisputeWindowClosed
+        );
+    }
+
     // Security: Validate reason bounds
     require!(
         reason <= 4,  // DisputeReason::Other
@@ -42,30 +70,56 @@ pub fn handler(
         GameError::InvalidPayload
     );
 
-    // Security: Validate GP deposit matches config requirement
+    // Anti-griefing: Cap the flagger's open disputes
+    let flagger_account = &mut ctx.accounts.flagger_account;
+    require!(
+        flagger_account.active_disputes < config.max_active_disputes(),
+        GameError::TooManyActiveDisputes
+    );
+
+    // Security: Validate GP deposit matches config requirement (escalates for repeat flaggers)
     require!(
-        gp_deposit >= config.dispute_deposit_gp,
+        gp_deposit >= flagger_account.dispute_deposit_for(config.dispute_deposit_gp, config.current_season_id),
         GameError::InsufficientGPForDispute
     );
+    flagger_account.record_dispute_filed(config.current_season_id);
 
     // Convert match_id and user_id to fixed-size arrays
-    let match_id_bytes = match_id.as_bytes();
     let mut match_id_array = [0u8; 36];
     match_id_array[..36].copy_from_slice(&match_id_bytes[..36.min(match_id_bytes.len())]);
 
     let user_id_bytes = user_id.as_bytes();
     require!(
         user_id_bytes.len() <= 64,
-        GameError::InvalidPayload
+        GameError::UserIdTooLong
     );
     let mut user_id_array = [0u8; 64];
     let copy_len = user_id_bytes.len().min(64);
     user_id_array[..copy_len].copy_from_slice(&user_id_bytes[..copy_len]);
 
+    let defendant_bytes = defendant_user_id.as_bytes();
+    require!(
+        defendant_bytes.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    // Security: Players can't dispute themselves (flagger and defendant accounts must differ)
+    require!(
+        defendant_bytes != user_id_bytes,
+        GameError::InvalidPayload
+    );
+    // Security: A named defendant must be seated in the match
+    require!(
+        defendant_bytes.is_empty() || match_account.find_player_index(defendant_bytes).is_some(),
+        GameError::PlayerNotInMatch
+    );
+    let mut defendant_array = [0u8; 64];
+    defendant_array[..defendant_bytes.len()].copy_from_slice(defendant_bytes);
+
     // Initialize dispute
     dispute.match_id = match_id_array;
     dispute.flagger = ctx.accounts.flagger.key();
     dispute.flagger_user_id = user_id_array;
+    dispute.defendant_user_id = defendant_array;
     dispute.reason = reason;
     dispute.evidence_hash = evidence_hash;
     dispute.gp_deposit = gp_deposit;
@@ -79,27 +133,68 @@ pub fn handler(
         timestamp: 0,
     }; 10]; // Initialize with default values
     dispute.vote_count = 0;
+    
+    // One registered validator is answerable for the dispute (enforce_inactivity charges it alone);
+    // the pick mixes in the slot so a flagger can't choose it by grinding keys
+    let assignment_seed = hashv(&[dispute.key().as_ref(), &clock.slot.to_le_bytes()]).to_bytes();
+    dispute.assigned_validator = ctx.accounts.signer_registry
+        .pick_validator(&assignment_seed)
+        .unwrap_or_default();
+    ctx.accounts.global_stats.record_dispute(clock.unix_timestamp);
 
-    msg!("Dispute flagged: match {}, reason {}, by {} (GP deposit: {})", 
-         match_id, reason, user_id, gp_deposit);
+    emit!(DisputeFlagged {
+        dispute: dispute.key(),
+        reason,
+        has_defendant: dispute.has_defendant(),
+        evidence_hash,
+        gp_deposit,
+        assigned_validator: dispute.assigned_validator,
+    });
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(match_id: String)]
+#[instruction(match_id: String, user_id: String)]
 pub struct FlagDispute<'info> {
     #[account(
         init,
         payer = flagger,
         space = Dispute::MAX_SIZE,
-        seeds = [b"dispute", match_id.as_bytes(), flagger.key().as_ref()],
+        seeds = [b"dispute", match_id_seed(match_id.as_bytes()), flagger.key().as_ref()],
         bump
     )]
     pub dispute: Account<'info, Dispute>,
     
+    #[account(
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Box<Account<'info, Match>>,
+    
     /// ConfigAccount to check dispute_deposit_gp requirement
     pub config_account: Account<'info, ConfigAccount>,
     
+    /// Flagger's user account (open dispute count and season deposit escalation)
+    #[account(
+        mut,
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub flagger_account: Box<Account<'info, UserAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_stats"],
+        bump
+    )]
+    pub global_stats: Box<Account<'info, GlobalStats>>,
+    
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub signer_registry: Box<Account<'info, SignerRegistry>>,
+    
     #[account(mut)]
     pub flagger: Signer<'info>,
     
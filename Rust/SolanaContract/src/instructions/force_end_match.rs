@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, SignerRegistry, match_id_seed};
+use crate::events::{MatchEnded, MatchForceEnded};
+use crate::error::GameError;
+
+/// Last-resort live-ops override: the admin council (signer registry authority) voids a match
+/// stuck in a state no timeout or failover path can clear. Works from any phase but Ended.
+/// `reason` is mandatory (Match::FORCE_END_* codes) and recorded on the match.
+/// The match ends as EndReason::ForceEnded with no winner (no trophy, no scores to settle).
+/// Entry costs are held off-chain (game_payment only records them), so the refund is driven
+/// by MatchForceEnded: the GP ledger refunds every seat's entry cost for the match.
+pub fn handler(ctx: Context<ForceEndMatch>, match_id: String, reason: u8) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+    
+    // Security: Only the admin council overrides a match
+    require!(
+        ctx.accounts.council.key() == ctx.accounts.signer_registry.authority,
+        GameError::Unauthorized
+    );
+    
+    // Security: A reason code is mandatory
+    require!(
+        (Match::FORCE_END_STUCK_STATE..=Match::FORCE_END_OTHER).contains(&reason),
+        GameError::InvalidPayload
+    );
+    
+    // Security: Ended matches are final
+    require!(
+        match_account.phase != 2 && !match_account.is_ended(),
+        GameError::InvalidPhase
+    );
+    
+    let previous_phase = match_account.phase;
+    match_account.force_end(reason, clock.unix_timestamp);
+    
+    emit!(MatchForceEnded {
+        match_account: match_account.key(),
+        council: ctx.accounts.council.key(),
+        reason,
+        previous_phase,
+        player_count: match_account.player_count,
+        ended_at: match_account.ended_at,
+    });
+    emit!(MatchEnded::from_match(match_account.key(), match_account));
+    
+    telemetry!("Match {} force-ended by {} (reason {}, was phase {})",
+         match_id, ctx.accounts.council.key(), reason, previous_phase);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct ForceEndMatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    pub council: Signer<'info>,
+}
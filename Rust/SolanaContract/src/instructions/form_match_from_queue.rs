@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, MatchQueue, GameRegistry, GlobalStats, SignerRegistry, SignerRole, require_not_sanctioned, require_sanction_pda, match_id_seed};
+use crate::instructions::create_match::{init_match, parse_game_type, parse_match_id, MatchSettings};
+use crate::events::PlayerJoined;
+use crate::error::GameError;
+
+pub fn handler(
+    ctx: Context<FormMatchFromQueue>,
+    game_type: u8,
+    match_id: String,
+    seed: u64,
+    player_count: u8,
+    tier_band: u8, // Max tier distance from the oldest queued player
+) -> Result<()> {
+    let queue = &mut ctx.accounts.match_queue;
+    let match_account = &mut ctx.accounts.match_account;
+    let clock = Clock::get()?;
+
+    // Security: Only registered coordinators can form matches
+    require!(
+        ctx.accounts.signer_registry.get_role(&ctx.accounts.coordinator.key()) == Some(SignerRole::Coordinator),
+        GameError::Unauthorized
+    );
+
+    let match_id_array = parse_match_id(&match_id)?;
+
+    // Security: Player count must fit the game's bounds
+    let game_config = parse_game_type(game_type)?.get_config();
+    require!(
+        player_count >= game_config.min_players && player_count <= game_config.max_players,
+        GameError::InvalidPayload
+    );
+
+    let selected = queue.select_banded(player_count as usize, tier_band)
+        .ok_or(GameError::NotEnoughQueuedPlayers)?;
+
+    // Security: Players sanctioned since they queued aren't seated (dequeue them first).
+    // Remaining accounts: each selected player's sanction PDA, in seat order
+    require!(
+        ctx.remaining_accounts.len() == selected.len(),
+        GameError::InvalidPayload
+    );
+    for (&index, sanction_info) in selected.iter().zip(ctx.remaining_accounts) {
+        require_sanction_pda(sanction_info, &queue.entries[index].user_id)?;
+        require_not_sanctioned(sanction_info, &clock)?;
+    }
+
+    // Same initialization (and MatchCreated event) as create_match: unlisted, single round
+    init_match(
+        match_account,
+        &mut ctx.accounts.global_stats,
+        &ctx.accounts.game_registry,
+        None,
+        ctx.accounts.coordinator.key(),
+        match_id_array,
+        MatchSettings { game_type, seed, rounds_to_play: 1, join_secret_hash: None, player_consent_start: false },
+    )?;
+
+    // Seat selected players in queue order
+    for &index in selected.iter() {
+        let player_index = match_account.add_player(queue.entries[index].user_id, queue.entries[index].wallet)?;
+        emit!(PlayerJoined {
+            match_account: match_account.key(),
+            player_index: player_index as u8,
+            player_count: match_account.player_count,
+            invited: false,
+            is_bot: false,
+        });
+    }
+
+    // Pop selected players (highest index first so earlier indices stay valid)
+    for &index in selected.iter().rev() {
+        queue.remove_at(index);
+    }
+
+    queue.matches_formed = queue.matches_formed
+        .checked_add(1)
+        .ok_or(GameError::Overflow)?;
+    queue.last_updated = clock.unix_timestamp;
+
+    telemetry!(
+        "Match {} formed from queue: game_type={}, players={}, {} still waiting",
+        match_id,
+        game_type,
+        player_count,
+        queue.entry_count
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(game_type: u8, match_id: String)]
+pub struct FormMatchFromQueue<'info> {
+    #[account(
+        mut,
+        seeds = [b"match_queue".as_ref(), &[game_type]],
+        bump
+    )]
+    pub match_queue: Box<Account<'info, MatchQueue>>,
+    
+    #[account(
+        init,
+        payer = coordinator,
+        space = Match::MAX_SIZE,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Box<Account<'info, Match>>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_stats"],
+        bump
+    )]
+    pub global_stats: Box<Account<'info, GlobalStats>>,
+    
+    #[account(
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub game_registry: Box<Account<'info, GameRegistry>>,
+    
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    #[account(mut)]
+    pub coordinator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -1,35 +1,74 @@
 use anchor_lang::prelude::*;
-use crate::state::{UserAccount, ConfigAccount};
+use crate::state::{UserAccount, ConfigAccount, GameRegistry, Match, require_not_sanctioned, match_id_seed};
 use crate::error::GameError;
 
 /// Records game payment (GP cost).
 /// Per spec Section 20.1.3: Game payment flow.
 /// Note: GP balance check happens off-chain in database. This instruction only updates stats.
+/// The GP cost is per game type: the registry entry's gp_cost for the match's game_type,
+/// or ConfigAccount.gp_cost_per_game when the game has no override. `gp_cost` (the amount
+/// deducted off-chain) must equal it.
+/// Game pass: while the user's subscription tier still has free plays left today
+/// (ConfigAccount.free_plays_per_day), the play is free and `gp_cost` must be 0.
 /// Note: String params converted to fixed arrays immediately for performance.
-pub fn handler(ctx: Context<StartGameWithGP>, match_id: String, user_id: String) -> Result<()> {
+pub fn handler(ctx: Context<StartGameWithGP>, match_id: String, user_id: String, gp_cost: u32, op_nonce: u64) -> Result<()> {
     // Convert String to fixed-size arrays immediately (optimization)
     let match_id_bytes = match_id.as_bytes();
     require!(
         match_id_bytes.len() == 36,
-        GameError::InvalidPayload
+        GameError::MatchIdMismatch
     );
     
     let user_id_bytes = user_id.as_bytes();
     require!(
         user_id_bytes.len() <= 64,
-        GameError::InvalidPayload
+        GameError::UserIdTooLong
     );
     
     let user_account = &mut ctx.accounts.user_account;
-    let _config = &ctx.accounts.config_account;
+    
+    // Idempotency: a retried operation can't be applied twice
+    user_account.use_op_nonce(op_nonce)?;
+    
+    let config = &ctx.accounts.config_account;
+    let clock = Clock::get()?;
+    
+    // Security: Banned or suspended users can't start paid games
+    require_not_sanctioned(&ctx.accounts.sanction, &clock)?;
+
+    // Game pass: subscription tiers with free plays skip the GP debit (daily cap)
+    user_account.apply_pending_subscription_changes(&clock);
+    let daily_free_plays = config.free_plays_for_tier(user_account.subscription_tier);
+    let free_play = user_account.use_free_play(daily_free_plays, &clock);
+
+    // Security: The charged amount must match the on-chain price for this game type
+    let game_type = ctx.accounts.match_account.game_type;
+    let game_gp_cost = ctx.accounts.game_registry.gp_cost_for(game_type, config.gp_cost_per_game);
+    let expected_gp_cost = if free_play { 0 } else { game_gp_cost };
+    require!(
+        gp_cost == expected_gp_cost,
+        GameError::InvalidGameFee
+    );
+
+    // Security: Brand-new accounts can't enter high-stakes matches (anti-smurf)
+    if config.high_stakes_gp_threshold > 0 && game_gp_cost >= config.high_stakes_gp_threshold {
+        require!(
+            user_account.account_age(&clock) >= config.min_account_age_high_stakes,
+            GameError::AccountTooNew
+        );
+    }
     
     // Update stats (GP balance deducted in database before calling this)
     user_account.games_played = user_account.games_played
         .checked_add(1)
         .ok_or(GameError::Overflow)?;
     
+    // Count toward placement (user appears on leaderboards once complete)
+    if user_account.record_placement_match() {
+        telemetry!("Placement complete");
+    }
+    
     // Update season stats
-    let clock = Clock::get()?;
     let current_season_id = (clock.unix_timestamp / 604800) as u64; // 7 days in seconds
     
     // Reset season stats if new season
@@ -44,7 +83,10 @@ pub fn handler(ctx: Context<StartGameWithGP>, match_id: String, user_id: String)
             .ok_or(GameError::Overflow)?;
     }
     
-    msg!("Game started: match_id={}, games_played={}", match_id, user_account.games_played);
+    if free_play {
+        telemetry!("Free play used: {}/{} today", user_account.free_play_used, daily_free_plays);
+    }
+    telemetry!("Game started: match_id={}, games_played={}", match_id, user_account.games_played);
     Ok(())
 }
 
@@ -65,6 +107,26 @@ pub struct StartGameWithGP<'info> {
     )]
     pub config_account: Account<'info, ConfigAccount>,
     
+    #[account(
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    /// Game registry (read-only) for the per-game GP cost
+    #[account(
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub game_registry: Box<Account<'info, GameRegistry>>,
+    
+    /// CHECK: User's sanction PDA (may not exist); checked by require_not_sanctioned
+    #[account(
+        seeds = [b"sanction", user_id.as_bytes()],
+        bump
+    )]
+    pub sanction: UncheckedAccount<'info>,
+    
     pub system_program: Program<'info, System>,
 }
 
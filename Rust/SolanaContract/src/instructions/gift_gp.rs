@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use crate::state::{UserAccount, ConfigAccount, require_not_sanctioned};
+use crate::error::GameError;
+
+/// Records a GP gift from one user to another.
+/// Authority-gated (ConfigAccount.authority): the backend submits the gift after moving the
+/// balances in database. On-chain limits: sender daily cap (ConfigAccount.gift_daily_cap_gp),
+/// minimum sender account age, and a treasury fee (gift_fee_bps) taken from the gifted amount.
+/// Note: GP balances are updated in database. This instruction enforces limits and records totals.
+pub fn handler(ctx: Context<GiftGP>, from_user_id: String, to_user_id: String, amount: u64, op_nonce: u64) -> Result<()> {
+    require!(
+        from_user_id.len() <= 64 && to_user_id.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    require!(
+        from_user_id != to_user_id,
+        GameError::InvalidPayload
+    );
+    
+    let config = &ctx.accounts.config_account;
+    let clock = Clock::get()?;
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        GameError::Unauthorized
+    );
+    
+    // Gifting is disabled until a daily cap is configured
+    require!(
+        config.gift_daily_cap_gp > 0 && amount > 0,
+        GameError::InvalidPayload
+    );
+    require!(
+        config.gift_fee_bps <= ConfigAccount::MAX_FEE_BPS,
+        GameError::InvalidGiftPolicy
+    );
+    
+    // Security: Sanctioned users can't send or receive gifts
+    require_not_sanctioned(&ctx.accounts.from_sanction, &clock)?;
+    require_not_sanctioned(&ctx.accounts.to_sanction, &clock)?;
+    
+    let sender = &mut ctx.accounts.from_user_account;
+    
+    // Idempotency: a retried operation can't be applied twice
+    sender.use_op_nonce(op_nonce)?;
+    
+    // Anti-abuse: Fresh accounts can't gift (farm-and-funnel)
+    require!(
+        sender.account_age(&clock) >= config.gift_min_account_age,
+        GameError::AccountTooNew
+    );
+    
+    // Anti-abuse: Daily cap per sender
+    let gifted_today = sender.gp_gifted_today(&clock)
+        .checked_add(amount)
+        .ok_or(GameError::Overflow)?;
+    require!(
+        gifted_today <= config.gift_daily_cap_gp,
+        GameError::GiftLimitExceeded
+    );
+    
+    // Treasury fee comes out of the gift
+    let fee = amount
+        .checked_mul(config.gift_fee_bps as u64)
+        .ok_or(GameError::Overflow)?
+        / 10_000;
+    let received = amount
+        .checked_sub(fee)
+        .ok_or(GameError::InvalidGiftPolicy)?;
+    
+    sender.gift_day = UserAccount::day_index(&clock);
+    sender.gp_gifted_day_total = gifted_today;
+    sender.lifetime_gp_gifted = sender.lifetime_gp_gifted
+        .checked_add(amount)
+        .ok_or(GameError::Overflow)?;
+    
+    let recipient = &mut ctx.accounts.to_user_account;
+    recipient.lifetime_gp_received = recipient.lifetime_gp_received
+        .checked_add(received)
+        .ok_or(GameError::Overflow)?;
+    
+    telemetry!("GP gift: {} -> {}: {} GP ({} GP fee)", from_user_id, to_user_id, received, fee);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(from_user_id: String, to_user_id: String)]
+pub struct GiftGP<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_account", from_user_id.as_bytes()],
+        bump
+    )]
+    pub from_user_account: Account<'info, UserAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"user_account", to_user_id.as_bytes()],
+        bump
+    )]
+    pub to_user_account: Account<'info, UserAccount>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    /// CHECK: Sender's sanction PDA (may not exist); checked by require_not_sanctioned
+    #[account(
+        seeds = [b"sanction", from_user_id.as_bytes()],
+        bump
+    )]
+    pub from_sanction: UncheckedAccount<'info>,
+    
+    /// CHECK: Recipient's sanction PDA (may not exist); checked by require_not_sanctioned
+    #[account(
+        seeds = [b"sanction", to_user_id.as_bytes()],
+        bump
+    )]
+    pub to_sanction: UncheckedAccount<'info>,
+    
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::state::{UserAccount, ConfigAccount, CreditAdjustment, CreditAdjustmentKind};
+use crate::error::GameError;
+
+/// Grants AI credits (AC) to a user as support compensation.
+/// Authority-gated (ConfigAccount.authority). Records a CreditAdjustment PDA so
+/// every grant is auditable on-chain instead of being a silent database edit.
+/// Note: AC balance is credited in database. This instruction records the grant.
+pub fn handler(
+    ctx: Context<GrantAICredits>,
+    user_id: String,
+    grant_id: [u8; 32],  // SHA-256 of the off-chain support ticket / grant id
+    ac_amount: u64,
+    reason: u8,  // Support reason code (off-chain enum)
+) -> Result<()> {
+    // Convert String to fixed-size array immediately (optimization)
+    let user_id_bytes = user_id.as_bytes();
+    require!(
+        user_id_bytes.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    let mut user_id_array = [0u8; 64];
+    let copy_len = user_id_bytes.len().min(64);
+    user_id_array[..copy_len].copy_from_slice(&user_id_bytes[..copy_len]);
+    
+    let config = &ctx.accounts.config_account;
+    let clock = Clock::get()?;
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        GameError::Unauthorized
+    );
+    
+    require!(
+        ac_amount > 0,
+        GameError::InvalidPayload
+    );
+    
+    let user_account = &mut ctx.accounts.user_account;
+    user_account.total_ac_granted = user_account.total_ac_granted
+        .checked_add(ac_amount)
+        .ok_or(GameError::Overflow)?;
+    
+    // Record audit trail
+    let adjustment = &mut ctx.accounts.credit_adjustment;
+    adjustment.user_id = user_id_array;
+    adjustment.reference_id = grant_id;
+    adjustment.kind = CreditAdjustmentKind::Grant as u8;
+    adjustment.ac_amount = ac_amount;
+    adjustment.reason = reason;
+    adjustment.authority = ctx.accounts.authority.key();
+    adjustment.created_at = clock.unix_timestamp;
+    
+    telemetry!("AI credits granted: {} AC (reason={})", ac_amount, reason);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(user_id: String, grant_id: [u8; 32])]
+pub struct GrantAICredits<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = CreditAdjustment::MAX_SIZE,
+        seeds = [b"credit_adjustment", user_id.as_bytes(), grant_id.as_ref()],
+        bump
+    )]
+    pub credit_adjustment: Account<'info, CreditAdjustment>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
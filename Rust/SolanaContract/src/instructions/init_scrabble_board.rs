@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, ScrabbleBoard, GameType, match_id_seed};
+use crate::error::GameError;
+
+/// Creates the ScrabbleBoard for a Scrabble match and commits the tile bag: `bag_head` is the
+/// head of the hash chain over the shuffled 100-tile bag (see ScrabbleBoard).
+/// Must be called by the match authority during the Dealing phase.
+pub fn handler(
+    ctx: Context<InitScrabbleBoard>,
+    match_id: String,
+    bag_head: [u8; 32],
+) -> Result<()> {
+    let match_account = &ctx.accounts.match_account;
+    let board = &mut ctx.accounts.scrabble_board;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Only the match authority (coordinator)
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Security: Scrabble matches only, committed before play starts
+    require!(
+        match_account.game_type == GameType::Scrabble as u8,
+        GameError::WrongGameType
+    );
+    require!(
+        match_account.phase == 0,
+        GameError::InvalidPhase
+    );
+    require!(
+        bag_head.iter().any(|&b| b != 0),
+        GameError::InvalidPayload
+    );
+
+    board.match_id = match_account.match_id;
+    board.commit_bag(bag_head);
+    board.authority = ctx.accounts.authority.key();
+    board.created_at = clock.unix_timestamp;
+
+    telemetry!("Scrabble board created for match {}", match_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct InitScrabbleBoard<'info> {
+    #[account(
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Box<Account<'info, Match>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = ScrabbleBoard::MAX_SIZE,
+        seeds = [b"scrabble_board", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub scrabble_board: Box<Account<'info, ScrabbleBoard>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::{CosmeticRegistry, ConfigAccount};
+use crate::error::GameError;
+
+/// Creates the cosmetic registry. Admin-only (ConfigAccount.authority), which also becomes
+/// the registry authority.
+pub fn handler(ctx: Context<InitializeCosmeticRegistry>) -> Result<()> {
+    let config = &ctx.accounts.config_account;
+    let clock = Clock::get()?;
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        GameError::Unauthorized
+    );
+    
+    let registry = &mut ctx.accounts.registry;
+    registry.authority = ctx.accounts.authority.key();
+    registry.item_count = 0;
+    registry.last_updated = clock.unix_timestamp;
+    
+    telemetry!("Cosmetic registry initialized");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeCosmeticRegistry<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = CosmeticRegistry::MAX_SIZE,
+        seeds = [b"cosmetic_registry"],
+        bump
+    )]
+    pub registry: Box<Account<'info, CosmeticRegistry>>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{GlobalStats, ConfigAccount};
+use crate::error::GameError;
+
+/// Creates the GlobalStats analytics account with all counters at zero.
+/// Admin-only (ConfigAccount.authority); instructions that update the counters need it to exist.
+pub fn handler(ctx: Context<InitializeGlobalStats>) -> Result<()> {
+    let config = &ctx.accounts.config_account;
+    let clock = Clock::get()?;
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        GameError::Unauthorized
+    );
+    
+    let stats = &mut ctx.accounts.global_stats;
+    stats.created_at = clock.unix_timestamp;
+    stats.last_updated = clock.unix_timestamp;
+    
+    telemetry!("Global stats initialized");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeGlobalStats<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = GlobalStats::MAX_SIZE,
+        seeds = [b"global_stats"],
+        bump
+    )]
+    pub global_stats: Box<Account<'info, GlobalStats>>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
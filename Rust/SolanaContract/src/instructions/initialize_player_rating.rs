@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::PlayerRating;
+use crate::error::GameError;
+
+pub fn handler(
+    ctx: Context<InitializePlayerRating>,
+    user_id: String,
+) -> Result<()> {
+    let player_rating = &mut ctx.accounts.player_rating;
+    let clock = Clock::get()?;
+
+    // Convert user_id String to fixed-size array
+    let user_id_bytes = user_id.as_bytes();
+    require!(
+        !user_id_bytes.is_empty() && user_id_bytes.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    let mut user_id_array = [0u8; 64];
+    user_id_array[..user_id_bytes.len()].copy_from_slice(user_id_bytes);
+
+    player_rating.initialize(user_id_array, clock.unix_timestamp);
+
+    telemetry!("Player rating initialized: {} (rating={})", user_id, PlayerRating::INITIAL_RATING);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(user_id: String)]
+pub struct InitializePlayerRating<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = PlayerRating::MAX_SIZE,
+        seeds = [b"player_rating", user_id.as_bytes()],
+        bump
+    )]
+    pub player_rating: Account<'info, PlayerRating>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, Invitation, match_id_seed};
+use crate::error::GameError;
+
+/// Invites a specific user to a match (friend challenge).
+/// Only the match authority can invite, before the match starts. The invitee joins with
+/// accept_invitation, which doesn't need the private match's join code.
+pub fn handler(
+    ctx: Context<InvitePlayer>,
+    match_id: String,
+    invitee_user_id: String,
+    expiry: i64, // Unix timestamp
+) -> Result<()> {
+    let match_account = &ctx.accounts.match_account;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate authority is match authority
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Security: Invites are only useful while the match is open
+    require!(match_account.phase == 0, GameError::InvalidPhase);
+    require!(match_account.can_join(), GameError::MatchFull);
+
+    require!(
+        expiry > clock.unix_timestamp,
+        GameError::InvalidTimestamp
+    );
+
+    let invitee_bytes = invitee_user_id.as_bytes();
+    require!(
+        !invitee_bytes.is_empty() && invitee_bytes.len() <= 64,
+        GameError::InvalidPayload
+    );
+    let mut invitee_array = [0u8; 64];
+    invitee_array[..invitee_bytes.len()].copy_from_slice(invitee_bytes);
+
+    let invitation = &mut ctx.accounts.invitation;
+    invitation.match_account = ctx.accounts.match_account.key();
+    invitation.invitee = invitee_array;
+    invitation.inviter = ctx.accounts.authority.key();
+    invitation.expires_at = expiry;
+    invitation.accepted = false;
+    invitation.created_at = clock.unix_timestamp;
+
+    telemetry!("Invitation: {} invited to match {} (expires {})", invitee_user_id, match_id, expiry);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String, invitee_user_id: String)]
+pub struct InvitePlayer<'info> {
+    #[account(
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = Invitation::MAX_SIZE,
+        seeds = [b"invitation", match_account.key().as_ref(), invitee_user_id.as_bytes()],
+        bump
+    )]
+    pub invitation: Account<'info, Invitation>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use crate::state::{Sanction, SanctionKind, Dispute, DisputeResolution, ConfigAccount, UserAccount, SignerRegistry, SignerRole};
+use crate::error::GameError;
+
+/// Issues (or replaces) a user's sanction.
+/// Authorized by the config authority (any kind and duration), or by a registered validator who
+/// voted on a dispute that was resolved against this user, who can only issue the sanction the
+/// strike schedule gives for the user's cheat strikes (ConfigAccount::scheduled_sanction). An
+/// active sanction is never replaced by a weaker or shorter one (lift_sanction first).
+pub fn handler(
+    ctx: Context<IssueSanction>,
+    user_id: String,
+    kind: u8,
+    reason: u8,
+    duration_seconds: i64, // 0 = no expiry
+) -> Result<()> {
+    let sanction = &mut ctx.accounts.sanction;
+    let clock = Clock::get()?;
+    let issuer = ctx.accounts.issuer.key();
+
+    // Security: Validate kind and reason bounds
+    let sanction_kind = match kind {
+        0 => SanctionKind::Warning,
+        1 => SanctionKind::Suspension,
+        2 => SanctionKind::Ban,
+        _ => return Err(GameError::InvalidAction.into()),
+    };
+    require!(reason <= 4, GameError::InvalidAction); // DisputeReason::Other
+    require!(duration_seconds >= 0, GameError::InvalidPayload);
+
+    // Convert user_id String to fixed-size array
+    let user_id_bytes = user_id.as_bytes();
+    require!(
+        !user_id_bytes.is_empty() && user_id_bytes.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    let mut user_id_array = [0u8; 64];
+    user_id_array[..user_id_bytes.len()].copy_from_slice(user_id_bytes);
+
+    // Security: Config authority, or a resolving validator of a dispute upheld against this user
+    let config = &ctx.accounts.config_account;
+    let dispute_key = match ctx.accounts.dispute.as_ref() {
+        Some(dispute) => {
+            require!(
+                dispute.get_resolution() == Some(DisputeResolution::ResolvedInFavorOfFlagger) &&
+                dispute.defendant_user_id == user_id_array,
+                GameError::Unauthorized
+            );
+            if issuer != config.authority {
+                let resolving_validator = dispute.has_vote_from(&issuer)
+                    && ctx.accounts.signer_registry.get_role(&issuer) == Some(SignerRole::Validator);
+                require!(resolving_validator, GameError::Unauthorized);
+
+                // Validators don't pick the penalty: kind and duration follow the strike schedule
+                let defendant = ctx.accounts.defendant_account
+                    .as_ref()
+                    .ok_or(GameError::InvalidPayload)?;
+                require!(
+                    config.scheduled_sanction(defendant.cheat_strikes) == Some((sanction_kind, duration_seconds)),
+                    GameError::Unauthorized
+                );
+            }
+            dispute.key()
+        }
+        None => {
+            require!(
+                issuer == config.authority,
+                GameError::Unauthorized
+            );
+            Pubkey::default()
+        }
+    };
+
+    let expires_at = if duration_seconds == 0 {
+        0
+    } else {
+        clock.unix_timestamp
+            .checked_add(duration_seconds)
+            .ok_or(GameError::Overflow)?
+    };
+    
+    // Never downgrade a stronger or longer sanction that is still in effect
+    require!(
+        !sanction.covers(sanction_kind, expires_at, &clock),
+        GameError::SanctionDowngrade
+    );
+    
+    sanction.user_id = user_id_array;
+    sanction.issue(
+        sanction_kind,
+        reason,
+        dispute_key,
+        issuer,
+        clock.unix_timestamp,
+        expires_at,
+    );
+
+    telemetry!(
+        "Sanction issued: {} kind={:?} reason={} expires_at={}",
+        user_id,
+        sanction.get_kind(),
+        reason,
+        sanction.expires_at
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(user_id: String)]
+pub struct IssueSanction<'info> {
+    #[account(
+        init_if_needed,
+        payer = issuer,
+        space = Sanction::MAX_SIZE,
+        seeds = [b"sanction", user_id.as_bytes()],
+        bump
+    )]
+    pub sanction: Account<'info, Sanction>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    /// Optional resolved dispute authorizing a dispute-outcome sanction
+    pub dispute: Option<Account<'info, Dispute>>,
+    
+    /// Sanctioned user's account (cheat strikes); required when a validator issues the sanction
+    #[account(
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub defendant_account: Option<Account<'info, UserAccount>>,
+    
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
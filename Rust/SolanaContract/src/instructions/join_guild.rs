@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::state::{Guild, UserAccount, ConfigAccount, require_not_sanctioned};
+use crate::error::GameError;
+
+/// Adds a user to a guild (capped at Guild::MAX_MEMBERS, one guild per user).
+/// The member's contribution counts from their season score at join time.
+/// Authority-gated (ConfigAccount.authority).
+pub fn handler(ctx: Context<JoinGuild>, name: String, user_id: String) -> Result<()> {
+    let config = &ctx.accounts.config_account;
+    let clock = Clock::get()?;
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        GameError::Unauthorized
+    );
+    
+    // Security: Banned or suspended users can't join guilds
+    require_not_sanctioned(&ctx.accounts.sanction, &clock)?;
+    
+    let user = &mut ctx.accounts.user_account;
+    let guild = &mut ctx.accounts.guild;
+    require!(!user.in_guild(), GameError::AlreadyInGuild);
+    require!(!guild.is_full(), GameError::GuildFull);
+    
+    let season_id = (clock.unix_timestamp / 604800) as u64; // 7 days in seconds
+    if season_id > guild.season_id {
+        guild.start_season(season_id);
+    }
+    let season_score = user.season_score_for(guild.season_id);
+    guild.add_member(user.user_id, season_score);
+    user.guild = guild.name;
+    
+    telemetry!("Guild {}: {} joined ({} members)", name, user_id, guild.member_count);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(name: String, user_id: String)]
+pub struct JoinGuild<'info> {
+    #[account(
+        mut,
+        seeds = [b"guild", name.as_bytes()],
+        bump
+    )]
+    pub guild: Box<Account<'info, Guild>>,
+    
+    #[account(
+        mut,
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    /// CHECK: User's sanction PDA (may not exist); checked by require_not_sanctioned
+    #[account(
+        seeds = [b"sanction", user_id.as_bytes()],
+        bump
+    )]
+    pub sanction: UncheckedAccount<'info>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    pub authority: Signer<'info>,
+}
@@ -1,16 +1,24 @@
 use anchor_lang::prelude::*;
-use crate::state::Match;
+use crate::state::{Match, OpenLobbyIndex, require_not_sanctioned, match_id_seed};
+use crate::instructions::create_match::sync_lobby_listing;
+use crate::events::PlayerJoined;
 use crate::error::GameError;
 
-pub fn handler(ctx: Context<JoinMatch>, match_id: String, user_id: String) -> Result<()> {
+pub fn handler(
+    ctx: Context<JoinMatch>,
+    match_id: String,
+    user_id: String,
+    is_bot: bool, // AI/bot seat (needs the match authority's co-signature as attestation)
+) -> Result<()> {
     let match_account = &mut ctx.accounts.match_account;
+    let clock = Clock::get()?;
     
     // Security: Validate match_id matches
     let match_id_bytes = match_id.as_bytes();
     require!(
         match_id_bytes.len() == 36 && 
         match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
-        GameError::InvalidPayload
+        GameError::MatchIdMismatch
     );
 
     // Security: Validate player is signer
@@ -23,54 +31,87 @@ pub fn handler(ctx: Context<JoinMatch>, match_id: String, user_id: String) -> Re
     require!(match_account.can_join(), GameError::MatchFull);
     require!(match_account.phase == 0, GameError::InvalidPhase);
 
+    let coordinator_approved = ctx.accounts.authority
+        .as_ref()
+        .map(|authority| authority.key() == match_account.authority)
+        .unwrap_or(false);
+
+    // Security: Only the match authority can attest a bot seat, so bots can't pass as humans
+    // on the human ladder and humans can't farm the bot ladder
+    require!(
+        !is_bot || coordinator_approved,
+        GameError::BotAttestationRequired
+    );
+
+    // Security: Private matches admit only players the coordinator co-signs for. It checks the
+    // join code off-chain (Match::join_secret_hash), so the code never appears in a transaction
+    require!(
+        !match_account.is_private() || coordinator_approved,
+        GameError::InvalidJoinSecret
+    );
+
+    // Security: Banned or suspended users can't join matches
+    require_not_sanctioned(&ctx.accounts.sanction, &clock)?;
+
     // Convert user_id String to fixed-size array
     let user_id_bytes = user_id.as_bytes();
     require!(
         user_id_bytes.len() <= 64,
-        GameError::InvalidPayload
+        GameError::UserIdTooLong
     );
     let mut user_id_array = [0u8; 64];
     let copy_len = user_id_bytes.len().min(64);
     user_id_array[..copy_len].copy_from_slice(&user_id_bytes[..copy_len]);
     
-    // Security: Check if player already joined (anti-cheat)
-    require!(
-        !match_account.has_player_id(&user_id_array),
-        GameError::PlayerNotInMatch
-    );
-
-    // Security: Validate bounds before adding player
-    let player_index = match_account.player_count as usize;
-    let max_players = match_account.get_max_players() as usize;
-    require!(
-        player_index < max_players && player_index < 10,
-        GameError::MatchFull
-    );
-    
-    // Add player to match
-    match_account.set_player_id(player_index, user_id_array);
-    match_account.player_count += 1;
-
-    // Check if all players joined (optimization: cache this check)
-    if match_account.player_count >= match_account.get_max_players() {
-        match_account.set_all_players_joined(true);
+    // Security: Rejects duplicate players (anti-cheat) and full matches, then adds the player
+    // (sets all_players_joined once the match is full)
+    let player_index = match_account.add_player(user_id_array, ctx.accounts.player.key())?;
+    if is_bot {
+        match_account.set_bot_seat(player_index);
     }
+    
+    // Public matches leave the lobby listing once full
+    sync_lobby_listing(ctx.accounts.lobby_index.as_mut(), match_account, clock.unix_timestamp)?;
 
-    let max_players = match_account.get_max_players();
-    msg!("Player {} joined match {} ({} of {})", user_id, match_id, match_account.player_count, max_players);
+    emit!(PlayerJoined {
+        match_account: match_account.key(),
+        player_index: player_index as u8,
+        player_count: match_account.player_count,
+        invited: false,
+        is_bot,
+    });
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(match_id: String)]
+#[instruction(match_id: String, user_id: String)]
 pub struct JoinMatch<'info> {
     #[account(
         mut,
-        seeds = [b"match", match_id.as_bytes()],
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
         bump
     )]
     pub match_account: Account<'info, Match>,
     
+    /// CHECK: User's sanction PDA (may not exist); checked by require_not_sanctioned
+    #[account(
+        seeds = [b"sanction", user_id.as_bytes()],
+        bump
+    )]
+    pub sanction: UncheckedAccount<'info>,
+    
     pub player: Signer<'info>,
+    
+    /// The game's open lobby index (required for public matches only)
+    #[account(
+        mut,
+        seeds = [b"open_lobbies".as_ref(), &[match_account.game_type]],
+        bump
+    )]
+    pub lobby_index: Option<Account<'info, OpenLobbyIndex>>,
+    
+    /// Optional coordinator co-signature (admits a player to a private match after checking
+    /// their join code; attests a bot seat)
+    pub authority: Option<Signer<'info>>,
 }
 
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::{Guild, UserAccount, ConfigAccount};
+use crate::error::GameError;
+
+/// Removes a member at the guild owner's request (verified off-chain by the backend).
+/// Their contribution this season stays banked in the guild score. The owner can't be kicked.
+/// Authority-gated (ConfigAccount.authority).
+pub fn handler(ctx: Context<KickMember>, name: String, user_id: String) -> Result<()> {
+    let config = &ctx.accounts.config_account;
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        GameError::Unauthorized
+    );
+    
+    let user = &mut ctx.accounts.user_account;
+    let guild = &mut ctx.accounts.guild;
+    require!(
+        user.guild == guild.name,
+        GameError::NotGuildMember
+    );
+    require!(
+        user.user_id != guild.owner,
+        GameError::GuildOwnerCannotLeave
+    );
+    require!(
+        guild.remove_member(&user.user_id),
+        GameError::NotGuildMember
+    );
+    user.guild = [0u8; 32];
+    
+    telemetry!("Guild {}: {} kicked ({} members)", name, user_id, guild.member_count);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(name: String, user_id: String)]
+pub struct KickMember<'info> {
+    #[account(
+        mut,
+        seeds = [b"guild", name.as_bytes()],
+        bump
+    )]
+    pub guild: Box<Account<'info, Guild>>,
+    
+    #[account(
+        mut,
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::{Sanction, ConfigAccount};
+use crate::error::GameError;
+
+/// Lifts a user's sanction early (appeal upheld, false positive).
+pub fn handler(
+    ctx: Context<LiftSanction>,
+    user_id: String,
+) -> Result<()> {
+    let sanction = &mut ctx.accounts.sanction;
+    let clock = Clock::get()?;
+
+    // Security: Only the config authority can lift sanctions
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config_account.authority,
+        GameError::Unauthorized
+    );
+
+    require!(!sanction.is_lifted(), GameError::SanctionNotActive);
+
+    sanction.lifted_at = clock.unix_timestamp;
+    sanction.lifted_by = ctx.accounts.authority.key();
+
+    telemetry!("Sanction lifted: {}", user_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(user_id: String)]
+pub struct LiftSanction<'info> {
+    #[account(
+        mut,
+        seeds = [b"sanction", user_id.as_bytes()],
+        bump
+    )]
+    pub sanction: Account<'info, Sanction>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    pub authority: Signer<'info>,
+}
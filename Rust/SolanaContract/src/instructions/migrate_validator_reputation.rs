@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+use crate::state::{ValidatorReputation, LegacyValidatorReputation};
+
+/// Rewrites a ValidatorReputation created with the legacy f64 reputation layout into the
+/// current fixed-point one: reputation becomes basis points (rounded to nearest), the slash
+/// escrow fields start empty, and the account grows to ValidatorReputation::MAX_SIZE.
+/// Permissionless: the payer funds the extra rent and no value other than the reputation
+/// encoding changes. A no-op for accounts already on the current layout.
+pub fn handler(ctx: Context<MigrateValidatorReputation>, validator_pubkey: Pubkey) -> Result<()> {
+    let info = ctx.accounts.validator_reputation.to_account_info();
+
+    // Security: Must be a ValidatorReputation of this program (PDA checked by seeds)
+    require!(info.owner == &crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+    if info.data_len() >= ValidatorReputation::MAX_SIZE {
+        ValidatorReputation::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        telemetry!("Validator reputation {} already migrated", validator_pubkey);
+        return Ok(());
+    }
+    let migrated = {
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() == LegacyValidatorReputation::SIZE
+                && data[..8] == ValidatorReputation::DISCRIMINATOR,
+            ErrorCode::AccountDidNotDeserialize
+        );
+        let legacy = LegacyValidatorReputation::deserialize(&mut &data[8..])?;
+        ValidatorReputation::from_legacy(&legacy)
+    };
+
+    // Top up rent for the new size
+    let rent_lamports = Rent::get()?.minimum_balance(ValidatorReputation::MAX_SIZE);
+    let shortfall = rent_lamports.saturating_sub(info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    info.realloc(ValidatorReputation::MAX_SIZE, true)?;
+    migrated.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    telemetry!(
+        "Validator reputation {} migrated: reputation {} bps ({} bytes)",
+        validator_pubkey,
+        migrated.reputation_bps,
+        ValidatorReputation::MAX_SIZE
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(validator_pubkey: Pubkey)]
+pub struct MigrateValidatorReputation<'info> {
+    /// CHECK: ValidatorReputation in the legacy or current layout (Account<ValidatorReputation>
+    /// can't load the legacy one); owner and discriminator checked in the handler
+    #[account(
+        mut,
+        seeds = [b"validator", validator_pubkey.as_ref()],
+        bump
+    )]
+    pub validator_reputation: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,223 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, spl_token::instruction::AuthorityType, Mint, Token, TokenAccount};
+use crate::state::{Match, Trophy, EndReason, match_id_seed};
+use crate::error::GameError;
+
+/// Metaplex Token Metadata program.
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bgRQA5uN");
+
+const TROPHY_SYMBOL: &str = "OCTROPHY";
+
+/// Mints a soulbound trophy NFT to the winner of an ended match.
+/// The NFT is a 0-decimal mint with supply 1 (mint authority revoked), Metaplex metadata
+/// naming the game and final score, and a frozen token account so it can't be transferred.
+/// Only the match authority can mint, once per match (Trophy PDA).
+/// The winner's wallet is passed by the authority (user_id -> wallet mapping is off-chain).
+pub fn handler(ctx: Context<MintTrophy>, match_id: String, uri: String) -> Result<()> {
+    let match_account = &ctx.accounts.match_account;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate authority is match authority
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Security: Only final results earn trophies (scored, no rounds left)
+    require!(
+        match_account.phase == 2 && match_account.is_ended() && !match_account.has_more_rounds(),
+        GameError::InvalidPhase
+    );
+    require!(
+        match_account.get_outcome() != EndReason::Completed || match_account.round_scored(),
+        GameError::InvalidPhase
+    );
+    let seat = match_account.winner().ok_or(GameError::NoWinner)?;
+
+    require!(
+        !uri.is_empty() && uri.len() <= 200,
+        GameError::InvalidPayload
+    );
+
+    let final_score = match_account.cumulative_scores[seat as usize];
+    let name = format!("{} Trophy", match_account.game_name_str());
+    
+    let authority_bump = ctx.bumps.trophy_authority;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"trophy_authority", &[authority_bump]]];
+    let trophy_authority = ctx.accounts.trophy_authority.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    // Supply of exactly one
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token::MintTo {
+                mint: ctx.accounts.trophy_mint.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: trophy_authority.clone(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    // Immutable Metaplex metadata (CreateMetadataAccountV3)
+    let metadata_ix = Instruction {
+        program_id: TOKEN_METADATA_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(ctx.accounts.metadata.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.trophy_mint.key(), false),
+            AccountMeta::new_readonly(trophy_authority.key(), true),
+            AccountMeta::new(ctx.accounts.authority.key(), true),
+            AccountMeta::new_readonly(trophy_authority.key(), true),
+            AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.rent.key(), false),
+        ],
+        data: create_metadata_v3_data(&name, TROPHY_SYMBOL, &uri)?,
+    };
+    invoke_signed(
+        &metadata_ix,
+        &[
+            ctx.accounts.metadata.to_account_info(),
+            ctx.accounts.trophy_mint.to_account_info(),
+            trophy_authority.clone(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+
+    // Soulbound: freeze the holder's token account
+    token::freeze_account(CpiContext::new_with_signer(
+        token_program.clone(),
+        token::FreezeAccount {
+            account: ctx.accounts.recipient_token_account.to_account_info(),
+            mint: ctx.accounts.trophy_mint.to_account_info(),
+            authority: trophy_authority.clone(),
+        },
+        signer_seeds,
+    ))?;
+
+    // No further trophies can be minted from this mint
+    token::set_authority(
+        CpiContext::new_with_signer(
+            token_program,
+            token::SetAuthority {
+                current_authority: trophy_authority,
+                account_or_mint: ctx.accounts.trophy_mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    let trophy = &mut ctx.accounts.trophy;
+    trophy.match_account = ctx.accounts.match_account.key();
+    trophy.seat = seat;
+    trophy.user_id = ctx.accounts.match_account.player_ids[seat as usize];
+    trophy.final_score = final_score;
+    trophy.mint = ctx.accounts.trophy_mint.key();
+    trophy.recipient = ctx.accounts.recipient.key();
+    trophy.minted_at = clock.unix_timestamp;
+
+    telemetry!("Trophy minted: match {}, seat {}, score {}, mint {}", 
+         match_id, seat, final_score, trophy.mint);
+    Ok(())
+}
+
+// Instruction data for Token Metadata CreateMetadataAccountV3 (discriminator 33):
+// DataV2 { name, symbol, uri, seller_fee_basis_points, creators, collection, uses }, is_mutable, collection_details
+fn create_metadata_v3_data(name: &str, symbol: &str, uri: &str) -> Result<Vec<u8>> {
+    let mut data = vec![33u8];
+    (name, symbol, uri, 0u16).serialize(&mut data)?;
+    data.extend_from_slice(&[0, 0, 0]); // creators, collection, uses: None
+    data.push(0); // is_mutable: false
+    data.push(0); // collection_details: None
+    Ok(data)
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct MintTrophy<'info> {
+    #[account(
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Box<Account<'info, Match>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = Trophy::MAX_SIZE,
+        seeds = [b"trophy", match_account.key().as_ref()],
+        bump
+    )]
+    pub trophy: Box<Account<'info, Trophy>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = 0,
+        mint::authority = trophy_authority,
+        mint::freeze_authority = trophy_authority,
+        seeds = [b"trophy_mint", match_account.key().as_ref()],
+        bump
+    )]
+    pub trophy_mint: Box<Account<'info, Mint>>,
+    
+    /// CHECK: PDA holding mint/freeze authority (and metadata update authority) for all trophies
+    #[account(
+        seeds = [b"trophy_authority"],
+        bump
+    )]
+    pub trophy_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Winner's wallet; any address can hold the trophy
+    pub recipient: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = trophy_mint,
+        associated_token::authority = recipient
+    )]
+    pub recipient_token_account: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Metadata PDA, created and validated by the Token Metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), trophy_mint.key().as_ref()],
+        seeds::program = TOKEN_METADATA_PROGRAM_ID,
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Token Metadata program
+    #[account(address = TOKEN_METADATA_PROGRAM_ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -1,50 +1,220 @@
 pub mod create_match;
+pub mod create_matches_batch; // Tournament/load-test bulk creation via remaining accounts
+pub mod create_match_counted; // Match id derived on-chain from (authority, counter)
 pub mod join_match;
+pub mod invite_player; // Friend challenge: invite a user to a match
+pub mod accept_invitation; // Join a match through an invitation
+// Matchmaking queue
+pub mod enqueue_player; // Join the open queue for a game type
+pub mod dequeue_player; // Leave the open queue
+pub mod form_match_from_queue; // Coordinator forms a match from tier-banded queued players
 pub mod start_match;
+pub mod set_ready; // Player-consent start: ready quorum starts the match
+pub mod set_teams; // Team/partnership assignment (Bridge, partner games)
+pub mod set_time_control; // Per-player time banks (competitive/tournament modes)
+pub mod schedule_match; // Calendar start time for tournament rounds
+pub mod set_rule_params; // Per-match variant: hand size, point target, handicaps
+pub mod expire_scheduled_match; // Void a scheduled match that missed its start window
+pub mod set_match_metadata; // Client key-value metadata on a match
+pub mod init_scrabble_board; // Scrabble board + tile bag commitment
+pub mod draw_tiles; // Deal committed Scrabble tiles into a rack
+pub mod place_word; // On-chain validated Scrabble word placement
+pub mod commit_word_search; // WordSearch puzzle commitment (coordinator)
+pub mod reveal_word_search_grid; // Publish the committed WordSearch grid
+pub mod submit_found_word; // Provable WordSearch found-word claims
+pub mod commit_crossword; // Crossword answer commitment (coordinator)
+pub mod submit_answer; // Crossword answers checked against the commitment
 pub mod commit_hand;
+pub mod commit_deck; // Shuffled deck Merkle commitment (coordinator)
 pub mod submit_move;
+pub mod submit_relayed_move; // Coordinator-paid moves authorized by the player's ed25519 signature
+pub mod attest_ai_move; // Model/prompt/response attestation for AI-made moves
+pub mod reveal_floor_card; // Floor card commit-reveal (coordinator)
+pub mod claim_timeout; // Forfeit a player whose time bank ran out
+pub mod close_showdown; // End a showdown round once its rebuttal window closed
+pub mod pause_match; // Live-ops pause (freezes moves and the turn clock)
+pub mod resume_match;
 pub mod end_match;
+pub mod transfer_match_authority; // Host migration: coordinator or player quorum reassigns the match authority
+pub mod start_next_round; // Multi-round matches
+pub mod create_series; // Best-of-N series over the same roster
+pub mod report_series_game; // Score a finished match into its series
+pub mod initialize_player_rating; // Per-game-type ELO rating account
+pub mod record_rating_change; // ELO rating update at match end
+pub mod mint_trophy; // Soulbound trophy NFT for the match winner (Metaplex)
 pub mod anchor_match_record;
+pub mod compact_match_moves; // Close anchored Move accounts, keep only count + transcript root
 pub mod register_signer;
 pub mod anchor_batch;
 pub mod flag_dispute;
 pub mod resolve_dispute;
+pub mod verify_replay_segment; // Validator re-checks a bounded slice of the move transcript
+pub mod issue_sanction; // Ban/suspension (authority or dispute-outcome driven)
+pub mod lift_sanction; // Lift a ban/suspension early
 pub mod calculate_scores;
 pub mod close_match_account; // Per critique Issue #3: Rent reclamation
 pub mod slash_validator; // Per critique Issue #3, #5: Validator slashing
+pub mod appeal_slash; // Validator appeals a slash (bond + escrowed stake)
+pub mod resolve_slash_appeal; // Council or peer validator decides a slash appeal
+pub mod migrate_validator_reputation; // Legacy f64 reputation -> basis points
+pub mod validator_heartbeat; // Validator liveness signal
+pub mod enforce_inactivity; // Permissionless inactivity slash crank
+pub mod rotate_coordinator; // Coordinator set membership and health
+pub mod force_end_match; // Admin council voids a stuck match
 // Economic model instructions (Section 20)
+pub mod create_user_account; // UserAccount creation (account age + placement tracking)
+pub mod extend_user_account; // Realloc a UserAccount to the current schema version
+pub mod erase_user_account; // GDPR erasure: anonymized stats tombstone, UID removed
+pub mod prove_account_ownership; // Support recovery: link a new wallet via oracle-signed proof
 pub mod daily_login; // Per spec Section 20.1.2: Daily login rewards
 pub mod game_payment; // Per spec Section 20.1.3: Game payment flow
 pub mod ad_reward; // Per spec Section 20.1.4: Ad reward system
 pub mod pro_subscription; // Per spec Section 20.1.5: Pro subscription
+pub mod cancel_subscription; // Subscription cancellation (no-renew at period end)
+pub mod downgrade_subscription; // Subscription downgrade effective at period end
 pub mod ai_credit_purchase; // Per spec Section 20.1.6: AI credit purchase
 pub mod ai_credit_consume; // Per spec Section 20.1.6: AI credit consumption
+pub mod accrue_subscription_credits; // Per-period subscription AC allowance with roll-over
+pub mod grant_ai_credits; // Support AC grants (audited via CreditAdjustment)
+pub mod refund_ai_consumption; // Support AC refunds (audited via CreditAdjustment)
+pub mod process_refund; // Stripe refund rolls back a subscription or AC purchase
+pub mod process_chargeback; // Stripe chargeback: refund rollback + chargeback counter
+pub mod set_ai_spend_limit; // Per-user daily AI credit cap
+pub mod create_promo; // Admin promo code creation
+pub mod redeem_promo; // Promo redemption (per-user bitmap, capped issuance)
+pub mod gift_gp; // User-to-user GP gifts (daily cap, account age, treasury fee)
+pub mod set_gift_policy; // Gift limits and treasury fee (ConfigAccount.gift_*)
+// Cosmetics
+pub mod initialize_cosmetic_registry; // Create the card back / table theme registry
+pub mod initialize_global_stats; // Create the protocol analytics counters account
+pub mod register_cosmetic; // Add an item to the cosmetic registry
+pub mod equip_cosmetic; // Equip an item in a user's cosmetic slot
+// Leaderboards (Section 20.1.6)
+pub mod create_leaderboard_shard; // Append a 100-rank shard to a season leaderboard
+pub mod submit_leaderboard_entry; // Insert score with cross-shard promotion/demotion
+pub mod decay_leaderboard; // Inactivity decay crank for a leaderboard shard
+pub mod distribute_season_rewards; // Paged end-of-season GP/lamport rewards
+// Guilds
+pub mod create_guild; // Create a guild (owner is the first member)
+pub mod join_guild; // Join a guild (capped membership, one guild per user)
+pub mod leave_guild; // Leave a guild (contribution stays banked)
+pub mod kick_member; // Owner-requested member removal
+pub mod submit_guild_score; // Member score sync + guild season leaderboard entry
 // Game registry instructions (Section 16.5)
 pub mod register_game; // Per spec Section 16.5: Register game in registry
 pub mod update_game; // Per spec Section 16.5: Update game in registry
+pub mod set_payload_schema; // Per-action move payload schema in registry
+pub mod set_game_fee; // Per-game GP cost and wager policy in registry
+pub mod set_game_rules; // Per-game run rules variant in registry
+pub mod set_game_match_cap; // Per-game daily match cap in registry
+pub mod set_game_betting; // Per-game betting flag and rake in registry
 // Move batching (Section 16.6)
 pub mod submit_batch_moves; // Per spec Section 16.6: Batch up to 5 moves per transaction
+pub mod set_congestion_level; // Coordinator-reported congestion (minimum batch size)
 
 pub use create_match::*;
+pub use create_matches_batch::*;
+pub use create_match_counted::*;
 pub use join_match::*;
+pub use invite_player::*;
+pub use accept_invitation::*;
+pub use enqueue_player::*;
+pub use dequeue_player::*;
+pub use form_match_from_queue::*;
 pub use start_match::*;
+pub use set_ready::*;
+pub use set_teams::*;
+pub use set_time_control::*;
+pub use schedule_match::*;
+pub use set_rule_params::*;
+pub use expire_scheduled_match::*;
+pub use set_match_metadata::*;
+pub use init_scrabble_board::*;
+pub use draw_tiles::*;
+pub use place_word::*;
+pub use commit_word_search::*;
+pub use reveal_word_search_grid::*;
+pub use submit_found_word::*;
+pub use commit_crossword::*;
+pub use submit_answer::*;
 pub use commit_hand::*;
+pub use commit_deck::*;
 pub use submit_move::*;
+pub use submit_relayed_move::*;
+pub use attest_ai_move::*;
+pub use reveal_floor_card::*;
+pub use claim_timeout::*;
+pub use close_showdown::*;
+pub use pause_match::*;
+pub use resume_match::*;
 pub use end_match::*;
+pub use transfer_match_authority::*;
+pub use start_next_round::*;
+pub use create_series::*;
+pub use report_series_game::*;
+pub use initialize_player_rating::*;
+pub use record_rating_change::*;
+pub use mint_trophy::*;
 pub use anchor_match_record::*;
+pub use compact_match_moves::*;
 pub use register_signer::*;
 pub use anchor_batch::*;
 pub use flag_dispute::*;
 pub use resolve_dispute::*;
+pub use verify_replay_segment::*;
+pub use issue_sanction::*;
+pub use lift_sanction::*;
 pub use close_match_account::*;
 pub use slash_validator::*;
+pub use appeal_slash::*;
+pub use resolve_slash_appeal::*;
+pub use migrate_validator_reputation::*;
+pub use validator_heartbeat::*;
+pub use enforce_inactivity::*;
+pub use rotate_coordinator::*;
+pub use force_end_match::*;
+pub use create_user_account::*;
+pub use extend_user_account::*;
+pub use erase_user_account::*;
+pub use prove_account_ownership::*;
 pub use daily_login::*;
 pub use game_payment::*;
 pub use ad_reward::*;
 pub use pro_subscription::*;
+pub use cancel_subscription::*;
+pub use downgrade_subscription::*;
 pub use ai_credit_purchase::*;
 pub use ai_credit_consume::*;
+pub use accrue_subscription_credits::*;
+pub use grant_ai_credits::*;
+pub use refund_ai_consumption::*;
+pub use process_refund::*;
+pub use process_chargeback::*;
+pub use set_ai_spend_limit::*;
+pub use create_promo::*;
+pub use redeem_promo::*;
+pub use gift_gp::*;
+pub use set_gift_policy::*;
+pub use initialize_cosmetic_registry::*;
+pub use initialize_global_stats::*;
+pub use register_cosmetic::*;
+pub use equip_cosmetic::*;
+pub use create_leaderboard_shard::*;
+pub use submit_leaderboard_entry::*;
+pub use decay_leaderboard::*;
+pub use distribute_season_rewards::*;
+pub use create_guild::*;
+pub use join_guild::*;
+pub use leave_guild::*;
+pub use kick_member::*;
+pub use submit_guild_score::*;
 pub use register_game::*;
 pub use update_game::*;
+pub use set_payload_schema::*;
+pub use set_game_fee::*;
+pub use set_game_rules::*;
+pub use set_game_match_cap::*;
+pub use set_game_betting::*;
 pub use submit_batch_moves::*;
+pub use set_congestion_level::*;
 
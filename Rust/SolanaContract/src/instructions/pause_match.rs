@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, match_id_seed};
+use crate::error::GameError;
+
+/// Pauses a match in play during a live-ops incident (e.g. coordinator outage).
+/// While paused, moves and timeout claims are rejected and the turn clock is frozen.
+/// The pause lifts itself after `max_duration_seconds` (at most Match::MAX_PAUSE_SECONDS)
+/// if resume_match is never sent. `reason` is a reason code for the audit trail:
+/// 0 = unspecified, 1 = coordinator outage, 2 = RPC/network incident, 3 = maintenance, 4 = dispute review.
+pub fn handler(
+    ctx: Context<PauseMatch>,
+    match_id: String,
+    reason: u8,
+    max_duration_seconds: u32,
+) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate authority (coordinator) is signer and matches
+    require!(
+        ctx.accounts.authority.is_signer,
+        GameError::Unauthorized
+    );
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Security: Only a match in play can be paused
+    require!(
+        match_account.phase == 1 && !match_account.is_ended(),
+        GameError::InvalidPhase
+    );
+
+    match_account.settle_expired_pause(clock.unix_timestamp);
+    require!(
+        !match_account.is_paused(clock.unix_timestamp),
+        GameError::MatchPaused
+    );
+
+    // Security: Pauses are bounded so a match can't be frozen indefinitely
+    require!(
+        max_duration_seconds > 0 && max_duration_seconds <= Match::MAX_PAUSE_SECONDS,
+        GameError::InvalidPauseDuration
+    );
+
+    match_account.pause(reason, max_duration_seconds, clock.unix_timestamp);
+
+    telemetry!("Match {} paused (reason {}, up to {}s)", match_id, reason, max_duration_seconds);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct PauseMatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, ScrabbleBoard, require_not_sanctioned, match_id_seed};
+use crate::events::{WordPlaced, TurnChanged};
+use crate::error::GameError;
+
+/// Plays Scrabble tiles for the player on turn: `tiles` go in a line from (row, col) (across
+/// if `horizontal`, else down), skipping cells already on the board. The board validates
+/// placement, adjacency, rack availability and the words formed, and scores them
+/// (see ScrabbleBoard::place_word); the turn then passes to the next seat.
+pub fn handler(
+    ctx: Context<PlaceWord>,
+    match_id: String,
+    user_id: String,
+    row: u8,
+    col: u8,
+    horizontal: bool,
+    tiles: Vec<u8>,
+) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    let board = &mut ctx.accounts.scrabble_board;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate match is in play
+    require!(
+        match_account.phase == 1 && !match_account.is_ended(),
+        GameError::InvalidPhase
+    );
+    match_account.settle_expired_pause(clock.unix_timestamp);
+    require!(
+        !match_account.is_paused(clock.unix_timestamp),
+        GameError::MatchPaused
+    );
+
+    // Security: Validate player is in match, signed by the seat's wallet, not sanctioned and on turn
+    require!(
+        user_id.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    let seat = match_account.find_player_index(user_id.as_bytes())
+        .ok_or(GameError::PlayerNotInMatch)?;
+    require!(
+        match_account.is_seat_wallet(seat, &ctx.accounts.player.key()),
+        GameError::Unauthorized
+    );
+    require_not_sanctioned(&ctx.accounts.sanction, &clock)?;
+    require!(
+        match_account.current_player == seat as u8,
+        GameError::NotPlayerTurn
+    );
+    require!(
+        !match_account.current_player_out_of_time(clock.unix_timestamp),
+        GameError::TurnDeadlinePassed
+    );
+
+    let play = board.place_word(seat, row, col, horizontal, &tiles)?;
+
+    match_account.current_player = ((seat + 1) % match_account.player_count as usize) as u8;
+    match_account.start_turn(clock.unix_timestamp);
+    emit!(TurnChanged::from_match(match_account));
+
+    emit!(WordPlaced {
+        match_account: match_account.key(),
+        player_index: seat as u8,
+        tiles_placed: tiles.len() as u8,
+        words_formed: play.words.len() as u8,
+        score: play.score,
+        total_score: board.scores[seat],
+    });
+    telemetry!("Seat {} played {:?} for {} points in match {}",
+         seat, play.words.iter().map(|w| String::from_utf8_lossy(w).into_owned()).collect::<Vec<_>>(),
+         play.score, match_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String, user_id: String)]
+pub struct PlaceWord<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Box<Account<'info, Match>>,
+    
+    #[account(
+        mut,
+        seeds = [b"scrabble_board", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub scrabble_board: Box<Account<'info, ScrabbleBoard>>,
+    
+    /// CHECK: User's sanction PDA (may not exist); checked by require_not_sanctioned
+    #[account(
+        seeds = [b"sanction", user_id.as_bytes()],
+        bump
+    )]
+    pub sanction: UncheckedAccount<'info>,
+    
+    pub player: Signer<'info>,
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::state::UserAccount;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use crate::state::{UserAccount, PaymentReceipt, SignerRegistry};
+use crate::payment::{PaymentAttestation, PaymentProduct};
 use crate::error::GameError;
 
 /// Subscription tier enum.
@@ -12,22 +14,30 @@ pub enum SubscriptionTier {
 
 /// Purchases or extends a pro subscription.
 /// Per spec Section 20.1.5: Pro subscription system.
-/// Note: Payment processed via Stripe off-chain. This instruction only updates subscription status.
+/// Note: Payment processed via Stripe off-chain. This instruction only updates subscription status,
+/// and only against the payment oracle's signed receipt for this user, tier and duration
+/// (see crate::payment), redeemed once into a PaymentReceipt.
 /// Note: String params converted to fixed arrays immediately for performance.
 pub fn handler(
     ctx: Context<PurchaseSubscription>,
     user_id: String,
     tier: u8,  // SubscriptionTier as u8
     duration_days: u8,  // Typically 30 days
+    op_nonce: u64,  // Per-user operation nonce (idempotency key)
+    attestation: PaymentAttestation,
 ) -> Result<()> {
     // Convert String to fixed-size array immediately (optimization)
     let user_id_bytes = user_id.as_bytes();
     require!(
         user_id_bytes.len() <= 64,
-        GameError::InvalidPayload
+        GameError::UserIdTooLong
     );
     
     let user_account = &mut ctx.accounts.user_account;
+    
+    // Idempotency: a retried operation can't be applied twice
+    user_account.use_op_nonce(op_nonce)?;
+    
     let clock = Clock::get()?;
     
     // Validate tier
@@ -44,9 +54,30 @@ pub fn handler(
         GameError::InvalidTier
     );
     
-    // Payment processed via Stripe (off-chain)
-    // In production: Call Stripe API to process payment
-    // After successful payment, update subscription in database
+    // Security: Payment processed via Stripe (off-chain); the oracle attests it was paid
+    let product = if subscription_tier == SubscriptionTier::ProPlus {
+        PaymentProduct::ProPlusSubscription
+    } else {
+        PaymentProduct::ProSubscription
+    };
+    attestation.verify(
+        &user_id,
+        product,
+        duration_days as u64,
+        &ctx.accounts.signer_registry,
+        &ctx.accounts.instructions,
+        &clock,
+    )?;
+    let receipt = &mut ctx.accounts.payment_receipt;
+    receipt.payment_intent_hash = attestation.payment_intent_hash;
+    receipt.user_id[..user_id_bytes.len()].copy_from_slice(user_id_bytes);
+    receipt.product = product as u8;
+    receipt.amount = duration_days as u64;
+    receipt.attestor = attestation.attestor;
+    receipt.created_at = clock.unix_timestamp;
+    
+    // Apply any downgrade whose effective date has already passed
+    user_account.apply_pending_subscription_changes(&clock);
     
     // Extend subscription expiry
     let duration_seconds = duration_days as i64 * 86400;
@@ -64,12 +95,16 @@ pub fn handler(
     
     user_account.subscription_tier = tier;
     
-    msg!("Subscription purchased: tier={}, expiry={}", tier, user_account.subscription_expiry);
+    // An explicit purchase supersedes any pending cancellation or downgrade
+    user_account.set_cancel_at_period_end(false);
+    user_account.clear_pending_downgrade();
+    
+    telemetry!("Subscription purchased: tier={}, expiry={}", tier, user_account.subscription_expiry);
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(user_id: String)]
+#[instruction(user_id: String, tier: u8, duration_days: u8, op_nonce: u64, attestation: PaymentAttestation)]
 pub struct PurchaseSubscription<'info> {
     #[account(
         mut,
@@ -78,6 +113,29 @@ pub struct PurchaseSubscription<'info> {
     )]
     pub user_account: Account<'info, UserAccount>,
     
+    /// Fails to init if this payment was already redeemed
+    #[account(
+        init,
+        payer = payer,
+        space = PaymentReceipt::MAX_SIZE,
+        seeds = [b"payment_receipt", attestation.payment_intent_hash.as_ref()],
+        bump
+    )]
+    pub payment_receipt: Account<'info, PaymentReceipt>,
+    
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    /// CHECK: Instructions sysvar (holds the preceding Ed25519 instruction)
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::state::{UserAccount, ConfigAccount, PaymentReceipt, PaymentReversal};
+use crate::instructions::process_refund::reverse_payment;
+use crate::error::GameError;
+
+/// Applies a Stripe chargeback (dispute lost): rolls the purchase back exactly like
+/// process_refund and counts it in UserAccount.chargeback_count, which off-chain risk
+/// checks use to block repeat offenders from further purchases.
+pub fn handler(
+    ctx: Context<ProcessChargeback>,
+    user_id: String,
+    product: u8,  // payment::PaymentProduct as u8
+    amount: u64,  // Days (subscriptions) or AC charged back
+    reference_hash: [u8; 32],  // SHA-256 of the Stripe dispute id
+) -> Result<()> {
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config_account.authority,
+        GameError::Unauthorized
+    );
+    
+    let accounts = ctx.accounts;
+    accounts.payment_reversal.reference_hash = reference_hash;
+    accounts.payment_reversal.authority = accounts.authority.key();
+    accounts.payment_reversal.chargeback = true;
+    reverse_payment(
+        &mut accounts.user_account,
+        &mut accounts.payment_receipt,
+        &mut accounts.payment_reversal,
+        &user_id,
+        product,
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(user_id: String, product: u8, amount: u64, reference_hash: [u8; 32])]
+pub struct ProcessChargeback<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    /// The purchase being charged back
+    #[account(mut)]
+    pub payment_receipt: Account<'info, PaymentReceipt>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = PaymentReversal::MAX_SIZE,
+        seeds = [b"payment_reversal", reference_hash.as_ref()],
+        bump
+    )]
+    pub payment_reversal: Account<'info, PaymentReversal>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use crate::state::{UserAccount, ConfigAccount, PaymentReceipt, PaymentReversal};
+use crate::payment::PaymentProduct;
+use crate::events::PaymentReversed;
+use crate::error::GameError;
+
+/// Applies a Stripe refund to on-chain state so it doesn't drift from billing.
+/// Authority-gated (ConfigAccount.authority). Subscription products take `amount` days off
+/// the subscription (it lapses if the expiry moves into the past); AI credits add `amount`
+/// to UserAccount.ac_reversed (the AC balance itself is debited in database).
+/// A PaymentReversal PDA keyed by reference_hash keeps a refund from being applied twice,
+/// and the purchase's PaymentReceipt caps all reversals at what was actually paid.
+pub fn handler(
+    ctx: Context<ProcessRefund>,
+    user_id: String,
+    product: u8,  // payment::PaymentProduct as u8
+    amount: u64,  // Days (subscriptions) or AC refunded
+    reference_hash: [u8; 32],  // SHA-256 of the Stripe refund id
+) -> Result<()> {
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config_account.authority,
+        GameError::Unauthorized
+    );
+    
+    let accounts = ctx.accounts;
+    accounts.payment_reversal.reference_hash = reference_hash;
+    accounts.payment_reversal.authority = accounts.authority.key();
+    accounts.payment_reversal.chargeback = false;
+    reverse_payment(
+        &mut accounts.user_account,
+        &mut accounts.payment_receipt,
+        &mut accounts.payment_reversal,
+        &user_id,
+        product,
+        amount,
+    )
+}
+
+/// Shared by process_refund and process_chargeback: rolls back the purchase, counts it
+/// against `receipt` and records it in `reversal` (reference_hash, authority and chargeback
+/// already set by the caller).
+pub(crate) fn reverse_payment(
+    user_account: &mut Account<UserAccount>,
+    receipt: &mut Account<PaymentReceipt>,
+    reversal: &mut Account<PaymentReversal>,
+    user_id: &str,
+    product: u8,
+    amount: u64,
+) -> Result<()> {
+    // Convert String to fixed-size array immediately (optimization)
+    let user_id_bytes = user_id.as_bytes();
+    require!(
+        user_id_bytes.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    let mut user_id_array = [0u8; 64];
+    user_id_array[..user_id_bytes.len()].copy_from_slice(user_id_bytes);
+    
+    let payment_product = PaymentProduct::from_u8(product).ok_or(GameError::InvalidPaymentProduct)?;
+    require!(
+        amount > 0,
+        GameError::InvalidPayload
+    );
+    
+    // Only what this receipt paid for, less earlier refunds and chargebacks
+    require!(
+        receipt.user_id == user_id_array && receipt.product == product,
+        GameError::ReversalExceedsReceipt
+    );
+    let remaining = receipt.amount.saturating_sub(receipt.amount_reversed);
+    require!(amount <= remaining, GameError::ReversalExceedsReceipt);
+    receipt.amount_reversed += amount;
+    
+    let clock = Clock::get()?;
+    let chargeback = reversal.chargeback;
+    
+    if payment_product.is_subscription() {
+        user_account.roll_back_subscription(amount, &clock);
+    } else {
+        user_account.ac_reversed = user_account.ac_reversed
+            .checked_add(amount)
+            .ok_or(GameError::Overflow)?;
+    }
+    if chargeback {
+        user_account.chargeback_count = user_account.chargeback_count.saturating_add(1);
+    }
+    
+    // Record audit trail
+    reversal.user_id = user_id_array;
+    reversal.product = product;
+    reversal.amount = amount;
+    reversal.created_at = clock.unix_timestamp;
+    
+    emit!(PaymentReversed {
+        user_account: user_account.key(),
+        product,
+        amount,
+        chargeback,
+        subscription_expiry: user_account.subscription_expiry,
+    });
+    
+    telemetry!("Payment reversed for {}: product={}, amount={}, chargeback={}",
+         user_id, product, amount, chargeback);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(user_id: String, product: u8, amount: u64, reference_hash: [u8; 32])]
+pub struct ProcessRefund<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    /// The purchase being reversed (created by pro_subscription or ai_credit_purchase)
+    #[account(mut)]
+    pub payment_receipt: Account<'info, PaymentReceipt>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = PaymentReversal::MAX_SIZE,
+        seeds = [b"payment_reversal", reference_hash.as_ref()],
+        bump
+    )]
+    pub payment_reversal: Account<'info, PaymentReversal>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
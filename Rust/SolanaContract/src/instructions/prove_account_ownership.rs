@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use crate::state::{UserAccount, SignerRegistry, SignerRole};
+use crate::events::WalletLinked;
+use crate::relay;
+use crate::error::GameError;
+
+/// Domain separator for ownership proofs (bump the version if the layout changes).
+pub const OWNERSHIP_DOMAIN: &[u8] = b"ocentra-ownership-v1";
+
+/// Bytes the identity oracle signs to vouch that the holder of `user_id`'s Firebase login
+/// asked to link `wallet`:
+/// "ocentra-ownership-v1" || user_id_len (u8) || user_id || wallet (32) || challenge (32)
+/// || wallet_link_count (u32 LE) || expiry (i64 LE)
+pub fn ownership_message(
+    user_id: &str,
+    wallet: &Pubkey,
+    challenge: &[u8; 32],
+    wallet_link_count: u32,
+    expiry: i64,
+) -> Result<Vec<u8>> {
+    require!(user_id.len() <= 64, GameError::UserIdTooLong);
+    let mut message = Vec::with_capacity(OWNERSHIP_DOMAIN.len() + 1 + user_id.len() + 32 + 32 + 4 + 8);
+    message.extend_from_slice(OWNERSHIP_DOMAIN);
+    message.push(user_id.len() as u8);
+    message.extend_from_slice(user_id.as_bytes());
+    message.extend_from_slice(wallet.as_ref());
+    message.extend_from_slice(challenge);
+    message.extend_from_slice(&wallet_link_count.to_le_bytes());
+    message.extend_from_slice(&expiry.to_le_bytes());
+    Ok(message)
+}
+
+/// Support recovery ("lost my wallet, still have my Firebase login"): links the signing
+/// wallet to an existing UserAccount. A registered coordinator, acting as the identity oracle
+/// after re-authenticating the Firebase user against `challenge`, signs ownership_message; the
+/// Ed25519 instruction before this one must carry exactly `signature` over it. The new wallet
+/// signs the transaction, so a proof can't link a wallet nobody controls.
+/// The account's wallet_link_count is signed in and bumped on every link, so an old proof
+/// (e.g. for the lost wallet) can't be replayed to take the account back.
+pub fn handler(
+    ctx: Context<ProveAccountOwnership>,
+    user_id: String,
+    challenge: [u8; 32],
+    signature: [u8; 64],
+    attestor: Pubkey,
+    expiry: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(clock.unix_timestamp <= expiry, GameError::OwnershipProofExpired);
+    require!(
+        ctx.accounts.signer_registry.get_role(&attestor) == Some(SignerRole::Coordinator),
+        GameError::InvalidOwnershipProof
+    );
+    
+    let user_account = &mut ctx.accounts.user_account;
+    let wallet = ctx.accounts.new_wallet.key();
+    let message = ownership_message(&user_id, &wallet, &challenge, user_account.wallet_link_count, expiry)?;
+    relay::verify_preceding_signature_bytes(&ctx.accounts.instructions, &attestor, &message, &signature)
+        .map_err(|_| error!(GameError::InvalidOwnershipProof))?;
+    
+    let previous_wallet = user_account.wallet;
+    user_account.wallet = wallet;
+    user_account.wallet_link_count = user_account.wallet_link_count.saturating_add(1);
+    user_account.wallet_linked_at = clock.unix_timestamp;
+    
+    emit!(WalletLinked {
+        user_account: user_account.key(),
+        wallet,
+        previous_wallet,
+        attestor,
+        wallet_link_count: user_account.wallet_link_count,
+    });
+    
+    telemetry!("Wallet linked: {} (link #{})", wallet, user_account.wallet_link_count);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(user_id: String)]
+pub struct ProveAccountOwnership<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    /// CHECK: Instructions sysvar (holds the preceding Ed25519 instruction)
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    /// Wallet being linked
+    pub new_wallet: Signer<'info>,
+}
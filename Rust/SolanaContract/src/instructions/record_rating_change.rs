@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, PlayerRating, compute_rating_deltas, match_id_seed};
+use crate::error::GameError;
+
+/// Applies ELO rating changes for every player of an ended match.
+/// Matches with an AI/bot seat are rated on the bot ladder (see PlayerRating).
+/// Remaining accounts: one writable PlayerRating PDA per player, in seat order.
+pub fn handler(
+    ctx: Context<RecordRatingChange>,
+    match_id: String,
+) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    let clock = Clock::get()?;
+
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate authority is signer and matches
+    require!(
+        ctx.accounts.authority.is_signer,
+        GameError::Unauthorized
+    );
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Security: Only ended matches are rated, and only once
+    require!(match_account.phase == 2, GameError::InvalidPhase);
+    require!(!match_account.ratings_recorded(), GameError::RatingsAlreadyRecorded);
+
+    let player_count = match_account.player_count as usize;
+    require!(
+        ctx.remaining_accounts.len() == player_count,
+        GameError::InvalidPayload
+    );
+
+    // Load and validate every player's rating account
+    let mut player_ratings: Vec<PlayerRating> = Vec::with_capacity(player_count);
+    for (index, account_info) in ctx.remaining_accounts.iter().enumerate() {
+        require!(
+            account_info.owner == &crate::ID && account_info.is_writable,
+            GameError::InvalidRatingAccount
+        );
+        let player_rating = {
+            let data = account_info.try_borrow_data()?;
+            PlayerRating::try_deserialize(&mut &data[..])?
+        };
+
+        // Security: Rating account must belong to the player in this seat
+        require!(
+            Some(player_rating.user_id) == match_account.get_player_id(index),
+            GameError::InvalidRatingAccount
+        );
+        player_ratings.push(player_rating);
+    }
+
+    let game_type = match_account.game_type;
+    let bot_ladder = match_account.has_bots();
+    let ratings: Vec<u16> = player_ratings.iter().map(|r| r.get_rating(game_type, bot_ladder)).collect();
+    let k_factors: Vec<i32> = player_ratings.iter().map(|r| r.k_factor(game_type, bot_ladder)).collect();
+    let deltas = compute_rating_deltas(
+        &ratings,
+        &match_account.cumulative_scores[..player_count],
+        &k_factors,
+    );
+
+    // Persist updated ratings
+    for (index, account_info) in ctx.remaining_accounts.iter().enumerate() {
+        let player_rating = &mut player_ratings[index];
+        player_rating.apply_rating_change(game_type, deltas[index], clock.unix_timestamp, bot_ladder);
+        let mut data = account_info.try_borrow_mut_data()?;
+        player_rating.try_serialize(&mut &mut data[..])?;
+        telemetry!(
+            "Rating updated: player {} {} -> {}",
+            index,
+            ratings[index],
+            player_rating.get_rating(game_type, bot_ladder)
+        );
+    }
+
+    match_account.set_ratings_recorded(true);
+
+    telemetry!("Ratings recorded for match {}", match_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct RecordRatingChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use crate::state::{PromoCode, UserAccount, GlobalStats, require_not_sanctioned};
+use crate::error::GameError;
+
+/// Redeems a promo code for a user.
+/// Each user can redeem a promo once (per-user bitmap on UserAccount) and the promo's
+/// max_redemptions caps total issuance.
+/// Note: GP/AC balances are credited in database. This instruction records the redemption.
+pub fn handler(ctx: Context<RedeemPromo>, user_id: String, promo_index: u16, code: String) -> Result<()> {
+    let user_id_bytes = user_id.as_bytes();
+    require!(
+        user_id_bytes.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    
+    let promo = &mut ctx.accounts.promo;
+    let user_account = &mut ctx.accounts.user_account;
+    let clock = Clock::get()?;
+    
+    // Security: Banned or suspended users can't redeem promos
+    require_not_sanctioned(&ctx.accounts.sanction, &clock)?;
+    
+    require!(
+        promo.matches_code(code.as_bytes()),
+        GameError::InvalidPromoCode
+    );
+    require!(
+        !promo.is_expired(&clock),
+        GameError::PromoExpired
+    );
+    require!(
+        !promo.is_exhausted(),
+        GameError::PromoExhausted
+    );
+    require!(
+        !user_account.has_redeemed_promo(promo_index),
+        GameError::PromoAlreadyRedeemed
+    );
+    
+    user_account.set_promo_redeemed(promo_index);
+    promo.redemption_count += 1;
+    
+    // Update lifetime stats (balances updated in database, not on-chain)
+    user_account.lifetime_gp_earned = user_account.lifetime_gp_earned
+        .checked_add(promo.gp_amount)
+        .ok_or(GameError::Overflow)?;
+    user_account.total_ac_granted = user_account.total_ac_granted
+        .checked_add(promo.ac_amount)
+        .ok_or(GameError::Overflow)?;
+    ctx.accounts.global_stats.record_gp_issued(promo.gp_amount, clock.unix_timestamp);
+    
+    telemetry!("Promo {} redeemed: {} GP, {} AC ({}/{})", 
+         promo_index, promo.gp_amount, promo.ac_amount, promo.redemption_count, promo.max_redemptions);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(user_id: String, promo_index: u16)]
+pub struct RedeemPromo<'info> {
+    #[account(
+        mut,
+        seeds = [b"promo", promo_index.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub promo: Account<'info, PromoCode>,
+    
+    #[account(
+        mut,
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    /// CHECK: User's sanction PDA (may not exist); checked by require_not_sanctioned
+    #[account(
+        seeds = [b"sanction", user_id.as_bytes()],
+        bump
+    )]
+    pub sanction: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_stats"],
+        bump
+    )]
+    pub global_stats: Box<Account<'info, GlobalStats>>,
+}
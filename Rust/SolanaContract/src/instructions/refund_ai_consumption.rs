@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use crate::state::{UserAccount, ConfigAccount, CreditAdjustment, CreditAdjustmentKind, UsageMeter};
+use crate::error::GameError;
+
+/// Refunds a previously recorded AI credit consumption (e.g., failed AI call).
+/// Authority-gated (ConfigAccount.authority). The refund comes out of what the user's
+/// UsageMeter recorded for `model_id` in billing period `period_id`, so only metered
+/// consumption can be refunded, and is reversed from total_ac_spent. Records a
+/// CreditAdjustment PDA (["credit_refund", user_id, consumption_id]) so a consumption
+/// can be refunded at most once.
+/// Note: AC balance is credited in database. This instruction keeps on-chain stats in sync.
+pub fn handler(
+    ctx: Context<RefundAIConsumption>,
+    user_id: String,
+    consumption_id: [u8; 32],  // SHA-256 of the off-chain consumption record id
+    period_id: u32,  // Billing period the consumption was metered in
+    model_id: u8,  // Model the consumption was metered under
+    ac_amount: u64,
+    reason: u8,  // Support reason code (off-chain enum)
+) -> Result<()> {
+    // Convert String to fixed-size array immediately (optimization)
+    let user_id_bytes = user_id.as_bytes();
+    require!(
+        user_id_bytes.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    let mut user_id_array = [0u8; 64];
+    let copy_len = user_id_bytes.len().min(64);
+    user_id_array[..copy_len].copy_from_slice(&user_id_bytes[..copy_len]);
+    
+    let config = &ctx.accounts.config_account;
+    let clock = Clock::get()?;
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        GameError::Unauthorized
+    );
+    
+    require!(
+        ac_amount > 0 && model_id < 10,
+        GameError::InvalidPayload
+    );
+    
+    // Security: Only consumption the meter recorded (and not already refunded) comes back
+    let usage_meter = &mut ctx.accounts.usage_meter;
+    let model_spent = &mut usage_meter.ac_spent[model_id as usize];
+    require!(
+        ac_amount <= *model_spent,
+        GameError::RefundExceedsSpent
+    );
+    *model_spent -= ac_amount;
+    
+    // Reverse the consumption from lifetime stats
+    let user_account = &mut ctx.accounts.user_account;
+    require!(
+        ac_amount <= user_account.total_ac_spent,
+        GameError::RefundExceedsSpent
+    );
+    user_account.total_ac_spent = user_account.total_ac_spent
+        .checked_sub(ac_amount)
+        .ok_or(GameError::Overflow)?;
+    
+    // Record audit trail
+    let adjustment = &mut ctx.accounts.credit_adjustment;
+    adjustment.user_id = user_id_array;
+    adjustment.reference_id = consumption_id;
+    adjustment.kind = CreditAdjustmentKind::Refund as u8;
+    adjustment.ac_amount = ac_amount;
+    adjustment.reason = reason;
+    adjustment.authority = ctx.accounts.authority.key();
+    adjustment.created_at = clock.unix_timestamp;
+    
+    telemetry!("AI consumption refunded: {} AC (period={}, model_id={}, reason={})", ac_amount, period_id, model_id, reason);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(user_id: String, consumption_id: [u8; 32], period_id: u32)]
+pub struct RefundAIConsumption<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    /// The meter the refunded consumption was recorded in
+    #[account(
+        mut,
+        seeds = [b"usage_meter", user_id.as_bytes(), period_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub usage_meter: Account<'info, UsageMeter>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = CreditAdjustment::MAX_SIZE,
+        seeds = [b"credit_refund", user_id.as_bytes(), consumption_id.as_ref()],
+        bump
+    )]
+    pub credit_adjustment: Account<'info, CreditAdjustment>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::{CosmeticRegistry, CosmeticItem, CosmeticKind};
+use crate::error::GameError;
+
+/// Registers a card back or table theme in the cosmetic registry.
+/// Admin-only instruction.
+pub fn handler(
+    ctx: Context<RegisterCosmetic>,
+    item_id: u16,
+    kind: u8,
+    name: String,
+    gp_price: u64,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == registry.authority,
+        GameError::Unauthorized
+    );
+    
+    // Validate inputs
+    require!(
+        CosmeticKind::from_u8(kind).is_some(),
+        GameError::InvalidCosmetic
+    );
+    require!(
+        !name.is_empty() && name.len() <= 20,
+        GameError::InvalidPayload
+    );
+    
+    let mut name_array = [0u8; 20];
+    name_array[..name.len()].copy_from_slice(name.as_bytes());
+    
+    registry.add_item(CosmeticItem {
+        item_id,
+        kind,
+        name: name_array,
+        gp_price,
+        enabled: true,
+    })?;
+    registry.last_updated = clock.unix_timestamp;
+    
+    telemetry!("Cosmetic registered: item_id={}, kind={}, name={}", item_id, kind, name);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterCosmetic<'info> {
+    #[account(
+        mut,
+        seeds = [b"cosmetic_registry"],
+        bump
+    )]
+    pub registry: Box<Account<'info, CosmeticRegistry>>,
+    
+    pub authority: Signer<'info>,
+}
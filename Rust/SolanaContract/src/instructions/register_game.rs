@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::{GameRegistry, GameDefinition};
+use crate::state::{GameRegistry, GameDefinition, PayloadSchema, RunRules};
 use crate::error::GameError;
 
 /// Registers a new game in the registry.
@@ -30,7 +30,7 @@ pub fn handler(
     );
     require!(
         !rule_engine_url.is_empty() && rule_engine_url.len() <= 200,
-        GameError::InvalidPayload
+        GameError::UrlTooLong
     );
     require!(
         min_players > 0 && min_players <= max_players && max_players <= 10,
@@ -57,13 +57,20 @@ pub fn handler(
         rule_engine_url: url_array,
         version,
         enabled: true,
+        payload_schemas: [PayloadSchema::UNDEFINED; PayloadSchema::MAX_ACTIONS], // Set via set_payload_schema
+        gp_cost: 0, // Config default until set via set_game_fee
+        wager_allowed: false,
+        rules_variant: RunRules::CLASSIC, // Override via set_game_rules
+        daily_match_cap: 0, // Uncapped until set via set_game_match_cap
+        has_betting: false, // Enabled via set_game_betting
+        rake_bps: 0,
     };
     
     // Add to registry
     registry.add_game(game)?;
     registry.last_updated = clock.unix_timestamp;
     
-    msg!("Game registered: game_id={}, name={}", game_id, name);
+    telemetry!("Game registered: game_id={}, name={}", game_id, name);
     Ok(())
 }
 
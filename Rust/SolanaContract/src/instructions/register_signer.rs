@@ -32,7 +32,7 @@ pub fn handler(
 
     registry.add_signer(pubkey, signer_role)?;
 
-    msg!("Signer registered: {} with role {:?}", pubkey, signer_role);
+    telemetry!("Signer registered: {} with role {:?}", pubkey, signer_role);
     Ok(())
 }
 
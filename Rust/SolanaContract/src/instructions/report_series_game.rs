@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use crate::state::{Series, Match, match_id_seed};
+use crate::events::SeriesGameReported;
+use crate::error::GameError;
+
+/// Reports a finished match to its series: the match winner (if any) scores a series win,
+/// and the series completes once a player reaches the win threshold (see Series).
+/// Series authority only.
+pub fn handler(
+    ctx: Context<ReportSeriesGame>,
+    series_id: String,
+    match_id: String,
+) -> Result<()> {
+    let series = &mut ctx.accounts.series;
+    let match_account = &ctx.accounts.match_account;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Only the series authority reports games
+    require!(
+        ctx.accounts.authority.key() == series.authority,
+        GameError::Unauthorized
+    );
+    require!(!series.is_complete(), GameError::SeriesAlreadyComplete);
+
+    // The match must be a finished game of this series' type between exactly the roster
+    require!(
+        match_account.phase == 2 && match_account.is_ended(),
+        GameError::InvalidPhase
+    );
+    require!(
+        match_account.game_type == series.game_type,
+        GameError::WrongGameType
+    );
+    let match_key = match_account.key();
+    require!(!series.has_match(&match_key), GameError::MatchAlreadyReported);
+    require!(
+        match_account.player_count == series.player_count,
+        GameError::SeriesRosterMismatch
+    );
+    let roster_seats = match_account.player_ids[..match_account.player_count as usize]
+        .iter()
+        .map(|player_id| series.seat_of(player_id))
+        .collect::<Option<Vec<usize>>>()
+        .ok_or(GameError::SeriesRosterMismatch)?;
+
+    let winner = match_account.winner().map(|seat| roster_seats[seat as usize]);
+    series.record_game(match_key, winner, clock.unix_timestamp);
+
+    emit!(SeriesGameReported {
+        series: series.key(),
+        match_account: match_key,
+        game_number: series.games_reported,
+        game_winner: winner.map(|seat| seat as u8).unwrap_or(Series::NO_WINNER),
+        wins: series.wins,
+        completed: series.is_complete(),
+        series_winner: series.winner,
+    });
+    telemetry!("Series {} game {} reported: match {}", series_id, series.games_reported, match_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(series_id: String, match_id: String)]
+pub struct ReportSeriesGame<'info> {
+    #[account(
+        mut,
+        seeds = [b"series", match_id_seed(series_id.as_bytes())],
+        bump
+    )]
+    pub series: Box<Account<'info, Series>>,
+    
+    #[account(
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Box<Account<'info, Match>>,
+    
+    pub authority: Signer<'info>,
+}
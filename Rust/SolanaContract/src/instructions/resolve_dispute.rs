@@ -1,13 +1,16 @@
 use anchor_lang::prelude::*;
-use crate::state::{Dispute, DisputeResolution, ValidatorVote};
+use crate::state::{Dispute, DisputeResolution, ValidatorVote, ConfigAccount, UserAccount, Sanction, SignerRegistry, SignerRole, user_id_seed, match_id_seed};
+use crate::events::DisputeResolved;
 use crate::error::GameError;
 
 /// Resolves a dispute and handles GP deposit refund/forfeit.
 /// Per spec Section 23: GP deposit is refunded if dispute is valid, forfeited if invalid.
 /// Actual GP refund/forfeit happens off-chain in database. This instruction records the decision.
+/// A dispute upheld against a named defendant adds a cheat strike and escalates their sanction
+/// per ConfigAccount thresholds (warning -> timed suspension -> permanent ban).
 pub fn handler(
     ctx: Context<ResolveDispute>,
-    dispute_id: String,
+    _dispute_id: String,
     resolution: u8,
 ) -> Result<()> {
     let dispute = &mut ctx.accounts.dispute;
@@ -19,6 +22,12 @@ pub fn handler(
         GameError::Unauthorized
     );
 
+    // Security: Only registered validators resolve disputes (and issue the resulting sanctions)
+    require!(
+        ctx.accounts.signer_registry.get_role(&ctx.accounts.validator.key()) == Some(SignerRole::Validator),
+        GameError::Unauthorized
+    );
+
     // Security: Validate dispute exists and is not already resolved
     require!(
         !dispute.is_resolved(),
@@ -40,6 +49,7 @@ pub fn handler(
     // Record resolution
     dispute.resolution = resolution;
     dispute.resolved_at = clock.unix_timestamp;
+    ctx.accounts.flagger_account.record_dispute_resolved();
 
     // Determine if GP should be refunded based on resolution
     // Resolution 1 = ResolvedInFavorOfFlagger (dispute valid) → refund GP
@@ -65,10 +75,48 @@ pub fn handler(
     };
     dispute.add_vote(validator_vote)?;
 
-    msg!("Dispute resolved: {} with resolution {} (GP {}: {})", 
-         dispute_id, resolution, 
-         if dispute.gp_refunded { "refunded" } else { "forfeited" },
-         dispute.gp_deposit);
+    // Cheat strike + automatic escalation for the accused player
+    if dispute_resolution == DisputeResolution::ResolvedInFavorOfFlagger && dispute.has_defendant() {
+        let config = &ctx.accounts.config_account;
+        let defendant = ctx.accounts.defendant_account
+            .as_mut()
+            .ok_or(GameError::InvalidPayload)?;
+        let sanction = ctx.accounts.defendant_sanction
+            .as_mut()
+            .ok_or(GameError::InvalidPayload)?;
+
+        defendant.cheat_strikes = defendant.cheat_strikes.saturating_add(1);
+
+        if let Some((kind, duration_seconds)) = config.scheduled_sanction(defendant.cheat_strikes) {
+            // Never downgrade a stronger sanction that is still in effect
+            let already_covered = sanction.is_active(&clock) && sanction.kind >= kind as u8;
+            if !already_covered {
+                let expires_at = if duration_seconds == 0 {
+                    0
+                } else {
+                    clock.unix_timestamp
+                        .checked_add(duration_seconds)
+                        .ok_or(GameError::Overflow)?
+                };
+                sanction.user_id = dispute.defendant_user_id;
+                sanction.issue(
+                    kind,
+                    dispute.reason,
+                    dispute.key(),
+                    ctx.accounts.validator.key(),
+                    clock.unix_timestamp,
+                    expires_at,
+                );
+                telemetry!("Sanction escalated: {:?} (strikes={})", kind, defendant.cheat_strikes);
+            }
+        }
+    }
+
+    emit!(DisputeResolved {
+        dispute: ctx.accounts.dispute.key(),
+        resolution,
+        gp_refunded: ctx.accounts.dispute.gp_refunded,
+    });
     Ok(())
 }
 
@@ -77,11 +125,53 @@ pub fn handler(
 pub struct ResolveDispute<'info> {
     #[account(
         mut,
-        seeds = [b"dispute", &dispute.match_id[..], dispute.flagger.as_ref()],
+        seeds = [b"dispute", match_id_seed(&dispute.match_id), dispute.flagger.as_ref()],
         bump
     )]
     pub dispute: Account<'info, Dispute>,
     
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    /// Flagger's user account (frees one of their open dispute slots)
+    #[account(
+        mut,
+        seeds = [b"user_account", user_id_seed(&dispute.flagger_user_id)],
+        bump
+    )]
+    pub flagger_account: Box<Account<'info, UserAccount>>,
+    
+    /// Accused player's account (required when the dispute names a defendant)
+    #[account(
+        mut,
+        seeds = [b"user_account", user_id_seed(&dispute.defendant_user_id)],
+        bump
+    )]
+    pub defendant_account: Option<Account<'info, UserAccount>>,
+    
+    /// Accused player's sanction record (required when the dispute names a defendant)
+    #[account(
+        init_if_needed,
+        payer = validator,
+        space = Sanction::MAX_SIZE,
+        seeds = [b"sanction", user_id_seed(&dispute.defendant_user_id)],
+        bump
+    )]
+    pub defendant_sanction: Option<Account<'info, Sanction>>,
+    
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    #[account(mut)]
     pub validator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
 }
 
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::state::{ValidatorReputation, SignerRegistry, SignerRole};
+use crate::error::GameError;
+
+/// Decides a pending slash appeal. Reviewed by the admin council (signer registry authority)
+/// or another registered validator, never the appellant.
+/// Overturned: the escrowed stake and pre-slash reputation are restored and the bond is
+/// refunded to the validator. Rejected: the slash is final and the bond goes to the council.
+pub fn handler(
+    ctx: Context<ResolveSlashAppeal>,
+    validator_pubkey: Pubkey,
+    overturn: bool,
+) -> Result<()> {
+    let reviewer = ctx.accounts.reviewer.key();
+    let registry = &ctx.accounts.signer_registry;
+
+    // Security: Council or a peer validator reviews; a validator can't judge its own appeal
+    require!(
+        reviewer == registry.authority
+            || (registry.get_role(&reviewer) == Some(SignerRole::Validator) && reviewer != validator_pubkey),
+        GameError::Unauthorized
+    );
+
+    let validator_account = &mut ctx.accounts.validator_reputation;
+    require!(
+        validator_account.appeal_pending(),
+        GameError::NoPendingAppeal
+    );
+
+    let bond = validator_account.appeal_bond;
+    let escrow = validator_account.slashed_escrow;
+    let bond_recipient = if overturn {
+        validator_account.stake = validator_account.stake
+            .checked_add(escrow)
+            .ok_or(GameError::Overflow)?;
+        validator_account.reputation_bps = validator_account.pre_slash_reputation_bps;
+        validator_account.appeal_status = ValidatorReputation::APPEAL_OVERTURNED;
+        ctx.accounts.validator_wallet.to_account_info()
+    } else {
+        validator_account.appeal_status = ValidatorReputation::APPEAL_REJECTED;
+        ctx.accounts.council.to_account_info()
+    };
+    validator_account.slashed_escrow = 0;
+    validator_account.appeal_bond = 0;
+
+    // Release the bond held on the reputation account
+    **validator_account.to_account_info().try_borrow_mut_lamports()? -= bond;
+    **bond_recipient.try_borrow_mut_lamports()? += bond;
+
+    telemetry!("Slash appeal for {} {} by {} (escrow {}, bond {} to {})",
+         validator_pubkey,
+         if overturn { "overturned" } else { "rejected" },
+         reviewer,
+         escrow,
+         bond,
+         bond_recipient.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(validator_pubkey: Pubkey)]
+pub struct ResolveSlashAppeal<'info> {
+    #[account(
+        mut,
+        seeds = [b"validator", validator_pubkey.as_ref()],
+        bump
+    )]
+    pub validator_reputation: Account<'info, ValidatorReputation>,
+    
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    /// CHECK: Appellant's wallet, refunded the bond when the slash is overturned
+    #[account(mut, address = validator_pubkey)]
+    pub validator_wallet: UncheckedAccount<'info>,
+    
+    /// CHECK: Admin council (signer registry authority), receives a forfeited bond
+    #[account(mut, address = signer_registry.authority)]
+    pub council: UncheckedAccount<'info>,
+    
+    pub reviewer: Signer<'info>,
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, match_id_seed};
+use crate::error::GameError;
+
+/// Resumes a paused match. The time spent paused is added back to the turn clock,
+/// so the player on turn isn't charged for the incident.
+pub fn handler(ctx: Context<ResumeMatch>, match_id: String) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate authority (coordinator) is signer and matches
+    require!(
+        ctx.accounts.authority.is_signer,
+        GameError::Unauthorized
+    );
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    require!(
+        match_account.paused_flag(),
+        GameError::MatchNotPaused
+    );
+
+    let reason = match_account.pause_reason;
+    match_account.resume(clock.unix_timestamp);
+
+    telemetry!("Match {} resumed (paused for reason {})", match_id, reason);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct ResumeMatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, match_id_seed};
+use crate::events::FloorCardRevealed;
+use crate::error::GameError;
+
+/// Reveals the next floor card by committing its hash on-chain.
+/// The coordinator derives the card from the committed deck/seed off-chain and
+/// commits SHA-256(suit, value). This is the only writer of floor_card_hash, which
+/// validate_pick_up checks the picked-up card against.
+pub fn handler(
+    ctx: Context<RevealFloorCard>,
+    match_id: String,
+    floor_card_hash: [u8; 32],
+) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate authority is signer and matches (coordinator deals the floor card)
+    require!(
+        ctx.accounts.authority.is_signer,
+        GameError::Unauthorized
+    );
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Security: Must be in Playing phase
+    require!(
+        match_account.phase == 1,
+        GameError::InvalidPhase
+    );
+
+    // Security: Previous floor card must have been picked up or declined
+    require!(
+        !match_account.floor_card_revealed(),
+        GameError::FloorCardAlreadyRevealed
+    );
+
+    // Security: Validate floor card hash is not all zeros
+    require!(
+        floor_card_hash.iter().any(|&b| b != 0),
+        GameError::EmptyHash
+    );
+
+    match_account.set_floor_card_hash(floor_card_hash);
+    match_account.set_floor_card_revealed(true);
+
+    emit!(FloorCardRevealed {
+        match_account: match_account.key(),
+        floor_card_hash,
+        current_player: match_account.current_player,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct RevealFloorCard<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    pub authority: Signer<'info>,
+}
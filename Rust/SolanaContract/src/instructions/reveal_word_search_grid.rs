@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, WordSearchPuzzle, match_id_seed};
+use crate::error::GameError;
+
+/// Publishes the committed WordSearch grid once play has started. The grid must hash to the
+/// grid_hash committed at creation, so the coordinator can't swap the puzzle after seeing
+/// who joined.
+pub fn handler(
+    ctx: Context<RevealWordSearchGrid>,
+    match_id: String,
+    grid: Vec<u8>,
+) -> Result<()> {
+    let match_account = &ctx.accounts.match_account;
+    let puzzle = &mut ctx.accounts.word_search_puzzle;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Only the match authority (coordinator)
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Security: The grid stays hidden until play starts
+    require!(
+        match_account.phase == 1 && !match_account.is_ended(),
+        GameError::InvalidPhase
+    );
+
+    puzzle.reveal_grid(&grid)?;
+
+    telemetry!("Word search grid revealed for match {}", match_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct RevealWordSearchGrid<'info> {
+    #[account(
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Box<Account<'info, Match>>,
+    
+    #[account(
+        mut,
+        seeds = [b"word_search", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub word_search_puzzle: Box<Account<'info, WordSearchPuzzle>>,
+    
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::{CoordinatorSet, SignerRegistry, SignerRole};
+use crate::error::GameError;
+
+/// Adds, removes or changes the health of a coordinator in the CoordinatorSet (created on first
+/// use). Only the signer registry authority rotates coordinators, and only registered
+/// Coordinator signers can be added. Active (not down) coordinators may end and anchor any match.
+pub fn handler(
+    ctx: Context<RotateCoordinator>,
+    coordinator: Pubkey,
+    action: u8, // CoordinatorSet::ROTATE_*
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let registry = &ctx.accounts.signer_registry;
+    
+    // Security: Only the signer registry authority rotates coordinators
+    require!(
+        ctx.accounts.authority.key() == registry.authority,
+        GameError::Unauthorized
+    );
+    
+    // Security: The set only holds registered coordinators
+    if action == CoordinatorSet::ROTATE_ADD {
+        require!(
+            registry.get_role(&coordinator) == Some(SignerRole::Coordinator),
+            GameError::SignerNotFound
+        );
+    }
+    
+    let coordinator_set = &mut ctx.accounts.coordinator_set;
+    coordinator_set.rotate(coordinator, action, clock.unix_timestamp)?;
+    
+    telemetry!("Coordinator set epoch {}: action {} on {} ({} coordinators)",
+         coordinator_set.epoch, action, coordinator, coordinator_set.count);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RotateCoordinator<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CoordinatorSet::MAX_SIZE,
+        seeds = [b"coordinator_set"],
+        bump
+    )]
+    pub coordinator_set: Box<Account<'info, CoordinatorSet>>,
+    
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, match_id_seed};
+use crate::error::GameError;
+
+/// Schedules a match for a calendar start (tournament rounds). Play can't begin before
+/// `scheduled_start`; if it hasn't begun Match::SCHEDULE_GRACE_SECONDS after it, anyone can
+/// void the match with expire_scheduled_match. 0 clears the schedule.
+/// Only the match authority can schedule, and only during the Dealing phase.
+pub fn handler(
+    ctx: Context<ScheduleMatch>,
+    match_id: String,
+    scheduled_start: i64, // Unix timestamp
+) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate authority is match authority
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Security: The schedule can only change before play starts
+    require!(
+        match_account.phase == 0,
+        GameError::InvalidPhase
+    );
+    require!(
+        scheduled_start == 0 || scheduled_start > clock.unix_timestamp,
+        GameError::InvalidTimestamp
+    );
+
+    match_account.scheduled_start = scheduled_start;
+
+    telemetry!("Match {} scheduled to start at {}", match_id, scheduled_start);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct ScheduleMatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    pub authority: Signer<'info>,
+}
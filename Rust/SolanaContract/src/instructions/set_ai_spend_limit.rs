@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::{UserAccount, ConfigAccount};
+use crate::events::AiSpendLimitSet;
+use crate::error::GameError;
+
+/// Sets a user's daily AI credit limit (0 = no limit), enforced by consume_ai_credits.
+/// Authority-gated (ConfigAccount.authority), on the user's or a parent's request.
+/// Tightening the limit applies immediately; raising or removing it only takes effect
+/// UserAccount::AC_LIMIT_RAISE_DELAY_SECONDS later, and every change is emitted, so a cap
+/// can't be lifted silently to spend past it.
+pub fn handler(
+    ctx: Context<SetAiSpendLimit>,
+    user_id: String,
+    daily_limit: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.config_account;
+    let clock = Clock::get()?;
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        GameError::Unauthorized
+    );
+    
+    let user_account = &mut ctx.accounts.user_account;
+    let previous_limit = user_account.ac_daily_limit;
+    let effective_at = user_account.set_ac_daily_limit(daily_limit, &clock);
+    
+    emit!(AiSpendLimitSet {
+        user_account: user_account.key(),
+        previous_limit,
+        daily_limit,
+        effective_at,
+    });
+    
+    telemetry!("AI spend limit for {} set to {} AC/day (effective at {})", user_id, daily_limit, effective_at);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(user_id: String)]
+pub struct SetAiSpendLimit<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    pub authority: Signer<'info>,
+}
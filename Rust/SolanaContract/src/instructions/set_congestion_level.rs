@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{ConfigAccount, SignerRegistry, SignerRole};
+use crate::error::GameError;
+
+/// Publishes the network congestion level coordinators observe (priority fees, dropped
+/// transactions). At ConfigAccount::CONGESTION_HIGH, submit_batch_moves requires at least
+/// ConfigAccount::CONGESTED_MIN_BATCH_MOVES moves so clients pack more moves per transaction.
+/// Registered coordinators only.
+pub fn handler(
+    ctx: Context<SetCongestionLevel>,
+    congestion_level: u8,
+) -> Result<()> {
+    // Security: Only registered coordinators report congestion
+    require!(
+        ctx.accounts.signer_registry.get_role(&ctx.accounts.coordinator.key()) == Some(SignerRole::Coordinator),
+        GameError::Unauthorized
+    );
+    require!(
+        congestion_level <= ConfigAccount::CONGESTION_HIGH,
+        GameError::InvalidCongestionLevel
+    );
+    
+    let config = &mut ctx.accounts.config_account;
+    config.congestion_level = congestion_level;
+    config.last_updated = Clock::get()?.unix_timestamp;
+    
+    telemetry!("Congestion level set to {} (min batch {} moves)", congestion_level, config.min_batch_moves());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCongestionLevel<'info> {
+    #[account(
+        mut,
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    pub coordinator: Signer<'info>,
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameRegistry, PotLedger};
+use crate::error::GameError;
+
+/// Turns betting on or off for a game and sets the rake taken from each settled pot.
+/// Betting actions (Brag blind/bet/show, Poker) are rejected with BettingDisabled until
+/// has_betting is set. Rake is capped at PotLedger::MAX_RAKE_BPS. Admin-only instruction.
+pub fn handler(
+    ctx: Context<SetGameBetting>,
+    game_id: u8,
+    has_betting: bool,
+    rake_bps: u16,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == registry.authority,
+        GameError::Unauthorized
+    );
+    require!(
+        rake_bps <= PotLedger::MAX_RAKE_BPS,
+        GameError::RakeTooHigh
+    );
+    
+    let game = registry.find_game_mut(game_id)
+        .ok_or(GameError::GameNotRegistered)?;
+    game.has_betting = has_betting;
+    game.rake_bps = rake_bps;
+    registry.last_updated = clock.unix_timestamp;
+    
+    telemetry!("Game betting set: game_id={}, has_betting={}, rake_bps={}", game_id, has_betting, rake_bps);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGameBetting<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub registry: Account<'info, GameRegistry>,
+    
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::GameRegistry;
+use crate::error::GameError;
+
+/// Sets the per-game GP entry cost and wager policy for a registered game.
+/// gp_cost = 0 falls back to ConfigAccount.gp_cost_per_game.
+/// Admin-only instruction.
+pub fn handler(
+    ctx: Context<SetGameFee>,
+    game_id: u8,
+    gp_cost: u32,
+    wager_allowed: bool,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == registry.authority,
+        GameError::Unauthorized
+    );
+    
+    let game = registry.find_game_mut(game_id)
+        .ok_or(GameError::GameNotRegistered)?;
+    game.gp_cost = gp_cost;
+    game.wager_allowed = wager_allowed;
+    registry.last_updated = clock.unix_timestamp;
+    
+    telemetry!("Game fee set: game_id={}, gp_cost={}, wager_allowed={}", game_id, gp_cost, wager_allowed);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGameFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub registry: Account<'info, GameRegistry>,
+    
+    pub authority: Signer<'info>,
+}
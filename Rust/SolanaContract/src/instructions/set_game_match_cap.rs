@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::GameRegistry;
+use crate::error::GameError;
+
+/// Sets the per-game daily match cap (matches created per UTC day, counted in GlobalStats).
+/// Throttles a single game during an exploit or infra incident without pausing the others;
+/// 0 removes the cap. Admin-only instruction.
+pub fn handler(
+    ctx: Context<SetGameMatchCap>,
+    game_id: u8,
+    daily_match_cap: u32,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == registry.authority,
+        GameError::Unauthorized
+    );
+    
+    let game = registry.find_game_mut(game_id)
+        .ok_or(GameError::GameNotRegistered)?;
+    game.daily_match_cap = daily_match_cap;
+    registry.last_updated = clock.unix_timestamp;
+    
+    telemetry!("Game match cap set: game_id={}, daily_match_cap={}", game_id, daily_match_cap);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGameMatchCap<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub registry: Account<'info, GameRegistry>,
+    
+    pub authority: Signer<'info>,
+}
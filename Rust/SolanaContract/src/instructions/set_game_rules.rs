@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameRegistry, RunRules};
+use crate::error::GameError;
+
+/// Sets the run rules variant (RunRules bitfield) for a registered game, so house-rule
+/// variants of a game (no wraparound, aces low, longer runs) can be registered.
+/// Admin-only instruction.
+pub fn handler(
+    ctx: Context<SetGameRules>,
+    game_id: u8,
+    rules_variant: u8,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == registry.authority,
+        GameError::Unauthorized
+    );
+    
+    require!(
+        RunRules::is_valid_bits(rules_variant),
+        GameError::InvalidRulesVariant
+    );
+    
+    let game = registry.find_game_mut(game_id)
+        .ok_or(GameError::GameNotRegistered)?;
+    game.rules_variant = rules_variant;
+    registry.last_updated = clock.unix_timestamp;
+    
+    telemetry!("Game rules set: game_id={}, rules_variant={:#04x}", game_id, rules_variant);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGameRules<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub registry: Account<'info, GameRegistry>,
+    
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::ConfigAccount;
+use crate::error::GameError;
+
+/// Sets the gift_gp limits: sender daily cap (0 disables gifting), minimum sender
+/// account age and the treasury fee in basis points (at most 100%).
+/// Authority-gated (ConfigAccount.authority).
+pub fn handler(
+    ctx: Context<SetGiftPolicy>,
+    gift_daily_cap_gp: u64,
+    gift_min_account_age: i64,
+    gift_fee_bps: u16,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config_account;
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        GameError::Unauthorized
+    );
+    require!(
+        gift_fee_bps <= ConfigAccount::MAX_FEE_BPS && gift_min_account_age >= 0,
+        GameError::InvalidGiftPolicy
+    );
+    
+    config.gift_daily_cap_gp = gift_daily_cap_gp;
+    config.gift_min_account_age = gift_min_account_age;
+    config.gift_fee_bps = gift_fee_bps;
+    config.last_updated = Clock::get()?.unix_timestamp;
+    
+    telemetry!("Gift policy set: cap={} GP/day, min age={}s, fee={} bps", 
+         gift_daily_cap_gp, gift_min_account_age, gift_fee_bps);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGiftPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    pub authority: Signer<'info>,
+}
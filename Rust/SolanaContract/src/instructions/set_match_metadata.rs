@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, match_id_seed};
+use crate::error::GameError;
+
+/// Attaches client metadata to a match (tournament id, table name, stream URL hash, ...):
+/// up to Match::MAX_METADATA_ENTRIES entries of an 8-byte key and a 32-byte value.
+/// Setting an existing key replaces its value; an all-zero value removes the entry.
+/// Only the match authority can set metadata, and only during the Dealing phase.
+pub fn handler(
+    ctx: Context<SetMatchMetadata>,
+    match_id: String,
+    key: [u8; 8],
+    value: [u8; 32],
+) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate authority is signer and matches
+    require!(
+        ctx.accounts.authority.is_signer,
+        GameError::Unauthorized
+    );
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Security: Metadata is fixed once play starts
+    require!(
+        match_account.phase == 0,
+        GameError::InvalidPhase
+    );
+
+    match_account.set_metadata(key, value)?;
+
+    telemetry!("Metadata {:?} set for match {}", key, match_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct SetMatchMetadata<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    pub authority: Signer<'info>,
+}
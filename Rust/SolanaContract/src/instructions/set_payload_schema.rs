@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameRegistry, PayloadSchema, PayloadField};
+use crate::error::GameError;
+
+/// Sets (or clears) the move payload schema for one action of a registered game.
+/// Per spec Section 16.5: Game registry system.
+/// Admin-only instruction.
+pub fn handler(
+    ctx: Context<SetPayloadSchema>,
+    game_id: u8,
+    action_type: u8,
+    schema: Option<PayloadSchema>, // None = clear (game-specific checks only)
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+    
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == registry.authority,
+        GameError::Unauthorized
+    );
+    
+    require!(
+        (action_type as usize) < PayloadSchema::MAX_ACTIONS,
+        GameError::InvalidAction
+    );
+    
+    let schema = match schema {
+        Some(schema) => {
+            // Validate descriptor: bounds within Move payload cap, known field tags,
+            // variable-length Bytes only as the last field
+            require!(
+                schema.min_len <= schema.max_len && schema.max_len <= 128,
+                GameError::InvalidPayload
+            );
+            let mut fixed_len = 0usize;
+            let mut ended = false;
+            for &tag in schema.fields.iter() {
+                let field = PayloadField::from_u8(tag).ok_or(GameError::InvalidPayload)?;
+                require!(!ended || field == PayloadField::End, GameError::InvalidPayload);
+                ended = field == PayloadField::End || field == PayloadField::Bytes;
+                fixed_len += field.size();
+            }
+            require!(fixed_len <= schema.max_len as usize, GameError::InvalidPayload);
+            PayloadSchema { defined: true, ..schema }
+        }
+        None => PayloadSchema::UNDEFINED,
+    };
+    
+    let game = registry.find_game_mut(game_id)
+        .ok_or(GameError::GameNotRegistered)?;
+    game.payload_schemas[action_type as usize] = schema;
+    registry.last_updated = clock.unix_timestamp;
+    
+    telemetry!("Payload schema set: game_id={}, action={}, defined={}", game_id, action_type, schema.defined);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPayloadSchema<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub registry: Account<'info, GameRegistry>,
+    
+    pub authority: Signer<'info>,
+}
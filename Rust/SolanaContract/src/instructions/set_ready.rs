@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, DeckCommitment, match_id_seed};
+use crate::events::PlayerReady;
+use crate::error::GameError;
+use super::start_match::begin_play;
+
+/// Marks a player ready (or not) in a player-consent match.
+/// Once at least min_players seated players are ready and every hand is committed,
+/// the match starts without the authority, so peer-hosted lobbies don't depend on
+/// the coordinator being online. Otherwise the vote is recorded and a later
+/// set_ready (or start_match) starts it.
+pub fn handler(
+    ctx: Context<SetReady>,
+    match_id: String,
+    user_id: String,  // Firebase UID (per spec: use user IDs, not Pubkeys)
+    ready: bool,
+) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate player is signer
+    require!(
+        ctx.accounts.player.is_signer,
+        GameError::Unauthorized
+    );
+
+    // Security: Must be in Dealing phase
+    require!(
+        match_account.phase == 0,
+        GameError::InvalidPhase
+    );
+
+    // Only matches created with player-consent start accept ready votes
+    require!(
+        match_account.player_consent_start(),
+        GameError::ConsentStartDisabled
+    );
+
+    // Convert user_id String to fixed-size array
+    let user_id_bytes = user_id.as_bytes();
+    require!(
+        user_id_bytes.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    let mut user_id_array = [0u8; 64];
+    user_id_array[..user_id_bytes.len()].copy_from_slice(user_id_bytes);
+    
+    // Security: Validate player is in the match (find by user_id)
+    let player_index = match_account.find_player_index(&user_id_array)
+        .ok_or(GameError::PlayerNotInMatch)?;
+
+    // Security: Only the seat's own wallet can mark it ready
+    require!(
+        match_account.is_seat_wallet(player_index, &ctx.accounts.player.key()),
+        GameError::Unauthorized
+    );
+
+    // A player can only vouch for a deal they have committed to
+    if ready {
+        require!(
+            match_account.get_committed_hand_hash(player_index).is_some(),
+            GameError::HandsNotCommitted
+        );
+    }
+
+    let ready_count = match_account.set_ready(player_index, ready);
+
+    emit!(PlayerReady {
+        match_account: match_account.key(),
+        player_index: player_index as u8,
+        ready,
+        ready_count,
+    });
+
+    if ready_count >= match_account.get_min_players() && match_account.all_hands_committed() {
+        let deck_size = ctx.accounts.deck_commitment
+            .as_ref()
+            .map(|deck| deck.deck_size)
+            .unwrap_or(DeckCommitment::STANDARD_DECK_SIZE);
+        begin_play(match_account, deck_size, clock.unix_timestamp)?;
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct SetReady<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    /// Optional deck commitment; when present its deck_size bounds the deal
+    #[account(
+        seeds = [b"deck_commitment", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub deck_commitment: Option<Account<'info, DeckCommitment>>,
+    
+    pub player: Signer<'info>,
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, RuleParams, match_id_seed};
+use crate::error::GameError;
+
+/// Sets the match's variant rules (starting hand size, point target, per-seat handicaps; see
+/// RuleParams) so casual lobbies can run variants without registering a new game.
+/// Part of match setup: only the match authority, and only before the first player joins, so
+/// everyone joins knowing the rules (send it in the create_match transaction). Handicaps
+/// become the seats' starting cumulative scores.
+pub fn handler(
+    ctx: Context<SetRuleParams>,
+    match_id: String,
+    rule_params: [u8; 16],
+) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate authority is match authority
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Security: Rules are fixed once anyone has joined
+    require!(
+        match_account.phase == 0 && match_account.player_count == 0,
+        GameError::InvalidPhase
+    );
+    require!(
+        RuleParams::is_valid_bytes(&rule_params),
+        GameError::InvalidRuleParams
+    );
+
+    match_account.rule_params = rule_params;
+    let params = match_account.rule_params();
+    for (score, handicap) in match_account.cumulative_scores.iter_mut().zip(params.handicaps) {
+        *score = handicap as i32;
+    }
+
+    telemetry!("Rule params set for match {}: hand size {}, point target {}",
+         match_id, params.hand_size, params.point_target);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct SetRuleParams<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    pub authority: Signer<'info>,
+}
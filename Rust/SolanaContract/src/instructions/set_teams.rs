@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, match_id_seed};
+use crate::error::GameError;
+
+/// Assigns players to teams/partnerships (Bridge and other partner games).
+/// teams[i] is the team id (1-5) of the player in slot i, 0 = no team.
+/// Only the match authority can set teams, and only during the Dealing phase.
+pub fn handler(ctx: Context<SetTeams>, match_id: String, teams: [u8; 10]) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate authority is signer and matches
+    require!(
+        ctx.accounts.authority.is_signer,
+        GameError::Unauthorized
+    );
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Security: Teams can only change before play starts
+    require!(
+        match_account.phase == 0,
+        GameError::InvalidPhase
+    );
+
+    // Validate team ids (slots beyond max_players must stay unassigned)
+    let max_players = match_account.get_max_players() as usize;
+    for (i, &team) in teams.iter().enumerate() {
+        require!(
+            team <= Match::MAX_TEAMS && (i < max_players || team == 0),
+            GameError::InvalidTeam
+        );
+    }
+
+    match_account.teams = teams;
+
+    telemetry!("Teams set for match {}: {:?}", match_id, teams);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct SetTeams<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    pub authority: Signer<'info>,
+}
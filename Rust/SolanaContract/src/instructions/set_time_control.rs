@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, match_id_seed};
+use crate::error::GameError;
+
+/// Enables chess-style time control for competitive/tournament matches.
+/// Every player starts with `initial_seconds` in their time bank and gets `increment_seconds`
+/// back after each completed turn. A player whose bank runs out can be forfeited with claim_timeout.
+/// Only the match authority can set time control, and only during the Dealing phase.
+pub fn handler(
+    ctx: Context<SetTimeControl>,
+    match_id: String,
+    initial_seconds: u32,
+    increment_seconds: u32,
+) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate authority is signer and matches
+    require!(
+        ctx.accounts.authority.is_signer,
+        GameError::Unauthorized
+    );
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Security: Time control can only change before play starts (or between rounds)
+    require!(
+        match_account.phase == 0,
+        GameError::InvalidPhase
+    );
+
+    // A zero bank would forfeit the first player immediately
+    require!(
+        initial_seconds > 0,
+        GameError::InvalidTimeControl
+    );
+
+    match_account.time_bank = [initial_seconds; 10];
+    match_account.time_increment = increment_seconds;
+    match_account.set_time_control(true);
+
+    telemetry!("Time control set for match {}: {}s + {}s/move", match_id, initial_seconds, increment_seconds);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct SetTimeControl<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    pub authority: Signer<'info>,
+}
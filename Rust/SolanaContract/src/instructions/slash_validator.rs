@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::ValidatorReputation;
+use crate::state::{ValidatorReputation, SignerRegistry};
 use crate::error::GameError;
 
 /**
@@ -7,7 +7,9 @@ use crate::error::GameError;
  * Per critique Issue #3, #5, Spec Section 33.3: Validator slashing mechanism.
  * 
  * Only the authority can slash validators.
- * Slashed amount is transferred to the authority or treasury.
+ * The slashed amount is held in slashed_escrow, not burned: the validator has
+ * SLASH_APPEAL_WINDOW_SECONDS to appeal_slash. Without an appeal the slash becomes final
+ * once the window passes; with one, resolve_slash_appeal decides.
  */
 pub fn handler(
     ctx: Context<SlashValidator>,
@@ -15,12 +17,20 @@ pub fn handler(
     amount: u64,
     reason: u8, // 0=malicious, 1=negligent, 2=inactivity
 ) -> Result<()> {
+    let clock = Clock::get()?;
+    
     // Security: Validate authority is signer
     require!(
         ctx.accounts.authority.is_signer,
         GameError::Unauthorized
     );
     
+    // Security: Only the signer registry authority (admin council) slashes
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.signer_registry.authority,
+        GameError::Unauthorized
+    );
+    
     // Security: Validate amount is positive
     require!(
         amount > 0,
@@ -29,7 +39,7 @@ pub fn handler(
     
     // Security: Validate reason is valid
     require!(
-        reason <= 2,
+        reason <= ValidatorReputation::SLASH_REASON_INACTIVITY,
         GameError::InvalidPayload
     );
     
@@ -42,33 +52,16 @@ pub fn handler(
         GameError::InvalidPayload
     );
     
-    // Security: Validate validator has sufficient stake
-    require!(
-        validator_account.stake >= amount,
-        GameError::InsufficientFunds
-    );
+    // One slash at a time: the previous one must be final (window passed or appeal resolved).
+    // Slash the stake into escrow (returned if an appeal overturns it) and cut reputation.
+    validator_account.apply_slash(amount, reason, clock.unix_timestamp)?;
     
-    // Slash the stake
-    validator_account.stake = validator_account.stake
-        .checked_sub(amount)
-        .ok_or(GameError::InsufficientFunds)?;
-    
-    // Update reputation (slash reduces reputation)
-    let reputation_penalty = match reason {
-        0 => 0.5, // Malicious: 50% reputation loss
-        1 => 0.2, // Negligent: 20% reputation loss
-        2 => 0.1, // Inactivity: 10% reputation loss
-        _ => 0.0,
-    };
-    validator_account.reputation = (validator_account.reputation * (1.0 - reputation_penalty)).max(0.0);
-    
-    // Transfer slashed amount from validator stake to authority (or treasury in production)
     // Note: In production, stake would be in a separate escrow account
     // For now, we just update the reputation account's stake field
     // The actual SOL transfer would happen when stake is withdrawn
     
-    msg!("Slashed validator {}: {} lamports (reason: {})", 
-         validator_pubkey, amount, reason);
+    telemetry!("Slashed validator {}: {} lamports (reason: {}, appealable until {})", 
+         validator_pubkey, amount, reason, validator_account.appeal_deadline());
     
     Ok(())
 }
@@ -83,6 +76,12 @@ pub struct SlashValidator<'info> {
     )]
     pub validator_reputation: Account<'info, ValidatorReputation>,
     
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
     #[account(mut)]
     pub authority: Signer<'info>,
 }
@@ -1,16 +1,19 @@
 use anchor_lang::prelude::*;
-use crate::state::Match;
+use crate::state::{Match, DeckCommitment, OpenLobbyIndex, match_id_seed};
+use crate::instructions::create_match::sync_lobby_listing;
+use crate::events::{MatchStarted, TurnChanged};
 use crate::error::GameError;
 
 pub fn handler(ctx: Context<StartMatch>, match_id: String) -> Result<()> {
     let match_account = &mut ctx.accounts.match_account;
+    let clock = Clock::get()?;
     
     // Security: Validate match_id matches
     let match_id_bytes = match_id.as_bytes();
     require!(
         match_id_bytes.len() == 36 && 
         match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
-        GameError::InvalidPayload
+        GameError::MatchIdMismatch
     );
 
     // Security: Validate authority is signer and matches
@@ -29,6 +32,30 @@ pub fn handler(ctx: Context<StartMatch>, match_id: String) -> Result<()> {
         GameError::InvalidPhase
     );
 
+    let deck_size = ctx.accounts.deck_commitment
+        .as_ref()
+        .map(|deck| deck.deck_size)
+        .unwrap_or(DeckCommitment::STANDARD_DECK_SIZE);
+    begin_play(match_account, deck_size, clock.unix_timestamp)?;
+    
+    // Public matches leave the lobby listing once play begins
+    sync_lobby_listing(ctx.accounts.lobby_index.as_mut(), match_account, clock.unix_timestamp)
+}
+
+/// Validates that a Dealing-phase match is ready and moves it into Playing.
+/// Shared by start_match (authority) and set_ready (player quorum).
+pub(crate) fn begin_play(match_account: &mut Account<Match>, deck_size: u8, now: i64) -> Result<()> {
+    // Scheduled matches: not before the start time, and not once the grace window has passed
+    // (the match is then left to expire_scheduled_match and its refunds)
+    require!(
+        !match_account.before_scheduled_start(now),
+        GameError::ScheduledStartNotReached
+    );
+    require!(
+        !match_account.schedule_missed(now),
+        GameError::ScheduledStartMissed
+    );
+
     // Security: Validate minimum players requirement (game-specific)
     let min_players = match_account.get_min_players();
     require!(
@@ -43,12 +70,31 @@ pub fn handler(ctx: Context<StartMatch>, match_id: String) -> Result<()> {
         GameError::InsufficientPlayers
     );
 
+    // Partner games (e.g., Bridge): every player must be assigned to a team
+    if match_account.get_game_type().requires_teams() || match_account.has_teams() {
+        require!(
+            match_account.teams_complete(),
+            GameError::TeamsIncomplete
+        );
+    }
+
+    // Dealing: every player must have committed a hand, and the dealt cards must fit the deck
+    require!(
+        match_account.all_hands_committed(),
+        GameError::HandsNotCommitted
+    );
+    require!(
+        match_account.dealt_card_count() <= deck_size as u16,
+        GameError::DealExceedsDeck
+    );
+    match_account.deck_size = deck_size;
+
     // Convert game_name array to string for logging (null-terminated)
     let game_name_str = String::from_utf8_lossy(&match_account.game_name)
         .trim_end_matches('\0')
         .to_string();
 
-    msg!("Starting {} match with {} players (min: {}, max: {})", 
+    telemetry!("Starting {} match with {} players (min: {}, max: {})", 
          game_name_str, 
          match_account.player_count,
          min_players,
@@ -57,21 +103,23 @@ pub fn handler(ctx: Context<StartMatch>, match_id: String) -> Result<()> {
     // Transition to playing phase
     match_account.phase = 1; // Playing
     match_account.set_all_players_joined(true);
+    match_account.start_transcript(); // Header is fixed once the first round starts
     
-    // Per critique: initialize committed hand hashes
-    // In production, players would commit their hand hashes here
-    // For now, initialize to all zeros (will be set when hands are dealt)
-    match_account.committed_hand_hashes = [0u8; 320];
-    
-    // Per critique Issue #1: Initialize hand sizes (will be set when hands are dealt)
-    // For CLAIM game, each player starts with 13 cards after dealing
-    // But we initialize to 0 here - will be set by commit_hand instruction
-    match_account.hand_sizes = [0u8; 10];
+    // Hand commitments and sizes from commit_hand carry into play untouched
     
     // Per critique Issue #1: Initialize floor card hash (no floor card yet)
     match_account.floor_card_hash = [0u8; 32];
 
-    msg!("Match started: {} with {} players", match_id, match_account.player_count);
+    // First player's turn clock and deadline start now
+    match_account.start_turn(now);
+    emit!(TurnChanged::from_match(match_account));
+
+    emit!(MatchStarted {
+        match_account: match_account.key(),
+        round_number: match_account.round_number,
+        player_count: match_account.player_count,
+        transcript_hash: match_account.transcript_hash,
+    });
     Ok(())
 }
 
@@ -80,11 +128,26 @@ pub fn handler(ctx: Context<StartMatch>, match_id: String) -> Result<()> {
 pub struct StartMatch<'info> {
     #[account(
         mut,
-        seeds = [b"match", match_id.as_bytes()],
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
         bump
     )]
     pub match_account: Account<'info, Match>,
     
+    /// Optional deck commitment; when present its deck_size bounds the deal
+    #[account(
+        seeds = [b"deck_commitment", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub deck_commitment: Option<Account<'info, DeckCommitment>>,
+    
+    /// The game's open lobby index (required for public matches only)
+    #[account(
+        mut,
+        seeds = [b"open_lobbies".as_ref(), &[match_account.game_type]],
+        bump
+    )]
+    pub lobby_index: Option<Account<'info, OpenLobbyIndex>>,
+    
     pub authority: Signer<'info>,
 }
 
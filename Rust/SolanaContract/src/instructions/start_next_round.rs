@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, match_id_seed};
+use crate::events::RoundStarted;
+use crate::error::GameError;
+use crate::instructions::calculate_scores::calculate_scores_from_state;
+
+/// Starts the next round of a multi-round match.
+/// The finished round's scores are added to cumulative_scores, then hand commitments,
+/// floor card and declared suits are reset. The match returns to the Dealing phase so
+/// players commit new hands before start_match begins play again.
+pub fn handler(ctx: Context<StartNextRound>, match_id: String) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate authority is signer and matches
+    require!(
+        ctx.accounts.authority.is_signer,
+        GameError::Unauthorized
+    );
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Security: Current round must be over (showdown called)
+    require!(
+        match_account.phase == 2,
+        GameError::InvalidPhase
+    );
+
+    // Security: Match must not be finalized/anchored
+    require!(
+        !match_account.has_match_hash(),
+        GameError::MatchAlreadyEnded
+    );
+
+    require!(
+        match_account.has_more_rounds(),
+        GameError::NoRoundsRemaining
+    );
+
+    // Accumulate the finished round's scores; a variant's point target ends the match early
+    let round_scores = calculate_scores_from_state(match_account);
+    require!(
+        !match_account.point_target_reached(&round_scores),
+        GameError::NoRoundsRemaining
+    );
+    match_account.accumulate_round_scores(&round_scores);
+
+    // Reset per-round state, keep cumulative scores and nonces
+    match_account.reset_round_state();
+    match_account.round_number += 1;
+    match_account.phase = 0; // Dealing (players commit new hands)
+    match_account.ended_at = 0;
+    
+    // Rotate the starting player each round
+    if match_account.player_count > 0 {
+        match_account.current_player = (match_account.round_number - 1) % match_account.player_count;
+    }
+
+    emit!(RoundStarted {
+        match_account: match_account.key(),
+        round_number: match_account.round_number,
+        rounds_to_play: match_account.rounds_to_play,
+        cumulative_scores: match_account.cumulative_scores,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct StartNextRound<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    pub authority: Signer<'info>,
+}
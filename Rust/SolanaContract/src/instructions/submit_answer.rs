@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, CrosswordPuzzle, require_not_sanctioned, match_id_seed};
+use crate::events::AnswerAccepted;
+use crate::error::GameError;
+
+/// Answers a crossword clue: the normalized `answer` is hashed with `clue_index` and proven
+/// against the committed answer_root (see CrosswordPuzzle). The first player to answer a clue
+/// scores one point per letter. Not turn-based: any player may answer during play.
+pub fn handler(
+    ctx: Context<SubmitAnswer>,
+    match_id: String,
+    user_id: String,
+    clue_index: u8,
+    answer: String,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    let puzzle = &mut ctx.accounts.crossword_puzzle;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate match is in play
+    require!(
+        match_account.phase == 1 && !match_account.is_ended(),
+        GameError::InvalidPhase
+    );
+    match_account.settle_expired_pause(clock.unix_timestamp);
+    require!(
+        !match_account.is_paused(clock.unix_timestamp),
+        GameError::MatchPaused
+    );
+
+    // Security: Validate player is in match
+    require!(
+        user_id.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    let seat = match_account.find_player_index(user_id.as_bytes())
+        .ok_or(GameError::PlayerNotInMatch)?;
+
+    // Security: Only the wallet that took the seat claims for it (the first solver scores)
+    require!(
+        match_account.is_seat_wallet(seat, &ctx.accounts.player.key()),
+        GameError::Unauthorized
+    );
+
+    // Security: Banned or suspended users can't play
+    require_not_sanctioned(&ctx.accounts.sanction, &clock)?;
+
+    let points = puzzle.solve(seat, clue_index, answer.as_bytes(), &proof)?;
+
+    emit!(AnswerAccepted {
+        match_account: match_account.key(),
+        player_index: seat as u8,
+        clue_index,
+        points,
+        total_score: puzzle.scores[seat],
+        clues_remaining: puzzle.clue_count - puzzle.clues_solved,
+    });
+    telemetry!("Seat {} solved clue {} in match {} ({} of {})",
+         seat, clue_index, match_id, puzzle.clues_solved, puzzle.clue_count);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String, user_id: String)]
+pub struct SubmitAnswer<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Box<Account<'info, Match>>,
+    
+    #[account(
+        mut,
+        seeds = [b"crossword", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub crossword_puzzle: Box<Account<'info, CrosswordPuzzle>>,
+    
+    /// CHECK: User's sanction PDA (may not exist); checked by require_not_sanctioned
+    #[account(
+        seeds = [b"sanction", user_id.as_bytes()],
+        bump
+    )]
+    pub sanction: UncheckedAccount<'info>,
+    
+    pub player: Signer<'info>,
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::state::{Match, Move};
+use crate::state::{Match, Move, ConfigAccount, GameRegistry, EndReason, require_not_sanctioned, match_id_seed};
+use crate::events::{MoveSubmitted, TurnChanged};
 use crate::validation;
 use crate::error::GameError;
 
@@ -18,6 +19,7 @@ pub struct BatchMove {
 /// - All moves must be from the same player and in the same match.
 /// - For turn-based games: Only works for moves that don't require turn order (e.g., declare intent, rebuttal).
 /// - Turn-based moves (pick_up, decline) can only be batched if the player has multiple consecutive turns.
+/// - Three Card Brag betting actions (11-15) are not batchable; use submit_move.
 /// - Use case: Primarily for queuing offline moves or non-turn-based actions (declare intent + call showdown).
 /// 
 /// **NOTE:** This is NOT meant to batch moves across different players or different turns in a turn-based game.
@@ -28,32 +30,105 @@ pub fn handler(
     user_id: String,  // Firebase UID (per spec: use user IDs, not Pubkeys)
     moves: Vec<BatchMove>,  // Up to 5 moves
 ) -> Result<()> {
-    let match_account = &mut ctx.accounts.match_account;
     let clock = Clock::get()?;
     
-    // Validate batch size (up to 5 moves)
-    require!(
-        moves.len() > 0 && moves.len() <= 5,
-        GameError::InvalidPayload
-    );
-    
     // Security: Validate player is signer
     require!(
         ctx.accounts.player.is_signer,
         GameError::Unauthorized
     );
     
+    // Security: Banned or suspended users can't submit moves
+    require_not_sanctioned(&ctx.accounts.sanction, &clock)?;
+    
+    let match_account = &mut ctx.accounts.match_account;
+    
     // Security: Validate match_id matches
     let match_id_bytes = match_id.as_bytes();
     require!(
         match_id_bytes.len() == 36 && 
         match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
-        GameError::InvalidPayload
+        GameError::MatchIdMismatch
     );
     
-    // Security: Validate match is in playing phase
+    let player = ctx.accounts.player.key();
+    let records = apply_batch_state(
+        match_account,
+        &ctx.accounts.game_registry,
+        &ctx.accounts.config_account,
+        player,
+        &user_id,
+        &moves,
+        &clock,
+    )?;
+    
+    for (batch_idx, (record, batch_move)) in records.iter().zip(moves.iter()).enumerate() {
+        // Get move account by index (avoid moving out of array)
+        let move_account = match batch_idx {
+            0 => &mut ctx.accounts.move_account_0,
+            1 => &mut ctx.accounts.move_account_1,
+            2 => &mut ctx.accounts.move_account_2,
+            3 => &mut ctx.accounts.move_account_3,
+            4 => &mut ctx.accounts.move_account_4,
+            _ => return Err(GameError::BatchTooLarge.into()),
+        };
+        
+        // Create move account
+        move_account.match_id = match_account.match_id;
+        move_account.player = player;
+        move_account.move_index = record.move_index;
+        move_account.action_type = batch_move.action_type;
+        move_account.set_payload(&batch_move.payload)?;
+        move_account.timestamp = clock.unix_timestamp;
+        move_account.rent_payer = player;
+        emit!(MoveSubmitted {
+            match_account: match_account.key(),
+            move_index: record.move_index,
+            player_index: record.player_index as u8,
+            player,
+            action_type: batch_move.action_type,
+            nonce: batch_move.nonce,
+            transcript_hash: record.transcript_hash,
+        });
+    }
+    
+    telemetry!("Batch moves submitted: match_id={}, count={}", match_id, moves.len());
+    Ok(())
+}
+
+/// A batched move as applied to match state (for its Move account and event).
+pub(crate) struct BatchMoveRecord {
+    pub move_index: u32,
+    pub player_index: usize,
+    pub transcript_hash: [u8; 32], // Running replay hash after this move
+}
+
+/// Runs the batch pipeline (validation and state transitions) against match state only.
+/// Used on-chain by submit_batch_moves and off-chain by `client::simulate_batch`.
+pub(crate) fn apply_batch_state(
+    match_account: &mut Match,
+    game_registry: &GameRegistry,
+    config: &ConfigAccount,
+    player: Pubkey,
+    user_id: &str,
+    moves: &[BatchMove],
+    clock: &Clock,
+) -> Result<Vec<BatchMoveRecord>> {
+    // Validate batch size (up to 5 moves)
     require!(
-        match_account.phase == 1,
+        moves.len() > 0 && moves.len() <= 5,
+        GameError::BatchTooLarge
+    );
+    
+    // Under high congestion, batches must be worth the transaction (single moves use submit_move)
+    require!(
+        moves.len() >= config.min_batch_moves(),
+        GameError::BatchTooSmall
+    );
+    
+    // Security: Validate match is in playing phase (or showdown, for rebuttals)
+    require!(
+        match_account.phase == 1 || match_account.phase == 3,
         GameError::InvalidPhase
     );
     
@@ -63,6 +138,19 @@ pub fn handler(
         GameError::MatchAlreadyEnded
     );
     
+    // Security: Rebuttals close with the showdown window
+    require!(
+        match_account.phase != 3 || clock.unix_timestamp < match_account.rebuttal_deadline,
+        GameError::RebuttalWindowClosed
+    );
+    
+    // Security: No moves while the match is paused (live-ops incident)
+    match_account.settle_expired_pause(clock.unix_timestamp);
+    require!(
+        !match_account.is_paused(clock.unix_timestamp),
+        GameError::MatchPaused
+    );
+    
     // Security: Validate minimum players requirement
     require!(
         match_account.has_minimum_players(),
@@ -73,7 +161,7 @@ pub fn handler(
     let user_id_bytes = user_id.as_bytes();
     require!(
         user_id_bytes.len() <= 64,
-        GameError::InvalidPayload
+        GameError::UserIdTooLong
     );
     let mut user_id_array = [0u8; 64];
     let copy_len = user_id_bytes.len().min(64);
@@ -83,66 +171,79 @@ pub fn handler(
     let player_index = match_account.find_player_index(&user_id_array)
         .ok_or(GameError::PlayerNotInMatch)?;
     
+    // Security: The signer must own the seat user_id names (player_wallets)
+    require!(
+        match_account.is_seat_wallet(player_index, &player),
+        GameError::Unauthorized
+    );
+    
     // Process each move in the batch
     let mut current_move_index = match_account.move_count;
     let mut current_player_index = player_index;
     
-    // Convert match_id to fixed array
-    let mut match_id_array = [0u8; 36];
-    let copy_len = match_id_bytes.len().min(36);
-    match_id_array[..copy_len].copy_from_slice(&match_id_bytes[..copy_len]);
+    let mut records = Vec::with_capacity(moves.len());
     
-    for (batch_idx, batch_move) in moves.iter().enumerate() {
+    for batch_move in moves {
+        // Security: Validate action_type bounds
         require!(
-            batch_idx < 5,
-            GameError::InvalidPayload
+            batch_move.action_type <= 15,
+            GameError::InvalidAction
         );
-        // Get move account by index (avoid moving out of array)
-        let move_account = match batch_idx {
-            0 => &mut ctx.accounts.move_account_0,
-            1 => &mut ctx.accounts.move_account_1,
-            2 => &mut ctx.accounts.move_account_2,
-            3 => &mut ctx.accounts.move_account_3,
-            4 => &mut ctx.accounts.move_account_4,
-            _ => return Err(GameError::InvalidPayload.into()),
-        };
-        // Security: Validate action_type bounds
+        
+        // Brag betting passes the turn and may end the match: one move per transaction
         require!(
-            batch_move.action_type <= 4,
+            !validation::brag::is_brag_action(batch_move.action_type),
             GameError::InvalidAction
         );
         
         // Security: Validate payload size
         require!(
             batch_move.payload.len() <= 128,
-            GameError::InvalidPayload
+            GameError::PayloadTooLarge
         );
         
-        // Security: Validate nonce (must be greater than last nonce)
-        let last_nonce = match_account.get_last_nonce(current_player_index);
+        // Anti-spam: Per-player move rate limit (each batched move counts against the slot limit)
         require!(
-            batch_move.nonce > last_nonce,
+            match_account.record_move_for_rate_limit(player_index, clock.slot, config.max_moves_per_slot),
+            GameError::MoveRateLimited
+        );
+        
+        // Anti-spam: Non-turn actions have a per-player cooldown, also within a batch
+        require!(
+            match_account.record_action_cooldown(current_player_index, batch_move.action_type, clock.slot, config.action_cooldown_slots),
+            GameError::ActionOnCooldown
+        );
+        
+        // Security: Validate nonce (used once, above the last nonce or unused within the window)
+        require!(
+            match_account.is_nonce_fresh(current_player_index, batch_move.nonce),
             GameError::InvalidNonce
         );
         
-        // Update last nonce for this player
-        match_account.set_last_nonce(current_player_index, batch_move.nonce);
+        // Anti-collusion: Evidence counters for disputes and the coordinator
+        match_account.record_move_anomalies(current_player_index, &player, clock.slot, batch_move.action_type, batch_move.nonce);
+        
+        // Mark the nonce used for this player
+        match_account.record_nonce(current_player_index, batch_move.nonce);
+        
+        // Security: Generic payload schema check before game-specific logic
+        validation::validate_payload_schema(game_registry, match_account.game_type, batch_move.action_type, &batch_move.payload)?;
         
         // Validate move legality (game-specific validation)
-        validation::validate_move(match_account, current_player_index, batch_move.action_type, &batch_move.payload)?;
+        let run_rules = game_registry.run_rules_for(match_account.game_type);
+        validation::validate_move(match_account, current_player_index, batch_move.action_type, &batch_move.payload, run_rules)?;
         
-        // Per critique: Card state validation for moves that involve cards (rebuttal)
-        if batch_move.action_type == 4 { // Rebuttal action
-            validation::validate_card_hash(match_account, current_player_index, &batch_move.payload)?;
+        // Per critique: Card state validation for moves that involve cards (showdown, rebuttal)
+        if batch_move.action_type == 3 || batch_move.action_type == 4 { // Call showdown / rebuttal
+            validation::validate_card_hash(match_account, current_player_index, &batch_move.payload, run_rules)?;
         }
         
-        // Create move account
-        move_account.match_id = match_id_array;
-        move_account.player = ctx.accounts.player.key();
-        move_account.move_index = current_move_index;
-        move_account.action_type = batch_move.action_type;
-        move_account.set_payload(&batch_move.payload)?;
-        move_account.timestamp = clock.unix_timestamp;
+        match_account.record_transcript_move(current_move_index, &player, batch_move.action_type, &batch_move.payload);
+        records.push(BatchMoveRecord {
+            move_index: current_move_index,
+            player_index: current_player_index,
+            transcript_hash: match_account.transcript_hash,
+        });
         
         // Update match state based on action type (same logic as submit_move)
         match batch_move.action_type {
@@ -150,7 +251,7 @@ pub fn handler(
                 // Declare intent: record the declared suit
                 if batch_move.payload.len() >= 1 {
                     let suit = batch_move.payload[0];
-                    require!(suit <= 3, GameError::InvalidPayload);
+                    require!(suit <= 3, GameError::InvalidSuit);
                     match_account.set_declared_suit(current_player_index, suit);
                 }
             }
@@ -160,15 +261,56 @@ pub fn handler(
                 match_account.clear_floor_card_hash();
                 let current_size = match_account.get_hand_size(current_player_index);
                 match_account.set_hand_size(current_player_index, current_size.saturating_add(1));
+                match_account.record_cards_dealt(1);
             }
             1 => {
                 // Decline: advance turn, clear floor card
                 match_account.set_floor_card_revealed(false);
             }
+            5 | 6 => {
+                // Play card / discard: card leaves the hand, remember which one
+                let current_size = match_account.get_hand_size(current_player_index);
+                match_account.set_hand_size(current_player_index, current_size.saturating_sub(1));
+                let mut card_hash = [0u8; 32];
+                card_hash.copy_from_slice(&batch_move.payload[0..32]);
+                match_account.record_discard(card_hash);
+            }
             3 => {
-                // Call showdown: transition to ended phase
-                match_account.phase = 2; // Ended
-                match_account.ended_at = clock.unix_timestamp;
+                // Call showdown: record the claimed run and open the rebuttal window
+                // (ends at once if nobody can rebut)
+                let claimed_run = validation::run_suit_and_top(&validation::parse_run(&batch_move.payload, run_rules)?, run_rules);
+                match_account.begin_showdown(current_player_index, claimed_run, clock.unix_timestamp, config.rebuttal_window());
+                if match_account.all_rebuttals_in() {
+                    match_account.finish_showdown(clock.unix_timestamp);
+                }
+            }
+            4 => {
+                // Rebuttal: the round ends once every undeclared player has responded
+                match_account.record_rebuttal(current_player_index);
+                if match_account.all_rebuttals_in() {
+                    match_account.finish_showdown(clock.unix_timestamp);
+                }
+            }
+            7 => {
+                // Resign: match ends immediately
+                match_account.end_with_outcome(EndReason::Resignation, current_player_index as u8, clock.unix_timestamp);
+            }
+            8 => {
+                // Offer draw: other players answer with AcceptDraw (or decline by playing on)
+                match_account.offer_draw(current_player_index);
+            }
+            9 => {
+                // Accept draw: match ends once every player agreed
+                let all_agreed = match_account.accept_draw(current_player_index);
+                if all_agreed {
+                    let offered_by = match_account.draw_offered_by;
+                    match_account.end_with_outcome(EndReason::Draw, offered_by, clock.unix_timestamp);
+                }
+            }
+            10 => {
+                // Declare meld (Rummy): the cards leave the hand face-up and score for the player
+                let meld = validation::rummy::parse_meld(&batch_move.payload, game_registry.run_rules_for(match_account.game_type))?;
+                match_account.record_meld(current_player_index, meld.card_bits, meld.card_count, meld.points);
             }
             _ => {}
         }
@@ -178,13 +320,27 @@ pub fn handler(
         // Batching turn-based moves only works if:
         // 1. The player has multiple consecutive turns (rare, game-specific)
         // 2. The game allows multiple actions per turn (e.g., pick up + play card)
-        let requires_turn = batch_move.action_type == 0 || batch_move.action_type == 1; // pick_up or decline
-        if requires_turn {
+        if validation::requires_turn(batch_move.action_type) {
             require!(
                 match_account.current_player == current_player_index as u8,
                 GameError::NotPlayerTurn
             );
             
+            // Time control: charge this turn to the player's bank
+            require!(
+                match_account.charge_turn_time(current_player_index, clock.unix_timestamp),
+                GameError::TimeBankExhausted
+            );
+            
+            // A turn played after its deadline is forfeit (claim_timeout)
+            require!(
+                !match_account.current_player_out_of_time(clock.unix_timestamp),
+                GameError::TurnDeadlinePassed
+            );
+            
+            // Playing on declines a pending draw offer
+            match_account.lapse_draw_offer(current_player_index);
+            
             // Advance to next player after turn-based move
             // WARNING: This assumes the player has multiple consecutive turns, which is rare in turn-based games.
             // Most turn-based games will fail here if batching multiple turn-based moves.
@@ -196,24 +352,52 @@ pub fn handler(
         current_move_index += 1;
     }
     
+    // Anti-cheat: Hands, discards and the deck must still add up
+    match_account.check_deck_accounting();
+    
     // Update match state after all moves processed
     match_account.move_count = current_move_index;
-    match_account.current_player = current_player_index as u8;
-    
-    msg!("Batch moves submitted: match_id={}, count={}", match_id, moves.len());
-    Ok(())
+    if match_account.current_player != current_player_index as u8 {
+        match_account.current_player = current_player_index as u8;
+        match_account.start_turn(clock.unix_timestamp);
+        if !match_account.is_ended() {
+            emit!(TurnChanged::from_match(match_account));
+        }
+    }
+    Ok(records)
 }
 
 #[derive(Accounts)]
-#[instruction(match_id: String)]
+#[instruction(match_id: String, user_id: String)]
 pub struct SubmitBatchMoves<'info> {
     #[account(
         mut,
-        seeds = [b"match", match_id.as_bytes()],
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
         bump
     )]
     pub match_account: Account<'info, Match>,
     
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    /// Game registry (payload schemas)
+    #[account(
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub game_registry: Box<Account<'info, GameRegistry>>,
+    
+    /// CHECK: User's sanction PDA (may not exist); checked by require_not_sanctioned
+    #[account(
+        seeds = [b"sanction", user_id.as_bytes()],
+        bump
+    )]
+    pub sanction: UncheckedAccount<'info>,
+    
     // Fixed array of up to 5 Move accounts (only initialize the ones we need)
     // Using init_if_needed to avoid errors if fewer than 5 moves
     #[account(
@@ -222,7 +406,7 @@ pub struct SubmitBatchMoves<'info> {
         space = Move::MAX_SIZE,
         seeds = [
             b"move",
-            match_id.as_bytes(),
+            match_id_seed(match_id.as_bytes()),
             match_account.move_count.to_le_bytes().as_ref()
         ],
         bump
@@ -235,7 +419,7 @@ pub struct SubmitBatchMoves<'info> {
         space = Move::MAX_SIZE,
         seeds = [
             b"move",
-            match_id.as_bytes(),
+            match_id_seed(match_id.as_bytes()),
             (match_account.move_count + 1).to_le_bytes().as_ref()
         ],
         bump
@@ -248,7 +432,7 @@ pub struct SubmitBatchMoves<'info> {
         space = Move::MAX_SIZE,
         seeds = [
             b"move",
-            match_id.as_bytes(),
+            match_id_seed(match_id.as_bytes()),
             (match_account.move_count + 2).to_le_bytes().as_ref()
         ],
         bump
@@ -261,7 +445,7 @@ pub struct SubmitBatchMoves<'info> {
         space = Move::MAX_SIZE,
         seeds = [
             b"move",
-            match_id.as_bytes(),
+            match_id_seed(match_id.as_bytes()),
             (match_account.move_count + 3).to_le_bytes().as_ref()
         ],
         bump
@@ -274,7 +458,7 @@ pub struct SubmitBatchMoves<'info> {
         space = Move::MAX_SIZE,
         seeds = [
             b"move",
-            match_id.as_bytes(),
+            match_id_seed(match_id.as_bytes()),
             (match_account.move_count + 4).to_le_bytes().as_ref()
         ],
         bump
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, WordSearchPuzzle, FoundWord, require_not_sanctioned, match_id_seed};
+use crate::events::WordFound;
+use crate::error::GameError;
+
+/// Claims a WordSearch word: the word is entry `word_index` of the committed word list (proven
+/// against word_root) and reads from (row, col) in `direction` on the revealed grid
+/// (see WordSearchPuzzle::DIRECTIONS). The first player to find a word scores its length.
+/// Not turn-based: any player may claim at any time during play.
+pub fn handler(
+    ctx: Context<SubmitFoundWord>,
+    match_id: String,
+    user_id: String,
+    claim: FoundWord,
+) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    let puzzle = &mut ctx.accounts.word_search_puzzle;
+    let clock = Clock::get()?;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate match is in play
+    require!(
+        match_account.phase == 1 && !match_account.is_ended(),
+        GameError::InvalidPhase
+    );
+    match_account.settle_expired_pause(clock.unix_timestamp);
+    require!(
+        !match_account.is_paused(clock.unix_timestamp),
+        GameError::MatchPaused
+    );
+
+    // Security: Validate player is in match
+    require!(
+        user_id.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    let seat = match_account.find_player_index(user_id.as_bytes())
+        .ok_or(GameError::PlayerNotInMatch)?;
+
+    // Security: Only the wallet that took the seat claims for it (the first solver scores)
+    require!(
+        match_account.is_seat_wallet(seat, &ctx.accounts.player.key()),
+        GameError::Unauthorized
+    );
+
+    // Security: Banned or suspended users can't play
+    require_not_sanctioned(&ctx.accounts.sanction, &clock)?;
+
+    let points = puzzle.claim_word(seat, &claim)?;
+
+    emit!(WordFound {
+        match_account: match_account.key(),
+        player_index: seat as u8,
+        word_index: claim.word_index,
+        row: claim.row,
+        col: claim.col,
+        direction: claim.direction,
+        points,
+        total_score: puzzle.scores[seat],
+        words_remaining: puzzle.word_count - puzzle.words_found,
+    });
+    telemetry!("Seat {} found {} in match {} ({} of {})",
+         seat, String::from_utf8_lossy(&claim.word), match_id, puzzle.words_found, puzzle.word_count);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String, user_id: String)]
+pub struct SubmitFoundWord<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Box<Account<'info, Match>>,
+    
+    #[account(
+        mut,
+        seeds = [b"word_search", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub word_search_puzzle: Box<Account<'info, WordSearchPuzzle>>,
+    
+    /// CHECK: User's sanction PDA (may not exist); checked by require_not_sanctioned
+    #[account(
+        seeds = [b"sanction", user_id.as_bytes()],
+        bump
+    )]
+    pub sanction: UncheckedAccount<'info>,
+    
+    pub player: Signer<'info>,
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use crate::state::{Guild, GameLeaderboard, LeaderboardEntry, LeaderboardIndex, SignerRegistry, SignerRole, UserAccount};
+use crate::instructions::submit_leaderboard_entry::insert_with_cascade;
+use crate::error::GameError;
+
+/// Refreshes one member's contribution to their guild's season score and submits the guild
+/// score to the season's guild leaderboard (game_type = Guild::GUILD_LEADERBOARD_TYPE).
+/// Called by the coordinator after a guild member's season score changes.
+/// Remaining accounts: guild leaderboard shards, as for submit_leaderboard_entry.
+pub fn handler(
+    ctx: Context<SubmitGuildScore>,
+    season_id: u64,
+    name: String,
+    user_id: String,
+) -> Result<()> {
+    let index = &mut ctx.accounts.leaderboard_index;
+    let guild = &mut ctx.accounts.guild;
+    let user = &ctx.accounts.user_account;
+    let clock = Clock::get()?;
+
+    // Security: Only registered coordinators submit leaderboard scores
+    require!(
+        ctx.accounts.signer_registry.get_role(&ctx.accounts.coordinator.key()) == Some(SignerRole::Coordinator),
+        GameError::Unauthorized
+    );
+
+    // Security: Season leaderboard is frozen once reward distribution starts
+    require!(!index.rewards_finalized, GameError::SeasonFinalized);
+
+    // Scores can't be submitted to a season the guild has already moved past
+    require!(season_id >= guild.season_id, GameError::InvalidLeaderboardShard);
+    if season_id > guild.season_id {
+        guild.start_season(season_id);
+    }
+
+    let member = guild.find_member(&user.user_id).ok_or(GameError::NotGuildMember)?;
+    guild.record_member_score(member, user.season_score_for(season_id));
+
+    let entry = LeaderboardEntry {
+        user_id: guild.leaderboard_id(),
+        score: guild.guild_score,
+        wins: 0,
+        games_played: guild.member_count as u32,
+        timestamp: clock.unix_timestamp,
+        decay_loss: 0,
+        decayed_day: 0,
+    };
+    let target_shard = insert_with_cascade(
+        index,
+        ctx.remaining_accounts,
+        Guild::GUILD_LEADERBOARD_TYPE,
+        season_id,
+        entry,
+        &clock,
+    )?;
+
+    index.last_updated = clock.unix_timestamp;
+
+    telemetry!(
+        "Guild score: {} score={} shard={} (member {}, season={}, ranks from {})",
+        name,
+        guild.guild_score,
+        target_shard,
+        user_id,
+        season_id,
+        target_shard as u32 * GameLeaderboard::MAX_ENTRIES as u32 + 1
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(season_id: u64, name: String, user_id: String)]
+pub struct SubmitGuildScore<'info> {
+    #[account(
+        mut,
+        seeds = [b"leaderboard_index".as_ref(), &[Guild::GUILD_LEADERBOARD_TYPE], &season_id.to_le_bytes()],
+        bump
+    )]
+    pub leaderboard_index: Account<'info, LeaderboardIndex>,
+    
+    #[account(
+        mut,
+        seeds = [b"guild", name.as_bytes()],
+        bump
+    )]
+    pub guild: Box<Account<'info, Guild>>,
+    
+    #[account(
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    pub coordinator: Signer<'info>,
+}
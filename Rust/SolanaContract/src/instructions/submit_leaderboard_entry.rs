@@ -0,0 +1,257 @@
+use anchor_lang::prelude::*;
+use crate::state::{GameLeaderboard, LeaderboardEntry, LeaderboardIndex, InsertOutcome, SignerRegistry, SignerRole, UserAccount};
+use crate::error::GameError;
+
+/// Inserts or updates a player's season score (the season total; decay already taken from
+/// the player's entry is subtracted, see LeaderboardEntry::carry_decay_from).
+/// Remaining accounts: writable GameLeaderboard shards in rank order, as described on
+/// insert_with_cascade.
+pub fn handler(
+    ctx: Context<SubmitLeaderboardEntry>,
+    game_type: u8,
+    season_id: u64,
+    user_id: String,
+    score: u64,
+    wins: u32,
+    games_played: u32,
+) -> Result<()> {
+    let index = &mut ctx.accounts.leaderboard_index;
+    let clock = Clock::get()?;
+
+    // Security: Only registered coordinators submit leaderboard scores
+    require!(
+        ctx.accounts.signer_registry.get_role(&ctx.accounts.coordinator.key()) == Some(SignerRole::Coordinator),
+        GameError::Unauthorized
+    );
+
+    // Security: Season leaderboard is frozen once reward distribution starts
+    require!(!index.rewards_finalized, GameError::SeasonFinalized);
+
+    // Convert user_id String to fixed-size array
+    let user_id_bytes = user_id.as_bytes();
+    require!(
+        !user_id_bytes.is_empty() && user_id_bytes.len() <= 64,
+        GameError::UserIdTooLong
+    );
+    let mut user_id_array = [0u8; 64];
+    user_id_array[..user_id_bytes.len()].copy_from_slice(user_id_bytes);
+
+    // Security: Users still in placement don't appear on leaderboards (anti-smurf)
+    require!(
+        ctx.accounts.user_account.user_id == user_id_array,
+        GameError::Unauthorized
+    );
+    require!(
+        ctx.accounts.user_account.placement_complete(),
+        GameError::PlacementIncomplete
+    );
+
+    let entry = LeaderboardEntry {
+        user_id: user_id_array,
+        score,
+        wins,
+        games_played,
+        timestamp: clock.unix_timestamp,
+        decay_loss: 0,
+        decayed_day: 0,
+    };
+    let shard_index = insert_with_cascade(index, ctx.remaining_accounts, game_type, season_id, entry, &clock)?;
+
+    index.last_updated = clock.unix_timestamp;
+
+    telemetry!(
+        "Leaderboard entry: {} score={} shard={} (game_type={}, season={})",
+        user_id,
+        score,
+        shard_index,
+        game_type,
+        season_id
+    );
+    Ok(())
+}
+
+/// Inserts or updates `entry` (shared by player and guild leaderboards) and returns the shard
+/// it landed in. `shards` are writable GameLeaderboard shards in rank order:
+/// - A score that ranks at or above the previous entry starts at the target shard for the
+///   decay-adjusted score (LeaderboardIndex::target_shard) and continues until the demotion
+///   cascade stops (a shard with room, or the shard holding the previous entry). A score tied
+///   with the target shard's lowest entry that loses the tie-break (LeaderboardEntry::rank_cmp)
+///   continues to the next shard.
+/// - A lower score starts at the shard holding the previous entry and continues until the
+///   entry outranks the next shard's highest entry (or the last shard); each shard it passes
+///   pulls that entry up to stay full.
+pub fn insert_with_cascade(
+    index: &mut LeaderboardIndex,
+    shards: &[AccountInfo],
+    game_type: u8,
+    season_id: u64,
+    mut entry: LeaderboardEntry,
+    clock: &Clock,
+) -> Result<u16> {
+    // The previous entry's decay carries over (read in place: shards are ~10KB each)
+    let mut previous = None;
+    for (offset, account_info) in shards.iter().enumerate() {
+        require!(
+            account_info.owner == &crate::ID && account_info.is_writable,
+            GameError::InvalidLeaderboardShard
+        );
+        if let Some(found) = GameLeaderboard::find_user_in(&account_info.try_borrow_data()?, &entry.user_id) {
+            previous = Some((offset, found));
+            break;
+        }
+    }
+    if let Some((offset, previous)) = previous {
+        entry.carry_decay_from(&previous);
+        if entry.score < previous.score {
+            // Security: A lower score moves down from where the entry is now
+            require!(offset == 0, GameError::InvalidLeaderboardShard);
+            return move_down(index, shards, game_type, season_id, entry, clock);
+        }
+    }
+
+    let target_shard = index.target_shard(entry.score);
+    require!(
+        target_shard < index.shard_count,
+        GameError::InvalidLeaderboardShard
+    );
+
+    let entry_id = entry.user_id;
+    let mut carry = Some(entry);
+    let mut last_shard = target_shard;
+
+    for (offset, account_info) in shards.iter().enumerate() {
+        let Some(entry) = carry.take() else {
+            break;
+        };
+        let shard_index = target_shard + offset as u16;
+        let mut shard = load_shard(account_info, game_type, season_id, Some(shard_index))?;
+
+        // A promoted player's old entry (in a lower shard) frees the slot that ends the cascade
+        if offset > 0 {
+            shard.remove_entry(&entry_id);
+        }
+
+        match shard.insert_entry(entry.clone()) {
+            InsertOutcome::Inserted => {}
+            InsertOutcome::Demoted(demoted) => carry = Some(demoted),
+            InsertOutcome::NotQualified => {
+                // Only a tie with the shard's lowest score can lose here; it belongs further down
+                require!(
+                    shard.min_score() == entry.score,
+                    GameError::InvalidLeaderboardShard
+                );
+                carry = Some(entry);
+            }
+        }
+        save_shard(account_info, &mut shard, index, clock)?;
+        last_shard = shard_index;
+    }
+
+    // An entry still carried past the last provided shard is only dropped off the bottom
+    // of a leaderboard that has reached MAX_SHARDS; otherwise the caller must pass (or create)
+    // the next shard.
+    if carry.is_some() {
+        require!(
+            last_shard + 1 == index.shard_count && index.shard_count == LeaderboardIndex::MAX_SHARDS,
+            GameError::InvalidLeaderboardShard
+        );
+    }
+    Ok(target_shard)
+}
+
+// Lower score: the entry leaves its shard, and each shard it passes on the way down pulls up
+// the next shard's highest entry (peeked in place) so every shard but the last stays full.
+fn move_down(
+    index: &mut LeaderboardIndex,
+    shards: &[AccountInfo],
+    game_type: u8,
+    season_id: u64,
+    entry: LeaderboardEntry,
+    clock: &Clock,
+) -> Result<u16> {
+    let mut leaving = entry.user_id;
+    let mut expected_index = None;
+
+    for (offset, account_info) in shards.iter().enumerate() {
+        let mut shard = load_shard(account_info, game_type, season_id, expected_index)?;
+        let shard_index = shard.shard_index;
+        expected_index = Some(shard_index + 1);
+        shard.remove_entry(&leaving);
+
+        let next_top = match shards.get(offset + 1) {
+            Some(next) => GameLeaderboard::entry_in(&next.try_borrow_data()?, 0),
+            None => {
+                // Security: Only the last shard may end the walk without a look at the next one
+                require!(shard_index + 1 >= index.shard_count, GameError::InvalidLeaderboardShard);
+                None
+            }
+        };
+        match next_top {
+            Some(top) if top.outranks(&entry) => {
+                leaving = top.user_id;
+                shard.insert_entry(top);
+                save_shard(account_info, &mut shard, index, clock)?;
+            }
+            _ => {
+                shard.insert_entry(entry);
+                save_shard(account_info, &mut shard, index, clock)?;
+                return Ok(shard_index);
+            }
+        }
+    }
+    err!(GameError::InvalidLeaderboardShard)
+}
+
+// Loads a shard of this leaderboard; `shard_index` None accepts any of its shards
+fn load_shard(account_info: &AccountInfo, game_type: u8, season_id: u64, shard_index: Option<u16>) -> Result<Box<GameLeaderboard>> {
+    require!(
+        account_info.owner == &crate::ID && account_info.is_writable,
+        GameError::InvalidLeaderboardShard
+    );
+    let shard = {
+        let data = account_info.try_borrow_data()?;
+        Box::new(GameLeaderboard::try_deserialize(&mut &data[..])?)
+    };
+
+    // Security: Shard must belong to this leaderboard and be next in rank order
+    require!(
+        shard.game_type == game_type &&
+        shard.season_id == season_id &&
+        shard_index.unwrap_or(shard.shard_index) == shard.shard_index,
+        GameError::InvalidLeaderboardShard
+    );
+    Ok(shard)
+}
+
+fn save_shard(account_info: &AccountInfo, shard: &mut GameLeaderboard, index: &mut LeaderboardIndex, clock: &Clock) -> Result<()> {
+    shard.last_updated = clock.unix_timestamp;
+    let mut data = account_info.try_borrow_mut_data()?;
+    shard.try_serialize(&mut &mut data[..])?;
+    index.refresh_shard(shard);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(game_type: u8, season_id: u64, user_id: String)]
+pub struct SubmitLeaderboardEntry<'info> {
+    #[account(
+        mut,
+        seeds = [b"leaderboard_index".as_ref(), &[game_type], &season_id.to_le_bytes()],
+        bump
+    )]
+    pub leaderboard_index: Account<'info, LeaderboardIndex>,
+    
+    #[account(
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    pub coordinator: Signer<'info>,
+}
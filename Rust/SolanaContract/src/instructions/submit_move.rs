@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::state::{Match, Move};
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use crate::state::{Match, Move, ConfigAccount, GameRegistry, EndReason, require_not_sanctioned, match_id_seed};
+use crate::events::{MoveSubmitted, TurnChanged};
+use crate::relay::{self, EnvelopeSignature, MoveEnvelope, SignedMoveEnvelope};
 use crate::validation;
 use crate::error::GameError;
 
@@ -10,9 +13,8 @@ pub fn handler(
     action_type: u8,
     payload: Vec<u8>,
     nonce: u64, // Per critique: nonce for replay protection
+    envelope_signature: Option<EnvelopeSignature>, // Player-signed envelope, kept as dispute evidence
 ) -> Result<()> {
-    let match_account = &mut ctx.accounts.match_account;
-    let move_account = &mut ctx.accounts.move_account;
     let clock = Clock::get()?;
 
     // Security: Validate player is signer
@@ -21,17 +23,109 @@ pub fn handler(
         GameError::Unauthorized
     );
 
+    // Security: Banned or suspended users can't submit moves
+    require_not_sanctioned(&ctx.accounts.sanction, &clock)?;
+
+    let envelope = MoveEnvelope { match_id, user_id, action_type, payload, nonce };
+
+    // Signed envelope: the Ed25519 instruction before this one must verify the player's
+    // signature over exactly this move and slot
+    let envelope_hash = match envelope_signature {
+        Some(envelope_signature) => {
+            let signed = SignedMoveEnvelope::new(envelope.clone(), envelope_signature);
+            require!(signed.slot <= clock.slot, GameError::InvalidEnvelopeSlot);
+            let instructions = ctx.accounts.instructions.as_ref().ok_or(GameError::InvalidRelaySignature)?;
+            relay::verify_preceding_signature_bytes(
+                instructions,
+                &ctx.accounts.player.key(),
+                &signed.message()?,
+                &signed.signature,
+            )?;
+            signed.hash()?
+        }
+        None => [0; 32],
+    };
+
+    let accounts = ctx.accounts;
+    accounts.move_account.rent_payer = accounts.player.key();
+    accounts.move_account.envelope_hash = envelope_hash;
+    apply_move(
+        &mut accounts.match_account,
+        &mut accounts.move_account,
+        &accounts.config_account,
+        &accounts.game_registry,
+        accounts.player.key(),
+        &envelope,
+        &clock,
+    )
+}
+
+/// Validates a move and records it (shared by submit_move and submit_relayed_move).
+/// `player` is the wallet that authorized the move: the transaction signer, or the
+/// ed25519 signer of a relayed envelope. It must be the wallet that took the seat;
+/// callers check signatures and sanctions.
+pub(crate) fn apply_move(
+    match_account: &mut Account<'_, Match>,
+    move_account: &mut Account<'_, Move>,
+    config: &ConfigAccount,
+    game_registry: &GameRegistry,
+    player: Pubkey,
+    envelope: &MoveEnvelope,
+    clock: &Clock,
+) -> Result<()> {
+    let move_index = match_account.move_count;
+    let turn_before = (match_account.current_player, match_account.turn_deadline);
+    let player_index = apply_move_state(match_account, config, game_registry, player, envelope, clock)?;
+
+    // Create move account with optimized struct
+    move_account.match_id = match_account.match_id;
+    move_account.player = player;
+    move_account.move_index = move_index;
+    move_account.action_type = envelope.action_type;
+    move_account.set_payload(&envelope.payload)?; // Uses fixed-size array
+    move_account.timestamp = clock.unix_timestamp;
+
+    emit!(MoveSubmitted {
+        match_account: match_account.key(),
+        move_index,
+        player_index: player_index as u8,
+        player,
+        action_type: envelope.action_type,
+        nonce: envelope.nonce,
+        transcript_hash: match_account.transcript_hash,
+    });
+    // A new turn started (pick up, decline, betting action): notify the next player
+    if !match_account.is_ended() && (match_account.current_player, match_account.turn_deadline) != turn_before {
+        emit!(TurnChanged::from_match(match_account));
+    }
+    Ok(())
+}
+
+/// Runs the full move pipeline (validation and state transition) against match state
+/// only, and returns the mover's seat. Used on-chain by apply_move and off-chain by
+/// `client::simulate_move` to pre-flight a move without sending it.
+pub(crate) fn apply_move_state(
+    match_account: &mut Match,
+    config: &ConfigAccount,
+    game_registry: &GameRegistry,
+    player: Pubkey,
+    envelope: &MoveEnvelope,
+    clock: &Clock,
+) -> Result<usize> {
+    let MoveEnvelope { match_id, user_id, action_type, payload, nonce } = envelope;
+    let (action_type, nonce) = (*action_type, *nonce);
+
     // Security: Validate match_id matches
     let match_id_bytes = match_id.as_bytes();
     require!(
         match_id_bytes.len() == 36 && 
         match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
-        GameError::InvalidPayload
+        GameError::MatchIdMismatch
     );
 
-    // Security: Validate match is in playing phase
+    // Security: Validate match is in playing phase (or showdown, for rebuttals)
     require!(
-        match_account.phase == 1,
+        match_account.phase == 1 || match_account.phase == 3,
         GameError::InvalidPhase
     );
 
@@ -41,6 +135,19 @@ pub fn handler(
         GameError::MatchAlreadyEnded
     );
 
+    // Security: Rebuttals close with the showdown window
+    require!(
+        match_account.phase != 3 || clock.unix_timestamp < match_account.rebuttal_deadline,
+        GameError::RebuttalWindowClosed
+    );
+
+    // Security: No moves while the match is paused (live-ops incident)
+    match_account.settle_expired_pause(clock.unix_timestamp);
+    require!(
+        !match_account.is_paused(clock.unix_timestamp),
+        GameError::MatchPaused
+    );
+
     // Security: Validate minimum players requirement
     require!(
         match_account.has_minimum_players(),
@@ -49,21 +156,21 @@ pub fn handler(
 
     // Security: Validate action_type bounds
     require!(
-        action_type <= 4,
+        action_type <= 15,
         GameError::InvalidAction
     );
 
     // Security: Validate payload size
     require!(
         payload.len() <= 128,
-        GameError::InvalidPayload
+        GameError::PayloadTooLarge
     );
 
     // Convert user_id String to fixed-size array
     let user_id_bytes = user_id.as_bytes();
     require!(
         user_id_bytes.len() <= 64,
-        GameError::InvalidPayload
+        GameError::UserIdTooLong
     );
     let mut user_id_array = [0u8; 64];
     let copy_len = user_id_bytes.len().min(64);
@@ -73,66 +180,80 @@ pub fn handler(
     let player_index = match_account.find_player_index(&user_id_array)
         .ok_or(GameError::PlayerNotInMatch)?;
     
-    // Anti-cheat: For declare_intent and call_showdown, any player can act (not turn-based)
-    let requires_turn = action_type == 0 || action_type == 1; // pick_up or decline
+    // Security: The authorizing wallet must own the seat user_id names (player_wallets)
+    require!(
+        match_account.is_seat_wallet(player_index, &player),
+        GameError::Unauthorized
+    );
     
-    if requires_turn {
+    // Anti-spam: Per-player move rate limit (max moves per slot)
+    require!(
+        match_account.record_move_for_rate_limit(player_index, clock.slot, config.max_moves_per_slot),
+        GameError::MoveRateLimited
+    );
+    
+    // Anti-spam: Non-turn actions (declare intent, showdown, ...) have a per-player cooldown
+    require!(
+        match_account.record_action_cooldown(player_index, action_type, clock.slot, config.action_cooldown_slots),
+        GameError::ActionOnCooldown
+    );
+    
+    // Anti-cheat: For declare_intent and call_showdown, any player can act (not turn-based)
+    if validation::requires_turn(action_type) {
         require!(
             match_account.current_player == player_index as u8,
             GameError::NotPlayerTurn
         );
-    }
-
-    // Anti-cheat: Timestamp validation - moves must be recent (within 5 minutes of creation)
-    // This prevents replay of old moves
-    let move_timestamp = clock.unix_timestamp;
-    require!(
-        move_timestamp >= match_account.created_at,
-        GameError::InvalidTimestamp
-    );
-    // Reject moves older than 5 minutes from match creation to prevent replay attacks
-    // Note: For long matches, this is a simplified check. Full replay protection is via nonce.
-    let max_age = 300i64; // 5 minutes in seconds
-    // Allow moves if match is still recent (within 5 min) OR if it's the first move
-    if match_account.move_count > 0 {
-        let match_age = move_timestamp.saturating_sub(match_account.created_at);
-        // For matches longer than 5 minutes, rely on nonce-based replay protection
-        // This timestamp check is just an additional safeguard for very old moves
-        if match_age > max_age * 10 { // 50 minutes - very old
-            return Err(GameError::InvalidTimestamp.into());
-        }
+        
+        // Time control: charge this turn to the player's bank (claim_timeout forfeits an empty bank)
+        require!(
+            match_account.charge_turn_time(player_index, clock.unix_timestamp),
+            GameError::TimeBankExhausted
+        );
+        
+        // A turn played after its deadline is forfeit (claim_timeout)
+        require!(
+            !match_account.current_player_out_of_time(clock.unix_timestamp),
+            GameError::TurnDeadlinePassed
+        );
+        
+        // Playing on declines a pending draw offer
+        match_account.lapse_draw_offer(player_index);
     }
 
     // Per critique: Replay protection - nonce validation
-    // Each move must have a nonce greater than the last nonce for this player
-    let last_nonce = match_account.get_last_nonce(player_index);
+    // Each nonce is used once: above the last nonce for this player, or unused within the window below it
     require!(
-        nonce > last_nonce,
+        match_account.is_nonce_fresh(player_index, nonce),
         GameError::InvalidNonce
     );
-    // Update last nonce for this player
-    match_account.set_last_nonce(player_index, nonce);
+    // Anti-collusion: Evidence counters for disputes and the coordinator
+    match_account.record_move_anomalies(player_index, &player, clock.slot, action_type, nonce);
+    // Mark the nonce used for this player
+    match_account.record_nonce(player_index, nonce);
 
-    // Anti-cheat: Validate move legality
-    validation::validate_move(match_account, player_index, action_type, &payload)?;
+    // Security: Generic payload schema check before game-specific logic
+    validation::validate_payload_schema(game_registry, match_account.game_type, action_type, payload)?;
 
-    // Per critique: Card state validation for moves that involve cards (rebuttal)
-    if action_type == 4 { // Rebuttal action
-        validation::validate_card_hash(match_account, player_index, &payload)?;
+    // Betting actions only for games the registry flags has_betting
+    if validation::brag::is_brag_action(action_type) {
+        require!(
+            game_registry.betting_enabled(match_account.game_type),
+            GameError::BettingDisabled
+        );
     }
 
-    // Convert match_id to fixed-size array
-    let mut match_id_array = [0u8; 36];
-    let copy_len = match_id_bytes.len().min(36);
-    match_id_array[..copy_len].copy_from_slice(&match_id_bytes[..copy_len]);
+    // Anti-cheat: Validate move legality
+    let run_rules = game_registry.run_rules_for(match_account.game_type);
+    validation::validate_move(match_account, player_index, action_type, payload, run_rules)?;
 
-    // Create move account with optimized struct
-    move_account.match_id = match_id_array;
-    move_account.player = ctx.accounts.player.key();
-    move_account.move_index = match_account.move_count;
-    move_account.action_type = action_type;
-    move_account.set_payload(&payload)?; // Uses fixed-size array
-    move_account.timestamp = clock.unix_timestamp;
+    // Per critique: Card state validation for moves that involve cards (showdown, rebuttal)
+    if action_type == 3 || action_type == 4 { // Call showdown / rebuttal
+        validation::validate_card_hash(match_account, player_index, payload, run_rules)?;
+    }
+
+    let move_index = match_account.move_count;
+    match_account.record_transcript_move(move_index, &player, action_type, payload);
 
     // Update match state based on action type
     match action_type {
@@ -140,7 +261,7 @@ pub fn handler(
             // Declare intent: record the declared suit
             if payload.len() >= 1 {
                 let suit = payload[0];
-                require!(suit <= 3, GameError::InvalidPayload); // Validate suit (0-3)
+                require!(suit <= 3, GameError::InvalidSuit); // Validate suit (0-3)
                 match_account.set_declared_suit(player_index, suit);
             }
         }
@@ -152,45 +273,114 @@ pub fn handler(
             // Increment hand size (card was picked up)
             let current_size = match_account.get_hand_size(player_index);
             match_account.set_hand_size(player_index, current_size.saturating_add(1));
+            match_account.record_cards_dealt(1);
             match_account.current_player = ((player_index + 1) % match_account.player_count as usize) as u8;
+            match_account.start_turn(clock.unix_timestamp);
         }
         1 => {
             // Decline: advance turn, clear floor card
             match_account.set_floor_card_revealed(false);
             match_account.current_player = ((player_index + 1) % match_account.player_count as usize) as u8;
+            match_account.start_turn(clock.unix_timestamp);
+        }
+        5 | 6 => {
+            // Play card / discard: card leaves the hand, remember which one
+            let current_size = match_account.get_hand_size(player_index);
+            match_account.set_hand_size(player_index, current_size.saturating_sub(1));
+            let mut card_hash = [0u8; 32];
+            card_hash.copy_from_slice(&payload[0..32]);
+            match_account.record_discard(card_hash);
         }
         3 => {
-            // Call showdown: transition to ended phase
-            match_account.phase = 2; // Ended
-            match_account.ended_at = clock.unix_timestamp;
+            // Call showdown: record the claimed run and open the rebuttal window
+            // (ends at once if nobody can rebut)
+            let claimed_run = validation::run_suit_and_top(&validation::parse_run(payload, run_rules)?, run_rules);
+            match_account.begin_showdown(player_index, claimed_run, clock.unix_timestamp, config.rebuttal_window());
+            if match_account.all_rebuttals_in() {
+                match_account.finish_showdown(clock.unix_timestamp);
+            }
+        }
+        4 => {
+            // Rebuttal: the round ends once every undeclared player has responded
+            match_account.record_rebuttal(player_index);
+            if match_account.all_rebuttals_in() {
+                match_account.finish_showdown(clock.unix_timestamp);
+            }
+        }
+        7 => {
+            // Resign: match ends immediately
+            match_account.end_with_outcome(EndReason::Resignation, player_index as u8, clock.unix_timestamp);
+        }
+        8 => {
+            // Offer draw: other players answer with AcceptDraw (or decline by playing on)
+            match_account.offer_draw(player_index);
+        }
+        9 => {
+            // Accept draw: match ends once every player agreed
+            let all_agreed = match_account.accept_draw(player_index);
+            if all_agreed {
+                let offered_by = match_account.draw_offered_by;
+                match_account.end_with_outcome(EndReason::Draw, offered_by, clock.unix_timestamp);
+            }
+        }
+        10 => {
+            // Declare meld (Rummy): the cards leave the hand face-up and score for the player
+            let meld = validation::rummy::parse_meld(payload, game_registry.run_rules_for(match_account.game_type))?;
+            match_account.record_meld(player_index, meld.card_bits, meld.card_count, meld.points);
+        }
+        11..=15 => {
+            // Three Card Brag: stakes go into the pot; the turn passes, or the pot is settled
+            let rake_bps = game_registry.rake_bps_for(match_account.game_type);
+            validation::brag::apply_brag_action(match_account, player_index, action_type, payload, rake_bps, clock.unix_timestamp)?;
         }
         _ => {}
     }
 
-    match_account.move_count += 1;
+    // Anti-cheat: Hands, discards and the deck must still add up
+    match_account.check_deck_accounting();
 
-    msg!("Move submitted: player {}, action {}, match {}", 
-         ctx.accounts.player.key(), action_type, match_id);
-    Ok(())
+    match_account.move_count += 1;
+    Ok(player_index)
 }
 
 #[derive(Accounts)]
-#[instruction(match_id: String)]
+#[instruction(match_id: String, user_id: String)]
 pub struct SubmitMove<'info> {
     #[account(
         mut,
-        seeds = [b"match", match_id.as_bytes()],
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
         bump
     )]
     pub match_account: Account<'info, Match>,
     
+    /// CHECK: Config account (read-only)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    /// Game registry (payload schemas)
+    #[account(
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub game_registry: Box<Account<'info, GameRegistry>>,
+    
+    /// CHECK: User's sanction PDA (may not exist); checked by require_not_sanctioned
+    #[account(
+        seeds = [b"sanction", user_id.as_bytes()],
+        bump
+    )]
+    pub sanction: UncheckedAccount<'info>,
+    
     #[account(
         init,
         payer = player,
         space = Move::MAX_SIZE,
         seeds = [
             b"move",
-            match_id.as_bytes(),
+            match_id_seed(match_id.as_bytes()),
             match_account.move_count.to_le_bytes().as_ref()
         ],
         bump
@@ -199,6 +389,11 @@ pub struct SubmitMove<'info> {
     
     #[account(mut)]
     pub player: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar (required only with an envelope signature)
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+    
     pub system_program: Program<'info, System>,
 }
 
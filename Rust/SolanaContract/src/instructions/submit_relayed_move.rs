@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use crate::state::{Match, Move, ConfigAccount, GameRegistry, SignerRegistry, SignerRole, require_not_sanctioned, match_id_seed};
+use crate::relay::{self, MoveEnvelope};
+use crate::instructions::submit_move::apply_move;
+use crate::error::GameError;
+
+/// Submits a move on behalf of a player who holds no SOL.
+/// The registered coordinator signs and pays the Move rent; the player's wallet authorizes the
+/// move with an ed25519 signature over `envelope.message()`, verified by an Ed25519 precompile
+/// instruction placed immediately before this one (see crate::relay). `player` must be the
+/// wallet that took envelope.user_id's seat (Match.player_wallets).
+/// Otherwise identical to submit_move: same validation, nonce and transcript rules.
+pub fn handler(ctx: Context<SubmitRelayedMove>, envelope: MoveEnvelope, player: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    // Security: Only registered coordinators relay moves
+    require!(
+        ctx.accounts.signer_registry.get_role(&ctx.accounts.coordinator.key()) == Some(SignerRole::Coordinator),
+        GameError::Unauthorized
+    );
+    
+    // Security: The player signed exactly this envelope
+    let message = envelope.message()?;
+    relay::verify_preceding_signature(&ctx.accounts.instructions, &player, &message)?;
+    
+    // Security: Banned or suspended users can't submit moves
+    require_not_sanctioned(&ctx.accounts.sanction, &clock)?;
+    
+    let accounts = ctx.accounts;
+    accounts.move_account.rent_payer = accounts.coordinator.key();
+    apply_move(
+        &mut accounts.match_account,
+        &mut accounts.move_account,
+        &accounts.config_account,
+        &accounts.game_registry,
+        player,
+        &envelope,
+        &clock,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(envelope: MoveEnvelope)]
+pub struct SubmitRelayedMove<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(envelope.match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    
+    /// Game registry (payload schemas)
+    #[account(
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub game_registry: Box<Account<'info, GameRegistry>>,
+    
+    /// CHECK: User's sanction PDA (may not exist); checked by require_not_sanctioned
+    #[account(
+        seeds = [b"sanction", envelope.user_id.as_bytes()],
+        bump
+    )]
+    pub sanction: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = coordinator,
+        space = Move::MAX_SIZE,
+        seeds = [
+            b"move",
+            match_id_seed(envelope.match_id.as_bytes()),
+            match_account.move_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub move_account: Account<'info, Move>,
+    
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    /// CHECK: Instructions sysvar (holds the preceding Ed25519 instruction)
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub coordinator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
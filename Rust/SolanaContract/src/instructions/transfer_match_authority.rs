@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, SignerRegistry, SignerRole, match_id_seed};
+use crate::events::MatchAuthorityTransferred;
+use crate::error::GameError;
+
+/// Hands a live match to a new authority when the original key is lost or its
+/// coordinator instance died, so the match can still be ended and anchored.
+/// A registered coordinator transfers immediately. Players (user_id set) vote
+/// instead, signed by the wallet that took their seat, and the transfer happens
+/// once a strict majority of seated players have voted for the same new_authority.
+pub fn handler(
+    ctx: Context<TransferMatchAuthority>,
+    match_id: String,
+    new_authority: Pubkey,
+    user_id: Option<String>, // Firebase UID of the voting player (None = coordinator transfer)
+) -> Result<()> {
+    let match_account = &mut ctx.accounts.match_account;
+    
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Validate signer
+    require!(
+        ctx.accounts.signer.is_signer,
+        GameError::Unauthorized
+    );
+
+    require!(
+        new_authority != Pubkey::default() && new_authority != match_account.authority,
+        GameError::InvalidNewAuthority
+    );
+
+    let previous_authority = match_account.authority;
+    let by_coordinator = match user_id {
+        None => {
+            // Security: Only registered coordinators can reassign without a player quorum
+            require!(
+                ctx.accounts.signer_registry.get_role(&ctx.accounts.signer.key()) == Some(SignerRole::Coordinator),
+                GameError::Unauthorized
+            );
+            true
+        }
+        Some(user_id) => {
+            let user_id_bytes = user_id.as_bytes();
+            require!(
+                user_id_bytes.len() <= 64,
+                GameError::UserIdTooLong
+            );
+            let mut user_id_array = [0u8; 64];
+            user_id_array[..user_id_bytes.len()].copy_from_slice(user_id_bytes);
+            
+            // Security: Validate player is in the match (find by user_id)
+            let player_index = match_account.find_player_index(&user_id_array)
+                .ok_or(GameError::PlayerNotInMatch)?;
+            
+            // Security: Only the seat's own wallet votes for it
+            require!(
+                match_account.is_seat_wallet(player_index, &ctx.accounts.signer.key()),
+                GameError::Unauthorized
+            );
+
+            if !match_account.vote_authority_transfer(player_index, new_authority) {
+                telemetry!("Authority transfer vote recorded for player {}", player_index);
+                return Ok(());
+            }
+            false
+        }
+    };
+
+    let authority_votes = match_account.authority_votes;
+    match_account.transfer_authority(new_authority);
+
+    emit!(MatchAuthorityTransferred {
+        match_account: match_account.key(),
+        previous_authority,
+        new_authority,
+        by_coordinator,
+        authority_votes,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct TransferMatchAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Account<'info, Match>,
+    
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    pub signer: Signer<'info>,
+}
@@ -26,7 +26,7 @@ pub fn handler(
     
     // Get existing game
     let existing_game = registry.find_game(game_id)
-        .ok_or(GameError::InvalidPayload)?;
+        .ok_or(GameError::GameNotRegistered)?;
     
     // Create updated game definition
     let mut updated_game = existing_game.clone();
@@ -88,7 +88,7 @@ pub fn handler(
     registry.update_game(game_id, updated_game)?;
     registry.last_updated = clock.unix_timestamp;
     
-    msg!("Game updated: game_id={}", game_id);
+    telemetry!("Game updated: game_id={}", game_id);
     Ok(())
 }
 
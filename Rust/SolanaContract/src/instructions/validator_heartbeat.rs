@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::state::ValidatorReputation;
+
+/// Marks the validator live. enforce_inactivity slashes validators whose last heartbeat (or
+/// last slash) is more than INACTIVITY_SECONDS old.
+pub fn handler(ctx: Context<ValidatorHeartbeat>) -> Result<()> {
+    let clock = Clock::get()?;
+    let validator_account = &mut ctx.accounts.validator_reputation;
+    
+    validator_account.last_active = clock.unix_timestamp;
+    
+    telemetry!("Validator {} heartbeat at {}", validator_account.validator, clock.unix_timestamp);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ValidatorHeartbeat<'info> {
+    #[account(
+        mut,
+        seeds = [b"validator", validator.key().as_ref()],
+        bump
+    )]
+    pub validator_reputation: Account<'info, ValidatorReputation>,
+    
+    pub validator: Signer<'info>,
+}
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, GameRegistry, ReplayCheckpoint, SignerRegistry, SignerRole, match_id_seed};
+use crate::error::GameError;
+use crate::{replay, validation};
+
+/// Move data for replay verification (move_index is start_index + position in the segment).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ReplayMoveData {
+    pub player: Pubkey,
+    pub action_type: u8,
+    pub payload: Vec<u8>,
+}
+
+/// Re-validates a contiguous segment of a match's moves and extends the running transcript hash.
+/// Segments are verified in order: start_index must be 0 (starts from the match header) or the
+/// checkpoint's next_index. The hash after the segment must equal expected_intermediate_hash,
+/// and the last segment must reproduce the match's transcript. Moves that break the rules don't
+/// fail the instruction; the first one is recorded in the checkpoint for dispute resolution.
+pub fn handler(
+    ctx: Context<VerifyReplaySegment>,
+    match_id: String,
+    start_index: u32,
+    moves_data: Vec<ReplayMoveData>,
+    expected_intermediate_hash: [u8; 32],
+) -> Result<()> {
+    let match_account = &ctx.accounts.match_account;
+    let checkpoint = &mut ctx.accounts.checkpoint;
+    let clock = Clock::get()?;
+
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36 && 
+        match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::MatchIdMismatch
+    );
+
+    // Security: Only registered validators adjudicate replays
+    require!(
+        ctx.accounts.signer_registry.get_role(&ctx.accounts.validator.key()) == Some(SignerRole::Validator),
+        GameError::Unauthorized
+    );
+
+    require!(match_account.has_transcript(), GameError::InvalidPhase);
+    require!(
+        !moves_data.is_empty() && moves_data.len() <= ReplayCheckpoint::MAX_SEGMENT_MOVES,
+        GameError::BatchTooLarge
+    );
+    let end_index = start_index
+        .checked_add(moves_data.len() as u32)
+        .ok_or(GameError::Overflow)?;
+    require!(end_index <= match_account.move_count, GameError::InvalidPayload);
+
+    // Segments are contiguous: restart from the header or continue from the checkpoint
+    let mut hash = if start_index == 0 {
+        checkpoint.match_account = match_account.key();
+        checkpoint.invalid_move_index = ReplayCheckpoint::NO_INVALID_MOVE;
+        checkpoint.complete = false;
+        replay::header_hash(&replay::MatchHeader::from_match(match_account))
+    } else {
+        require!(
+            checkpoint.match_account == match_account.key() && checkpoint.next_index == start_index,
+            GameError::InvalidPayload
+        );
+        checkpoint.transcript_hash
+    };
+
+    for (offset, mv) in moves_data.iter().enumerate() {
+        let move_index = start_index + offset as u32;
+        let rules_ok = validation::validate_replay_move(
+            &ctx.accounts.game_registry,
+            match_account.game_type,
+            mv.action_type,
+            &mv.payload,
+        ).is_ok();
+        if !rules_ok && !checkpoint.has_invalid_move() {
+            checkpoint.invalid_move_index = move_index;
+        }
+        hash = replay::chain_move(&hash, move_index, &mv.player, mv.action_type, &mv.payload);
+    }
+
+    // Security: The segment must be the one the match actually recorded
+    require!(hash == expected_intermediate_hash, GameError::MatchHashMismatch);
+    if end_index == match_account.move_count {
+        require!(hash == match_account.transcript_hash, GameError::MatchHashMismatch);
+        checkpoint.complete = true;
+    }
+
+    checkpoint.verifier = ctx.accounts.validator.key();
+    checkpoint.next_index = end_index;
+    checkpoint.transcript_hash = hash;
+    checkpoint.updated_at = clock.unix_timestamp;
+
+    telemetry!("Replay segment verified: match {}, moves {}..{} of {}, first invalid move: {}", 
+         match_id, start_index, end_index, match_account.move_count,
+         if checkpoint.has_invalid_move() { checkpoint.invalid_move_index as i64 } else { -1 });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct VerifyReplaySegment<'info> {
+    #[account(
+        seeds = [b"match", match_id_seed(match_id.as_bytes())],
+        bump
+    )]
+    pub match_account: Box<Account<'info, Match>>,
+    
+    #[account(
+        init_if_needed,
+        payer = validator,
+        space = ReplayCheckpoint::MAX_SIZE,
+        seeds = [b"replay_checkpoint", match_account.key().as_ref()],
+        bump
+    )]
+    pub checkpoint: Account<'info, ReplayCheckpoint>,
+    
+    #[account(
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub game_registry: Box<Account<'info, GameRegistry>>,
+    
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    #[account(mut)]
+    pub validator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
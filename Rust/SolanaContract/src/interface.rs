@@ -0,0 +1,75 @@
+//! Cross-program invocation interface (enabled with the `cpi` feature).
+//!
+//! Anchor generates the raw instruction builders (`crate::cpi::*`) and their account
+//! structs (`crate::cpi::accounts::*`), e.g. `cpi::submit_move`. This module adds PDA
+//! derivation, a typed wrapper for match creation, and a read-only match outcome query so
+//! programs such as prediction markets or guilds can settle on finished matches.
+
+use anchor_lang::prelude::*;
+use crate::cpi;
+use crate::error::GameError;
+use crate::state::{EndReason, Match};
+
+pub use crate::pda::*;
+
+/// Creates a public, single-round match. `signer_seeds` are the calling program's
+/// seeds for `accounts.authority` when it is a PDA (empty when the authority signs directly).
+pub fn create_match<'info>(
+    program: AccountInfo<'info>,
+    accounts: cpi::accounts::CreateMatch<'info>,
+    signer_seeds: &[&[&[u8]]],
+    match_id: String,
+    game_type: u8,
+    seed: u64,
+) -> Result<()> {
+    let ctx = CpiContext::new_with_signer(program, accounts, signer_seeds);
+    cpi::create_match(ctx, match_id, game_type, seed, 1, None, false)
+}
+
+/// Final state of a match as seen by other programs.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MatchOutcome {
+    pub match_id: [u8; 36],
+    pub game_type: u8,
+    pub ended: bool,                      // Ended phase with ended_at set
+    pub ended_at: i64,
+    pub end_reason: EndReason,            // Completed, Timeout, Resignation, Draw or ForceEnded
+    pub player_count: u8,
+    pub player_ids: [[u8; 64]; 10],       // Seat order
+    pub scores: [i32; 10],                // Cumulative scores (all rounds), seat order
+    pub winner: Option<u8>,               // Seat with the unique highest score, or the opponent of a
+                                          // 2-player resignation/timeout (None if unfinished, tied or drawn)
+    pub match_hash: Option<[u8; 32]>,     // Anchored record hash, once set
+}
+
+/// Reads a match account owned by this program and summarizes its outcome.
+pub fn read_match_outcome(match_info: &AccountInfo) -> Result<MatchOutcome> {
+    require!(match_info.owner == &crate::ID, GameError::Unauthorized);
+    let data = match_info.try_borrow_data()?;
+    let match_account = Match::try_deserialize(&mut &data[..])?;
+
+    let ended = match_account.phase == 2 && match_account.is_ended();
+    let player_count = match_account.player_count.min(10);
+    let scores = match_account.cumulative_scores;
+    let end_reason = match_account.get_outcome();
+    let winner = match_account.winner();
+
+    let match_hash = if match_account.match_hash.iter().any(|&b| b != 0) {
+        Some(match_account.match_hash)
+    } else {
+        None
+    };
+
+    Ok(MatchOutcome {
+        match_id: match_account.match_id,
+        game_type: match_account.game_type,
+        ended,
+        ended_at: match_account.ended_at,
+        end_reason,
+        player_count,
+        player_ids: match_account.player_ids,
+        scores,
+        winner,
+        match_hash,
+    })
+}
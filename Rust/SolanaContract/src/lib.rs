@@ -1,11 +1,35 @@
 use anchor_lang::prelude::*;
 
+extern crate alloc;
+
 declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 
+/// Human-readable log line, only with the `telemetry` feature. Arguments are still
+/// type-checked, but the formatting is compiled out otherwise; use events for anything
+/// indexers rely on.
+macro_rules! telemetry {
+    ($($arg:tt)*) => {
+        if cfg!(feature = "telemetry") {
+            anchor_lang::prelude::msg!($($arg)*);
+        }
+    };
+}
+
 pub mod state;
 pub mod instructions;
 pub mod error;
 pub mod validation;
+pub mod pda; // PDA derivation (shared by cpi/client helpers)
+pub mod replay; // Canonical match transcript hashing (on-chain and off-chain verifiers)
+pub mod merkle; // Merkle roots and inclusion proofs (match records, commitments), no_std-friendly
+pub mod oracle; // SOL/USD price feed decoding for lamport pricing
+pub mod relay; // Signed move envelopes for coordinator-paid (gasless) moves
+pub mod payment; // Oracle-signed Stripe payment receipts for purchases
+pub mod events; // Typed events (emit!) for indexers
+#[cfg(feature = "cpi")]
+pub mod interface; // CPI helpers for other programs
+#[cfg(feature = "client")]
+pub mod client; // Off-chain instruction builders and account decoders
 
 use state::*;
 use instructions::*;
@@ -20,18 +44,220 @@ pub mod solana_games_program {
         match_id: String,
         game_type: u8,
         seed: u64,
+        rounds_to_play: u8,
+        join_secret_hash: Option<[u8; 32]>,
+        player_consent_start: bool,
+    ) -> Result<()> {
+        instructions::create_match::handler(ctx, match_id, game_type, seed, rounds_to_play, join_secret_hash, player_consent_start)
+    }
+
+    pub fn create_match_counted(
+        ctx: Context<CreateMatchCounted>,
+        match_counter: u64,
+        game_type: u8,
+        seed: u64,
+        rounds_to_play: u8,
+        join_secret_hash: Option<[u8; 32]>,
+        player_consent_start: bool,
+    ) -> Result<()> {
+        instructions::create_match_counted::handler(ctx, match_counter, game_type, seed, rounds_to_play, join_secret_hash, player_consent_start)
+    }
+
+    pub fn create_matches_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateMatchesBatch<'info>>,
+        game_type: u8,
+        rounds_to_play: u8,
+        matches: Vec<BatchMatch>,
+    ) -> Result<()> {
+        instructions::create_matches_batch::handler(ctx, game_type, rounds_to_play, matches)
+    }
+
+    pub fn join_match(
+        ctx: Context<JoinMatch>,
+        match_id: String,
+        user_id: String,
+        is_bot: bool,
     ) -> Result<()> {
-        instructions::create_match::handler(ctx, match_id, game_type, seed)
+        instructions::join_match::handler(ctx, match_id, user_id, is_bot)
     }
 
-    pub fn join_match(ctx: Context<JoinMatch>, match_id: String, user_id: String) -> Result<()> {
-        instructions::join_match::handler(ctx, match_id, user_id)
+    pub fn invite_player(
+        ctx: Context<InvitePlayer>,
+        match_id: String,
+        invitee_user_id: String,
+        expiry: i64,
+    ) -> Result<()> {
+        instructions::invite_player::handler(ctx, match_id, invitee_user_id, expiry)
+    }
+
+    pub fn accept_invitation(ctx: Context<AcceptInvitation>, match_id: String, user_id: String) -> Result<()> {
+        instructions::accept_invitation::handler(ctx, match_id, user_id)
+    }
+
+    pub fn enqueue_player(ctx: Context<EnqueuePlayer>, game_type: u8, user_id: String) -> Result<()> {
+        instructions::enqueue_player::handler(ctx, game_type, user_id)
+    }
+
+    pub fn dequeue_player(ctx: Context<DequeuePlayer>, game_type: u8, user_id: String) -> Result<()> {
+        instructions::dequeue_player::handler(ctx, game_type, user_id)
+    }
+
+    pub fn form_match_from_queue(
+        ctx: Context<FormMatchFromQueue>,
+        game_type: u8,
+        match_id: String,
+        seed: u64,
+        player_count: u8,
+        tier_band: u8,
+    ) -> Result<()> {
+        instructions::form_match_from_queue::handler(ctx, game_type, match_id, seed, player_count, tier_band)
     }
 
     pub fn start_match(ctx: Context<StartMatch>, match_id: String) -> Result<()> {
         instructions::start_match::handler(ctx, match_id)
     }
 
+    pub fn set_ready(ctx: Context<SetReady>, match_id: String, user_id: String, ready: bool) -> Result<()> {
+        instructions::set_ready::handler(ctx, match_id, user_id, ready)
+    }
+
+    pub fn set_teams(ctx: Context<SetTeams>, match_id: String, teams: [u8; 10]) -> Result<()> {
+        instructions::set_teams::handler(ctx, match_id, teams)
+    }
+
+    pub fn set_time_control(
+        ctx: Context<SetTimeControl>,
+        match_id: String,
+        initial_seconds: u32,
+        increment_seconds: u32,
+    ) -> Result<()> {
+        instructions::set_time_control::handler(ctx, match_id, initial_seconds, increment_seconds)
+    }
+
+    pub fn set_rule_params(
+        ctx: Context<SetRuleParams>,
+        match_id: String,
+        rule_params: [u8; 16],
+    ) -> Result<()> {
+        instructions::set_rule_params::handler(ctx, match_id, rule_params)
+    }
+
+    pub fn schedule_match(
+        ctx: Context<ScheduleMatch>,
+        match_id: String,
+        scheduled_start: i64,
+    ) -> Result<()> {
+        instructions::schedule_match::handler(ctx, match_id, scheduled_start)
+    }
+
+    pub fn expire_scheduled_match(ctx: Context<ExpireScheduledMatch>, match_id: String) -> Result<()> {
+        instructions::expire_scheduled_match::handler(ctx, match_id)
+    }
+
+    pub fn set_match_metadata(
+        ctx: Context<SetMatchMetadata>,
+        match_id: String,
+        key: [u8; 8],
+        value: [u8; 32],
+    ) -> Result<()> {
+        instructions::set_match_metadata::handler(ctx, match_id, key, value)
+    }
+
+    pub fn init_scrabble_board(
+        ctx: Context<InitScrabbleBoard>,
+        match_id: String,
+        bag_head: [u8; 32],
+    ) -> Result<()> {
+        instructions::init_scrabble_board::handler(ctx, match_id, bag_head)
+    }
+
+    pub fn draw_tiles(
+        ctx: Context<DrawTiles>,
+        match_id: String,
+        user_id: String,
+        reveals: Vec<TileReveal>,
+    ) -> Result<()> {
+        instructions::draw_tiles::handler(ctx, match_id, user_id, reveals)
+    }
+
+    pub fn place_word(
+        ctx: Context<PlaceWord>,
+        match_id: String,
+        user_id: String,
+        row: u8,
+        col: u8,
+        horizontal: bool,
+        tiles: Vec<u8>,
+    ) -> Result<()> {
+        instructions::place_word::handler(ctx, match_id, user_id, row, col, horizontal, tiles)
+    }
+
+    pub fn commit_word_search(
+        ctx: Context<CommitWordSearch>,
+        match_id: String,
+        grid_hash: [u8; 32],
+        word_root: [u8; 32],
+        rows: u8,
+        cols: u8,
+        word_count: u8,
+    ) -> Result<()> {
+        instructions::commit_word_search::handler(ctx, match_id, grid_hash, word_root, rows, cols, word_count)
+    }
+
+    pub fn reveal_word_search_grid(ctx: Context<RevealWordSearchGrid>, match_id: String, grid: Vec<u8>) -> Result<()> {
+        instructions::reveal_word_search_grid::handler(ctx, match_id, grid)
+    }
+
+    pub fn submit_found_word(
+        ctx: Context<SubmitFoundWord>,
+        match_id: String,
+        user_id: String,
+        claim: FoundWord,
+    ) -> Result<()> {
+        instructions::submit_found_word::handler(ctx, match_id, user_id, claim)
+    }
+
+    pub fn commit_crossword(
+        ctx: Context<CommitCrossword>,
+        match_id: String,
+        answer_root: [u8; 32],
+        clue_count: u8,
+    ) -> Result<()> {
+        instructions::commit_crossword::handler(ctx, match_id, answer_root, clue_count)
+    }
+
+    pub fn submit_answer(
+        ctx: Context<SubmitAnswer>,
+        match_id: String,
+        user_id: String,
+        clue_index: u8,
+        answer: String,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::submit_answer::handler(ctx, match_id, user_id, clue_index, answer, proof)
+    }
+
+    pub fn claim_timeout(ctx: Context<ClaimTimeout>, match_id: String) -> Result<()> {
+        instructions::claim_timeout::handler(ctx, match_id)
+    }
+
+    pub fn close_showdown(ctx: Context<CloseShowdown>, match_id: String) -> Result<()> {
+        instructions::close_showdown::handler(ctx, match_id)
+    }
+
+    pub fn pause_match(
+        ctx: Context<PauseMatch>,
+        match_id: String,
+        reason: u8,
+        max_duration_seconds: u32,
+    ) -> Result<()> {
+        instructions::pause_match::handler(ctx, match_id, reason, max_duration_seconds)
+    }
+
+    pub fn resume_match(ctx: Context<ResumeMatch>, match_id: String) -> Result<()> {
+        instructions::resume_match::handler(ctx, match_id)
+    }
+
     pub fn commit_hand(
         ctx: Context<CommitHand>,
         match_id: String,
@@ -42,6 +268,15 @@ pub mod solana_games_program {
         instructions::commit_hand::handler(ctx, match_id, user_id, hand_hash, hand_size)
     }
 
+    pub fn commit_deck(
+        ctx: Context<CommitDeck>,
+        match_id: String,
+        deck_root: [u8; 32],
+        deck_size: u8,
+    ) -> Result<()> {
+        instructions::commit_deck::handler(ctx, match_id, deck_root, deck_size)
+    }
+
     pub fn submit_move(
         ctx: Context<SubmitMove>,
         match_id: String,
@@ -49,8 +284,68 @@ pub mod solana_games_program {
         action_type: u8,
         payload: Vec<u8>,
         nonce: u64,
+        envelope_signature: Option<relay::EnvelopeSignature>,
+    ) -> Result<()> {
+        instructions::submit_move::handler(ctx, match_id, user_id, action_type, payload, nonce, envelope_signature)
+    }
+
+    pub fn submit_relayed_move(
+        ctx: Context<SubmitRelayedMove>,
+        envelope: relay::MoveEnvelope,
+        player: Pubkey,
     ) -> Result<()> {
-        instructions::submit_move::handler(ctx, match_id, user_id, action_type, payload, nonce)
+        instructions::submit_relayed_move::handler(ctx, envelope, player)
+    }
+
+    pub fn attest_ai_move(
+        ctx: Context<AttestAiMove>,
+        match_id: String,
+        move_index: u32,
+        model_id: u8,
+        prompt_hash: [u8; 32],
+        response_hash: [u8; 32],
+        consumption_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::attest_ai_move::handler(ctx, match_id, move_index, model_id, prompt_hash, response_hash, consumption_id)
+    }
+
+    pub fn reveal_floor_card(
+        ctx: Context<RevealFloorCard>,
+        match_id: String,
+        floor_card_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_floor_card::handler(ctx, match_id, floor_card_hash)
+    }
+
+    pub fn start_next_round(ctx: Context<StartNextRound>, match_id: String) -> Result<()> {
+        instructions::start_next_round::handler(ctx, match_id)
+    }
+
+    pub fn create_series(
+        ctx: Context<CreateSeries>,
+        series_id: String,
+        game_type: u8,
+        best_of: u8,
+        player_ids: Vec<String>,
+    ) -> Result<()> {
+        instructions::create_series::handler(ctx, series_id, game_type, best_of, player_ids)
+    }
+
+    pub fn report_series_game(
+        ctx: Context<ReportSeriesGame>,
+        series_id: String,
+        match_id: String,
+    ) -> Result<()> {
+        instructions::report_series_game::handler(ctx, series_id, match_id)
+    }
+
+    pub fn transfer_match_authority(
+        ctx: Context<TransferMatchAuthority>,
+        match_id: String,
+        new_authority: Pubkey,
+        user_id: Option<String>,
+    ) -> Result<()> {
+        instructions::transfer_match_authority::handler(ctx, match_id, new_authority, user_id)
     }
 
     pub fn end_match(
@@ -62,13 +357,35 @@ pub mod solana_games_program {
         instructions::end_match::handler(ctx, match_id, match_hash, hot_url)
     }
 
+    pub fn initialize_player_rating(ctx: Context<InitializePlayerRating>, user_id: String) -> Result<()> {
+        instructions::initialize_player_rating::handler(ctx, user_id)
+    }
+
+    pub fn record_rating_change(ctx: Context<RecordRatingChange>, match_id: String) -> Result<()> {
+        instructions::record_rating_change::handler(ctx, match_id)
+    }
+
+    pub fn mint_trophy(ctx: Context<MintTrophy>, match_id: String, uri: String) -> Result<()> {
+        instructions::mint_trophy::handler(ctx, match_id, uri)
+    }
+
     pub fn anchor_match_record(
         ctx: Context<AnchorMatchRecord>,
         match_id: String,
         match_hash: [u8; 32],
         hot_url: Option<String>,
+        cold_uri: Option<String>,
+        cold_content_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::anchor_match_record::handler(ctx, match_id, match_hash, hot_url, cold_uri, cold_content_hash)
+    }
+
+    pub fn compact_match_moves<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CompactMatchMoves<'info>>,
+        match_id: String,
+        start_index: u32,
     ) -> Result<()> {
-        instructions::anchor_match_record::handler(ctx, match_id, match_hash, hot_url)
+        instructions::compact_match_moves::handler(ctx, match_id, start_index)
     }
 
     pub fn register_signer(
@@ -86,19 +403,21 @@ pub mod solana_games_program {
         count: u64,
         first_match_id: String,
         last_match_id: String,
+        link: BatchLink,
     ) -> Result<()> {
-        instructions::anchor_batch::handler(ctx, batch_id, merkle_root, count, first_match_id, last_match_id)
+        instructions::anchor_batch::handler(ctx, batch_id, merkle_root, count, first_match_id, last_match_id, link)
     }
 
     pub fn flag_dispute(
         ctx: Context<FlagDispute>,
         match_id: String,
         user_id: String,
+        defendant_user_id: String,
         reason: u8,
         evidence_hash: [u8; 32],
         gp_deposit: u32,
     ) -> Result<()> {
-        instructions::flag_dispute::handler(ctx, match_id, user_id, reason, evidence_hash, gp_deposit)
+        instructions::flag_dispute::handler(ctx, match_id, user_id, defendant_user_id, reason, evidence_hash, gp_deposit)
     }
 
     pub fn resolve_dispute(
@@ -109,6 +428,30 @@ pub mod solana_games_program {
         instructions::resolve_dispute::handler(ctx, dispute_id, resolution)
     }
 
+    pub fn verify_replay_segment(
+        ctx: Context<VerifyReplaySegment>,
+        match_id: String,
+        start_index: u32,
+        moves_data: Vec<ReplayMoveData>,
+        expected_intermediate_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::verify_replay_segment::handler(ctx, match_id, start_index, moves_data, expected_intermediate_hash)
+    }
+
+    pub fn issue_sanction(
+        ctx: Context<IssueSanction>,
+        user_id: String,
+        kind: u8,
+        reason: u8,
+        duration_seconds: i64,
+    ) -> Result<()> {
+        instructions::issue_sanction::handler(ctx, user_id, kind, reason, duration_seconds)
+    }
+
+    pub fn lift_sanction(ctx: Context<LiftSanction>, user_id: String) -> Result<()> {
+        instructions::lift_sanction::handler(ctx, user_id)
+    }
+
     // Per critique Issue #3: Add missing instructions
     pub fn close_match_account(
         ctx: Context<CloseMatchAccount>,
@@ -126,28 +469,111 @@ pub mod solana_games_program {
         instructions::slash_validator::handler(ctx, validator_pubkey, amount, reason)
     }
 
+    pub fn appeal_slash(ctx: Context<AppealSlash>) -> Result<()> {
+        instructions::appeal_slash::handler(ctx)
+    }
+
+    pub fn resolve_slash_appeal(
+        ctx: Context<ResolveSlashAppeal>,
+        validator_pubkey: Pubkey,
+        overturn: bool,
+    ) -> Result<()> {
+        instructions::resolve_slash_appeal::handler(ctx, validator_pubkey, overturn)
+    }
+
+    pub fn migrate_validator_reputation(
+        ctx: Context<MigrateValidatorReputation>,
+        validator_pubkey: Pubkey,
+    ) -> Result<()> {
+        instructions::migrate_validator_reputation::handler(ctx, validator_pubkey)
+    }
+
+    pub fn validator_heartbeat(ctx: Context<ValidatorHeartbeat>) -> Result<()> {
+        instructions::validator_heartbeat::handler(ctx)
+    }
+
+    pub fn enforce_inactivity<'info>(
+        ctx: Context<'_, '_, 'info, 'info, EnforceInactivity<'info>>,
+        validator_pubkey: Pubkey,
+    ) -> Result<()> {
+        instructions::enforce_inactivity::handler(ctx, validator_pubkey)
+    }
+
+    pub fn rotate_coordinator(
+        ctx: Context<RotateCoordinator>,
+        coordinator: Pubkey,
+        action: u8,
+    ) -> Result<()> {
+        instructions::rotate_coordinator::handler(ctx, coordinator, action)
+    }
+
+    pub fn force_end_match(
+        ctx: Context<ForceEndMatch>,
+        match_id: String,
+        reason: u8,
+    ) -> Result<()> {
+        instructions::force_end_match::handler(ctx, match_id, reason)
+    }
+
     // Economic model instructions (Section 20)
+    pub fn create_user_account(
+        ctx: Context<CreateUserAccount>,
+        user_id: String,
+    ) -> Result<()> {
+        instructions::create_user_account::handler(ctx, user_id)
+    }
+
+    pub fn extend_user_account(
+        ctx: Context<ExtendUserAccount>,
+        user_id: String,
+    ) -> Result<()> {
+        instructions::extend_user_account::handler(ctx, user_id)
+    }
+
+    pub fn erase_user_account(
+        ctx: Context<EraseUserAccount>,
+        user_id: String,
+        anon_key: [u8; 32],
+    ) -> Result<()> {
+        instructions::erase_user_account::handler(ctx, user_id, anon_key)
+    }
+
+    pub fn prove_account_ownership(
+        ctx: Context<ProveAccountOwnership>,
+        user_id: String,
+        challenge: [u8; 32],
+        signature: [u8; 64],
+        attestor: Pubkey,
+        expiry: i64,
+    ) -> Result<()> {
+        instructions::prove_account_ownership::handler(ctx, user_id, challenge, signature, attestor, expiry)
+    }
+
     pub fn claim_daily_login(
         ctx: Context<ClaimDailyLogin>,
         user_id: String,
+        op_nonce: u64,
     ) -> Result<()> {
-        instructions::daily_login::handler(ctx, user_id)
+        instructions::daily_login::handler(ctx, user_id, op_nonce)
     }
 
     pub fn start_game_with_gp(
         ctx: Context<StartGameWithGP>,
         match_id: String,
         user_id: String,
+        gp_cost: u32,
+        op_nonce: u64,
     ) -> Result<()> {
-        instructions::game_payment::handler(ctx, match_id, user_id)
+        instructions::game_payment::handler(ctx, match_id, user_id, gp_cost, op_nonce)
     }
 
     pub fn claim_ad_reward(
         ctx: Context<ClaimAdReward>,
         user_id: String,
         ad_verification_signature: Vec<u8>,
+        op_nonce: u64,
     ) -> Result<()> {
-        instructions::ad_reward::handler(ctx, user_id, ad_verification_signature)
+        instructions::ad_reward::handler(ctx, user_id, ad_verification_signature, op_nonce)
     }
 
     pub fn purchase_subscription(
@@ -155,16 +581,37 @@ pub mod solana_games_program {
         user_id: String,
         tier: u8,
         duration_days: u8,
+        op_nonce: u64,
+        attestation: payment::PaymentAttestation,
     ) -> Result<()> {
-        instructions::pro_subscription::handler(ctx, user_id, tier, duration_days)
+        instructions::pro_subscription::handler(ctx, user_id, tier, duration_days, op_nonce, attestation)
+    }
+
+    pub fn cancel_subscription(
+        ctx: Context<CancelSubscription>,
+        user_id: String,
+        op_nonce: u64,
+    ) -> Result<()> {
+        instructions::cancel_subscription::handler(ctx, user_id, op_nonce)
+    }
+
+    pub fn downgrade_subscription(
+        ctx: Context<DowngradeSubscription>,
+        user_id: String,
+        new_tier: u8,
+        op_nonce: u64,
+    ) -> Result<()> {
+        instructions::downgrade_subscription::handler(ctx, user_id, new_tier, op_nonce)
     }
 
     pub fn purchase_ai_credits(
         ctx: Context<PurchaseAICredits>,
         user_id: String,
         ac_amount: u64,
+        op_nonce: u64,
+        attestation: Option<payment::PaymentAttestation>,
     ) -> Result<()> {
-        instructions::ai_credit_purchase::handler(ctx, user_id, ac_amount)
+        instructions::ai_credit_purchase::handler(ctx, user_id, ac_amount, op_nonce, attestation)
     }
 
     pub fn consume_ai_credits(
@@ -172,8 +619,203 @@ pub mod solana_games_program {
         user_id: String,
         model_id: u8,
         tokens_used: u32,
+        period_id: u32,
+        op_nonce: u64,
+    ) -> Result<()> {
+        instructions::ai_credit_consume::handler(ctx, user_id, model_id, tokens_used, period_id, op_nonce)
+    }
+
+    pub fn accrue_subscription_credits(
+        ctx: Context<AccrueSubscriptionCredits>,
+        user_id: String,
+        period_id: u32,
+    ) -> Result<()> {
+        instructions::accrue_subscription_credits::handler(ctx, user_id, period_id)
+    }
+
+    pub fn grant_ai_credits(
+        ctx: Context<GrantAICredits>,
+        user_id: String,
+        grant_id: [u8; 32],
+        ac_amount: u64,
+        reason: u8,
+    ) -> Result<()> {
+        instructions::grant_ai_credits::handler(ctx, user_id, grant_id, ac_amount, reason)
+    }
+
+    pub fn refund_ai_consumption(
+        ctx: Context<RefundAIConsumption>,
+        user_id: String,
+        consumption_id: [u8; 32],
+        period_id: u32,
+        model_id: u8,
+        ac_amount: u64,
+        reason: u8,
+    ) -> Result<()> {
+        instructions::refund_ai_consumption::handler(ctx, user_id, consumption_id, period_id, model_id, ac_amount, reason)
+    }
+
+    pub fn process_refund(
+        ctx: Context<ProcessRefund>,
+        user_id: String,
+        product: u8,
+        amount: u64,
+        reference_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::process_refund::handler(ctx, user_id, product, amount, reference_hash)
+    }
+
+    pub fn process_chargeback(
+        ctx: Context<ProcessChargeback>,
+        user_id: String,
+        product: u8,
+        amount: u64,
+        reference_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::process_chargeback::handler(ctx, user_id, product, amount, reference_hash)
+    }
+
+    pub fn set_ai_spend_limit(
+        ctx: Context<SetAiSpendLimit>,
+        user_id: String,
+        daily_limit: u64,
+    ) -> Result<()> {
+        instructions::set_ai_spend_limit::handler(ctx, user_id, daily_limit)
+    }
+
+    // Promo codes
+    pub fn create_promo(
+        ctx: Context<CreatePromo>,
+        promo_index: u16,
+        code_hash: [u8; 32],
+        gp_amount: u64,
+        ac_amount: u64,
+        max_redemptions: u32,
+        expires_at: i64,
     ) -> Result<()> {
-        instructions::ai_credit_consume::handler(ctx, user_id, model_id, tokens_used)
+        instructions::create_promo::handler(ctx, promo_index, code_hash, gp_amount, ac_amount, max_redemptions, expires_at)
+    }
+
+    pub fn redeem_promo(
+        ctx: Context<RedeemPromo>,
+        user_id: String,
+        promo_index: u16,
+        code: String,
+    ) -> Result<()> {
+        instructions::redeem_promo::handler(ctx, user_id, promo_index, code)
+    }
+
+    pub fn gift_gp(
+        ctx: Context<GiftGP>,
+        from_user_id: String,
+        to_user_id: String,
+        amount: u64,
+        op_nonce: u64,
+    ) -> Result<()> {
+        instructions::gift_gp::handler(ctx, from_user_id, to_user_id, amount, op_nonce)
+    }
+
+    pub fn set_gift_policy(
+        ctx: Context<SetGiftPolicy>,
+        gift_daily_cap_gp: u64,
+        gift_min_account_age: i64,
+        gift_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::set_gift_policy::handler(ctx, gift_daily_cap_gp, gift_min_account_age, gift_fee_bps)
+    }
+
+    // Cosmetics
+    pub fn initialize_cosmetic_registry(ctx: Context<InitializeCosmeticRegistry>) -> Result<()> {
+        instructions::initialize_cosmetic_registry::handler(ctx)
+    }
+
+    pub fn initialize_global_stats(ctx: Context<InitializeGlobalStats>) -> Result<()> {
+        instructions::initialize_global_stats::handler(ctx)
+    }
+
+    pub fn register_cosmetic(
+        ctx: Context<RegisterCosmetic>,
+        item_id: u16,
+        kind: u8,
+        name: String,
+        gp_price: u64,
+    ) -> Result<()> {
+        instructions::register_cosmetic::handler(ctx, item_id, kind, name, gp_price)
+    }
+
+    pub fn equip_cosmetic(
+        ctx: Context<EquipCosmetic>,
+        user_id: String,
+        kind: u8,
+        item_id: u16,
+    ) -> Result<()> {
+        instructions::equip_cosmetic::handler(ctx, user_id, kind, item_id)
+    }
+
+    // Leaderboard instructions (Section 20.1.6)
+    pub fn create_leaderboard_shard(
+        ctx: Context<CreateLeaderboardShard>,
+        game_type: u8,
+        season_id: u64,
+        shard_index: u16,
+    ) -> Result<()> {
+        instructions::create_leaderboard_shard::handler(ctx, game_type, season_id, shard_index)
+    }
+
+    pub fn submit_leaderboard_entry(
+        ctx: Context<SubmitLeaderboardEntry>,
+        game_type: u8,
+        season_id: u64,
+        user_id: String,
+        score: u64,
+        wins: u32,
+        games_played: u32,
+    ) -> Result<()> {
+        instructions::submit_leaderboard_entry::handler(ctx, game_type, season_id, user_id, score, wins, games_played)
+    }
+
+    pub fn decay_leaderboard(
+        ctx: Context<DecayLeaderboard>,
+        game_type: u8,
+        season_id: u64,
+        shard_index: u16,
+    ) -> Result<()> {
+        instructions::decay_leaderboard::handler(ctx, game_type, season_id, shard_index)
+    }
+
+    pub fn distribute_season_rewards<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributeSeasonRewards<'info>>,
+        game_type: u8,
+        season_id: u64,
+        shard_index: u16,
+    ) -> Result<()> {
+        instructions::distribute_season_rewards::handler(ctx, game_type, season_id, shard_index)
+    }
+
+    // Guilds
+    pub fn create_guild(ctx: Context<CreateGuild>, name: String, owner_user_id: String) -> Result<()> {
+        instructions::create_guild::handler(ctx, name, owner_user_id)
+    }
+
+    pub fn join_guild(ctx: Context<JoinGuild>, name: String, user_id: String) -> Result<()> {
+        instructions::join_guild::handler(ctx, name, user_id)
+    }
+
+    pub fn leave_guild(ctx: Context<LeaveGuild>, name: String, user_id: String) -> Result<()> {
+        instructions::leave_guild::handler(ctx, name, user_id)
+    }
+
+    pub fn kick_member(ctx: Context<KickMember>, name: String, user_id: String) -> Result<()> {
+        instructions::kick_member::handler(ctx, name, user_id)
+    }
+
+    pub fn submit_guild_score(
+        ctx: Context<SubmitGuildScore>,
+        season_id: u64,
+        name: String,
+        user_id: String,
+    ) -> Result<()> {
+        instructions::submit_guild_score::handler(ctx, season_id, name, user_id)
     }
 
     // Game registry instructions (Section 16.5)
@@ -202,6 +844,49 @@ pub mod solana_games_program {
         instructions::update_game::handler(ctx, game_id, name, min_players, max_players, rule_engine_url, version, enabled)
     }
 
+    pub fn set_payload_schema(
+        ctx: Context<SetPayloadSchema>,
+        game_id: u8,
+        action_type: u8,
+        schema: Option<PayloadSchema>,
+    ) -> Result<()> {
+        instructions::set_payload_schema::handler(ctx, game_id, action_type, schema)
+    }
+
+    pub fn set_game_fee(
+        ctx: Context<SetGameFee>,
+        game_id: u8,
+        gp_cost: u32,
+        wager_allowed: bool,
+    ) -> Result<()> {
+        instructions::set_game_fee::handler(ctx, game_id, gp_cost, wager_allowed)
+    }
+
+    pub fn set_game_rules(
+        ctx: Context<SetGameRules>,
+        game_id: u8,
+        rules_variant: u8,
+    ) -> Result<()> {
+        instructions::set_game_rules::handler(ctx, game_id, rules_variant)
+    }
+
+    pub fn set_game_match_cap(
+        ctx: Context<SetGameMatchCap>,
+        game_id: u8,
+        daily_match_cap: u32,
+    ) -> Result<()> {
+        instructions::set_game_match_cap::handler(ctx, game_id, daily_match_cap)
+    }
+
+    pub fn set_game_betting(
+        ctx: Context<SetGameBetting>,
+        game_id: u8,
+        has_betting: bool,
+        rake_bps: u16,
+    ) -> Result<()> {
+        instructions::set_game_betting::handler(ctx, game_id, has_betting, rake_bps)
+    }
+
     // Move batching (Section 16.6)
     pub fn submit_batch_moves(
         ctx: Context<SubmitBatchMoves>,
@@ -211,5 +896,12 @@ pub mod solana_games_program {
     ) -> Result<()> {
         instructions::submit_batch_moves::handler(ctx, match_id, user_id, moves)
     }
+
+    pub fn set_congestion_level(
+        ctx: Context<SetCongestionLevel>,
+        congestion_level: u8,
+    ) -> Result<()> {
+        instructions::set_congestion_level::handler(ctx, congestion_level)
+    }
 }
 
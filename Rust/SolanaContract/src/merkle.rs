@@ -0,0 +1,107 @@
+//! Merkle trees for batch anchors and match commitments, shared by the coordinator and
+//! third-party auditors.
+//!
+//! Pure Rust: SHA-256 from `sha2` (no default features), `core` and `alloc` only, no Anchor
+//! or Solana types, so it builds for `no_std` targets. The tree rules are the ones the
+//! on-chain verifiers use (`DeckCommitment::verify_card`, `WordSearchPuzzle::verify_word`,
+//! `CrosswordPuzzle::verify_answer`):
+//!
+//! ```text
+//! node  = SHA-256(left || right)
+//! leaves are zero-padded to a power of two; a single leaf is its own root
+//! proof = sibling hashes leaf-to-root; bit i of the leaf index is 1 when the node is on the right
+//! ```
+//!
+//! Match records (the leaves of an `anchor_batch` root) are encoded as
+//!
+//! ```text
+//! leaf = SHA-256("ocentra-match-record-v1" || match_id (36) || match_hash (32) || ended_at (i64 LE))
+//! ```
+//!
+//! in the order the batch lists them (first_match_id .. last_match_id).
+
+use alloc::vec::Vec;
+use sha2::{Digest, Sha256};
+
+/// Domain separator for match record leaves (bump the version if the layout changes).
+pub const MATCH_RECORD_DOMAIN: &[u8] = b"ocentra-match-record-v1";
+
+/// SHA-256 over the concatenation of `parts`.
+pub fn hashv(parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+/// Parent of two nodes.
+pub fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    hashv(&[left, right])
+}
+
+/// Canonical leaf for a finished match in a batch.
+pub fn match_record_leaf(match_id: &[u8; 36], match_hash: &[u8; 32], ended_at: i64) -> [u8; 32] {
+    hashv(&[MATCH_RECORD_DOMAIN, match_id, match_hash, &ended_at.to_le_bytes()])
+}
+
+// Leaves zero-padded to the next power of two (empty stays empty)
+fn padded(leaves: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    let mut level = leaves.to_vec();
+    if !level.is_empty() {
+        level.resize(level.len().next_power_of_two(), [0; 32]);
+    }
+    level
+}
+
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level.chunks(2).map(|pair| hash_pair(&pair[0], &pair[1])).collect()
+}
+
+/// Root over `leaves` (all zeros for no leaves).
+pub fn root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    let mut level = padded(leaves);
+    if level.is_empty() {
+        return [0; 32];
+    }
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level[0]
+}
+
+/// Proof for the leaf at `index`, leaf-to-root (None if out of range).
+pub fn proof(leaves: &[[u8; 32]], index: usize) -> Option<Vec<[u8; 32]>> {
+    if index >= leaves.len() {
+        return None;
+    }
+    let mut level = padded(leaves);
+    let mut position = index;
+    let mut siblings = Vec::new();
+    while level.len() > 1 {
+        siblings.push(level[position ^ 1]);
+        level = next_level(&level);
+        position /= 2;
+    }
+    Some(siblings)
+}
+
+/// Root reached from `leaf` at `index` through `proof`.
+pub fn root_from_proof(leaf: [u8; 32], index: usize, proof: &[[u8; 32]]) -> [u8; 32] {
+    let mut node = leaf;
+    let mut position = index;
+    for sibling in proof {
+        node = if position & 1 == 0 {
+            hash_pair(&node, sibling)
+        } else {
+            hash_pair(sibling, &node)
+        };
+        position /= 2;
+    }
+    node
+}
+
+/// Whether `leaf` sits at `index` under `root`.
+pub fn verify(leaf: [u8; 32], index: usize, proof: &[[u8; 32]], root: &[u8; 32]) -> bool {
+    root_from_proof(leaf, index, proof) == *root
+}
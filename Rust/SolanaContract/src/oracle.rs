@@ -0,0 +1,93 @@
+//! SOL/USD price oracle for lamport-denominated pricing.
+//!
+//! Reads a Pyth v2 price account directly (the Pyth SDK pins an older solana-program). Only
+//! the fields needed for pricing are decoded:
+//!
+//! ```text
+//! offset  0  magic     u32  0xa1b2c3d4
+//! offset  8  atype     u32  3 = price account
+//! offset 20  expo      i32  price = agg.price * 10^expo
+//! offset 96  timestamp i64  publish time of the aggregate price
+//! offset 208 agg.price i64
+//! offset 216 agg.conf  u64  confidence interval (same exponent as price)
+//! offset 224 agg.status u32 1 = trading
+//! ```
+
+use anchor_lang::prelude::*;
+use crate::error::GameError;
+
+pub const PYTH_MAGIC: u32 = 0xa1b2c3d4;
+pub const PYTH_PRICE_ACCOUNT_TYPE: u32 = 3;
+pub const PYTH_STATUS_TRADING: u32 = 1;
+pub const PYTH_PRICE_ACCOUNT_MIN_LEN: usize = 240;
+
+/// Aggregate price from a Pyth price account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OraclePrice {
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+impl OraclePrice {
+    /// Decodes the aggregate price. Rejects non-price accounts and prices that aren't trading.
+    pub fn from_pyth_account(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= PYTH_PRICE_ACCOUNT_MIN_LEN
+                && read_u32(data, 0) == PYTH_MAGIC
+                && read_u32(data, 8) == PYTH_PRICE_ACCOUNT_TYPE,
+            GameError::InvalidPriceFeed
+        );
+        require!(
+            read_u32(data, 224) == PYTH_STATUS_TRADING,
+            GameError::PriceUnavailable
+        );
+        Ok(Self {
+            price: read_u64(data, 208) as i64,
+            conf: read_u64(data, 216),
+            expo: read_u32(data, 20) as i32,
+            publish_time: read_u64(data, 96) as i64,
+        })
+    }
+
+    /// Checks staleness (seconds since publish, always enforced) and confidence width (basis
+    /// points of price, 0 = unchecked).
+    pub fn check(&self, now: i64, max_staleness_seconds: i64, max_confidence_bps: u16) -> Result<()> {
+        require!(self.price > 0, GameError::PriceUnavailable);
+        require!(
+            now.saturating_sub(self.publish_time) <= max_staleness_seconds,
+            GameError::PriceUnavailable
+        );
+        require!(
+            max_confidence_bps == 0
+                || (self.conf as u128) * 10_000 <= (self.price as u128) * max_confidence_bps as u128,
+            GameError::PriceUnavailable
+        );
+        Ok(())
+    }
+
+    /// Lamports for a USD amount in micro-dollars (1_000_000 = $1), rounded up.
+    /// lamports = usd_micros * 10^9 / (10^6 * price * 10^expo)
+    pub fn lamports_for_usd_micros(&self, usd_micros: u128) -> Result<u64> {
+        require!(self.price > 0, GameError::PriceUnavailable);
+        let scale = 3i32.checked_sub(self.expo).ok_or(GameError::Overflow)?;
+        let (numerator, denominator) = if scale >= 0 {
+            let factor = 10u128.checked_pow(scale as u32).ok_or(GameError::Overflow)?;
+            (usd_micros.checked_mul(factor).ok_or(GameError::Overflow)?, self.price as u128)
+        } else {
+            let factor = 10u128.checked_pow(scale.unsigned_abs()).ok_or(GameError::Overflow)?;
+            (usd_micros, (self.price as u128).checked_mul(factor).ok_or(GameError::Overflow)?)
+        };
+        let lamports = numerator.div_ceil(denominator);
+        Ok(u64::try_from(lamports).map_err(|_| GameError::Overflow)?)
+    }
+}
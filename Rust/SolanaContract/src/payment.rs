@@ -0,0 +1,96 @@
+//! Off-chain (Stripe) payment attestations.
+//!
+//! `purchase_subscription` and the Stripe path of `purchase_ai_credits` grant nothing on the
+//! caller's word: a registered coordinator, acting as the payment oracle once the Stripe
+//! webhook confirms the charge, signs a receipt with ed25519:
+//!
+//! ```text
+//! message = "ocentra-payment-v1" || user_id_len (u8) || user_id || product (u8)
+//!           || amount (u64 LE) || payment_intent_hash (32) || expiry (i64 LE)
+//! ```
+//!
+//! `payment_intent_hash` is the SHA-256 of the Stripe PaymentIntent id. The signature is
+//! checked by the Ed25519 precompile instruction immediately before the purchase (see
+//! crate::relay), and the purchase creates a `PaymentReceipt` PDA keyed by the hash, so one
+//! receipt can't be replayed into several grants.
+
+use anchor_lang::prelude::*;
+use crate::state::{SignerRegistry, SignerRole};
+use crate::relay;
+use crate::error::GameError;
+
+/// Domain separator (bump the version if the layout changes).
+pub const PAYMENT_DOMAIN: &[u8] = b"ocentra-payment-v1";
+
+/// What a receipt pays for; `amount` is in the product's unit.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum PaymentProduct {
+    ProSubscription = 1,     // amount = duration in days
+    ProPlusSubscription = 2, // amount = duration in days
+    AiCredits = 3,           // amount = AC
+}
+
+impl PaymentProduct {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Self::ProSubscription),
+            2 => Some(Self::ProPlusSubscription),
+            3 => Some(Self::AiCredits),
+            _ => None,
+        }
+    }
+    
+    pub fn is_subscription(&self) -> bool {
+        *self != Self::AiCredits
+    }
+}
+
+/// The oracle's receipt for one Stripe payment, as passed to the purchase instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PaymentAttestation {
+    pub payment_intent_hash: [u8; 32], // SHA-256 of the Stripe PaymentIntent id
+    pub expiry: i64,                   // Receipt can't be redeemed after this
+    pub attestor: Pubkey,              // Registered coordinator that signed it
+}
+
+impl PaymentAttestation {
+    /// Bytes the attestor signs (see module docs).
+    pub fn message(&self, user_id: &str, product: PaymentProduct, amount: u64) -> Result<Vec<u8>> {
+        require!(user_id.len() <= 64, GameError::UserIdTooLong);
+        let mut message = Vec::with_capacity(PAYMENT_DOMAIN.len() + 1 + user_id.len() + 1 + 8 + 32 + 8);
+        message.extend_from_slice(PAYMENT_DOMAIN);
+        message.push(user_id.len() as u8);
+        message.extend_from_slice(user_id.as_bytes());
+        message.push(product as u8);
+        message.extend_from_slice(&amount.to_le_bytes());
+        message.extend_from_slice(&self.payment_intent_hash);
+        message.extend_from_slice(&self.expiry.to_le_bytes());
+        Ok(message)
+    }
+
+    /// Checks the receipt is unexpired, from a registered coordinator, and signed over exactly
+    /// this grant by the Ed25519 instruction before the current one.
+    pub fn verify(
+        &self,
+        user_id: &str,
+        product: PaymentProduct,
+        amount: u64,
+        signer_registry: &SignerRegistry,
+        instructions_sysvar: &AccountInfo,
+        clock: &Clock,
+    ) -> Result<()> {
+        require!(clock.unix_timestamp <= self.expiry, GameError::PaymentAttestationExpired);
+        require!(
+            signer_registry.get_role(&self.attestor) == Some(SignerRole::Coordinator),
+            GameError::InvalidPaymentAttestation
+        );
+        let message = self.message(user_id, product, amount)?;
+        relay::verify_preceding_signature(instructions_sysvar, &self.attestor, &message)
+            .map_err(|_| error!(GameError::InvalidPaymentAttestation))
+    }
+}
+
+/// Seed for the PaymentReceipt PDA of an optional attestation (empty when absent).
+pub fn receipt_seed(attestation: &Option<PaymentAttestation>) -> &[u8] {
+    attestation.as_ref().map_or(&[], |a| a.payment_intent_hash.as_ref())
+}
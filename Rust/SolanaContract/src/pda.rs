@@ -0,0 +1,191 @@
+//! PDA derivation for this program's accounts.
+//! Seeds mirror the `#[account(seeds = ...)]` constraints in `instructions/`.
+
+use anchor_lang::prelude::*;
+use crate::state::{match_id_seed, MatchCreator};
+
+pub fn match_pda(match_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"match", match_id_seed(match_id.as_bytes())], &crate::ID)
+}
+
+pub fn batch_anchor_pda(batch_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"batch_anchor", batch_id.as_bytes()], &crate::ID)
+}
+
+pub fn batch_head_pda(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"batch_head", authority.as_ref()], &crate::ID)
+}
+
+pub fn match_creator_pda(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"match_creator", authority.as_ref()], &crate::ID)
+}
+
+/// match_id of `authority`'s counted match number `match_counter` (see create_match_counted);
+/// its PDA is match_pda of this id.
+pub fn counted_match_id(authority: &Pubkey, match_counter: u64) -> String {
+    String::from_utf8_lossy(&MatchCreator::derived_match_id(authority, match_counter)).into_owned()
+}
+
+pub fn move_pda(match_id: &str, move_index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"move", match_id_seed(match_id.as_bytes()), move_index.to_le_bytes().as_ref()],
+        &crate::ID,
+    )
+}
+
+pub fn ai_attestation_pda(match_id: &str, move_index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"ai_attestation", match_id_seed(match_id.as_bytes()), move_index.to_le_bytes().as_ref()],
+        &crate::ID,
+    )
+}
+
+pub fn dispute_pda(match_id: &str, flagger: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"dispute", match_id_seed(match_id.as_bytes()), flagger.as_ref()],
+        &crate::ID,
+    )
+}
+
+pub fn validator_reputation_pda(validator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"validator", validator.as_ref()], &crate::ID)
+}
+
+pub fn user_account_pda(user_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"user_account", user_id.as_bytes()], &crate::ID)
+}
+
+pub fn usage_meter_pda(user_id: &str, period_id: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"usage_meter", user_id.as_bytes(), period_id.to_le_bytes().as_ref()],
+        &crate::ID,
+    )
+}
+
+pub fn credit_grant_pda(user_id: &str, grant_id: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"credit_adjustment", user_id.as_bytes(), grant_id.as_ref()], &crate::ID)
+}
+
+pub fn credit_refund_pda(user_id: &str, consumption_id: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"credit_refund", user_id.as_bytes(), consumption_id.as_ref()], &crate::ID)
+}
+
+pub fn payment_receipt_pda(payment_intent_hash: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"payment_receipt", payment_intent_hash.as_ref()], &crate::ID)
+}
+
+pub fn payment_reversal_pda(reference_hash: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"payment_reversal", reference_hash.as_ref()], &crate::ID)
+}
+
+pub fn erased_user_pda(anon_key: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"erased_user", anon_key.as_ref()], &crate::ID)
+}
+
+pub fn config_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config_account"], &crate::ID)
+}
+
+pub fn game_registry_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"game_registry"], &crate::ID)
+}
+
+pub fn signer_registry_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"signer_registry"], &crate::ID)
+}
+
+pub fn coordinator_set_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"coordinator_set"], &crate::ID)
+}
+
+pub fn sanction_pda(user_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"sanction", user_id.as_bytes()], &crate::ID)
+}
+
+pub fn player_rating_pda(user_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"player_rating", user_id.as_bytes()], &crate::ID)
+}
+
+pub fn scrabble_board_pda(match_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"scrabble_board", match_id_seed(match_id.as_bytes())], &crate::ID)
+}
+
+pub fn word_search_pda(match_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"word_search", match_id_seed(match_id.as_bytes())], &crate::ID)
+}
+
+pub fn crossword_pda(match_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"crossword", match_id_seed(match_id.as_bytes())], &crate::ID)
+}
+
+pub fn series_pda(series_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"series", match_id_seed(series_id.as_bytes())], &crate::ID)
+}
+
+pub fn deck_commitment_pda(match_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"deck_commitment", match_id_seed(match_id.as_bytes())], &crate::ID)
+}
+
+pub fn match_queue_pda(game_type: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"match_queue", &[game_type]], &crate::ID)
+}
+
+pub fn open_lobby_index_pda(game_type: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"open_lobbies", &[game_type]], &crate::ID)
+}
+
+pub fn leaderboard_index_pda(game_type: u8, season_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"leaderboard_index", &[game_type], &season_id.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+pub fn leaderboard_shard_pda(game_type: u8, season_id: u64, shard_index: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"leaderboard", &[game_type], &season_id.to_le_bytes(), &shard_index.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+pub fn promo_pda(promo_index: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"promo", promo_index.to_le_bytes().as_ref()], &crate::ID)
+}
+
+pub fn trophy_pda(match_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"trophy", match_account.as_ref()], &crate::ID)
+}
+
+pub fn trophy_mint_pda(match_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"trophy_mint", match_account.as_ref()], &crate::ID)
+}
+
+pub fn trophy_authority_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"trophy_authority"], &crate::ID)
+}
+
+pub fn global_stats_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"global_stats"], &crate::ID)
+}
+
+pub fn cosmetic_registry_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"cosmetic_registry"], &crate::ID)
+}
+
+pub fn player_cosmetics_pda(user_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"cosmetics", user_id.as_bytes()], &crate::ID)
+}
+
+pub fn guild_pda(name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"guild", name.as_bytes()], &crate::ID)
+}
+
+pub fn replay_checkpoint_pda(match_id: &str) -> (Pubkey, u8) {
+    let match_account = match_pda(match_id).0;
+    Pubkey::find_program_address(&[b"replay_checkpoint", match_account.as_ref()], &crate::ID)
+}
+
+pub fn invitation_pda(match_id: &str, invitee_user_id: &str) -> (Pubkey, u8) {
+    let match_account = match_pda(match_id).0;
+    Pubkey::find_program_address(&[b"invitation", match_account.as_ref(), invitee_user_id.as_bytes()], &crate::ID)
+}
@@ -0,0 +1,192 @@
+//! Relayed (gasless) moves.
+//!
+//! Players identified by Firebase UIDs may hold no SOL. In relay mode a registered coordinator
+//! signs `submit_relayed_move` and pays the Move rent, while the player's wallet proves intent
+//! with an ed25519 signature over the move envelope:
+//!
+//! ```text
+//! message = "ocentra-move-v1" || match_id (36) || user_id_len (u8) || user_id
+//!           || action_type (u8) || nonce (u64 LE) || payload_len (u8) || payload
+//! ```
+//!
+//! The signature is checked by the Ed25519 precompile in the instruction immediately before
+//! `submit_relayed_move`; the program then confirms that instruction covers this signer and
+//! exactly this message. The per-player nonce keeps a signed envelope from being replayed.
+//!
+//! Signed moves (`submit_move` with an envelope signature) are dispute evidence rather than
+//! relaying: the wallet that submits the move also signs the envelope together with the slot
+//! it saw, and the Move stores a hash of what was signed:
+//!
+//! ```text
+//! signed_message = "ocentra-signed-move-v1" || slot (u64 LE) || message
+//! envelope_hash  = SHA-256(signed_message || signature)
+//! ```
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
+use crate::error::GameError;
+
+/// Domain separator (bump the version if the layout changes).
+pub const RELAY_DOMAIN: &[u8] = b"ocentra-move-v1";
+/// Domain separator for signed move envelopes (dispute evidence).
+pub const SIGNED_MOVE_DOMAIN: &[u8] = b"ocentra-signed-move-v1";
+
+// Ed25519 precompile instruction layout (one signature, all data inline)
+const ED25519_OFFSETS_START: usize = 2;           // num_signatures (u8) + padding (u8)
+const ED25519_PUBKEY_OFFSET: usize = 16;          // after one 14-byte offsets entry
+const ED25519_SIGNATURE_OFFSET: usize = 16 + 32;
+const ED25519_MESSAGE_OFFSET: usize = 16 + 32 + 64;
+const ED25519_THIS_INSTRUCTION: u16 = u16::MAX;
+
+/// A move as signed by the player.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MoveEnvelope {
+    pub match_id: String,
+    pub user_id: String,
+    pub action_type: u8,
+    pub payload: Vec<u8>,
+    pub nonce: u64,
+}
+
+impl MoveEnvelope {
+    /// Bytes the player signs (see module docs).
+    pub fn message(&self) -> Result<Vec<u8>> {
+        require!(self.match_id.len() == 36, GameError::MatchIdMismatch);
+        require!(self.user_id.len() <= 64, GameError::UserIdTooLong);
+        require!(self.payload.len() <= 128, GameError::PayloadTooLarge);
+        let mut message = Vec::with_capacity(RELAY_DOMAIN.len() + 36 + 1 + self.user_id.len() + 1 + 8 + 1 + self.payload.len());
+        message.extend_from_slice(RELAY_DOMAIN);
+        message.extend_from_slice(self.match_id.as_bytes());
+        message.push(self.user_id.len() as u8);
+        message.extend_from_slice(self.user_id.as_bytes());
+        message.push(self.action_type);
+        message.extend_from_slice(&self.nonce.to_le_bytes());
+        message.push(self.payload.len() as u8);
+        message.extend_from_slice(&self.payload);
+        Ok(message)
+    }
+}
+
+/// Player signature over a move envelope, as passed to submit_move.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EnvelopeSignature {
+    pub slot: u64,           // Slot the client signed at (not after the slot it lands in)
+    pub signature: [u8; 64], // Ed25519 signature over SignedMoveEnvelope::message
+}
+
+/// A move exactly as the player signed it: the move bytes, the slot and the signature.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedMoveEnvelope {
+    pub envelope: MoveEnvelope,
+    pub slot: u64,
+    pub signature: [u8; 64],
+}
+
+impl SignedMoveEnvelope {
+    pub fn new(envelope: MoveEnvelope, signature: EnvelopeSignature) -> Self {
+        SignedMoveEnvelope { envelope, slot: signature.slot, signature: signature.signature }
+    }
+
+    /// Bytes the player signs (see module docs).
+    pub fn message(&self) -> Result<Vec<u8>> {
+        let move_bytes = self.envelope.message()?;
+        let mut message = Vec::with_capacity(SIGNED_MOVE_DOMAIN.len() + 8 + move_bytes.len());
+        message.extend_from_slice(SIGNED_MOVE_DOMAIN);
+        message.extend_from_slice(&self.slot.to_le_bytes());
+        message.extend_from_slice(&move_bytes);
+        Ok(message)
+    }
+
+    /// Hash stored on the Move (`envelope_hash`): covers the signed bytes and the signature.
+    pub fn hash(&self) -> Result<[u8; 32]> {
+        Ok(hashv(&[&self.message()?, &self.signature]).to_bytes())
+    }
+}
+
+/// Ed25519 precompile instruction data verifying one signature (for clients and tests).
+pub fn ed25519_instruction_data(signer: &Pubkey, signature: &[u8; 64], message: &[u8]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(ED25519_MESSAGE_OFFSET + message.len());
+    data.push(1); // num_signatures
+    data.push(0); // padding
+    for value in [
+        ED25519_SIGNATURE_OFFSET as u16,
+        ED25519_THIS_INSTRUCTION,
+        ED25519_PUBKEY_OFFSET as u16,
+        ED25519_THIS_INSTRUCTION,
+        ED25519_MESSAGE_OFFSET as u16,
+        message.len() as u16,
+        ED25519_THIS_INSTRUCTION,
+    ] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+    data
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    data.get(offset..offset + 2).map(|b| u16::from_le_bytes([b[0], b[1]]))
+}
+
+/// Checks that `ix` is an Ed25519 precompile instruction verifying `signer` over `message`.
+/// The precompile itself rejects the transaction if the signature is invalid.
+pub fn verify_ed25519_instruction(ix: &Instruction, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    require!(
+        ix.program_id == ed25519_program::ID && ix.accounts.is_empty(),
+        GameError::InvalidRelaySignature
+    );
+    let data = &ix.data;
+    require!(data.first() == Some(&1), GameError::InvalidRelaySignature);
+
+    let field = |i: usize| read_u16(data, ED25519_OFFSETS_START + i * 2).ok_or(GameError::InvalidRelaySignature);
+    let (signature_ix, pubkey_offset, pubkey_ix) = (field(1)?, field(2)? as usize, field(3)?);
+    let (message_offset, message_size, message_ix) = (field(4)? as usize, field(5)? as usize, field(6)?);
+
+    // Offsets must point into this instruction, not data planted in another one
+    require!(
+        signature_ix == ED25519_THIS_INSTRUCTION
+            && pubkey_ix == ED25519_THIS_INSTRUCTION
+            && message_ix == ED25519_THIS_INSTRUCTION,
+        GameError::InvalidRelaySignature
+    );
+    require!(
+        data.get(pubkey_offset..pubkey_offset + 32) == Some(signer.as_ref())
+            && data.get(message_offset..message_offset + message_size) == Some(message),
+        GameError::InvalidRelaySignature
+    );
+    Ok(())
+}
+
+fn preceding_instruction(instructions_sysvar: &AccountInfo) -> Result<Instruction> {
+    let current = load_current_index_checked(instructions_sysvar)? as usize;
+    require!(current > 0, GameError::InvalidRelaySignature);
+    Ok(load_instruction_at_checked(current - 1, instructions_sysvar)?)
+}
+
+/// Verifies the Ed25519 instruction immediately before the current one.
+pub fn verify_preceding_signature(instructions_sysvar: &AccountInfo, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    let ix = preceding_instruction(instructions_sysvar)?;
+    verify_ed25519_instruction(&ix, signer, message)
+}
+
+/// Like verify_preceding_signature, and the instruction must carry exactly `signature`
+/// (for instructions that also take the signature as an argument).
+pub fn verify_preceding_signature_bytes(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+    signature: &[u8; 64],
+) -> Result<()> {
+    let ix = preceding_instruction(instructions_sysvar)?;
+    verify_ed25519_instruction(&ix, signer, message)?;
+    let signature_offset = read_u16(&ix.data, ED25519_OFFSETS_START).ok_or(GameError::InvalidRelaySignature)? as usize;
+    require!(
+        ix.data.get(signature_offset..signature_offset + 64) == Some(&signature[..]),
+        GameError::InvalidRelaySignature
+    );
+    Ok(())
+}
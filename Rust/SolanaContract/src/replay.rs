@@ -0,0 +1,106 @@
+//! Canonical match transcript hashing.
+//!
+//! The match hash is a hash chain over a fixed match header and every move in order:
+//!
+//! ```text
+//! h_0     = SHA-256("ocentra-replay-v1" || match_id || game_type || seed || authority
+//!                   || player_count || player_ids[0..player_count])
+//! h_(i+1) = SHA-256(h_i || move_index (u32 LE) || player || action_type || payload_len || payload)
+//! match_hash = SHA-256(h_n || "end" || move_count (u32 LE))
+//! ```
+//!
+//! The program keeps h_i on the Match (`transcript_hash`) as moves are submitted, and
+//! `end_match` / `anchor_match_record` only accept the resulting match hash. Off-chain
+//! verifiers rebuild it from the Move accounts with [`compute_match_hash`].
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::state::{Match, Move};
+
+/// Domain separator (bump the version if the layout changes).
+pub const REPLAY_DOMAIN: &[u8] = b"ocentra-replay-v1";
+
+/// Match fields fixed before the first move.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MatchHeader {
+    pub match_id: [u8; 36],
+    pub game_type: u8,
+    pub seed: u64,
+    pub authority: Pubkey,
+    pub player_ids: Vec<[u8; 64]>,
+}
+
+impl MatchHeader {
+    pub fn from_match(match_account: &Match) -> Self {
+        MatchHeader {
+            match_id: match_account.match_id,
+            game_type: match_account.game_type,
+            seed: match_account.seed,
+            authority: match_account.header_authority,
+            player_ids: match_account.player_ids[..match_account.player_count as usize].to_vec(),
+        }
+    }
+}
+
+/// One move as it appears in the transcript.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ReplayMove {
+    pub move_index: u32,
+    pub player: Pubkey,
+    pub action_type: u8,
+    pub payload: Vec<u8>,
+}
+
+impl From<&Move> for ReplayMove {
+    fn from(move_account: &Move) -> Self {
+        ReplayMove {
+            move_index: move_account.move_index,
+            player: move_account.player,
+            action_type: move_account.action_type,
+            payload: move_account.get_payload_slice().to_vec(),
+        }
+    }
+}
+
+/// h_0: the transcript hash before any move.
+pub fn header_hash(header: &MatchHeader) -> [u8; 32] {
+    let player_count = [header.player_ids.len() as u8];
+    let mut parts: Vec<&[u8]> = vec![
+        REPLAY_DOMAIN,
+        &header.match_id,
+        std::slice::from_ref(&header.game_type),
+    ];
+    let seed = header.seed.to_le_bytes();
+    parts.push(&seed);
+    parts.push(header.authority.as_ref());
+    parts.push(&player_count);
+    for player_id in &header.player_ids {
+        parts.push(player_id);
+    }
+    hashv(&parts).to_bytes()
+}
+
+/// h_(i+1) from h_i and the next move.
+pub fn chain_move(prev: &[u8; 32], move_index: u32, player: &Pubkey, action_type: u8, payload: &[u8]) -> [u8; 32] {
+    hashv(&[
+        prev,
+        &move_index.to_le_bytes(),
+        player.as_ref(),
+        &[action_type, payload.len() as u8],
+        payload,
+    ])
+    .to_bytes()
+}
+
+/// Match hash from the final transcript hash.
+pub fn finalize(transcript_hash: &[u8; 32], move_count: u32) -> [u8; 32] {
+    hashv(&[transcript_hash, b"end", &move_count.to_le_bytes()]).to_bytes()
+}
+
+/// Canonical match hash over a header and the full, ordered move list.
+pub fn compute_match_hash(moves: &[ReplayMove], header: &MatchHeader) -> [u8; 32] {
+    let transcript = moves.iter().fold(header_hash(header), |h, m| {
+        chain_move(&h, m.move_index, &m.player, m.action_type, &m.payload)
+    });
+    finalize(&transcript, moves.len() as u32)
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+/// AiMoveAttestation records which AI model produced a move
+/// (seeds: ["ai_attestation", match_id, move_index]).
+///
+/// One per Move, written by the match authority after the move lands. The prompt and
+/// response hashes let an audit replay the model call against the stored transcript, and
+/// consumption_id ties the move to the AC consumption that paid for it.
+#[account]
+pub struct AiMoveAttestation {
+    pub match_id: [u8; 36],         // UUID v4 (fixed 36 bytes)
+    pub move_index: u32,            // Move this attests (same index as the Move PDA)
+    pub player: Pubkey,             // Move's player (the seat the AI played)
+    pub model_id: u8,               // Model ID (0-9, ai_model_costs index)
+    pub prompt_hash: [u8; 32],      // SHA-256 of the prompt sent to the model
+    pub response_hash: [u8; 32],    // SHA-256 of the model's raw response
+    pub consumption_id: [u8; 32],   // Off-chain consumption record id hash (all zeros = not billed)
+    pub authority: Pubkey,          // Match authority that attested
+    pub created_at: i64,            // Unix timestamp
+}
+
+impl AiMoveAttestation {
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        36 +                             // match_id ([u8; 36])
+        4 +                              // move_index (u32)
+        32 +                             // player (Pubkey)
+        1 +                              // model_id (u8)
+        32 +                             // prompt_hash ([u8; 32])
+        32 +                             // response_hash ([u8; 32])
+        32 +                             // consumption_id ([u8; 32])
+        32 +                             // authority (Pubkey)
+        8;                               // created_at (i64)
+
+    // Total: 8 + 36 + 4 + 32 + 1 + 32 + 32 + 32 + 32 + 8 = 217 bytes
+
+    pub fn is_billed(&self) -> bool {
+        self.consumption_id != [0u8; 32]
+    }
+}
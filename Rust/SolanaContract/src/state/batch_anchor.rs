@@ -1,4 +1,16 @@
 use anchor_lang::prelude::*;
+use crate::state::fixed_str::fixed_bytes_to_string;
+use crate::error::GameError;
+use std::fmt;
+
+/// Where a batch sits in its authority's chain, as passed to anchor_batch.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct BatchLink {
+    pub previous_batch_root: [u8; 32], // merkle_root of the previous batch (zeros for the first)
+    pub sequence: u64,                 // 0-based position in the chain
+    pub period_start: i64,             // Covered window [period_start, period_end) of match end times
+    pub period_end: i64,
+}
 
 #[account]
 pub struct BatchAnchor {
@@ -9,6 +21,59 @@ pub struct BatchAnchor {
     pub last_match_id: [u8; 36],    // Fixed-size UUID (saves 4 bytes vs String)
     pub timestamp: i64,
     pub authority: Pubkey,
+    pub previous_batch_root: [u8; 32], // merkle_root of the authority's previous batch (zeros = first)
+    pub sequence: u64,               // Position in the authority's chain (0-based)
+    pub period_start: i64,           // Window of matches covered: [period_start, period_end)
+    pub period_end: i64,
+}
+
+/// BatchHead is the tip of one authority's chain of batch anchors (seeds: ["batch_head", authority]).
+/// Each anchor_batch must name the current head root as its previous_batch_root and the next
+/// sequence number, so the anchors form a hash chain and a missing or reordered batch breaks
+/// the linkage. Batch periods must follow each other without overlap and can't end in the
+/// future, so every anchor covers a disjoint, ordered time window.
+#[account]
+pub struct BatchHead {
+    pub authority: Pubkey,
+    pub head_root: [u8; 32],         // merkle_root of the latest batch (zeros = no batch yet)
+    pub batch_count: u64,            // Batches anchored on this chain (= next sequence)
+    pub last_period_end: i64,        // period_end of the latest batch (0 = no batch yet)
+    pub updated_at: i64,
+}
+
+impl BatchHead {
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        32 +                             // authority (Pubkey)
+        32 +                             // head_root ([u8; 32])
+        8 +                              // batch_count (u64)
+        8 +                              // last_period_end (i64)
+        8;                               // updated_at (i64)
+    
+    // Total: 8 + 32 + 32 + 8 + 8 + 8 = 96 bytes
+    
+    /// Links a new batch onto the chain: it must reference the current head, take the next
+    /// sequence number and cover a period after the previous one.
+    pub fn extend(&mut self, link: &BatchLink, merkle_root: [u8; 32], now: i64) -> Result<()> {
+        require!(
+            link.previous_batch_root == self.head_root,
+            GameError::BatchChainMismatch
+        );
+        require!(
+            link.sequence == self.batch_count,
+            GameError::BatchSequenceMismatch
+        );
+        require!(
+            link.period_start >= self.last_period_end
+                && link.period_start < link.period_end
+                && link.period_end <= now,
+            GameError::InvalidBatchPeriod
+        );
+        self.head_root = merkle_root;
+        self.batch_count = self.batch_count.saturating_add(1);
+        self.last_period_end = link.period_end;
+        self.updated_at = now;
+        Ok(())
+    }
 }
 
 impl BatchAnchor {
@@ -19,9 +84,56 @@ impl BatchAnchor {
         36 +                             // first_match_id (fixed [u8; 36])
         36 +                             // last_match_id (fixed [u8; 36])
         8 +                              // timestamp
-        32;                              // authority
+        32 +                             // authority
+        32 +                             // previous_batch_root
+        8 +                              // sequence (u64)
+        8 +                              // period_start (i64)
+        8;                               // period_end (i64)
     
-    // Total: 8 + 50 + 32 + 4 + 36 + 36 + 8 + 32 = 206 bytes
+    // Total: 8 + 50 + 32 + 4 + 36 + 36 + 8 + 32 + 32 + 8 + 8 + 8 = 262 bytes
     // Previous: ~230 bytes (saved ~24 bytes)
+    
+    pub fn batch_id_str(&self) -> String {
+        fixed_bytes_to_string(&self.batch_id)
+    }
+    
+    pub fn first_match_id_str(&self) -> String {
+        fixed_bytes_to_string(&self.first_match_id)
+    }
+    
+    pub fn last_match_id_str(&self) -> String {
+        fixed_bytes_to_string(&self.last_match_id)
+    }
+}
+
+impl fmt::Display for BatchAnchor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "BatchAnchor {} ({} matches, {}..{})",
+            self.batch_id_str(),
+            self.count,
+            self.first_match_id_str(),
+            self.last_match_id_str()
+        )
+    }
+}
+
+impl fmt::Debug for BatchAnchor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BatchAnchor")
+            .field("batch_id", &self.batch_id_str())
+            .field("merkle_root", &self.merkle_root)
+            .field("count", &self.count)
+            .field("first_match_id", &self.first_match_id_str())
+            .field("last_match_id", &self.last_match_id_str())
+            .field("timestamp", &self.timestamp)
+            .field("authority", &self.authority)
+            .field("previous_batch_root", &self.previous_batch_root)
+            .field("sequence", &self.sequence)
+            .field("period_start", &self.period_start)
+            .field("period_end", &self.period_end)
+            .finish()
+    }
 }
 
@@ -1,14 +1,16 @@
 use anchor_lang::prelude::*;
+use crate::state::{Dispute, Match, SanctionKind};
 
 /// ConfigAccount stores economic model parameters.
 /// Per spec Section 20.1.1: Global configuration for token system.
+/// New fields are appended after `last_updated` so an existing account's layout never shifts.
 #[account]
 pub struct ConfigAccount {
     pub authority: Pubkey,                 // Authority that can update config
     
     // AC (AI Credits) pricing
     pub ac_price_usd: [u8; 8],            // Price of AC in USD (f64 as bytes, 0.01 = $0.01 per AC)
-    pub ac_price_lamports: u64,           // Price of 1 AC in lamports (for on-chain reference)
+    pub ac_price_lamports: u64,           // Price of 1 AC in lamports (fallback when no oracle is configured)
     
     // GP (Game Points) configuration
     pub gp_daily_amount: u64,             // Daily GP distribution (e.g., 1000)
@@ -37,6 +39,65 @@ pub struct ConfigAccount {
     // Timestamps
     pub created_at: i64,                  // Account creation timestamp
     pub last_updated: i64,                // Last update timestamp
+    
+    // AI model token quotas (0 = unlimited)
+    pub ai_model_token_quotas: [u32; 10], // Max tokens (thousands) per user per billing period (0 = unlimited)
+    
+    // Move rate limiting
+    pub max_moves_per_slot: u8,           // Max moves per player per slot (0 = unlimited)
+    
+    // Season rewards (distribute_season_rewards)
+    pub season_reward_base_gp: u64,       // GP per reward multiplier unit (see GameLeaderboard::season_reward_multiplier)
+    pub season_reward_top_n: u16,         // Number of top ranks rewarded per season
+    pub season_reward_lamports: u64,      // Treasury lamports per reward multiplier unit (0 = GP only)
+    
+    // Anti-smurf gating
+    pub placement_matches_required: u8,   // Matches a new account plays before appearing on leaderboards
+    pub high_stakes_gp_threshold: u32,    // GP entry cost at or above which a match is high-stakes (0 = disabled)
+    pub min_account_age_high_stakes: i64, // Minimum account age (seconds) to enter high-stakes matches
+    
+    // Cheat strike escalation (0 = threshold disabled)
+    pub strike_warning_threshold: u8,     // Strikes that trigger a warning
+    pub strike_suspension_threshold: u8,  // Strikes that trigger a timed suspension
+    pub strike_ban_threshold: u8,         // Strikes that trigger a permanent ban
+    pub strike_suspension_seconds: i64,   // Suspension length (604800 = 7 days)
+    
+    // GP gifting (gift_gp)
+    pub gift_daily_cap_gp: u64,           // Max GP a user can gift per day (0 = gifting disabled)
+    pub gift_min_account_age: i64,        // Minimum sender account age (seconds)
+    pub gift_fee_bps: u16,                // Treasury fee on gifts (basis points, 100 = 1%)
+    
+    // SOL/USD oracle pricing (ai_credit_purchase)
+    pub sol_usd_price_feed: Pubkey,       // Pyth SOL/USD price account (default = oracle disabled)
+    pub max_price_staleness_seconds: i64, // Max oracle price age (0 = DEFAULT_MAX_PRICE_STALENESS_SECONDS)
+    pub max_price_confidence_bps: u16,    // Max confidence interval as bps of price (0 = unchecked)
+    pub ac_treasury: Pubkey,              // Receives lamports for on-chain AC purchases
+    
+    // Non-turn action cooldowns
+    pub action_cooldown_slots: u64,       // Min slots between a player's repeats of a non-turn action (0 = off)
+    
+    // Showdown
+    pub rebuttal_window_seconds: u32,     // Showdown rebuttal window (0 = Match::DEFAULT_REBUTTAL_WINDOW_SECONDS)
+    
+    // Subscriber free plays (start_game_with_gp)
+    pub free_plays_per_day: [u16; 3],     // GP-free game starts per day by subscription tier (Free, Pro, ProPlus; 0 = none)
+    
+    // Leaderboard decay (decay_leaderboard)
+    pub leaderboard_decay_days: u16,      // Inactivity before a leaderboard entry decays (0 = decay disabled)
+    pub leaderboard_decay_bps: u16,       // Score lost per decay (basis points, 1000 = 10%)
+    
+    // Network load (set_congestion_level)
+    pub congestion_level: u8,             // Coordinator-reported congestion (0 = normal, 1 = elevated, 2 = high)
+    
+    // Dispute filing window
+    pub dispute_window_seconds: u32,      // Disputes accepted until this long after match end (0 = Dispute::DEFAULT_WINDOW_SECONDS)
+    
+    // Open dispute limit
+    pub max_active_disputes: u8,          // Unresolved disputes one user may have open (0 = Dispute::DEFAULT_MAX_ACTIVE)
+    
+    // Subscription AC allowance (accrue_subscription_credits)
+    pub ac_allowance_per_period: [u32; 3], // AC included per billing period by subscription tier (0 = none)
+    pub ac_rollover_cap: [u32; 3],        // Unused allowance carried into the next period by tier (0 = no roll-over)
 }
 
 impl ConfigAccount {
@@ -56,9 +117,61 @@ impl ConfigAccount {
         8 +                                 // current_season_id (u64)
         8 +                                 // season_duration_seconds (i64)
         8 +                                 // created_at (i64)
-        8;                                  // last_updated (i64)
+        8 +                                 // last_updated (i64)
+        (4 * 10) +                         // ai_model_token_quotas ([u32; 10] = 40 bytes)
+        1 +                                 // max_moves_per_slot (u8)
+        8 +                                 // season_reward_base_gp (u64)
+        2 +                                 // season_reward_top_n (u16)
+        8 +                                 // season_reward_lamports (u64)
+        1 +                                 // placement_matches_required (u8)
+        4 +                                 // high_stakes_gp_threshold (u32)
+        8 +                                 // min_account_age_high_stakes (i64)
+        1 +                                 // strike_warning_threshold (u8)
+        1 +                                 // strike_suspension_threshold (u8)
+        1 +                                 // strike_ban_threshold (u8)
+        8 +                                 // strike_suspension_seconds (i64)
+        8 +                                 // gift_daily_cap_gp (u64)
+        8 +                                 // gift_min_account_age (i64)
+        2 +                                 // gift_fee_bps (u16)
+        32 +                                // sol_usd_price_feed (Pubkey)
+        8 +                                 // max_price_staleness_seconds (i64)
+        2 +                                 // max_price_confidence_bps (u16)
+        32 +                                // ac_treasury (Pubkey)
+        8 +                                 // action_cooldown_slots (u64)
+        4 +                                 // rebuttal_window_seconds (u32)
+        (2 * 3) +                          // free_plays_per_day ([u16; 3] = 6 bytes)
+        2 +                                 // leaderboard_decay_days (u16)
+        2 +                                 // leaderboard_decay_bps (u16)
+        1 +                                 // congestion_level (u8)
+        4 +                                 // dispute_window_seconds (u32)
+        1 +                                 // max_active_disputes (u8)
+        (4 * 3) +                          // ac_allowance_per_period ([u32; 3] = 12 bytes)
+        (4 * 3);                           // ac_rollover_cap ([u32; 3] = 12 bytes)
     
-    // Total: 8 + 32 + 8 + 8 + 8 + 4 + 4 + 1 + 8 + 8 + 1 + 4 + 40 + 8 + 8 + 8 + 8 = 174 bytes
+    // Total: 8 + 32 + 8 + 8 + 8 + 4 + 4 + 1 + 8 + 8 + 1 + 4 + 40 + 8 + 8 + 8 + 8 + 40 + 1 + 8 + 2 + 8 + 1 + 4 + 8 + 1 + 1 + 1 + 8 + 8 + 8 + 2 + 32 + 8 + 2 + 32 + 8 + 4 + 6 + 2 + 2 + 1 + 4 + 1 + 12 + 12 = 393 bytes
+    
+    /// Sanction for a user's cheat strike count, or None below every threshold.
+    pub fn sanction_for_strikes(&self, strikes: u8) -> Option<SanctionKind> {
+        let reached = |threshold: u8| threshold > 0 && strikes >= threshold;
+        if reached(self.strike_ban_threshold) {
+            Some(SanctionKind::Ban)
+        } else if reached(self.strike_suspension_threshold) {
+            Some(SanctionKind::Suspension)
+        } else if reached(self.strike_warning_threshold) {
+            Some(SanctionKind::Warning)
+        } else {
+            None
+        }
+    }
+    
+    /// Sanction and duration in seconds (0 = no expiry) the strike schedule gives for `strikes`:
+    /// suspensions last strike_suspension_seconds, warnings are informational and bans permanent.
+    pub fn scheduled_sanction(&self, strikes: u8) -> Option<(SanctionKind, i64)> {
+        self.sanction_for_strikes(strikes).map(|kind| match kind {
+            SanctionKind::Suspension => (kind, self.strike_suspension_seconds),
+            _ => (kind, 0),
+        })
+    }
     
     pub fn get_ac_price_usd(&self) -> f64 {
         // Convert [u8; 8] back to f64
@@ -68,5 +181,74 @@ impl ConfigAccount {
     pub fn set_ac_price_usd(&mut self, price: f64) {
         self.ac_price_usd = price.to_le_bytes();
     }
+    
+    /// Daily free plays granted by a subscription tier (0 for unknown tiers).
+    pub fn free_plays_for_tier(&self, tier: u8) -> u16 {
+        self.free_plays_per_day.get(tier as usize).copied().unwrap_or(0)
+    }
+    
+    /// AC allowance a subscription tier includes per billing period (0 for unknown tiers).
+    pub fn ac_allowance_for_tier(&self, tier: u8) -> u64 {
+        self.ac_allowance_per_period.get(tier as usize).copied().unwrap_or(0) as u64
+    }
+    
+    /// Most unused allowance a tier carries into the next period (0 for unknown tiers).
+    pub fn ac_rollover_cap_for_tier(&self, tier: u8) -> u64 {
+        self.ac_rollover_cap.get(tier as usize).copied().unwrap_or(0) as u64
+    }
+    
+    pub fn rebuttal_window(&self) -> u32 {
+        if self.rebuttal_window_seconds == 0 {
+            Match::DEFAULT_REBUTTAL_WINDOW_SECONDS
+        } else {
+            self.rebuttal_window_seconds
+        }
+    }
+    
+    pub fn dispute_window(&self) -> u32 {
+        if self.dispute_window_seconds == 0 {
+            Dispute::DEFAULT_WINDOW_SECONDS
+        } else {
+            self.dispute_window_seconds
+        }
+    }
+    
+    pub fn max_active_disputes(&self) -> u8 {
+        if self.max_active_disputes == 0 {
+            Dispute::DEFAULT_MAX_ACTIVE
+        } else {
+            self.max_active_disputes
+        }
+    }
+
+    pub const MAX_FEE_BPS: u16 = 10_000; // 100%
+    
+    pub const CONGESTION_HIGH: u8 = 2;
+    pub const CONGESTED_MIN_BATCH_MOVES: usize = 3; // Fewest moves per submit_batch_moves under high congestion
+    
+    /// Fewest moves submit_batch_moves accepts at the current congestion level.
+    pub fn min_batch_moves(&self) -> usize {
+        if self.congestion_level >= Self::CONGESTION_HIGH {
+            Self::CONGESTED_MIN_BATCH_MOVES
+        } else {
+            1
+        }
+    }
+    
+    pub fn has_price_feed(&self) -> bool {
+        self.sol_usd_price_feed != Pubkey::default()
+    }
+    
+    pub const DEFAULT_MAX_PRICE_STALENESS_SECONDS: i64 = 60;
+    
+    /// Oracle price age limit. Unset falls back to the default: a configured feed is never
+    /// read without a staleness check.
+    pub fn max_price_staleness(&self) -> i64 {
+        if self.max_price_staleness_seconds <= 0 {
+            Self::DEFAULT_MAX_PRICE_STALENESS_SECONDS
+        } else {
+            self.max_price_staleness_seconds
+        }
+    }
 }
 
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use crate::error::GameError;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct CoordinatorEntry {
+    pub coordinator: Pubkey,
+    pub health: u8,        // HEALTH_HEALTHY / HEALTH_DEGRADED / HEALTH_DOWN
+    pub added_epoch: u64,  // Set epoch when the coordinator joined
+    pub updated_at: i64,   // Last rotation touching this entry
+}
+
+/// CoordinatorSet lists the coordinator instances allowed to finalize any match (end_match,
+/// anchor_match_record), not only the match's own authority, so a dead instance doesn't strand
+/// its matches. Singleton PDA ["coordinator_set"], maintained by the signer registry authority
+/// via rotate_coordinator. epoch bumps on every rotation so off-chain instances can detect changes.
+#[account]
+pub struct CoordinatorSet {
+    pub epoch: u64,                                // Rotation counter
+    pub coordinators: [CoordinatorEntry; 8],       // Fixed array (first `count` entries used)
+    pub count: u8,                                 // Coordinators in the set (0-8)
+    pub updated_at: i64,                           // Last rotation timestamp
+}
+
+impl CoordinatorSet {
+    pub const MAX_COORDINATORS: usize = 8;
+    
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        8 +                              // epoch (u64)
+        (32 + 1 + 8 + 8) * 8 +           // coordinators (fixed [CoordinatorEntry; 8])
+        1 +                              // count (u8)
+        8;                               // updated_at (i64)
+    
+    // Total: 8 + 8 + 392 + 1 + 8 = 417 bytes
+    
+    pub const HEALTH_HEALTHY: u8 = 0;
+    pub const HEALTH_DEGRADED: u8 = 1; // Still accepted; coordinators should prefer healthy peers
+    pub const HEALTH_DOWN: u8 = 2;     // Kept in the set but no longer accepted
+    
+    pub const ROTATE_ADD: u8 = 0;
+    pub const ROTATE_REMOVE: u8 = 1;
+    pub const ROTATE_MARK_HEALTHY: u8 = 2;
+    pub const ROTATE_MARK_DEGRADED: u8 = 3;
+    pub const ROTATE_MARK_DOWN: u8 = 4;
+    
+    pub fn entries(&self) -> &[CoordinatorEntry] {
+        &self.coordinators[..(self.count as usize).min(Self::MAX_COORDINATORS)]
+    }
+    
+    fn position(&self, coordinator: &Pubkey) -> Option<usize> {
+        self.entries().iter().position(|entry| entry.coordinator == *coordinator)
+    }
+    
+    /// True if the coordinator is in the set and not marked down.
+    pub fn is_active(&self, coordinator: &Pubkey) -> bool {
+        self.position(coordinator)
+            .is_some_and(|index| self.coordinators[index].health != Self::HEALTH_DOWN)
+    }
+    
+    pub fn add(&mut self, coordinator: Pubkey, now: i64) -> Result<()> {
+        require!(self.position(&coordinator).is_none(), GameError::CoordinatorAlreadyInSet);
+        require!((self.count as usize) < Self::MAX_COORDINATORS, GameError::CoordinatorSetFull);
+        self.coordinators[self.count as usize] = CoordinatorEntry {
+            coordinator,
+            health: Self::HEALTH_HEALTHY,
+            added_epoch: self.epoch + 1,
+            updated_at: now,
+        };
+        self.count += 1;
+        Ok(())
+    }
+    
+    /// Removes the coordinator, moving the last entry into its slot.
+    pub fn remove(&mut self, coordinator: &Pubkey) -> Result<()> {
+        let index = self.position(coordinator).ok_or(GameError::CoordinatorNotInSet)?;
+        let last = self.count as usize - 1;
+        self.coordinators[index] = self.coordinators[last];
+        self.coordinators[last] = CoordinatorEntry::default();
+        self.count -= 1;
+        Ok(())
+    }
+    
+    pub fn set_health(&mut self, coordinator: &Pubkey, health: u8, now: i64) -> Result<()> {
+        let index = self.position(coordinator).ok_or(GameError::CoordinatorNotInSet)?;
+        self.coordinators[index].health = health;
+        self.coordinators[index].updated_at = now;
+        Ok(())
+    }
+    
+    /// Applies a ROTATE_* action and bumps the epoch.
+    pub fn rotate(&mut self, coordinator: Pubkey, action: u8, now: i64) -> Result<()> {
+        match action {
+            Self::ROTATE_ADD => self.add(coordinator, now)?,
+            Self::ROTATE_REMOVE => self.remove(&coordinator)?,
+            Self::ROTATE_MARK_HEALTHY => self.set_health(&coordinator, Self::HEALTH_HEALTHY, now)?,
+            Self::ROTATE_MARK_DEGRADED => self.set_health(&coordinator, Self::HEALTH_DEGRADED, now)?,
+            Self::ROTATE_MARK_DOWN => self.set_health(&coordinator, Self::HEALTH_DOWN, now)?,
+            _ => return Err(GameError::InvalidAction.into()),
+        }
+        self.epoch = self.epoch.saturating_add(1);
+        self.updated_at = now;
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::state::fixed_str::fixed_bytes_to_string;
+
+/// CosmeticKind is the equip slot a cosmetic item occupies.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum CosmeticKind {
+    CardBack = 0,
+    TableTheme = 1,
+}
+
+impl CosmeticKind {
+    pub fn from_u8(kind: u8) -> Option<Self> {
+        match kind {
+            0 => Some(CosmeticKind::CardBack),
+            1 => Some(CosmeticKind::TableTheme),
+            _ => None,
+        }
+    }
+}
+
+/// CosmeticItem is a single entry in the cosmetic registry.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct CosmeticItem {
+    pub item_id: u16,                   // Unique item identifier (0 = none/default)
+    pub kind: u8,                       // CosmeticKind
+    pub name: [u8; 20],                 // Display name (fixed 20 bytes, null-padded)
+    pub gp_price: u64,                  // Store price in GP (purchases settle off-chain for now)
+    pub enabled: bool,                  // Can be equipped
+}
+
+impl CosmeticItem {
+    pub const SIZE: usize = 2 +          // item_id (u16)
+        1 +                              // kind (u8)
+        20 +                             // name ([u8; 20])
+        8 +                              // gp_price (u64)
+        1;                               // enabled (bool)
+    
+    // Total: 2 + 1 + 20 + 8 + 1 = 32 bytes per entry
+    
+    pub const EMPTY: CosmeticItem = CosmeticItem {
+        item_id: 0,
+        kind: 0,
+        name: [0u8; 20],
+        gp_price: 0,
+        enabled: false,
+    };
+    
+    pub fn get_name_string(&self) -> String {
+        fixed_bytes_to_string(&self.name)
+    }
+}
+
+/// CosmeticRegistry lists the card backs and table themes that can be equipped.
+/// Single PDA (["cosmetic_registry"]), fixed array like GameRegistry.
+#[account]
+pub struct CosmeticRegistry {
+    pub authority: Pubkey,                   // Authority that can register items
+    pub item_count: u16,                     // Number of registered items (0-64)
+    pub items: [CosmeticItem; 64],           // Fixed array of up to 64 items
+    pub last_updated: i64,                   // Last update timestamp
+}
+
+impl CosmeticRegistry {
+    pub const MAX_ITEMS: usize = 64;
+    
+    pub const MAX_SIZE: usize = 8 +          // discriminator
+        32 +                                 // authority (Pubkey)
+        2 +                                  // item_count (u16)
+        (CosmeticItem::SIZE * 64) +          // items ([CosmeticItem; 64] = 2048 bytes)
+        8;                                   // last_updated (i64)
+    
+    // Total: 8 + 32 + 2 + 2048 + 8 = 2098 bytes
+    
+    /// Finds an item by item_id.
+    pub fn find_item(&self, item_id: u16) -> Option<&CosmeticItem> {
+        self.items[..self.item_count as usize]
+            .iter()
+            .find(|item| item.item_id == item_id)
+    }
+    
+    /// Adds a new item to the registry.
+    pub fn add_item(&mut self, item: CosmeticItem) -> Result<()> {
+        use crate::error::GameError;
+        require!(
+            (self.item_count as usize) < Self::MAX_ITEMS,
+            GameError::InvalidPayload
+        );
+        require!(
+            item.item_id != 0 && self.find_item(item.item_id).is_none(),
+            GameError::InvalidCosmetic
+        );
+        
+        self.items[self.item_count as usize] = item;
+        self.item_count += 1;
+        Ok(())
+    }
+}
+
+/// PlayerCosmetics stores the items a user has equipped, one per CosmeticKind.
+/// Seeds: ["cosmetics", user_id]. Other clients read it to render a player's card backs and table.
+#[account]
+pub struct PlayerCosmetics {
+    pub user_id: [u8; 64],              // Firebase UID (fixed 64 bytes, null-padded)
+    pub equipped: [u16; 4],             // item_id per CosmeticKind slot (0 = default); 2 slots reserved
+    pub updated_at: i64,                // Last equip timestamp
+}
+
+impl PlayerCosmetics {
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        64 +                             // user_id ([u8; 64])
+        8 +                              // equipped ([u16; 4])
+        8;                               // updated_at (i64)
+    
+    // Total: 8 + 64 + 8 + 8 = 88 bytes
+    
+    pub fn get_equipped(&self, kind: CosmeticKind) -> u16 {
+        self.equipped[kind as usize]
+    }
+    
+    pub fn set_equipped(&mut self, kind: CosmeticKind, item_id: u16) {
+        self.equipped[kind as usize] = item_id;
+    }
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum CreditAdjustmentKind {
+    Grant = 0,
+    Refund = 1,
+}
+
+/// CreditAdjustment is an on-chain audit record for support-issued AC changes.
+/// One PDA per adjustment, keyed by the off-chain grant/consumption id so the
+/// same adjustment can never be applied twice: grants at ["credit_adjustment", user_id,
+/// grant_id], refunds at ["credit_refund", user_id, consumption_id].
+#[account]
+pub struct CreditAdjustment {
+    pub user_id: [u8; 64],          // Firebase UID (fixed 64 bytes, null-padded)
+    pub reference_id: [u8; 32],     // Grant id or consumption id (SHA-256 of off-chain record id)
+    pub kind: u8,                   // CreditAdjustmentKind as u8
+    pub ac_amount: u64,             // AC granted or refunded
+    pub reason: u8,                 // Support reason code (off-chain enum)
+    pub authority: Pubkey,          // Support authority that issued the adjustment
+    pub created_at: i64,            // Unix timestamp
+}
+
+impl CreditAdjustment {
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        64 +                             // user_id (fixed [u8; 64])
+        32 +                             // reference_id ([u8; 32])
+        1 +                              // kind (u8)
+        8 +                              // ac_amount (u64)
+        1 +                              // reason (u8)
+        32 +                             // authority (Pubkey)
+        8;                               // created_at (i64)
+    
+    // Total: 8 + 64 + 32 + 1 + 8 + 1 + 32 + 8 = 154 bytes
+    
+    pub fn get_kind(&self) -> CreditAdjustmentKind {
+        match self.kind {
+            1 => CreditAdjustmentKind::Refund,
+            _ => CreditAdjustmentKind::Grant,
+        }
+    }
+}
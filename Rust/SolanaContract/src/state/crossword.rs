@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash;
+use crate::error::GameError;
+use crate::merkle;
+
+/// CrosswordPuzzle holds the answer commitment for a Crosswords match
+/// (seeds: ["crossword", match_id]).
+///
+/// The coordinator commits answer_root at creation, the Merkle root over the clue answers
+/// with leaves SHA-256(clue_index || normalized answer). Answers are normalized to their
+/// letters, uppercased ("Ice-cream" and "ICE CREAM" are both ICECREAM), so players needn't
+/// match the setter's spacing or case. Proofs reveal sibling leaves, which are guessable
+/// offline for short answers; puzzles wanting secrecy should keep the answers long.
+#[account]
+pub struct CrosswordPuzzle {
+    pub match_id: [u8; 36],             // UUID v4 (fixed 36 bytes)
+    pub answer_root: [u8; 32],          // Merkle root of the clue answers
+    pub clue_count: u8,                 // Leaves in the answer list (max MAX_CLUES)
+    pub solved_by: [u8; 128],           // Per clue index: solver's seat + 1, 0 = unsolved
+    pub clues_solved: u8,
+    pub scores: [u16; 10],              // Per seat: letters of the answers solved
+    pub authority: Pubkey,              // Coordinator that committed the answers
+    pub committed_at: i64,
+}
+
+impl CrosswordPuzzle {
+    pub const MAX_CLUES: u8 = 128;
+    pub const MAX_ANSWER_LEN: usize = 64;   // Submitted answer, before normalizing
+
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        36 +                             // match_id ([u8; 36])
+        32 +                             // answer_root ([u8; 32])
+        1 +                              // clue_count (u8)
+        128 +                            // solved_by ([u8; 128])
+        1 +                              // clues_solved (u8)
+        2 * 10 +                         // scores ([u16; 10])
+        32 +                             // authority (Pubkey)
+        8;                               // committed_at (i64)
+
+    // Total: 8 + 36 + 32 + 1 + 128 + 1 + 20 + 32 + 8 = 266 bytes
+
+    /// An answer's letters, uppercased; everything else is dropped.
+    pub fn normalize_answer(answer: &[u8]) -> Vec<u8> {
+        answer
+            .iter()
+            .filter(|b| b.is_ascii_alphabetic())
+            .map(u8::to_ascii_uppercase)
+            .collect()
+    }
+
+    /// Computes the Merkle leaf for a normalized answer at a clue index.
+    pub fn answer_leaf(clue_index: u8, normalized: &[u8]) -> [u8; 32] {
+        hash::hashv(&[&[clue_index], normalized]).to_bytes()
+    }
+
+    /// Verifies that `normalized` is the answer committed for `clue_index`.
+    /// Proof is ordered leaf-to-root; the index bits select left/right at each level.
+    pub fn verify_answer(&self, clue_index: u8, normalized: &[u8], proof: &[[u8; 32]]) -> bool {
+        if clue_index >= self.clue_count {
+            return false;
+        }
+        merkle::verify(Self::answer_leaf(clue_index, normalized), clue_index as usize, proof, &self.answer_root)
+    }
+
+    /// Starts the puzzle from the coordinator's commitment.
+    pub fn commit(&mut self, answer_root: [u8; 32], clue_count: u8) -> Result<()> {
+        require!(
+            (1..=Self::MAX_CLUES).contains(&clue_count),
+            GameError::InvalidPayload
+        );
+        require!(
+            answer_root.iter().any(|&b| b != 0),
+            GameError::EmptyHash
+        );
+        self.answer_root = answer_root;
+        self.clue_count = clue_count;
+        Ok(())
+    }
+
+    /// Credits a correct, unsolved answer to a seat. Returns the points scored
+    /// (one per letter of the normalized answer).
+    pub fn solve(&mut self, seat: usize, clue_index: u8, answer: &[u8], proof: &[[u8; 32]]) -> Result<u16> {
+        require!(seat < self.scores.len(), GameError::PlayerNotInMatch);
+        require!(
+            !answer.is_empty() && answer.len() <= Self::MAX_ANSWER_LEN,
+            GameError::InvalidPayload
+        );
+        let normalized = Self::normalize_answer(answer);
+        require!(
+            !normalized.is_empty() && self.verify_answer(clue_index, &normalized, proof),
+            GameError::WrongAnswer
+        );
+        require!(self.solved_by[clue_index as usize] == 0, GameError::ClueAlreadySolved);
+
+        let points = normalized.len() as u16;
+        self.solved_by[clue_index as usize] = seat as u8 + 1;
+        self.clues_solved += 1;
+        self.scores[seat] = self.scores[seat].saturating_add(points);
+        Ok(points)
+    }
+
+    pub fn all_solved(&self) -> bool {
+        self.clues_solved >= self.clue_count
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash;
+use crate::merkle;
+
+/// DeckCommitment stores the Merkle root of a match's shuffled deck.
+/// Committed by the coordinator during Dealing, derived from the match seed.
+/// Leaves are SHA-256(position || card_hash), where card_hash is SHA-256(suit, value)
+/// (the same card hash used for floor cards), so every dealt card - hands and floor
+/// cards - can be proven to sit at a given deck position during dispute replay.
+#[account]
+pub struct DeckCommitment {
+    pub match_id: [u8; 36],         // UUID v4 (fixed 36 bytes)
+    pub deck_root: [u8; 32],        // Merkle root of the shuffled deck
+    pub deck_size: u8,              // Number of cards in the deck (e.g., 52)
+    pub seed: u64,                  // Match seed the shuffle was derived from
+    pub authority: Pubkey,          // Coordinator that committed the deck
+    pub committed_at: i64,          // Unix timestamp
+}
+
+impl DeckCommitment {
+    /// Deck size assumed when no deck has been committed for the match
+    pub const STANDARD_DECK_SIZE: u8 = 52;
+
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        36 +                             // match_id (fixed [u8; 36])
+        32 +                             // deck_root ([u8; 32])
+        1 +                              // deck_size (u8)
+        8 +                              // seed (u64)
+        32 +                             // authority (Pubkey)
+        8;                               // committed_at (i64)
+    
+    // Total: 8 + 36 + 32 + 1 + 8 + 32 + 8 = 125 bytes
+    
+    /// Computes the Merkle leaf for a card at a deck position.
+    pub fn card_leaf(position: u8, card_hash: &[u8; 32]) -> [u8; 32] {
+        hash::hashv(&[&[position], card_hash]).to_bytes()
+    }
+    
+    /// Verifies that card_hash sits at `position` in the committed deck.
+    /// Proof is ordered leaf-to-root; the position bits select left/right at each level.
+    pub fn verify_card(&self, position: u8, card_hash: &[u8; 32], proof: &[[u8; 32]]) -> bool {
+        if position >= self.deck_size {
+            return false;
+        }
+        merkle::verify(Self::card_leaf(position, card_hash), position as usize, proof, &self.deck_root)
+    }
+}
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 use crate::error::GameError;
+use crate::state::fixed_str::fixed_bytes_to_string;
+use std::fmt;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
 pub enum DisputeReason {
     InvalidMove = 0,
     PlayerTimeout = 1,
@@ -10,7 +12,7 @@ pub enum DisputeReason {
     Other = 4,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
 pub enum DisputeResolution {
     ResolvedInFavorOfFlagger = 0,
     ResolvedInFavorOfDefendant = 1,
@@ -30,6 +32,7 @@ pub struct Dispute {
     pub match_id: [u8; 36],         // Fixed-size UUID (saves 4 bytes vs String)
     pub flagger: Pubkey,
     pub flagger_user_id: [u8; 64],  // Firebase UID of flagger (for GP deposit tracking)
+    pub defendant_user_id: [u8; 64], // Firebase UID of accused player (all zeros = match-level dispute)
     pub reason: u8,                 // DisputeReason as u8
     pub evidence_hash: [u8; 32],
     pub gp_deposit: u32,             // GP deposit amount (deducted off-chain, tracked on-chain)
@@ -39,6 +42,7 @@ pub struct Dispute {
     pub resolution: u8,             // 0 = not resolved, 1-4 = resolution type (saves 1 byte vs Option)
     pub validator_votes: [ValidatorVote; 10], // Fixed array (max 10 validators, saves 4 bytes vs Vec)
     pub vote_count: u8,              // Actual number of votes (0-10)
+    pub assigned_validator: Pubkey,  // Validator picked at flag time; only it is charged a miss (default = none registered)
 }
 
 impl Dispute {
@@ -46,6 +50,7 @@ impl Dispute {
         36 +                             // match_id (fixed [u8; 36])
         32 +                             // flagger (Pubkey)
         64 +                             // flagger_user_id (Firebase UID, fixed [u8; 64])
+        64 +                             // defendant_user_id (Firebase UID, fixed [u8; 64])
         1 +                              // reason (u8)
         32 +                             // evidence_hash
         4 +                              // gp_deposit (u32)
@@ -54,9 +59,29 @@ impl Dispute {
         8 +                              // resolved_at (i64, 0 = not resolved)
         1 +                              // resolution (u8, 0 = not resolved)
         (32 + 1 + 8) * 10 +             // validator_votes (fixed [ValidatorVote; 10])
-        1;                               // vote_count (u8)
+        1 +                              // vote_count (u8)
+        32;                              // assigned_validator (Pubkey)
     
-    // Total: 8 + 36 + 32 + 64 + 1 + 32 + 4 + 1 + 8 + 8 + 1 + 410 + 1 = 606 bytes
+    // Total: 8 + 36 + 32 + 64 + 64 + 1 + 32 + 4 + 1 + 8 + 8 + 1 + 410 + 1 + 32 = 702 bytes
+    
+    pub const DEFAULT_WINDOW_SECONDS: u32 = 604800; // 7 days after match end (ConfigAccount.dispute_window_seconds = 0)
+    pub const DEFAULT_MAX_ACTIVE: u8 = 3; // Unresolved disputes per user (ConfigAccount.max_active_disputes = 0)
+
+    pub fn match_id_str(&self) -> String {
+        fixed_bytes_to_string(&self.match_id)
+    }
+
+    pub fn flagger_user_id_str(&self) -> String {
+        fixed_bytes_to_string(&self.flagger_user_id)
+    }
+
+    /// Accused player's Firebase UID, or None for match-level disputes.
+    pub fn defendant_user_id_str(&self) -> Option<String> {
+        if !self.has_defendant() {
+            return None;
+        }
+        Some(fixed_bytes_to_string(&self.defendant_user_id))
+    }
 
     pub fn is_resolved(&self) -> bool {
         self.resolution != 0 && self.resolved_at != 0
@@ -84,6 +109,16 @@ impl Dispute {
         })
     }
     
+    pub fn has_defendant(&self) -> bool {
+        self.defendant_user_id.iter().any(|&b| b != 0)
+    }
+
+    pub fn has_vote_from(&self, validator: &Pubkey) -> bool {
+        self.validator_votes[..self.vote_count as usize]
+            .iter()
+            .any(|vote| vote.validator == *validator)
+    }
+    
     pub fn add_vote(&mut self, vote: ValidatorVote) -> Result<()> {
         require!(
             self.vote_count < 10,
@@ -95,3 +130,36 @@ impl Dispute {
     }
 }
 
+impl fmt::Display for Dispute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Dispute on {} by {} ({:?}, {})",
+            self.match_id_str(),
+            self.flagger_user_id_str(),
+            self.get_reason(),
+            match self.get_resolution() {
+                Some(resolution) => format!("{:?}", resolution),
+                None => "unresolved".to_string(),
+            }
+        )
+    }
+}
+
+impl fmt::Debug for Dispute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dispute")
+            .field("match_id", &self.match_id_str())
+            .field("flagger", &self.flagger)
+            .field("flagger_user_id", &self.flagger_user_id_str())
+            .field("defendant_user_id", &self.defendant_user_id_str())
+            .field("reason", &self.get_reason())
+            .field("gp_deposit", &self.gp_deposit)
+            .field("gp_refunded", &self.gp_refunded)
+            .field("created_at", &self.created_at)
+            .field("resolved_at", &self.resolved_at)
+            .field("resolution", &self.get_resolution())
+            .field("vote_count", &self.vote_count)
+            .finish()
+    }
+}
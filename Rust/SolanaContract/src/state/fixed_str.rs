@@ -0,0 +1,6 @@
+/// Converts a null-padded fixed-size byte field to a String (lossy UTF-8, trailing NULs trimmed).
+pub fn fixed_bytes_to_string(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes)
+        .trim_end_matches('\0')
+        .to_string()
+}
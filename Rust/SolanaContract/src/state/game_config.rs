@@ -56,6 +56,11 @@ impl GameType {
         }
     }
 
+    /// Whether the game is played in fixed partnerships (team assignment required to start)
+    pub fn requires_teams(&self) -> bool {
+        matches!(self, GameType::Bridge)
+    }
+
     pub fn get_name(&self) -> &'static str {
         match self {
             GameType::Claim => "CLAIM",
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use std::cmp::Ordering;
 
 /// LeaderboardEntry represents a single entry in the leaderboard.
 /// Per spec Section 20.1.6: Per-game-type leaderboards with top 100 entries.
@@ -9,48 +10,110 @@ pub struct LeaderboardEntry {
     pub wins: u32,                        // Wins this season (4 bytes)
     pub games_played: u32,                // Games this season (4 bytes)
     pub timestamp: i64,                    // Last update timestamp (8 bytes)
+    pub decay_loss: u64,                   // Score taken by inactivity decay this season (see decay_leaderboard)
+    pub decayed_day: u32,                  // UTC day of the last decay (0 = never; u32 keeps a shard under 10KB)
 }
 
 impl LeaderboardEntry {
-    pub const SIZE: usize = 64 + 8 + 4 + 4 + 8; // 88 bytes per entry
+    pub const SIZE: usize = 64 + 8 + 4 + 4 + 8 + 8 + 4; // 100 bytes per entry
+    
+    /// Leaderboard order (Less = ranks above `other`): higher score first; on equal scores the
+    /// earlier timestamp wins, then more wins, then the lexicographically smaller user_id.
+    /// Total over distinct users, so every shard has exactly one valid order.
+    pub fn rank_cmp(&self, other: &LeaderboardEntry) -> Ordering {
+        other.score.cmp(&self.score)
+            .then(self.timestamp.cmp(&other.timestamp))
+            .then(other.wins.cmp(&self.wins))
+            .then(self.user_id.cmp(&other.user_id))
+    }
+    
+    pub fn outranks(&self, other: &LeaderboardEntry) -> bool {
+        self.rank_cmp(other) == Ordering::Less
+    }
+    
+    /// Applies the decay already taken from a user's previous entry to a newly submitted score.
+    /// Submitted scores are season totals that never decay, so without this a resubmission
+    /// would restore the points decay_leaderboard took.
+    pub fn carry_decay_from(&mut self, previous: &LeaderboardEntry) {
+        self.score = self.score.saturating_sub(previous.decay_loss);
+        self.decay_loss = previous.decay_loss;
+        self.decayed_day = previous.decayed_day;
+    }
+}
+
+/// Result of inserting an entry into a leaderboard shard.
+#[derive(Clone, PartialEq)]
+pub enum InsertOutcome {
+    NotQualified,                         // Shard is full and the score is below its lowest entry
+    Inserted,                             // Inserted (or updated) without displacing anyone
+    Demoted(LeaderboardEntry),            // Inserted; the shard's lowest entry was pushed out to the next shard
 }
 
-/// GameLeaderboard stores top 100 players per game type per season.
-/// Per spec Section 20.1.6: One leaderboard per game type per season.
+/// GameLeaderboard is one rank shard of a per-game-type, per-season leaderboard.
+/// Per spec Section 20.1.6: Per-game-type leaderboards, sharded into 100-entry rank buckets
+/// (shard 0 = ranks 1-100, shard 1 = ranks 101-200, ...). Every shard except the last is full.
 #[account]
 pub struct GameLeaderboard {
     pub game_type: u8,                    // Game type (0=CLAIM, 1=Poker, 2=WordSearch, etc.)
     pub season_id: u64,                   // Season ID (timestamp / 604800)
+    pub shard_index: u16,                 // Rank bucket (ranks shard_index*100+1 ..= shard_index*100+100)
     pub entry_count: u8,                  // Number of entries (0-100)
-    pub entries: [LeaderboardEntry; 100], // Top 100 entries (fixed array)
+    pub entries: [LeaderboardEntry; 100], // Entries for this rank bucket (fixed array)
     pub last_updated: i64,                // Last update timestamp
 }
 
 impl GameLeaderboard {
+    pub const MAX_ENTRIES: usize = 100;
+    
     pub const MAX_SIZE: usize = 8 +        // discriminator
         1 +                                 // game_type (u8)
         8 +                                 // season_id (u64)
+        2 +                                 // shard_index (u16)
         1 +                                 // entry_count (u8)
-        (LeaderboardEntry::SIZE * 100) +   // entries ([LeaderboardEntry; 100] = 8800 bytes)
+        (LeaderboardEntry::SIZE * 100) +   // entries ([LeaderboardEntry; 100] = 10000 bytes)
         8;                                  // last_updated (i64)
     
-    // Total: 8 + 1 + 8 + 1 + 8800 + 8 = 8826 bytes (within 10KB limit)
+    // Total: 8 + 1 + 8 + 2 + 1 + 10000 + 8 = 10028 bytes (within 10KB limit)
+    
+    // Byte offset of entries[0] in account data (discriminator through entry_count)
+    const ENTRIES_OFFSET: usize = 8 + 1 + 8 + 2 + 1;
     
-    /// Find the insertion point for a new score using binary search.
-    /// Returns the index where the entry should be inserted to maintain descending order.
-    pub fn find_insertion_point(&self, score: u64) -> usize {
+    /// Reads entry `i` straight from shard account data, without loading the whole shard.
+    pub fn entry_in(data: &[u8], i: usize) -> Option<LeaderboardEntry> {
+        let count = *data.get(Self::ENTRIES_OFFSET - 1)? as usize;
+        if i >= count {
+            return None;
+        }
+        let start = Self::ENTRIES_OFFSET + i * LeaderboardEntry::SIZE;
+        LeaderboardEntry::deserialize(&mut data.get(start..start + LeaderboardEntry::SIZE)?).ok()
+    }
+    
+    /// Finds a user's entry in shard account data (see entry_in).
+    pub fn find_user_in(data: &[u8], user_id: &[u8; 64]) -> Option<LeaderboardEntry> {
+        let count = *data.get(Self::ENTRIES_OFFSET - 1)? as usize;
+        (0..count)
+            .find(|&i| {
+                let start = Self::ENTRIES_OFFSET + i * LeaderboardEntry::SIZE;
+                data.get(start..start + 64) == Some(&user_id[..])
+            })
+            .and_then(|i| Self::entry_in(data, i))
+    }
+    
+    /// Find the insertion point for a new entry using binary search.
+    /// Returns the index where the entry should be inserted to maintain rank order.
+    pub fn find_insertion_point(&self, entry: &LeaderboardEntry) -> usize {
         let count = self.entry_count as usize;
         if count == 0 {
             return 0;
         }
         
-        // Binary search for insertion point (descending order: highest score first)
+        // Binary search for insertion point (rank order, see LeaderboardEntry::rank_cmp)
         let mut left = 0;
         let mut right = count;
         
         while left < right {
             let mid = (left + right) / 2;
-            if self.entries[mid].score > score {
+            if self.entries[mid].outranks(entry) {
                 left = mid + 1;
             } else {
                 right = mid;
@@ -60,78 +123,242 @@ impl GameLeaderboard {
         left
     }
     
-    /// Insert or update an entry in the leaderboard.
-    /// Returns true if the entry was inserted/updated, false if it doesn't qualify.
-    pub fn insert_entry(&mut self, entry: LeaderboardEntry) -> bool {
-        let score = entry.score;
-        let user_id = entry.user_id;
-        
-        // Check if score qualifies (beats rank 100 OR entry_count < 100)
-        let qualifies = (self.entry_count as usize) < 100 || 
-                       (self.entry_count > 0 && score > self.entries[(self.entry_count - 1) as usize].score);
-        
-        if !qualifies {
+    pub fn find_user(&self, user_id: &[u8; 64]) -> Option<usize> {
+        self.entries[..self.entry_count as usize]
+            .iter()
+            .position(|e| e.user_id == *user_id)
+    }
+    
+    /// Replaces a user's id in place (erasure), keeping their rank. Returns true if present.
+    pub fn rename_user(&mut self, user_id: &[u8; 64], new_user_id: [u8; 64]) -> bool {
+        let Some(idx) = self.find_user(user_id) else {
+            return false;
+        };
+        self.entries[idx].user_id = new_user_id;
+        true
+    }
+    
+    /// Remove a user's entry, shifting lower entries up. Returns true if the user was present.
+    pub fn remove_entry(&mut self, user_id: &[u8; 64]) -> bool {
+        let Some(idx) = self.find_user(user_id) else {
             return false;
+        };
+        let count = self.entry_count as usize;
+        for i in idx..count - 1 {
+            self.entries[i] = self.entries[i + 1].clone();
+        }
+        self.entry_count -= 1;
+        true
+    }
+    
+    pub fn min_score(&self) -> u64 {
+        match self.entry_count {
+            0 => 0,
+            count => self.entries[(count - 1) as usize].score,
+        }
+    }
+    
+    pub fn max_score(&self) -> u64 {
+        match self.entry_count {
+            0 => 0,
+            _ => self.entries[0].score,
+        }
+    }
+    
+    /// Insert or update an entry in this shard.
+    /// When the shard is full, the lowest entry is demoted and returned so the caller can
+    /// push it into the next shard (a promotion into this shard is a demotion out of it).
+    /// Re-submitting an unchanged score keeps the entry's earlier timestamp, so it doesn't lose ties.
+    pub fn insert_entry(&mut self, mut entry: LeaderboardEntry) -> InsertOutcome {
+        // Check if entry qualifies (user already here, room left, or ranks above the lowest entry)
+        let full = self.entry_count as usize >= Self::MAX_ENTRIES;
+        let existing = self.find_user(&entry.user_id);
+        if existing.is_none() && full && !entry.outranks(&self.entries[Self::MAX_ENTRIES - 1]) {
+            return InsertOutcome::NotQualified;
         }
         
         // Remove user's old entry if exists
-        let mut old_index = None;
-        for (i, e) in self.entries.iter().enumerate() {
-            if i >= self.entry_count as usize {
-                break;
-            }
-            if e.user_id == user_id {
-                old_index = Some(i);
-                break;
+        if let Some(i) = existing {
+            let old = &self.entries[i];
+            if old.score == entry.score {
+                entry.timestamp = entry.timestamp.min(old.timestamp);
             }
+            self.remove_entry(&entry.user_id);
         }
         
-        if let Some(idx) = old_index {
-            // Remove old entry, shift down
-            for i in idx..((self.entry_count as usize).saturating_sub(1)) {
-                if i + 1 < 100 {
-                    self.entries[i] = self.entries[i + 1].clone();
-                }
-            }
-            if self.entry_count > 0 {
-                self.entry_count -= 1;
-            }
+        // Make room by demoting the lowest entry
+        let mut demoted = None;
+        if self.entry_count as usize >= Self::MAX_ENTRIES {
+            demoted = Some(self.entries[Self::MAX_ENTRIES - 1].clone());
+            self.entry_count -= 1;
         }
         
         // Find insertion point
-        let insert_pos = self.find_insertion_point(score);
+        let insert_pos = self.find_insertion_point(&entry);
         
         // Shift entries down to make room
         let count = self.entry_count as usize;
         for i in (insert_pos..count).rev() {
-            if i < 99 {
-                self.entries[i + 1] = self.entries[i].clone();
-            }
+            self.entries[i + 1] = self.entries[i].clone();
         }
         
         // Insert new entry
-        if insert_pos < 100 {
-            self.entries[insert_pos] = entry;
-            if (self.entry_count as usize) < 100 {
-                self.entry_count += 1;
+        self.entries[insert_pos] = entry;
+        self.entry_count += 1;
+        
+        match demoted {
+            Some(entry) => InsertOutcome::Demoted(entry),
+            None => InsertOutcome::Inserted,
+        }
+    }
+    
+    /// Inactivity decay: every entry not updated for `stale_after_seconds` loses `decay_bps`
+    /// of its score, at most once per `stale_after_seconds` (counted from the UTC day of the
+    /// last decay) per entry, and the shard is re-sorted. The loss is added to decay_loss.
+    /// Returns the number of entries decayed.
+    pub fn decay_stale_entries(&mut self, now: i64, stale_after_seconds: i64, decay_bps: u16) -> u8 {
+        let count = self.entry_count as usize;
+        let mut decayed = 0;
+        for entry in self.entries[..count].iter_mut() {
+            if now.saturating_sub(entry.timestamp) >= stale_after_seconds
+                && now.saturating_sub(entry.decayed_day as i64 * 86400) >= stale_after_seconds
+            {
+                let loss = (entry.score as u128 * decay_bps.min(10_000) as u128 / 10_000) as u64;
+                entry.score -= loss;
+                entry.decay_loss = entry.decay_loss.saturating_add(loss);
+                entry.decayed_day = (now / 86400) as u32;
+                decayed += 1;
+            }
+        }
+        if decayed > 0 {
+            self.sort_entries();
+        }
+        decayed
+    }
+    
+    /// Restores rank order across the boundary with the next shard: while `lower`'s highest
+    /// entry outranks this shard's lowest, the two entries trade places.
+    /// Returns the number of entries exchanged.
+    pub fn rebalance_with(&mut self, lower: &mut GameLeaderboard) -> u8 {
+        let mut exchanged = 0;
+        while self.entry_count > 0
+            && lower.entry_count > 0
+            && lower.entries[0].outranks(&self.entries[self.entry_count as usize - 1])
+        {
+            let last = self.entry_count as usize - 1;
+            std::mem::swap(&mut self.entries[last], &mut lower.entries[0]);
+            self.sort_entries();
+            lower.sort_entries();
+            exchanged += 1;
+        }
+        exchanged
+    }
+    
+    // Insertion sort into rank order (entries are nearly sorted; no allocation)
+    fn sort_entries(&mut self) {
+        let count = self.entry_count as usize;
+        for i in 1..count {
+            let mut j = i;
+            while j > 0 && self.entries[j].outranks(&self.entries[j - 1]) {
+                self.entries.swap(j - 1, j);
+                j -= 1;
             }
         }
-        
-        true
     }
     
-    /// Get the rank of a user in the leaderboard.
+    /// Get the rank of a user within this shard.
     /// Returns 0 if not found, 1-100 if found.
     pub fn get_user_rank(&self, user_id: &[u8; 64]) -> u16 {
-        for (i, entry) in self.entries.iter().enumerate() {
-            if i >= self.entry_count as usize {
-                break;
-            }
-            if entry.user_id == *user_id {
-                return (i + 1) as u16;
-            }
+        match self.find_user(user_id) {
+            Some(i) => (i + 1) as u16,
+            None => 0,
+        }
+    }
+    
+    /// Season reward multiplier by season-wide rank (reward = multiplier * base reward).
+    pub fn season_reward_multiplier(global_rank: u32) -> u64 {
+        match global_rank {
+            0 => 0,                 // Not ranked
+            1 => 50,                // Champion
+            2 => 30,
+            3 => 20,
+            4..=10 => 10,           // Top 10
+            11..=25 => 5,           // Top 25
+            26..=50 => 3,           // Top 50
+            51..=100 => 2,          // Top 100
+            _ => 1,                 // Remaining rewarded ranks
+        }
+    }
+    
+    /// Get the season-wide rank of a user (shard offset + rank within shard).
+    /// Returns 0 if not found.
+    pub fn get_global_rank(&self, user_id: &[u8; 64]) -> u32 {
+        match self.get_user_rank(user_id) {
+            0 => 0,
+            rank => self.shard_index as u32 * Self::MAX_ENTRIES as u32 + rank as u32,
         }
-        0
     }
 }
 
+/// LeaderboardIndex maps score ranges to rank shards for one game type and season.
+/// Clients use it to find which GameLeaderboard shard a score (or rank) lives in.
+#[account]
+pub struct LeaderboardIndex {
+    pub game_type: u8,                    // Game type
+    pub season_id: u64,                   // Season ID
+    pub shard_count: u16,                 // Number of shards created (0-32)
+    pub shard_max_scores: [u64; 32],      // Highest score per shard
+    pub shard_min_scores: [u64; 32],      // Lowest score per shard
+    pub shard_entry_counts: [u8; 32],     // Entries per shard (0-100)
+    pub rewards_finalized: bool,          // Season closed for submissions; rewards being distributed
+    pub rewards_paid_count: u32,          // Ranks 1..=rewards_paid_count have been paid (idempotent cursor)
+    pub last_updated: i64,                // Last update timestamp
+}
+
+impl LeaderboardIndex {
+    pub const MAX_SHARDS: u16 = 32; // 3,200 ranked players per game type per season
+    
+    pub const MAX_SIZE: usize = 8 +        // discriminator
+        1 +                                 // game_type (u8)
+        8 +                                 // season_id (u64)
+        2 +                                 // shard_count (u16)
+        (8 * 32) +                          // shard_max_scores ([u64; 32])
+        (8 * 32) +                          // shard_min_scores ([u64; 32])
+        32 +                                // shard_entry_counts ([u8; 32])
+        1 +                                 // rewards_finalized (bool)
+        4 +                                 // rewards_paid_count (u32)
+        8;                                  // last_updated (i64)
+    
+    // Total: 8 + 1 + 8 + 2 + 256 + 256 + 32 + 1 + 4 + 8 = 576 bytes
+    
+    /// The shard a score should be inserted into: the first shard with room or whose
+    /// lowest score it beats or ties (a lost tie-break moves on to the next shard).
+    /// Returns shard_count when a new shard is needed.
+    pub fn target_shard(&self, score: u64) -> u16 {
+        (0..self.shard_count)
+            .find(|&i| {
+                let i = i as usize;
+                (self.shard_entry_counts[i] as usize) < GameLeaderboard::MAX_ENTRIES
+                    || score >= self.shard_min_scores[i]
+            })
+            .unwrap_or(self.shard_count)
+    }
+    
+    /// Record a shard's current score range after it changes.
+    pub fn refresh_shard(&mut self, shard: &GameLeaderboard) {
+        let i = shard.shard_index as usize;
+        if i >= Self::MAX_SHARDS as usize {
+            return;
+        }
+        self.shard_max_scores[i] = shard.max_score();
+        self.shard_min_scores[i] = shard.min_score();
+        self.shard_entry_counts[i] = shard.entry_count;
+    }
+    
+    pub fn total_entries(&self) -> u32 {
+        self.shard_entry_counts[..self.shard_count as usize]
+            .iter()
+            .map(|&c| c as u32)
+            .sum()
+    }
+}
@@ -1,4 +1,119 @@
 use anchor_lang::prelude::*;
+use crate::state::fixed_str::fixed_bytes_to_string;
+
+/// PayloadField tags describe a move payload's layout, field by field.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum PayloadField {
+    End = 0,          // No more fields
+    U8 = 1,
+    U16 = 2,
+    U32 = 3,
+    U64 = 4,
+    Hash32 = 5,       // 32-byte hash (card hash, commitment)
+    Suit = 6,         // u8, 0-3
+    CardIndex = 7,    // u8, 0-51
+    Bool = 8,         // u8, 0-1
+    Bytes = 9,        // Variable-length remainder (must be last)
+}
+
+impl PayloadField {
+    pub fn from_u8(tag: u8) -> Option<Self> {
+        match tag {
+            0 => Some(PayloadField::End),
+            1 => Some(PayloadField::U8),
+            2 => Some(PayloadField::U16),
+            3 => Some(PayloadField::U32),
+            4 => Some(PayloadField::U64),
+            5 => Some(PayloadField::Hash32),
+            6 => Some(PayloadField::Suit),
+            7 => Some(PayloadField::CardIndex),
+            8 => Some(PayloadField::Bool),
+            9 => Some(PayloadField::Bytes),
+            _ => None,
+        }
+    }
+
+    /// Fixed byte size of the field (0 for End and variable-length Bytes).
+    pub fn size(&self) -> usize {
+        match self {
+            PayloadField::End | PayloadField::Bytes => 0,
+            PayloadField::U8 | PayloadField::Suit | PayloadField::CardIndex | PayloadField::Bool => 1,
+            PayloadField::U16 => 2,
+            PayloadField::U32 => 4,
+            PayloadField::U64 => 8,
+            PayloadField::Hash32 => 32,
+        }
+    }
+}
+
+/// PayloadSchema describes the payload accepted for one action type of a game.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct PayloadSchema {
+    pub defined: bool,                  // false = no schema registered (game-specific checks only)
+    pub min_len: u8,                    // Minimum payload length
+    pub max_len: u8,                    // Maximum payload length (<= Move payload cap)
+    pub fields: [u8; 8],                // PayloadField tags in order (0 = end)
+}
+
+impl PayloadSchema {
+    pub const SIZE: usize = 1 + 1 + 1 + 8; // 11 bytes
+    pub const MAX_ACTIONS: usize = 16;     // Schemas per game: every action_type submit_move accepts (0-15)
+    
+    pub const UNDEFINED: PayloadSchema = PayloadSchema {
+        defined: false,
+        min_len: 0,
+        max_len: 0,
+        fields: [0u8; 8],
+    };
+}
+
+/// RunRules is the decoded form of GameDefinition.rules_variant, the house rules for
+/// what counts as a run in showdown claims and rebuttals.
+/// Bit 0: wraparound allowed (the top rank connects to the bottom one, e.g. K-A-2)
+/// Bit 1: aces low (Ace ranks 1, below 2) instead of high (Ace ranks 14, above King)
+/// Bits 4-7: run length in cards (0 = DEFAULT_RUN_LENGTH)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RunRules {
+    pub wraparound: bool,
+    pub aces_low: bool,
+    pub run_length: u8,
+}
+
+impl RunRules {
+    pub const WRAPAROUND: u8 = 1 << 0;
+    pub const ACES_LOW: u8 = 1 << 1;
+    pub const RUN_LENGTH_SHIFT: u8 = 4;
+    pub const DEFAULT_RUN_LENGTH: u8 = 3;
+    pub const MIN_RUN_LENGTH: u8 = 3;
+    pub const MAX_RUN_LENGTH: u8 = 13; // A whole suit
+    /// CLAIM's rules: aces high, wraparound allowed, 3-card runs
+    pub const CLASSIC: u8 = Self::WRAPAROUND;
+
+    pub fn from_bits(bits: u8) -> Self {
+        let run_length = bits >> Self::RUN_LENGTH_SHIFT;
+        RunRules {
+            wraparound: bits & Self::WRAPAROUND != 0,
+            aces_low: bits & Self::ACES_LOW != 0,
+            run_length: if run_length == 0 { Self::DEFAULT_RUN_LENGTH } else { run_length },
+        }
+    }
+
+    /// A variant is valid when no unknown bits are set and the run length is in range.
+    pub fn is_valid_bits(bits: u8) -> bool {
+        let run_length = bits >> Self::RUN_LENGTH_SHIFT;
+        bits & 0x0C == 0 &&
+            (run_length == 0 || (Self::MIN_RUN_LENGTH..=Self::MAX_RUN_LENGTH).contains(&run_length))
+    }
+
+    /// Rank of a card value (2-14, Ace = 14) under these rules, None if not a card value.
+    pub fn rank(&self, value: u8) -> Option<u8> {
+        match value {
+            14 if self.aces_low => Some(1),
+            2..=14 => Some(value),
+            _ => None,
+        }
+    }
+}
 
 /// GameDefinition represents a single game in the registry.
 /// Per spec Section 16.5: Game registry system.
@@ -12,6 +127,13 @@ pub struct GameDefinition {
     pub rule_engine_url: [u8; 200],      // Off-chain rule engine endpoint (fixed 200 bytes, null-padded)
     pub version: u8,                     // Game version (for updates)
     pub enabled: bool,                   // Is game enabled?
+    pub payload_schemas: [PayloadSchema; 16], // Move payload schema per action_type (0-15)
+    pub gp_cost: u32,                    // GP cost to start a game (0 = use ConfigAccount.gp_cost_per_game)
+    pub wager_allowed: bool,             // Whether wagered matches may be played for this game
+    pub rules_variant: u8,               // RunRules bitfield (set via set_game_rules)
+    pub daily_match_cap: u32,            // Max matches created per UTC day (0 = uncapped, set via set_game_match_cap)
+    pub has_betting: bool,               // Betting actions enabled over the match's PotLedger (set via set_game_betting)
+    pub rake_bps: u16,                   // Rake taken from each settled pot, in basis points
 }
 
 impl GameDefinition {
@@ -21,26 +143,48 @@ impl GameDefinition {
         1 +                                // max_players (u8)
         200 +                              // rule_engine_url ([u8; 200])
         1 +                                // version (u8)
-        1;                                 // enabled (bool)
+        1 +                                // enabled (bool)
+        (PayloadSchema::SIZE * 16) +       // payload_schemas ([PayloadSchema; 16] = 176 bytes)
+        4 +                                // gp_cost (u32)
+        1 +                                // wager_allowed (bool)
+        1 +                                // rules_variant (u8 bitfield)
+        4 +                                // daily_match_cap (u32)
+        1 +                                // has_betting (bool)
+        2;                                 // rake_bps (u16)
     
-    // Total: 1 + 20 + 1 + 1 + 200 + 1 + 1 = 225 bytes per entry
+    // Total: 1 + 20 + 1 + 1 + 200 + 1 + 1 + 176 + 4 + 1 + 1 + 4 + 1 + 2 = 414 bytes per entry
     
     pub fn get_name_string(&self) -> String {
-        String::from_utf8_lossy(&self.name)
-            .trim_end_matches('\0')
-            .to_string()
+        fixed_bytes_to_string(&self.name)
+    }
+    
+    pub fn get_payload_schema(&self, action_type: u8) -> Option<&PayloadSchema> {
+        self.payload_schemas
+            .get(action_type as usize)
+            .filter(|schema| schema.defined)
+    }
+    
+    pub fn run_rules(&self) -> RunRules {
+        RunRules::from_bits(self.rules_variant)
     }
     
     pub fn get_rule_engine_url_string(&self) -> String {
-        String::from_utf8_lossy(&self.rule_engine_url)
-            .trim_end_matches('\0')
-            .to_string()
+        fixed_bytes_to_string(&self.rule_engine_url)
+    }
+    
+    /// GP cost to start this game, falling back to the global config cost when not overridden.
+    pub fn effective_gp_cost(&self, config_gp_cost: u32) -> u32 {
+        if self.gp_cost > 0 {
+            self.gp_cost
+        } else {
+            config_gp_cost
+        }
     }
 }
 
 /// GameRegistry stores all registered games.
 /// Per spec Section 16.5: On-chain game registry.
-/// Uses fixed-size array for optimization (max 20 games = 4500 bytes).
+/// Uses fixed-size array for optimization (max 20 games = 8280 bytes).
 #[account]
 pub struct GameRegistry {
     pub authority: Pubkey,                // Authority that can register/update games
@@ -53,10 +197,10 @@ impl GameRegistry {
     pub const MAX_SIZE: usize = 8 +        // discriminator
         32 +                                // authority (Pubkey)
         1 +                                 // game_count (u8)
-        (GameDefinition::SIZE * 20) +      // games ([GameDefinition; 20] = 4500 bytes)
+        (GameDefinition::SIZE * 20) +      // games ([GameDefinition; 20] = 8280 bytes)
         8;                                  // last_updated (i64)
     
-    // Total: 8 + 32 + 1 + 4500 + 8 = 4549 bytes (within 10KB limit)
+    // Total: 8 + 32 + 1 + 8280 + 8 = 8329 bytes (within 10KB limit)
     
     /// Finds a game by game_id.
     pub fn find_game(&self, game_id: u8) -> Option<&GameDefinition> {
@@ -68,6 +212,43 @@ impl GameRegistry {
         None
     }
     
+    /// GP cost to start a match of `game_type`: the game's override if registered and set,
+    /// otherwise the global config cost.
+    pub fn gp_cost_for(&self, game_type: u8, config_gp_cost: u32) -> u32 {
+        self.find_game(game_type)
+            .map(|game| game.effective_gp_cost(config_gp_cost))
+            .unwrap_or(config_gp_cost)
+    }
+    
+    /// Run rules for `game_type`: the registered variant, or CLAIM's classic rules
+    /// for games without a registry entry.
+    pub fn run_rules_for(&self, game_type: u8) -> RunRules {
+        self.find_game(game_type)
+            .map(|game| game.run_rules())
+            .unwrap_or(RunRules::from_bits(RunRules::CLASSIC))
+    }
+    
+    /// Daily match cap for `game_type` (0 = uncapped, including unregistered games).
+    pub fn daily_match_cap_for(&self, game_type: u8) -> u32 {
+        self.find_game(game_type)
+            .map(|game| game.daily_match_cap)
+            .unwrap_or(0)
+    }
+    
+    /// Whether `game_type` takes betting actions (false for unregistered games).
+    pub fn betting_enabled(&self, game_type: u8) -> bool {
+        self.find_game(game_type)
+            .map(|game| game.has_betting)
+            .unwrap_or(false)
+    }
+    
+    /// Rake on settled pots for `game_type`, in basis points (0 for unregistered games).
+    pub fn rake_bps_for(&self, game_type: u8) -> u16 {
+        self.find_game(game_type)
+            .map(|game| game.rake_bps)
+            .unwrap_or(0)
+    }
+    
     /// Finds a game by game_id (mutable).
     pub fn find_game_mut(&mut self, game_id: u8) -> Option<&mut GameDefinition> {
         for i in 0..self.game_count as usize {
@@ -83,13 +264,13 @@ impl GameRegistry {
         use crate::error::GameError;
         require!(
             (self.game_count as usize) < 20,
-            GameError::InvalidPayload
+            GameError::GameRegistryFull
         );
         
         // Check if game_id already exists
         require!(
             self.find_game(game.game_id).is_none(),
-            GameError::InvalidPayload
+            GameError::GameAlreadyRegistered
         );
         
         self.games[self.game_count as usize] = game;
@@ -101,7 +282,7 @@ impl GameRegistry {
     pub fn update_game(&mut self, game_id: u8, updated_game: GameDefinition) -> Result<()> {
         use crate::error::GameError;
         let game = self.find_game_mut(game_id)
-            .ok_or(GameError::InvalidPayload)?;
+            .ok_or(GameError::GameNotRegistered)?;
         
         // Ensure game_id doesn't change
         require!(
@@ -117,7 +298,7 @@ impl GameRegistry {
     pub fn disable_game(&mut self, game_id: u8) -> Result<()> {
         use crate::error::GameError;
         let game = self.find_game_mut(game_id)
-            .ok_or(GameError::InvalidPayload)?;
+            .ok_or(GameError::GameNotRegistered)?;
         
         game.enabled = false;
         Ok(())
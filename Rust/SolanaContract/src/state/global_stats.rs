@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::error::GameError;
+
+/// GlobalStats holds protocol-wide counters for dashboards (one account read instead of
+/// indexing transaction history). Singleton PDA ["global_stats"].
+/// Moves are added when a match ends (from Match.move_count), so submit_move doesn't
+/// write-lock a global account and matches keep executing in parallel.
+#[account]
+pub struct GlobalStats {
+    pub total_matches_created: u64,       // Matches created (create_match, batches, queue)
+    pub total_matches_ended: u64,         // Matches finalized by end_match (each counted once)
+    pub total_moves: u64,                 // Moves in ended matches
+    pub total_disputes: u64,              // Disputes filed
+    pub total_gp_issued: u64,             // GP issued (daily login, ads, promos, season rewards)
+    pub matches_created_by_game: [u64; 20], // Per game_type (< MAX_GAME_TYPES) matches created
+    pub matches_ended_by_game: [u64; 20],   // Per game_type matches ended
+    pub moves_by_game: [u64; 20],           // Per game_type moves in ended matches
+    pub stats_day: u64,                   // Day index (timestamp / 86400) of matches_today_by_game
+    pub matches_today_by_game: [u32; 20],   // Per game_type matches created on stats_day (daily caps)
+    pub created_at: i64,                  // Account creation timestamp
+    pub last_updated: i64,                // Last update timestamp
+}
+
+impl GlobalStats {
+    /// Per-game counters cover game_type 0..20 (GameRegistry holds 20 games); others only count in totals.
+    pub const MAX_GAME_TYPES: usize = 20;
+    
+    pub const MAX_SIZE: usize = 8 +        // discriminator
+        8 +                                 // total_matches_created (u64)
+        8 +                                 // total_matches_ended (u64)
+        8 +                                 // total_moves (u64)
+        8 +                                 // total_disputes (u64)
+        8 +                                 // total_gp_issued (u64)
+        (8 * 20) +                          // matches_created_by_game ([u64; 20] = 160 bytes)
+        (8 * 20) +                          // matches_ended_by_game ([u64; 20] = 160 bytes)
+        (8 * 20) +                          // moves_by_game ([u64; 20] = 160 bytes)
+        8 +                                 // stats_day (u64)
+        (4 * 20) +                          // matches_today_by_game ([u32; 20] = 80 bytes)
+        8 +                                 // created_at (i64)
+        8;                                  // last_updated (i64)
+    
+    // Total: 8 + 8 + 8 + 8 + 8 + 8 + 160 + 160 + 160 + 8 + 80 + 8 + 8 = 632 bytes
+    
+    /// Matches of `game_type` created today (counters roll over at UTC midnight).
+    pub fn matches_today(&self, game_type: u8, now: i64) -> u32 {
+        if self.stats_day != (now / 86400) as u64 {
+            return 0;
+        }
+        self.matches_today_by_game.get(game_type as usize).copied().unwrap_or(0)
+    }
+    
+    /// Counts a new match, rejecting it once the game's daily cap (0 = uncapped) is reached.
+    pub fn record_match_created(&mut self, game_type: u8, daily_cap: u32, now: i64) -> Result<()> {
+        let today = self.matches_today(game_type, now);
+        require!(
+            daily_cap == 0 || today < daily_cap,
+            GameError::DailyMatchCapReached
+        );
+        
+        let day = (now / 86400) as u64;
+        if self.stats_day != day {
+            self.stats_day = day;
+            self.matches_today_by_game = [0; 20];
+        }
+        self.total_matches_created = self.total_matches_created.saturating_add(1);
+        let game = game_type as usize;
+        if game < Self::MAX_GAME_TYPES {
+            self.matches_created_by_game[game] = self.matches_created_by_game[game].saturating_add(1);
+            self.matches_today_by_game[game] = today.saturating_add(1);
+        }
+        self.last_updated = now;
+        Ok(())
+    }
+    
+    pub fn record_match_ended(&mut self, game_type: u8, move_count: u64, now: i64) {
+        self.total_matches_ended = self.total_matches_ended.saturating_add(1);
+        self.total_moves = self.total_moves.saturating_add(move_count);
+        let game = game_type as usize;
+        if game < Self::MAX_GAME_TYPES {
+            self.matches_ended_by_game[game] = self.matches_ended_by_game[game].saturating_add(1);
+            self.moves_by_game[game] = self.moves_by_game[game].saturating_add(move_count);
+        }
+        self.last_updated = now;
+    }
+    
+    pub fn record_dispute(&mut self, now: i64) {
+        self.total_disputes = self.total_disputes.saturating_add(1);
+        self.last_updated = now;
+    }
+    
+    pub fn record_gp_issued(&mut self, amount: u64, now: i64) {
+        self.total_gp_issued = self.total_gp_issued.saturating_add(amount);
+        self.last_updated = now;
+    }
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::state::fixed_str::fixed_bytes_to_string;
+
+/// Guild is a player clan with a capped member list and a season score.
+/// Seeds: ["guild", name]. The guild score is the sum of what each member earned this season
+/// while in the guild (season score minus the score they joined with), plus the banked
+/// contributions of members who left. It never decreases within a season, so guilds can be
+/// ranked with the sharded GameLeaderboard (game_type = GUILD_LEADERBOARD_TYPE).
+#[account]
+pub struct Guild {
+    pub name: [u8; 32],                 // Guild name (null-padded); also the leaderboard entry id
+    pub owner: [u8; 64],                // Owner's Firebase UID (can't leave or be kicked)
+    pub member_count: u8,               // Number of members (1-16)
+    pub members: [[u8; 64]; 16],        // Member Firebase UIDs (first member_count are valid)
+    pub member_baselines: [u64; 16],    // Member's season score when they joined (or at season start)
+    pub member_scores: [u64; 16],       // Member's contribution this season
+    pub season_id: u64,                 // Season the scores belong to
+    pub banked_score: u64,              // Contributions of members who left this season
+    pub guild_score: u64,               // banked_score + sum(member_scores)
+    pub created_at: i64,                // Unix timestamp
+}
+
+impl Guild {
+    pub const MAX_MEMBERS: usize = 16;
+    
+    /// game_type used for guild season leaderboards (player leaderboards use 0-7).
+    pub const GUILD_LEADERBOARD_TYPE: u8 = 255;
+    
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        32 +                             // name ([u8; 32])
+        64 +                             // owner ([u8; 64])
+        1 +                              // member_count (u8)
+        (64 * 16) +                      // members ([[u8; 64]; 16] = 1024 bytes)
+        (8 * 16) +                       // member_baselines ([u64; 16])
+        (8 * 16) +                       // member_scores ([u64; 16])
+        8 +                              // season_id (u64)
+        8 +                              // banked_score (u64)
+        8 +                              // guild_score (u64)
+        8;                               // created_at (i64)
+    
+    // Total: 8 + 32 + 64 + 1 + 1024 + 128 + 128 + 8 + 8 + 8 + 8 = 1417 bytes
+    
+    pub fn name_str(&self) -> String {
+        fixed_bytes_to_string(&self.name)
+    }
+    
+    /// Leaderboard entry id (LeaderboardEntry.user_id) for this guild.
+    pub fn leaderboard_id(&self) -> [u8; 64] {
+        let mut id = [0u8; 64];
+        id[..32].copy_from_slice(&self.name);
+        id
+    }
+    
+    pub fn find_member(&self, user_id: &[u8; 64]) -> Option<usize> {
+        self.members[..self.member_count as usize]
+            .iter()
+            .position(|m| m == user_id)
+    }
+    
+    pub fn is_full(&self) -> bool {
+        self.member_count as usize >= Self::MAX_MEMBERS
+    }
+    
+    /// Adds a member whose contribution starts from `season_score`.
+    /// Caller checks capacity and that the user isn't already in a guild.
+    pub fn add_member(&mut self, user_id: [u8; 64], season_score: u64) {
+        let i = self.member_count as usize;
+        self.members[i] = user_id;
+        self.member_baselines[i] = season_score;
+        self.member_scores[i] = 0;
+        self.member_count += 1;
+    }
+    
+    /// Removes a member, banking their contribution so the guild score doesn't drop.
+    /// Returns false if the user isn't a member.
+    pub fn remove_member(&mut self, user_id: &[u8; 64]) -> bool {
+        let Some(idx) = self.find_member(user_id) else {
+            return false;
+        };
+        self.banked_score = self.banked_score.saturating_add(self.member_scores[idx]);
+        let last = self.member_count as usize - 1;
+        self.members[idx] = self.members[last];
+        self.member_baselines[idx] = self.member_baselines[last];
+        self.member_scores[idx] = self.member_scores[last];
+        self.members[last] = [0u8; 64];
+        self.member_baselines[last] = 0;
+        self.member_scores[last] = 0;
+        self.member_count -= 1;
+        true
+    }
+    
+    /// Moves the guild to a new season: contributions restart from zero.
+    pub fn start_season(&mut self, season_id: u64) {
+        self.season_id = season_id;
+        self.member_baselines = [0u64; 16];
+        self.member_scores = [0u64; 16];
+        self.banked_score = 0;
+        self.guild_score = 0;
+    }
+    
+    /// Updates a member's contribution from their current season score and recomputes the
+    /// guild score. Contributions only grow within a season.
+    pub fn record_member_score(&mut self, idx: usize, season_score: u64) {
+        let contribution = season_score.saturating_sub(self.member_baselines[idx]);
+        self.member_scores[idx] = self.member_scores[idx].max(contribution);
+        self.guild_score = self.member_scores[..self.member_count as usize]
+            .iter()
+            .fold(self.banked_score, |total, &s| total.saturating_add(s));
+    }
+}
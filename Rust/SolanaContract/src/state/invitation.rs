@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Invitation admits one named user to a private match (friend challenges).
+/// Seeds: ["invitation", match, invitee_user_id]. Created by the match authority with
+/// invite_player; consumed by the invitee's accept_invitation, which joins the match.
+#[account]
+pub struct Invitation {
+    pub match_account: Pubkey,          // Match PDA the invite is for
+    pub invitee: [u8; 64],              // Invited user's Firebase UID (fixed 64 bytes, null-padded)
+    pub inviter: Pubkey,                // Match authority that sent the invite
+    pub expires_at: i64,                // Unix timestamp after which the invite can't be accepted
+    pub accepted: bool,                 // Set once the invitee joins
+    pub created_at: i64,                // Unix timestamp
+}
+
+impl Invitation {
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        32 +                             // match_account (Pubkey)
+        64 +                             // invitee ([u8; 64])
+        32 +                             // inviter (Pubkey)
+        8 +                              // expires_at (i64)
+        1 +                              // accepted (bool)
+        8;                               // created_at (i64)
+    
+    // Total: 8 + 32 + 64 + 32 + 8 + 1 + 8 = 153 bytes
+    
+    pub fn is_expired(&self, clock: &Clock) -> bool {
+        clock.unix_timestamp >= self.expires_at
+    }
+}
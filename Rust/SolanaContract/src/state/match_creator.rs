@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash;
+
+/// MatchCreator counts the matches an authority created with create_match_counted
+/// (seeds: ["match_creator", authority]).
+/// Each counted match gets the match_id derived_match_id(authority, match_counter), so its
+/// match PDA follows from (authority, counter) and is unique on-chain: ids can't be ground
+/// for vanity or collisions, and nobody has to trust the creator's UUIDs. Derived ids carry
+/// UUID version DERIVED_ID_VERSION, which create_match rejects so they can't be squatted.
+#[account]
+pub struct MatchCreator {
+    pub authority: Pubkey,              // Creating authority
+    pub match_counter: u64,             // Counter of the next counted match (starts at 0)
+    pub last_created_at: i64,           // Unix timestamp of the last counted match
+}
+
+impl MatchCreator {
+    pub const DERIVED_ID_VERSION: u8 = b'8'; // UUIDv8 (custom) version digit
+    const VERSION_POS: usize = 14;           // xxxxxxxx-xxxx-Vxxx-xxxx-xxxxxxxxxxxx
+    
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        32 +                             // authority (Pubkey)
+        8 +                              // match_counter (u64)
+        8;                               // last_created_at (i64)
+    
+    // Total: 8 + 32 + 8 + 8 = 56 bytes
+    
+    /// UUID-formatted match_id for (authority, counter): the hex of
+    /// SHA-256("match_creator" || authority || counter LE), with the version digit set.
+    pub fn derived_match_id(authority: &Pubkey, match_counter: u64) -> [u8; 36] {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        let digest = hash::hashv(&[b"match_creator", authority.as_ref(), &match_counter.to_le_bytes()]).to_bytes();
+        let mut match_id = [b'-'; 36];
+        let mut nibbles = digest.iter().flat_map(|b| [b >> 4, b & 0x0F]);
+        for (i, c) in match_id.iter_mut().enumerate() {
+            if ![8, 13, 18, 23].contains(&i) {
+                *c = HEX[nibbles.next().unwrap_or(0) as usize];
+            }
+        }
+        match_id[Self::VERSION_POS] = Self::DERIVED_ID_VERSION;
+        match_id
+    }
+    
+    /// True for ids in the derived range (reserved for create_match_counted).
+    pub fn is_derived_match_id(match_id: &[u8]) -> bool {
+        match_id.len() == 36 && match_id[Self::VERSION_POS] == Self::DERIVED_ID_VERSION
+    }
+}
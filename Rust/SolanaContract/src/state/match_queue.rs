@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::error::GameError;
+
+/// QueueEntry is a single player waiting for a match.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct QueueEntry {
+    pub user_id: [u8; 64],                // Firebase UID (fixed 64 bytes, null-padded)
+    pub tier: u8,                         // UserAccount.current_tier snapshot at enqueue time
+    pub enqueued_at: i64,                 // Unix timestamp
+    pub wallet: Pubkey,                   // Wallet that enqueued (may dequeue; takes the seat)
+}
+
+impl QueueEntry {
+    pub const SIZE: usize = 64 + 1 + 8 + 32; // 105 bytes per entry
+}
+
+/// MatchQueue is the open matchmaking queue for one game type.
+/// Entries are kept in FIFO order; coordinators form matches from the oldest entry
+/// plus the next players within its tier band (form_match_from_queue).
+#[account]
+pub struct MatchQueue {
+    pub game_type: u8,                    // GameType as u8
+    pub entry_count: u8,                  // Number of waiting players (0-50)
+    pub entries: [QueueEntry; 50],        // FIFO queue (fixed array)
+    pub matches_formed: u64,              // Total matches formed from this queue
+    pub last_updated: i64,                // Last update timestamp
+}
+
+impl MatchQueue {
+    pub const MAX_ENTRIES: usize = 50;
+    
+    pub const MAX_SIZE: usize = 8 +        // discriminator
+        1 +                                 // game_type (u8)
+        1 +                                 // entry_count (u8)
+        (QueueEntry::SIZE * 50) +          // entries ([QueueEntry; 50] = 5250 bytes)
+        8 +                                 // matches_formed (u64)
+        8;                                  // last_updated (i64)
+    
+    // Total: 8 + 1 + 1 + 5250 + 8 + 8 = 5276 bytes
+    
+    pub fn find_entry(&self, user_id: &[u8; 64]) -> Option<usize> {
+        self.entries[..self.entry_count as usize]
+            .iter()
+            .position(|e| e.user_id == *user_id)
+    }
+    
+    pub fn push(&mut self, entry: QueueEntry) -> Result<()> {
+        require!(
+            (self.entry_count as usize) < Self::MAX_ENTRIES,
+            GameError::QueueFull
+        );
+        require!(
+            self.find_entry(&entry.user_id).is_none(),
+            GameError::AlreadyQueued
+        );
+        self.entries[self.entry_count as usize] = entry;
+        self.entry_count += 1;
+        Ok(())
+    }
+    
+    /// Removes the entry at `index`, preserving FIFO order of the remaining entries.
+    pub fn remove_at(&mut self, index: usize) -> QueueEntry {
+        let count = self.entry_count as usize;
+        let removed = self.entries[index];
+        for i in index..count - 1 {
+            self.entries[i] = self.entries[i + 1];
+        }
+        self.entries[count - 1] = QueueEntry {
+            user_id: [0u8; 64],
+            tier: 0,
+            enqueued_at: 0,
+            wallet: Pubkey::default(),
+        };
+        self.entry_count -= 1;
+        removed
+    }
+    
+    /// Selects `count` players: the oldest entry plus the next entries whose tier is
+    /// within `tier_band` of it. Returns queue indices in FIFO order, or None if not enough.
+    pub fn select_banded(&self, count: usize, tier_band: u8) -> Option<Vec<usize>> {
+        let queued = self.entry_count as usize;
+        if count == 0 || queued < count {
+            return None;
+        }
+        let anchor_tier = self.entries[0].tier;
+        let selected: Vec<usize> = (0..queued)
+            .filter(|&i| self.entries[i].tier.abs_diff(anchor_tier) <= tier_band)
+            .take(count)
+            .collect();
+        if selected.len() == count {
+            Some(selected)
+        } else {
+            None
+        }
+    }
+}
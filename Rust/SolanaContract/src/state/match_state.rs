@@ -1,11 +1,63 @@
 use anchor_lang::prelude::*;
 use crate::state::game_config::{GameType, GameConfig};
+use crate::error::GameError;
+use crate::state::fixed_str::fixed_bytes_to_string;
+use crate::state::pot_ledger::PotLedger;
+use crate::replay;
+use std::fmt;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
 pub enum GamePhase {
     Dealing = 0,
     Playing = 1,
     Ended = 2,
+    Showdown = 3, // Showdown called; undeclared players may rebut until rebuttal_deadline
+}
+
+/// How a match reached the Ended phase (stored in `Match::outcome`).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum EndReason {
+    Completed = 0, // Played out (showdown / scoring), or not ended yet
+    Timeout = 1,   // outcome_player's time bank ran out
+    Resignation = 2, // outcome_player resigned
+    Draw = 3,      // All players agreed to a draw (outcome_player = who offered it)
+    ForceEnded = 4, // Voided by the admin council (force_end_match); no winner, entry costs refunded
+}
+
+/// RuleParams is the decoded form of Match.rule_params, a per-match variant set by
+/// set_rule_params so casual lobbies can tweak a game without registering a new one.
+/// All zeros = the game's standard rules.
+/// Byte 0: starting hand size (commit_hand must deal exactly this many cards, 0 = any)
+/// Bytes 1-2: point target, u16 LE (no further rounds once a player's cumulative score
+/// reaches it, 0 = play rounds_to_play rounds)
+/// Bytes 4-13: per-seat handicap, i8 points each, the seat's starting cumulative score
+/// Bytes 3, 14-15: reserved (must be 0)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RuleParams {
+    pub hand_size: u8,
+    pub point_target: u16,
+    pub handicaps: [i8; 10],
+}
+
+impl RuleParams {
+    pub const HANDICAP_OFFSET: usize = 4;
+
+    pub fn from_bytes(bytes: &[u8; 16]) -> Self {
+        let mut handicaps = [0i8; 10];
+        for (seat, handicap) in handicaps.iter_mut().enumerate() {
+            *handicap = bytes[Self::HANDICAP_OFFSET + seat] as i8;
+        }
+        RuleParams {
+            hand_size: bytes[0],
+            point_target: u16::from_le_bytes([bytes[1], bytes[2]]),
+            handicaps,
+        }
+    }
+
+    /// Params are valid when the reserved bytes are clear and the hand size fits a deck.
+    pub fn is_valid_bytes(bytes: &[u8; 16]) -> bool {
+        bytes[3] == 0 && bytes[14] == 0 && bytes[15] == 0 && bytes[0] <= 52
+    }
 }
 
 #[account]
@@ -18,7 +70,7 @@ pub struct Match {
     
     pub game_type: u8,              // GameType enum as u8
     pub seed: u64,                  // RNG seed
-    pub phase: u8,                  // 0=Dealing, 1=Playing, 2=Ended
+    pub phase: u8,                  // 0=Dealing, 1=Playing, 2=Ended, 3=Showdown
     pub current_player: u8,         // Index (0-9)
     pub player_ids: [[u8; 64]; 10], // Fixed array of 10 Firebase UIDs (max 64 bytes each, null-padded)
     pub player_count: u8,           // Current number of players
@@ -39,7 +91,12 @@ pub struct Match {
     // Pack boolean flags into single u8 (saves 1 byte)
     // Bit 0: floor_card_revealed
     // Bit 1: all_players_joined
-    // Bits 2-7: reserved
+    // Bit 2: round_scored (current round's scores added to cumulative_scores)
+    // Bit 3: ratings_recorded (ELO changes applied for this match)
+    // Bit 4: time_control (per-player time banks enforced)
+    // Bit 5: paused (live-ops pause, see pause_match)
+    // Bit 6: player_consent_start (set_ready quorum may start the match, see set_ready)
+    // Bit 7: stats_recorded (finalized match counted in GlobalStats)
     pub flags: u8,
     
     // Per critique Issue #1: Floor card hash for on-chain validation
@@ -52,6 +109,17 @@ pub struct Match {
     // Format: [player0_size(1) | player1_size(1) | ... | player9_size(1)]
     pub hand_sizes: [u8; 10], // 10 players × 1 byte = 10 bytes
     
+    // Hash of the card most recently played or discarded (PlayCard/Discard actions)
+    // All zeros = nothing played/discarded this round
+    pub last_discard_hash: [u8; 32],
+    
+    // Discard pile for the current round (reset with the round)
+    // discard_pile_root: hash chain over every discarded card, root = SHA-256(prev_root || card_hash)
+    // recent_discards: ring buffer of the last 8 discarded card hashes, slot = discard_count % 8
+    pub discard_pile_root: [u8; 32],
+    pub discard_count: u8,
+    pub recent_discards: [u8; 256], // 8 cards × 32 bytes = 256 bytes
+    
     // Per critique: committed hand hashes for card validation
     // Each player commits their hand hash at match start (SHA-256 of sorted card list)
     // Format: [player0_hash(32) | player1_hash(32) | ... | player9_hash(32)]
@@ -61,6 +129,154 @@ pub struct Match {
     // Each player must submit nonce > last_nonce[player_index] to prevent replay attacks
     // Format: [player0_nonce(8) | player1_nonce(8) | ... | player9_nonce(8)]
     pub last_nonce: [u64; 10], // 10 players × 8 bytes = 80 bytes
+    
+    // Move rate limiting: slot of each player's last move and moves submitted in that slot
+    // Limit (max moves per player per slot) comes from ConfigAccount.max_moves_per_slot
+    pub last_move_slot: [u64; 10], // 10 players × 8 bytes = 80 bytes
+    pub slot_move_counts: [u8; 10], // 10 players × 1 byte = 10 bytes
+    
+    // Multi-round support (CLAIM, Rummy, etc. are played over several rounds)
+    pub round_number: u8,           // Current round (1-based)
+    pub rounds_to_play: u8,         // Total rounds in this match (1 = single round)
+    pub cumulative_scores: [i32; 10], // Scores accumulated over completed rounds (10 players × 4 bytes)
+    
+    // Team/partnership assignment per player slot (Bridge and other partner games)
+    // 0 = no team, 1-5 = team id
+    pub teams: [u8; 10],
+    
+    // Private matches: Match::join_secret_hash of the join code (all zeros = public match)
+    pub join_secret_hash: [u8; 32],
+    
+    // Chess-style time control (enabled by set_time_control, flag bit 4)
+    // Remaining seconds per player; the player on turn is charged for elapsed wall time
+    pub time_bank: [u32; 10],        // 10 players × 4 bytes = 40 bytes
+    pub time_increment: u32,         // Seconds added to a bank after each completed turn
+    pub turn_started_at: i64,        // Unix timestamp the current turn began
+    
+    // How the match ended (EndReason as u8) and the player it applies to (e.g. who timed out)
+    pub outcome: u8,
+    pub outcome_player: u8,
+    
+    // Pending draw offer: bit i set = player i offered/accepted (0 = no offer pending)
+    // The offer lapses when a player who hasn't agreed makes a turn-based move
+    pub draw_votes: u16,
+    pub draw_offered_by: u8,         // Player who made the pending offer
+    
+    // Live-ops pause (coordinator outage etc.): moves are rejected and the turn clock is frozen
+    // The pause lifts itself at pause_expires_at if resume_match is never sent
+    pub paused_at: i64,              // Unix timestamp the pause began
+    pub pause_expires_at: i64,       // paused_at + max pause duration
+    pub pause_reason: u8,            // Reason code (see pause_match)
+    
+    // Running replay transcript hash (see crate::replay): header hash at the first start_match,
+    // then chained with every move. end_match derives match_hash from it.
+    pub transcript_hash: [u8; 32],
+    
+    // Permanent archive (set once by anchor_match_record, unlike hot_url)
+    pub cold_uri: [u8; 64],              // Arweave tx id or IPFS CID (null-padded, all zeros = not set)
+    pub cold_content_hash: [u8; 32],     // SHA-256 of the archived record at cold_uri
+    
+    // Anti-collusion evidence (see record_move_anomalies); counters saturate at 255
+    // anomaly_flags bits: 0 = repeated declines, 1 = nonce gaps, 2 = shared fee payer,
+    // 3 = deck accounting desync (see check_deck_accounting)
+    pub last_decline_player: u8,     // Seat of the previous move if it was a decline (0xFF = not a decline)
+    pub decline_pair: u8,            // Seats of the last back-to-back decline pair (low << 4 | high, 0xFF = none)
+    pub repeated_decline_count: u8,  // Back-to-back declines between the same pair of seats
+    pub nonce_gap_count: u8,         // Moves whose nonce skipped past last_nonce + 1
+    pub shared_payer_count: u8,      // Moves signed in the same slot by the signer of another seat's move
+    pub last_move_signer: Pubkey,    // Signer (fee payer) of the previous move
+    pub last_move_signer_slot: u64,  // Slot of the previous move
+    pub last_move_signer_player: u8, // Seat of the previous move
+    pub anomaly_flags: u8,
+    
+    // Player-consent start: bit i set = player i is ready (reset every round)
+    pub ready_votes: u16,
+    
+    // Pending player-quorum authority transfer (see transfer_match_authority)
+    // Voting for a different proposed_authority replaces the pending proposal
+    pub proposed_authority: Pubkey,  // Pubkey::default() = no proposal pending
+    pub authority_votes: u16,        // Bit i set = player i voted for proposed_authority
+    
+    // Authority hashed into the transcript header; frozen once the transcript starts so a
+    // later authority transfer doesn't invalidate the replay hash
+    pub header_authority: Pubkey,
+    
+    // Per-action cooldowns for non-turn actions (see COOLDOWN_ACTIONS): slot of each
+    // player's last accepted action of each kind, 0 = never
+    // Format: [player0_action0(8) | player0_action1(8) | ... | player9_action3(8)]
+    pub last_action_slots: [u64; 40], // 10 players × 4 actions × 8 bytes = 320 bytes
+    
+    // Showdown resolution (phase 3): undeclared players may rebut until the deadline,
+    // then the round ends (close_showdown, or the last outstanding rebuttal)
+    pub showdown_caller: u8,         // Seat that called showdown
+    pub rebuttal_deadline: i64,      // Unix timestamp the rebuttal window closes
+    pub rebuttal_responses: u16,     // Bit i set = player i has rebutted
+    pub claimed_run_suit: u8,        // Suit of the run the showdown caller claimed
+    pub claimed_run_top: u8,         // Top card value of that run; rebuttals must beat (top, suit)
+    
+    // Nonce acceptance window per player: bit i set = nonce last_nonce - 1 - i was used.
+    // Lets moves submitted concurrently land out of order (see is_nonce_fresh)
+    pub nonce_windows: [u64; 10],    // 10 players × 8 bytes = 80 bytes
+    
+    // Unix timestamp the current turn must be played by (0 = no turn running), stamped each
+    // time the turn advances; past it, claim_timeout forfeits the player on turn
+    pub turn_deadline: i64,
+    
+    // Move account compaction (see compact_match_moves): Move PDAs 0..compacted_moves are
+    // closed and their rent refunded; compaction_hash is the transcript hash through them
+    pub compacted_moves: u32,
+    pub compaction_hash: [u8; 32],
+    
+    // Wallet that paid the match account's rent (the creating authority); close_match_account
+    // refunds it here even after the authority changed hands
+    pub rent_payer: Pubkey,
+    
+    // Deck accounting: deck_size is fixed when play begins (deck commitment or a standard deck);
+    // cards_dealt_total counts cards dealt out of it this round (committed hands + pick-ups).
+    // Invariant: hands + discard_count + remaining deck == deck_size
+    pub deck_size: u8,
+    pub cards_dealt_total: u16,
+    
+    // Reason code force_end_match was given (0 = not force-ended, see FORCE_END_* codes)
+    pub force_end_reason: u8,
+    
+    // Client metadata (tournament id, table name, stream URL hash, ...) set by set_match_metadata:
+    // up to MAX_METADATA_ENTRIES key/value pairs, all-zero key = free slot
+    pub metadata_keys: [[u8; 8]; 4],     // 4 entries × 8 bytes = 32 bytes
+    pub metadata_values: [[u8; 32]; 4],  // 4 entries × 32 bytes = 128 bytes
+    
+    // Rummy melds (validation::rummy): cards laid face-up this round, one bit per card
+    // (see rummy::card_bit), and each seat's meld points over the match
+    pub melded_cards: u64,
+    pub meld_points: [u16; 10],          // 10 players × 2 bytes = 20 bytes
+    
+    // Betting ledger for the current hand (games with has_betting), see state/pot_ledger.rs
+    pub pot: PotLedger,
+    
+    // Calendar start (tournament rounds), set by schedule_match: play can't begin before it, and
+    // a match still not started SCHEDULE_GRACE_SECONDS after it can be expired (0 = unscheduled)
+    pub scheduled_start: i64,
+    
+    // Per-match variant (hand size, point target, handicaps), see RuleParams
+    pub rule_params: [u8; 16],
+    
+    // AI/bot seats: bit i set = seat i is played by a bot (attested by the match authority's
+    // co-signature at join). Matches with bots are rated on the bot ladder, not human ratings
+    pub is_bot: u16,
+    
+    // Lobby visibility (VISIBILITY_* codes), fixed at create_match: public matches are listed in
+    // the game's OpenLobbyIndex while joinable, unlisted ones are joined by id, private by join code
+    pub visibility: u8,
+    
+    // Wallet that took each seat (the join_match / accept_invitation signer, or the enqueuing
+    // wallet for form_match_from_queue). Seat actions named by user_id must be signed by it
+    pub player_wallets: [Pubkey; 10],
+}
+
+/// Seed bytes for a match_id in match-scoped PDAs ("match", "move", "dispute", ...).
+/// Seeds are capped at 32 bytes and a UUID v4 is 36, so only its first 32 bytes are used.
+pub fn match_id_seed(match_id: &[u8]) -> &[u8] {
+    &match_id[..match_id.len().min(32)]
 }
 
 impl Match {
@@ -84,13 +300,262 @@ impl Match {
         1 +                              // flags (u8 bitfield)
         32 +                             // floor_card_hash ([u8; 32]) - per critique Issue #1
         10 +                             // hand_sizes ([u8; 10]) - per critique Issue #1
+        32 +                             // last_discard_hash ([u8; 32])
+        32 +                             // discard_pile_root ([u8; 32], hash chain)
+        1 +                              // discard_count (u8)
+        256 +                            // recent_discards ([u8; 256] = 8 × 32 bytes)
         320 +                            // committed_hand_hashes ([u8; 320])
-        (8 * 10);                        // last_nonce ([u64; 10] = 80 bytes)
+        (8 * 10) +                       // last_nonce ([u64; 10] = 80 bytes)
+        (8 * 10) +                       // last_move_slot ([u64; 10] = 80 bytes)
+        10 +                             // slot_move_counts ([u8; 10])
+        1 +                              // round_number (u8)
+        1 +                              // rounds_to_play (u8)
+        (4 * 10) +                       // cumulative_scores ([i32; 10] = 40 bytes)
+        10 +                             // teams ([u8; 10])
+        32 +                             // join_secret_hash ([u8; 32], all zeros = public)
+        (4 * 10) +                       // time_bank ([u32; 10] = 40 bytes)
+        4 +                              // time_increment (u32)
+        8 +                              // turn_started_at (i64)
+        1 +                              // outcome (u8, EndReason)
+        1 +                              // outcome_player (u8)
+        2 +                              // draw_votes (u16 bitmask)
+        1 +                              // draw_offered_by (u8)
+        8 +                              // paused_at (i64)
+        8 +                              // pause_expires_at (i64)
+        1 +                              // pause_reason (u8)
+        32 +                             // transcript_hash ([u8; 32])
+        64 +                             // cold_uri (fixed [u8; 64])
+        32 +                             // cold_content_hash ([u8; 32])
+        1 +                              // last_decline_player (u8)
+        1 +                              // decline_pair (u8)
+        1 +                              // repeated_decline_count (u8)
+        1 +                              // nonce_gap_count (u8)
+        1 +                              // shared_payer_count (u8)
+        32 +                             // last_move_signer (Pubkey)
+        8 +                              // last_move_signer_slot (u64)
+        1 +                              // last_move_signer_player (u8)
+        1 +                              // anomaly_flags (u8 bitfield)
+        2 +                              // ready_votes (u16 bitmask)
+        32 +                             // proposed_authority (Pubkey)
+        2 +                              // authority_votes (u16 bitmask)
+        32 +                             // header_authority (Pubkey)
+        (8 * 40) +                       // last_action_slots ([u64; 40] = 320 bytes)
+        1 +                              // showdown_caller (u8)
+        8 +                              // rebuttal_deadline (i64)
+        2 +                              // rebuttal_responses (u16 bitmask)
+        1 +                              // claimed_run_suit (u8)
+        1 +                              // claimed_run_top (u8)
+        (8 * 10) +                       // nonce_windows ([u64; 10] = 80 bytes)
+        8 +                              // turn_deadline (i64)
+        4 +                              // compacted_moves (u32)
+        32 +                             // compaction_hash ([u8; 32])
+        32 +                             // rent_payer (Pubkey)
+        1 +                              // deck_size (u8)
+        2 +                              // cards_dealt_total (u16)
+        1 +                              // force_end_reason (u8)
+        (8 * 4) +                        // metadata_keys ([[u8; 8]; 4] = 32 bytes)
+        (32 * 4) +                       // metadata_values ([[u8; 32]; 4] = 128 bytes)
+        8 +                              // melded_cards (u64 bitmask)
+        (2 * 10) +                       // meld_points ([u16; 10] = 20 bytes)
+        PotLedger::SIZE +                // pot (PotLedger = 223 bytes)
+        8 +                              // scheduled_start (i64)
+        16 +                             // rule_params ([u8; 16])
+        2 +                              // is_bot (u16 bitmask)
+        1 +                              // visibility (u8)
+        (32 * 10);                       // player_wallets ([Pubkey; 10] = 320 bytes)
+    
+    // Total: 8 + 36 + 10 + 20 + 1 + 8 + 1 + 1 + 640 + 1 + 4 + 8 + 8 + 32 + 200 + 32 + 5 + 1 + 32 + 10 + 32 + 32 + 1 + 256 + 320 + 80 + 80 + 10 + 1 + 1 + 40 + 10 + 32 + 40 + 4 + 8 + 1 + 1 + 2 + 1 + 8 + 8 + 1 + 32 + 64 + 32 + 1 + 1 + 1 + 1 + 1 + 32 + 8 + 1 + 1 + 2 + 32 + 2 + 32 + 320 + 1 + 8 + 2 + 1 + 1 + 80 + 8 + 4 + 32 + 32 + 1 + 2 + 1 + 32 + 128 + 8 + 20 + 223 + 8 + 16 + 2 + 1 + 320 = 3521 bytes
     
-    // Total: 8 + 36 + 10 + 20 + 1 + 8 + 1 + 1 + 320 + 1 + 4 + 8 + 8 + 32 + 200 + 32 + 5 + 1 + 32 + 10 + 320 + 80 = 1146 bytes
+    pub const MAX_TEAMS: u8 = 5;
+    pub const RECENT_DISCARDS: usize = 8;
+    pub const MAX_PAUSE_SECONDS: u32 = 3600; // 1 hour per pause
+    pub const MAX_METADATA_ENTRIES: usize = 4;
+    // force_end_match reason codes (mandatory, for the audit trail)
+    pub const FORCE_END_STUCK_STATE: u8 = 1;     // Unrecoverable state no timeout path clears
+    pub const FORCE_END_COORDINATOR_LOST: u8 = 2; // No coordinator can finalize the match
+    pub const FORCE_END_INTEGRITY: u8 = 3;       // Exploit or corrupted transcript
+    pub const FORCE_END_OTHER: u8 = 4;
+    pub const FORCE_END_SCHEDULE_MISSED: u8 = 5; // Set by expire_scheduled_match, not the council
+    pub const SCHEDULE_GRACE_SECONDS: i64 = 900; // 15 minutes past scheduled_start to begin play
+    pub const VISIBILITY_UNLISTED: u8 = 0;       // Joinable by match id, not listed (default)
+    pub const VISIBILITY_PUBLIC: u8 = 1;         // Listed in the game's OpenLobbyIndex while joinable
+    pub const VISIBILITY_PRIVATE: u8 = 2;        // Join code or coordinator co-signature required
+    pub const ANOMALY_REPEATED_DECLINES: u8 = 0x01;
+    pub const ANOMALY_NONCE_GAPS: u8 = 0x02;
+    pub const ANOMALY_SHARED_PAYER: u8 = 0x04;
+    pub const ANOMALY_DECK_DESYNC: u8 = 0x08;
+    pub const ANOMALY_THRESHOLD: u8 = 3; // Repeated declines / nonce gaps before the flag is raised
+    const NO_SEAT: u8 = 0xFF;
+    // Non-turn actions a player could otherwise flood: declare intent, call showdown,
+    // rebuttal, offer draw (ConfigAccount.action_cooldown_slots apart)
+    pub const COOLDOWN_ACTIONS: [u8; 4] = [2, 3, 4, 8];
+    pub const TURN_TIMEOUT_SECONDS: u32 = 300; // Per-turn deadline without time control
+    pub const NONCE_WINDOW: u64 = 64; // How far below last_nonce an unused nonce is still accepted
+    pub const DEFAULT_REBUTTAL_WINDOW_SECONDS: u32 = 60; // When ConfigAccount.rebuttal_window_seconds is 0
     // Added version field per critique Phase 2.4, committed hand hashes and nonce tracking per critique
     // Added floor_card_hash and hand_sizes per critique Issue #1 for on-chain validation
 
+    /// Initializes all fields of a freshly created match (Dealing phase, no players).
+    /// Shared by create_match and matchmaking so every creation path zeroes the same state.
+    pub fn initialize(
+        &mut self,
+        match_id: [u8; 36],
+        game_type: GameType,
+        seed: u64,
+        authority: Pubkey,
+        created_at: i64,
+        rounds_to_play: u8,
+    ) {
+        // Convert game name to fixed-size array (null-padded)
+        let game_name_bytes = game_type.get_name().as_bytes();
+        let mut game_name_array = [0u8; 20];
+        let name_copy_len = game_name_bytes.len().min(20);
+        game_name_array[..name_copy_len].copy_from_slice(&game_name_bytes[..name_copy_len]);
+
+        self.match_id = match_id;
+        
+        // Per critique Phase 2.4: Initialize version field (default to "1.0.0")
+        let version_bytes = "1.0.0".as_bytes();
+        let mut version_array = [0u8; 10];
+        let version_copy_len = version_bytes.len().min(10);
+        version_array[..version_copy_len].copy_from_slice(&version_bytes[..version_copy_len]);
+        self.version = version_array;
+        
+        self.game_type = game_type as u8;
+        self.game_name = game_name_array;
+        self.seed = seed;
+        self.phase = 0; // Dealing
+        self.current_player = 0;
+        self.player_ids = [[0u8; 64]; 10]; // Initialize all player_ids to empty
+        self.player_count = 0;
+        self.move_count = 0;
+        self.created_at = created_at;
+        self.ended_at = 0; // 0 = not ended
+        self.match_hash = [0u8; 32]; // All zeros = not set
+        self.hot_url = [0u8; 200]; // All zeros = not set
+        self.authority = authority;
+        self.declared_suits = [0u8; 5]; // All zeros = no suits declared
+        self.flags = 0; // All flags false
+        self.floor_card_hash = [0u8; 32]; // All zeros = no floor card - per critique Issue #1
+        self.hand_sizes = [0u8; 10]; // All zeros = no hands committed yet - per critique Issue #1
+        self.last_discard_hash = [0u8; 32]; // All zeros = nothing discarded yet
+        self.discard_pile_root = [0u8; 32]; // Empty discard pile
+        self.discard_count = 0;
+        self.recent_discards = [0u8; 256];
+        self.committed_hand_hashes = [0u8; 320]; // All zeros = not committed yet
+        self.last_nonce = [0u64; 10]; // All zeros = no moves yet
+        self.last_move_slot = [0u64; 10]; // All zeros = no moves yet
+        self.slot_move_counts = [0u8; 10];
+        self.round_number = 1;
+        self.rounds_to_play = rounds_to_play.max(1);
+        self.cumulative_scores = [0i32; 10];
+        self.teams = [0u8; 10]; // All zeros = no teams
+        self.join_secret_hash = [0u8; 32]; // All zeros = public
+        self.time_bank = [0u32; 10]; // No time control until set_time_control
+        self.time_increment = 0;
+        self.turn_started_at = 0;
+        self.outcome = EndReason::Completed as u8;
+        self.outcome_player = 0;
+        self.draw_votes = 0; // No draw offer pending
+        self.draw_offered_by = 0;
+        self.paused_at = 0; // Not paused
+        self.pause_expires_at = 0;
+        self.pause_reason = 0;
+        self.transcript_hash = [0u8; 32]; // Set by the first start_match
+        self.cold_uri = [0u8; 64]; // All zeros = not archived
+        self.cold_content_hash = [0u8; 32];
+        self.last_decline_player = Self::NO_SEAT;
+        self.decline_pair = Self::NO_SEAT;
+        self.repeated_decline_count = 0;
+        self.nonce_gap_count = 0;
+        self.shared_payer_count = 0;
+        self.last_move_signer = Pubkey::default();
+        self.last_move_signer_slot = 0;
+        self.last_move_signer_player = Self::NO_SEAT;
+        self.anomaly_flags = 0; // No anomalies
+        self.ready_votes = 0; // Nobody ready yet
+        self.proposed_authority = Pubkey::default(); // No authority transfer pending
+        self.authority_votes = 0;
+        self.header_authority = authority;
+        self.last_action_slots = [0u64; 40]; // No cooldowns running
+        self.showdown_caller = 0; // No showdown yet
+        self.rebuttal_deadline = 0;
+        self.rebuttal_responses = 0;
+        self.claimed_run_suit = 0; // No run claimed yet
+        self.claimed_run_top = 0;
+        self.nonce_windows = [0u64; 10]; // No nonces used yet
+        self.turn_deadline = 0; // Stamped when play starts
+        self.compacted_moves = 0; // Move accounts kept until the match is finalized
+        self.compaction_hash = [0u8; 32];
+        self.rent_payer = authority; // Every creation path pays rent from the creating authority
+        self.deck_size = 0; // Set when play begins
+        self.cards_dealt_total = 0;
+        self.force_end_reason = 0;
+        self.metadata_keys = [[0u8; 8]; 4];
+        self.metadata_values = [[0u8; 32]; 4];
+        self.melded_cards = 0;
+        self.meld_points = [0u16; 10];
+        self.pot = PotLedger::default();
+        self.scheduled_start = 0;
+        self.rule_params = [0u8; 16];
+        self.is_bot = 0;
+        self.player_wallets = [Pubkey::default(); 10];
+    }
+
+    // Adds a player (and the wallet taking the seat) to the next free slot. Returns the player index.
+    pub fn add_player(&mut self, user_id: [u8; 64], wallet: Pubkey) -> Result<usize> {
+        let player_index = self.player_count as usize;
+        let max_players = self.get_max_players() as usize;
+        require!(
+            player_index < max_players && player_index < 10,
+            GameError::MatchFull
+        );
+        require!(
+            !self.has_player_id(&user_id),
+            GameError::PlayerNotInMatch
+        );
+        self.set_player_id(player_index, user_id);
+        self.player_wallets[player_index] = wallet;
+        self.player_count += 1;
+        if self.player_count >= self.get_max_players() {
+            self.set_all_players_joined(true);
+        }
+        Ok(player_index)
+    }
+
+    // String getters for null-padded fields (off-chain code, logs, tests)
+    pub fn match_id_str(&self) -> String {
+        fixed_bytes_to_string(&self.match_id)
+    }
+
+    pub fn version_str(&self) -> String {
+        fixed_bytes_to_string(&self.version)
+    }
+
+    pub fn game_name_str(&self) -> String {
+        fixed_bytes_to_string(&self.game_name)
+    }
+
+    pub fn hot_url_str(&self) -> String {
+        fixed_bytes_to_string(&self.hot_url)
+    }
+
+    pub fn cold_uri_str(&self) -> String {
+        fixed_bytes_to_string(&self.cold_uri)
+    }
+
+    pub fn has_cold_uri(&self) -> bool {
+        self.cold_uri.iter().any(|&b| b != 0)
+    }
+
+    /// Player's Firebase UID, or None if the seat is out of range or empty.
+    pub fn player_id_str(&self, player_index: usize) -> Option<String> {
+        if player_index >= self.player_count as usize || player_index >= 10 {
+            return None;
+        }
+        Some(fixed_bytes_to_string(&self.player_ids[player_index]))
+    }
+
     pub fn get_game_type(&self) -> GameType {
         match self.game_type {
             0 => GameType::Claim,
@@ -136,10 +601,29 @@ impl Match {
             0 => GamePhase::Dealing,
             1 => GamePhase::Playing,
             2 => GamePhase::Ended,
+            3 => GamePhase::Showdown,
             _ => GamePhase::Dealing, // Default fallback
         }
     }
 
+    pub fn get_outcome(&self) -> EndReason {
+        match self.outcome {
+            1 => EndReason::Timeout,
+            2 => EndReason::Resignation,
+            3 => EndReason::Draw,
+            4 => EndReason::ForceEnded,
+            _ => EndReason::Completed,
+        }
+    }
+
+    // Ends the match now with the given outcome (applied to player_index)
+    pub fn end_with_outcome(&mut self, outcome: EndReason, player_index: u8, now: i64) {
+        self.phase = 2; // Ended
+        self.ended_at = now;
+        self.outcome = outcome as u8;
+        self.outcome_player = player_index;
+    }
+
     // Helper methods for packed bitfield operations
     pub fn has_declared_suit(&self, player_index: usize) -> bool {
         if player_index >= 10 {
@@ -216,15 +700,572 @@ impl Match {
         }
     }
 
+    pub fn round_scored(&self) -> bool {
+        (self.flags & 0x04) != 0
+    }
+
+    pub fn set_round_scored(&mut self, scored: bool) {
+        if scored {
+            self.flags |= 0x04;
+        } else {
+            self.flags &= !0x04;
+        }
+    }
+
+    pub fn ratings_recorded(&self) -> bool {
+        (self.flags & 0x08) != 0
+    }
+
+    pub fn set_ratings_recorded(&mut self, recorded: bool) {
+        if recorded {
+            self.flags |= 0x08;
+        } else {
+            self.flags &= !0x08;
+        }
+    }
+
+    pub fn time_control(&self) -> bool {
+        (self.flags & 0x10) != 0
+    }
+
+    pub fn set_time_control(&mut self, enabled: bool) {
+        if enabled {
+            self.flags |= 0x10;
+        } else {
+            self.flags &= !0x10;
+        }
+    }
+
+    pub fn paused_flag(&self) -> bool {
+        (self.flags & 0x20) != 0
+    }
+
+    pub fn set_paused_flag(&mut self, paused: bool) {
+        if paused {
+            self.flags |= 0x20;
+        } else {
+            self.flags &= !0x20;
+        }
+    }
+
+    pub fn player_consent_start(&self) -> bool {
+        (self.flags & 0x40) != 0
+    }
+
+    pub fn set_player_consent_start(&mut self, enabled: bool) {
+        if enabled {
+            self.flags |= 0x40;
+        } else {
+            self.flags &= !0x40;
+        }
+    }
+
+    pub fn stats_recorded(&self) -> bool {
+        (self.flags & 0x80) != 0
+    }
+
+    pub fn set_stats_recorded(&mut self, recorded: bool) {
+        if recorded {
+            self.flags |= 0x80;
+        } else {
+            self.flags &= !0x80;
+        }
+    }
+
+    // Marks player_index ready (or not) and returns how many seated players are ready
+    pub fn set_ready(&mut self, player_index: usize, ready: bool) -> u8 {
+        if player_index < 10 {
+            if ready {
+                self.ready_votes |= 1 << player_index;
+            } else {
+                self.ready_votes &= !(1 << player_index);
+            }
+        }
+        self.ready_count()
+    }
+
+    pub fn ready_count(&self) -> u8 {
+        let seated = (1u16 << self.player_count.min(10)) - 1;
+        (self.ready_votes & seated).count_ones() as u8
+    }
+
+    // Records player_index's vote to hand the match to new_authority.
+    // Returns true once a strict majority of seated players agree on it.
+    pub fn vote_authority_transfer(&mut self, player_index: usize, new_authority: Pubkey) -> bool {
+        if self.proposed_authority != new_authority {
+            self.proposed_authority = new_authority;
+            self.authority_votes = 0;
+        }
+        if player_index < 10 {
+            self.authority_votes |= 1 << player_index;
+        }
+        let seated = (1u16 << self.player_count.min(10)) - 1;
+        (self.authority_votes & seated).count_ones() * 2 > self.player_count as u32
+    }
+
+    pub fn transfer_authority(&mut self, new_authority: Pubkey) {
+        self.authority = new_authority;
+        if !self.has_transcript() {
+            self.header_authority = new_authority;
+        }
+        self.proposed_authority = Pubkey::default();
+        self.authority_votes = 0;
+    }
+
+    // True while a pause is in effect (an expired pause no longer counts)
+    pub fn is_paused(&self, now: i64) -> bool {
+        self.paused_flag() && now < self.pause_expires_at
+    }
+
+    pub fn pause(&mut self, reason: u8, max_duration_seconds: u32, now: i64) {
+        self.set_paused_flag(true);
+        self.paused_at = now;
+        self.pause_expires_at = now.saturating_add(max_duration_seconds as i64);
+        self.pause_reason = reason;
+    }
+
+    // Lifts the pause (ended at `now`, capped at its expiry) and pushes the turn clock
+    // forward by the paused time so no player is charged for it
+    pub fn resume(&mut self, now: i64) {
+        if !self.paused_flag() {
+            return;
+        }
+        let paused_for = now.min(self.pause_expires_at).saturating_sub(self.paused_at).max(0);
+        self.turn_started_at = self.turn_started_at.saturating_add(paused_for);
+        if self.turn_deadline != 0 {
+            self.turn_deadline = self.turn_deadline.saturating_add(paused_for);
+        }
+        self.set_paused_flag(false);
+    }
+
+    // Value stored under a metadata key, if any
+    pub fn metadata(&self, key: &[u8; 8]) -> Option<[u8; 32]> {
+        if *key == [0u8; 8] {
+            return None;
+        }
+        self.metadata_keys
+            .iter()
+            .position(|k| k == key)
+            .map(|slot| self.metadata_values[slot])
+    }
+
+    // Inserts or replaces a metadata entry; an all-zero value removes it
+    pub fn set_metadata(&mut self, key: [u8; 8], value: [u8; 32]) -> Result<()> {
+        require!(key != [0u8; 8], GameError::InvalidPayload);
+        let existing = self.metadata_keys.iter().position(|k| *k == key);
+        if value == [0u8; 32] {
+            if let Some(slot) = existing {
+                self.metadata_keys[slot] = [0u8; 8];
+                self.metadata_values[slot] = [0u8; 32];
+            }
+            return Ok(());
+        }
+        let slot = existing
+            .or_else(|| self.metadata_keys.iter().position(|k| *k == [0u8; 8]))
+            .ok_or(GameError::MatchMetadataFull)?;
+        self.metadata_keys[slot] = key;
+        self.metadata_values[slot] = value;
+        Ok(())
+    }
+
+    // Whether a scheduled match can't begin play yet (false for unscheduled matches)
+    pub fn before_scheduled_start(&self, now: i64) -> bool {
+        self.scheduled_start != 0 && now < self.scheduled_start
+    }
+
+    // Whether a scheduled match missed its start window (false for unscheduled matches)
+    pub fn schedule_missed(&self, now: i64) -> bool {
+        self.scheduled_start != 0 && now >= self.scheduled_start.saturating_add(Self::SCHEDULE_GRACE_SECONDS)
+    }
+
+    // Voids the match now (admin override): ended with no winner, any pause lifted
+    pub fn force_end(&mut self, reason: u8, now: i64) {
+        self.resume(now);
+        self.end_with_outcome(EndReason::ForceEnded, Self::NO_SEAT, now);
+        self.force_end_reason = reason;
+        self.turn_deadline = 0;
+    }
+
+    // Applies a pause that expired without resume_match. Call before reading the turn clock.
+    pub fn settle_expired_pause(&mut self, now: i64) {
+        if self.paused_flag() && !self.is_paused(now) {
+            self.resume(now);
+        }
+    }
+
+    // Seconds the player on turn has used since the turn began
+    pub fn turn_elapsed(&self, now: i64) -> u32 {
+        now.saturating_sub(self.turn_started_at).clamp(0, u32::MAX as i64) as u32
+    }
+
+    // Starts the current player's turn clock and stamps its deadline: the player's remaining
+    // bank under time control, TURN_TIMEOUT_SECONDS otherwise
+    pub fn start_turn(&mut self, now: i64) {
+        let player_index = self.current_player as usize;
+        let allowance = if self.time_control() && player_index < 10 {
+            self.time_bank[player_index]
+        } else {
+            Self::TURN_TIMEOUT_SECONDS
+        };
+        self.turn_started_at = now;
+        self.turn_deadline = now.saturating_add(allowance as i64);
+    }
+
+    // True once the current turn's deadline has passed (under time control: the bank is used up)
+    pub fn current_player_out_of_time(&self, now: i64) -> bool {
+        self.turn_deadline != 0 && now >= self.turn_deadline
+    }
+
+    // Charges the elapsed turn time to player_index's bank, adds the increment and starts the
+    // next turn's clock. Returns false (bank untouched) if the bank ran out during this turn.
+    pub fn charge_turn_time(&mut self, player_index: usize, now: i64) -> bool {
+        if !self.time_control() || player_index >= 10 {
+            return true;
+        }
+        let elapsed = self.turn_elapsed(now);
+        if elapsed >= self.time_bank[player_index] {
+            return false;
+        }
+        self.time_bank[player_index] = (self.time_bank[player_index] - elapsed)
+            .saturating_add(self.time_increment);
+        self.turn_started_at = now;
+        true
+    }
+
+    pub fn draw_offer_pending(&self) -> bool {
+        self.draw_votes != 0
+    }
+
+    // Starts a new draw offer from player_index (replaces any pending offer)
+    pub fn offer_draw(&mut self, player_index: usize) {
+        if player_index < 10 {
+            self.draw_votes = 1 << player_index;
+            self.draw_offered_by = player_index as u8;
+        }
+    }
+
+    // Records player_index agreeing to the pending draw. Returns true once every seated player agreed.
+    pub fn accept_draw(&mut self, player_index: usize) -> bool {
+        if player_index < 10 {
+            self.draw_votes |= 1 << player_index;
+        }
+        let seated = (1u16 << self.player_count.min(10)) - 1;
+        self.draw_votes & seated == seated
+    }
+
+    // A turn-based move by a player who hasn't agreed declines the pending offer
+    pub fn lapse_draw_offer(&mut self, player_index: usize) {
+        if player_index < 10 && self.draw_votes & (1 << player_index) == 0 {
+            self.draw_votes = 0;
+        }
+    }
+
+    // Showdown: caller_index claims a win with a (suit, top value) run; undeclared
+    // players get window_seconds to rebut with a higher one
+    pub fn begin_showdown(&mut self, caller_index: usize, claimed_run: (u8, u8), now: i64, window_seconds: u32) {
+        self.phase = 3; // Showdown
+        self.showdown_caller = caller_index as u8;
+        (self.claimed_run_suit, self.claimed_run_top) = claimed_run;
+        self.rebuttal_deadline = now.saturating_add(window_seconds as i64);
+        self.rebuttal_responses = 0;
+    }
+
+    pub fn has_rebutted(&self, player_index: usize) -> bool {
+        player_index < 10 && self.rebuttal_responses & (1 << player_index) != 0
+    }
+
+    pub fn record_rebuttal(&mut self, player_index: usize) {
+        if player_index < 10 {
+            self.rebuttal_responses |= 1 << player_index;
+        }
+    }
+
+    // Every seated player who could rebut (undeclared) has done so
+    pub fn all_rebuttals_in(&self) -> bool {
+        (0..self.player_count as usize)
+            .all(|i| self.has_declared_suit(i) || self.has_rebutted(i))
+    }
+
+    // The showdown can be resolved: its window closed or nobody is left to respond
+    pub fn showdown_closed(&self, now: i64) -> bool {
+        self.phase == 3 && (now >= self.rebuttal_deadline || self.all_rebuttals_in())
+    }
+
+    // Ends the round that a showdown was called in (scored by end_match / start_next_round)
+    pub fn finish_showdown(&mut self, now: i64) {
+        self.phase = 2; // Ended
+        self.ended_at = now;
+    }
+
+    pub fn has_more_rounds(&self) -> bool {
+        self.round_number < self.rounds_to_play
+    }
+
+    pub fn is_bot_seat(&self, player_index: usize) -> bool {
+        player_index < 10 && self.is_bot & (1 << player_index) != 0
+    }
+
+    pub fn set_bot_seat(&mut self, player_index: usize) {
+        if player_index < 10 {
+            self.is_bot |= 1 << player_index;
+        }
+    }
+
+    pub fn has_bots(&self) -> bool {
+        self.is_bot != 0
+    }
+
+    pub fn rule_params(&self) -> RuleParams {
+        RuleParams::from_bytes(&self.rule_params)
+    }
+
+    // Whether a seat has reached the variant's point target with `round_scores` added
+    // (false when no target is set)
+    pub fn point_target_reached(&self, round_scores: &[i32; 10]) -> bool {
+        let target = self.rule_params().point_target;
+        let seated = (self.player_count as usize).min(10);
+        target != 0 && (0..seated).any(|seat| {
+            let pending = if self.round_scored() { 0 } else { round_scores[seat] };
+            self.cumulative_scores[seat].saturating_add(pending) >= target as i32
+        })
+    }
+
+    // Adds a round's scores to the cumulative totals (once per round)
+    pub fn accumulate_round_scores(&mut self, round_scores: &[i32; 10]) {
+        if self.round_scored() {
+            return;
+        }
+        for (total, score) in self.cumulative_scores.iter_mut().zip(round_scores.iter()) {
+            *total = total.saturating_add(*score);
+        }
+        self.set_round_scored(true);
+    }
+
+    // Clears per-round state (hands, floor card, declarations) for the next round.
+    // Cumulative scores and nonces are preserved.
+    pub fn reset_round_state(&mut self) {
+        self.declared_suits = [0u8; 5];
+        self.cards_dealt_total = 0;
+        self.set_floor_card_revealed(false);
+        self.clear_floor_card_hash();
+        self.hand_sizes = [0u8; 10];
+        self.last_discard_hash = [0u8; 32];
+        self.discard_pile_root = [0u8; 32];
+        self.discard_count = 0;
+        self.recent_discards = [0u8; 256];
+        self.committed_hand_hashes = [0u8; 320];
+        self.melded_cards = 0;
+        self.pot = PotLedger::default();
+        self.ready_votes = 0;
+        self.set_round_scored(false);
+    }
+
+    // Team helpers
+    pub fn get_team(&self, player_index: usize) -> u8 {
+        if player_index >= 10 {
+            return 0;
+        }
+        self.teams[player_index]
+    }
+
+    pub fn has_teams(&self) -> bool {
+        self.teams.iter().any(|&t| t != 0)
+    }
+
+    // Number of joined players assigned to a team
+    pub fn team_size(&self, team: u8) -> u8 {
+        self.teams[..self.player_count as usize].iter().filter(|&&t| t == team).count() as u8
+    }
+
+    // Team-based games need every joined player on a team and at least 2 players per team
+    pub fn teams_complete(&self) -> bool {
+        let joined = &self.teams[..self.player_count as usize];
+        joined.iter().all(|&t| t != 0 && self.team_size(t) >= 2)
+    }
+
+    // Private match helpers
+    pub fn is_private(&self) -> bool {
+        self.join_secret_hash.iter().any(|&b| b != 0)
+    }
+
+    /// Private match commitment to a join code, salted with the match account's address so a
+    /// guess only tests one match. The coordinator checks codes against it off-chain before
+    /// co-signing a join; the code itself never goes on-chain.
+    pub fn join_secret_hash(match_account: &Pubkey, secret: &[u8]) -> [u8; 32] {
+        anchor_lang::solana_program::hash::hashv(&[b"join_secret", match_account.as_ref(), secret]).to_bytes()
+    }
+
+    pub fn is_public(&self) -> bool {
+        self.visibility == Self::VISIBILITY_PUBLIC
+    }
+
     // Helper to check if match is ended
     pub fn is_ended(&self) -> bool {
         self.ended_at != 0
     }
 
+    /// Replaces a seated player's id in place (erasure). Returns true if present.
+    pub fn rename_player(&mut self, user_id: &[u8; 64], new_user_id: [u8; 64]) -> bool {
+        let seats = (self.player_count as usize).min(10);
+        let Some(slot) = self.player_ids[..seats].iter_mut().find(|id| **id == *user_id) else {
+            return false;
+        };
+        *slot = new_user_id;
+        true
+    }
+
+    // Winning seat of an ended match: the opponent of a 2-player resignation/timeout, otherwise
+    // the unique highest cumulative score. None if not ended, drawn or tied.
+    pub fn winner(&self) -> Option<u8> {
+        if self.phase != 2 || !self.is_ended() {
+            return None;
+        }
+        let player_count = self.player_count.min(10);
+        match self.get_outcome() {
+            EndReason::Draw | EndReason::ForceEnded => None,
+            EndReason::Timeout | EndReason::Resignation if player_count == 2 => {
+                Some(1 - self.outcome_player.min(1))
+            }
+            _ => {
+                let seated = &self.cumulative_scores[..player_count as usize];
+                seated.iter().copied().max().and_then(|best| {
+                    let mut leaders = seated.iter().enumerate().filter(|(_, &score)| score == best);
+                    match (leaders.next(), leaders.next()) {
+                        (Some((index, _)), None) => Some(index as u8),
+                        _ => None,
+                    }
+                })
+            }
+        }
+    }
+
     // Helper to check if match hash is set
     pub fn has_match_hash(&self) -> bool {
         self.match_hash.iter().any(|&b| b != 0)
     }
+    
+    // Replay transcript helpers (see crate::replay)
+    pub fn has_transcript(&self) -> bool {
+        self.transcript_hash.iter().any(|&b| b != 0)
+    }
+    
+    /// Starts the transcript from the match header (first round only; later rounds continue it).
+    pub fn start_transcript(&mut self) {
+        if !self.has_transcript() {
+            self.transcript_hash = replay::header_hash(&replay::MatchHeader::from_match(self));
+        }
+    }
+    
+    /// Chains the move about to be stored at move_index = move_count into the transcript.
+    pub fn record_transcript_move(&mut self, move_index: u32, player: &Pubkey, action_type: u8, payload: &[u8]) {
+        self.transcript_hash = replay::chain_move(&self.transcript_hash, move_index, player, action_type, payload);
+    }
+    
+    /// Canonical match hash for the moves recorded so far.
+    pub fn replay_match_hash(&self) -> [u8; 32] {
+        replay::finalize(&self.transcript_hash, self.move_count)
+    }
+
+    /// Updates the anti-collusion counters for a move (call before the nonce is recorded).
+    /// Raises anomaly_flags bits once a counter crosses its threshold; flags are never cleared.
+    pub fn record_move_anomalies(
+        &mut self,
+        player_index: usize,
+        signer: &Pubkey,
+        slot: u64,
+        action_type: u8,
+        nonce: u64,
+    ) {
+        if player_index >= 10 {
+            return;
+        }
+        let seat = player_index as u8;
+        
+        // Back-to-back declines between the same two seats (soft-playing a partner)
+        if action_type == 1 {
+            let previous = self.last_decline_player;
+            if previous != Self::NO_SEAT && previous != seat {
+                let pair = (previous.min(seat) << 4) | previous.max(seat);
+                if pair == self.decline_pair {
+                    self.repeated_decline_count = self.repeated_decline_count.saturating_add(1);
+                } else {
+                    self.decline_pair = pair;
+                }
+            }
+            self.last_decline_player = seat;
+        } else {
+            self.last_decline_player = Self::NO_SEAT;
+        }
+        if self.repeated_decline_count >= Self::ANOMALY_THRESHOLD {
+            self.anomaly_flags |= Self::ANOMALY_REPEATED_DECLINES;
+        }
+        
+        // Nonce gaps (moves signed out of band and withheld)
+        let last_nonce = self.get_last_nonce(player_index);
+        if last_nonce > 0 && nonce > last_nonce.saturating_add(1) {
+            self.nonce_gap_count = self.nonce_gap_count.saturating_add(1);
+            if self.nonce_gap_count >= Self::ANOMALY_THRESHOLD {
+                self.anomaly_flags |= Self::ANOMALY_NONCE_GAPS;
+            }
+        }
+        
+        // One fee payer submitting for different seats in the same slot
+        if slot == self.last_move_signer_slot
+            && *signer == self.last_move_signer
+            && seat != self.last_move_signer_player
+        {
+            self.shared_payer_count = self.shared_payer_count.saturating_add(1);
+            self.anomaly_flags |= Self::ANOMALY_SHARED_PAYER;
+        }
+        self.last_move_signer = *signer;
+        self.last_move_signer_slot = slot;
+        self.last_move_signer_player = seat;
+    }
+
+    pub fn has_anomaly(&self, flag: u8) -> bool {
+        (self.anomaly_flags & flag) != 0
+    }
+
+    /// Counts cards dealt out of the deck (a picked-up floor card).
+    pub fn record_cards_dealt(&mut self, count: u16) {
+        self.cards_dealt_total = self.cards_dealt_total.saturating_add(count);
+    }
+
+    /// Cards still in the deck this round (a declined floor card is never dealt).
+    pub fn remaining_deck(&self) -> u16 {
+        (self.deck_size as u16).saturating_sub(self.cards_dealt_total)
+    }
+
+    /// Deck accounting invariant: hands + discards + melds + remaining deck == deck_size,
+    /// and no more cards dealt than the deck holds.
+    pub fn deck_accounting_consistent(&self) -> bool {
+        self.cards_dealt_total <= self.deck_size as u16
+            && self.dealt_card_count() + self.discard_count as u16 + self.melded_card_count() + self.remaining_deck()
+                == self.deck_size as u16
+    }
+
+    /// Cards laid down in melds this round.
+    pub fn melded_card_count(&self) -> u16 {
+        self.melded_cards.count_ones() as u16
+    }
+
+    /// Records a validated meld: its cards leave the player's hand and its points are credited.
+    pub fn record_meld(&mut self, player_index: usize, card_bits: u64, card_count: u8, points: u16) {
+        if player_index >= 10 {
+            return;
+        }
+        self.melded_cards |= card_bits;
+        self.hand_sizes[player_index] = self.hand_sizes[player_index].saturating_sub(card_count);
+        self.meld_points[player_index] = self.meld_points[player_index].saturating_add(points);
+    }
+
+    /// Raises ANOMALY_DECK_DESYNC once the invariant breaks (call after each move's state change).
+    /// Like the other anomaly flags it is never cleared, so disputes can cite it.
+    pub fn check_deck_accounting(&mut self) {
+        if self.deck_size > 0 && !self.deck_accounting_consistent() {
+            self.anomaly_flags |= Self::ANOMALY_DECK_DESYNC;
+        }
+    }
 
     // Helper to get last nonce for a player
     pub fn get_last_nonce(&self, player_index: usize) -> u64 {
@@ -241,6 +1282,77 @@ impl Match {
         }
     }
 
+    // Replay protection: a nonce is accepted once, if it is above last_nonce or an unused
+    // nonce at most NONCE_WINDOW below it (concurrent submissions can land out of order)
+    pub fn is_nonce_fresh(&self, player_index: usize, nonce: u64) -> bool {
+        if player_index >= 10 || nonce == 0 {
+            return false;
+        }
+        let last_nonce = self.last_nonce[player_index];
+        if nonce > last_nonce {
+            return true;
+        }
+        let distance = last_nonce - nonce;
+        (1..=Self::NONCE_WINDOW).contains(&distance)
+            && self.nonce_windows[player_index] & (1 << (distance - 1)) == 0
+    }
+
+    // Marks a fresh nonce used; a new highest nonce slides the window up
+    pub fn record_nonce(&mut self, player_index: usize, nonce: u64) {
+        if player_index >= 10 {
+            return;
+        }
+        let last_nonce = self.last_nonce[player_index];
+        if nonce > last_nonce {
+            let shift = nonce - last_nonce;
+            let mut window = if shift >= 64 { 0 } else { self.nonce_windows[player_index] << shift };
+            // The previous last_nonce moves into the window
+            if last_nonce > 0 && shift <= Self::NONCE_WINDOW {
+                window |= 1 << (shift - 1);
+            }
+            self.nonce_windows[player_index] = window;
+            self.last_nonce[player_index] = nonce;
+        } else if (1..=Self::NONCE_WINDOW).contains(&(last_nonce - nonce)) {
+            self.nonce_windows[player_index] |= 1 << (last_nonce - nonce - 1);
+        }
+    }
+
+    // Helper to record a move against the per-slot rate limit
+    // Returns false if the player already submitted max_moves_per_slot moves in this slot
+    pub fn record_move_for_rate_limit(&mut self, player_index: usize, slot: u64, max_moves_per_slot: u8) -> bool {
+        if player_index >= 10 {
+            return false;
+        }
+        if self.last_move_slot[player_index] != slot {
+            self.last_move_slot[player_index] = slot;
+            self.slot_move_counts[player_index] = 0;
+        }
+        if max_moves_per_slot > 0 && self.slot_move_counts[player_index] >= max_moves_per_slot {
+            return false;
+        }
+        self.slot_move_counts[player_index] = self.slot_move_counts[player_index].saturating_add(1);
+        true
+    }
+
+    // Helper to record a non-turn action against its per-player cooldown
+    // Returns false if the same player took the same action less than cooldown_slots ago
+    pub fn record_action_cooldown(&mut self, player_index: usize, action_type: u8, slot: u64, cooldown_slots: u64) -> bool {
+        let action = match Self::COOLDOWN_ACTIONS.iter().position(|&a| a == action_type) {
+            Some(action) => action,
+            None => return true, // Not cooldown-limited
+        };
+        if player_index >= 10 {
+            return false;
+        }
+        let index = player_index * Self::COOLDOWN_ACTIONS.len() + action;
+        let last_slot = self.last_action_slots[index];
+        if cooldown_slots > 0 && last_slot != 0 && slot.saturating_sub(last_slot) < cooldown_slots {
+            return false;
+        }
+        self.last_action_slots[index] = slot;
+        true
+    }
+
     // Helper to get committed hand hash for a player
     pub fn get_committed_hand_hash(&self, player_index: usize) -> Option<[u8; 32]> {
         if player_index >= 10 {
@@ -298,6 +1410,55 @@ impl Match {
             self.hand_sizes[player_index] = size;
         }
     }
+
+    // Every seated player has committed a hand hash and a non-zero hand size
+    pub fn all_hands_committed(&self) -> bool {
+        (0..self.player_count as usize).all(|i| {
+            self.get_committed_hand_hash(i).is_some() && self.get_hand_size(i) > 0
+        })
+    }
+
+    // Total number of cards dealt into committed hands this round
+    pub fn dealt_card_count(&self) -> u16 {
+        self.hand_sizes[..(self.player_count as usize).min(10)]
+            .iter()
+            .map(|&size| size as u16)
+            .sum()
+    }
+    
+    // Hash of the last played/discarded card (None = nothing discarded this round)
+    pub fn get_last_discard_hash(&self) -> Option<[u8; 32]> {
+        if self.last_discard_hash.iter().all(|&b| b == 0) {
+            None
+        } else {
+            Some(self.last_discard_hash)
+        }
+    }
+    
+    pub fn set_last_discard_hash(&mut self, hash: [u8; 32]) {
+        self.last_discard_hash = hash;
+    }
+    
+    // Records a card leaving a hand: extends the hash chain and the recent-discards ring
+    pub fn record_discard(&mut self, card_hash: [u8; 32]) {
+        self.set_last_discard_hash(card_hash);
+        self.discard_pile_root = anchor_lang::solana_program::hash::hashv(&[
+            &self.discard_pile_root,
+            &card_hash,
+        ]).to_bytes();
+        let start = (self.discard_count as usize % Self::RECENT_DISCARDS) * 32;
+        self.recent_discards[start..start + 32].copy_from_slice(&card_hash);
+        self.discard_count = self.discard_count.saturating_add(1);
+    }
+    
+    // True if the card hash is among the last RECENT_DISCARDS cards discarded this round
+    pub fn was_recently_discarded(&self, card_hash: &[u8; 32]) -> bool {
+        let stored = (self.discard_count as usize).min(Self::RECENT_DISCARDS);
+        self.recent_discards
+            .chunks_exact(32)
+            .take(stored)
+            .any(|slot| slot == card_hash)
+    }
     
     // Helper to get player_id by index
     pub fn get_player_id(&self, player_index: usize) -> Option<[u8; 64]> {
@@ -307,6 +1468,11 @@ impl Match {
         Some(self.player_ids[player_index])
     }
     
+    // True if `wallet` took seat `player_index` (see player_wallets)
+    pub fn is_seat_wallet(&self, player_index: usize, wallet: &Pubkey) -> bool {
+        player_index < 10 && self.player_wallets[player_index] == *wallet
+    }
+    
     // Helper to set player_id by index
     pub fn set_player_id(&mut self, player_index: usize, user_id: [u8; 64]) {
         if player_index < 10 {
@@ -335,3 +1501,46 @@ impl Match {
     }
 }
 
+impl fmt::Display for Match {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Match {} ({}, phase {}, {} players, {} moves)",
+            self.match_id_str(),
+            self.game_name_str(),
+            self.phase,
+            self.player_count,
+            self.move_count
+        )
+    }
+}
+
+impl fmt::Debug for Match {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let players: Vec<String> = (0..self.player_count as usize)
+            .filter_map(|i| self.player_id_str(i))
+            .collect();
+        f.debug_struct("Match")
+            .field("match_id", &self.match_id_str())
+            .field("version", &self.version_str())
+            .field("game_name", &self.game_name_str())
+            .field("game_type", &self.game_type)
+            .field("seed", &self.seed)
+            .field("phase", &self.phase)
+            .field("current_player", &self.current_player)
+            .field("players", &players)
+            .field("move_count", &self.move_count)
+            .field("round_number", &self.round_number)
+            .field("rounds_to_play", &self.rounds_to_play)
+            .field("cumulative_scores", &&self.cumulative_scores[..self.player_count.min(10) as usize])
+            .field("created_at", &self.created_at)
+            .field("ended_at", &self.ended_at)
+            .field("hot_url", &self.hot_url_str())
+            .field("cold_uri", &self.cold_uri_str())
+            .field("authority", &self.authority)
+            .field("flags", &format_args!("{:#010b}", self.flags))
+            .field("anomaly_flags", &format_args!("{:#010b}", self.anomaly_flags))
+            .field("outcome", &self.get_outcome())
+            .finish()
+    }
+}
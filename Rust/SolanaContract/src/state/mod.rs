@@ -1,3 +1,4 @@
+pub mod fixed_str; // Null-padded byte field -> String helper
 pub mod match_state;
 pub mod move_state;
 pub mod game_config;
@@ -9,7 +10,31 @@ pub mod user_account; // Per spec Section 20: Economic model - UserAccount
 pub mod config_account; // Per spec Section 20: Economic model - ConfigAccount
 pub mod game_leaderboard; // Per spec Section 20.1.6: Leaderboard system
 pub mod game_registry; // Per spec Section 16.5: Game registry system
+pub mod credit_adjustment; // Support AC grant/refund audit trail
+pub mod usage_meter; // Per-model AI usage metering per billing period
+pub mod deck_commitment; // Shuffled deck Merkle commitment per match
+pub mod match_queue; // Open matchmaking queue per game type
+pub mod player_rating; // Per-game-type ELO skill rating
+pub mod sanction; // On-chain ban/suspension registry
+pub mod promo_code; // Promo/coupon codes with redemption caps
+pub mod trophy; // Soulbound winner trophy NFT record
+pub mod cosmetics; // Cosmetic item registry and per-user equipped items
+pub mod guild; // Guilds/clans with season scores
+pub mod invitation; // Direct invites to (private) matches
+pub mod replay_checkpoint; // Segmented on-chain replay verification progress
+pub mod global_stats; // Protocol-wide analytics counters
+pub mod coordinator_set; // Active coordinator instances for failover
+pub mod scrabble_board; // On-chain Scrabble board, racks and tile bag
+pub mod word_search; // WordSearch grid + word list commitment and found words
+pub mod crossword; // Crossword answer commitment and solved clues
+pub mod pot_ledger; // Pot, side pots and rake for games with has_betting (embedded in Match)
+pub mod series; // Best-of-N series over the same roster
+pub mod ai_move_attestation; // Which AI model produced a move, linked to its AC consumption
+pub mod payment_receipt; // Redeemed Stripe payment attestations and applied refunds/chargebacks
+pub mod open_lobby_index; // Joinable public matches per game type
+pub mod match_creator; // Per-authority counter behind deterministic match ids
 
+pub use fixed_str::*;
 pub use match_state::*;
 pub use move_state::*;
 pub use game_config::*;
@@ -21,4 +46,27 @@ pub use user_account::*;
 pub use config_account::*;
 pub use game_leaderboard::*;
 pub use game_registry::*;
+pub use credit_adjustment::*;
+pub use usage_meter::*;
+pub use deck_commitment::*;
+pub use match_queue::*;
+pub use player_rating::*;
+pub use sanction::*;
+pub use promo_code::*;
+pub use trophy::*;
+pub use cosmetics::*;
+pub use guild::*;
+pub use invitation::*;
+pub use replay_checkpoint::*;
+pub use global_stats::*;
+pub use coordinator_set::*;
+pub use scrabble_board::*;
+pub use word_search::*;
+pub use crossword::*;
+pub use pot_ledger::*;
+pub use series::*;
+pub use ai_move_attestation::*;
+pub use payment_receipt::*;
+pub use open_lobby_index::*;
+pub use match_creator::*;
 
@@ -1,13 +1,26 @@
 use anchor_lang::prelude::*;
 use crate::error::GameError;
+use crate::state::fixed_str::fixed_bytes_to_string;
+use std::fmt;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
 pub enum ActionType {
     PickUp = 0,
     Decline = 1,
     DeclareIntent = 2,
     CallShowdown = 3,
     Rebuttal = 4,
+    PlayCard = 5,
+    Discard = 6,
+    Resign = 7,
+    OfferDraw = 8,
+    AcceptDraw = 9,
+    DeclareMeld = 10,
+    Blind = 11,
+    See = 12,
+    Bet = 13,
+    Fold = 14,
+    Show = 15,
 }
 
 #[account]
@@ -19,6 +32,8 @@ pub struct Move {
     pub payload: [u8; 128],       // Fixed-size payload (saves 4 bytes vs Vec, reduced from 256 to 128)
     pub payload_len: u8,          // Actual payload length (0-128)
     pub timestamp: i64,           // Unix timestamp
+    pub rent_payer: Pubkey,       // Paid this account's rent (the player, or the relaying coordinator); refunded by compact_match_moves
+    pub envelope_hash: [u8; 32],  // SignedMoveEnvelope::hash of the player-signed envelope (all zeros = not signed)
 }
 
 impl Move {
@@ -29,22 +44,33 @@ impl Move {
         1 +                              // action_type (u8)
         128 +                            // payload (fixed [u8; 128])
         1 +                              // payload_len (u8)
-        8;                               // timestamp (i64)
+        8 +                              // timestamp (i64)
+        32 +                             // rent_payer (Pubkey)
+        32;                              // envelope_hash ([u8; 32])
     
-    // Total: 8 + 36 + 32 + 4 + 1 + 128 + 1 + 8 = 218 bytes
+    // Total: 8 + 36 + 32 + 4 + 1 + 128 + 1 + 8 + 32 + 32 = 282 bytes
     // Previous: ~350 bytes (saved ~130 bytes)
     
+    pub fn match_id_str(&self) -> String {
+        fixed_bytes_to_string(&self.match_id)
+    }
+    
     pub fn get_payload_slice(&self) -> &[u8] {
         &self.payload[..self.payload_len as usize]
     }
     
     pub fn set_payload(&mut self, data: &[u8]) -> Result<()> {
-        require!(data.len() <= 128, GameError::InvalidPayload);
+        require!(data.len() <= 128, GameError::PayloadTooLarge);
         self.payload[..data.len()].copy_from_slice(data);
         self.payload_len = data.len() as u8;
         Ok(())
     }
 
+    /// True when the move was submitted with a player-signed envelope (dispute evidence).
+    pub fn has_envelope_hash(&self) -> bool {
+        self.envelope_hash.iter().any(|&b| b != 0)
+    }
+
     pub fn get_action_type(&self) -> ActionType {
         match self.action_type {
             0 => ActionType::PickUp,
@@ -52,8 +78,47 @@ impl Move {
             2 => ActionType::DeclareIntent,
             3 => ActionType::CallShowdown,
             4 => ActionType::Rebuttal,
+            5 => ActionType::PlayCard,
+            6 => ActionType::Discard,
+            7 => ActionType::Resign,
+            8 => ActionType::OfferDraw,
+            9 => ActionType::AcceptDraw,
+            10 => ActionType::DeclareMeld,
+            11 => ActionType::Blind,
+            12 => ActionType::See,
+            13 => ActionType::Bet,
+            14 => ActionType::Fold,
+            15 => ActionType::Show,
             _ => ActionType::PickUp, // Default fallback
         }
     }
 }
 
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Move #{} in {} ({:?} by {}, {} byte payload)",
+            self.move_index,
+            self.match_id_str(),
+            self.get_action_type(),
+            self.player,
+            self.payload_len
+        )
+    }
+}
+
+impl fmt::Debug for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Move")
+            .field("match_id", &self.match_id_str())
+            .field("player", &self.player)
+            .field("move_index", &self.move_index)
+            .field("action_type", &self.get_action_type())
+            .field("payload", &self.get_payload_slice())
+            .field("timestamp", &self.timestamp)
+            .field("rent_payer", &self.rent_payer)
+            .field("signed", &self.has_envelope_hash())
+            .finish()
+    }
+}
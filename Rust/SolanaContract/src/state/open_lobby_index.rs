@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::error::GameError;
+
+/// LobbyEntry is one listed match waiting for players.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct LobbyEntry {
+    pub match_id: [u8; 36],               // UUID v4 (fixed 36 bytes)
+    pub listed_at: i64,                   // Unix timestamp
+}
+
+impl LobbyEntry {
+    pub const SIZE: usize = 36 + 8; // 44 bytes per entry
+}
+
+/// OpenLobbyIndex lists the joinable public matches of one game type
+/// (seeds: ["open_lobbies", game_type]).
+/// create_match lists a public match, join_match delists it once it's full and start_match
+/// once play begins. Entries are in listing order; when the index is full, the oldest entry
+/// is evicted if it has been listed for STALE_AFTER_SECONDS (an abandoned lobby), otherwise
+/// the listing fails. Clients should still check each match's phase before joining.
+#[account]
+pub struct OpenLobbyIndex {
+    pub game_type: u8,                    // GameType as u8
+    pub entry_count: u8,                  // Number of listed matches (0-32)
+    pub entries: [LobbyEntry; 32],        // Oldest first (fixed array)
+    pub last_updated: i64,                // Last update timestamp
+}
+
+impl OpenLobbyIndex {
+    pub const MAX_ENTRIES: usize = 32;
+    pub const STALE_AFTER_SECONDS: i64 = 3600; // 1 hour
+    
+    pub const MAX_SIZE: usize = 8 +        // discriminator
+        1 +                                 // game_type (u8)
+        1 +                                 // entry_count (u8)
+        (LobbyEntry::SIZE * 32) +          // entries ([LobbyEntry; 32] = 1408 bytes)
+        8;                                  // last_updated (i64)
+    
+    // Total: 8 + 1 + 1 + 1408 + 8 = 1426 bytes
+    
+    pub fn find_entry(&self, match_id: &[u8; 36]) -> Option<usize> {
+        self.entries[..self.entry_count as usize]
+            .iter()
+            .position(|e| e.match_id == *match_id)
+    }
+    
+    /// Lists a match, evicting the oldest entry if the index is full and it is stale.
+    /// Returns the evicted match_id, if any.
+    pub fn list(&mut self, match_id: [u8; 36], now: i64) -> Result<Option<[u8; 36]>> {
+        let mut evicted = None;
+        if self.entry_count as usize == Self::MAX_ENTRIES {
+            require!(
+                now - self.entries[0].listed_at >= Self::STALE_AFTER_SECONDS,
+                GameError::LobbyIndexFull
+            );
+            evicted = Some(self.remove_at(0).match_id);
+        }
+        if self.find_entry(&match_id).is_none() {
+            self.entries[self.entry_count as usize] = LobbyEntry { match_id, listed_at: now };
+            self.entry_count += 1;
+        }
+        self.last_updated = now;
+        Ok(evicted)
+    }
+    
+    /// Removes a match if it's listed (it may already have been evicted).
+    pub fn delist(&mut self, match_id: &[u8; 36], now: i64) -> bool {
+        match self.find_entry(match_id) {
+            Some(index) => {
+                self.remove_at(index);
+                self.last_updated = now;
+                true
+            }
+            None => false,
+        }
+    }
+    
+    /// Removes the entry at `index`, preserving listing order of the remaining entries.
+    pub fn remove_at(&mut self, index: usize) -> LobbyEntry {
+        let count = self.entry_count as usize;
+        let removed = self.entries[index];
+        for i in index..count - 1 {
+            self.entries[i] = self.entries[i + 1];
+        }
+        self.entries[count - 1] = LobbyEntry {
+            match_id: [0u8; 36],
+            listed_at: 0,
+        };
+        self.entry_count -= 1;
+        removed
+    }
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+/// PaymentReceipt records one redeemed Stripe payment attestation
+/// (seeds: ["payment_receipt", payment_intent_hash]).
+/// Created by the purchase that redeems it, so the same receipt can never grant twice.
+#[account]
+pub struct PaymentReceipt {
+    pub payment_intent_hash: [u8; 32], // SHA-256 of the Stripe PaymentIntent id
+    pub user_id: [u8; 64],             // Firebase UID (fixed 64 bytes, null-padded)
+    pub product: u8,                   // payment::PaymentProduct as u8
+    pub amount: u64,                   // Days or AC, by product
+    pub attestor: Pubkey,              // Coordinator that signed the receipt
+    pub created_at: i64,               // Unix timestamp
+    pub amount_reversed: u64,          // Refunded or charged back so far (<= amount)
+}
+
+impl PaymentReceipt {
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        32 +                             // payment_intent_hash ([u8; 32])
+        64 +                             // user_id (fixed [u8; 64])
+        1 +                              // product (u8)
+        8 +                              // amount (u64)
+        32 +                             // attestor (Pubkey)
+        8 +                              // created_at (i64)
+        8;                               // amount_reversed (u64)
+    
+    // Total: 8 + 32 + 64 + 1 + 8 + 32 + 8 + 8 = 161 bytes
+}
+
+/// PaymentReversal records one Stripe refund or chargeback applied on-chain
+/// (seeds: ["payment_reversal", reference_hash]), so the same clawback is never applied twice.
+#[account]
+pub struct PaymentReversal {
+    pub reference_hash: [u8; 32],      // SHA-256 of the Stripe refund or dispute id
+    pub user_id: [u8; 64],             // Firebase UID (fixed 64 bytes, null-padded)
+    pub product: u8,                   // payment::PaymentProduct as u8
+    pub amount: u64,                   // Days or AC rolled back, by product
+    pub chargeback: bool,              // false = refund
+    pub authority: Pubkey,             // Authority that applied it
+    pub created_at: i64,               // Unix timestamp
+}
+
+impl PaymentReversal {
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        32 +                             // reference_hash ([u8; 32])
+        64 +                             // user_id (fixed [u8; 64])
+        1 +                              // product (u8)
+        8 +                              // amount (u64)
+        1 +                              // chargeback (bool)
+        32 +                             // authority (Pubkey)
+        8;                               // created_at (i64)
+    
+    // Total: 8 + 32 + 64 + 1 + 8 + 1 + 32 + 8 = 154 bytes
+}
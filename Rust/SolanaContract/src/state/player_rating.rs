@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+
+/// PlayerRating tracks a player's ELO skill rating per game type.
+/// Companion PDA to UserAccount (seeds: ["player_rating", user_id]).
+/// Matches with an AI/bot seat (Match::is_bot) are rated on a separate bot ladder
+/// (`bot_ladder` = true below), so playing bots never moves the human rating.
+#[account]
+pub struct PlayerRating {
+    pub user_id: [u8; 64],                // Firebase UID (fixed 64 bytes, null-padded)
+    pub ratings: [u16; 8],                // Current ELO rating per GameType (index = game_type)
+    pub peak_ratings: [u16; 8],           // Highest rating reached per GameType
+    pub games_rated: [u32; 8],            // Rated matches played per GameType
+    pub created_at: i64,                  // Unix timestamp
+    pub last_updated: i64,                // Last rating change timestamp
+    pub bot_ratings: [u16; 8],            // Bot ladder rating per GameType (matches with bots)
+    pub bot_games_rated: [u32; 8],        // Bot ladder matches played per GameType
+}
+
+impl PlayerRating {
+    pub const INITIAL_RATING: u16 = 1200;
+    pub const MIN_RATING: u16 = 100;
+    pub const MAX_RATING: u16 = 3000;
+    pub const K_FACTOR: i32 = 32;          // Max rating change per opponent
+    pub const PROVISIONAL_K_FACTOR: i32 = 48; // Used for a player's first PROVISIONAL_GAMES in a game type
+    pub const PROVISIONAL_GAMES: u32 = 10;
+
+    pub const MAX_SIZE: usize = 8 +        // discriminator
+        64 +                                // user_id ([u8; 64])
+        (2 * 8) +                           // ratings ([u16; 8])
+        (2 * 8) +                           // peak_ratings ([u16; 8])
+        (4 * 8) +                           // games_rated ([u32; 8])
+        8 +                                 // created_at (i64)
+        8 +                                 // last_updated (i64)
+        (2 * 8) +                           // bot_ratings ([u16; 8])
+        (4 * 8);                            // bot_games_rated ([u32; 8])
+    
+    // Total: 8 + 64 + 16 + 16 + 32 + 8 + 8 + 16 + 32 = 200 bytes
+
+    pub fn initialize(&mut self, user_id: [u8; 64], created_at: i64) {
+        self.user_id = user_id;
+        self.ratings = [Self::INITIAL_RATING; 8];
+        self.peak_ratings = [Self::INITIAL_RATING; 8];
+        self.games_rated = [0u32; 8];
+        self.bot_ratings = [Self::INITIAL_RATING; 8];
+        self.bot_games_rated = [0u32; 8];
+        self.created_at = created_at;
+        self.last_updated = created_at;
+    }
+
+    pub fn get_rating(&self, game_type: u8, bot_ladder: bool) -> u16 {
+        let ratings = if bot_ladder { &self.bot_ratings } else { &self.ratings };
+        ratings
+            .get(game_type as usize)
+            .copied()
+            .unwrap_or(Self::INITIAL_RATING)
+    }
+
+    pub fn k_factor(&self, game_type: u8, bot_ladder: bool) -> i32 {
+        let games_rated = if bot_ladder { &self.bot_games_rated } else { &self.games_rated };
+        let games = games_rated.get(game_type as usize).copied().unwrap_or(0);
+        if games < Self::PROVISIONAL_GAMES {
+            Self::PROVISIONAL_K_FACTOR
+        } else {
+            Self::K_FACTOR
+        }
+    }
+
+    /// Applies a rating delta for one rated match, clamped to [MIN_RATING, MAX_RATING].
+    /// Peak ratings track the human ladder only.
+    pub fn apply_rating_change(&mut self, game_type: u8, delta: i32, timestamp: i64, bot_ladder: bool) {
+        let index = game_type as usize;
+        if index >= self.ratings.len() {
+            return;
+        }
+        let new_rating = (self.get_rating(game_type, bot_ladder) as i32 + delta)
+            .clamp(Self::MIN_RATING as i32, Self::MAX_RATING as i32) as u16;
+        if bot_ladder {
+            self.bot_ratings[index] = new_rating;
+            self.bot_games_rated[index] = self.bot_games_rated[index].saturating_add(1);
+        } else {
+            self.ratings[index] = new_rating;
+            self.peak_ratings[index] = self.peak_ratings[index].max(new_rating);
+            self.games_rated[index] = self.games_rated[index].saturating_add(1);
+        }
+        self.last_updated = timestamp;
+    }
+}
+
+// Expected score (per mille) for a rating advantage of 0, 25, 50, ... 800 points.
+// 1000 / (1 + 10^(-diff / 400)), precomputed so no floating point is needed on-chain.
+const EXPECTED_SCORE_TABLE: [i32; 33] = [
+    500, 536, 571, 606, 640, 673, 703, 733, 760, 785, 808, 830, 849, 867, 882, 896, 909,
+    920, 930, 939, 947, 954, 960, 965, 969, 973, 977, 980, 983, 985, 987, 989, 990,
+];
+
+/// ELO expected score (0-1000) for a player rated `rating` against `opponent_rating`.
+/// Linearly interpolates the lookup table; differences beyond 800 points are capped.
+pub fn expected_score_permille(rating: u16, opponent_rating: u16) -> i32 {
+    let diff = rating as i32 - opponent_rating as i32;
+    let abs_diff = diff.abs().min(800);
+    let bucket = (abs_diff / 25) as usize;
+    let remainder = abs_diff % 25;
+    let low = EXPECTED_SCORE_TABLE[bucket];
+    let high = EXPECTED_SCORE_TABLE[(bucket + 1).min(32)];
+    let expected = low + (high - low) * remainder / 25;
+    if diff >= 0 {
+        expected
+    } else {
+        1000 - expected
+    }
+}
+
+/// Multi-player ELO: each player is scored pairwise against every opponent
+/// (win = 1000, draw = 500, loss = 0 per mille) and the summed change is
+/// averaged over the number of opponents.
+pub fn compute_rating_deltas(
+    ratings: &[u16],
+    scores: &[i32],
+    k_factors: &[i32],
+) -> Vec<i32> {
+    let player_count = ratings.len();
+    let mut deltas = vec![0i32; player_count];
+    if player_count < 2 {
+        return deltas;
+    }
+    for i in 0..player_count {
+        let mut total = 0i32;
+        for j in 0..player_count {
+            if i == j {
+                continue;
+            }
+            let actual = match scores[i].cmp(&scores[j]) {
+                std::cmp::Ordering::Greater => 1000,
+                std::cmp::Ordering::Equal => 500,
+                std::cmp::Ordering::Less => 0,
+            };
+            let expected = expected_score_permille(ratings[i], ratings[j]);
+            total += k_factors[i] * (actual - expected);
+        }
+        deltas[i] = total / (1000 * (player_count as i32 - 1));
+    }
+    deltas
+}
@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+
+/// One layer of the pot: the chips in it and the seats that can win it.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SidePot {
+    pub amount: u64,
+    pub eligible: u16, // Seat bitmask
+}
+
+/// PotLedger tracks the chips staked in the current hand of a betting game (any game whose
+/// GameDefinition has `has_betting`: Three Card Brag, Poker), embedded in the Match.
+///
+/// Contributions are per seat. A seat still in the hand that has put in less than others
+/// (all-in) only competes for the chips up to its own contribution: side_pots() splits the
+/// pot into layers at each such level. settle() pays each layer, minus the game's rake, to the
+/// best hand_rank among its eligible seats. Chips are the game's betting units and are only
+/// accounted here; any GP or wager settlement happens off the payouts once the match ends.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, Default)]
+pub struct PotLedger {
+    pub contributions: [u64; 10],   // Per seat, this hand
+    pub stake: u64,                 // Current stake (Brag: in blind units, seen players bet double)
+    pub folded: u16,                // Seat bitmask: out of the hand
+    pub seen: u16,                  // Seat bitmask: looked at their cards (Brag)
+    pub revealed: u16,              // Seat bitmask: hand shown and checked against the commitment
+    pub hand_ranks: [u32; 10],      // Per seat, higher wins (set on reveal)
+    pub payouts: [u64; 10],         // Per seat, filled by settle()
+    pub rake: u64,                  // Taken by settle() (GameDefinition.rake_bps of each layer)
+    pub settled: bool,
+}
+
+impl PotLedger {
+    pub const SIZE: usize =
+        (8 * 10) +                       // contributions ([u64; 10] = 80 bytes)
+        8 +                              // stake (u64)
+        2 +                              // folded (u16 bitmask)
+        2 +                              // seen (u16 bitmask)
+        2 +                              // revealed (u16 bitmask)
+        (4 * 10) +                       // hand_ranks ([u32; 10] = 40 bytes)
+        (8 * 10) +                       // payouts ([u64; 10] = 80 bytes)
+        8 +                              // rake (u64)
+        1;                               // settled (bool)
+
+    // Total: 80 + 8 + 2 + 2 + 2 + 40 + 80 + 8 + 1 = 223 bytes
+
+    pub const MAX_RAKE_BPS: u16 = 1000; // 10%
+
+    /// Chips in the pot this hand.
+    pub fn total(&self) -> u64 {
+        self.contributions.iter().fold(0u64, |sum, &c| sum.saturating_add(c))
+    }
+
+    /// Adds a seat's chips to the pot.
+    pub fn contribute(&mut self, seat: usize, amount: u64) {
+        if seat < 10 {
+            self.contributions[seat] = self.contributions[seat].saturating_add(amount);
+        }
+    }
+
+    pub fn is_folded(&self, seat: usize) -> bool {
+        seat < 10 && self.folded & (1 << seat) != 0
+    }
+
+    pub fn fold(&mut self, seat: usize) {
+        if seat < 10 {
+            self.folded |= 1 << seat;
+        }
+    }
+
+    pub fn has_seen(&self, seat: usize) -> bool {
+        seat < 10 && self.seen & (1 << seat) != 0
+    }
+
+    pub fn see(&mut self, seat: usize) {
+        if seat < 10 {
+            self.seen |= 1 << seat;
+        }
+    }
+
+    pub fn is_revealed(&self, seat: usize) -> bool {
+        seat < 10 && self.revealed & (1 << seat) != 0
+    }
+
+    pub fn reveal(&mut self, seat: usize, hand_rank: u32) {
+        if seat < 10 {
+            self.revealed |= 1 << seat;
+            self.hand_ranks[seat] = hand_rank;
+        }
+    }
+
+    /// Seats still in the hand, in seat order.
+    pub fn active_seats(&self, player_count: u8) -> impl Iterator<Item = usize> + '_ {
+        (0..(player_count as usize).min(10)).filter(move |&seat| !self.is_folded(seat))
+    }
+
+    /// Next seat after `seat` still in the hand (`seat` itself if nobody else is).
+    pub fn next_active(&self, seat: usize, player_count: u8) -> usize {
+        let count = (player_count as usize).clamp(1, 10);
+        (1..=count)
+            .map(|step| (seat + step) % count)
+            .find(|&next| !self.is_folded(next))
+            .unwrap_or(seat)
+    }
+
+    /// The pot split into layers, lowest first: one per distinct contribution of the seats
+    /// still in the hand. Folded seats' chips fill the layers but can't win them; chips above
+    /// the highest active contribution go to the top layer.
+    pub fn side_pots(&self, player_count: u8) -> Vec<SidePot> {
+        let seats = (player_count as usize).min(10);
+        let mut levels: Vec<u64> = self.active_seats(player_count).map(|seat| self.contributions[seat]).collect();
+        levels.sort_unstable();
+        levels.dedup();
+
+        let mut pots = Vec::with_capacity(levels.len());
+        let mut floor = 0u64;
+        for (i, &level) in levels.iter().enumerate() {
+            let ceiling = if i + 1 == levels.len() { u64::MAX } else { level };
+            let amount = self.contributions[..seats]
+                .iter()
+                .map(|&c| c.min(ceiling).saturating_sub(floor))
+                .fold(0u64, |sum, c| sum.saturating_add(c));
+            let eligible = self
+                .active_seats(player_count)
+                .filter(|&seat| self.contributions[seat] >= level)
+                .fold(0u16, |mask, seat| mask | 1 << seat);
+            if amount > 0 {
+                pots.push(SidePot { amount, eligible });
+            }
+            floor = level;
+        }
+        pots
+    }
+
+    /// Pays out the pot: each layer, less rake_bps of it, goes to its eligible seat with the
+    /// highest hand_rank, split evenly on a tie (odd chips to the lowest seat). If every seat
+    /// has folded nobody can win, so each gets its own contribution back, unraked.
+    /// Settling twice is a no-op.
+    pub fn settle(&mut self, player_count: u8, rake_bps: u16) {
+        if self.settled {
+            return;
+        }
+        if self.active_seats(player_count).next().is_none() {
+            let seats = (player_count as usize).min(10);
+            self.payouts[..seats].copy_from_slice(&self.contributions[..seats]);
+            self.settled = true;
+            return;
+        }
+        let rake_bps = rake_bps.min(Self::MAX_RAKE_BPS) as u128;
+        for pot in self.side_pots(player_count) {
+            let rake = (pot.amount as u128 * rake_bps / 10_000) as u64;
+            let prize = pot.amount - rake;
+            let eligible = (0..10usize).filter(|&seat| pot.eligible & (1 << seat) != 0);
+            let best = eligible.clone().map(|seat| self.hand_ranks[seat]).max().unwrap_or(0);
+            let winners: Vec<usize> = eligible.filter(|&seat| self.hand_ranks[seat] == best).collect();
+            let share = prize / winners.len() as u64;
+            for (i, &seat) in winners.iter().enumerate() {
+                let odd_chips = if i == 0 { prize % winners.len() as u64 } else { 0 };
+                self.payouts[seat] = self.payouts[seat].saturating_add(share + odd_chips);
+            }
+            self.rake = self.rake.saturating_add(rake);
+        }
+        self.settled = true;
+    }
+}
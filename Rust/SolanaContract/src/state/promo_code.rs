@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+/// PromoCode is an on-chain promo/coupon definition.
+/// One PDA per promo, keyed by promo_index (0..MAX_PROMOS). The code itself is never stored,
+/// only its SHA-256; redeem_promo hashes the submitted code and compares.
+/// redemption_count is the on-chain record used to audit over-issuance.
+#[account]
+pub struct PromoCode {
+    pub promo_index: u16,             // Bit index in UserAccount.promo_redemptions
+    pub code_hash: [u8; 32],          // SHA-256 of the promo code
+    pub gp_amount: u64,               // GP granted per redemption
+    pub ac_amount: u64,               // AC granted per redemption
+    pub max_redemptions: u32,         // Total redemptions allowed across all users
+    pub redemption_count: u32,        // Redemptions so far
+    pub expires_at: i64,              // Unix timestamp (0 = never expires)
+    pub authority: Pubkey,            // Admin that created the promo
+    pub created_at: i64,              // Unix timestamp
+}
+
+impl PromoCode {
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        2 +                              // promo_index (u16)
+        32 +                             // code_hash ([u8; 32])
+        8 +                              // gp_amount (u64)
+        8 +                              // ac_amount (u64)
+        4 +                              // max_redemptions (u32)
+        4 +                              // redemption_count (u32)
+        8 +                              // expires_at (i64)
+        32 +                             // authority (Pubkey)
+        8;                               // created_at (i64)
+    
+    // Total: 8 + 2 + 32 + 8 + 8 + 4 + 4 + 8 + 32 + 8 = 114 bytes
+    
+    /// Promo indexes fit the 128-byte per-user redemption bitmap.
+    pub const MAX_PROMOS: u16 = 1024;
+    
+    pub fn is_expired(&self, clock: &Clock) -> bool {
+        self.expires_at != 0 && clock.unix_timestamp >= self.expires_at
+    }
+    
+    pub fn is_exhausted(&self) -> bool {
+        self.redemption_count >= self.max_redemptions
+    }
+    
+    pub fn matches_code(&self, code: &[u8]) -> bool {
+        anchor_lang::solana_program::hash::hash(code).to_bytes() == self.code_hash
+    }
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+/// ReplayCheckpoint tracks on-chain verification of a match transcript in bounded segments.
+/// Seeds: ["replay_checkpoint", match]. Each verify_replay_segment call continues from
+/// next_index with the running transcript hash (see crate::replay), so a dispute can be
+/// adjudicated move by move without the full history in one transaction.
+#[account]
+pub struct ReplayCheckpoint {
+    pub match_account: Pubkey,          // Match PDA being verified
+    pub verifier: Pubkey,               // Validator that submitted the latest segment
+    pub next_index: u32,                // First move not yet verified
+    pub transcript_hash: [u8; 32],      // Running transcript hash after move next_index - 1
+    pub invalid_move_index: u32,        // First move that failed rule checks (NO_INVALID_MOVE = none)
+    pub complete: bool,                 // All moves verified and the final hash matched match_hash
+    pub updated_at: i64,                // Unix timestamp
+}
+
+impl ReplayCheckpoint {
+    pub const NO_INVALID_MOVE: u32 = u32::MAX;
+    
+    /// Moves per verify_replay_segment call (keeps each segment within compute limits).
+    pub const MAX_SEGMENT_MOVES: usize = 16;
+    
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        32 +                             // match_account (Pubkey)
+        32 +                             // verifier (Pubkey)
+        4 +                              // next_index (u32)
+        32 +                             // transcript_hash ([u8; 32])
+        4 +                              // invalid_move_index (u32)
+        1 +                              // complete (bool)
+        8;                               // updated_at (i64)
+    
+    // Total: 8 + 32 + 32 + 4 + 32 + 4 + 1 + 8 = 121 bytes
+    
+    pub fn has_invalid_move(&self) -> bool {
+        self.invalid_move_index != Self::NO_INVALID_MOVE
+    }
+}
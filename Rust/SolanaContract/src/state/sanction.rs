@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use crate::error::GameError;
+use crate::state::user_id_seed;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum SanctionKind {
+    Warning = 0,      // Recorded only, does not block play
+    Suspension = 1,   // Blocks play until expires_at
+    Ban = 2,          // Blocks play until lifted
+}
+
+/// Sanction is the on-chain ban/suspension record for one user (seeds: ["sanction", user_id]).
+/// Reissuing overwrites the previous sanction; lifted or expired sanctions no longer block play.
+#[account]
+pub struct Sanction {
+    pub user_id: [u8; 64],                // Firebase UID (fixed 64 bytes, null-padded)
+    pub kind: u8,                         // SanctionKind as u8
+    pub reason: u8,                       // DisputeReason as u8
+    pub dispute: Pubkey,                  // Dispute that triggered the sanction (default = issued manually)
+    pub issued_by: Pubkey,                // Authority or resolving validator
+    pub issued_at: i64,                   // Unix timestamp
+    pub expires_at: i64,                  // 0 = no expiry
+    pub lifted_at: i64,                   // 0 = not lifted
+    pub lifted_by: Pubkey,                // Authority that lifted the sanction
+}
+
+impl Sanction {
+    pub const MAX_SIZE: usize = 8 +        // discriminator
+        64 +                                // user_id ([u8; 64])
+        1 +                                 // kind (u8)
+        1 +                                 // reason (u8)
+        32 +                                // dispute (Pubkey)
+        32 +                                // issued_by (Pubkey)
+        8 +                                 // issued_at (i64)
+        8 +                                 // expires_at (i64)
+        8 +                                 // lifted_at (i64)
+        32;                                 // lifted_by (Pubkey)
+    
+    // Total: 8 + 64 + 1 + 1 + 32 + 32 + 8 + 8 + 8 + 32 = 194 bytes
+    
+    pub fn get_kind(&self) -> SanctionKind {
+        match self.kind {
+            0 => SanctionKind::Warning,
+            1 => SanctionKind::Suspension,
+            _ => SanctionKind::Ban,
+        }
+    }
+    
+    pub fn is_lifted(&self) -> bool {
+        self.lifted_at != 0
+    }
+    
+    pub fn is_expired(&self, clock: &Clock) -> bool {
+        self.expires_at != 0 && clock.unix_timestamp >= self.expires_at
+    }
+    
+    /// Records a new sanction, replacing the previous one.
+    pub fn issue(
+        &mut self,
+        kind: SanctionKind,
+        reason: u8,
+        dispute: Pubkey,
+        issued_by: Pubkey,
+        issued_at: i64,
+        expires_at: i64,
+    ) {
+        self.kind = kind as u8;
+        self.reason = reason;
+        self.dispute = dispute;
+        self.issued_by = issued_by;
+        self.issued_at = issued_at;
+        self.expires_at = expires_at;
+        self.lifted_at = 0;
+        self.lifted_by = Pubkey::default();
+    }
+    
+    /// Whether the sanction currently blocks the user from playing and claiming rewards.
+    pub fn is_active(&self, clock: &Clock) -> bool {
+        self.get_kind() != SanctionKind::Warning && !self.is_lifted() && !self.is_expired(clock)
+    }
+    
+    /// Whether the sanction in effect is at least `kind` until at least `expires_at` (0 = no expiry),
+    /// i.e. issuing that sanction would weaken or shorten it.
+    pub fn covers(&self, kind: SanctionKind, expires_at: i64, clock: &Clock) -> bool {
+        if !self.is_active(clock) {
+            return false;
+        }
+        let (current, requested) = (self.kind, kind as u8);
+        current > requested
+            || (current == requested && (self.expires_at == 0 || (expires_at != 0 && self.expires_at >= expires_at)))
+    }
+}
+
+/// Rejects users with an active sanction.
+/// `sanction_info` is the user's ["sanction", user_id] PDA, which may not exist (never sanctioned).
+pub fn require_not_sanctioned(sanction_info: &AccountInfo, clock: &Clock) -> Result<()> {
+    require!(!is_sanctioned(sanction_info, clock)?, GameError::UserSanctioned);
+    Ok(())
+}
+
+/// Whether the user's ["sanction", user_id] PDA (which may not exist) holds an active sanction.
+pub fn is_sanctioned(sanction_info: &AccountInfo, clock: &Clock) -> Result<bool> {
+    if sanction_info.owner != &crate::ID || sanction_info.data_is_empty() {
+        return Ok(false);
+    }
+    let data = sanction_info.try_borrow_data()?;
+    let sanction = Sanction::try_deserialize(&mut &data[..])?;
+    Ok(sanction.is_active(clock))
+}
+
+/// Checks that a sanction account passed in remaining accounts (where Anchor can't derive it)
+/// is `user_id`'s ["sanction", user_id] PDA.
+pub fn require_sanction_pda(sanction_info: &AccountInfo, user_id: &[u8; 64]) -> Result<()> {
+    let (expected, _) = Pubkey::find_program_address(&[b"sanction", user_id_seed(user_id)], &crate::ID);
+    require!(sanction_info.key() == expected, GameError::InvalidPayload);
+    Ok(())
+}
@@ -0,0 +1,268 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash;
+use crate::error::GameError;
+
+/// One tile dealt from the committed bag: the tile and the commitment to the rest of the bag.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct TileReveal {
+    pub tile: u8,            // 1-26 = A-Z, ScrabbleBoard::BLANK = blank
+    pub next_head: [u8; 32], // Bag commitment after this tile
+}
+
+/// Words formed by one place_word, scored.
+pub struct WordPlay {
+    pub words: Vec<Vec<u8>>, // Uppercase ASCII, main word first, then cross words
+    pub score: u16,
+}
+
+/// ScrabbleBoard holds the on-chain board, racks and tile bag for a Scrabble match
+/// (seeds: ["scrabble_board", match_id]).
+///
+/// Tile bag: the coordinator commits a hash chain over the shuffled bag,
+/// head_i = SHA-256(tile_i || head_i+1), so each draw reveals one tile and the next head
+/// without exposing the tiles still in the bag. Draws per letter are capped at the standard
+/// distribution, so the bag can't hold more of a letter than a real one.
+///
+/// Board cells: 0 = empty, 1-26 = A-Z, letter | BLANK_PLAYED = blank played as that letter.
+/// Racks are public (every seat's tiles are visible on-chain) so place_word can check them.
+#[account]
+pub struct ScrabbleBoard {
+    pub match_id: [u8; 36],             // UUID v4 (fixed 36 bytes)
+    pub tiles: [u8; 225],               // 15×15 board, row-major, one byte per cell
+    pub racks: [[u8; 7]; 4],            // Per seat, 0 = empty slot
+    pub bag_head: [u8; 32],             // Commitment to the tiles still in the bag
+    pub bag_remaining: u8,              // Tiles left in the bag (starts at BAG_SIZE)
+    pub drawn_counts: [u8; 27],         // Tiles drawn per kind (index 0-25 = A-Z, 26 = blank)
+    pub scores: [u16; 4],               // Per seat
+    pub words_played: u16,              // Accepted place_word moves
+    pub authority: Pubkey,              // Coordinator that committed the bag
+    pub created_at: i64,
+}
+
+impl ScrabbleBoard {
+    pub const SIZE: usize = 15;
+    pub const CENTER: usize = 7;
+    pub const RACK_SIZE: usize = 7;
+    pub const MAX_SEATS: usize = 4;
+    pub const BAG_SIZE: u8 = 100;
+    pub const BLANK: u8 = 27;            // Blank tile in a rack / reveal
+    pub const BLANK_PLAYED: u8 = 0x80;   // Flag on a placed tile: blank standing for the letter
+    pub const BINGO_BONUS: u16 = 50;     // All 7 rack tiles in one move
+
+    /// Standard English tile distribution (A-Z, then blank).
+    pub const DISTRIBUTION: [u8; 27] = [
+        9, 2, 2, 4, 12, 2, 3, 2, 9, 1, 1, 4, 2, 6, 8, 2, 1, 6, 4, 6, 4, 2, 2, 1, 2, 1, 2,
+    ];
+    /// Standard English letter values (A-Z).
+    pub const LETTER_VALUES: [u8; 26] = [
+        1, 3, 3, 2, 1, 4, 2, 4, 1, 8, 5, 1, 3, 1, 1, 3, 10, 1, 1, 1, 1, 4, 4, 8, 4, 10,
+    ];
+
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        36 +                             // match_id ([u8; 36])
+        225 +                            // tiles ([u8; 225])
+        7 * 4 +                          // racks ([[u8; 7]; 4])
+        32 +                             // bag_head ([u8; 32])
+        1 +                              // bag_remaining (u8)
+        27 +                             // drawn_counts ([u8; 27])
+        2 * 4 +                          // scores ([u16; 4])
+        2 +                              // words_played (u16)
+        32 +                             // authority (Pubkey)
+        8;                               // created_at (i64)
+
+    // Total: 8 + 36 + 225 + 28 + 32 + 1 + 27 + 8 + 2 + 32 + 8 = 407 bytes
+
+    pub fn cell(&self, row: usize, col: usize) -> u8 {
+        self.tiles[row * Self::SIZE + col]
+    }
+
+    /// Letter (1-26) a board cell or placed tile stands for.
+    pub fn letter_of(tile: u8) -> u8 {
+        tile & !Self::BLANK_PLAYED
+    }
+
+    /// Letter value of a board cell (blanks score 0).
+    pub fn tile_value(tile: u8) -> u16 {
+        if tile & Self::BLANK_PLAYED != 0 || tile == 0 {
+            return 0;
+        }
+        Self::LETTER_VALUES[(tile - 1) as usize % 26] as u16
+    }
+
+    /// (letter multiplier, word multiplier) of a premium square on the standard board.
+    pub fn premium(row: usize, col: usize) -> (u16, u16) {
+        // The board is symmetric: fold into the top-left quadrant, then about its diagonal
+        let (r, c) = (row.min(14 - row), col.min(14 - col));
+        match (r.min(c), r.max(c)) {
+            (0, 0) | (0, 7) => (1, 3),
+            (1, 1) | (2, 2) | (3, 3) | (4, 4) | (7, 7) => (1, 2),
+            (1, 5) | (5, 5) => (3, 1),
+            (0, 3) | (2, 6) | (3, 7) | (6, 6) => (2, 1),
+            _ => (1, 1),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.iter().all(|&t| t == 0)
+    }
+
+    /// Starts the bag from the coordinator's commitment.
+    pub fn commit_bag(&mut self, bag_head: [u8; 32]) {
+        self.bag_head = bag_head;
+        self.bag_remaining = Self::BAG_SIZE;
+        self.drawn_counts = [0; 27];
+    }
+
+    /// Deals revealed tiles from the bag into a seat's rack, checking each against the chain.
+    pub fn draw_tiles(&mut self, seat: usize, reveals: &[TileReveal]) -> Result<()> {
+        require!(seat < Self::MAX_SEATS, GameError::PlayerNotInMatch);
+        for reveal in reveals {
+            require!(self.bag_remaining > 0, GameError::InvalidTileReveal);
+            require!(
+                (1..=Self::BLANK).contains(&reveal.tile)
+                    && hash::hashv(&[&[reveal.tile], &reveal.next_head]).to_bytes() == self.bag_head,
+                GameError::InvalidTileReveal
+            );
+            let kind = (reveal.tile - 1) as usize;
+            require!(
+                self.drawn_counts[kind] < Self::DISTRIBUTION[kind],
+                GameError::InvalidTileReveal
+            );
+            let slot = self.racks[seat]
+                .iter()
+                .position(|&t| t == 0)
+                .ok_or(GameError::RackFull)?;
+            self.racks[seat][slot] = reveal.tile;
+            self.drawn_counts[kind] += 1;
+            self.bag_remaining -= 1;
+            self.bag_head = reveal.next_head;
+        }
+        Ok(())
+    }
+
+    /// Places `placed` tiles from the seat's rack in a line from (row, col), skipping over
+    /// tiles already on the board, and scores every word formed.
+    ///
+    /// Checks: the line stays on the board and has no gaps; the first word covers the center
+    /// and later words touch an existing tile; the tiles are in the rack (a tile with
+    /// BLANK_PLAYED uses a blank); at least one word of 2+ letters is formed. Each word is the
+    /// full run through the placed tiles along the line (main word) or across it (cross words).
+    /// Dictionary validity is not checked on-chain; the formed words are returned for
+    /// the coordinator and disputes.
+    pub fn place_word(
+        &mut self,
+        seat: usize,
+        row: u8,
+        col: u8,
+        horizontal: bool,
+        placed: &[u8],
+    ) -> Result<WordPlay> {
+        require!(seat < Self::MAX_SEATS, GameError::PlayerNotInMatch);
+        require!(
+            !placed.is_empty() && placed.len() <= Self::RACK_SIZE,
+            GameError::InvalidTilePlacement
+        );
+        let (dr, dc) = if horizontal { (0, 1) } else { (1, 0) };
+        let first_move = self.is_empty();
+
+        // Walk the line: each tile goes in the next empty cell
+        let mut positions = Vec::with_capacity(placed.len());
+        let (mut r, mut c) = (row as usize, col as usize);
+        require!(r < Self::SIZE && c < Self::SIZE && self.cell(r, c) == 0, GameError::InvalidTilePlacement);
+        for _ in placed {
+            while r < Self::SIZE && c < Self::SIZE && self.cell(r, c) != 0 {
+                r += dr;
+                c += dc;
+            }
+            require!(r < Self::SIZE && c < Self::SIZE, GameError::InvalidTilePlacement);
+            positions.push((r, c));
+            r += dr;
+            c += dc;
+        }
+
+        // Connection: the first word covers the center, later words touch the board
+        if first_move {
+            require!(
+                placed.len() >= 2 && positions.contains(&(Self::CENTER, Self::CENTER)),
+                GameError::InvalidTilePlacement
+            );
+        } else {
+            let touches = positions.iter().any(|&(r, c)| {
+                (r > 0 && self.cell(r - 1, c) != 0)
+                    || (r + 1 < Self::SIZE && self.cell(r + 1, c) != 0)
+                    || (c > 0 && self.cell(r, c - 1) != 0)
+                    || (c + 1 < Self::SIZE && self.cell(r, c + 1) != 0)
+            });
+            require!(touches, GameError::InvalidTilePlacement);
+        }
+
+        // Tile availability: take each tile from the rack
+        let mut rack = self.racks[seat];
+        for &tile in placed {
+            let letter = Self::letter_of(tile);
+            require!((1..=26).contains(&letter), GameError::InvalidTilePlacement);
+            let wanted = if tile & Self::BLANK_PLAYED != 0 { Self::BLANK } else { letter };
+            let slot = rack.iter().position(|&t| t == wanted).ok_or(GameError::TileNotInRack)?;
+            rack[slot] = 0;
+        }
+
+        for (&(r, c), &tile) in positions.iter().zip(placed) {
+            self.tiles[r * Self::SIZE + c] = tile;
+        }
+
+        // Main word along the line, then a cross word through each new tile
+        let mut words = Vec::new();
+        let mut score: u16 = 0;
+        let (r0, c0) = positions[0];
+        let mut runs = vec![(r0, c0, dr, dc)];
+        runs.extend(positions.iter().map(|&(r, c)| (r, c, dc, dr)));
+        for (r, c, dr, dc) in runs {
+            if let Some((word, word_score)) = self.scored_run(r, c, dr, dc, &positions) {
+                words.push(word);
+                score = score.saturating_add(word_score);
+            }
+        }
+        require!(!words.is_empty(), GameError::InvalidTilePlacement);
+        if placed.len() == Self::RACK_SIZE {
+            score = score.saturating_add(Self::BINGO_BONUS);
+        }
+
+        self.racks[seat] = rack;
+        self.scores[seat] = self.scores[seat].saturating_add(score);
+        self.words_played = self.words_played.saturating_add(1);
+        Ok(WordPlay { words, score })
+    }
+
+    /// The run of tiles through (row, col) in direction (dr, dc) and its score, if it has 2+
+    /// letters. Premium squares only count under tiles placed this move (`new_tiles`).
+    fn scored_run(
+        &self,
+        row: usize,
+        col: usize,
+        dr: usize,
+        dc: usize,
+        new_tiles: &[(usize, usize)],
+    ) -> Option<(Vec<u8>, u16)> {
+        let (mut r, mut c) = (row, col);
+        while r >= dr && c >= dc && self.cell(r - dr, c - dc) != 0 {
+            r -= dr;
+            c -= dc;
+        }
+        let mut word = Vec::new();
+        let (mut letters, mut word_multiplier) = (0u16, 1u16);
+        while r < Self::SIZE && c < Self::SIZE && self.cell(r, c) != 0 {
+            let tile = self.cell(r, c);
+            let (letter_mult, word_mult) = if new_tiles.contains(&(r, c)) {
+                Self::premium(r, c)
+            } else {
+                (1, 1)
+            };
+            letters += Self::tile_value(tile) * letter_mult;
+            word_multiplier *= word_mult;
+            word.push(b'A' + Self::letter_of(tile) - 1);
+            r += dr;
+            c += dc;
+        }
+        (word.len() >= 2).then(|| (word, letters * word_multiplier))
+    }
+}
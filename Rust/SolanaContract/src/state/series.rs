@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::error::GameError;
+
+/// Series links the matches of a best-of-N between a fixed roster (ranked play, tournament
+/// finals). Seeds: ["series", series_id].
+///
+/// Each reported match must be ended, of the series' game type, and seat exactly the roster
+/// (in any seat order). Its winner scores a series win; a player reaching wins_needed() wins
+/// the series. Matches without a winner (draws, voided matches) use one of the MAX_GAMES slots
+/// but score nothing; if the slots run out first, the series goes to the unique leader, or
+/// ends without a winner on a tie.
+#[account]
+pub struct Series {
+    pub series_id: [u8; 36],            // UUID v4 (fixed 36 bytes)
+    pub game_type: u8,                  // GameType of every match in the series
+    pub best_of: u8,                    // 1, 3, 5 or 7
+    pub player_ids: [[u8; 64]; 10],     // Roster Firebase UIDs (null-padded), series seat order
+    pub player_count: u8,
+    pub wins: [u8; 10],                 // Series score per roster seat
+    pub matches: [Pubkey; 7],           // Reported Match PDAs, in report order
+    pub games_reported: u8,
+    pub winner: u8,                     // Roster seat of the series winner (NO_WINNER until decided)
+    pub completed_at: i64,              // 0 = series still running
+    pub authority: Pubkey,              // Coordinator that created the series and reports games
+    pub created_at: i64,
+}
+
+impl Series {
+    pub const MAX_GAMES: usize = 7;
+    pub const NO_WINNER: u8 = 0xFF;
+
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        36 +                             // series_id ([u8; 36])
+        1 +                              // game_type (u8)
+        1 +                              // best_of (u8)
+        64 * 10 +                        // player_ids ([[u8; 64]; 10])
+        1 +                              // player_count (u8)
+        10 +                             // wins ([u8; 10])
+        32 * 7 +                         // matches ([Pubkey; 7])
+        1 +                              // games_reported (u8)
+        1 +                              // winner (u8)
+        8 +                              // completed_at (i64)
+        32 +                             // authority (Pubkey)
+        8;                               // created_at (i64)
+
+    // Total: 8 + 36 + 1 + 1 + 640 + 1 + 10 + 224 + 1 + 1 + 8 + 32 + 8 = 971 bytes
+
+    /// Series wins needed to take a best-of-`best_of`.
+    pub fn wins_needed(&self) -> u8 {
+        self.best_of / 2 + 1
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.completed_at != 0
+    }
+
+    /// Roster seat of a (null-padded) player id.
+    pub fn seat_of(&self, player_id: &[u8; 64]) -> Option<usize> {
+        self.player_ids[..self.player_count as usize].iter().position(|id| id == player_id)
+    }
+
+    pub fn has_match(&self, match_account: &Pubkey) -> bool {
+        self.matches[..self.games_reported as usize].contains(match_account)
+    }
+
+    /// Sets up the roster: 2-10 distinct, non-empty user ids of at most 64 bytes.
+    pub fn init(&mut self, game_type: u8, best_of: u8, player_ids: &[String]) -> Result<()> {
+        require!(
+            best_of % 2 == 1 && (best_of as usize) <= Self::MAX_GAMES,
+            GameError::InvalidSeriesLength
+        );
+        require!(
+            (2..=10).contains(&player_ids.len()),
+            GameError::SeriesRosterMismatch
+        );
+        for (seat, user_id) in player_ids.iter().enumerate() {
+            let bytes = user_id.as_bytes();
+            require!(!bytes.is_empty(), GameError::InvalidPayload);
+            require!(bytes.len() <= 64, GameError::UserIdTooLong);
+            let mut id = [0u8; 64];
+            id[..bytes.len()].copy_from_slice(bytes);
+            require!(!self.player_ids[..seat].contains(&id), GameError::SeriesRosterMismatch);
+            self.player_ids[seat] = id;
+        }
+        self.game_type = game_type;
+        self.best_of = best_of;
+        self.player_count = player_ids.len() as u8;
+        self.wins = [0; 10];
+        self.games_reported = 0;
+        self.winner = Self::NO_WINNER;
+        self.completed_at = 0;
+        Ok(())
+    }
+
+    /// Records one reported match and its winner (roster seat, None = no winner), completing
+    /// the series once it is decided or out of slots.
+    pub fn record_game(&mut self, match_account: Pubkey, winner: Option<usize>, now: i64) {
+        self.matches[self.games_reported as usize] = match_account;
+        self.games_reported += 1;
+        if let Some(seat) = winner {
+            self.wins[seat] = self.wins[seat].saturating_add(1);
+            if self.wins[seat] >= self.wins_needed() {
+                self.complete(Some(seat), now);
+                return;
+            }
+        }
+        if self.games_reported as usize == Self::MAX_GAMES {
+            let seated = &self.wins[..self.player_count as usize];
+            let best = seated.iter().copied().max().unwrap_or(0);
+            let mut leaders = (0..seated.len()).filter(|&seat| seated[seat] == best);
+            let leader = match (leaders.next(), leaders.next()) {
+                (Some(seat), None) => Some(seat),
+                _ => None,
+            };
+            self.complete(leader, now);
+        }
+    }
+
+    fn complete(&mut self, winner: Option<usize>, now: i64) {
+        self.winner = winner.map(|seat| seat as u8).unwrap_or(Self::NO_WINNER);
+        self.completed_at = now;
+    }
+}
@@ -33,6 +33,23 @@ impl SignerRegistry {
             .and_then(|index| self.roles.get(index).cloned())
     }
 
+    /// Picks one registered validator from `seed` (uniform over Validator-role signers),
+    /// or None when no validator is registered.
+    pub fn pick_validator(&self, seed: &[u8; 32]) -> Option<Pubkey> {
+        let validators: Vec<Pubkey> = self.signers
+            .iter()
+            .zip(self.roles.iter())
+            .filter(|(_, role)| **role == SignerRole::Validator)
+            .map(|(signer, _)| *signer)
+            .collect();
+        if validators.is_empty() {
+            return None;
+        }
+        let mut index_bytes = [0u8; 8];
+        index_bytes.copy_from_slice(&seed[..8]);
+        Some(validators[(u64::from_le_bytes(index_bytes) % validators.len() as u64) as usize])
+    }
+
     pub fn add_signer(&mut self, pubkey: Pubkey, role: SignerRole) -> Result<()> {
         if self.signers.contains(&pubkey) {
             return Err(anchor_lang::error!(crate::error::GameError::SignerAlreadyExists));
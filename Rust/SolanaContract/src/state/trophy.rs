@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// Trophy records the soulbound NFT minted for a match winner.
+/// One PDA per match (["trophy", match]), so each winner can be awarded only once.
+#[account]
+pub struct Trophy {
+    pub match_account: Pubkey,      // Match PDA the trophy was won in
+    pub seat: u8,                   // Winner's seat index
+    pub user_id: [u8; 64],          // Winner's Firebase UID (fixed 64 bytes, null-padded)
+    pub final_score: i32,           // Winner's cumulative score
+    pub mint: Pubkey,               // Trophy NFT mint (supply 1, 0 decimals)
+    pub recipient: Pubkey,          // Wallet holding the (frozen) trophy token
+    pub minted_at: i64,             // Unix timestamp
+}
+
+impl Trophy {
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        32 +                             // match_account (Pubkey)
+        1 +                              // seat (u8)
+        64 +                             // user_id (fixed [u8; 64])
+        4 +                              // final_score (i32)
+        32 +                             // mint (Pubkey)
+        32 +                             // recipient (Pubkey)
+        8;                               // minted_at (i64)
+    
+    // Total: 8 + 32 + 1 + 64 + 4 + 32 + 32 + 8 = 181 bytes
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+/// UsageMeter records AI usage per model for one user and one billing period.
+/// One PDA per user per period, so billing reports can be verified on-chain
+/// and per-model quotas can be enforced in consume_ai_credits.
+/// Subscription AC allowance for the period is credited by accrue_subscription_credits and
+/// drawn down by consume_ai_credits before any purchased AC.
+#[account]
+pub struct UsageMeter {
+    pub user_id: [u8; 64],          // Firebase UID (fixed 64 bytes, null-padded)
+    pub period_id: u32,             // Billing period (timestamp / PERIOD_SECONDS)
+    pub tokens_used: [u64; 10],     // Tokens used per model_id (in thousands)
+    pub ac_spent: [u64; 10],        // AC spent per model_id
+    pub call_counts: [u32; 10],     // API calls per model_id
+    pub created_at: i64,            // First consumption in this period
+    pub last_updated: i64,          // Last consumption in this period
+    pub ac_allowance: u64,          // Subscription allowance credited for this period (roll-over included)
+    pub ac_rolled_over: u64,        // Part of ac_allowance carried over from the previous period
+    pub ac_allowance_used: u64,     // Consumption covered by the allowance
+    pub allowance_accrued_at: i64,  // When the allowance was credited (0 = not yet)
+}
+
+impl UsageMeter {
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        64 +                             // user_id (fixed [u8; 64])
+        4 +                              // period_id (u32)
+        (8 * 10) +                       // tokens_used ([u64; 10] = 80 bytes)
+        (8 * 10) +                       // ac_spent ([u64; 10] = 80 bytes)
+        (4 * 10) +                       // call_counts ([u32; 10] = 40 bytes)
+        8 +                              // created_at (i64)
+        8 +                              // last_updated (i64)
+        8 +                              // ac_allowance (u64)
+        8 +                              // ac_rolled_over (u64)
+        8 +                              // ac_allowance_used (u64)
+        8;                               // allowance_accrued_at (i64)
+    
+    // Total: 8 + 64 + 4 + 80 + 80 + 40 + 8 + 8 + 8 + 8 + 8 + 8 = 324 bytes
+    
+    /// Billing period length (30 days in seconds)
+    pub const PERIOD_SECONDS: i64 = 2_592_000;
+    
+    pub fn current_period_id(clock: &Clock) -> u32 {
+        (clock.unix_timestamp / Self::PERIOD_SECONDS) as u32
+    }
+    
+    pub fn is_initialized(&self) -> bool {
+        self.created_at != 0
+    }
+    
+    pub fn allowance_accrued(&self) -> bool {
+        self.allowance_accrued_at != 0
+    }
+    
+    pub fn allowance_remaining(&self) -> u64 {
+        self.ac_allowance.saturating_sub(self.ac_allowance_used)
+    }
+    
+    /// Covers as much of `ac_cost` as the remaining allowance allows. Returns the covered part.
+    pub fn use_allowance(&mut self, ac_cost: u64) -> u64 {
+        let covered = ac_cost.min(self.allowance_remaining());
+        self.ac_allowance_used += covered;
+        covered
+    }
+    
+    pub fn total_ac_spent(&self) -> u64 {
+        self.ac_spent.iter().fold(0u64, |acc, &v| acc.saturating_add(v))
+    }
+    
+    pub fn total_tokens_used(&self) -> u64 {
+        self.tokens_used.iter().fold(0u64, |acc, &v| acc.saturating_add(v))
+    }
+}
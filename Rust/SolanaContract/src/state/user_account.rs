@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::error::GameError;
 
 /// UserAccount stores user statistics and aggregates for leaderboards.
 /// Token balances (GP/AC) are stored in database, not on-chain.
@@ -32,6 +33,74 @@ pub struct UserAccount {
     pub season_games: u32,                 // Games played this season
     pub leaderboard_rank: u16,             // 0 = not ranked, 1-100 = rank
     pub active_multiplier: u8,             // Reward multiplier (1-5x based on rank)
+    
+    // Pending subscription changes (applied at subscription_expiry)
+    pub subscription_flags: u8,            // Bit 0: cancel_at_period_end (no-renew), bits 1-7: reserved
+    pub pending_tier: u8,                  // Tier to switch to at pending_tier_effective_at (0 = no pending downgrade)
+    pub pending_tier_effective_at: i64,    // When pending_tier takes effect (0 = no pending downgrade)
+    
+    // Support adjustments (see CreditAdjustment)
+    pub total_ac_granted: u64,             // Total AC granted by support (lifetime)
+    
+    // Anti-smurf gating
+    pub created_at: i64,                   // Account creation timestamp (0 = legacy account, treated as established)
+    pub placement_matches_remaining: u8,   // Matches left before the user appears on leaderboards
+    
+    // Anti-cheat
+    pub cheat_strikes: u8,                 // Disputes resolved against this user (drives sanction escalation)
+    
+    // Promo codes
+    pub promo_redemptions: [u8; 128],      // Bitmap of redeemed PromoCode indexes (1024 promos)
+    
+    // GP gifting (see gift_gp)
+    pub gift_day: u64,                     // Day index (timestamp / 86400) of gp_gifted_day_total
+    pub gp_gifted_day_total: u64,          // GP gifted on gift_day (daily cap)
+    pub lifetime_gp_gifted: u64,           // Total GP gifted to others (before fees)
+    pub lifetime_gp_received: u64,         // Total GP received as gifts (after fees)
+    
+    // Guild membership
+    pub guild: [u8; 32],                   // Guild name (null-padded, all zero = not in a guild)
+    
+    // Idempotency for economic instructions (see use_op_nonce)
+    pub last_op_nonce: u64,                // Last operation nonce accepted (0 = none yet)
+    
+    // Subscription game pass (see start_game_with_gp)
+    pub free_play_day: u64,                // Day index (timestamp / 86400) of free_play_used
+    pub free_play_used: u16,               // GP-free game starts used on free_play_day (daily cap)
+    
+    // Layout versioning (see extend_user_account). New fields go after this one.
+    pub schema_version: u8,                // Layout the account was created or extended to (0 = pre-versioning)
+    
+    // Dispute filing limits (schema 2, see flag_dispute)
+    pub active_disputes: u8,               // Disputes filed and not yet resolved
+    pub dispute_season_id: u64,            // Season of season_disputes_filed
+    pub season_disputes_filed: u16,        // Disputes filed in dispute_season_id (escalates the deposit)
+    
+    // AI spending limit (schema 3, see set_ai_spend_limit / consume_ai_credits)
+    pub ac_daily_limit: u64,               // Max AC consumed per day (0 = no limit)
+    pub ac_spend_day: u64,                 // Day index (timestamp / 86400) of ac_spent_today
+    pub ac_spent_today: u64,               // AC consumed on ac_spend_day
+    pub ac_pending_limit: u64,             // Raised limit waiting for ac_pending_limit_at (0 = remove the limit)
+    pub ac_pending_limit_at: i64,          // When ac_pending_limit takes effect (0 = no pending raise)
+    
+    // Payment reversals (schema 4, see process_refund / process_chargeback)
+    pub chargeback_count: u16,             // Stripe chargebacks against this user (lifetime)
+    pub ac_reversed: u64,                  // Purchased AC clawed back by refunds and chargebacks (lifetime)
+    
+    // Linked wallet (schema 5, see prove_account_ownership). A record for clients and support,
+    // and where distribute_season_rewards pays lamports: no instruction authorizes by it. Seat
+    // actions (set_ready, authority votes, relayed moves) are bound to the wallet that joined,
+    // Match.player_wallets. Cleared by erase_user_account
+    pub wallet: Pubkey,                    // Wallet linked to this account (default = none)
+    pub wallet_link_count: u32,            // Links so far; signed into each ownership proof (replay guard)
+    pub wallet_linked_at: i64,             // When wallet was linked (0 = never)
+}
+
+/// Seed bytes for a stored (null-padded) user_id, matching the ["user_account", user_id] PDA
+/// derived from the unpadded String.
+pub fn user_id_seed(user_id: &[u8; 64]) -> &[u8] {
+    let len = user_id.iter().position(|&b| b == 0).unwrap_or(64);
+    &user_id[..len]
 }
 
 impl UserAccount {
@@ -53,14 +122,333 @@ impl UserAccount {
         4 +                                 // season_wins (u32)
         4 +                                 // season_games (u32)
         2 +                                 // leaderboard_rank (u16)
-        1;                                  // active_multiplier (u8)
+        1 +                                 // active_multiplier (u8)
+        1 +                                 // subscription_flags (u8 bitfield)
+        1 +                                 // pending_tier (u8)
+        8 +                                 // pending_tier_effective_at (i64)
+        8 +                                 // total_ac_granted (u64)
+        8 +                                 // created_at (i64)
+        1 +                                 // placement_matches_remaining (u8)
+        1 +                                 // cheat_strikes (u8)
+        128 +                               // promo_redemptions ([u8; 128] bitmap)
+        8 +                                 // gift_day (u64)
+        8 +                                 // gp_gifted_day_total (u64)
+        8 +                                 // lifetime_gp_gifted (u64)
+        8 +                                 // lifetime_gp_received (u64)
+        32 +                                // guild ([u8; 32])
+        8 +                                 // last_op_nonce (u64)
+        8 +                                 // free_play_day (u64)
+        2 +                                 // free_play_used (u16)
+        1 +                                 // schema_version (u8)
+        1 +                                 // active_disputes (u8)
+        8 +                                 // dispute_season_id (u64)
+        2 +                                 // season_disputes_filed (u16)
+        8 +                                 // ac_daily_limit (u64)
+        8 +                                 // ac_spend_day (u64)
+        8 +                                 // ac_spent_today (u64)
+        8 +                                 // ac_pending_limit (u64)
+        8 +                                 // ac_pending_limit_at (i64)
+        2 +                                 // chargeback_count (u16)
+        8 +                                 // ac_reversed (u64)
+        32 +                                // wallet (Pubkey)
+        4 +                                 // wallet_link_count (u32)
+        8;                                  // wallet_linked_at (i64)
+    
+    // Total: 8 + 64 + 8 + 8 + 8 + 1 + 8 + 4 + 4 + 4 + 8 + 4 + 1 + 8 + 8 + 4 + 4 + 2 + 1 + 1 + 1 + 8 + 8 + 8 + 1 + 1 + 128 + 8 + 8 + 8 + 8 + 32 + 8 + 8 + 2 + 1 + 1 + 8 + 2 + 8 + 8 + 8 + 8 + 8 + 2 + 8 + 32 + 4 + 8 = 501 bytes
+    
+    /// Current layout. To add fields: append them at the end, bump SCHEMA_VERSION and push the
+    /// new MAX_SIZE onto SCHEMA_SIZES. Existing accounts grow via extend_user_account and read
+    /// the new fields as zero until then.
+    pub const SCHEMA_VERSION: u8 = 5;
+    
+    /// Account length (discriminator included) of each schema_version. 0 is the pre-versioning
+    /// layout: accounts created before schema_version existed are anywhere from the original
+    /// 157 bytes (user_id .. active_multiplier) up to SCHEMA_VERSION_OFFSET, since fields were
+    /// appended without a version; 157 is the smallest one in the wild.
+    pub const SCHEMA_SIZES: [usize; 6] = [157, 396, 407, 447, 457, Self::MAX_SIZE];
+    
+    // Byte offset of schema_version (fixed: later fields are appended after it)
+    const SCHEMA_VERSION_OFFSET: usize = 395;
+    
+    pub const MAX_DISPUTE_DEPOSIT_DOUBLINGS: u16 = 4; // Repeat-flagger deposit tops out at 16x
+    pub const AC_LIMIT_RAISE_DELAY_SECONDS: i64 = 86400; // Raising the AI spending limit waits a day
+    pub const OP_NONCE_WINDOW: u64 = 1024; // Furthest an op_nonce may jump past last_op_nonce
+    pub const ERASED_ID_PREFIX: &'static [u8] = b"erased:"; // user_id of an erased account's tombstone
+    
+    /// Schema version of raw account data; accounts too short to hold the field (every
+    /// pre-versioning layout ends at or before SCHEMA_VERSION_OFFSET) are version 0.
+    pub fn schema_version_of(data: &[u8]) -> u8 {
+        if data.len() <= Self::SCHEMA_VERSION_OFFSET {
+            return 0;
+        }
+        data[Self::SCHEMA_VERSION_OFFSET]
+    }
+    
+    /// Reads an account of any schema version: fields the account doesn't have yet read as zero.
+    /// Use where an account may not have been extended (Account<UserAccount> needs the current size).
+    pub fn try_deserialize_versioned(data: &[u8]) -> Result<UserAccount> {
+        // Security: Even the original layout is SCHEMA_SIZES[0] bytes; anything shorter isn't a UserAccount
+        require!(data.len() >= Self::SCHEMA_SIZES[0], ErrorCode::AccountDidNotDeserialize);
+        if data.len() >= Self::MAX_SIZE {
+            return UserAccount::try_deserialize(&mut &data[..]);
+        }
+        let mut padded = data.to_vec();
+        padded.resize(Self::MAX_SIZE, 0);
+        UserAccount::try_deserialize(&mut &padded[..])
+    }
+    
+    /// Writes the current schema version into raw account data (after extend_user_account's realloc).
+    pub fn stamp_schema_version(data: &mut [u8]) {
+        if let Some(version) = data.get_mut(Self::SCHEMA_VERSION_OFFSET) {
+            *version = Self::SCHEMA_VERSION;
+        }
+    }
+    
+    /// Anonymized user_id for an erased account: ERASED_ID_PREFIX + hex of the first 28 bytes
+    /// of `anon_key` (a salted hash of the original user_id, computed off-chain).
+    pub fn erased_user_id(anon_key: &[u8; 32]) -> [u8; 64] {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        let mut user_id = [0u8; 64];
+        let prefix_len = Self::ERASED_ID_PREFIX.len();
+        user_id[..prefix_len].copy_from_slice(Self::ERASED_ID_PREFIX);
+        for (i, byte) in anon_key[..28].iter().enumerate() {
+            user_id[prefix_len + 2 * i] = HEX[(byte >> 4) as usize];
+            user_id[prefix_len + 2 * i + 1] = HEX[(byte & 0x0f) as usize];
+        }
+        user_id
+    }
     
-    // Total: 8 + 64 + 8 + 8 + 8 + 1 + 8 + 4 + 4 + 4 + 8 + 4 + 1 + 8 + 8 + 4 + 4 + 2 + 1 = 161 bytes
+    pub fn is_erased(&self) -> bool {
+        self.user_id.starts_with(Self::ERASED_ID_PREFIX)
+    }
     
     pub fn has_active_subscription(&self, clock: &Clock) -> bool {
         self.subscription_expiry > clock.unix_timestamp && self.subscription_tier > 0
     }
     
+    // Subscription flag helpers
+    pub fn cancel_at_period_end(&self) -> bool {
+        (self.subscription_flags & 0x01) != 0
+    }
+    
+    pub fn set_cancel_at_period_end(&mut self, cancelled: bool) {
+        if cancelled {
+            self.subscription_flags |= 0x01;
+        } else {
+            self.subscription_flags &= !0x01;
+        }
+    }
+    
+    pub fn has_pending_downgrade(&self) -> bool {
+        self.pending_tier_effective_at != 0
+    }
+    
+    pub fn clear_pending_downgrade(&mut self) {
+        self.pending_tier = 0;
+        self.pending_tier_effective_at = 0;
+    }
+    
+    /// Applies a pending downgrade once its effective date has passed.
+    /// Returns true if the tier changed.
+    pub fn apply_pending_subscription_changes(&mut self, clock: &Clock) -> bool {
+        if !self.has_pending_downgrade() || clock.unix_timestamp < self.pending_tier_effective_at {
+            return false;
+        }
+        self.subscription_tier = self.pending_tier;
+        self.clear_pending_downgrade();
+        true
+    }
+    
+    pub fn has_redeemed_promo(&self, promo_index: u16) -> bool {
+        let (byte, bit) = (promo_index as usize / 8, promo_index % 8);
+        byte < self.promo_redemptions.len() && (self.promo_redemptions[byte] & (1 << bit)) != 0
+    }
+    
+    pub fn set_promo_redeemed(&mut self, promo_index: u16) {
+        let (byte, bit) = (promo_index as usize / 8, promo_index % 8);
+        if byte < self.promo_redemptions.len() {
+            self.promo_redemptions[byte] |= 1 << bit;
+        }
+    }
+    
+    pub fn in_guild(&self) -> bool {
+        self.guild.iter().any(|&b| b != 0)
+    }
+    
+    /// Season score for `season_id` (0 if the user hasn't played that season yet).
+    pub fn season_score_for(&self, season_id: u64) -> u64 {
+        if self.current_season_id == season_id {
+            self.season_score
+        } else {
+            0
+        }
+    }
+    
+    pub fn placement_complete(&self) -> bool {
+        self.placement_matches_remaining == 0
+    }
+    
+    /// Counts a played match toward placement. Returns true if placement just completed.
+    pub fn record_placement_match(&mut self) -> bool {
+        if self.placement_complete() {
+            return false;
+        }
+        self.placement_matches_remaining -= 1;
+        self.placement_complete()
+    }
+    
+    pub fn account_age(&self, clock: &Clock) -> i64 {
+        if self.created_at == 0 {
+            return i64::MAX; // Legacy account created before age tracking
+        }
+        clock.unix_timestamp.saturating_sub(self.created_at)
+    }
+    
+    pub fn day_index(clock: &Clock) -> u64 {
+        (clock.unix_timestamp.max(0) / 86400) as u64
+    }
+    
+    /// GP-free game starts used so far today (the counter resets when the day changes).
+    pub fn free_plays_used_today(&self, clock: &Clock) -> u16 {
+        if self.free_play_day == Self::day_index(clock) {
+            self.free_play_used
+        } else {
+            0
+        }
+    }
+    
+    /// Uses one of today's free plays if an active subscription grants `daily_free_plays`
+    /// and the daily cap isn't reached. Returns false (nothing recorded) otherwise.
+    pub fn use_free_play(&mut self, daily_free_plays: u16, clock: &Clock) -> bool {
+        if !self.has_active_subscription(clock) {
+            return false;
+        }
+        let used = self.free_plays_used_today(clock);
+        if used >= daily_free_plays {
+            return false;
+        }
+        self.free_play_day = Self::day_index(clock);
+        self.free_play_used = used + 1;
+        true
+    }
+    
+    /// GP gifted so far today (the counter resets when the day changes).
+    pub fn gp_gifted_today(&self, clock: &Clock) -> u64 {
+        if self.gift_day == Self::day_index(clock) {
+            self.gp_gifted_day_total
+        } else {
+            0
+        }
+    }
+    
+    /// Disputes filed so far this season (the counter resets when the season changes).
+    pub fn disputes_filed_in_season(&self, season_id: u64) -> u16 {
+        if self.dispute_season_id == season_id {
+            self.season_disputes_filed
+        } else {
+            0
+        }
+    }
+    
+    /// GP deposit for this user's next dispute: base_gp, doubled for each dispute already
+    /// filed this season (capped at MAX_DISPUTE_DEPOSIT_DOUBLINGS).
+    pub fn dispute_deposit_for(&self, base_gp: u32, season_id: u64) -> u32 {
+        let doublings = self.disputes_filed_in_season(season_id).min(Self::MAX_DISPUTE_DEPOSIT_DOUBLINGS);
+        base_gp.saturating_mul(1 << doublings)
+    }
+    
+    pub fn record_dispute_filed(&mut self, season_id: u64) {
+        self.season_disputes_filed = self.disputes_filed_in_season(season_id).saturating_add(1);
+        self.dispute_season_id = season_id;
+        self.active_disputes = self.active_disputes.saturating_add(1);
+    }
+    
+    pub fn record_dispute_resolved(&mut self) {
+        self.active_disputes = self.active_disputes.saturating_sub(1);
+    }
+    
+    /// AC consumed on AI calls so far today (the counter resets when the day changes).
+    pub fn ac_spent_on_day(&self, clock: &Clock) -> u64 {
+        if self.ac_spend_day == Self::day_index(clock) {
+            self.ac_spent_today
+        } else {
+            0
+        }
+    }
+    
+    pub fn has_pending_ac_limit(&self) -> bool {
+        self.ac_pending_limit_at != 0
+    }
+    
+    /// Whether `daily_limit` would loosen the current limit (0 = no limit is the loosest).
+    pub fn raises_ac_limit(&self, daily_limit: u64) -> bool {
+        self.ac_daily_limit != 0 && (daily_limit == 0 || daily_limit > self.ac_daily_limit)
+    }
+    
+    /// Applies a pending limit raise once its delay has passed.
+    pub fn apply_pending_ac_limit(&mut self, clock: &Clock) {
+        if self.has_pending_ac_limit() && clock.unix_timestamp >= self.ac_pending_limit_at {
+            self.ac_daily_limit = self.ac_pending_limit;
+            self.ac_pending_limit = 0;
+            self.ac_pending_limit_at = 0;
+        }
+    }
+    
+    /// Sets the daily AI spending limit. Tightening applies at once (and drops any pending
+    /// raise); raising waits AC_LIMIT_RAISE_DELAY_SECONDS. Returns when the limit takes effect.
+    pub fn set_ac_daily_limit(&mut self, daily_limit: u64, clock: &Clock) -> i64 {
+        self.apply_pending_ac_limit(clock);
+        if self.raises_ac_limit(daily_limit) {
+            self.ac_pending_limit = daily_limit;
+            self.ac_pending_limit_at = clock.unix_timestamp.saturating_add(Self::AC_LIMIT_RAISE_DELAY_SECONDS);
+            return self.ac_pending_limit_at;
+        }
+        self.ac_daily_limit = daily_limit;
+        self.ac_pending_limit = 0;
+        self.ac_pending_limit_at = 0;
+        clock.unix_timestamp
+    }
+    
+    /// Counts `ac_amount` toward today's AI spending, rejecting it if it would go over the limit.
+    pub fn record_ac_spend(&mut self, ac_amount: u64, clock: &Clock) -> Result<()> {
+        self.apply_pending_ac_limit(clock);
+        let spent = self.ac_spent_on_day(clock)
+            .checked_add(ac_amount)
+            .ok_or(GameError::Overflow)?;
+        require!(
+            self.ac_daily_limit == 0 || spent <= self.ac_daily_limit,
+            GameError::AiSpendLimitExceeded
+        );
+        self.ac_spend_day = Self::day_index(clock);
+        self.ac_spent_today = spent;
+        Ok(())
+    }
+    
+    /// Takes `days` back off the subscription (a refunded or charged-back purchase). The
+    /// subscription lapses if that moves its expiry into the past.
+    pub fn roll_back_subscription(&mut self, days: u64, clock: &Clock) {
+        let seconds = (days as i64).saturating_mul(86400);
+        self.subscription_expiry = self.subscription_expiry.saturating_sub(seconds);
+        if self.subscription_expiry <= clock.unix_timestamp {
+            self.subscription_expiry = 0;
+            self.set_cancel_at_period_end(false);
+            self.clear_pending_downgrade();
+        }
+    }
+    
+    /// Consumes an operation nonce for an economic instruction. Like the match move nonce,
+    /// each nonce must be greater than the last, so a retried transaction can't apply twice.
+    /// It may skip at most OP_NONCE_WINDOW ahead, so nobody can lock an account out by
+    /// submitting u64::MAX (several of these instructions need no user signature).
+    pub fn use_op_nonce(&mut self, op_nonce: u64) -> Result<()> {
+        require!(
+            op_nonce > self.last_op_nonce && op_nonce - self.last_op_nonce <= Self::OP_NONCE_WINDOW,
+            GameError::InvalidNonce
+        );
+        self.last_op_nonce = op_nonce;
+        Ok(())
+    }
+    
     pub fn can_claim_daily(&self, clock: &Clock) -> bool {
         let time_since_last_claim = clock.unix_timestamp - self.last_claim;
         time_since_last_claim >= 86400 // 24 hours in seconds
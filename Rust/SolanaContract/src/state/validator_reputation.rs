@@ -1,48 +1,212 @@
 use anchor_lang::prelude::*;
+use crate::error::GameError;
 
 /**
  * Validator reputation and stake tracking.
  * Per critique Issue #5, Spec Section 33.1: Reputation system for validators.
+ * Reputation is fixed-point basis points (10000 = 1.0) so updates are exact integer math.
  */
 #[account]
 pub struct ValidatorReputation {
     pub validator: Pubkey,
     pub stake: u64,              // SOL staked as validator bond
-    pub reputation: f64,        // Reputation score (0.0 - 1.0)
+    pub reputation_bps: u16,     // Reputation score (0 - 10000 = 0.0 - 1.0)
     pub total_resolutions: u32, // Total disputes resolved
     pub correct_resolutions: u32, // Correct resolutions (for accuracy calculation)
     pub created_at: i64,
-    pub last_active: i64,       // Last dispute resolution timestamp
+    pub last_active: i64,       // Last validator_heartbeat (or dispute resolution) timestamp
+    
+    // Slash escrow and appeal (see slash_validator, appeal_slash, resolve_slash_appeal)
+    pub slashed_escrow: u64,        // Slashed stake held until the slash is final (0 = none pending)
+    pub slashed_at: i64,            // When slashed_escrow was slashed
+    pub slash_reason: u8,           // 0=malicious, 1=negligent, 2=inactivity
+    pub pre_slash_reputation_bps: u16, // Reputation restored if the appeal is upheld for the validator
+    pub appeal_bond: u64,           // Lamports locked on this account by appeal_slash (0 = none)
+    pub appeal_status: u8,          // APPEAL_NONE / APPEAL_PENDING / APPEAL_REJECTED / APPEAL_OVERTURNED
+    
+    // Liveness (see validator_heartbeat, enforce_inactivity)
+    pub missed_disputes: u16,       // Overdue disputes counted against this validator since its last slash
+    pub missed_checked_to: i64,     // created_at of the newest dispute counted (each dispute counts once)
+}
+
+/// Layout before fixed-point reputation (f64 reputation, no slash escrow). Read only by
+/// migrate_validator_reputation.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyValidatorReputation {
+    pub validator: Pubkey,
+    pub stake: u64,
+    pub reputation: f64,
+    pub total_resolutions: u32,
+    pub correct_resolutions: u32,
+    pub created_at: i64,
+    pub last_active: i64,
+}
+
+impl LegacyValidatorReputation {
+    pub const SIZE: usize = 8 + 32 + 8 + 8 + 4 + 4 + 8 + 8; // 80 bytes (discriminator included)
 }
 
 impl ValidatorReputation {
     pub const MAX_SIZE: usize = 8 +      // discriminator
         32 +                             // validator (Pubkey)
         8 +                              // stake (u64)
-        8 +                              // reputation (f64)
+        2 +                              // reputation_bps (u16)
         4 +                              // total_resolutions (u32)
         4 +                              // correct_resolutions (u32)
         8 +                              // created_at (i64)
-        8;                               // last_active (i64)
+        8 +                              // last_active (i64)
+        8 +                              // slashed_escrow (u64)
+        8 +                              // slashed_at (i64)
+        1 +                              // slash_reason (u8)
+        2 +                              // pre_slash_reputation_bps (u16)
+        8 +                              // appeal_bond (u64)
+        1 +                              // appeal_status (u8)
+        2 +                              // missed_disputes (u16)
+        8;                               // missed_checked_to (i64)
+    
+    // Total: 8 + 32 + 8 + 2 + 4 + 4 + 8 + 8 + 8 + 8 + 1 + 2 + 8 + 1 + 2 + 8 = 112 bytes
     
-    // Total: 8 + 32 + 8 + 8 + 4 + 4 + 8 + 8 = 80 bytes
+    pub const MAX_REPUTATION_BPS: u16 = 10000; // 1.0
+    pub const DEFAULT_ACCURACY_BPS: u16 = 5000; // New validators (no resolutions yet)
+    pub const SLASH_APPEAL_WINDOW_SECONDS: i64 = 259200; // 3 days to appeal a slash
+    pub const APPEAL_BOND_BPS: u64 = 1000; // Appeal bond: 10% of the escrowed slash (lamports)
+    pub const APPEAL_NONE: u8 = 0;
+    pub const APPEAL_PENDING: u8 = 1;
+    pub const APPEAL_REJECTED: u8 = 2; // Slash stands, bond forfeited
+    pub const APPEAL_OVERTURNED: u8 = 3; // Stake and reputation restored, bond refunded
+    pub const SLASH_REASON_MALICIOUS: u8 = 0;
+    pub const SLASH_REASON_NEGLIGENT: u8 = 1;
+    pub const SLASH_REASON_INACTIVITY: u8 = 2;
+    pub const INACTIVITY_SECONDS: i64 = 1209600; // 14 days without a heartbeat
+    pub const DISPUTE_RESPONSE_SECONDS: i64 = 259200; // A dispute unresolved after 3 days is missed
+    pub const MAX_MISSED_DISPUTES: u16 = 5; // Missed disputes that trigger an inactivity slash
+    pub const INACTIVITY_SLASH_BPS: u64 = 500; // Inactivity slash: 5% of stake
     
-    pub fn calculate_accuracy(&self) -> f64 {
+    /// Share of correct resolutions in basis points (rounded down).
+    pub fn calculate_accuracy_bps(&self) -> u16 {
         if self.total_resolutions == 0 {
-            return 0.5; // Default reputation for new validators
+            return Self::DEFAULT_ACCURACY_BPS; // Default reputation for new validators
         }
-        self.correct_resolutions as f64 / self.total_resolutions as f64
+        let correct = self.correct_resolutions.min(self.total_resolutions) as u64;
+        (correct * Self::MAX_REPUTATION_BPS as u64 / self.total_resolutions as u64) as u16
     }
     
     pub fn update_reputation(&mut self, was_correct: bool) {
-        self.total_resolutions += 1;
+        self.total_resolutions = self.total_resolutions.saturating_add(1);
         if was_correct {
-            self.correct_resolutions += 1;
+            self.correct_resolutions = self.correct_resolutions.saturating_add(1);
         }
         
-        // Update reputation based on accuracy
-        let accuracy = self.calculate_accuracy();
-        self.reputation = (self.reputation * 0.7 + accuracy * 0.3).clamp(0.0, 1.0);
+        // Update reputation based on accuracy: 70% previous, 30% accuracy
+        let accuracy = self.calculate_accuracy_bps() as u32;
+        let blended = (self.reputation_bps as u32 * 7 + accuracy * 3) / 10;
+        self.reputation_bps = blended.min(Self::MAX_REPUTATION_BPS as u32) as u16;
+    }
+    
+    /// Cuts reputation by penalty_bps of its current value (5000 = half).
+    pub fn apply_reputation_penalty(&mut self, penalty_bps: u16) {
+        let kept = Self::MAX_REPUTATION_BPS.saturating_sub(penalty_bps) as u32;
+        self.reputation_bps = (self.reputation_bps as u32 * kept / Self::MAX_REPUTATION_BPS as u32) as u16;
+    }
+    
+    /// Converts a legacy f64 reputation (0.0 - 1.0, clamped) to basis points, rounding to nearest.
+    pub fn reputation_bps_from_f64(reputation: f64) -> u16 {
+        if reputation.is_nan() {
+            return 0;
+        }
+        (reputation.clamp(0.0, 1.0) * Self::MAX_REPUTATION_BPS as f64).round() as u16
+    }
+    
+    /// Current-layout account for a legacy one (no slash pending).
+    pub fn from_legacy(legacy: &LegacyValidatorReputation) -> Self {
+        Self {
+            validator: legacy.validator,
+            stake: legacy.stake,
+            reputation_bps: Self::reputation_bps_from_f64(legacy.reputation),
+            total_resolutions: legacy.total_resolutions,
+            correct_resolutions: legacy.correct_resolutions,
+            created_at: legacy.created_at,
+            last_active: legacy.last_active,
+            slashed_escrow: 0,
+            slashed_at: 0,
+            slash_reason: 0,
+            pre_slash_reputation_bps: 0,
+            appeal_bond: 0,
+            appeal_status: Self::APPEAL_NONE,
+            missed_disputes: 0,
+            missed_checked_to: 0,
+        }
+    }
+    
+    pub fn has_pending_slash(&self) -> bool {
+        self.slashed_escrow > 0
+    }
+    
+    pub fn appeal_pending(&self) -> bool {
+        self.appeal_status == Self::APPEAL_PENDING
+    }
+    
+    pub fn appeal_deadline(&self) -> i64 {
+        self.slashed_at.saturating_add(Self::SLASH_APPEAL_WINDOW_SECONDS)
+    }
+    
+    /// Makes an unappealed slash final once its appeal window has passed (the escrow is burned).
+    /// Returns true if a slash was finalized.
+    pub fn settle_slash(&mut self, now: i64) -> bool {
+        if self.has_pending_slash() && !self.appeal_pending() && now >= self.appeal_deadline() {
+            self.slashed_escrow = 0;
+            return true;
+        }
+        false
+    }
+    
+    /// Lamports appeal_slash locks for the current slash (at least 1).
+    pub fn appeal_bond_for_slash(&self) -> u64 {
+        (self.slashed_escrow.saturating_mul(Self::APPEAL_BOND_BPS) / 10000).max(1)
+    }
+    
+    /// Reputation cut for a slash reason, in basis points of the current reputation.
+    pub fn reputation_penalty_bps(reason: u8) -> u16 {
+        match reason {
+            Self::SLASH_REASON_MALICIOUS => 5000, // Malicious: 50% reputation loss
+            Self::SLASH_REASON_NEGLIGENT => 2000, // Negligent: 20% reputation loss
+            Self::SLASH_REASON_INACTIVITY => 1000, // Inactivity: 10% reputation loss
+            _ => 0,
+        }
+    }
+    
+    /// Moves amount of stake into slashed_escrow and cuts reputation for reason.
+    /// The previous slash must be final (settled here if its appeal window has passed).
+    pub fn apply_slash(&mut self, amount: u64, reason: u8, now: i64) -> Result<()> {
+        self.settle_slash(now);
+        require!(!self.has_pending_slash(), GameError::SlashPending);
+        require!(self.stake >= amount, GameError::InsufficientFunds);
+        
+        self.stake = self.stake
+            .checked_sub(amount)
+            .ok_or(GameError::InsufficientFunds)?;
+        self.slashed_escrow = amount;
+        self.slashed_at = now;
+        self.slash_reason = reason;
+        self.pre_slash_reputation_bps = self.reputation_bps;
+        self.appeal_bond = 0;
+        self.appeal_status = Self::APPEAL_NONE;
+        self.apply_reputation_penalty(Self::reputation_penalty_bps(reason));
+        Ok(())
+    }
+    
+    /// Start of the current inactivity period: the last heartbeat, or the last slash if later
+    /// (so an inactive validator is slashed once per INACTIVITY_SECONDS, not on every crank).
+    pub fn inactive_since(&self) -> i64 {
+        self.last_active.max(self.slashed_at).max(self.created_at)
+    }
+    
+    pub fn is_inactive(&self, now: i64) -> bool {
+        now.saturating_sub(self.inactive_since()) >= Self::INACTIVITY_SECONDS
+    }
+    
+    /// Stake enforce_inactivity slashes: INACTIVITY_SLASH_BPS of stake, at least 1 while staked.
+    pub fn inactivity_slash_amount(&self) -> u64 {
+        (self.stake.saturating_mul(Self::INACTIVITY_SLASH_BPS) / 10000).max(1).min(self.stake)
     }
 }
-
@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash;
+use crate::error::GameError;
+use crate::merkle;
+
+/// A word claimed in a WordSearch grid, with its proof against the committed word list.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct FoundWord {
+    pub word_index: u8,          // Leaf index in the word list
+    pub word: Vec<u8>,           // Uppercase ASCII
+    pub row: u8,                 // Grid coordinates of the first letter
+    pub col: u8,
+    pub direction: u8,           // Index into WordSearchPuzzle::DIRECTIONS
+    pub proof: Vec<[u8; 32]>,    // Merkle proof, leaf-to-root
+}
+
+/// WordSearchPuzzle holds the puzzle commitment for a WordSearch match
+/// (seeds: ["word_search", match_id]).
+///
+/// The coordinator commits the puzzle before play: grid_hash = SHA-256(rows || cols || grid),
+/// with the grid as row-major uppercase ASCII, and word_root, the Merkle root over the hidden
+/// words with leaves SHA-256(index || word). The grid is revealed once play starts and checked
+/// against grid_hash; the word list stays hidden, each word proven only when someone finds it.
+#[account]
+pub struct WordSearchPuzzle {
+    pub match_id: [u8; 36],             // UUID v4 (fixed 36 bytes)
+    pub grid_hash: [u8; 32],            // SHA-256(rows || cols || grid)
+    pub word_root: [u8; 32],            // Merkle root of the hidden word list
+    pub rows: u8,
+    pub cols: u8,
+    pub word_count: u8,                 // Leaves in the word list (max MAX_WORDS)
+    pub grid_revealed: bool,
+    pub grid: [u8; 400],                // Revealed grid, row-major (first rows × cols bytes)
+    pub found_by: [u8; 64],             // Per word index: finder's seat + 1, 0 = not found
+    pub words_found: u8,
+    pub scores: [u16; 10],              // Per seat: letters of the words found
+    pub authority: Pubkey,              // Coordinator that committed the puzzle
+    pub committed_at: i64,
+}
+
+impl WordSearchPuzzle {
+    pub const MAX_DIMENSION: u8 = 20;
+    pub const MAX_WORDS: u8 = 64;
+    pub const MIN_WORD_LEN: usize = 2;
+
+    /// (row step, column step) per direction: E, SE, S, SW, W, NW, N, NE.
+    pub const DIRECTIONS: [(i8, i8); 8] = [
+        (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1), (-1, 0), (-1, 1),
+    ];
+
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        36 +                             // match_id ([u8; 36])
+        32 +                             // grid_hash ([u8; 32])
+        32 +                             // word_root ([u8; 32])
+        1 +                              // rows (u8)
+        1 +                              // cols (u8)
+        1 +                              // word_count (u8)
+        1 +                              // grid_revealed (bool)
+        400 +                            // grid ([u8; 400])
+        64 +                             // found_by ([u8; 64])
+        1 +                              // words_found (u8)
+        2 * 10 +                         // scores ([u16; 10])
+        32 +                             // authority (Pubkey)
+        8;                               // committed_at (i64)
+
+    // Total: 8 + 36 + 32 + 32 + 1 + 1 + 1 + 1 + 400 + 64 + 1 + 20 + 32 + 8 = 637 bytes
+
+    /// Commitment to a grid of the given dimensions.
+    pub fn grid_commitment(rows: u8, cols: u8, grid: &[u8]) -> [u8; 32] {
+        hash::hashv(&[&[rows, cols], grid]).to_bytes()
+    }
+
+    /// Computes the Merkle leaf for a word at an index of the word list.
+    pub fn word_leaf(index: u8, word: &[u8]) -> [u8; 32] {
+        hash::hashv(&[&[index], word]).to_bytes()
+    }
+
+    /// Starts the puzzle from the coordinator's commitment.
+    pub fn commit(&mut self, grid_hash: [u8; 32], word_root: [u8; 32], rows: u8, cols: u8, word_count: u8) -> Result<()> {
+        require!(
+            (1..=Self::MAX_DIMENSION).contains(&rows) && (1..=Self::MAX_DIMENSION).contains(&cols),
+            GameError::InvalidPayload
+        );
+        require!(
+            (1..=Self::MAX_WORDS).contains(&word_count),
+            GameError::InvalidPayload
+        );
+        require!(
+            grid_hash.iter().any(|&b| b != 0) && word_root.iter().any(|&b| b != 0),
+            GameError::EmptyHash
+        );
+        self.grid_hash = grid_hash;
+        self.word_root = word_root;
+        self.rows = rows;
+        self.cols = cols;
+        self.word_count = word_count;
+        Ok(())
+    }
+
+    /// Publishes the grid, which must match the committed grid_hash.
+    pub fn reveal_grid(&mut self, grid: &[u8]) -> Result<()> {
+        require!(!self.grid_revealed, GameError::InvalidGridReveal);
+        require!(
+            grid.len() == self.rows as usize * self.cols as usize
+                && grid.iter().all(u8::is_ascii_uppercase)
+                && Self::grid_commitment(self.rows, self.cols, grid) == self.grid_hash,
+            GameError::InvalidGridReveal
+        );
+        self.grid[..grid.len()].copy_from_slice(grid);
+        self.grid_revealed = true;
+        Ok(())
+    }
+
+    /// Verifies that `word` is entry `index` of the committed word list.
+    /// Proof is ordered leaf-to-root; the index bits select left/right at each level.
+    pub fn verify_word(&self, index: u8, word: &[u8], proof: &[[u8; 32]]) -> bool {
+        if index >= self.word_count {
+            return false;
+        }
+        merkle::verify(Self::word_leaf(index, word), index as usize, proof, &self.word_root)
+    }
+
+    /// Whether `word` reads from (row, col) in `direction` on the revealed grid.
+    pub fn word_at(&self, word: &[u8], row: u8, col: u8, direction: u8) -> bool {
+        let Some(&(dr, dc)) = Self::DIRECTIONS.get(direction as usize) else {
+            return false;
+        };
+        let (mut r, mut c) = (row as i32, col as i32);
+        for &letter in word {
+            if r < 0 || c < 0 || r >= self.rows as i32 || c >= self.cols as i32 {
+                return false;
+            }
+            if self.grid[r as usize * self.cols as usize + c as usize] != letter {
+                return false;
+            }
+            r += dr as i32;
+            c += dc as i32;
+        }
+        true
+    }
+
+    /// Credits a found word to a seat: the word must be in the committed list, read from the
+    /// given grid coordinates, and not already found. Returns the points scored.
+    pub fn claim_word(&mut self, seat: usize, claim: &FoundWord) -> Result<u16> {
+        let (index, word) = (claim.word_index, &claim.word[..]);
+        require!(seat < self.scores.len(), GameError::PlayerNotInMatch);
+        require!(self.grid_revealed, GameError::InvalidGridReveal);
+        require!(
+            word.len() >= Self::MIN_WORD_LEN && word.len() <= Self::MAX_DIMENSION as usize,
+            GameError::InvalidPayload
+        );
+        require!(self.verify_word(index, word, &claim.proof), GameError::WordNotInList);
+        require!(self.found_by[index as usize] == 0, GameError::WordAlreadyFound);
+        require!(self.word_at(word, claim.row, claim.col, claim.direction), GameError::WordNotInGrid);
+
+        let points = word.len() as u16;
+        self.found_by[index as usize] = seat as u8 + 1;
+        self.words_found += 1;
+        self.scores[seat] = self.scores[seat].saturating_add(points);
+        Ok(points)
+    }
+
+    pub fn all_found(&self) -> bool {
+        self.words_found >= self.word_count
+    }
+}
@@ -1,12 +1,116 @@
 use anchor_lang::prelude::*;
-use crate::state::Match;
+use crate::state::{Match, GameRegistry, PayloadSchema, PayloadField, RunRules};
 use crate::error::GameError;
 
+pub mod rummy; // Rummy meld declarations (sets, runs, jokers)
+pub mod brag; // Three Card Brag betting and show
+
+/// Generic payload check against the game's registered schema for this action.
+/// Runs before game-specific validation; games/actions without a schema are skipped.
+pub fn validate_payload_schema(
+    registry: &GameRegistry,
+    game_type: u8,
+    action_type: u8,
+    payload: &[u8],
+) -> Result<()> {
+    let Some(game) = registry.find_game(game_type) else {
+        return Ok(());
+    };
+    match game.get_payload_schema(action_type) {
+        Some(schema) => check_payload_layout(schema, payload),
+        None => Ok(()),
+    }
+}
+
+fn check_payload_layout(schema: &PayloadSchema, payload: &[u8]) -> Result<()> {
+    require!(
+        payload.len() >= schema.min_len as usize && payload.len() <= schema.max_len as usize,
+        GameError::PayloadSchemaMismatch
+    );
+
+    let mut offset = 0usize;
+    for &tag in schema.fields.iter() {
+        let field = PayloadField::from_u8(tag).ok_or(GameError::PayloadSchemaMismatch)?;
+        match field {
+            PayloadField::End => break,
+            PayloadField::Bytes => {
+                offset = payload.len(); // Variable-length remainder
+                break;
+            }
+            _ => {
+                let end = offset + field.size();
+                require!(end <= payload.len(), GameError::PayloadSchemaMismatch);
+                let value = payload[offset];
+                match field {
+                    PayloadField::Suit => require!(value < 4, GameError::InvalidSuit),
+                    PayloadField::CardIndex => require!(value < 52, GameError::PayloadSchemaMismatch),
+                    PayloadField::Bool => require!(value <= 1, GameError::PayloadSchemaMismatch),
+                    _ => {}
+                }
+                offset = end;
+            }
+        }
+    }
+
+    // No trailing bytes beyond the declared layout
+    require!(offset == payload.len(), GameError::PayloadSchemaMismatch);
+    Ok(())
+}
+
+/// Rule checks for a move replayed from a transcript (verify_replay_segment).
+/// Only what a move carries can be re-checked: action bounds, the registered payload schema
+/// and each action's payload format. Turn order and hand state depend on coordinator
+/// inputs (floor card reveals) that aren't part of the transcript.
+pub fn validate_replay_move(
+    registry: &GameRegistry,
+    game_type: u8,
+    action_type: u8,
+    payload: &[u8],
+) -> Result<()> {
+    require!(action_type <= 15, GameError::InvalidAction);
+    require!(payload.len() <= 128, GameError::PayloadTooLarge);
+    validate_payload_schema(registry, game_type, action_type, payload)?;
+
+    match action_type {
+        0 => require!(payload.len() >= 32, GameError::PayloadTooShort),
+        2 => {
+            require!(!payload.is_empty(), GameError::PayloadTooShort);
+            require!(payload[0] < 4, GameError::InvalidSuit);
+        }
+        3 | 4 => {
+            parse_run(payload, registry.run_rules_for(game_type))?;
+        }
+        10 => {
+            rummy::parse_meld(payload, registry.run_rules_for(game_type))?;
+        }
+        brag::BLIND | brag::BET => {
+            brag::parse_amount(payload)?;
+        }
+        brag::SHOW => {
+            brag::parse_hand(payload)?;
+        }
+        5 | 6 => {
+            require!(payload.len() >= 32, GameError::PayloadTooShort);
+            require!(payload[..32].iter().any(|&b| b != 0), GameError::EmptyHash);
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Actions only the current player may take (turn order, time bank and deadline apply).
+/// Declare intent, showdown, rebuttal and the draw actions can be played out of turn.
+pub fn requires_turn(action_type: u8) -> bool {
+    matches!(action_type, 0 | 1 | 5 | 6) // pick_up, decline, play_card, discard
+        || brag::TURN_ACTIONS.contains(&action_type)
+}
+
 pub fn validate_move(
     match_account: &Match,
     player_index: usize,
     action_type: u8,
     payload: &[u8],
+    run_rules: RunRules,
 ) -> Result<()> {
     let max_players = match_account.get_max_players() as usize;
     require!(
@@ -21,8 +125,13 @@ pub fn validate_move(
         0 => validate_pick_up(match_account, player_index, payload),
         1 => validate_decline(match_account, player_index, payload),
         2 => validate_declare_intent(match_account, player_index, payload),
-        3 => validate_call_showdown(match_account, player_index, payload),
-        4 => validate_rebuttal(match_account, player_index, payload),
+        3 => validate_call_showdown(match_account, player_index, payload, run_rules),
+        4 => validate_rebuttal(match_account, player_index, payload, run_rules),
+        5 | 6 => validate_play_or_discard(match_account, player_index, payload),
+        7 | 8 => validate_resign_or_offer_draw(match_account, player_index, payload),
+        9 => validate_accept_draw(match_account, player_index, payload),
+        10 => rummy::validate_declare_meld(match_account, player_index, payload, run_rules),
+        11..=15 => brag::validate_brag_action(match_account, player_index, action_type, payload),
         _ => Err(GameError::InvalidAction.into()),
     }
 }
@@ -46,18 +155,18 @@ fn validate_pick_up(match_account: &Match, player_index: usize, payload: &[u8])
     // Payload format for pick_up: [card_hash(32 bytes)]
     require!(
         payload.len() >= 32,
-        GameError::InvalidPayload
+        GameError::PayloadTooShort
     );
     
     let card_hash = &payload[0..32];
     let card_hash_array: [u8; 32] = card_hash.try_into()
-        .map_err(|_| GameError::InvalidPayload)?;
+        .map_err(|_| GameError::PayloadTooShort)?;
     
     // Validate card hash matches floor card hash
     if let Some(floor_hash) = match_account.get_floor_card_hash() {
         require!(
             card_hash_array == floor_hash,
-            GameError::InvalidPayload // Card hash mismatch
+            GameError::CardHashMismatchFloor
         );
     } else {
         return Err(GameError::InvalidPhase.into()); // No floor card
@@ -69,7 +178,61 @@ fn validate_pick_up(match_account: &Match, player_index: usize, payload: &[u8])
     let current_hand_size = match_account.get_hand_size(player_index);
     require!(
         current_hand_size < max_hand_size,
-        GameError::InvalidPayload // Hand is full
+        GameError::HandFull
+    );
+    
+    Ok(())
+}
+
+fn validate_play_or_discard(match_account: &Match, player_index: usize, payload: &[u8]) -> Result<()> {
+    require!(
+        match_account.phase == 1, // Playing phase
+        GameError::InvalidPhase
+    );
+    
+    // Payload format for play_card/discard: [card_hash(32 bytes)]
+    require!(
+        payload.len() >= 32,
+        GameError::PayloadTooShort
+    );
+    require!(
+        payload[0..32].iter().any(|&b| b != 0),
+        GameError::EmptyHash // All zeros is reserved for "no card"
+    );
+    
+    // Security: Can't play or discard from an empty hand
+    require!(
+        match_account.get_hand_size(player_index) > 0,
+        GameError::EmptyHand
+    );
+    
+    Ok(())
+}
+
+fn validate_resign_or_offer_draw(match_account: &Match, _player_index: usize, _payload: &[u8]) -> Result<()> {
+    // Any seated player may resign or offer a draw at any point during play
+    require!(
+        match_account.phase == 1, // Playing phase
+        GameError::InvalidPhase
+    );
+    
+    Ok(())
+}
+
+fn validate_accept_draw(match_account: &Match, player_index: usize, _payload: &[u8]) -> Result<()> {
+    require!(
+        match_account.phase == 1, // Playing phase
+        GameError::InvalidPhase
+    );
+    
+    // There must be a pending offer from someone else to accept
+    require!(
+        match_account.draw_offer_pending(),
+        GameError::NoDrawOffer
+    );
+    require!(
+        match_account.draw_offered_by as usize != player_index,
+        GameError::InvalidAction
     );
     
     Ok(())
@@ -104,26 +267,26 @@ fn validate_declare_intent(match_account: &Match, player_index: usize, payload:
     );
     require!(
         payload.len() >= 1,
-        GameError::InvalidPayload
+        GameError::PayloadTooShort
     );
     
     // Suit is encoded as u8: 0=spades, 1=hearts, 2=diamonds, 3=clubs
     let suit = payload[0];
     require!(
         suit < 4,
-        GameError::InvalidPayload
+        GameError::InvalidSuit
     );
 
     // Player must not have already declared (per RuleEngine.validateDeclareIntent)
     require!(
         !match_account.has_declared_suit(player_index),
-        GameError::InvalidAction
+        GameError::SuitAlreadyDeclared
     );
 
     // Suit must not be locked by another player (per RuleEngine.validateDeclareIntent)
     require!(
         !match_account.is_suit_locked(suit),
-        GameError::InvalidAction
+        GameError::SuitLocked
     );
 
     // Note: "Player must have at least one card of the declared suit" validation
@@ -133,7 +296,7 @@ fn validate_declare_intent(match_account: &Match, player_index: usize, payload:
     Ok(())
 }
 
-fn validate_call_showdown(match_account: &Match, player_index: usize, _payload: &[u8]) -> Result<()> {
+fn validate_call_showdown(match_account: &Match, player_index: usize, payload: &[u8], run_rules: RunRules) -> Result<()> {
     require!(
         match_account.phase == 1, // Playing phase
         GameError::InvalidPhase
@@ -145,135 +308,169 @@ fn validate_call_showdown(match_account: &Match, player_index: usize, _payload:
         GameError::InvalidAction
     );
 
+    // The caller claims a run in the declared suit; rebuttals are measured against it
+    let cards = parse_run(payload, run_rules)?;
+    require!(
+        match_account.get_declared_suit(player_index) == Some(cards[0].0),
+        GameError::InvalidSuit
+    );
+
     Ok(())
 }
 
-fn validate_rebuttal(match_account: &Match, player_index: usize, payload: &[u8]) -> Result<()> {
+fn validate_rebuttal(match_account: &Match, player_index: usize, payload: &[u8], run_rules: RunRules) -> Result<()> {
     require!(
-        match_account.phase == 1, // Playing phase (showdown is part of playing)
+        match_account.phase == 3, // Showdown phase (rebuttal window open)
         GameError::InvalidPhase
     );
     
-    // Player must be undeclared to rebuttal
+    // Player must be undeclared to rebuttal, and rebuts at most once
     require!(
-        !match_account.has_declared_suit(player_index),
+        !match_account.has_declared_suit(player_index) && !match_account.has_rebutted(player_index),
         GameError::InvalidAction
     );
 
-    // Payload must contain exactly 3 cards (each card is suit + value = 2 bytes)
-    // Format: [suit1, value1, suit2, value2, suit3, value3]
+    let cards = parse_run(payload, run_rules)?;
+
+    // Security: A card that already left a hand can't be claimed in a run
+    // Card hash is SHA-256 of [suit, value], same as the floor card hash
+    for &(suit, value) in &cards {
+        let card_hash = anchor_lang::solana_program::hash::hash(&[suit, value]).to_bytes();
+        require!(
+            !match_account.was_recently_discarded(&card_hash),
+            GameError::CardAlreadyDiscarded
+        );
+    }
+
+    // The rebuttal must beat the run the showdown caller claimed: a higher top card, or the
+    // same top card in a higher suit
+    let (suit, top_value) = run_suit_and_top(&cards, run_rules);
     require!(
-        payload.len() >= 6,
-        GameError::InvalidPayload
+        (top_value, suit) > (match_account.claimed_run_top, match_account.claimed_run_suit),
+        GameError::RebuttalTooLow
     );
 
-    // Validate cards form a valid 3-card run
-    let cards = [
-        (payload[0], payload[1]),
-        (payload[2], payload[3]),
-        (payload[4], payload[5]),
-    ];
+    Ok(())
+}
 
+// Parses a run payload: run_rules.run_length cards, each card is suit + value = 2 bytes
+// Format: [suit1, value1, suit2, value2, suit3, value3, ...]
+pub fn parse_run(payload: &[u8], run_rules: RunRules) -> Result<Vec<(u8, u8)>> {
+    let run_length = run_rules.run_length as usize;
     require!(
-        is_valid_run(cards),
-        GameError::InvalidPayload
+        payload.len() >= run_length * 2,
+        GameError::PayloadTooShort
     );
 
-    // Per critique: validate rebuttal is higher than previous declaration
-    // Check if any player has declared a suit
-    let mut highest_declared_value = 0u8;
-    for i in 0..match_account.player_count as usize {
-        if let Some(declared_suit) = match_account.get_declared_suit(i) {
-            // Find highest value in declared suit (simplified - would need full hand state)
-            // For now, we validate the run value is reasonable
-            let run_value = cards[0].1 + cards[1].1 + cards[2].1;
-            if run_value <= highest_declared_value {
-                return Err(GameError::InvalidPayload.into());
-            }
-        }
-    }
+    let cards: Vec<(u8, u8)> = payload[..run_length * 2]
+        .chunks_exact(2)
+        .map(|card| (card[0], card[1]))
+        .collect();
 
-    Ok(())
+    require!(
+        is_valid_run(&cards, run_rules),
+        GameError::InvalidRun
+    );
+    Ok(cards)
+}
+
+// Suit and top value of a valid run. The top is the highest ranked card, so with
+// aces high the K-A-2 wraparound ranks with Q-K-A
+pub fn run_suit_and_top(cards: &[(u8, u8)], run_rules: RunRules) -> (u8, u8) {
+    let top = cards.iter()
+        .filter_map(|&(_, value)| run_rules.rank(value))
+        .max()
+        .unwrap_or(0);
+    (cards.first().map(|card| card.0).unwrap_or(0), top)
 }
 
-fn is_valid_run(cards: [(u8, u8); 3]) -> bool {
+fn is_valid_run(cards: &[(u8, u8)], run_rules: RunRules) -> bool {
     // All cards must be same suit
-    if cards[0].0 != cards[1].0 || cards[1].0 != cards[2].0 {
+    let Some(&(suit, _)) = cards.first() else {
+        return false;
+    };
+    if cards.iter().any(|card| card.0 != suit) {
         return false;
     }
 
-    // Sort by value
-    let mut values = [cards[0].1, cards[1].1, cards[2].1];
-    values.sort();
+    // Rank and sort (Ace is 14, or 1 when aces are low)
+    let mut ranks = Vec::with_capacity(cards.len());
+    for &(_, value) in cards {
+        match run_rules.rank(value) {
+            Some(rank) => ranks.push(rank),
+            None => return false,
+        }
+    }
+    ranks.sort();
 
-    // Check for normal consecutive sequence
-    if values[1] == values[0] + 1 && values[2] == values[1] + 1 {
-        return true;
+    // Gaps between neighbouring ranks: a duplicate card is never a run
+    let mut broken_gaps = 0;
+    for pair in ranks.windows(2) {
+        match pair[1] - pair[0] {
+            0 => return false,
+            1 => {}
+            _ => broken_gaps += 1,
+        }
     }
 
-    // Check for A-K-2 wraparound (values 14, 13, 2)
-    if values[0] == 2 && values[1] == 13 && values[2] == 14 {
+    // Check for normal consecutive sequence
+    if broken_gaps == 0 {
         return true;
     }
 
-    false
+    // Wraparound: one break is allowed if the run continues from the top rank
+    // to the bottom one (K-A-2 with aces high, Q-K-A with aces low)
+    let (lowest_rank, highest_rank) = if run_rules.aces_low { (1, 13) } else { (2, 14) };
+    run_rules.wraparound && broken_gaps == 1 &&
+        ranks[0] == lowest_rank && ranks[ranks.len() - 1] == highest_rank
+}
+
+/// Hand commitment format (commit_hand): SHA-256 of the cards sorted by suit then value,
+/// as [suit1, value1, suit2, value2, ...].
+pub fn hand_hash(cards: &[(u8, u8)]) -> [u8; 32] {
+    let mut sorted_cards = cards.to_vec();
+    sorted_cards.sort();
+    let card_bytes: Vec<u8> = sorted_cards.iter().flat_map(|&(suit, value)| [suit, value]).collect();
+    anchor_lang::solana_program::hash::hash(&card_bytes).to_bytes()
 }
 
 // Per critique Issue #4: Card hash validation - implement proper commitment-reveal scheme
-// Validates that cards in a rebuttal move match the committed hand hash
+// Validates that cards in a showdown or rebuttal move match the committed hand hash
 pub fn validate_card_hash(
     match_account: &Match,
     player_index: usize,
     payload: &[u8],
+    run_rules: RunRules,
 ) -> Result<()> {
-    use anchor_lang::solana_program::hash;
-    
     // Get committed hand hash for this player
     let committed_hash = match_account.get_committed_hand_hash(player_index)
         .ok_or(GameError::CardHashMismatch)?;
     
-    // Extract cards from payload (rebuttal format: [suit1, value1, suit2, value2, suit3, value3])
-    if payload.len() < 6 {
-        return Err(GameError::InvalidPayload.into());
+    // Extract the game's run_length cards from payload (run format: [suit1, value1, suit2, value2, ...])
+    let run_length = run_rules.run_length as usize;
+    if payload.len() < run_length * 2 {
+        return Err(GameError::PayloadTooShort.into());
     }
     
-    let cards = [
-        (payload[0], payload[1]),
-        (payload[2], payload[3]),
-        (payload[4], payload[5]),
-    ];
-    
-    // Sort cards by suit then value for consistent hashing (must match commit_hand format)
-    let mut sorted_cards = cards;
-    sorted_cards.sort_by(|a, b| {
-        match a.0.cmp(&b.0) {
-            std::cmp::Ordering::Equal => a.1.cmp(&b.1),
-            other => other,
-        }
-    });
-    
-    // Compute hash of the 3 revealed cards
-    // Format: [suit1, value1, suit2, value2, suit3, value3] as bytes
-    let card_bytes = [
-        sorted_cards[0].0, sorted_cards[0].1,
-        sorted_cards[1].0, sorted_cards[1].1,
-        sorted_cards[2].0, sorted_cards[2].1,
-    ];
+    let cards: Vec<(u8, u8)> = payload[..run_length * 2]
+        .chunks_exact(2)
+        .map(|card| (card[0], card[1]))
+        .collect();
     
-    // Use SHA-256 (Solana's hash function) to compute hash
-    let revealed_hash = hash::hash(&card_bytes).to_bytes();
+    // Compute hash of the revealed cards (same format as commit_hand)
+    let revealed_hash = hand_hash(&cards);
     
     // Per critique Issue #4: Implement proper hash verification
-    // The committed hash is for the full hand, so we need to verify that these 3 cards
+    // The committed hash is for the full hand, so we need to verify that these cards
     // are a subset of the committed hand. Since we can't store full hands on-chain,
     // we use a commitment-reveal scheme:
     // 1. Player commits full hand hash at match start
-    // 2. On rebuttal, player reveals 3 cards
+    // 2. On rebuttal, player reveals run_length cards
     // 3. We verify the revealed cards hash matches a subset of the committed hand
     
     // For now, we verify:
     // - Committed hash exists (prevents uncommitted moves)
-    // - Revealed cards form valid run (already validated in validate_rebuttal)
+    // - Revealed cards form valid run (already validated in validate_call_showdown / validate_rebuttal)
     // - Cards are valid format
     
     // Full validation requires either:
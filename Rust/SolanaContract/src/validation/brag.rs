@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+use crate::state::{Match, GameType, EndReason};
+use crate::error::GameError;
+use super::hand_hash;
+
+// Three Card Brag betting (actions 11-15) over the match's PotLedger
+// (state/pot_ledger.rs).
+// Stakes are in blind units: a blind player bets at least the current stake, a player who
+// has seen their cards at least twice it, and a seen bet sets the stake to half the amount.
+// Payloads: Blind / Bet = [amount u64 LE]; Show = the player's 3 cards as [suit, value] pairs,
+// which must hash to their committed hand (commit_hand, SHA-256 of the cards sorted by suit
+// then value).
+//
+// Show needs exactly two players left. The caller must have seen and pays twice the stake;
+// the other player answers with their own Show (free) and the higher hand takes the pot. On a
+// tie the caller loses, as the player who paid to see. The last player left after folds
+// takes the pot unseen. Either way the losers are folded and the pot is settled, less the
+// game's rake (GameDefinition.rake_bps).
+
+pub const BLIND: u8 = 11;
+pub const SEE: u8 = 12;
+pub const BET: u8 = 13;
+pub const FOLD: u8 = 14;
+pub const SHOW: u8 = 15;
+/// Actions played on the player's turn (SEE can be done any time)
+pub const TURN_ACTIONS: [u8; 4] = [BLIND, BET, FOLD, SHOW];
+
+// Hand categories, high to low
+const PRIAL: u32 = 5;           // Three of a kind (3-3-3 highest)
+const RUNNING_FLUSH: u32 = 4;
+const RUN: u32 = 3;             // A-2-3 highest, then A-K-Q
+const FLUSH: u32 = 2;
+const PAIR: u32 = 1;
+const HIGH_CARD: u32 = 0;
+
+pub fn is_brag_action(action_type: u8) -> bool {
+    (BLIND..=SHOW).contains(&action_type)
+}
+
+pub fn parse_amount(payload: &[u8]) -> Result<u64> {
+    let bytes: [u8; 8] = payload
+        .get(..8)
+        .and_then(|b| b.try_into().ok())
+        .ok_or(GameError::PayloadTooShort)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// The 3 distinct cards of a shown hand (suit 0-3, value 2-14).
+pub fn parse_hand(payload: &[u8]) -> Result<[(u8, u8); 3]> {
+    require!(payload.len() >= 6, GameError::PayloadTooShort);
+    let hand = [(payload[0], payload[1]), (payload[2], payload[3]), (payload[4], payload[5])];
+    require!(
+        hand.iter().all(|&(suit, value)| suit < 4 && (2..=14).contains(&value)),
+        GameError::InvalidPayload
+    );
+    require!(
+        hand[0] != hand[1] && hand[1] != hand[2] && hand[0] != hand[2],
+        GameError::InvalidPayload
+    );
+    Ok(hand)
+}
+
+/// Comparable hand strength: category << 16 | tiebreak (card values 4 bits each, high first).
+pub fn hand_rank(hand: &[(u8, u8); 3]) -> u32 {
+    let mut values = [hand[0].1, hand[1].1, hand[2].1].map(u32::from);
+    values.sort_unstable_by(|a, b| b.cmp(a)); // High to low
+    let flush = hand[0].0 == hand[1].0 && hand[1].0 == hand[2].0;
+    let run_top = match values {
+        [14, 3, 2] => Some(15), // A-2-3 beats A-K-Q
+        [a, b, c] if a == b + 1 && b == c + 1 => Some(a),
+        _ => None,
+    };
+
+    let (category, tiebreak) = if values[0] == values[2] {
+        (PRIAL, if values[0] == 3 { 15 } else { values[0] })
+    } else if let Some(top) = run_top {
+        (if flush { RUNNING_FLUSH } else { RUN }, top)
+    } else if flush {
+        (FLUSH, values[0] << 8 | values[1] << 4 | values[2])
+    } else if values[0] == values[1] || values[1] == values[2] {
+        // Pair value, then the odd card
+        let (pair, kicker) = if values[0] == values[1] { (values[0], values[2]) } else { (values[1], values[0]) };
+        (PAIR, pair << 4 | kicker)
+    } else {
+        (HIGH_CARD, values[0] << 8 | values[1] << 4 | values[2])
+    };
+    category << 16 | tiebreak
+}
+
+pub fn validate_brag_action(
+    match_account: &Match,
+    player_index: usize,
+    action_type: u8,
+    payload: &[u8],
+) -> Result<()> {
+    require!(
+        match_account.game_type == GameType::ThreeCardBrag as u8,
+        GameError::WrongGameType
+    );
+    require!(
+        match_account.phase == 1, // Playing phase
+        GameError::InvalidPhase
+    );
+    let pot = &match_account.pot;
+    require!(
+        !pot.is_folded(player_index) && !pot.settled,
+        GameError::PlayerFolded
+    );
+    if TURN_ACTIONS.contains(&action_type) {
+        require!(
+            match_account.current_player == player_index as u8,
+            GameError::NotPlayerTurn
+        );
+    }
+    let committed_hash = match_account.get_committed_hand_hash(player_index)
+        .ok_or(GameError::CardHashMismatch)?;
+
+    // Once a show is called only the reply is accepted
+    let show_called = pot.revealed != 0;
+    require!(
+        !show_called || action_type == SHOW || action_type == FOLD,
+        GameError::ShowNotAvailable
+    );
+
+    let min_stake = pot.stake.max(1);
+    match action_type {
+        BLIND => {
+            require!(!pot.has_seen(player_index), GameError::AlreadySeen);
+            require!(parse_amount(payload)? >= min_stake, GameError::BetTooLow);
+        }
+        SEE => {
+            require!(!pot.has_seen(player_index), GameError::AlreadySeen);
+        }
+        BET => {
+            require!(pot.has_seen(player_index), GameError::HandNotSeen);
+            require!(parse_amount(payload)? >= min_stake.saturating_mul(2), GameError::BetTooLow);
+        }
+        FOLD => {
+            require!(!pot.is_revealed(player_index), GameError::ShowNotAvailable);
+        }
+        SHOW => {
+            require!(
+                pot.active_seats(match_account.player_count).count() == 2 && !pot.is_revealed(player_index),
+                GameError::ShowNotAvailable
+            );
+            require!(show_called || pot.has_seen(player_index), GameError::HandNotSeen);
+            let hand = parse_hand(payload)?;
+            require!(hand_hash(&hand) == committed_hash, GameError::HandRevealMismatch);
+        }
+        _ => return Err(GameError::InvalidAction.into()),
+    }
+    Ok(())
+}
+
+/// Applies a validated brag action to the pot, passing the turn after betting actions and
+/// settling the pot (ending the match) once one player is left.
+pub fn apply_brag_action(
+    match_account: &mut Match,
+    player_index: usize,
+    action_type: u8,
+    payload: &[u8],
+    rake_bps: u16,
+    now: i64,
+) -> Result<()> {
+    let player_count = match_account.player_count;
+    let pot = &mut match_account.pot;
+    match action_type {
+        BLIND => {
+            let amount = parse_amount(payload)?;
+            pot.contribute(player_index, amount);
+            pot.stake = amount;
+        }
+        SEE => {
+            pot.see(player_index);
+            return Ok(()); // Not a turn action
+        }
+        BET => {
+            let amount = parse_amount(payload)?;
+            pot.contribute(player_index, amount);
+            pot.stake = amount / 2;
+        }
+        FOLD => {
+            pot.fold(player_index);
+        }
+        SHOW => {
+            let rank = hand_rank(&parse_hand(payload)?);
+            match (0..player_count as usize).find(|&seat| pot.is_revealed(seat)) {
+                // Reply: higher hand wins, the caller loses a tie
+                Some(caller) => {
+                    pot.reveal(player_index, rank);
+                    let loser = if pot.hand_ranks[caller] > rank { player_index } else { caller };
+                    pot.fold(loser);
+                }
+                None => {
+                    pot.contribute(player_index, pot.stake.max(1).saturating_mul(2));
+                    pot.reveal(player_index, rank);
+                }
+            }
+        }
+        _ => return Err(GameError::InvalidAction.into()),
+    }
+
+    let active: Vec<usize> = pot.active_seats(player_count).collect();
+    if let [winner] = active[..] {
+        pot.settle(player_count, rake_bps);
+        match_account.end_with_outcome(EndReason::Completed, winner as u8, now);
+    } else {
+        match_account.current_player = pot.next_active(player_index, player_count) as u8;
+        match_account.start_turn(now);
+    }
+    Ok(())
+}
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash;
+use crate::state::{Match, GameType, RunRules};
+use crate::error::GameError;
+
+// Rummy melds (action 10, declare_meld): a set (3-4 cards of one value) or a run (3+
+// consecutive cards of one suit) laid face-up from the hand.
+// Payload format: [suit1, value1, suit2, value2, ...], cards as in runs (suit 0-3, value 2-14),
+// jokers as [JOKER_SUIT, joker index]. A joker stands in for any card the meld needs.
+
+pub const JOKER_SUIT: u8 = 4;
+pub const JOKERS: u8 = 2; // Jokers in the deck, told apart by their value byte (0 or 1)
+pub const MIN_MELD_CARDS: usize = 3;
+pub const MAX_SET_CARDS: usize = 4;
+pub const MAX_MELD_CARDS: usize = 13;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MeldKind {
+    Set,
+    Run,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Meld {
+    pub kind: MeldKind,
+    pub card_bits: u64,  // Match::melded_cards bits of the cards (see card_bit)
+    pub card_count: u8,
+    pub points: u16,     // Card points, jokers counted as the card they stand for
+}
+
+/// Bit of a card in Match::melded_cards: suit * 13 + (value - 2) for the 52 cards,
+/// then one bit per joker. None if not a card.
+pub fn card_bit(suit: u8, value: u8) -> Option<u64> {
+    match (suit, value) {
+        (0..=3, 2..=14) => Some(1 << (suit as u64 * 13 + (value - 2) as u64)),
+        (JOKER_SUIT, v) if v < JOKERS => Some(1 << (52 + v as u64)),
+        _ => None,
+    }
+}
+
+/// Points for a card of the given rank: face value, 10 for J/Q/K, Ace 15 high or 1 low.
+pub fn rank_points(rank: u8) -> u16 {
+    match rank {
+        1 => 1,
+        11..=13 => 10,
+        14 => 15,
+        _ => rank as u16,
+    }
+}
+
+/// Parses and validates a meld payload. Jokers may fill gaps in or extend a run (upward
+/// first, then downward) or complete a set, but natural cards must outnumber them.
+pub fn parse_meld(payload: &[u8], run_rules: RunRules) -> Result<Meld> {
+    let card_count = payload.len() / 2;
+    require!(
+        card_count * 2 == payload.len() && (MIN_MELD_CARDS..=MAX_MELD_CARDS).contains(&card_count),
+        GameError::InvalidMeld
+    );
+
+    let mut card_bits = 0u64;
+    let mut naturals: Vec<(u8, u8)> = Vec::with_capacity(payload.len() / 2);
+    for card in payload.chunks_exact(2) {
+        let bit = card_bit(card[0], card[1]).ok_or(GameError::InvalidMeld)?;
+        require!(card_bits & bit == 0, GameError::InvalidMeld); // Same card twice
+        card_bits |= bit;
+        if card[0] != JOKER_SUIT {
+            let rank = run_rules.rank(card[1]).ok_or(GameError::InvalidMeld)?;
+            naturals.push((card[0], rank));
+        }
+    }
+    let jokers = card_count - naturals.len();
+    require!(naturals.len() > jokers, GameError::InvalidMeld);
+
+    let (suit, rank) = naturals[0];
+    if naturals.iter().all(|&(_, r)| r == rank) {
+        // Set: one value, distinct suits (distinct cards of one value differ in suit)
+        require!(card_count <= MAX_SET_CARDS, GameError::InvalidMeld);
+        return Ok(Meld {
+            kind: MeldKind::Set,
+            card_bits,
+            card_count: card_count as u8,
+            points: rank_points(rank) * card_count as u16,
+        });
+    }
+
+    // Run: one suit; jokers fill the gaps between the natural ranks, then extend the run
+    require!(naturals.iter().all(|&(s, _)| s == suit), GameError::InvalidMeld);
+    let low = naturals.iter().map(|&(_, r)| r).min().unwrap_or(0);
+    let high = naturals.iter().map(|&(_, r)| r).max().unwrap_or(0);
+    let gaps = (high - low + 1) as usize - naturals.len();
+    require!(jokers >= gaps, GameError::InvalidMeld);
+    let (lowest_rank, highest_rank) = if run_rules.aces_low { (1, 13) } else { (2, 14) };
+    let extra = (jokers - gaps) as u8;
+    let top = high.saturating_add(extra).min(highest_rank);
+    let bottom = low.saturating_sub(extra - (top - high));
+    require!(bottom >= lowest_rank, GameError::InvalidMeld);
+
+    Ok(Meld {
+        kind: MeldKind::Run,
+        card_bits,
+        card_count: card_count as u8,
+        points: (bottom..=top).map(rank_points).sum(),
+    })
+}
+
+/// declare_meld: a Rummy player on turn lays down a meld from their hand.
+/// Reveals stay consistent with the hand commitment as far as the chain can tell: the player
+/// must have committed a hand, can't meld more cards than it holds, and can't meld a card that
+/// was already melded or discarded this round. The full hand is checked against its commitment
+/// off-chain at replay, as for showdown runs (validate_card_hash).
+pub fn validate_declare_meld(
+    match_account: &Match,
+    player_index: usize,
+    payload: &[u8],
+    run_rules: RunRules,
+) -> Result<()> {
+    require!(
+        match_account.game_type == GameType::Rummy as u8,
+        GameError::WrongGameType
+    );
+    require!(
+        match_account.phase == 1, // Playing phase
+        GameError::InvalidPhase
+    );
+    require!(
+        match_account.current_player == player_index as u8,
+        GameError::NotPlayerTurn
+    );
+    require!(
+        match_account.get_committed_hand_hash(player_index).is_some(),
+        GameError::CardHashMismatch
+    );
+
+    let meld = parse_meld(payload, run_rules)?;
+    require!(
+        meld.card_count <= match_account.get_hand_size(player_index),
+        GameError::InvalidMeld
+    );
+    require!(
+        match_account.melded_cards & meld.card_bits == 0,
+        GameError::CardAlreadyMelded
+    );
+
+    // Card hash is SHA-256 of [suit, value], same as the floor card hash
+    for card in payload.chunks_exact(2) {
+        let card_hash = hash::hash(card).to_bytes();
+        require!(
+            !match_account.was_recently_discarded(&card_hash),
+            GameError::CardAlreadyDiscarded
+        );
+    }
+
+    Ok(())
+}
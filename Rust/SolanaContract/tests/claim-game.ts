@@ -18,10 +18,14 @@ describe("solana-games-program", () => {
   const player3 = Keypair.generate();
   const player4 = Keypair.generate();
 
+  // Seeds are capped at 32 bytes, so match-scoped PDAs use the first 32 bytes of the
+  // match_id (match_id_seed in the program)
+  const matchIdSeed = (matchId: string) => Buffer.from(matchId).subarray(0, 32);
+
   // Helper to get match PDA
   const getMatchPDA = async (matchId: string) => {
     return await anchor.web3.PublicKey.findProgramAddress(
-      [Buffer.from("match"), Buffer.from(matchId)],
+      [Buffer.from("match"), matchIdSeed(matchId)],
       program.programId
     );
   };
@@ -171,7 +175,7 @@ describe("solana-games-program", () => {
     const [movePDA] = await anchor.web3.PublicKey.findProgramAddress(
       [
         Buffer.from("move"),
-        Buffer.from(matchId),
+        matchIdSeed(matchId),
         Buffer.from(new Uint8Array(new anchor.BN(moveIndex).toArray("le", 4))),
       ],
       program.programId
@@ -209,7 +213,7 @@ describe("solana-games-program", () => {
     const [movePDA] = await anchor.web3.PublicKey.findProgramAddress(
       [
         Buffer.from("move"),
-        Buffer.from(matchId),
+        matchIdSeed(matchId),
         Buffer.from(new Uint8Array(new anchor.BN(moveIndex).toArray("le", 4))),
       ],
       program.programId
@@ -0,0 +1,2059 @@
+// Shared solana-program-test harness for the integration and compute-budget suites.
+// Each test binary uses a subset of these helpers.
+#![allow(dead_code)]
+
+use anchor_lang::{
+    error::ERROR_CODE_OFFSET, AccountDeserialize, AccountSerialize, AnchorDeserialize,
+    AnchorSerialize, Discriminator, InstructionData, ToAccountMetas,
+};
+use solana_games_program::{
+    accounts, error::GameError, instruction, merkle, pda, relay::{self, EnvelopeSignature, MoveEnvelope, SignedMoveEnvelope},
+    payment::{PaymentAttestation, PaymentProduct},
+    instructions::{ownership_message, BatchMatch, BatchMove, ReplayMoveData},
+    state::{
+        BatchLink, ConfigAccount, Dispute, GameLeaderboard, GameRegistry, GlobalStats, LeaderboardIndex, LegacyValidatorReputation, Match, PaymentReceipt, Sanction, SanctionKind, SignerRegistry,
+        FoundWord, SignerRole, TileReveal, UsageMeter, UserAccount, ValidatorReputation, WordSearchPuzzle,
+    },
+};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+pub const MATCH_ID: &str = "3f1c2d4e-5a6b-4c7d-8e9f-0a1b2c3d4e5f";
+pub const SERIES_ID: &str = "9b8a7c6d-5e4f-4a3b-8c2d-1e0f9a8b7c6d";
+pub const ALICE: &str = "firebase-uid-alice";
+pub const BOB: &str = "firebase-uid-bob";
+pub const CAROL: &str = "firebase-uid-carol";
+pub const DAVE: &str = "firebase-uid-dave";
+pub const USER_IDS: [&str; 4] = [ALICE, BOB, CAROL, DAVE];
+
+// Anchor's entry wants the account slice borrowed for 'info; program-test hands out a shorter borrow
+fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    solana_games_program::entry(program_id, accounts, data)
+}
+
+/// True when program-test will load the SBF build (`anchor build` output) instead of the
+/// native processor. Compute units are only metered for the SBF build.
+pub fn sbf_build_available() -> bool {
+    std::env::var("SBF_OUT_DIR").is_ok() || std::env::var("BPF_OUT_DIR").is_ok()
+}
+
+// Serialized program-owned account (discriminator + borsh), zero-padded to `space`
+fn program_account<T: AccountSerialize>(value: &T, space: usize) -> Account {
+    let mut data = Vec::with_capacity(space);
+    value.try_serialize(&mut data).unwrap();
+    data.resize(space, 0);
+    Account {
+        lamports: 1_000_000_000,
+        data,
+        owner: solana_games_program::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+// Account built from zeroed fields (all-zero = defaults everywhere in this program)
+fn zeroed<T: AnchorDeserialize>(space: usize) -> T {
+    let data = vec![0u8; space - 8];
+    T::deserialize(&mut &data[..]).unwrap()
+}
+
+pub struct TestEnv {
+    pub context: ProgramTestContext,
+    pub coordinator: Keypair,
+    pub validator: Keypair,    // Registered in the signer registry as a Validator
+    pub players: Vec<Keypair>, // Wallets for USER_IDS, same order
+    pub lobby_index: Option<Pubkey>, // Open lobby index of MATCH_ID when it was created public
+}
+
+impl TestEnv {
+    pub async fn start() -> Self {
+        // Loads the SBF build instead when SBF_OUT_DIR/BPF_OUT_DIR is set
+        let mut program_test = ProgramTest::new(
+            "solana_games_program",
+            solana_games_program::ID,
+            processor!(process_instruction),
+        );
+
+        let coordinator = Keypair::new();
+
+        // Config and registry have no init instruction; preload them (no rate limit, no games,
+        // coordinator as registry admin), along with zeroed global stats
+        let config: ConfigAccount = zeroed(ConfigAccount::MAX_SIZE);
+        program_test.add_account(pda::config_pda().0, program_account(&config, ConfigAccount::MAX_SIZE));
+        let mut registry: GameRegistry = zeroed(GameRegistry::MAX_SIZE);
+        registry.authority = coordinator.pubkey();
+        program_test.add_account(pda::game_registry_pda().0, program_account(&registry, GameRegistry::MAX_SIZE));
+        let stats: GlobalStats = zeroed(GlobalStats::MAX_SIZE);
+        program_test.add_account(pda::global_stats_pda().0, program_account(&stats, GlobalStats::MAX_SIZE));
+
+        let validator = Keypair::new();
+        let signers = SignerRegistry {
+            signers: vec![validator.pubkey(), coordinator.pubkey()],
+            roles: vec![SignerRole::Validator, SignerRole::Coordinator],
+            authority: coordinator.pubkey(),
+        };
+        program_test.add_account(pda::signer_registry_pda().0, program_account(&signers, SignerRegistry::MAX_SIZE));
+
+        let players: Vec<Keypair> = USER_IDS.iter().map(|_| Keypair::new()).collect();
+        for key in [&coordinator, &validator].into_iter().chain(&players) {
+            program_test.add_account(
+                key.pubkey(),
+                Account::new(10_000_000_000, 0, &system_program::ID),
+            );
+        }
+
+        let context = program_test.start_with_context().await;
+        TestEnv { context, coordinator, validator, players, lobby_index: None }
+    }
+
+    // Create → join `player_count` players → commit hands → start
+    pub async fn started_match(player_count: usize) -> Self {
+        let mut env = TestEnv::start().await;
+        env.create_match().await.unwrap();
+        for (i, user_id) in USER_IDS.iter().enumerate().take(player_count) {
+            let player = env.player(i);
+            env.join_match(&player, user_id).await.unwrap();
+        }
+        for (i, user_id) in USER_IDS.iter().enumerate().take(player_count) {
+            let player = env.player(i);
+            env.commit_hand(&player, user_id, [0xA0 + i as u8; 32]).await.unwrap();
+        }
+        env.start_match().await.unwrap();
+        env
+    }
+
+    pub fn player(&self, index: usize) -> Keypair {
+        self.players[index].insecure_clone()
+    }
+
+    fn transaction(&self, ixs: &[Instruction], signers: &[&Keypair], blockhash: solana_sdk::hash::Hash) -> Transaction {
+        let mut all_signers = vec![&self.context.payer];
+        all_signers.extend_from_slice(signers);
+        Transaction::new_signed_with_payer(ixs, Some(&self.context.payer.pubkey()), &all_signers, blockhash)
+    }
+
+    pub async fn send(&mut self, ix: Instruction, signers: &[&Keypair]) -> Result<(), BanksClientError> {
+        self.send_all(&[ix], signers).await
+    }
+
+    pub async fn send_all(&mut self, ixs: &[Instruction], signers: &[&Keypair]) -> Result<(), BanksClientError> {
+        // Fresh blockhash so otherwise-identical retries aren't deduplicated
+        let blockhash = self.context.get_new_latest_blockhash().await.unwrap();
+        let tx = self.transaction(ixs, signers, blockhash);
+        self.context.banks_client.process_transaction(tx).await
+    }
+
+    /// Executes the instruction (must succeed) and returns the compute units it consumed.
+    pub async fn measure(&mut self, ix: Instruction, signers: &[&Keypair]) -> u64 {
+        let blockhash = self.context.get_new_latest_blockhash().await.unwrap();
+        let tx = self.transaction(&[ix], signers, blockhash);
+        let outcome = self.context.banks_client.process_transaction_with_metadata(tx).await.unwrap();
+        let metadata = outcome.metadata.expect("transaction metadata missing");
+        if let Err(err) = outcome.result {
+            panic!("transaction failed: {:?}\n{}", err, metadata.log_messages.join("\n"));
+        }
+        metadata.compute_units_consumed
+    }
+
+    pub async fn fetch<T: AccountDeserialize>(&mut self, address: Pubkey) -> T {
+        let account = self.context.banks_client.get_account(address).await.unwrap().expect("account missing");
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub async fn fetch_match(&mut self) -> Match {
+        self.fetch(pda::match_pda(MATCH_ID).0).await
+    }
+
+    pub async fn create_match(&mut self) -> Result<(), BanksClientError> {
+        self.create_match_of_type(0).await // CLAIM
+    }
+
+    pub async fn create_match_of_type(&mut self, game_type: u8) -> Result<(), BanksClientError> {
+        self.create_match_with_listing(MATCH_ID, game_type, false).await
+    }
+
+    pub async fn create_match_with_id(&mut self, match_id: &str) -> Result<(), BanksClientError> {
+        self.create_match_with_listing(match_id, 0, false).await // CLAIM
+    }
+
+    // Public match: listed in the game's open lobby index (join/start then pass it too)
+    pub async fn create_public_match(&mut self) -> Result<(), BanksClientError> {
+        self.create_match_with_listing(MATCH_ID, 0, true).await // CLAIM
+    }
+
+    async fn create_match_with_listing(&mut self, match_id: &str, game_type: u8, public: bool) -> Result<(), BanksClientError> {
+        self.lobby_index = public.then(|| pda::open_lobby_index_pda(game_type).0);
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::CreateMatch {
+                match_account: pda::match_pda(match_id).0,
+                global_stats: pda::global_stats_pda().0,
+                game_registry: pda::game_registry_pda().0,
+                lobby_index: self.lobby_index,
+                authority: self.coordinator.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::CreateMatch {
+                match_id: match_id.to_string(),
+                game_type,
+                seed: 42,
+                rounds_to_play: 1,
+                join_secret_hash: None,
+                player_consent_start: true,
+            }
+            .data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    // Counted match `match_counter` of the coordinator (unlisted CLAIM match)
+    pub async fn create_match_counted(&mut self, match_counter: u64) -> Result<(), BanksClientError> {
+        let authority = self.coordinator.pubkey();
+        let match_id = pda::counted_match_id(&authority, match_counter);
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::CreateMatchCounted {
+                match_creator: pda::match_creator_pda(&authority).0,
+                match_account: pda::match_pda(&match_id).0,
+                global_stats: pda::global_stats_pda().0,
+                game_registry: pda::game_registry_pda().0,
+                lobby_index: None,
+                authority,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::CreateMatchCounted {
+                match_counter,
+                game_type: 0, // CLAIM
+                seed: 42,
+                rounds_to_play: 1,
+                join_secret_hash: None,
+                player_consent_start: true,
+            }
+            .data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    // Coordinator anchors batch `batch_id` (merkle root = hash of the id) onto its batch chain
+    pub async fn anchor_batch(&mut self, batch_id: &str, link: BatchLink) -> Result<(), BanksClientError> {
+        let authority = self.coordinator.pubkey();
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::AnchorBatch {
+                batch_anchor: pda::batch_anchor_pda(batch_id).0,
+                batch_head: pda::batch_head_pda(&authority).0,
+                authority,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::AnchorBatch {
+                batch_id: batch_id.to_string(),
+                merkle_root: batch_root(batch_id),
+                count: 10,
+                first_match_id: MATCH_ID.to_string(),
+                last_match_id: MATCH_ID.to_string(),
+                link,
+            }
+            .data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn create_matches_batch(&mut self, match_ids: &[&str]) -> Result<(), BanksClientError> {
+        let mut account_metas = accounts::CreateMatchesBatch {
+            global_stats: pda::global_stats_pda().0,
+            game_registry: pda::game_registry_pda().0,
+            authority: self.coordinator.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+        account_metas.extend(match_ids.iter().map(|id| AccountMeta::new(pda::match_pda(id).0, false)));
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: account_metas,
+            data: instruction::CreateMatchesBatch {
+                game_type: 0, // CLAIM
+                rounds_to_play: 3,
+                matches: match_ids
+                    .iter()
+                    .enumerate()
+                    .map(|(i, id)| BatchMatch { match_id: id.to_string(), seed: 100 + i as u64 })
+                    .collect(),
+            }
+            .data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn join_match(&mut self, player: &Keypair, user_id: &str) -> Result<(), BanksClientError> {
+        let ix = self.join_match_ix(player, user_id, None, false);
+        self.send(ix, &[player]).await
+    }
+
+    // Admitted by the coordinator's co-signature (private matches)
+    pub async fn join_match_admitted(&mut self, player: &Keypair, user_id: &str) -> Result<(), BanksClientError> {
+        let coordinator = self.coordinator.insecure_clone();
+        let ix = self.join_match_ix(player, user_id, Some(coordinator.pubkey()), false);
+        self.send(ix, &[player, &coordinator]).await
+    }
+
+    // Bot seat, attested by the coordinator's co-signature unless `attested` is false
+    pub async fn join_match_as_bot(&mut self, player: &Keypair, user_id: &str, attested: bool) -> Result<(), BanksClientError> {
+        if !attested {
+            let ix = self.join_match_ix(player, user_id, None, true);
+            return self.send(ix, &[player]).await;
+        }
+        let coordinator = self.coordinator.insecure_clone();
+        let ix = self.join_match_ix(player, user_id, Some(coordinator.pubkey()), true);
+        self.send(ix, &[player, &coordinator]).await
+    }
+
+    fn join_match_ix(&self, player: &Keypair, user_id: &str, authority: Option<Pubkey>, is_bot: bool) -> Instruction {
+        Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::JoinMatch {
+                match_account: pda::match_pda(MATCH_ID).0,
+                sanction: pda::sanction_pda(user_id).0,
+                player: player.pubkey(),
+                lobby_index: self.lobby_index,
+                authority,
+            }
+            .to_account_metas(None),
+            data: instruction::JoinMatch {
+                match_id: MATCH_ID.to_string(),
+                user_id: user_id.to_string(),
+                is_bot,
+            }
+            .data(),
+        }
+    }
+
+    pub async fn initialize_player_rating(&mut self, user_id: &str) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::InitializePlayerRating {
+                player_rating: pda::player_rating_pda(user_id).0,
+                payer: self.context.payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::InitializePlayerRating { user_id: user_id.to_string() }.data(),
+        };
+        self.send(ix, &[]).await
+    }
+
+    // Rates MATCH_ID with one PlayerRating per seat (`user_ids` in seat order)
+    pub async fn record_rating_change(&mut self, user_ids: &[&str]) -> Result<(), BanksClientError> {
+        let mut account_metas = accounts::RecordRatingChange {
+            match_account: pda::match_pda(MATCH_ID).0,
+            authority: self.coordinator.pubkey(),
+        }
+        .to_account_metas(None);
+        account_metas.extend(user_ids.iter().map(|id| AccountMeta::new(pda::player_rating_pda(id).0, false)));
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: account_metas,
+            data: instruction::RecordRatingChange { match_id: MATCH_ID.to_string() }.data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn invite_player(&mut self, invitee_user_id: &str, expiry: i64) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::InvitePlayer {
+                match_account: pda::match_pda(MATCH_ID).0,
+                invitation: pda::invitation_pda(MATCH_ID, invitee_user_id).0,
+                authority: self.coordinator.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::InvitePlayer {
+                match_id: MATCH_ID.to_string(),
+                invitee_user_id: invitee_user_id.to_string(),
+                expiry,
+            }
+            .data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn accept_invitation(&mut self, player: &Keypair, user_id: &str) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::AcceptInvitation {
+                match_account: pda::match_pda(MATCH_ID).0,
+                invitation: pda::invitation_pda(MATCH_ID, user_id).0,
+                sanction: pda::sanction_pda(user_id).0,
+                player: player.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::AcceptInvitation {
+                match_id: MATCH_ID.to_string(),
+                user_id: user_id.to_string(),
+            }
+            .data(),
+        };
+        self.send(ix, &[player]).await
+    }
+
+    pub async fn enqueue_player(&mut self, player: &Keypair, user_id: &str) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::EnqueuePlayer {
+                match_queue: pda::match_queue_pda(0).0,
+                user_account: pda::user_account_pda(user_id).0,
+                sanction: pda::sanction_pda(user_id).0,
+                player: player.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::EnqueuePlayer { game_type: 0, user_id: user_id.to_string() }.data(),
+        };
+        self.send(ix, &[player]).await
+    }
+
+    // Forms MATCH_ID (CLAIM) from the queue; `seated` lists the user_ids the queue will select
+    pub async fn form_match_from_queue(&mut self, seated: &[&str]) -> Result<(), BanksClientError> {
+        let mut ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::FormMatchFromQueue {
+                match_queue: pda::match_queue_pda(0).0,
+                match_account: pda::match_pda(MATCH_ID).0,
+                global_stats: pda::global_stats_pda().0,
+                game_registry: pda::game_registry_pda().0,
+                signer_registry: pda::signer_registry_pda().0,
+                coordinator: self.coordinator.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::FormMatchFromQueue {
+                game_type: 0,
+                match_id: MATCH_ID.to_string(),
+                seed: 42,
+                player_count: seated.len() as u8,
+                tier_band: 0,
+            }
+            .data(),
+        };
+        ix.accounts.extend(seated.iter().map(|user_id| AccountMeta::new_readonly(pda::sanction_pda(user_id).0, false)));
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn verify_replay_segment(
+        &mut self,
+        start_index: u32,
+        moves_data: Vec<ReplayMoveData>,
+        expected_intermediate_hash: [u8; 32],
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::VerifyReplaySegment {
+                match_account: pda::match_pda(MATCH_ID).0,
+                checkpoint: pda::replay_checkpoint_pda(MATCH_ID).0,
+                game_registry: pda::game_registry_pda().0,
+                signer_registry: pda::signer_registry_pda().0,
+                validator: self.validator.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::VerifyReplaySegment {
+                match_id: MATCH_ID.to_string(),
+                start_index,
+                moves_data,
+                expected_intermediate_hash,
+            }
+            .data(),
+        };
+        let validator = self.validator.insecure_clone();
+        self.send(ix, &[&validator]).await
+    }
+
+    pub async fn commit_hand(&mut self, player: &Keypair, user_id: &str, hand_hash: [u8; 32]) -> Result<(), BanksClientError> {
+        self.commit_hand_of_size(player, user_id, hand_hash, 3).await
+    }
+
+    pub async fn commit_hand_of_size(
+        &mut self,
+        player: &Keypair,
+        user_id: &str,
+        hand_hash: [u8; 32],
+        hand_size: u8,
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::CommitHand {
+                match_account: pda::match_pda(MATCH_ID).0,
+                player: player.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::CommitHand {
+                match_id: MATCH_ID.to_string(),
+                user_id: user_id.to_string(),
+                hand_hash,
+                hand_size,
+            }
+            .data(),
+        };
+        self.send(ix, &[player]).await
+    }
+
+    pub async fn start_match(&mut self) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::StartMatch {
+                match_account: pda::match_pda(MATCH_ID).0,
+                deck_commitment: None,
+                lobby_index: self.lobby_index,
+                authority: self.coordinator.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::StartMatch { match_id: MATCH_ID.to_string() }.data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn set_ready(&mut self, player: &Keypair, user_id: &str, ready: bool) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::SetReady {
+                match_account: pda::match_pda(MATCH_ID).0,
+                deck_commitment: None,
+                player: player.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::SetReady {
+                match_id: MATCH_ID.to_string(),
+                user_id: user_id.to_string(),
+                ready,
+            }
+            .data(),
+        };
+        self.send(ix, &[player]).await
+    }
+
+    pub async fn set_time_control(&mut self, initial_seconds: u32, increment_seconds: u32) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::SetTimeControl {
+                match_account: pda::match_pda(MATCH_ID).0,
+                authority: self.coordinator.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::SetTimeControl {
+                match_id: MATCH_ID.to_string(),
+                initial_seconds,
+                increment_seconds,
+            }
+            .data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn set_rule_params(&mut self, rule_params: [u8; 16]) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::SetRuleParams {
+                match_account: pda::match_pda(MATCH_ID).0,
+                authority: self.coordinator.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::SetRuleParams { match_id: MATCH_ID.to_string(), rule_params }.data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn schedule_match(&mut self, scheduled_start: i64) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::ScheduleMatch {
+                match_account: pda::match_pda(MATCH_ID).0,
+                authority: self.coordinator.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::ScheduleMatch { match_id: MATCH_ID.to_string(), scheduled_start }.data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn expire_scheduled_match(&mut self, cranker: &Keypair) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::ExpireScheduledMatch {
+                match_account: pda::match_pda(MATCH_ID).0,
+                cranker: cranker.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::ExpireScheduledMatch { match_id: MATCH_ID.to_string() }.data(),
+        };
+        self.send(ix, &[cranker]).await
+    }
+
+    pub async fn set_match_metadata(&mut self, authority: &Keypair, key: &[u8; 8], value: [u8; 32]) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::SetMatchMetadata {
+                match_account: pda::match_pda(MATCH_ID).0,
+                authority: authority.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::SetMatchMetadata { match_id: MATCH_ID.to_string(), key: *key, value }.data(),
+        };
+        self.send(ix, &[authority]).await
+    }
+
+    pub async fn init_scrabble_board(&mut self, bag_head: [u8; 32]) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::InitScrabbleBoard {
+                match_account: pda::match_pda(MATCH_ID).0,
+                scrabble_board: pda::scrabble_board_pda(MATCH_ID).0,
+                authority: self.coordinator.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::InitScrabbleBoard { match_id: MATCH_ID.to_string(), bag_head }.data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn draw_tiles(&mut self, player: &Keypair, user_id: &str, reveals: &[TileReveal]) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::DrawTiles {
+                match_account: pda::match_pda(MATCH_ID).0,
+                scrabble_board: pda::scrabble_board_pda(MATCH_ID).0,
+                sanction: pda::sanction_pda(user_id).0,
+                authority: self.coordinator.pubkey(),
+                player: player.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::DrawTiles {
+                match_id: MATCH_ID.to_string(),
+                user_id: user_id.to_string(),
+                reveals: reveals.to_vec(),
+            }
+            .data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator, player]).await
+    }
+
+    pub async fn place_word(
+        &mut self,
+        player: &Keypair,
+        user_id: &str,
+        (row, col): (u8, u8),
+        horizontal: bool,
+        tiles: &[u8],
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::PlaceWord {
+                match_account: pda::match_pda(MATCH_ID).0,
+                scrabble_board: pda::scrabble_board_pda(MATCH_ID).0,
+                sanction: pda::sanction_pda(user_id).0,
+                player: player.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::PlaceWord {
+                match_id: MATCH_ID.to_string(),
+                user_id: user_id.to_string(),
+                row,
+                col,
+                horizontal,
+                tiles: tiles.to_vec(),
+            }
+            .data(),
+        };
+        self.send(ix, &[player]).await
+    }
+
+    pub async fn commit_word_search(
+        &mut self,
+        grid_hash: [u8; 32],
+        word_root: [u8; 32],
+        (rows, cols): (u8, u8),
+        word_count: u8,
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::CommitWordSearch {
+                match_account: pda::match_pda(MATCH_ID).0,
+                word_search_puzzle: pda::word_search_pda(MATCH_ID).0,
+                authority: self.coordinator.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::CommitWordSearch {
+                match_id: MATCH_ID.to_string(),
+                grid_hash,
+                word_root,
+                rows,
+                cols,
+                word_count,
+            }
+            .data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn reveal_word_search_grid(&mut self, grid: &[u8]) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::RevealWordSearchGrid {
+                match_account: pda::match_pda(MATCH_ID).0,
+                word_search_puzzle: pda::word_search_pda(MATCH_ID).0,
+                authority: self.coordinator.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::RevealWordSearchGrid { match_id: MATCH_ID.to_string(), grid: grid.to_vec() }.data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn submit_found_word(
+        &mut self,
+        player: &Keypair,
+        user_id: &str,
+        claim: FoundWord,
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::SubmitFoundWord {
+                match_account: pda::match_pda(MATCH_ID).0,
+                word_search_puzzle: pda::word_search_pda(MATCH_ID).0,
+                sanction: pda::sanction_pda(user_id).0,
+                player: player.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::SubmitFoundWord {
+                match_id: MATCH_ID.to_string(),
+                user_id: user_id.to_string(),
+                claim,
+            }
+            .data(),
+        };
+        self.send(ix, &[player]).await
+    }
+
+    pub async fn register_game(&mut self, game_id: u8, name: &str, max_players: u8) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::RegisterGame {
+                registry: pda::game_registry_pda().0,
+                authority: self.coordinator.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::RegisterGame {
+                game_id,
+                name: name.to_string(),
+                min_players: 2,
+                max_players,
+                rule_engine_url: "https://rules.example/".to_string(),
+                version: 1,
+            }
+            .data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn set_game_betting(&mut self, game_id: u8, has_betting: bool, rake_bps: u16) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::SetGameBetting {
+                registry: pda::game_registry_pda().0,
+                authority: self.coordinator.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::SetGameBetting { game_id, has_betting, rake_bps }.data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn commit_crossword(&mut self, answer_root: [u8; 32], clue_count: u8) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::CommitCrossword {
+                match_account: pda::match_pda(MATCH_ID).0,
+                crossword_puzzle: pda::crossword_pda(MATCH_ID).0,
+                authority: self.coordinator.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::CommitCrossword { match_id: MATCH_ID.to_string(), answer_root, clue_count }.data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn submit_answer(
+        &mut self,
+        player: &Keypair,
+        user_id: &str,
+        clue_index: u8,
+        answer: &str,
+        proof: &[[u8; 32]],
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::SubmitAnswer {
+                match_account: pda::match_pda(MATCH_ID).0,
+                crossword_puzzle: pda::crossword_pda(MATCH_ID).0,
+                sanction: pda::sanction_pda(user_id).0,
+                player: player.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::SubmitAnswer {
+                match_id: MATCH_ID.to_string(),
+                user_id: user_id.to_string(),
+                clue_index,
+                answer: answer.to_string(),
+                proof: proof.to_vec(),
+            }
+            .data(),
+        };
+        self.send(ix, &[player]).await
+    }
+
+    pub async fn claim_timeout(&mut self, claimer: &Keypair) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::ClaimTimeout {
+                match_account: pda::match_pda(MATCH_ID).0,
+                claimer: claimer.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::ClaimTimeout { match_id: MATCH_ID.to_string() }.data(),
+        };
+        self.send(ix, &[claimer]).await
+    }
+
+    pub async fn close_showdown(&mut self, closer: &Keypair) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::CloseShowdown {
+                match_account: pda::match_pda(MATCH_ID).0,
+                closer: closer.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::CloseShowdown { match_id: MATCH_ID.to_string() }.data(),
+        };
+        self.send(ix, &[closer]).await
+    }
+
+    pub async fn pause_match(&mut self, authority: &Keypair, reason: u8, max_duration_seconds: u32) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::PauseMatch {
+                match_account: pda::match_pda(MATCH_ID).0,
+                authority: authority.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::PauseMatch {
+                match_id: MATCH_ID.to_string(),
+                reason,
+                max_duration_seconds,
+            }
+            .data(),
+        };
+        self.send(ix, &[authority]).await
+    }
+
+    pub async fn resume_match(&mut self) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::ResumeMatch {
+                match_account: pda::match_pda(MATCH_ID).0,
+                authority: self.coordinator.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::ResumeMatch { match_id: MATCH_ID.to_string() }.data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    // Moves the on-chain clock (Clock::unix_timestamp) forward
+    pub async fn advance_clock(&mut self, seconds: i64) {
+        let mut clock: Clock = self.context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp += seconds;
+        self.context.set_sysvar(&clock);
+    }
+
+    pub async fn unix_timestamp(&mut self) -> i64 {
+        let clock: Clock = self.context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp
+    }
+
+    pub async fn reveal_floor_card(&mut self, floor_card_hash: [u8; 32]) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::RevealFloorCard {
+                match_account: pda::match_pda(MATCH_ID).0,
+                authority: self.coordinator.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::RevealFloorCard {
+                match_id: MATCH_ID.to_string(),
+                floor_card_hash,
+            }
+            .data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn submit_move_ix(
+        &mut self,
+        player: &Keypair,
+        user_id: &str,
+        action_type: u8,
+        payload: Vec<u8>,
+        nonce: u64,
+    ) -> Instruction {
+        let move_index = self.fetch_match().await.move_count;
+        Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::SubmitMove {
+                match_account: pda::match_pda(MATCH_ID).0,
+                config_account: pda::config_pda().0,
+                game_registry: pda::game_registry_pda().0,
+                sanction: pda::sanction_pda(user_id).0,
+                move_account: pda::move_pda(MATCH_ID, move_index).0,
+                player: player.pubkey(),
+                instructions: None,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::SubmitMove {
+                match_id: MATCH_ID.to_string(),
+                user_id: user_id.to_string(),
+                action_type,
+                payload,
+                nonce,
+                envelope_signature: None,
+            }
+            .data(),
+        }
+    }
+
+    /// Move with a signed envelope: `signer` signs `envelope` at `slot`, `player` submits it.
+    /// Returns the envelope hash the Move should keep.
+    pub async fn submit_signed_move(
+        &mut self,
+        signer: &Keypair,
+        player: &Keypair,
+        envelope: MoveEnvelope,
+        slot: u64,
+    ) -> Result<[u8; 32], BanksClientError> {
+        let unsigned = SignedMoveEnvelope { envelope, slot, signature: [0; 64] };
+        let message = unsigned.message().unwrap();
+        let signature: [u8; 64] = signer.sign_message(&message).into();
+        let signed = SignedMoveEnvelope { signature, ..unsigned };
+        let verify_ix = Instruction {
+            program_id: solana_sdk::ed25519_program::id(),
+            accounts: vec![],
+            data: relay::ed25519_instruction_data(&signer.pubkey(), &signature, &message),
+        };
+        let move_index = self.fetch_match().await.move_count;
+        let envelope = &signed.envelope;
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::SubmitMove {
+                match_account: pda::match_pda(MATCH_ID).0,
+                config_account: pda::config_pda().0,
+                game_registry: pda::game_registry_pda().0,
+                sanction: pda::sanction_pda(&envelope.user_id).0,
+                move_account: pda::move_pda(MATCH_ID, move_index).0,
+                player: player.pubkey(),
+                instructions: Some(solana_sdk::sysvar::instructions::id()),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::SubmitMove {
+                match_id: envelope.match_id.clone(),
+                user_id: envelope.user_id.clone(),
+                action_type: envelope.action_type,
+                payload: envelope.payload.clone(),
+                nonce: envelope.nonce,
+                envelope_signature: Some(EnvelopeSignature { slot, signature }),
+            }
+            .data(),
+        };
+        self.send_all(&[verify_ix, ix], &[player]).await?;
+        Ok(signed.hash().unwrap())
+    }
+
+    pub async fn current_slot(&mut self) -> u64 {
+        let clock: Clock = self.context.banks_client.get_sysvar().await.unwrap();
+        clock.slot
+    }
+
+    pub async fn submit_move(
+        &mut self,
+        player: &Keypair,
+        user_id: &str,
+        action_type: u8,
+        payload: Vec<u8>,
+        nonce: u64,
+    ) -> Result<(), BanksClientError> {
+        let ix = self.submit_move_ix(player, user_id, action_type, payload, nonce).await;
+        self.send(ix, &[player]).await
+    }
+
+    pub async fn attest_ai_move(
+        &mut self,
+        authority: &Keypair,
+        move_index: u32,
+        model_id: u8,
+        consumption_id: [u8; 32],
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::AttestAiMove {
+                match_account: pda::match_pda(MATCH_ID).0,
+                move_account: pda::move_pda(MATCH_ID, move_index).0,
+                attestation: pda::ai_attestation_pda(MATCH_ID, move_index).0,
+                authority: authority.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::AttestAiMove {
+                match_id: MATCH_ID.to_string(),
+                move_index,
+                model_id,
+                prompt_hash: [0xA1; 32],
+                response_hash: [0xB2; 32],
+                consumption_id,
+            }
+            .data(),
+        };
+        self.send(ix, &[authority]).await
+    }
+
+    pub fn relay_envelope(user_id: &str, action_type: u8, payload: Vec<u8>, nonce: u64) -> MoveEnvelope {
+        MoveEnvelope {
+            match_id: MATCH_ID.to_string(),
+            user_id: user_id.to_string(),
+            action_type,
+            payload,
+            nonce,
+        }
+    }
+
+    /// Coordinator-paid move: `signer` signs `signed`, the coordinator submits `envelope`.
+    pub async fn submit_relayed_move(
+        &mut self,
+        signer: &Keypair,
+        signed: &MoveEnvelope,
+        envelope: MoveEnvelope,
+    ) -> Result<(), BanksClientError> {
+        let message = signed.message().unwrap();
+        let signature: [u8; 64] = signer.sign_message(&message).into();
+        let verify_ix = Instruction {
+            program_id: solana_sdk::ed25519_program::id(),
+            accounts: vec![],
+            data: relay::ed25519_instruction_data(&signer.pubkey(), &signature, &message),
+        };
+        let move_index = self.fetch_match().await.move_count;
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::SubmitRelayedMove {
+                match_account: pda::match_pda(MATCH_ID).0,
+                config_account: pda::config_pda().0,
+                game_registry: pda::game_registry_pda().0,
+                sanction: pda::sanction_pda(&envelope.user_id).0,
+                move_account: pda::move_pda(MATCH_ID, move_index).0,
+                signer_registry: pda::signer_registry_pda().0,
+                instructions: solana_sdk::sysvar::instructions::id(),
+                coordinator: self.coordinator.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::SubmitRelayedMove { envelope, player: signer.pubkey() }.data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send_all(&[verify_ix, ix], &[&coordinator]).await
+    }
+
+    pub async fn submit_batch_moves_ix(&mut self, player: &Keypair, user_id: &str, moves: Vec<BatchMove>) -> Instruction {
+        let move_count = self.fetch_match().await.move_count;
+        let move_pda = |offset: u32| pda::move_pda(MATCH_ID, move_count + offset).0;
+        Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::SubmitBatchMoves {
+                match_account: pda::match_pda(MATCH_ID).0,
+                config_account: pda::config_pda().0,
+                game_registry: pda::game_registry_pda().0,
+                sanction: pda::sanction_pda(user_id).0,
+                move_account_0: move_pda(0),
+                move_account_1: move_pda(1),
+                move_account_2: move_pda(2),
+                move_account_3: move_pda(3),
+                move_account_4: move_pda(4),
+                player: player.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::SubmitBatchMoves {
+                match_id: MATCH_ID.to_string(),
+                user_id: user_id.to_string(),
+                moves,
+            }
+            .data(),
+        }
+    }
+
+    pub async fn flag_dispute(&mut self, flagger: &Keypair, user_id: &str, defendant_user_id: &str) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::FlagDispute {
+                dispute: pda::dispute_pda(MATCH_ID, &flagger.pubkey()).0,
+                match_account: pda::match_pda(MATCH_ID).0,
+                config_account: pda::config_pda().0,
+                flagger_account: pda::user_account_pda(user_id).0,
+                global_stats: pda::global_stats_pda().0,
+                signer_registry: pda::signer_registry_pda().0,
+                flagger: flagger.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::FlagDispute {
+                match_id: MATCH_ID.to_string(),
+                user_id: user_id.to_string(),
+                defendant_user_id: defendant_user_id.to_string(),
+                reason: 0,
+                evidence_hash: [0xE1; 32],
+                gp_deposit: 0,
+            }
+            .data(),
+        };
+        self.send(ix, &[flagger]).await
+    }
+
+    pub async fn set_validator_reputation(&mut self, reputation: &ValidatorReputation) {
+        let account = program_account(reputation, ValidatorReputation::MAX_SIZE);
+        self.context.set_account(&pda::validator_reputation_pda(&reputation.validator).0, &account.into());
+    }
+
+    // Writes env.validator's reputation in the pre-fixed-point layout (f64 reputation)
+    pub async fn set_legacy_validator_reputation(&mut self, stake: u64, reputation: f64) {
+        let legacy = LegacyValidatorReputation {
+            validator: self.validator.pubkey(),
+            stake,
+            reputation,
+            total_resolutions: 7,
+            correct_resolutions: 5,
+            created_at: 1,
+            last_active: 2,
+        };
+        let mut data = ValidatorReputation::DISCRIMINATOR.to_vec();
+        data.extend(legacy.try_to_vec().unwrap());
+        let account = Account {
+            lamports: self.context.banks_client.get_rent().await.unwrap().minimum_balance(data.len()),
+            data,
+            owner: solana_games_program::ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+        self.context.set_account(&pda::validator_reputation_pda(&legacy.validator).0, &account.into());
+    }
+
+    pub async fn migrate_validator_reputation(&mut self) -> Result<(), BanksClientError> {
+        let validator = self.validator.pubkey();
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::MigrateValidatorReputation {
+                validator_reputation: pda::validator_reputation_pda(&validator).0,
+                payer: self.context.payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::MigrateValidatorReputation { validator_pubkey: validator }.data(),
+        };
+        self.send(ix, &[]).await
+    }
+
+    // Slashed by the coordinator (the signer registry authority) unless `authority` is given
+    pub async fn slash_validator(&mut self, authority: &Keypair, amount: u64, reason: u8) -> Result<(), BanksClientError> {
+        let validator = self.validator.pubkey();
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::SlashValidator {
+                validator_reputation: pda::validator_reputation_pda(&validator).0,
+                signer_registry: pda::signer_registry_pda().0,
+                authority: authority.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::SlashValidator { validator_pubkey: validator, amount, reason }.data(),
+        };
+        self.send(ix, &[authority]).await
+    }
+
+    pub async fn appeal_slash(&mut self) -> Result<(), BanksClientError> {
+        let validator = self.validator.insecure_clone();
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::AppealSlash {
+                validator_reputation: pda::validator_reputation_pda(&validator.pubkey()).0,
+                validator: validator.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::AppealSlash {}.data(),
+        };
+        self.send(ix, &[&validator]).await
+    }
+
+    pub async fn resolve_slash_appeal(&mut self, reviewer: &Keypair, overturn: bool) -> Result<(), BanksClientError> {
+        let validator = self.validator.pubkey();
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::ResolveSlashAppeal {
+                validator_reputation: pda::validator_reputation_pda(&validator).0,
+                signer_registry: pda::signer_registry_pda().0,
+                validator_wallet: validator,
+                council: self.coordinator.pubkey(),
+                reviewer: reviewer.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::ResolveSlashAppeal { validator_pubkey: validator, overturn }.data(),
+        };
+        self.send(ix, &[reviewer]).await
+    }
+
+    pub async fn validator_heartbeat(&mut self) -> Result<(), BanksClientError> {
+        let validator = self.validator.insecure_clone();
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::ValidatorHeartbeat {
+                validator_reputation: pda::validator_reputation_pda(&validator.pubkey()).0,
+                validator: validator.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::ValidatorHeartbeat {}.data(),
+        };
+        self.send(ix, &[&validator]).await
+    }
+
+    // Copy of `m` stored as the match `match_id` (for tests needing several ended matches)
+    pub async fn set_match(&mut self, match_id: &str, mut m: Match) {
+        m.match_id.copy_from_slice(match_id.as_bytes());
+        let account = program_account(&m, Match::MAX_SIZE);
+        self.context.set_account(&pda::match_pda(match_id).0, &account.into());
+    }
+
+    pub async fn create_series(&mut self, game_type: u8, best_of: u8, player_ids: &[&str]) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::CreateSeries {
+                series: pda::series_pda(SERIES_ID).0,
+                authority: self.coordinator.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::CreateSeries {
+                series_id: SERIES_ID.to_string(),
+                game_type,
+                best_of,
+                player_ids: player_ids.iter().map(|id| id.to_string()).collect(),
+            }
+            .data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn report_series_game(&mut self, match_id: &str) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::ReportSeriesGame {
+                series: pda::series_pda(SERIES_ID).0,
+                match_account: pda::match_pda(match_id).0,
+                authority: self.coordinator.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::ReportSeriesGame { series_id: SERIES_ID.to_string(), match_id: match_id.to_string() }.data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    // Unresolved dispute on MATCH_ID filed by `flagger` at `created_at` and assigned to
+    // `assigned_validator`; returns its address
+    pub async fn set_open_dispute(&mut self, flagger: &Pubkey, created_at: i64, assigned_validator: Pubkey) -> Pubkey {
+        let mut dispute: Dispute = zeroed(Dispute::MAX_SIZE);
+        dispute.match_id.copy_from_slice(MATCH_ID.as_bytes());
+        dispute.flagger = *flagger;
+        dispute.created_at = created_at;
+        dispute.assigned_validator = assigned_validator;
+        let address = pda::dispute_pda(MATCH_ID, flagger).0;
+        self.context.set_account(&address, &program_account(&dispute, Dispute::MAX_SIZE).into());
+        address
+    }
+
+    // Cranked by the test payer with `disputes` as missed-dispute evidence
+    pub async fn enforce_inactivity(&mut self, disputes: &[Pubkey]) -> Result<(), BanksClientError> {
+        let validator = self.validator.pubkey();
+        let mut accounts = accounts::EnforceInactivity {
+            validator_reputation: pda::validator_reputation_pda(&validator).0,
+            cranker: self.context.payer.pubkey(),
+        }
+        .to_account_metas(None);
+        accounts.extend(disputes.iter().map(|dispute| AccountMeta::new_readonly(*dispute, false)));
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts,
+            data: instruction::EnforceInactivity { validator_pubkey: validator }.data(),
+        };
+        self.send(ix, &[]).await
+    }
+
+    pub async fn set_congestion_level(&mut self, signer: &Keypair, congestion_level: u8) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::SetCongestionLevel {
+                config_account: pda::config_pda().0,
+                signer_registry: pda::signer_registry_pda().0,
+                coordinator: signer.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::SetCongestionLevel { congestion_level }.data(),
+        };
+        self.send(ix, &[signer]).await
+    }
+
+    pub fn end_match_ix(&self, authority: &Keypair) -> Instruction {
+        Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::EndMatch {
+                match_account: pda::match_pda(MATCH_ID).0,
+                coordinator_set: None,
+                global_stats: pda::global_stats_pda().0,
+                authority: authority.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::EndMatch {
+                match_id: MATCH_ID.to_string(),
+                match_hash: None,
+                hot_url: None,
+            }
+            .data(),
+        }
+    }
+
+    pub async fn transfer_match_authority(
+        &mut self,
+        signer: &Keypair,
+        new_authority: Pubkey,
+        user_id: Option<&str>,
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::TransferMatchAuthority {
+                match_account: pda::match_pda(MATCH_ID).0,
+                signer_registry: pda::signer_registry_pda().0,
+                signer: signer.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::TransferMatchAuthority {
+                match_id: MATCH_ID.to_string(),
+                new_authority,
+                user_id: user_id.map(str::to_string),
+            }
+            .data(),
+        };
+        self.send(ix, &[signer]).await
+    }
+
+    pub async fn end_match(&mut self, authority: &Keypair) -> Result<(), BanksClientError> {
+        let ix = self.end_match_ix(authority);
+        self.send(ix, &[authority]).await
+    }
+
+    // end_match signed by a failover coordinator (passes the CoordinatorSet)
+    pub async fn end_match_as_failover(&mut self, coordinator: &Keypair) -> Result<(), BanksClientError> {
+        let mut ix = self.end_match_ix(coordinator);
+        ix.accounts[1] = AccountMeta::new_readonly(pda::coordinator_set_pda().0, false);
+        self.send(ix, &[coordinator]).await
+    }
+
+    pub async fn force_end_match(&mut self, council: &Keypair, reason: u8) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::ForceEndMatch {
+                match_account: pda::match_pda(MATCH_ID).0,
+                signer_registry: pda::signer_registry_pda().0,
+                council: council.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::ForceEndMatch { match_id: MATCH_ID.to_string(), reason }.data(),
+        };
+        self.send(ix, &[council]).await
+    }
+
+    // Registered by the coordinator (the signer registry authority)
+    pub async fn register_signer(&mut self, pubkey: Pubkey, role: u8) -> Result<(), BanksClientError> {
+        let coordinator = self.coordinator.insecure_clone();
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::RegisterSigner {
+                registry: pda::signer_registry_pda().0,
+                authority: coordinator.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::RegisterSigner { pubkey, role }.data(),
+        };
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn rotate_coordinator(&mut self, authority: &Keypair, coordinator: Pubkey, action: u8) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::RotateCoordinator {
+                coordinator_set: pda::coordinator_set_pda().0,
+                signer_registry: pda::signer_registry_pda().0,
+                authority: authority.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::RotateCoordinator { coordinator, action }.data(),
+        };
+        self.send(ix, &[authority]).await
+    }
+
+    pub async fn anchor_match_record(
+        &mut self,
+        match_hash: [u8; 32],
+        hot_url: &str,
+        cold: Option<(&str, [u8; 32])>,
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::AnchorMatchRecord {
+                match_account: pda::match_pda(MATCH_ID).0,
+                coordinator_set: None,
+                authority: self.coordinator.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::AnchorMatchRecord {
+                match_id: MATCH_ID.to_string(),
+                match_hash,
+                hot_url: Some(hot_url.to_string()),
+                cold_uri: cold.map(|(uri, _)| uri.to_string()),
+                cold_content_hash: cold.map(|(_, hash)| hash),
+            }
+            .data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    // Moves are (move_index, rent payer) in the order they're passed; the test payer cranks
+    pub async fn compact_match_moves(&mut self, start_index: u32, moves: &[(u32, Pubkey)]) -> Result<(), BanksClientError> {
+        let mut account_metas = accounts::CompactMatchMoves {
+            match_account: pda::match_pda(MATCH_ID).0,
+            cranker: self.context.payer.pubkey(),
+        }
+        .to_account_metas(None);
+        for &(move_index, payer) in moves {
+            account_metas.push(AccountMeta::new(pda::move_pda(MATCH_ID, move_index).0, false));
+            account_metas.push(AccountMeta::new(payer, false));
+        }
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: account_metas,
+            data: instruction::CompactMatchMoves { match_id: MATCH_ID.to_string(), start_index }.data(),
+        };
+        self.send(ix, &[]).await
+    }
+
+    // Closed by the test payer; rent goes to `rent_payer`
+    pub async fn close_match_account(&mut self, rent_payer: Pubkey) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::CloseMatchAccount {
+                match_account: pda::match_pda(MATCH_ID).0,
+                closer: self.context.payer.pubkey(),
+                rent_payer,
+            }
+            .to_account_metas(None),
+            data: instruction::CloseMatchAccount { match_id: MATCH_ID.to_string() }.data(),
+        };
+        self.send(ix, &[]).await
+    }
+
+    // Writes a UserAccount truncated or zero-padded to `space` bytes (older layouts are shorter),
+    // funded at the rent minimum for that size
+    pub async fn set_user_account(&mut self, user_id: &str, mut user: UserAccount, space: usize) {
+        user.user_id = [0u8; 64];
+        user.user_id[..user_id.len()].copy_from_slice(user_id.as_bytes());
+        let mut account = program_account(&user, UserAccount::MAX_SIZE);
+        account.data.resize(space, 0);
+        account.lamports = self.context.banks_client.get_rent().await.unwrap().minimum_balance(space);
+        self.context.set_account(&pda::user_account_pda(user_id).0, &account.into());
+    }
+
+    // Writes a UserAccount in the original pre-versioning layout (user_id .. active_multiplier)
+    pub async fn set_baseline_user_account(&mut self, user_id: &str, games_played: u32, season_score: u64) {
+        let mut data = UserAccount::DISCRIMINATOR.to_vec();
+        let mut id = [0u8; 64];
+        id[..user_id.len()].copy_from_slice(user_id.as_bytes());
+        data.extend_from_slice(&id);
+        data.extend_from_slice(&[0u8; 8 + 8 + 8 + 1 + 8]); // last_claim .. lifetime_gp_earned
+        data.extend_from_slice(&games_played.to_le_bytes());
+        data.extend_from_slice(&[0u8; 4 + 4 + 8 + 4 + 1 + 8]); // games_won .. current_season_id
+        data.extend_from_slice(&season_score.to_le_bytes());
+        data.extend_from_slice(&[0u8; 4 + 4 + 2 + 1]); // season_wins .. active_multiplier
+        let lamports = self.context.banks_client.get_rent().await.unwrap().minimum_balance(data.len());
+        let account = Account { lamports, data, owner: solana_games_program::ID, executable: false, rent_epoch: 0 };
+        self.context.set_account(&pda::user_account_pda(user_id).0, &account.into());
+    }
+
+    // Writes the PaymentReceipt a purchase of `amount` would have left for `intent_id`
+    pub async fn set_payment_receipt(&mut self, intent_id: &str, user_id: &str, product: PaymentProduct, amount: u64) {
+        let payment_intent_hash = solana_sdk::hash::hash(intent_id.as_bytes()).to_bytes();
+        let mut receipt = PaymentReceipt {
+            payment_intent_hash,
+            user_id: [0u8; 64],
+            product: product as u8,
+            amount,
+            attestor: self.coordinator.pubkey(),
+            created_at: 1,
+            amount_reversed: 0,
+        };
+        receipt.user_id[..user_id.len()].copy_from_slice(user_id.as_bytes());
+        let account = program_account(&receipt, PaymentReceipt::MAX_SIZE);
+        self.context.set_account(&pda::payment_receipt_pda(&payment_intent_hash).0, &account.into());
+    }
+
+    pub async fn set_sanction(&mut self, user_id: &str, kind: SanctionKind, expires_at: i64) {
+        let mut sanction: Sanction = zeroed(Sanction::MAX_SIZE);
+        sanction.user_id[..user_id.len()].copy_from_slice(user_id.as_bytes());
+        sanction.issue(kind, 0, Pubkey::default(), self.coordinator.pubkey(), 1, expires_at);
+        let account = program_account(&sanction, Sanction::MAX_SIZE);
+        self.context.set_account(&pda::sanction_pda(user_id).0, &account.into());
+    }
+
+    pub async fn set_config(&mut self, config: &ConfigAccount) {
+        let account = program_account(config, ConfigAccount::MAX_SIZE);
+        self.context.set_account(&pda::config_pda().0, &account.into());
+    }
+
+    // Signed by the coordinator (tests set it as the config authority)
+    pub async fn set_ai_spend_limit(&mut self, user_id: &str, daily_limit: u64) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::SetAiSpendLimit {
+                user_account: pda::user_account_pda(user_id).0,
+                config_account: pda::config_pda().0,
+                authority: self.coordinator.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::SetAiSpendLimit { user_id: user_id.to_string(), daily_limit }.data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    // Metered in the current billing period, paid by the test payer
+    pub async fn consume_ai_credits(
+        &mut self,
+        user_id: &str,
+        model_id: u8,
+        tokens_used: u32,
+        op_nonce: u64,
+    ) -> Result<(), BanksClientError> {
+        let period_id = (self.unix_timestamp().await / UsageMeter::PERIOD_SECONDS) as u32;
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::ConsumeAICredits {
+                user_account: pda::user_account_pda(user_id).0,
+                usage_meter: pda::usage_meter_pda(user_id, period_id).0,
+                config_account: pda::config_pda().0,
+                payer: self.context.payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::ConsumeAiCredits {
+                user_id: user_id.to_string(),
+                model_id,
+                tokens_used,
+                period_id,
+                op_nonce,
+            }
+            .data(),
+        };
+        self.send(ix, &[]).await
+    }
+
+    // Support grant `grant_id`, signed by the coordinator (tests set it as the config authority)
+    pub async fn grant_ai_credits(&mut self, user_id: &str, grant_id: [u8; 32], ac_amount: u64) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::GrantAICredits {
+                user_account: pda::user_account_pda(user_id).0,
+                credit_adjustment: pda::credit_grant_pda(user_id, &grant_id).0,
+                config_account: pda::config_pda().0,
+                authority: self.coordinator.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::GrantAiCredits { user_id: user_id.to_string(), grant_id, ac_amount, reason: 1 }.data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    // Refunds consumption `consumption_id` metered under `model_id` in the current billing period
+    pub async fn refund_ai_consumption(&mut self, user_id: &str, consumption_id: [u8; 32], model_id: u8, ac_amount: u64) -> Result<(), BanksClientError> {
+        let period_id = (self.unix_timestamp().await / UsageMeter::PERIOD_SECONDS) as u32;
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::RefundAIConsumption {
+                user_account: pda::user_account_pda(user_id).0,
+                usage_meter: pda::usage_meter_pda(user_id, period_id).0,
+                credit_adjustment: pda::credit_refund_pda(user_id, &consumption_id).0,
+                config_account: pda::config_pda().0,
+                authority: self.coordinator.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::RefundAiConsumption {
+                user_id: user_id.to_string(),
+                consumption_id,
+                period_id,
+                model_id,
+                ac_amount,
+                reason: 1,
+            }
+            .data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    // Current billing period; `with_previous` passes the previous period's meter for roll-over
+    pub async fn accrue_subscription_credits(&mut self, user_id: &str, with_previous: bool) -> Result<(), BanksClientError> {
+        let period_id = (self.unix_timestamp().await / UsageMeter::PERIOD_SECONDS) as u32;
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::AccrueSubscriptionCredits {
+                user_account: pda::user_account_pda(user_id).0,
+                usage_meter: pda::usage_meter_pda(user_id, period_id).0,
+                previous_meter: with_previous.then(|| pda::usage_meter_pda(user_id, period_id - 1).0),
+                config_account: pda::config_pda().0,
+                payer: self.context.payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::AccrueSubscriptionCredits { user_id: user_id.to_string(), period_id }.data(),
+        };
+        self.send(ix, &[]).await
+    }
+
+    // Receipt for the Stripe PaymentIntent `intent_id`, attested by the coordinator
+    pub fn payment_attestation(&self, intent_id: &str, expiry: i64) -> PaymentAttestation {
+        PaymentAttestation {
+            payment_intent_hash: solana_sdk::hash::hash(intent_id.as_bytes()).to_bytes(),
+            expiry,
+            attestor: self.coordinator.pubkey(),
+        }
+    }
+
+    // Ed25519 verify instruction for `signer`'s signature over the receipt for this grant
+    pub fn sign_payment(
+        signer: &Keypair,
+        attestation: &PaymentAttestation,
+        user_id: &str,
+        product: PaymentProduct,
+        amount: u64,
+    ) -> Instruction {
+        let message = attestation.message(user_id, product, amount).unwrap();
+        let signature: [u8; 64] = signer.sign_message(&message).into();
+        Instruction {
+            program_id: solana_sdk::ed25519_program::id(),
+            accounts: vec![],
+            data: relay::ed25519_instruction_data(&signer.pubkey(), &signature, &message),
+        }
+    }
+
+    pub async fn purchase_subscription(
+        &mut self,
+        user_id: &str,
+        tier: u8,
+        duration_days: u8,
+        op_nonce: u64,
+        attestation: PaymentAttestation,
+        verify_ix: Instruction,
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::PurchaseSubscription {
+                user_account: pda::user_account_pda(user_id).0,
+                payment_receipt: pda::payment_receipt_pda(&attestation.payment_intent_hash).0,
+                signer_registry: pda::signer_registry_pda().0,
+                instructions: solana_sdk::sysvar::instructions::id(),
+                payer: self.context.payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::PurchaseSubscription {
+                user_id: user_id.to_string(),
+                tier,
+                duration_days,
+                op_nonce,
+                attestation,
+            }
+            .data(),
+        };
+        self.send_all(&[verify_ix, ix], &[]).await
+    }
+
+    // Stripe purchase (no buyer); `verify_ix` goes right before it when given
+    pub async fn purchase_ai_credits(
+        &mut self,
+        user_id: &str,
+        ac_amount: u64,
+        op_nonce: u64,
+        attestation: Option<PaymentAttestation>,
+        verify_ix: Option<Instruction>,
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::PurchaseAICredits {
+                user_account: pda::user_account_pda(user_id).0,
+                config_account: pda::config_pda().0,
+                price_feed: None,
+                buyer: None,
+                treasury: None,
+                payment_receipt: attestation.as_ref().map(|a| pda::payment_receipt_pda(&a.payment_intent_hash).0),
+                signer_registry: pda::signer_registry_pda().0,
+                instructions: solana_sdk::sysvar::instructions::id(),
+                payer: self.context.payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::PurchaseAiCredits { user_id: user_id.to_string(), ac_amount, op_nonce, attestation }.data(),
+        };
+        let ixs: Vec<Instruction> = verify_ix.into_iter().chain([ix]).collect();
+        self.send_all(&ixs, &[]).await
+    }
+
+    // Signed by the coordinator (tests set it as the config authority)
+    pub async fn reverse_payment(
+        &mut self,
+        chargeback: bool,
+        user_id: &str,
+        product: PaymentProduct,
+        amount: u64,
+        reference_id: &str,
+        intent_id: &str,
+    ) -> Result<(), BanksClientError> {
+        let reference_hash = solana_sdk::hash::hash(reference_id.as_bytes()).to_bytes();
+        let (user_account, payment_reversal) =
+            (pda::user_account_pda(user_id).0, pda::payment_reversal_pda(&reference_hash).0);
+        let payment_receipt = pda::payment_receipt_pda(&solana_sdk::hash::hash(intent_id.as_bytes()).to_bytes()).0;
+        let (user_id, product, authority) = (user_id.to_string(), product as u8, self.coordinator.pubkey());
+        let (accounts, data) = if chargeback {
+            (
+                accounts::ProcessChargeback {
+                    user_account,
+                    payment_receipt,
+                    payment_reversal,
+                    config_account: pda::config_pda().0,
+                    authority,
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                instruction::ProcessChargeback { user_id, product, amount, reference_hash }.data(),
+            )
+        } else {
+            (
+                accounts::ProcessRefund {
+                    user_account,
+                    payment_receipt,
+                    payment_reversal,
+                    config_account: pda::config_pda().0,
+                    authority,
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                instruction::ProcessRefund { user_id, product, amount, reference_hash }.data(),
+            )
+        };
+        let ix = Instruction { program_id: solana_games_program::ID, accounts, data };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    // Shard 0 of game type 0, season `season_id`, holding `entries` (user id, score) in rank order
+    pub async fn set_leaderboard_shard(&mut self, season_id: u64, entries: &[(&str, u64)]) -> Pubkey {
+        let mut shard: GameLeaderboard = zeroed(GameLeaderboard::MAX_SIZE);
+        shard.season_id = season_id;
+        for (entry, &(user_id, score)) in shard.entries.iter_mut().zip(entries) {
+            entry.user_id[..user_id.len()].copy_from_slice(user_id.as_bytes());
+            entry.score = score;
+        }
+        shard.entry_count = entries.len() as u8;
+        let address = pda::leaderboard_shard_pda(0, season_id, 0).0;
+        self.context.set_account(&address, &program_account(&shard, GameLeaderboard::MAX_SIZE).into());
+        address
+    }
+
+    // Signed by the coordinator (tests set it as the config authority)
+    // Index for set_leaderboard_shard's single shard holding `entry_count` entries
+    pub async fn set_leaderboard_index(&mut self, season_id: u64, entry_count: u8) {
+        let mut index: LeaderboardIndex = zeroed(LeaderboardIndex::MAX_SIZE);
+        index.season_id = season_id;
+        index.shard_count = 1;
+        index.shard_entry_counts[0] = entry_count;
+        let address = pda::leaderboard_index_pda(0, season_id).0;
+        self.context.set_account(&address, &program_account(&index, LeaderboardIndex::MAX_SIZE).into());
+    }
+
+    // Shards 0.. of game type 0, season `season_id`, holding (user id, score) entries in rank
+    // order (every entry last active at unix time 0), and the index describing them
+    pub async fn set_leaderboard(&mut self, season_id: u64, shards: &[&[(&str, u64)]]) {
+        let mut index: LeaderboardIndex = zeroed(LeaderboardIndex::MAX_SIZE);
+        index.season_id = season_id;
+        index.shard_count = shards.len() as u16;
+        for (shard_index, entries) in shards.iter().enumerate() {
+            let mut shard: GameLeaderboard = zeroed(GameLeaderboard::MAX_SIZE);
+            shard.season_id = season_id;
+            shard.shard_index = shard_index as u16;
+            for (entry, &(user_id, score)) in shard.entries.iter_mut().zip(entries.iter()) {
+                entry.user_id[..user_id.len()].copy_from_slice(user_id.as_bytes());
+                entry.score = score;
+            }
+            shard.entry_count = entries.len() as u8;
+            index.refresh_shard(&shard);
+            let address = pda::leaderboard_shard_pda(0, season_id, shard_index as u16).0;
+            self.context.set_account(&address, &program_account(&shard, GameLeaderboard::MAX_SIZE).into());
+        }
+        let address = pda::leaderboard_index_pda(0, season_id).0;
+        self.context.set_account(&address, &program_account(&index, LeaderboardIndex::MAX_SIZE).into());
+    }
+
+    // Submitted by the coordinator, passing game type 0 shards `shards` as the remaining accounts
+    pub async fn submit_leaderboard_entry(&mut self, season_id: u64, user_id: &str, score: u64, shards: &[u16]) -> Result<(), BanksClientError> {
+        let mut account_metas = accounts::SubmitLeaderboardEntry {
+            leaderboard_index: pda::leaderboard_index_pda(0, season_id).0,
+            user_account: pda::user_account_pda(user_id).0,
+            signer_registry: pda::signer_registry_pda().0,
+            coordinator: self.coordinator.pubkey(),
+        }
+        .to_account_metas(None);
+        for &shard_index in shards {
+            account_metas.push(AccountMeta::new(pda::leaderboard_shard_pda(0, season_id, shard_index).0, false));
+        }
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: account_metas,
+            data: instruction::SubmitLeaderboardEntry { game_type: 0, season_id, user_id: user_id.to_string(), score, wins: 0, games_played: 0 }.data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    // Decays game type 0 shard `shard_index` and, when `has_next`, the shard after it
+    pub async fn decay_leaderboard(&mut self, season_id: u64, shard_index: u16, has_next: bool) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::DecayLeaderboard {
+                leaderboard_index: pda::leaderboard_index_pda(0, season_id).0,
+                leaderboard_shard: pda::leaderboard_shard_pda(0, season_id, shard_index).0,
+                next_shard: has_next.then(|| pda::leaderboard_shard_pda(0, season_id, shard_index + 1).0),
+                config_account: pda::config_pda().0,
+                cranker: self.coordinator.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::DecayLeaderboard { game_type: 0, season_id, shard_index }.data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    // Pays shard 0 of game type 0 from the coordinator (config authority and treasury):
+    // each ranked user is followed by the wallet that should receive its lamports
+    pub async fn distribute_season_rewards(&mut self, season_id: u64, ranks: &[(&str, Pubkey)]) -> Result<(), BanksClientError> {
+        let mut account_metas = accounts::DistributeSeasonRewards {
+            leaderboard_index: pda::leaderboard_index_pda(0, season_id).0,
+            leaderboard_shard: pda::leaderboard_shard_pda(0, season_id, 0).0,
+            config_account: pda::config_pda().0,
+            global_stats: pda::global_stats_pda().0,
+            authority: self.coordinator.pubkey(),
+            treasury: Some(self.coordinator.pubkey()),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+        for &(user_id, wallet) in ranks {
+            account_metas.push(AccountMeta::new(pda::user_account_pda(user_id).0, false));
+            account_metas.push(AccountMeta::new_readonly(pda::sanction_pda(user_id).0, false));
+            account_metas.push(AccountMeta::new(wallet, false));
+        }
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: account_metas,
+            data: instruction::DistributeSeasonRewards { game_type: 0, season_id, shard_index: 0 }.data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    pub async fn erase_user_account(&mut self, user_id: &str, anon_key: [u8; 32], shards: &[Pubkey]) -> Result<(), BanksClientError> {
+        let mut account_metas = accounts::EraseUserAccount {
+            user_account: pda::user_account_pda(user_id).0,
+            erased_account: pda::erased_user_pda(&anon_key).0,
+            player_rating: pda::player_rating_pda(user_id).0,
+            sanction: pda::sanction_pda(user_id).0,
+            config_account: pda::config_pda().0,
+            authority: self.coordinator.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+        account_metas.extend(shards.iter().map(|&shard| AccountMeta::new(shard, false)));
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: account_metas,
+            data: instruction::EraseUserAccount { user_id: user_id.to_string(), anon_key }.data(),
+        };
+        let coordinator = self.coordinator.insecure_clone();
+        self.send(ix, &[&coordinator]).await
+    }
+
+    // `attestor` signs the ownership proof for `link_count`; `new_wallet` signs the transaction
+    pub async fn prove_account_ownership(
+        &mut self,
+        user_id: &str,
+        new_wallet: &Keypair,
+        attestor: &Keypair,
+        link_count: u32,
+        expiry: i64,
+    ) -> Result<(), BanksClientError> {
+        let challenge = [0x5A; 32];
+        let message = ownership_message(user_id, &new_wallet.pubkey(), &challenge, link_count, expiry).unwrap();
+        let signature: [u8; 64] = attestor.sign_message(&message).into();
+        let verify_ix = Instruction {
+            program_id: solana_sdk::ed25519_program::id(),
+            accounts: vec![],
+            data: relay::ed25519_instruction_data(&attestor.pubkey(), &signature, &message),
+        };
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::ProveAccountOwnership {
+                user_account: pda::user_account_pda(user_id).0,
+                signer_registry: pda::signer_registry_pda().0,
+                instructions: solana_sdk::sysvar::instructions::id(),
+                new_wallet: new_wallet.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::ProveAccountOwnership {
+                user_id: user_id.to_string(),
+                challenge,
+                signature,
+                attestor: attestor.pubkey(),
+                expiry,
+            }
+            .data(),
+        };
+        self.send_all(&[verify_ix, ix], &[new_wallet]).await
+    }
+
+    pub fn blank_user_account() -> UserAccount {
+        zeroed(UserAccount::MAX_SIZE)
+    }
+
+    pub fn blank_validator_reputation() -> ValidatorReputation {
+        zeroed(ValidatorReputation::MAX_SIZE)
+    }
+
+    pub async fn extend_user_account(&mut self, user_id: &str) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: solana_games_program::ID,
+            accounts: accounts::ExtendUserAccount {
+                user_account: pda::user_account_pda(user_id).0,
+                payer: self.context.payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::ExtendUserAccount { user_id: user_id.to_string() }.data(),
+        };
+        self.send(ix, &[]).await
+    }
+}
+
+/// Hash-chain commitment to a tile bag dealt in `tiles` order: the bag head and one reveal per tile.
+pub fn tile_bag(tiles: &[u8], salt: [u8; 32]) -> ([u8; 32], Vec<TileReveal>) {
+    let mut head = salt;
+    let mut reveals = Vec::with_capacity(tiles.len());
+    for &tile in tiles.iter().rev() {
+        reveals.push(TileReveal { tile, next_head: head });
+        head = solana_sdk::hash::hashv(&[&[tile], &head]).to_bytes();
+    }
+    reveals.reverse();
+    (head, reveals)
+}
+
+/// Stand-in merkle root for the batch anchored as `batch_id`.
+pub fn batch_root(batch_id: &str) -> [u8; 32] {
+    solana_sdk::hash::hash(batch_id.as_bytes()).to_bytes()
+}
+
+/// Merkle root over a word list (leaves per WordSearchPuzzle::word_leaf) and each word's proof.
+pub fn word_list(words: &[&[u8]]) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+    merkle_tree(words.iter().enumerate().map(|(i, word)| WordSearchPuzzle::word_leaf(i as u8, word)).collect())
+}
+
+/// Merkle root over leaves zero-padded to a power of two, and each leaf's proof, leaf-to-root.
+pub fn merkle_tree(leaves: Vec<[u8; 32]>) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+    let proofs = (0..leaves.len()).map(|i| merkle::proof(&leaves, i).unwrap()).collect();
+    (merkle::root(&leaves), proofs)
+}
+
+/// Asserts the transaction failed with the given Anchor framework or program error code.
+pub fn assert_error_code(result: Result<(), BanksClientError>, code: u32) {
+    match result {
+        Err(BanksClientError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(actual),
+        ))) => assert_eq!(actual, code, "unexpected error code"),
+        other => panic!("expected custom error {}, got {:?}", code, other),
+    }
+}
+
+pub fn assert_game_error(result: Result<(), BanksClientError>, expected: GameError) {
+    assert_error_code(result, ERROR_CODE_OFFSET + expected as u32);
+}
@@ -0,0 +1,88 @@
+// Compute-unit regression benchmarks for the hot-path instructions in a 4-player match.
+// CUs are only metered for the SBF build, so these run against `anchor build` output:
+//   anchor build && SBF_OUT_DIR=target/deploy cargo test --test compute_budget -- --nocapture
+// Without SBF_OUT_DIR the benchmarks are skipped.
+
+mod common;
+
+use common::{sbf_build_available, TestEnv, USER_IDS};
+use solana_games_program::instructions::BatchMove;
+
+// Budgets per instruction (CU). Raise deliberately, with the measured number in the PR.
+const SUBMIT_MOVE_BUDGET: u64 = 60_000;
+const SUBMIT_BATCH_MOVES_BUDGET: u64 = 150_000;
+const END_MATCH_BUDGET: u64 = 40_000;
+
+const PLAYERS: usize = 4;
+
+fn check_budget(instruction: &str, consumed: u64, budget: u64) {
+    println!("{}: {} CU (budget {})", instruction, consumed, budget);
+    assert!(
+        consumed <= budget,
+        "{} consumed {} CU, over its {} CU budget",
+        instruction,
+        consumed,
+        budget
+    );
+}
+
+#[tokio::test]
+async fn submit_move_within_budget() {
+    if !sbf_build_available() {
+        println!("skipped: set SBF_OUT_DIR to the anchor build output to meter compute units");
+        return;
+    }
+    let mut env = TestEnv::started_match(PLAYERS).await;
+    let (alice, bob) = (env.player(0), env.player(1));
+
+    // Turn-based move with a full 32-byte payload (pick up the floor card)
+    let floor_card = [0x11; 32];
+    env.reveal_floor_card(floor_card).await.unwrap();
+    let ix = env.submit_move_ix(&alice, USER_IDS[0], 0, floor_card.to_vec(), 1).await;
+    let consumed = env.measure(ix, &[&alice]).await;
+    check_budget("submit_move (pick_up)", consumed, SUBMIT_MOVE_BUDGET);
+
+    // Out-of-turn move (declare intent)
+    let ix = env.submit_move_ix(&bob, USER_IDS[1], 2, vec![1], 1).await;
+    let consumed = env.measure(ix, &[&bob]).await;
+    check_budget("submit_move (declare_intent)", consumed, SUBMIT_MOVE_BUDGET);
+}
+
+#[tokio::test]
+async fn submit_batch_moves_within_budget() {
+    if !sbf_build_available() {
+        println!("skipped: set SBF_OUT_DIR to the anchor build output to meter compute units");
+        return;
+    }
+    let mut env = TestEnv::started_match(PLAYERS).await;
+    let carol = env.player(2);
+
+    // Declare + call showdown; every batch initializes all five move accounts
+    let moves = vec![
+        BatchMove { action_type: 2, payload: vec![2], nonce: 1 },
+        BatchMove { action_type: 3, payload: vec![2, 4, 2, 5, 2, 6], nonce: 2 },
+    ];
+    let ix = env.submit_batch_moves_ix(&carol, USER_IDS[2], moves).await;
+    let consumed = env.measure(ix, &[&carol]).await;
+    check_budget("submit_batch_moves", consumed, SUBMIT_BATCH_MOVES_BUDGET);
+}
+
+#[tokio::test]
+async fn end_match_within_budget() {
+    if !sbf_build_available() {
+        println!("skipped: set SBF_OUT_DIR to the anchor build output to meter compute units");
+        return;
+    }
+    let mut env = TestEnv::started_match(PLAYERS).await;
+
+    // Every player declares so scoring has full state to work through
+    for (i, user_id) in USER_IDS.iter().enumerate().take(PLAYERS) {
+        let player = env.player(i);
+        env.submit_move(&player, user_id, 2, vec![i as u8], 1).await.unwrap();
+    }
+
+    let coordinator = env.coordinator.insecure_clone();
+    let ix = env.end_match_ix(&coordinator);
+    let consumed = env.measure(ix, &[&coordinator]).await;
+    check_budget("end_match", consumed, END_MATCH_BUDGET);
+}
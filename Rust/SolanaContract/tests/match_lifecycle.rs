@@ -0,0 +1,2033 @@
+// Integration tests running the program under solana-program-test (BanksClient).
+// Covers the full match lifecycle plus the main rejection paths.
+// Run with: cargo test --test match_lifecycle
+
+mod common;
+
+use common::{assert_error_code, assert_game_error, batch_root, merkle_tree, tile_bag, word_list, TestEnv, ALICE, BOB, CAROL, MATCH_ID, SERIES_ID};
+use solana_games_program::{
+    error::GameError, instructions::{BatchMove, ReplayMoveData}, pda, payment::PaymentProduct, replay, validation::{self, brag},
+    state::{AiMoveAttestation, BatchAnchor, BatchHead, BatchLink, ConfigAccount, CoordinatorSet, Dispute, EndReason, GameLeaderboard, GameType, GlobalStats, LeaderboardIndex, Match, MatchCreator, Move, OpenLobbyIndex, PaymentReceipt, PlayerRating, ReplayCheckpoint, RuleParams, SanctionKind, ScrabbleBoard, Series, UsageMeter, UserAccount, ValidatorReputation, FoundWord, WordSearchPuzzle, CrosswordPuzzle},
+};
+use solana_sdk::{pubkey::Pubkey, signature::{Keypair, Signer}};
+
+#[tokio::test]
+async fn full_match_lifecycle() {
+    let mut env = TestEnv::started_match(2).await;
+    let (alice, bob) = (env.player(0), env.player(1));
+
+    let m = env.fetch_match().await;
+    assert_eq!(m.match_id_str(), MATCH_ID);
+    assert_eq!(m.phase, 1);
+    assert_eq!(m.player_count, 2);
+    assert_eq!(m.player_id_str(0).as_deref(), Some(ALICE));
+    assert_eq!(m.player_id_str(1).as_deref(), Some(BOB));
+    assert!(m.all_players_joined());
+
+    // Alice picks up the first floor card, Bob declines the second
+    let first_card = [0x11; 32];
+    env.reveal_floor_card(first_card).await.unwrap();
+    env.submit_move(&alice, ALICE, 0, first_card.to_vec(), 1).await.unwrap();
+    env.reveal_floor_card([0x22; 32]).await.unwrap();
+    env.submit_move(&bob, BOB, 1, vec![], 1).await.unwrap();
+
+    // Alice declares spades and calls showdown with a run (opens Bob's rebuttal window)
+    env.submit_move(&alice, ALICE, 2, vec![0], 2).await.unwrap();
+    env.submit_move(&alice, ALICE, 3, vec![0, 9, 0, 10, 0, 11], 3).await.unwrap();
+
+    let m = env.fetch_match().await;
+    assert_eq!(m.move_count, 4);
+    assert_eq!(m.get_hand_size(0), 4);
+    assert_eq!(m.cards_dealt_total, m.dealt_card_count() + m.discard_count as u16);
+    assert!(m.deck_accounting_consistent() && !m.has_anomaly(Match::ANOMALY_DECK_DESYNC));
+    assert_eq!(m.get_declared_suit(0), Some(0));
+    assert_eq!(m.get_last_nonce(0), 3);
+    assert_eq!(m.get_last_nonce(1), 1);
+    assert_eq!(m.phase, 3);
+    assert!(!m.is_ended());
+
+    // Bob lets the window lapse
+    let coordinator = env.coordinator.insecure_clone();
+    let result = env.end_match(&coordinator).await;
+    assert_game_error(result, GameError::ShowdownInProgress);
+    env.advance_clock(Match::DEFAULT_REBUTTAL_WINDOW_SECONDS as i64).await;
+
+    let first_move: Move = env.fetch(pda::move_pda(MATCH_ID, 0).0).await;
+    assert_eq!(first_move.match_id_str(), MATCH_ID);
+    assert_eq!(first_move.player, alice.pubkey());
+    assert_eq!(first_move.get_payload_slice(), &first_card[..]);
+    assert!(!first_move.has_envelope_hash());
+
+    env.end_match(&coordinator).await.unwrap();
+    let m = env.fetch_match().await;
+    assert_eq!(m.phase, 2);
+    assert!(m.is_ended());
+    assert!(m.round_scored());
+
+    // Global stats count the finalized match and its moves once, even if end_match is repeated
+    env.end_match(&coordinator).await.unwrap();
+    let stats: GlobalStats = env.fetch(pda::global_stats_pda().0).await;
+    assert_eq!((stats.total_matches_created, stats.total_matches_ended), (1, 1));
+    assert_eq!(stats.total_moves, m.move_count as u64);
+    assert_eq!(stats.matches_ended_by_game[0], 1);
+    assert_eq!(stats.moves_by_game[0], m.move_count as u64);
+
+    // The anchored hash is the canonical replay hash, reproducible from the Move accounts
+    let mut moves = Vec::new();
+    for i in 0..m.move_count {
+        let mv: Move = env.fetch(pda::move_pda(MATCH_ID, i).0).await;
+        moves.push(replay::ReplayMove::from(&mv));
+    }
+    let match_hash = replay::compute_match_hash(&moves, &replay::MatchHeader::from_match(&m));
+    assert_eq!(m.match_hash, match_hash);
+
+    let result = env.anchor_match_record([0x5A; 32], "https://hot.example/matches/1", None).await;
+    assert_game_error(result, GameError::MatchHashMismatch);
+    let archive = ("bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi", [0xC0; 32]);
+    env.anchor_match_record(match_hash, "https://hot.example/matches/1", Some(archive)).await.unwrap();
+    let m = env.fetch_match().await;
+    assert_eq!(m.match_hash, match_hash);
+    assert_eq!(m.hot_url_str(), "https://hot.example/matches/1");
+    assert_eq!(m.cold_uri_str(), archive.0);
+    assert_eq!(m.cold_content_hash, archive.1);
+
+    // hot_url can move; the archive location can't
+    env.anchor_match_record(match_hash, "https://hot.example/matches/1-v2", None).await.unwrap();
+    let result = env.anchor_match_record(match_hash, "https://hot.example/matches/1", Some(("ar-tx-id", [0xC1; 32]))).await;
+    assert_game_error(result, GameError::ColdUriAlreadySet);
+}
+
+#[tokio::test]
+async fn replay_is_verified_in_segments() {
+    let mut env = TestEnv::started_match(2).await;
+    let (alice, bob) = (env.player(0), env.player(1));
+
+    env.submit_move(&alice, ALICE, 2, vec![0], 1).await.unwrap();
+    env.submit_move(&bob, BOB, 2, vec![1], 1).await.unwrap();
+    env.submit_move(&alice, ALICE, 8, vec![], 2).await.unwrap();
+
+    let m = env.fetch_match().await;
+    let mut segment = Vec::new();
+    let mut hashes = vec![replay::header_hash(&replay::MatchHeader::from_match(&m))];
+    for i in 0..m.move_count {
+        let mv: Move = env.fetch(pda::move_pda(MATCH_ID, i).0).await;
+        let payload = mv.get_payload_slice().to_vec();
+        hashes.push(replay::chain_move(&hashes[i as usize], i, &mv.player, mv.action_type, &payload));
+        segment.push(ReplayMoveData { player: mv.player, action_type: mv.action_type, payload });
+    }
+
+    let result = env.verify_replay_segment(0, segment[..2].to_vec(), [0x5A; 32]).await;
+    assert_game_error(result, GameError::MatchHashMismatch);
+    env.verify_replay_segment(0, segment[..2].to_vec(), hashes[2]).await.unwrap();
+
+    // Segments must continue from the checkpoint
+    let result = env.verify_replay_segment(1, segment[1..].to_vec(), hashes[3]).await;
+    assert_game_error(result, GameError::InvalidPayload);
+    env.verify_replay_segment(2, segment[2..].to_vec(), hashes[3]).await.unwrap();
+
+    let checkpoint: ReplayCheckpoint = env.fetch(pda::replay_checkpoint_pda(MATCH_ID).0).await;
+    assert_eq!(checkpoint.next_index, 3);
+    assert!(checkpoint.complete);
+    assert!(!checkpoint.has_invalid_move());
+    assert_eq!(checkpoint.transcript_hash, m.transcript_hash);
+}
+
+#[tokio::test]
+async fn move_out_of_turn_is_rejected() {
+    let mut env = TestEnv::started_match(2).await;
+    let bob = env.player(1);
+
+    env.reveal_floor_card([0x11; 32]).await.unwrap();
+    let result = env.submit_move(&bob, BOB, 1, vec![], 1).await;
+    assert_game_error(result, GameError::NotPlayerTurn);
+    assert_eq!(env.fetch_match().await.move_count, 0);
+}
+
+#[tokio::test]
+async fn moves_must_be_signed_by_the_seat_wallet() {
+    let mut env = TestEnv::started_match(2).await;
+    let (alice, bob) = (env.player(0), env.player(1));
+
+    // Bob's wallet can't move for Alice's seat, alone or in a batch
+    let result = env.submit_move(&bob, ALICE, 2, vec![0], 1).await;
+    assert_game_error(result, GameError::Unauthorized);
+    let batch = vec![BatchMove { action_type: 2, payload: vec![0], nonce: 1 }];
+    let ix = env.submit_batch_moves_ix(&bob, ALICE, batch.clone()).await;
+    assert_game_error(env.send(ix, &[&bob]).await, GameError::Unauthorized);
+    assert_eq!(env.fetch_match().await.move_count, 0);
+
+    let ix = env.submit_batch_moves_ix(&alice, ALICE, batch).await;
+    env.send(ix, &[&alice]).await.unwrap();
+    assert_eq!(env.fetch_match().await.get_declared_suit(0), Some(0));
+}
+
+#[tokio::test]
+async fn replayed_nonce_is_rejected() {
+    // Carol stays undeclared, so Alice's showdown keeps the match in play
+    let mut env = TestEnv::started_match(3).await;
+    let (alice, bob) = (env.player(0), env.player(1));
+
+    let run = vec![0, 4, 0, 5, 0, 6];
+    env.submit_move(&alice, ALICE, 2, vec![0], 5).await.unwrap();
+    let result = env.submit_move(&alice, ALICE, 3, run.clone(), 5).await;
+    assert_game_error(result, GameError::InvalidNonce);
+
+    // Nonces are tracked per player; too far below the latest one is stale
+    env.submit_move(&bob, BOB, 2, vec![1], 70).await.unwrap();
+    let stale = 70 - Match::NONCE_WINDOW - 1;
+    let result = env.submit_move(&bob, BOB, 2, vec![1], stale).await;
+    assert_game_error(result, GameError::InvalidNonce);
+
+    // An unused lower nonce inside the window still lands (concurrent moves arriving out of order), once
+    env.submit_move(&alice, ALICE, 3, run.clone(), 4).await.unwrap();
+    let result = env.submit_move(&alice, ALICE, 3, run, 4).await;
+    assert_game_error(result, GameError::InvalidNonce);
+
+    let m = env.fetch_match().await;
+    assert_eq!(m.move_count, 3);
+    assert_eq!(m.get_last_nonce(0), 5);
+}
+
+#[tokio::test]
+async fn relayed_move_is_paid_by_the_coordinator() {
+    let mut env = TestEnv::started_match(2).await;
+    let alice = env.player(0);
+    let alice_lamports = env.context.banks_client.get_balance(alice.pubkey()).await.unwrap();
+
+    let envelope = TestEnv::relay_envelope(ALICE, 2, vec![0], 1);
+    env.submit_relayed_move(&alice, &envelope, envelope.clone()).await.unwrap();
+
+    let m = env.fetch_match().await;
+    assert_eq!(m.move_count, 1);
+    assert_eq!(m.get_last_nonce(0), 1);
+    let recorded: Move = env.fetch(pda::move_pda(MATCH_ID, 0).0).await;
+    assert_eq!(recorded.player, alice.pubkey());
+    assert_eq!(env.context.banks_client.get_balance(alice.pubkey()).await.unwrap(), alice_lamports);
+
+    // The coordinator can't alter what the player signed
+    let signed = TestEnv::relay_envelope(ALICE, 2, vec![1], 2);
+    let result = env.submit_relayed_move(&alice, &signed, TestEnv::relay_envelope(ALICE, 2, vec![2], 2)).await;
+    assert_game_error(result, GameError::InvalidRelaySignature);
+
+    // The signed nonce still protects against replay
+    let result = env.submit_relayed_move(&alice, &envelope, envelope.clone()).await;
+    assert_game_error(result, GameError::InvalidNonce);
+
+    // Bob's wallet can't sign moves for Alice's seat
+    let bob = env.player(1);
+    let forged = TestEnv::relay_envelope(ALICE, 2, vec![0], 3);
+    let result = env.submit_relayed_move(&bob, &forged, forged.clone()).await;
+    assert_game_error(result, GameError::Unauthorized);
+}
+
+#[tokio::test]
+async fn signed_move_keeps_the_envelope_hash() {
+    let mut env = TestEnv::started_match(2).await;
+    let (alice, bob) = (env.player(0), env.player(1));
+    let slot = env.current_slot().await;
+    let envelope = TestEnv::relay_envelope(ALICE, 2, vec![0], 1);
+
+    // The envelope must be signed by the submitting player, at a slot that has happened
+    let result = env.submit_signed_move(&bob, &alice, envelope.clone(), slot).await;
+    assert_game_error(result.map(|_| ()), GameError::InvalidRelaySignature);
+    let result = env.submit_signed_move(&alice, &alice, envelope.clone(), slot + 1_000).await;
+    assert_game_error(result.map(|_| ()), GameError::InvalidEnvelopeSlot);
+
+    let envelope_hash = env.submit_signed_move(&alice, &alice, envelope, slot).await.unwrap();
+    let recorded: Move = env.fetch(pda::move_pda(MATCH_ID, 0).0).await;
+    assert!(recorded.has_envelope_hash());
+    assert_eq!(recorded.envelope_hash, envelope_hash);
+    assert_eq!(recorded.player, alice.pubkey());
+    assert_eq!(env.fetch_match().await.get_declared_suit(0), Some(0));
+}
+
+#[tokio::test]
+async fn matches_are_created_in_bulk() {
+    let mut env = TestEnv::start().await;
+    let alice = env.player(0);
+    let bracket = [
+        "0b9a2c1e-1111-4c7d-8e9f-0a1b2c3d4e5f",
+        "0b9a2c1e-2222-4c7d-8e9f-0a1b2c3d4e5f",
+        "0b9a2c1e-3333-4c7d-8e9f-0a1b2c3d4e5f",
+    ];
+
+    env.create_matches_batch(&bracket).await.unwrap();
+    for (i, id) in bracket.iter().enumerate() {
+        let m: Match = env.fetch(pda::match_pda(id).0).await;
+        assert_eq!(m.match_id_str(), *id);
+        assert_eq!(m.seed, 100 + i as u64);
+        assert_eq!(m.rounds_to_play, 3);
+        assert_eq!(m.authority, env.coordinator.pubkey());
+        assert_eq!(m.phase, 0);
+    }
+
+    // Batched matches are ordinary matches
+    env.create_match().await.unwrap();
+    env.join_match(&alice, ALICE).await.unwrap();
+
+    // An already-initialized match fails the whole batch
+    let result = env.create_matches_batch(&[MATCH_ID, "0b9a2c1e-4444-4c7d-8e9f-0a1b2c3d4e5f"]).await;
+    assert_game_error(result, GameError::InvalidMatchAccount);
+    let result = env.create_matches_batch(&[]).await;
+    assert_game_error(result, GameError::BatchTooLarge);
+}
+
+#[tokio::test]
+async fn double_join_is_rejected() {
+    let mut env = TestEnv::start().await;
+    let (alice, bob) = (env.player(0), env.player(1));
+
+    env.create_match().await.unwrap();
+    env.join_match(&alice, ALICE).await.unwrap();
+    let result = env.join_match(&alice, ALICE).await;
+    assert_game_error(result, GameError::PlayerNotInMatch);
+    // Same user id from a different wallet is still a duplicate
+    let result = env.join_match(&bob, ALICE).await;
+    assert_game_error(result, GameError::PlayerNotInMatch);
+
+    assert_eq!(env.fetch_match().await.player_count, 1);
+}
+
+#[tokio::test]
+async fn private_match_admits_only_coordinator_cosigned_joins() {
+    let mut env = TestEnv::start().await;
+    let (alice, bob) = (env.player(0), env.player(1));
+    env.create_match().await.unwrap();
+    let match_key = pda::match_pda(MATCH_ID).0;
+    let mut match_account = env.fetch_match().await;
+    match_account.join_secret_hash = Match::join_secret_hash(&match_key, b"friends-only");
+    env.set_match(MATCH_ID, match_account.clone()).await;
+
+    // The commitment is salted per match
+    assert_ne!(match_account.join_secret_hash, Match::join_secret_hash(&Pubkey::new_unique(), b"friends-only"));
+
+    let result = env.join_match(&alice, ALICE).await;
+    assert_game_error(result, GameError::InvalidJoinSecret);
+    env.join_match_admitted(&alice, ALICE).await.unwrap();
+    env.join_match_admitted(&bob, BOB).await.unwrap();
+    assert_eq!(env.fetch_match().await.player_count, 2);
+}
+
+#[tokio::test]
+async fn invited_player_joins_once_before_expiry() {
+    let mut env = TestEnv::start().await;
+    let (alice, bob) = (env.player(0), env.player(1));
+
+    env.create_match().await.unwrap();
+    let now = env.unix_timestamp().await;
+    env.invite_player(ALICE, now + 60).await.unwrap();
+    env.invite_player(BOB, now + 600).await.unwrap();
+
+    // Uninvited users have no invitation account
+    let result = env.accept_invitation(&bob, "firebase-uid-mallory").await;
+    assert_error_code(result, anchor_lang::error::ErrorCode::AccountNotInitialized as u32);
+
+    env.advance_clock(120).await;
+    let result = env.accept_invitation(&alice, ALICE).await;
+    assert_game_error(result, GameError::InvitationExpired);
+
+    env.accept_invitation(&bob, BOB).await.unwrap();
+    let result = env.accept_invitation(&bob, BOB).await;
+    assert_game_error(result, GameError::InvalidInvitation);
+
+    let m = env.fetch_match().await;
+    assert_eq!(m.player_count, 1);
+    assert_eq!(m.player_id_str(0).as_deref(), Some(BOB));
+}
+
+#[tokio::test]
+async fn start_match_keeps_committed_hands() {
+    let mut env = TestEnv::start().await;
+    let (alice, bob) = (env.player(0), env.player(1));
+
+    env.create_match().await.unwrap();
+    env.join_match(&alice, ALICE).await.unwrap();
+    env.join_match(&bob, BOB).await.unwrap();
+    env.commit_hand(&alice, ALICE, [0xA0; 32]).await.unwrap();
+    let result = env.start_match().await;
+    assert_game_error(result, GameError::HandsNotCommitted);
+
+    // 3 + 50 cards can't come out of a 52-card deck
+    env.commit_hand_of_size(&bob, BOB, [0xA1; 32], 50).await.unwrap();
+    let result = env.start_match().await;
+    assert_game_error(result, GameError::DealExceedsDeck);
+
+    env.commit_hand(&bob, BOB, [0xA1; 32]).await.unwrap();
+    env.start_match().await.unwrap();
+    let m = env.fetch_match().await;
+    assert_eq!(m.phase, 1);
+    assert_eq!(m.get_committed_hand_hash(0), Some([0xA0; 32]));
+    assert_eq!(m.get_committed_hand_hash(1), Some([0xA1; 32]));
+    assert_eq!(m.dealt_card_count(), 6);
+}
+
+#[tokio::test]
+async fn ready_quorum_starts_the_match() {
+    let mut env = TestEnv::start().await;
+    let (alice, bob) = (env.player(0), env.player(1));
+
+    env.create_match().await.unwrap();
+    env.join_match(&alice, ALICE).await.unwrap();
+    env.join_match(&bob, BOB).await.unwrap();
+    env.commit_hand(&alice, ALICE, [0xA0; 32]).await.unwrap();
+
+    // Bob can't vouch for a deal he hasn't committed to
+    let result = env.set_ready(&bob, BOB, true).await;
+    assert_game_error(result, GameError::HandsNotCommitted);
+
+    env.set_ready(&alice, ALICE, true).await.unwrap();
+    env.commit_hand(&bob, BOB, [0xA1; 32]).await.unwrap();
+
+    // Alice can't mark Bob's seat ready
+    let result = env.set_ready(&alice, BOB, true).await;
+    assert_game_error(result, GameError::Unauthorized);
+    let m = env.fetch_match().await;
+    assert_eq!(m.phase, 0);
+    assert_eq!(m.ready_count(), 1);
+
+    // Second ready vote reaches CLAIM's two-player minimum, no authority involved
+    env.set_ready(&bob, BOB, true).await.unwrap();
+    let m = env.fetch_match().await;
+    assert_eq!(m.phase, 1);
+    assert!(m.all_players_joined());
+    assert!(m.has_transcript());
+
+    let result = env.set_ready(&alice, ALICE, false).await;
+    assert_game_error(result, GameError::InvalidPhase);
+}
+
+#[tokio::test]
+async fn play_and_discard_decrement_hand_size() {
+    let mut env = TestEnv::start().await;
+    let (alice, bob) = (env.player(0), env.player(1));
+
+    env.create_match().await.unwrap();
+    env.join_match(&alice, ALICE).await.unwrap();
+    env.join_match(&bob, BOB).await.unwrap();
+    env.commit_hand(&alice, ALICE, [0xA0; 32]).await.unwrap();
+    env.commit_hand_of_size(&bob, BOB, [0xA1; 32], 2).await.unwrap();
+    env.start_match().await.unwrap();
+
+    let card = [0x11; 32];
+    env.reveal_floor_card(card).await.unwrap();
+    env.submit_move(&alice, ALICE, 0, card.to_vec(), 1).await.unwrap();
+    assert_eq!(env.fetch_match().await.get_hand_size(0), 4);
+
+    // Playing and discarding are turn actions; the pick-up passed the turn to Bob
+    let result = env.submit_move(&alice, ALICE, 6, card.to_vec(), 2).await;
+    assert_game_error(result, GameError::NotPlayerTurn);
+    let result = env.submit_move(&alice, ALICE, 5, card.to_vec(), 3).await;
+    assert_game_error(result, GameError::NotPlayerTurn);
+
+    env.submit_move(&bob, BOB, 6, card.to_vec(), 1).await.unwrap();
+    let m = env.fetch_match().await;
+    assert_eq!(m.get_hand_size(1), 1);
+    assert_eq!(m.get_last_discard_hash(), Some(card));
+
+    env.submit_move(&bob, BOB, 6, [0x33; 32].to_vec(), 2).await.unwrap();
+    assert_eq!(env.fetch_match().await.get_hand_size(1), 0);
+
+    // Can't play from an empty hand
+    let result = env.submit_move(&bob, BOB, 5, card.to_vec(), 3).await;
+    assert_game_error(result, GameError::EmptyHand);
+}
+
+#[tokio::test]
+async fn time_bank_runs_down_and_times_out() {
+    let mut env = TestEnv::start().await;
+    let (alice, bob) = (env.player(0), env.player(1));
+
+    env.create_match().await.unwrap();
+    env.join_match(&alice, ALICE).await.unwrap();
+    env.join_match(&bob, BOB).await.unwrap();
+    env.set_time_control(60, 5).await.unwrap();
+    env.commit_hand(&alice, ALICE, [0xA0; 32]).await.unwrap();
+    env.commit_hand(&bob, BOB, [0xA1; 32]).await.unwrap();
+    env.start_match().await.unwrap();
+
+    // Alice spends 10s on her turn: 60 - 10 + 5 increment
+    env.advance_clock(10).await;
+    env.reveal_floor_card([0x11; 32]).await.unwrap();
+    env.submit_move(&alice, ALICE, 1, vec![], 1).await.unwrap();
+    let m = env.fetch_match().await;
+    assert_eq!(m.time_bank[0], 55);
+    assert_eq!(m.turn_started_at, env.unix_timestamp().await);
+
+    // Bob still has time
+    let result = env.claim_timeout(&alice).await;
+    assert_game_error(result, GameError::TimeBankNotExhausted);
+
+    // Bob's bank runs out: his move is rejected and anyone can claim the timeout
+    env.advance_clock(60).await;
+    env.reveal_floor_card([0x22; 32]).await.unwrap();
+    let result = env.submit_move(&bob, BOB, 1, vec![], 1).await;
+    assert_game_error(result, GameError::TimeBankExhausted);
+    env.claim_timeout(&alice).await.unwrap();
+
+    let m = env.fetch_match().await;
+    assert_eq!(m.phase, 2);
+    assert!(m.is_ended());
+    assert_eq!(m.get_outcome(), EndReason::Timeout);
+    assert_eq!(m.outcome_player, 1);
+    assert_eq!(m.time_bank[1], 0);
+}
+
+#[tokio::test]
+async fn missed_turn_deadline_forfeits_without_time_control() {
+    let mut env = TestEnv::started_match(2).await;
+    let (alice, bob) = (env.player(0), env.player(1));
+    let timeout = Match::TURN_TIMEOUT_SECONDS as i64;
+
+    // Each turn gets its own deadline, however long the match has been running
+    env.advance_clock(timeout - 1).await;
+    env.reveal_floor_card([0x11; 32]).await.unwrap();
+    env.submit_move(&alice, ALICE, 1, vec![], 1).await.unwrap();
+    let now = env.unix_timestamp().await;
+    assert_eq!(env.fetch_match().await.turn_deadline, now + timeout);
+
+    // Declaring isn't turn-based, so it doesn't touch the deadline
+    env.advance_clock(timeout - 1).await;
+    env.submit_move(&alice, ALICE, 2, vec![0], 2).await.unwrap();
+    let result = env.claim_timeout(&alice).await;
+    assert_game_error(result, GameError::TimeBankNotExhausted);
+
+    env.advance_clock(1).await;
+    env.reveal_floor_card([0x22; 32]).await.unwrap();
+    let result = env.submit_move(&bob, BOB, 1, vec![], 1).await;
+    assert_game_error(result, GameError::TurnDeadlinePassed);
+    env.claim_timeout(&alice).await.unwrap();
+
+    let m = env.fetch_match().await;
+    assert!(m.is_ended());
+    assert_eq!(m.get_outcome(), EndReason::Timeout);
+    assert_eq!(m.outcome_player, 1);
+}
+
+#[tokio::test]
+async fn pause_freezes_moves_and_turn_clock() {
+    let mut env = TestEnv::start().await;
+    let (alice, bob) = (env.player(0), env.player(1));
+    let coordinator = env.coordinator.insecure_clone();
+
+    env.create_match().await.unwrap();
+    env.join_match(&alice, ALICE).await.unwrap();
+    env.join_match(&bob, BOB).await.unwrap();
+    env.set_time_control(60, 0).await.unwrap();
+    env.commit_hand(&alice, ALICE, [0xA0; 32]).await.unwrap();
+    env.commit_hand(&bob, BOB, [0xA1; 32]).await.unwrap();
+    env.start_match().await.unwrap();
+    env.reveal_floor_card([0x11; 32]).await.unwrap();
+
+    let result = env.pause_match(&alice, 1, 600).await;
+    assert_game_error(result, GameError::Unauthorized);
+    let result = env.pause_match(&coordinator, 1, 7200).await;
+    assert_game_error(result, GameError::InvalidPauseDuration);
+
+    // 10s used, then a 5 minute outage that neither moves nor times out anyone
+    env.advance_clock(10).await;
+    env.pause_match(&coordinator, 1, 600).await.unwrap();
+    env.advance_clock(300).await;
+    let result = env.submit_move(&alice, ALICE, 1, vec![], 1).await;
+    assert_game_error(result, GameError::MatchPaused);
+    let result = env.claim_timeout(&bob).await;
+    assert_game_error(result, GameError::MatchPaused);
+    env.resume_match().await.unwrap();
+
+    env.advance_clock(10).await;
+    env.submit_move(&alice, ALICE, 1, vec![], 1).await.unwrap();
+    assert_eq!(env.fetch_match().await.time_bank[0], 40);
+
+    let result = env.resume_match().await;
+    assert_game_error(result, GameError::MatchNotPaused);
+}
+
+#[tokio::test]
+async fn resignation_ends_the_match() {
+    let mut env = TestEnv::started_match(2).await;
+    let (bob, outsider) = (env.player(1), env.player(2));
+
+    // Only the wallet that took the seat can resign it
+    let result = env.submit_move(&outsider, BOB, 7, vec![], 1).await;
+    assert_game_error(result, GameError::Unauthorized);
+    assert!(!env.fetch_match().await.is_ended());
+
+    env.submit_move(&bob, BOB, 7, vec![], 1).await.unwrap();
+    let m = env.fetch_match().await;
+    assert_eq!(m.phase, 2);
+    assert!(m.is_ended());
+    assert_eq!(m.get_outcome(), EndReason::Resignation);
+    assert_eq!(m.outcome_player, 1);
+
+    let result = env.submit_move(&bob, BOB, 2, vec![1], 2).await;
+    assert_game_error(result, GameError::InvalidPhase);
+}
+
+#[tokio::test]
+async fn draw_offer_lapses_or_is_accepted() {
+    let mut env = TestEnv::started_match(2).await;
+    let (alice, bob) = (env.player(0), env.player(1));
+
+    let result = env.submit_move(&bob, BOB, 9, vec![], 1).await;
+    assert_game_error(result, GameError::NoDrawOffer);
+
+    // Bob offers, Alice plays on instead: the offer lapses
+    env.submit_move(&bob, BOB, 8, vec![], 1).await.unwrap();
+    env.reveal_floor_card([0x11; 32]).await.unwrap();
+    env.submit_move(&alice, ALICE, 1, vec![], 1).await.unwrap();
+    assert!(!env.fetch_match().await.draw_offer_pending());
+    let result = env.submit_move(&alice, ALICE, 9, vec![], 2).await;
+    assert_game_error(result, GameError::NoDrawOffer);
+
+    // Alice offers, Bob accepts
+    env.submit_move(&alice, ALICE, 8, vec![], 2).await.unwrap();
+    let result = env.submit_move(&alice, ALICE, 9, vec![], 3).await;
+    assert_game_error(result, GameError::InvalidAction);
+    let result = env.submit_move(&alice, BOB, 9, vec![], 3).await;
+    assert_game_error(result, GameError::Unauthorized);
+    env.submit_move(&bob, BOB, 9, vec![], 2).await.unwrap();
+
+    let m = env.fetch_match().await;
+    assert_eq!(m.phase, 2);
+    assert_eq!(m.get_outcome(), EndReason::Draw);
+    assert_eq!(m.outcome_player, 0);
+}
+
+#[tokio::test]
+async fn showdown_waits_for_rebuttals_or_the_deadline() {
+    let claimed = vec![2, 4, 2, 5, 2, 6];
+    let run = vec![1, 5, 1, 6, 1, 7];
+
+    // Every undeclared player rebuts: the round ends without waiting
+    let mut env = TestEnv::started_match(3).await;
+    let (alice, bob, carol) = (env.player(0), env.player(1), env.player(2));
+    env.submit_move(&carol, CAROL, 2, vec![2], 1).await.unwrap();
+    env.submit_move(&carol, CAROL, 3, claimed.clone(), 2).await.unwrap();
+    let m = env.fetch_match().await;
+    assert_eq!(m.phase, 3);
+    assert_eq!((m.claimed_run_suit, m.claimed_run_top), (2, 6));
+
+    let result = env.submit_move(&alice, ALICE, 2, vec![0], 1).await;
+    assert_game_error(result, GameError::InvalidPhase);
+    // The same top card in a higher suit beats the claim
+    env.submit_move(&alice, ALICE, 4, vec![3, 4, 3, 5, 3, 6], 1).await.unwrap();
+    let result = env.submit_move(&alice, ALICE, 4, run.clone(), 2).await;
+    assert_game_error(result, GameError::InvalidAction);
+    let result = env.close_showdown(&carol).await;
+    assert_game_error(result, GameError::ShowdownInProgress);
+
+    // A rebuttal has to beat the claimed run's top card, then its suit
+    let result = env.submit_move(&bob, BOB, 4, vec![1, 4, 1, 5, 1, 6], 1).await;
+    assert_game_error(result, GameError::RebuttalTooLow);
+    env.submit_move(&bob, BOB, 4, run.clone(), 1).await.unwrap();
+    let m = env.fetch_match().await;
+    assert_eq!(m.phase, 2);
+    assert!(m.is_ended());
+    assert!(m.has_rebutted(0) && m.has_rebutted(1));
+
+    // Nobody rebuts: the window closes and anyone may end the round
+    let mut env = TestEnv::started_match(3).await;
+    let (alice, carol) = (env.player(0), env.player(2));
+    env.submit_move(&carol, CAROL, 2, vec![2], 1).await.unwrap();
+    // The claimed run must be in the declared suit
+    let result = env.submit_move(&carol, CAROL, 3, run.clone(), 2).await;
+    assert_game_error(result, GameError::InvalidSuit);
+    env.submit_move(&carol, CAROL, 3, claimed, 2).await.unwrap();
+    env.advance_clock(Match::DEFAULT_REBUTTAL_WINDOW_SECONDS as i64).await;
+
+    let result = env.submit_move(&alice, ALICE, 4, run, 1).await;
+    assert_game_error(result, GameError::RebuttalWindowClosed);
+    env.close_showdown(&alice).await.unwrap();
+    let m = env.fetch_match().await;
+    assert_eq!(m.phase, 2);
+    assert!(m.is_ended());
+    assert_eq!(m.rebuttal_responses, 0);
+}
+
+#[tokio::test]
+async fn end_match_requires_match_authority() {
+    let mut env = TestEnv::started_match(2).await;
+    let alice = env.player(0);
+
+    let result = env.end_match(&alice).await;
+    assert_game_error(result, GameError::Unauthorized);
+    assert_eq!(env.fetch_match().await.phase, 1);
+}
+
+#[tokio::test]
+async fn match_authority_moves_by_player_quorum_or_coordinator() {
+    let mut env = TestEnv::started_match(2).await;
+    let (alice, bob) = (env.player(0), env.player(1));
+    let coordinator = env.coordinator.insecure_clone();
+    let validator = env.validator.insecure_clone();
+    let new_host = Keypair::new();
+
+    // Only the Coordinator role can reassign without a quorum
+    let result = env.transfer_match_authority(&validator, new_host.pubkey(), None).await;
+    assert_game_error(result, GameError::Unauthorized);
+
+    // One of two players is not a majority
+    env.transfer_match_authority(&alice, new_host.pubkey(), Some(ALICE)).await.unwrap();
+    assert_eq!(env.fetch_match().await.authority, coordinator.pubkey());
+
+    // A vote only counts when signed by the wallet that took the seat
+    let result = env.transfer_match_authority(&alice, new_host.pubkey(), Some(BOB)).await;
+    assert_game_error(result, GameError::Unauthorized);
+    assert_eq!(env.fetch_match().await.authority, coordinator.pubkey());
+    env.transfer_match_authority(&bob, new_host.pubkey(), Some(BOB)).await.unwrap();
+    let m = env.fetch_match().await;
+    assert_eq!(m.authority, new_host.pubkey());
+    assert_eq!(m.header_authority, coordinator.pubkey());
+
+    // The new host can finish the match; the replay hash still verifies
+    let result = env.end_match(&coordinator).await;
+    assert_game_error(result, GameError::Unauthorized);
+    env.end_match(&new_host).await.unwrap();
+    let m = env.fetch_match().await;
+    assert_eq!(m.match_hash, m.replay_match_hash());
+
+    env.transfer_match_authority(&coordinator, coordinator.pubkey(), None).await.unwrap();
+    assert_eq!(env.fetch_match().await.authority, coordinator.pubkey());
+}
+
+#[tokio::test]
+async fn unknown_match_account_is_rejected() {
+    let mut env = TestEnv::start().await;
+    let coordinator = env.coordinator.insecure_clone();
+
+    // No create_match: the match PDA doesn't exist
+    let result = env.end_match(&coordinator).await;
+    assert_error_code(result, anchor_lang::error::ErrorCode::AccountNotInitialized as u32);
+}
+
+#[tokio::test]
+async fn legacy_user_account_is_extended_to_the_current_schema() {
+    let mut env = TestEnv::start().await;
+    let address = pda::user_account_pda(ALICE).0;
+    env.set_baseline_user_account(ALICE, 7, 420).await;
+
+    // Versioned reads see the original layout, with every later field zeroed
+    let account = env.context.banks_client.get_account(address).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), UserAccount::SCHEMA_SIZES[0]);
+    assert_eq!(UserAccount::schema_version_of(&account.data), 0);
+    let legacy = UserAccount::try_deserialize_versioned(&account.data).unwrap();
+    assert_eq!((legacy.games_played, legacy.season_score, legacy.free_play_used), (7, 420, 0));
+    assert!(UserAccount::try_deserialize_versioned(&account.data[..100]).is_err());
+
+    env.extend_user_account(ALICE).await.unwrap();
+    let account = env.context.banks_client.get_account(address).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), UserAccount::MAX_SIZE);
+    let rent = env.context.banks_client.get_rent().await.unwrap();
+    assert!(account.lamports >= rent.minimum_balance(UserAccount::MAX_SIZE));
+    let user: UserAccount = env.fetch(address).await;
+    assert_eq!(user.schema_version, UserAccount::SCHEMA_VERSION);
+    assert_eq!((user.games_played, user.season_score, user.free_play_used), (7, 420, 0));
+
+    // Accounts from between the original layout and schema_version are version 0 too
+    let mut user = TestEnv::blank_user_account();
+    user.games_played = 7;
+    user.free_play_used = 2;
+    env.set_user_account(CAROL, user, UserAccount::SCHEMA_SIZES[1] - 1).await;
+    env.extend_user_account(CAROL).await.unwrap();
+    let user: UserAccount = env.fetch(pda::user_account_pda(CAROL).0).await;
+    assert_eq!((user.schema_version, user.games_played, user.free_play_used), (UserAccount::SCHEMA_VERSION, 7, 2));
+
+    // Already current: nothing to do
+    env.extend_user_account(ALICE).await.unwrap();
+    let account = env.context.banks_client.get_account(address).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), UserAccount::MAX_SIZE);
+
+    // Only this program's UserAccounts can be extended
+    let result = env.extend_user_account(BOB).await;
+    assert_error_code(result, anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram as u32);
+}
+
+#[tokio::test]
+async fn anchored_moves_are_compacted_and_rent_refunded() {
+    let mut env = TestEnv::started_match(2).await;
+    let (alice, bob) = (env.player(0), env.player(1));
+    env.submit_move(&alice, ALICE, 8, vec![], 1).await.unwrap();
+    env.submit_move(&bob, BOB, 9, vec![], 1).await.unwrap();
+    let moves = [(0, alice.pubkey()), (1, bob.pubkey())];
+
+    // Ended by the draw, but nothing is pruned before the match hash is recorded
+    let result = env.compact_match_moves(0, &moves).await;
+    assert_game_error(result, GameError::InvalidPhase);
+    let coordinator = env.coordinator.insecure_clone();
+    env.end_match(&coordinator).await.unwrap();
+
+    // Segments start at compacted_moves, and rent only goes back to the wallet that paid it
+    let result = env.compact_match_moves(1, &moves[1..]).await;
+    assert_game_error(result, GameError::InvalidPayload);
+    let result = env.compact_match_moves(0, &[(0, bob.pubkey())]).await;
+    assert_game_error(result, GameError::RentPayerMismatch);
+
+    let move_rent = env.context.banks_client.get_balance(pda::move_pda(MATCH_ID, 0).0).await.unwrap();
+    let alice_lamports = env.context.banks_client.get_balance(alice.pubkey()).await.unwrap();
+    env.compact_match_moves(0, &moves[..1]).await.unwrap();
+    assert_eq!(
+        env.context.banks_client.get_balance(alice.pubkey()).await.unwrap(),
+        alice_lamports + move_rent
+    );
+    assert_eq!(env.fetch_match().await.compacted_moves, 1);
+    env.compact_match_moves(1, &moves[1..]).await.unwrap();
+
+    let m = env.fetch_match().await;
+    assert_eq!(m.compacted_moves, m.move_count);
+    assert_eq!(m.compaction_hash, m.transcript_hash);
+    for (move_index, _) in moves {
+        let account = env.context.banks_client.get_account(pda::move_pda(MATCH_ID, move_index).0).await.unwrap();
+        assert!(account.is_none());
+    }
+    let result = env.compact_match_moves(1, &moves[1..]).await;
+    assert_game_error(result, GameError::InvalidPayload);
+
+    // The match account's rent goes back to its creator, not to whoever closes it
+    assert_eq!(m.rent_payer, coordinator.pubkey());
+    let result = env.close_match_account(alice.pubkey()).await;
+    assert_game_error(result, GameError::RentPayerMismatch);
+    let match_rent = env.context.banks_client.get_balance(pda::match_pda(MATCH_ID).0).await.unwrap();
+    let coordinator_lamports = env.context.banks_client.get_balance(coordinator.pubkey()).await.unwrap();
+    env.close_match_account(coordinator.pubkey()).await.unwrap();
+    assert_eq!(
+        env.context.banks_client.get_balance(coordinator.pubkey()).await.unwrap(),
+        coordinator_lamports + match_rent
+    );
+}
+
+#[tokio::test]
+async fn high_congestion_requires_larger_batches() {
+    let mut env = TestEnv::started_match(2).await;
+    let alice = env.player(0);
+    let coordinator = env.coordinator.insecure_clone();
+    let validator = env.validator.insecure_clone();
+
+    let result = env.set_congestion_level(&validator, 2).await;
+    assert_game_error(result, GameError::Unauthorized);
+    let result = env.set_congestion_level(&coordinator, 3).await;
+    assert_game_error(result, GameError::InvalidCongestionLevel);
+    env.set_congestion_level(&coordinator, 2).await.unwrap();
+    let config: ConfigAccount = env.fetch(pda::config_pda().0).await;
+    assert_eq!(config.min_batch_moves(), ConfigAccount::CONGESTED_MIN_BATCH_MOVES);
+
+    let declare = BatchMove { action_type: 2, payload: vec![0], nonce: 1 };
+    let ix = env.submit_batch_moves_ix(&alice, ALICE, vec![declare.clone()]).await;
+    let result = env.send(ix, &[&alice]).await;
+    assert_game_error(result, GameError::BatchTooSmall);
+
+    // Declare, offer a draw and decline the floor card in one transaction
+    env.reveal_floor_card([0x11; 32]).await.unwrap();
+    let moves = vec![
+        declare,
+        BatchMove { action_type: 8, payload: vec![], nonce: 2 },
+        BatchMove { action_type: 1, payload: vec![], nonce: 3 },
+    ];
+    let ix = env.submit_batch_moves_ix(&alice, ALICE, moves).await;
+    env.send(ix, &[&alice]).await.unwrap();
+    assert_eq!(env.fetch_match().await.move_count, 3);
+
+    // Back to normal: single-move batches are accepted again
+    env.set_congestion_level(&coordinator, 0).await.unwrap();
+    let bob = env.player(1);
+    let ix = env.submit_batch_moves_ix(&bob, BOB, vec![BatchMove { action_type: 2, payload: vec![1], nonce: 1 }]).await;
+    env.send(ix, &[&bob]).await.unwrap();
+}
+
+#[tokio::test]
+async fn disputes_are_filed_within_the_window_after_match_end() {
+    let mut env = TestEnv::started_match(2).await;
+    let (alice, bob) = (env.player(0), env.player(1));
+
+    for user_id in [ALICE, BOB] {
+        env.set_user_account(user_id, TestEnv::blank_user_account(), UserAccount::MAX_SIZE).await;
+    }
+
+    // Nothing to dispute while the match is still being played
+    let result = env.flag_dispute(&alice, ALICE, BOB).await;
+    assert_game_error(result, GameError::InvalidPhase);
+
+    env.submit_move(&bob, BOB, 7, vec![], 1).await.unwrap();
+    let result = env.flag_dispute(&alice, ALICE, ALICE).await;
+    assert_game_error(result, GameError::InvalidPayload);
+    // The defendant must be seated in the match
+    let result = env.flag_dispute(&alice, ALICE, CAROL).await;
+    assert_game_error(result, GameError::PlayerNotInMatch);
+    env.flag_dispute(&alice, ALICE, BOB).await.unwrap();
+    let dispute: Dispute = env.fetch(pda::dispute_pda(MATCH_ID, &alice.pubkey()).0).await;
+    assert_eq!(dispute.assigned_validator, env.validator.pubkey());
+    let stats: GlobalStats = env.fetch(pda::global_stats_pda().0).await;
+    assert_eq!(stats.total_disputes, 1);
+    let user: UserAccount = env.fetch(pda::user_account_pda(ALICE).0).await;
+    assert_eq!((user.active_disputes, user.season_disputes_filed), (1, 1));
+
+    // Open disputes are capped per user
+    let mut busy = TestEnv::blank_user_account();
+    busy.active_disputes = Dispute::DEFAULT_MAX_ACTIVE;
+    env.set_user_account(BOB, busy, UserAccount::MAX_SIZE).await;
+    let result = env.flag_dispute(&bob, BOB, ALICE).await;
+    assert_game_error(result, GameError::TooManyActiveDisputes);
+    env.set_user_account(BOB, TestEnv::blank_user_account(), UserAccount::MAX_SIZE).await;
+
+    env.advance_clock(Dispute::DEFAULT_WINDOW_SECONDS as i64 + 1).await;
+    let result = env.flag_dispute(&bob, BOB, ALICE).await;
+    assert_game_error(result, GameError::DisputeWindowClosed);
+}
+
+#[tokio::test]
+async fn slashes_are_escrowed_until_the_appeal_is_decided() {
+    let mut env = TestEnv::start().await;
+    let (coordinator, validator) = (env.coordinator.insecure_clone(), env.validator.insecure_clone());
+    let reputation_pda = pda::validator_reputation_pda(&validator.pubkey()).0;
+    let mut reputation: ValidatorReputation = TestEnv::blank_validator_reputation();
+    reputation.validator = validator.pubkey();
+    reputation.stake = 1_000_000;
+    reputation.reputation_bps = 8000;
+    env.set_validator_reputation(&reputation).await;
+
+    // Only the council slashes, and one slash at a time
+    let result = env.slash_validator(&validator, 400_000, 1).await;
+    assert_game_error(result, GameError::Unauthorized);
+    env.slash_validator(&coordinator, 400_000, 1).await.unwrap();
+    let result = env.slash_validator(&coordinator, 100_000, 1).await;
+    assert_game_error(result, GameError::SlashPending);
+    let slashed: ValidatorReputation = env.fetch(reputation_pda).await;
+    assert_eq!((slashed.stake, slashed.slashed_escrow), (600_000, 400_000));
+    assert_eq!(slashed.reputation_bps, 6400); // Negligent: -20%
+
+    // The appeal locks a 10% bond; the appellant can't review it
+    let lamports_before = env.context.banks_client.get_balance(reputation_pda).await.unwrap();
+    env.appeal_slash().await.unwrap();
+    let result = env.appeal_slash().await;
+    assert_game_error(result, GameError::NoAppealableSlash);
+    assert_eq!(env.context.banks_client.get_balance(reputation_pda).await.unwrap(), lamports_before + 40_000);
+    let result = env.resolve_slash_appeal(&validator, true).await;
+    assert_game_error(result, GameError::Unauthorized);
+
+    // Overturned: stake, reputation and bond come back
+    env.resolve_slash_appeal(&coordinator, true).await.unwrap();
+    let restored: ValidatorReputation = env.fetch(reputation_pda).await;
+    assert_eq!((restored.stake, restored.slashed_escrow, restored.reputation_bps), (1_000_000, 0, 8000));
+    assert_eq!(restored.appeal_status, ValidatorReputation::APPEAL_OVERTURNED);
+    assert_eq!(env.context.banks_client.get_balance(reputation_pda).await.unwrap(), lamports_before);
+    let result = env.resolve_slash_appeal(&coordinator, false).await;
+    assert_game_error(result, GameError::NoPendingAppeal);
+
+    // Unappealed, the next slash is final once the window passes
+    env.slash_validator(&coordinator, 250_000, 0).await.unwrap();
+    env.advance_clock(ValidatorReputation::SLASH_APPEAL_WINDOW_SECONDS).await;
+    let result = env.appeal_slash().await;
+    assert_game_error(result, GameError::AppealWindowClosed);
+    env.slash_validator(&coordinator, 50_000, 2).await.unwrap();
+    let final_state: ValidatorReputation = env.fetch(reputation_pda).await;
+    assert_eq!((final_state.stake, final_state.slashed_escrow), (700_000, 50_000));
+}
+
+#[tokio::test]
+async fn legacy_validator_reputation_is_migrated_to_basis_points() {
+    let mut env = TestEnv::start().await;
+    let reputation_pda = pda::validator_reputation_pda(&env.validator.pubkey()).0;
+    env.set_legacy_validator_reputation(750_000, 0.73456).await;
+
+    env.migrate_validator_reputation().await.unwrap();
+    let account = env.context.banks_client.get_account(reputation_pda).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), ValidatorReputation::MAX_SIZE);
+    let migrated: ValidatorReputation = env.fetch(reputation_pda).await;
+    assert_eq!(migrated.validator, env.validator.pubkey());
+    assert_eq!(migrated.stake, 750_000);
+    assert_eq!(migrated.reputation_bps, 7346);
+    assert_eq!((migrated.total_resolutions, migrated.correct_resolutions), (7, 5));
+    assert!(!migrated.has_pending_slash());
+
+    // Already current: nothing changes
+    env.migrate_validator_reputation().await.unwrap();
+    let again: ValidatorReputation = env.fetch(reputation_pda).await;
+    assert_eq!(again.reputation_bps, 7346);
+}
+
+#[tokio::test]
+async fn inactive_validators_are_slashed_by_the_crank() {
+    let mut env = TestEnv::start().await;
+    let reputation_pda = pda::validator_reputation_pda(&env.validator.pubkey()).0;
+    let start = env.unix_timestamp().await;
+    let mut reputation: ValidatorReputation = TestEnv::blank_validator_reputation();
+    reputation.validator = env.validator.pubkey();
+    reputation.stake = 1_000_000;
+    reputation.reputation_bps = 8000;
+    reputation.created_at = start;
+    reputation.last_active = start;
+    env.set_validator_reputation(&reputation).await;
+
+    // Nothing to enforce, and a fresh dispute isn't overdue yet
+    let result = env.enforce_inactivity(&[]).await;
+    assert_game_error(result, GameError::ValidatorNotInactive);
+    let flaggers: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+    let mut disputes = Vec::new();
+    for (i, flagger) in flaggers.iter().enumerate() {
+        disputes.push(env.set_open_dispute(flagger, start + i as i64, env.validator.pubkey()).await);
+    }
+    let result = env.enforce_inactivity(&disputes[..1]).await;
+    assert_game_error(result, GameError::InvalidInactivityEvidence);
+    // A dispute assigned to another validator is never this validator's miss
+    let elsewhere = env.set_open_dispute(&Pubkey::new_unique(), start, Pubkey::new_unique()).await;
+
+    // Missed disputes accumulate (each counted once) until the threshold slashes 5% of stake,
+    // even though the validator keeps sending heartbeats
+    env.advance_clock(ValidatorReputation::DISPUTE_RESPONSE_SECONDS + 10).await;
+    env.validator_heartbeat().await.unwrap();
+    let result = env.enforce_inactivity(&[elsewhere]).await;
+    assert_game_error(result, GameError::InvalidInactivityEvidence);
+    env.enforce_inactivity(&disputes[..2]).await.unwrap();
+    let counted: ValidatorReputation = env.fetch(reputation_pda).await;
+    assert_eq!((counted.missed_disputes, counted.stake), (2, 1_000_000));
+    let result = env.enforce_inactivity(&disputes[1..2]).await;
+    assert_game_error(result, GameError::InvalidInactivityEvidence);
+    env.enforce_inactivity(&disputes[2..]).await.unwrap();
+    let slashed: ValidatorReputation = env.fetch(reputation_pda).await;
+    assert_eq!((slashed.stake, slashed.slashed_escrow), (950_000, 50_000));
+    assert_eq!(slashed.slash_reason, ValidatorReputation::SLASH_REASON_INACTIVITY);
+    assert_eq!((slashed.missed_disputes, slashed.reputation_bps), (0, 7200));
+
+    // Heartbeats keep the validator live; silence for INACTIVITY_SECONDS is slashed
+    env.advance_clock(ValidatorReputation::INACTIVITY_SECONDS - 60).await;
+    let result = env.enforce_inactivity(&[]).await;
+    assert_game_error(result, GameError::ValidatorNotInactive);
+    env.validator_heartbeat().await.unwrap();
+    env.advance_clock(120).await;
+    let result = env.enforce_inactivity(&[]).await;
+    assert_game_error(result, GameError::ValidatorNotInactive);
+    env.advance_clock(ValidatorReputation::INACTIVITY_SECONDS).await;
+    env.enforce_inactivity(&[]).await.unwrap();
+    let inactive: ValidatorReputation = env.fetch(reputation_pda).await;
+    assert_eq!((inactive.stake, inactive.slashed_escrow), (902_500, 47_500));
+    assert_eq!(inactive.reputation_bps, 6480);
+}
+
+#[tokio::test]
+async fn failover_coordinators_can_end_a_stranded_match() {
+    let mut env = TestEnv::started_match(2).await;
+    let coordinator = env.coordinator.insecure_clone();
+    let backup = Keypair::new();
+    let set_pda = pda::coordinator_set_pda().0;
+
+    // Only registered coordinators join the set, and only the registry authority rotates it
+    let result = env.rotate_coordinator(&coordinator, backup.pubkey(), CoordinatorSet::ROTATE_ADD).await;
+    assert_game_error(result, GameError::SignerNotFound);
+    env.register_signer(backup.pubkey(), 0).await.unwrap();
+    env.rotate_coordinator(&coordinator, backup.pubkey(), CoordinatorSet::ROTATE_ADD).await.unwrap();
+    let result = env.rotate_coordinator(&backup, backup.pubkey(), CoordinatorSet::ROTATE_MARK_DEGRADED).await;
+    assert_game_error(result, GameError::Unauthorized);
+    let result = env.rotate_coordinator(&coordinator, backup.pubkey(), CoordinatorSet::ROTATE_ADD).await;
+    assert_game_error(result, GameError::CoordinatorAlreadyInSet);
+    let set: CoordinatorSet = env.fetch(set_pda).await;
+    assert_eq!((set.epoch, set.count), (1, 1));
+    assert!(set.is_active(&backup.pubkey()) && !set.is_active(&coordinator.pubkey()));
+
+    // Without the set (or once marked down) the backup is just another signer
+    let result = env.end_match(&backup).await;
+    assert_game_error(result, GameError::Unauthorized);
+    env.rotate_coordinator(&coordinator, backup.pubkey(), CoordinatorSet::ROTATE_MARK_DOWN).await.unwrap();
+    let result = env.end_match_as_failover(&backup).await;
+    assert_game_error(result, GameError::Unauthorized);
+
+    // A healthy backup finalizes the match the original coordinator left behind
+    env.rotate_coordinator(&coordinator, backup.pubkey(), CoordinatorSet::ROTATE_MARK_HEALTHY).await.unwrap();
+    env.end_match_as_failover(&backup).await.unwrap();
+    assert!(env.fetch_match().await.is_ended());
+
+    env.rotate_coordinator(&coordinator, backup.pubkey(), CoordinatorSet::ROTATE_REMOVE).await.unwrap();
+    let set: CoordinatorSet = env.fetch(set_pda).await;
+    assert_eq!((set.epoch, set.count), (4, 0));
+    let result = env.rotate_coordinator(&coordinator, backup.pubkey(), CoordinatorSet::ROTATE_MARK_DOWN).await;
+    assert_game_error(result, GameError::CoordinatorNotInSet);
+}
+
+#[tokio::test]
+async fn council_force_ends_a_stuck_match_without_a_winner() {
+    let mut env = TestEnv::started_match(2).await;
+    let (coordinator, validator, alice) = (env.coordinator.insecure_clone(), env.validator.insecure_clone(), env.player(0));
+    env.pause_match(&coordinator, 1, 600).await.unwrap();
+
+    // Council only, with a valid reason code
+    let result = env.force_end_match(&alice, Match::FORCE_END_STUCK_STATE).await;
+    assert_game_error(result, GameError::Unauthorized);
+    let result = env.force_end_match(&validator, Match::FORCE_END_STUCK_STATE).await;
+    assert_game_error(result, GameError::Unauthorized);
+    for reason in [0, Match::FORCE_END_OTHER + 1] {
+        let result = env.force_end_match(&coordinator, reason).await;
+        assert_game_error(result, GameError::InvalidPayload);
+    }
+
+    env.force_end_match(&coordinator, Match::FORCE_END_COORDINATOR_LOST).await.unwrap();
+    let m = env.fetch_match().await;
+    assert_eq!((m.phase, m.get_outcome()), (2, EndReason::ForceEnded));
+    assert_eq!(m.force_end_reason, Match::FORCE_END_COORDINATOR_LOST);
+    assert!(m.is_ended() && !m.paused_flag());
+    assert_eq!(m.winner(), None);
+
+    let result = env.force_end_match(&coordinator, Match::FORCE_END_OTHER).await;
+    assert_game_error(result, GameError::InvalidPhase);
+}
+
+#[tokio::test]
+async fn match_metadata_is_set_by_the_authority_before_play() {
+    let mut env = TestEnv::start().await;
+    let (coordinator, alice, bob) = (env.coordinator.insecure_clone(), env.player(0), env.player(1));
+    env.create_match().await.unwrap();
+
+    let result = env.set_match_metadata(&alice, b"tourney\0", [0x11; 32]).await;
+    assert_game_error(result, GameError::Unauthorized);
+    let result = env.set_match_metadata(&coordinator, &[0; 8], [0x11; 32]).await;
+    assert_game_error(result, GameError::InvalidPayload);
+
+    // Four slots; replacing a key reuses its slot and a zero value frees it
+    for (i, key) in [b"tourney\0", b"table\0\0\0", b"stream\0\0", b"series\0\0"].into_iter().enumerate() {
+        env.set_match_metadata(&coordinator, key, [i as u8 + 1; 32]).await.unwrap();
+    }
+    let result = env.set_match_metadata(&coordinator, b"extra\0\0\0", [0x55; 32]).await;
+    assert_game_error(result, GameError::MatchMetadataFull);
+    env.set_match_metadata(&coordinator, b"table\0\0\0", [0x22; 32]).await.unwrap();
+    env.set_match_metadata(&coordinator, b"series\0\0", [0; 32]).await.unwrap();
+    env.set_match_metadata(&coordinator, b"extra\0\0\0", [0x55; 32]).await.unwrap();
+    let m = env.fetch_match().await;
+    assert_eq!(m.metadata(b"tourney\0"), Some([1; 32]));
+    assert_eq!(m.metadata(b"table\0\0\0"), Some([0x22; 32]));
+    assert_eq!(m.metadata(b"series\0\0"), None);
+    assert_eq!(m.metadata(b"extra\0\0\0"), Some([0x55; 32]));
+
+    // Fixed once play starts
+    env.join_match(&alice, ALICE).await.unwrap();
+    env.join_match(&bob, BOB).await.unwrap();
+    env.commit_hand(&alice, ALICE, [0xA0; 32]).await.unwrap();
+    env.commit_hand(&bob, BOB, [0xA1; 32]).await.unwrap();
+    env.start_match().await.unwrap();
+    let result = env.set_match_metadata(&coordinator, b"tourney\0", [0x77; 32]).await;
+    assert_game_error(result, GameError::InvalidPhase);
+}
+
+#[tokio::test]
+async fn scrabble_words_are_validated_and_scored_on_chain() {
+    let mut env = TestEnv::start().await;
+    let (alice, bob) = (env.player(0), env.player(1));
+    let board_pda = pda::scrabble_board_pda(MATCH_ID).0;
+    // Alice: C A T E R I O, Bob: S N D L U G + blank
+    let (bag_head, reveals) = tile_bag(&[3, 1, 20, 5, 18, 9, 15, 19, 14, 4, 12, 21, 7, ScrabbleBoard::BLANK], [0x5A; 32]);
+
+    env.create_match().await.unwrap();
+    let result = env.init_scrabble_board(bag_head).await;
+    assert_game_error(result, GameError::WrongGameType);
+
+    let mut env = TestEnv::start().await;
+    env.create_match_of_type(GameType::Scrabble as u8).await.unwrap();
+    env.join_match(&alice, ALICE).await.unwrap();
+    env.join_match(&bob, BOB).await.unwrap();
+    env.init_scrabble_board(bag_head).await.unwrap();
+
+    // Tiles come off the committed bag in order
+    let result = env.draw_tiles(&alice, ALICE, &reveals[1..2]).await;
+    assert_game_error(result, GameError::InvalidTileReveal);
+    env.draw_tiles(&alice, ALICE, &reveals[..7]).await.unwrap();
+    let result = env.draw_tiles(&alice, ALICE, &reveals[7..8]).await;
+    assert_game_error(result, GameError::RackFull);
+
+    // Racks are dealt only with the seat's own wallet co-signing, and not while sanctioned
+    let result = env.draw_tiles(&alice, BOB, &reveals[7..]).await;
+    assert_game_error(result, GameError::Unauthorized);
+    let now = env.unix_timestamp().await;
+    env.set_sanction(BOB, SanctionKind::Suspension, now + 3600).await;
+    let result = env.draw_tiles(&bob, BOB, &reveals[7..]).await;
+    assert_game_error(result, GameError::UserSanctioned);
+    env.set_sanction(BOB, SanctionKind::Suspension, now - 1).await;
+    env.draw_tiles(&bob, BOB, &reveals[7..]).await.unwrap();
+    let board: ScrabbleBoard = env.fetch(board_pda).await;
+    assert_eq!(board.bag_remaining, ScrabbleBoard::BAG_SIZE - 14);
+    assert_eq!(board.racks[0], [3, 1, 20, 5, 18, 9, 15]);
+
+    env.commit_hand(&alice, ALICE, [0xA0; 32]).await.unwrap();
+    env.commit_hand(&bob, BOB, [0xA1; 32]).await.unwrap();
+    env.start_match().await.unwrap();
+
+    // The first word must cover the center, on the player's turn
+    let result = env.place_word(&bob, BOB, (7, 7), true, &[19, 14]).await;
+    assert_game_error(result, GameError::NotPlayerTurn);
+    let result = env.place_word(&bob, ALICE, (7, 6), true, &[3, 1, 20]).await;
+    assert_game_error(result, GameError::Unauthorized);
+    let result = env.place_word(&alice, ALICE, (0, 0), true, &[3, 1, 20]).await;
+    assert_game_error(result, GameError::InvalidTilePlacement);
+    env.place_word(&alice, ALICE, (7, 6), true, &[3, 1, 20]).await.unwrap(); // CAT: 5 × 2 (center)
+
+    // Later words must come from the rack and touch the board
+    let result = env.place_word(&bob, BOB, (7, 9), true, &[26]).await;
+    assert_game_error(result, GameError::TileNotInRack);
+    let result = env.place_word(&bob, BOB, (2, 2), true, &[19, 14]).await;
+    assert_game_error(result, GameError::InvalidTilePlacement);
+    env.place_word(&bob, BOB, (7, 9), true, &[19]).await.unwrap(); // CATS: 6
+    env.place_word(&alice, ALICE, (8, 7), false, &[18, 5]).await.unwrap(); // ARE down from the A: 3
+
+    let board: ScrabbleBoard = env.fetch(board_pda).await;
+    assert_eq!((board.cell(7, 6), board.cell(7, 9), board.cell(9, 7)), (3, 19, 5));
+    assert_eq!(board.scores, [10 + 3, 6, 0, 0]);
+    assert_eq!(board.racks[0], [0, 0, 0, 0, 0, 9, 15]);
+    assert_eq!(board.racks[1], [0, 14, 4, 12, 21, 7, ScrabbleBoard::BLANK]);
+    assert_eq!(board.words_played, 3);
+    assert_eq!(env.fetch_match().await.current_player, 1);
+}
+
+#[tokio::test]
+async fn word_search_claims_are_proven_against_the_committed_puzzle() {
+    let mut env = TestEnv::start().await;
+    let (alice, bob) = (env.player(0), env.player(1));
+    let puzzle_pda = pda::word_search_pda(MATCH_ID).0;
+    let grid = b"CATSODOGWXYEQBEN";
+    let words: [&[u8]; 4] = [b"CATS", b"COW", b"DOG", b"NEB"];
+    let grid_hash = WordSearchPuzzle::grid_commitment(4, 4, grid);
+    let (word_root, proofs) = word_list(&words);
+    let claim = |index: usize, (row, col): (u8, u8), direction: u8| FoundWord {
+        word_index: index as u8,
+        word: words[index].to_vec(),
+        row,
+        col,
+        direction,
+        proof: proofs[index].clone(),
+    };
+
+    env.create_match().await.unwrap();
+    let result = env.commit_word_search(grid_hash, word_root, (4, 4), 4).await;
+    assert_game_error(result, GameError::WrongGameType);
+
+    let mut env = TestEnv::start().await;
+    env.create_match_of_type(GameType::WordSearch as u8).await.unwrap();
+    env.join_match(&alice, ALICE).await.unwrap();
+    env.join_match(&bob, BOB).await.unwrap();
+    env.commit_word_search(grid_hash, word_root, (4, 4), 4).await.unwrap();
+
+    // The grid stays hidden until play starts, then must match the commitment
+    let result = env.reveal_word_search_grid(grid).await;
+    assert_game_error(result, GameError::InvalidPhase);
+    env.commit_hand(&alice, ALICE, [0xA0; 32]).await.unwrap();
+    env.commit_hand(&bob, BOB, [0xA1; 32]).await.unwrap();
+    env.start_match().await.unwrap();
+    let result = env.submit_found_word(&alice, ALICE, claim(0, (0, 0), 0)).await;
+    assert_game_error(result, GameError::InvalidGridReveal);
+    let result = env.reveal_word_search_grid(b"CATSODOGWXYEQBEM").await;
+    assert_game_error(result, GameError::InvalidGridReveal);
+    env.reveal_word_search_grid(grid).await.unwrap();
+
+    // Each word is proven against the list and read off the grid, once
+    env.submit_found_word(&alice, ALICE, claim(0, (0, 0), 0)).await.unwrap(); // CATS east
+    let result = env.submit_found_word(&bob, BOB, claim(0, (0, 0), 0)).await;
+    assert_game_error(result, GameError::WordAlreadyFound);
+    let result = env.submit_found_word(&bob, BOB, claim(2, (1, 0), 0)).await;
+    assert_game_error(result, GameError::WordNotInGrid);
+    let result = env.submit_found_word(&bob, BOB, FoundWord { word: b"DOGE".to_vec(), ..claim(2, (1, 1), 0) }).await;
+    assert_game_error(result, GameError::WordNotInList);
+
+    // Claims score for the seat, so only its wallet may make them, and not while sanctioned
+    let result = env.submit_found_word(&bob, ALICE, claim(3, (3, 3), 4)).await;
+    assert_game_error(result, GameError::Unauthorized);
+    let now = env.unix_timestamp().await;
+    env.set_sanction(BOB, SanctionKind::Suspension, now + 3600).await;
+    let result = env.submit_found_word(&bob, BOB, claim(3, (3, 3), 4)).await;
+    assert_game_error(result, GameError::UserSanctioned);
+    env.set_sanction(BOB, SanctionKind::Suspension, now - 1).await;
+    env.submit_found_word(&bob, BOB, claim(3, (3, 3), 4)).await.unwrap(); // NEB west
+    env.submit_found_word(&alice, ALICE, claim(1, (0, 0), 2)).await.unwrap(); // COW south
+
+    let puzzle: WordSearchPuzzle = env.fetch(puzzle_pda).await;
+    assert_eq!(&puzzle.found_by[..4], &[1, 1, 0, 2]);
+    assert_eq!(&puzzle.scores[..2], &[4 + 3, 3]);
+    assert_eq!(puzzle.words_found, 3);
+    assert!(!puzzle.all_found());
+}
+
+#[tokio::test]
+async fn crossword_answers_are_checked_against_the_committed_root() {
+    let mut env = TestEnv::start().await;
+    let (alice, bob) = (env.player(0), env.player(1));
+    let puzzle_pda = pda::crossword_pda(MATCH_ID).0;
+    let answers: [&[u8]; 3] = [b"ICECREAM", b"OWL", b"SOLANA"];
+    let (answer_root, proofs) = merkle_tree(
+        answers.iter().enumerate().map(|(i, a)| CrosswordPuzzle::answer_leaf(i as u8, a)).collect(),
+    );
+
+    env.create_match().await.unwrap();
+    let result = env.commit_crossword(answer_root, 3).await;
+    assert_game_error(result, GameError::WrongGameType);
+
+    let mut env = TestEnv::start().await;
+    env.create_match_of_type(GameType::Crosswords as u8).await.unwrap();
+    env.join_match(&alice, ALICE).await.unwrap();
+    env.join_match(&bob, BOB).await.unwrap();
+    env.commit_crossword(answer_root, 3).await.unwrap();
+    let result = env.submit_answer(&alice, ALICE, 1, "owl", &proofs[1]).await;
+    assert_game_error(result, GameError::InvalidPhase);
+    env.commit_hand(&alice, ALICE, [0xA0; 32]).await.unwrap();
+    env.commit_hand(&bob, BOB, [0xA1; 32]).await.unwrap();
+    env.start_match().await.unwrap();
+
+    // Answers are normalized to uppercase letters before hashing with the clue index
+    env.submit_answer(&alice, ALICE, 0, "Ice-cream", &proofs[0]).await.unwrap();
+    let result = env.submit_answer(&bob, BOB, 0, "ICE CREAM", &proofs[0]).await;
+    assert_game_error(result, GameError::ClueAlreadySolved);
+    let result = env.submit_answer(&bob, BOB, 1, "OWLS", &proofs[1]).await;
+    assert_game_error(result, GameError::WrongAnswer);
+    let result = env.submit_answer(&bob, BOB, 2, "owl", &proofs[1]).await;
+    assert_game_error(result, GameError::WrongAnswer);
+
+    // Only the seat's wallet answers for it, and not while sanctioned
+    let result = env.submit_answer(&bob, ALICE, 1, "owl", &proofs[1]).await;
+    assert_game_error(result, GameError::Unauthorized);
+    let now = env.unix_timestamp().await;
+    env.set_sanction(BOB, SanctionKind::Suspension, now + 3600).await;
+    let result = env.submit_answer(&bob, BOB, 1, "owl", &proofs[1]).await;
+    assert_game_error(result, GameError::UserSanctioned);
+    env.set_sanction(BOB, SanctionKind::Suspension, now - 1).await;
+    env.submit_answer(&bob, BOB, 1, "owl", &proofs[1]).await.unwrap();
+
+    let puzzle: CrosswordPuzzle = env.fetch(puzzle_pda).await;
+    assert_eq!(&puzzle.solved_by[..3], &[1, 2, 0]);
+    assert_eq!(&puzzle.scores[..2], &[8, 3]);
+    assert_eq!(puzzle.clues_solved, 2);
+    assert!(!puzzle.all_solved());
+}
+
+#[tokio::test]
+async fn rummy_melds_leave_the_hand_and_score() {
+    let mut env = TestEnv::started_match(2).await;
+    let alice = env.player(0);
+    let result = env.submit_move(&alice, ALICE, 10, vec![0, 7, 1, 7, 2, 7], 1).await;
+    assert_game_error(result, GameError::WrongGameType);
+
+    let mut env = TestEnv::start().await;
+    let (alice, bob) = (env.player(0), env.player(1));
+    env.create_match_of_type(GameType::Rummy as u8).await.unwrap();
+    env.join_match(&alice, ALICE).await.unwrap();
+    env.join_match(&bob, BOB).await.unwrap();
+    env.commit_hand_of_size(&alice, ALICE, [0xA0; 32], 10).await.unwrap();
+    env.commit_hand_of_size(&bob, BOB, [0xA1; 32], 10).await.unwrap();
+    env.start_match().await.unwrap();
+
+    let result = env.submit_move(&bob, BOB, 10, vec![0, 7, 1, 7, 2, 7], 1).await;
+    assert_game_error(result, GameError::NotPlayerTurn);
+    let result = env.submit_move(&alice, ALICE, 10, vec![0, 2, 1, 3, 2, 4], 1).await;
+    assert_game_error(result, GameError::InvalidMeld);
+    env.submit_move(&alice, ALICE, 10, vec![0, 7, 1, 7, 2, 7], 2).await.unwrap(); // Set of 7s: 21
+
+    // A joker fills the run's gap; a melded card can't be melded again
+    env.submit_move(&alice, ALICE, 10, vec![1, 9, 4, 0, 1, 11], 3).await.unwrap(); // 9 10 J of hearts: 29
+    let result = env.submit_move(&alice, ALICE, 10, vec![0, 7, 0, 8, 0, 9], 4).await;
+    assert_game_error(result, GameError::CardAlreadyMelded);
+    let result = env.submit_move(&alice, ALICE, 10, vec![4, 0, 4, 1, 3, 5], 5).await;
+    assert_game_error(result, GameError::InvalidMeld);
+
+    let m = env.fetch_match().await;
+    assert_eq!(m.meld_points[..2], [21 + 29, 0]);
+    assert_eq!(m.melded_card_count(), 6);
+    assert_eq!(m.get_hand_size(0), 4);
+    assert!(m.deck_accounting_consistent());
+}
+
+#[tokio::test]
+async fn brag_bets_fill_the_pot_and_show_compares_committed_hands() {
+    let mut env = TestEnv::started_match(2).await;
+    let alice = env.player(0);
+    let result = env.submit_move(&alice, ALICE, brag::BLIND, 10u64.to_le_bytes().to_vec(), 1).await;
+    assert_game_error(result, GameError::BettingDisabled);
+
+    let mut env = TestEnv::start().await;
+    let (alice, bob, carol) = (env.player(0), env.player(1), env.player(2));
+    let brag_game = GameType::ThreeCardBrag as u8;
+    env.register_game(brag_game, "Three Card Brag", 6).await.unwrap();
+    let alice_hand = [(0, 14), (1, 14), (2, 14)]; // Prial of aces
+    let bob_hand = [(1, 5), (1, 6), (1, 7)]; // Running flush
+    let cards = |hand: [(u8, u8); 3]| hand.iter().flat_map(|&(suit, value)| [suit, value]).collect::<Vec<u8>>();
+    let chips = |amount: u64| amount.to_le_bytes().to_vec();
+    env.create_match_of_type(GameType::ThreeCardBrag as u8).await.unwrap();
+    for (player, user_id) in [(&alice, ALICE), (&bob, BOB), (&carol, CAROL)] {
+        env.join_match(player, user_id).await.unwrap();
+    }
+    env.commit_hand(&alice, ALICE, validation::hand_hash(&alice_hand)).await.unwrap();
+    env.commit_hand(&bob, BOB, validation::hand_hash(&bob_hand)).await.unwrap();
+    env.commit_hand(&carol, CAROL, [0xA2; 32]).await.unwrap();
+    env.start_match().await.unwrap();
+
+    // Betting actions need the registry's has_betting flag; rake is capped
+    let result = env.submit_move(&alice, ALICE, brag::BLIND, chips(10), 1).await;
+    assert_game_error(result, GameError::BettingDisabled);
+    let result = env.set_game_betting(brag_game, true, 2_000).await;
+    assert_game_error(result, GameError::RakeTooHigh);
+    env.set_game_betting(brag_game, true, 1_000).await.unwrap();
+
+    // Alice stakes blind; Bob must see before betting, then bet double
+    env.submit_move(&alice, ALICE, brag::BLIND, chips(10), 2).await.unwrap();
+    let result = env.submit_move(&bob, BOB, brag::BET, chips(20), 1).await;
+    assert_game_error(result, GameError::HandNotSeen);
+    env.submit_move(&bob, BOB, brag::SEE, vec![], 2).await.unwrap();
+    let result = env.submit_move(&bob, BOB, brag::BET, chips(10), 3).await;
+    assert_game_error(result, GameError::BetTooLow);
+    env.submit_move(&bob, BOB, brag::BET, chips(20), 4).await.unwrap();
+    env.submit_move(&carol, CAROL, brag::FOLD, vec![], 1).await.unwrap();
+
+    // Two left: a seen Alice calls the show with her committed hand, Bob answers
+    let result = env.submit_move(&alice, ALICE, brag::SHOW, cards(alice_hand), 3).await;
+    assert_game_error(result, GameError::HandNotSeen);
+    env.submit_move(&alice, ALICE, brag::SEE, vec![], 4).await.unwrap();
+    let result = env.submit_move(&alice, ALICE, brag::SHOW, cards(bob_hand), 5).await;
+    assert_game_error(result, GameError::HandRevealMismatch);
+    env.submit_move(&alice, ALICE, brag::SHOW, cards(alice_hand), 6).await.unwrap();
+    let result = env.submit_move(&bob, BOB, brag::BET, chips(40), 5).await;
+    assert_game_error(result, GameError::ShowNotAvailable);
+    env.submit_move(&bob, BOB, brag::SHOW, cards(bob_hand), 6).await.unwrap();
+
+    let m = env.fetch_match().await;
+    assert!(m.is_ended());
+    assert_eq!((m.outcome, m.outcome_player), (EndReason::Completed as u8, 0));
+    assert_eq!(m.pot.total(), 10 + 20 + 20);
+    assert_eq!(m.pot.contributions[..3], [30, 20, 0]);
+    assert!(m.pot.settled);
+    assert_eq!(m.pot.payouts[..3], [45, 0, 0]);
+    assert_eq!(m.pot.rake, 5);
+}
+
+#[tokio::test]
+async fn series_completes_when_a_player_reaches_the_win_threshold() {
+    let mut env = TestEnv::started_match(2).await;
+    let bob = env.player(1);
+    env.submit_move(&bob, BOB, 7, vec![], 1).await.unwrap(); // Bob resigns: Alice wins
+    let won = env.fetch_match().await;
+
+    // Roster order differs from the match seats
+    let result = env.create_series(0, 4, &[BOB, ALICE]).await;
+    assert_game_error(result, GameError::InvalidSeriesLength);
+    env.create_series(0, 3, &[BOB, ALICE]).await.unwrap();
+
+    env.report_series_game(MATCH_ID).await.unwrap();
+    let result = env.report_series_game(MATCH_ID).await;
+    assert_game_error(result, GameError::MatchAlreadyReported);
+
+    // A draw uses a slot without scoring; a match with another player doesn't count
+    let (drawn, outsider, rematch) = (
+        "5c4b3a29-1111-4c7d-8e9f-0a1b2c3d4e5f",
+        "5c4b3a29-2222-4c7d-8e9f-0a1b2c3d4e5f",
+        "5c4b3a29-3333-4c7d-8e9f-0a1b2c3d4e5f",
+    );
+    let mut m = won.clone();
+    m.outcome = EndReason::Draw as u8;
+    env.set_match(drawn, m).await;
+    env.report_series_game(drawn).await.unwrap();
+    let mut m = won.clone();
+    m.player_ids[1] = [0; 64];
+    m.player_ids[1][..CAROL.len()].copy_from_slice(CAROL.as_bytes());
+    env.set_match(outsider, m).await;
+    let result = env.report_series_game(outsider).await;
+    assert_game_error(result, GameError::SeriesRosterMismatch);
+
+    let series: Series = env.fetch(pda::series_pda(SERIES_ID).0).await;
+    assert_eq!((series.games_reported, series.wins[..2].to_vec()), (2, vec![0, 1]));
+    assert!(!series.is_complete());
+
+    // Second win for Alice takes the best of 3
+    env.set_match(rematch, won).await;
+    env.report_series_game(rematch).await.unwrap();
+    let series: Series = env.fetch(pda::series_pda(SERIES_ID).0).await;
+    assert!(series.is_complete());
+    assert_eq!(series.winner, 1);
+    assert_eq!(series.wins[..2], [0, 2]);
+    let result = env.report_series_game(outsider).await;
+    assert_game_error(result, GameError::SeriesAlreadyComplete);
+}
+
+#[tokio::test]
+async fn scheduled_matches_start_on_time_or_expire() {
+    // Joined and dealt, but scheduled 10 minutes out
+    async fn scheduled_env() -> (TestEnv, i64) {
+        let mut env = TestEnv::start().await;
+        env.create_match().await.unwrap();
+        for (i, user_id) in [ALICE, BOB].into_iter().enumerate() {
+            let player = env.player(i);
+            env.join_match(&player, user_id).await.unwrap();
+            env.commit_hand(&player, user_id, [0xA0 + i as u8; 32]).await.unwrap();
+        }
+        let start = env.unix_timestamp().await + 600;
+        let result = env.schedule_match(start - 1200).await;
+        assert_game_error(result, GameError::InvalidTimestamp);
+        env.schedule_match(start).await.unwrap();
+        (env, start)
+    }
+
+    let (mut env, start) = scheduled_env().await;
+    let carol = env.player(2);
+    assert_game_error(env.start_match().await, GameError::ScheduledStartNotReached);
+    env.advance_clock(600).await;
+    let result = env.expire_scheduled_match(&carol).await;
+    assert_game_error(result, GameError::ScheduledStartNotReached);
+    env.start_match().await.unwrap();
+    let m = env.fetch_match().await;
+    assert_eq!((m.phase, m.scheduled_start), (1, start));
+
+    // Nobody starts it within the grace window: anyone can void it for refunds
+    let (mut env, _) = scheduled_env().await;
+    env.advance_clock(600 + Match::SCHEDULE_GRACE_SECONDS).await;
+    assert_game_error(env.start_match().await, GameError::ScheduledStartMissed);
+    env.expire_scheduled_match(&carol).await.unwrap();
+    let m = env.fetch_match().await;
+    assert!(m.is_ended());
+    assert_eq!(m.get_outcome(), EndReason::ForceEnded);
+    assert_eq!(m.force_end_reason, Match::FORCE_END_SCHEDULE_MISSED);
+    assert_eq!(m.winner(), None);
+    let result = env.expire_scheduled_match(&carol).await;
+    assert_game_error(result, GameError::InvalidPhase);
+}
+
+#[tokio::test]
+async fn rule_params_set_hand_size_and_handicaps_before_anyone_joins() {
+    let mut env = TestEnv::start().await;
+    let (alice, bob) = (env.player(0), env.player(1));
+    env.create_match().await.unwrap();
+
+    // 5-card hands, first to 100 points, Bob starts 3 points down
+    let mut rule_params = [0u8; 16];
+    rule_params[0] = 5;
+    rule_params[1..3].copy_from_slice(&100u16.to_le_bytes());
+    rule_params[RuleParams::HANDICAP_OFFSET + 1] = -3i8 as u8;
+    let mut reserved = rule_params;
+    reserved[15] = 1;
+    assert_game_error(env.set_rule_params(reserved).await, GameError::InvalidRuleParams);
+    env.set_rule_params(rule_params).await.unwrap();
+
+    env.join_match(&alice, ALICE).await.unwrap();
+    env.join_match(&bob, BOB).await.unwrap();
+    assert_game_error(env.set_rule_params([0; 16]).await, GameError::InvalidPhase);
+
+    let result = env.commit_hand(&alice, ALICE, [0xA0; 32]).await;
+    assert_game_error(result, GameError::HandSizeMismatch);
+    env.commit_hand_of_size(&alice, ALICE, [0xA0; 32], 5).await.unwrap();
+    env.commit_hand_of_size(&bob, BOB, [0xA1; 32], 5).await.unwrap();
+    env.start_match().await.unwrap();
+
+    let m = env.fetch_match().await;
+    assert_eq!(m.rule_params().point_target, 100);
+    assert_eq!(m.cumulative_scores[..2], [0, -3]);
+    assert!(!m.point_target_reached(&[99, 99, 0, 0, 0, 0, 0, 0, 0, 0]));
+    assert!(m.point_target_reached(&[100, 0, 0, 0, 0, 0, 0, 0, 0, 0]));
+}
+
+#[tokio::test]
+async fn bot_seats_are_attested_and_rated_on_the_bot_ladder() {
+    let mut env = TestEnv::start().await;
+    let (alice, bot) = (env.player(0), env.player(1));
+    env.create_match().await.unwrap();
+    env.join_match(&alice, ALICE).await.unwrap();
+    let result = env.join_match_as_bot(&bot, BOB, false).await;
+    assert_game_error(result, GameError::BotAttestationRequired);
+    env.join_match_as_bot(&bot, BOB, true).await.unwrap();
+
+    let m = env.fetch_match().await;
+    assert!(!m.is_bot_seat(0) && m.is_bot_seat(1));
+
+    // Alice beats the bot: only her bot ladder rating moves
+    let mut m = m;
+    m.phase = 2;
+    m.ended_at = env.unix_timestamp().await;
+    m.cumulative_scores[..2].copy_from_slice(&[10, 0]);
+    env.set_match(MATCH_ID, m).await;
+    for user_id in [ALICE, BOB] {
+        env.initialize_player_rating(user_id).await.unwrap();
+    }
+    env.record_rating_change(&[ALICE, BOB]).await.unwrap();
+
+    let rating: PlayerRating = env.fetch(pda::player_rating_pda(ALICE).0).await;
+    assert_eq!((rating.get_rating(0, false), rating.games_rated[0]), (PlayerRating::INITIAL_RATING, 0));
+    assert!(rating.get_rating(0, true) > PlayerRating::INITIAL_RATING);
+    assert_eq!(rating.bot_games_rated[0], 1);
+}
+
+#[tokio::test]
+async fn ai_moves_are_attested_once_by_the_match_authority() {
+    let mut env = TestEnv::started_match(2).await;
+    let alice = env.player(0);
+    let coordinator = env.coordinator.insecure_clone();
+    let first_card = [0x11; 32];
+    env.reveal_floor_card(first_card).await.unwrap();
+    env.submit_move(&alice, ALICE, 0, first_card.to_vec(), 1).await.unwrap();
+
+    let result = env.attest_ai_move(&alice, 0, 2, [0x5C; 32]).await;
+    assert_game_error(result, GameError::Unauthorized);
+    let result = env.attest_ai_move(&coordinator, 0, 10, [0x5C; 32]).await;
+    assert_game_error(result, GameError::InvalidPayload);
+    // No Move account yet at index 1
+    assert!(env.attest_ai_move(&coordinator, 1, 2, [0x5C; 32]).await.is_err());
+
+    env.attest_ai_move(&coordinator, 0, 2, [0x5C; 32]).await.unwrap();
+    let attestation: AiMoveAttestation = env.fetch(pda::ai_attestation_pda(MATCH_ID, 0).0).await;
+    assert_eq!((attestation.move_index, attestation.model_id), (0, 2));
+    assert_eq!(attestation.player, alice.pubkey());
+    assert_eq!(attestation.prompt_hash, [0xA1; 32]);
+    assert!(attestation.is_billed());
+
+    // One attestation per move
+    env.advance_clock(1).await;
+    assert!(env.attest_ai_move(&coordinator, 0, 2, [0x5C; 32]).await.is_err());
+}
+
+#[tokio::test]
+async fn ai_refunds_are_capped_by_metered_consumption_and_apart_from_grants() {
+    let mut env = TestEnv::start().await;
+    let mut config: ConfigAccount = env.fetch(pda::config_pda().0).await;
+    config.authority = env.coordinator.pubkey();
+    config.ai_model_costs[0] = 10;
+    env.set_config(&config).await;
+    env.set_user_account(ALICE, TestEnv::blank_user_account(), UserAccount::MAX_SIZE).await;
+    env.consume_ai_credits(ALICE, 0, 5, 1).await.unwrap();
+
+    // A grant and a refund with the same id don't collide
+    env.grant_ai_credits(ALICE, [0x42; 32], 30).await.unwrap();
+    env.refund_ai_consumption(ALICE, [0x42; 32], 0, 20).await.unwrap();
+    let result = env.refund_ai_consumption(ALICE, [0x42; 32], 0, 20).await;
+    assert!(result.is_err());
+
+    // Only what the meter recorded for that model can come back
+    let result = env.refund_ai_consumption(ALICE, [0x43; 32], 1, 10).await;
+    assert_game_error(result, GameError::RefundExceedsSpent);
+    let result = env.refund_ai_consumption(ALICE, [0x43; 32], 0, 31).await;
+    assert_game_error(result, GameError::RefundExceedsSpent);
+    env.refund_ai_consumption(ALICE, [0x43; 32], 0, 30).await.unwrap();
+
+    let period_id = (env.unix_timestamp().await / UsageMeter::PERIOD_SECONDS) as u32;
+    let meter: UsageMeter = env.fetch(pda::usage_meter_pda(ALICE, period_id).0).await;
+    let user: UserAccount = env.fetch(pda::user_account_pda(ALICE).0).await;
+    assert_eq!((meter.ac_spent[0], user.total_ac_spent, user.total_ac_granted), (0, 0, 30));
+}
+
+#[tokio::test]
+async fn daily_ai_spend_limit_caps_consumption_and_raises_wait_a_day() {
+    let mut env = TestEnv::start().await;
+    let mut config: ConfigAccount = env.fetch(pda::config_pda().0).await;
+    config.authority = env.coordinator.pubkey();
+    config.ai_model_costs[0] = 10;
+    env.set_config(&config).await;
+    env.set_user_account(ALICE, TestEnv::blank_user_account(), UserAccount::MAX_SIZE).await;
+
+    env.set_ai_spend_limit(ALICE, 100).await.unwrap();
+    env.consume_ai_credits(ALICE, 0, 6, 1).await.unwrap();
+    let result = env.consume_ai_credits(ALICE, 0, 5, 2).await;
+    assert_game_error(result, GameError::AiSpendLimitExceeded);
+    env.consume_ai_credits(ALICE, 0, 4, 3).await.unwrap();
+
+    // Raising the cap is queued; the spent counter resets the next day
+    env.set_ai_spend_limit(ALICE, 500).await.unwrap();
+    let user: UserAccount = env.fetch(pda::user_account_pda(ALICE).0).await;
+    assert_eq!((user.ac_daily_limit, user.ac_pending_limit, user.ac_spent_today), (100, 500, 100));
+    env.advance_clock(UserAccount::AC_LIMIT_RAISE_DELAY_SECONDS).await;
+    env.consume_ai_credits(ALICE, 0, 30, 4).await.unwrap();
+    let user: UserAccount = env.fetch(pda::user_account_pda(ALICE).0).await;
+    assert_eq!((user.ac_daily_limit, user.ac_spent_today), (500, 300));
+    assert!(!user.has_pending_ac_limit());
+
+    // Tightening applies at once
+    env.set_ai_spend_limit(ALICE, 200).await.unwrap();
+    let result = env.consume_ai_credits(ALICE, 0, 1, 5).await;
+    assert_game_error(result, GameError::AiSpendLimitExceeded);
+
+    // An op_nonce can't jump far enough to lock the account
+    let result = env.consume_ai_credits(ALICE, 0, 1, u64::MAX).await;
+    assert_game_error(result, GameError::InvalidNonce);
+}
+
+#[tokio::test]
+async fn subscription_allowance_covers_ai_calls_and_rolls_over_up_to_the_cap() {
+    let mut env = TestEnv::start().await;
+    let mut config: ConfigAccount = env.fetch(pda::config_pda().0).await;
+    config.ai_model_costs[0] = 10;
+    config.ac_allowance_per_period = [0, 0, 500];
+    config.ac_rollover_cap = [0, 0, 150];
+    env.set_config(&config).await;
+    let mut user = TestEnv::blank_user_account();
+    user.subscription_tier = 2;
+    user.subscription_expiry = env.unix_timestamp().await + 3 * UsageMeter::PERIOD_SECONDS;
+    env.set_user_account(ALICE, user, UserAccount::MAX_SIZE).await;
+    env.set_user_account(BOB, TestEnv::blank_user_account(), UserAccount::MAX_SIZE).await;
+
+    let result = env.accrue_subscription_credits(BOB, false).await;
+    assert_game_error(result, GameError::NoSubscriptionAllowance);
+    env.accrue_subscription_credits(ALICE, false).await.unwrap();
+    env.advance_clock(1).await;
+    let result = env.accrue_subscription_credits(ALICE, false).await;
+    assert_game_error(result, GameError::AllowanceAlreadyAccrued);
+
+    // 300 AC of the 500 allowance used; 150 of the 200 left rolls over
+    env.consume_ai_credits(ALICE, 0, 30, 1).await.unwrap();
+    let period_id = (env.unix_timestamp().await / UsageMeter::PERIOD_SECONDS) as u32;
+    let meter: UsageMeter = env.fetch(pda::usage_meter_pda(ALICE, period_id).0).await;
+    assert_eq!((meter.ac_allowance, meter.ac_allowance_used, meter.allowance_remaining()), (500, 300, 200));
+
+    env.advance_clock(UsageMeter::PERIOD_SECONDS).await;
+    env.accrue_subscription_credits(ALICE, true).await.unwrap();
+    let meter: UsageMeter = env.fetch(pda::usage_meter_pda(ALICE, period_id + 1).0).await;
+    assert_eq!((meter.ac_allowance, meter.ac_rolled_over), (650, 150));
+}
+
+#[tokio::test]
+async fn stripe_purchases_need_a_fresh_oracle_receipt() {
+    let mut env = TestEnv::start().await;
+    let (coordinator, alice) = (env.coordinator.insecure_clone(), env.player(0));
+    env.set_user_account(ALICE, TestEnv::blank_user_account(), UserAccount::MAX_SIZE).await;
+    let now = env.unix_timestamp().await;
+
+    // Receipt signed for a 30-day Pro plan can't buy 60 days or ProPlus
+    let receipt = env.payment_attestation("pi_sub_1", now + 600);
+    let signed = TestEnv::sign_payment(&coordinator, &receipt, ALICE, PaymentProduct::ProSubscription, 30);
+    let result = env.purchase_subscription(ALICE, 1, 60, 1, receipt.clone(), signed.clone()).await;
+    assert_game_error(result, GameError::InvalidPaymentAttestation);
+    let result = env.purchase_subscription(ALICE, 2, 30, 1, receipt.clone(), signed.clone()).await;
+    assert_game_error(result, GameError::InvalidPaymentAttestation);
+
+    // Only registered coordinators attest
+    let mut forged = receipt.clone();
+    forged.attestor = alice.pubkey();
+    let forged_ix = TestEnv::sign_payment(&alice, &forged, ALICE, PaymentProduct::ProSubscription, 30);
+    let result = env.purchase_subscription(ALICE, 1, 30, 1, forged, forged_ix).await;
+    assert_game_error(result, GameError::InvalidPaymentAttestation);
+
+    env.purchase_subscription(ALICE, 1, 30, 1, receipt.clone(), signed.clone()).await.unwrap();
+    let user: UserAccount = env.fetch(pda::user_account_pda(ALICE).0).await;
+    assert_eq!(user.subscription_tier, 1);
+
+    // The same receipt can't be redeemed twice
+    let result = env.purchase_subscription(ALICE, 1, 30, 2, receipt, signed).await;
+    assert!(result.is_err());
+
+    // Stripe AC purchases need a receipt too, and it expires. They're already paid, so the
+    // SOL/USD feed isn't read (a halted feed can't block recording them)
+    let mut config: ConfigAccount = env.fetch(pda::config_pda().0).await;
+    config.sol_usd_price_feed = Pubkey::new_unique();
+    env.set_config(&config).await;
+    let result = env.purchase_ai_credits(ALICE, 500, 3, None, None).await;
+    assert_game_error(result, GameError::InvalidPaymentAttestation);
+    let receipt = env.payment_attestation("pi_ac_1", now + 600);
+    let signed = TestEnv::sign_payment(&coordinator, &receipt, ALICE, PaymentProduct::AiCredits, 500);
+    env.advance_clock(601).await;
+    let result = env.purchase_ai_credits(ALICE, 500, 3, Some(receipt.clone()), Some(signed.clone())).await;
+    assert_game_error(result, GameError::PaymentAttestationExpired);
+    let receipt = env.payment_attestation("pi_ac_2", now + 1200);
+    let signed = TestEnv::sign_payment(&coordinator, &receipt, ALICE, PaymentProduct::AiCredits, 500);
+    env.purchase_ai_credits(ALICE, 500, 3, Some(receipt), Some(signed)).await.unwrap();
+}
+
+#[tokio::test]
+async fn refunds_and_chargebacks_roll_back_purchases_once() {
+    let mut env = TestEnv::start().await;
+    let mut config: ConfigAccount = env.fetch(pda::config_pda().0).await;
+    config.authority = env.coordinator.pubkey();
+    env.set_config(&config).await;
+    let now = env.unix_timestamp().await;
+    let mut user = TestEnv::blank_user_account();
+    user.subscription_tier = 1;
+    user.subscription_expiry = now + 40 * 86400;
+    env.set_user_account(ALICE, user, UserAccount::MAX_SIZE).await;
+    env.set_payment_receipt("pi_sub_1", ALICE, PaymentProduct::ProSubscription, 30).await;
+    env.set_payment_receipt("pi_sub_2", ALICE, PaymentProduct::ProSubscription, 30).await;
+    env.set_payment_receipt("pi_ac_1", ALICE, PaymentProduct::AiCredits, 500).await;
+
+    env.reverse_payment(false, ALICE, PaymentProduct::ProSubscription, 20, "re_1", "pi_sub_1").await.unwrap();
+    let user: UserAccount = env.fetch(pda::user_account_pda(ALICE).0).await;
+    assert_eq!((user.subscription_expiry, user.chargeback_count), (now + 20 * 86400, 0));
+    let result = env.reverse_payment(false, ALICE, PaymentProduct::ProSubscription, 20, "re_1", "pi_sub_1").await;
+    assert!(result.is_err());
+
+    // A receipt only covers what it paid for, for its own user and product
+    let result = env.reverse_payment(false, ALICE, PaymentProduct::ProSubscription, 11, "re_2", "pi_sub_1").await;
+    assert_game_error(result, GameError::ReversalExceedsReceipt);
+    let result = env.reverse_payment(false, ALICE, PaymentProduct::AiCredits, 10, "re_2", "pi_sub_1").await;
+    assert_game_error(result, GameError::ReversalExceedsReceipt);
+    env.reverse_payment(false, ALICE, PaymentProduct::ProSubscription, 10, "re_2", "pi_sub_1").await.unwrap();
+    let receipt: PaymentReceipt = env.fetch(pda::payment_receipt_pda(&solana_sdk::hash::hash(b"pi_sub_1").to_bytes()).0).await;
+    assert_eq!(receipt.amount_reversed, 30);
+
+    // Charging back more days than are left lapses the subscription
+    env.reverse_payment(true, ALICE, PaymentProduct::ProSubscription, 30, "dp_1", "pi_sub_2").await.unwrap();
+    env.reverse_payment(true, ALICE, PaymentProduct::AiCredits, 500, "dp_2", "pi_ac_1").await.unwrap();
+    let user: UserAccount = env.fetch(pda::user_account_pda(ALICE).0).await;
+    assert_eq!((user.subscription_expiry, user.chargeback_count, user.ac_reversed), (0, 2, 500));
+}
+
+#[tokio::test]
+async fn season_lamport_rewards_only_go_to_the_linked_wallet() {
+    let mut env = TestEnv::start().await;
+    let mut config: ConfigAccount = env.fetch(pda::config_pda().0).await;
+    config.authority = env.coordinator.pubkey();
+    config.season_reward_top_n = 3;
+    config.season_reward_base_gp = 10;
+    config.season_reward_lamports = 1_000;
+    env.set_config(&config).await;
+    let (alice_wallet, bob_wallet, carol_wallet) = (env.player(0).pubkey(), env.player(1).pubkey(), env.player(2).pubkey());
+    let mut user = TestEnv::blank_user_account();
+    user.wallet = alice_wallet;
+    env.set_user_account(ALICE, user.clone(), UserAccount::MAX_SIZE).await;
+    env.set_user_account(BOB, TestEnv::blank_user_account(), UserAccount::MAX_SIZE).await;
+    user.wallet = carol_wallet;
+    env.set_user_account(CAROL, user, UserAccount::MAX_SIZE).await;
+    env.set_leaderboard_shard(0, &[(ALICE, 90), (BOB, 40), (CAROL, 20)]).await;
+    env.set_leaderboard_index(0, 3).await;
+    env.set_sanction(CAROL, SanctionKind::Ban, 0).await;
+    let ranks = [(ALICE, alice_wallet), (BOB, bob_wallet), (CAROL, carol_wallet)];
+
+    let result = env.distribute_season_rewards(0, &[(ALICE, bob_wallet), (BOB, bob_wallet), (CAROL, carol_wallet)]).await;
+    assert_game_error(result, GameError::Unauthorized);
+
+    // Bob never linked a wallet: GP only. Carol is banned: nothing, but her rank is passed
+    let alice_lamports = env.context.banks_client.get_balance(alice_wallet).await.unwrap();
+    let bob_lamports = env.context.banks_client.get_balance(bob_wallet).await.unwrap();
+    let carol_lamports = env.context.banks_client.get_balance(carol_wallet).await.unwrap();
+    env.distribute_season_rewards(0, &ranks).await.unwrap();
+    assert_eq!(env.context.banks_client.get_balance(alice_wallet).await.unwrap(), alice_lamports + 50 * 1_000);
+    assert_eq!(env.context.banks_client.get_balance(bob_wallet).await.unwrap(), bob_lamports);
+    assert_eq!(env.context.banks_client.get_balance(carol_wallet).await.unwrap(), carol_lamports);
+    let bob: UserAccount = env.fetch(pda::user_account_pda(BOB).0).await;
+    assert_eq!((bob.lifetime_gp_earned, bob.leaderboard_rank), (30 * 10, 2));
+    let carol: UserAccount = env.fetch(pda::user_account_pda(CAROL).0).await;
+    assert_eq!((carol.lifetime_gp_earned, carol.leaderboard_rank), (0, 0));
+    let index: LeaderboardIndex = env.fetch(pda::leaderboard_index_pda(0, 0).0).await;
+    assert_eq!(index.rewards_paid_count, 3);
+}
+
+#[tokio::test]
+async fn leaderboard_decay_survives_resubmission_and_lower_scores_move_down() {
+    let mut env = TestEnv::start().await;
+    let mut config: ConfigAccount = env.fetch(pda::config_pda().0).await;
+    config.leaderboard_decay_days = 7;
+    config.leaderboard_decay_bps = 5_000;
+    env.set_config(&config).await;
+    env.set_user_account(ALICE, TestEnv::blank_user_account(), UserAccount::MAX_SIZE).await;
+    let fillers: Vec<String> = (0..99).map(|i| format!("filler-{}", i)).collect();
+    let mut top: Vec<(&str, u64)> = vec![(ALICE, 1_000)];
+    top.extend(fillers.iter().enumerate().map(|(i, id)| (id.as_str(), 900 - i as u64)));
+    env.set_leaderboard(0, &[&top, &[(BOB, 700), (CAROL, 100)]]).await;
+
+    // Everyone was last active at time 0: both shards lose half
+    env.decay_leaderboard(0, 0, true).await.unwrap();
+    let shard: GameLeaderboard = env.fetch(pda::leaderboard_shard_pda(0, 0, 0).0).await;
+    assert_eq!((shard.entries[0].score, shard.entries[0].decay_loss), (500, 500));
+
+    // Resubmitted season totals keep the decay: an unchanged total stays at 500, +100 is 600
+    env.submit_leaderboard_entry(0, ALICE, 1_000, &[0]).await.unwrap();
+    let shard: GameLeaderboard = env.fetch(pda::leaderboard_shard_pda(0, 0, 0).0).await;
+    assert_eq!(shard.entries[0].score, 500);
+    env.submit_leaderboard_entry(0, ALICE, 1_100, &[0]).await.unwrap();
+    let shard: GameLeaderboard = env.fetch(pda::leaderboard_shard_pda(0, 0, 0).0).await;
+    assert_eq!(shard.entries[0].score, 600);
+
+    // A lower total moves down, pulling Bob up to keep shard 0 full; the walk can't stop early
+    let result = env.submit_leaderboard_entry(0, ALICE, 500, &[0]).await;
+    assert_game_error(result, GameError::InvalidLeaderboardShard);
+    env.submit_leaderboard_entry(0, ALICE, 500, &[0, 1]).await.unwrap();
+    let upper: GameLeaderboard = env.fetch(pda::leaderboard_shard_pda(0, 0, 0).0).await;
+    let lower: GameLeaderboard = env.fetch(pda::leaderboard_shard_pda(0, 0, 1).0).await;
+    assert_eq!(upper.entry_count, 100);
+    assert_eq!(upper.get_user_rank(&lower.entries[0].user_id), 0);
+    assert_eq!((upper.entries[99].score, upper.get_global_rank(&upper.entries[99].user_id)), (350, 100));
+    assert_eq!((lower.entry_count, lower.entries[0].score, lower.entries[1].score), (2, 50, 0));
+    assert_eq!(lower.get_global_rank(&lower.entries[1].user_id), 102);
+    let index: LeaderboardIndex = env.fetch(pda::leaderboard_index_pda(0, 0).0).await;
+    assert_eq!((index.shard_entry_counts[0], index.shard_min_scores[0], index.shard_max_scores[1]), (100, 350, 50));
+}
+
+#[tokio::test]
+async fn erasure_moves_stats_to_an_anonymized_tombstone() {
+    let mut env = TestEnv::started_match(2).await;
+    let mut config: ConfigAccount = env.fetch(pda::config_pda().0).await;
+    config.authority = env.coordinator.pubkey();
+    env.set_config(&config).await;
+    let mut user = TestEnv::blank_user_account();
+    user.games_played = 12;
+    user.games_won = 5;
+    user.guild[..4].copy_from_slice(b"aces");
+    user.wallet = env.player(0).pubkey();
+    user.wallet_linked_at = 1;
+    env.set_user_account(ALICE, user.clone(), UserAccount::MAX_SIZE).await;
+    let shard = env.set_leaderboard_shard(3, &[(BOB, 90), (ALICE, 40)]).await;
+    let anon_key = [0x3C; 32];
+    let now = env.unix_timestamp().await;
+    env.initialize_player_rating(ALICE).await.unwrap();
+    env.set_payment_receipt("pi_erased", ALICE, PaymentProduct::AiCredits, 100).await;
+    env.set_sanction(ALICE, SanctionKind::Suspension, now + 3600).await;
+    let receipt = pda::payment_receipt_pda(&solana_sdk::hash::hash(b"pi_erased").to_bytes()).0;
+    let match_account = pda::match_pda(MATCH_ID).0;
+
+    let result = env.erase_user_account(ALICE, anon_key, &[shard]).await;
+    assert_game_error(result, GameError::ErasureBlocked);
+    user.guild = [0; 32];
+    env.set_user_account(ALICE, user, UserAccount::MAX_SIZE).await;
+    // Erasure can't shed an active suspension, or rewrite a match still in play
+    let result = env.erase_user_account(ALICE, anon_key, &[shard]).await;
+    assert_game_error(result, GameError::UserSanctioned);
+    env.set_sanction(ALICE, SanctionKind::Suspension, now - 1).await;
+    let result = env.erase_user_account(ALICE, anon_key, &[shard, match_account, receipt]).await;
+    assert_game_error(result, GameError::InvalidErasureAccount);
+    let mut m = env.fetch_match().await;
+    m.ended_at = now;
+    env.set_match(MATCH_ID, m).await;
+    env.erase_user_account(ALICE, anon_key, &[shard, match_account, receipt]).await.unwrap();
+
+    // The UID-derived PDAs are closed, the other records renamed
+    for closed in [pda::user_account_pda(ALICE).0, pda::player_rating_pda(ALICE).0, pda::sanction_pda(ALICE).0] {
+        assert!(env.context.banks_client.get_account(closed).await.unwrap().is_none());
+    }
+    let erased_id = UserAccount::erased_user_id(&anon_key);
+    let m = env.fetch_match().await;
+    assert_eq!(m.find_player_index(&erased_id), Some(0));
+    let receipt: PaymentReceipt = env.fetch(receipt).await;
+    assert_eq!(receipt.user_id, erased_id);
+    let tombstone: UserAccount = env.fetch(pda::erased_user_pda(&anon_key).0).await;
+    assert!(tombstone.is_erased());
+    assert_eq!(tombstone.user_id, UserAccount::erased_user_id(&anon_key));
+    assert_eq!((tombstone.games_played, tombstone.games_won), (12, 5));
+    assert_eq!((tombstone.wallet, tombstone.wallet_linked_at), (Pubkey::default(), 0));
+
+    // Same rank, anonymized id
+    let leaderboard: GameLeaderboard = env.fetch(shard).await;
+    assert_eq!(leaderboard.find_user(&tombstone.user_id), Some(1));
+    assert_eq!(leaderboard.entries[1].score, 40);
+}
+
+#[tokio::test]
+async fn lost_wallet_is_replaced_with_an_oracle_signed_proof() {
+    let mut env = TestEnv::start().await;
+    env.set_user_account(ALICE, TestEnv::blank_user_account(), UserAccount::MAX_SIZE).await;
+    let coordinator = env.coordinator.insecure_clone();
+    let (lost, replacement) = (Keypair::new(), Keypair::new());
+    let expiry = env.unix_timestamp().await + 600;
+
+    // Only a registered coordinator can vouch for the login
+    let result = env.prove_account_ownership(ALICE, &lost, &Keypair::new(), 0, expiry).await;
+    assert_game_error(result, GameError::InvalidOwnershipProof);
+    let result = env.prove_account_ownership(ALICE, &lost, &coordinator, 0, expiry - 1200).await;
+    assert_game_error(result, GameError::OwnershipProofExpired);
+    env.prove_account_ownership(ALICE, &lost, &coordinator, 0, expiry).await.unwrap();
+
+    env.prove_account_ownership(ALICE, &replacement, &coordinator, 1, expiry).await.unwrap();
+    let user: UserAccount = env.fetch(pda::user_account_pda(ALICE).0).await;
+    assert_eq!((user.wallet, user.wallet_link_count), (replacement.pubkey(), 2));
+
+    // A proof signed for an earlier link can't take the account back
+    let result = env.prove_account_ownership(ALICE, &lost, &coordinator, 0, expiry).await;
+    assert_game_error(result, GameError::InvalidOwnershipProof);
+}
+
+#[tokio::test]
+async fn public_lobbies_are_listed_until_full_or_started() {
+    let mut env = TestEnv::start().await;
+    env.create_public_match().await.unwrap();
+    let m = env.fetch_match().await;
+    assert_eq!(m.visibility, Match::VISIBILITY_PUBLIC);
+    let index_key = pda::open_lobby_index_pda(0).0;
+    let index: OpenLobbyIndex = env.fetch(index_key).await;
+    assert_eq!(index.find_entry(&m.match_id), Some(0));
+
+    // Joins of a public match must keep the index in step
+    let (alice, bob) = (env.player(0), env.player(1));
+    env.lobby_index = None;
+    let result = env.join_match(&alice, ALICE).await;
+    assert_game_error(result, GameError::LobbyIndexMismatch);
+    env.lobby_index = Some(index_key);
+    env.join_match(&alice, ALICE).await.unwrap();
+    env.join_match(&bob, BOB).await.unwrap();
+    let index: OpenLobbyIndex = env.fetch(index_key).await;
+    assert_eq!(index.entry_count, 1);
+
+    env.commit_hand(&alice, ALICE, [0xA0; 32]).await.unwrap();
+    env.commit_hand(&bob, BOB, [0xA1; 32]).await.unwrap();
+    env.start_match().await.unwrap();
+    let index: OpenLobbyIndex = env.fetch(index_key).await;
+    assert_eq!(index.entry_count, 0);
+}
+
+#[tokio::test]
+async fn sanctioned_players_are_kept_out_of_the_queue_and_queued_matches() {
+    let mut env = TestEnv::start().await;
+    let (alice, bob, carol) = (env.player(0), env.player(1), env.player(2));
+    for user_id in [ALICE, BOB, CAROL] {
+        env.set_user_account(user_id, TestEnv::blank_user_account(), UserAccount::MAX_SIZE).await;
+    }
+    let now = env.unix_timestamp().await;
+
+    env.set_sanction(CAROL, SanctionKind::Ban, 0).await;
+    assert_game_error(env.enqueue_player(&carol, CAROL).await, GameError::UserSanctioned);
+
+    // Sanctioned after queueing: no seat until the sanction ends (or they're dequeued)
+    env.enqueue_player(&alice, ALICE).await.unwrap();
+    env.enqueue_player(&bob, BOB).await.unwrap();
+    env.set_sanction(BOB, SanctionKind::Suspension, now + 3600).await;
+    assert_game_error(env.form_match_from_queue(&[ALICE, BOB]).await, GameError::UserSanctioned);
+    assert_game_error(env.form_match_from_queue(&[ALICE, CAROL]).await, GameError::InvalidPayload);
+    env.set_sanction(BOB, SanctionKind::Suspension, now - 1).await;
+    env.form_match_from_queue(&[ALICE, BOB]).await.unwrap();
+
+    // Initialized like create_match: unlisted, single round, counted against the daily cap
+    let m = env.fetch_match().await;
+    assert_eq!(m.player_count, 2);
+    assert!(m.is_seat_wallet(1, &bob.pubkey()));
+    assert_eq!((m.visibility, m.rounds_to_play), (Match::VISIBILITY_UNLISTED, 1));
+    let stats: GlobalStats = env.fetch(pda::global_stats_pda().0).await;
+    assert_eq!(stats.matches_today_by_game[0], 1);
+}
+
+#[tokio::test]
+async fn counted_matches_get_ids_derived_from_the_creator_counter() {
+    let mut env = TestEnv::start().await;
+    let authority = env.coordinator.pubkey();
+
+    // The counter must be the creator's next one
+    let result = env.create_match_counted(1).await;
+    assert_game_error(result, GameError::MatchCounterMismatch);
+    env.create_match_counted(0).await.unwrap();
+    env.create_match_counted(1).await.unwrap();
+    let result = env.create_match_counted(1).await; // Its match PDA already exists
+    assert!(result.is_err());
+
+    let first_id = pda::counted_match_id(&authority, 0);
+    let m: Match = env.fetch(pda::match_pda(&first_id).0).await;
+    assert_eq!(m.match_id_str(), first_id);
+    assert_ne!(first_id, pda::counted_match_id(&authority, 1));
+    let creator: MatchCreator = env.fetch(pda::match_creator_pda(&authority).0).await;
+    assert_eq!(creator.match_counter, 2);
+
+    // The derived range can't be squatted through create_match
+    let next_id = pda::counted_match_id(&authority, 2);
+    let result = env.create_match_with_id(&next_id).await;
+    assert_game_error(result, GameError::ReservedMatchId);
+}
+
+#[tokio::test]
+async fn batch_anchors_chain_onto_the_previous_root() {
+    let mut env = TestEnv::start().await;
+    let authority = env.coordinator.pubkey();
+    let now = env.unix_timestamp().await;
+    let link = |previous: &str, sequence, period_start, period_end| BatchLink {
+        previous_batch_root: if previous.is_empty() { [0; 32] } else { batch_root(previous) },
+        sequence,
+        period_start,
+        period_end,
+    };
+
+    let result = env.anchor_batch("batch-1", link("batch-0", 0, now - 300, now - 200)).await;
+    assert_game_error(result, GameError::BatchChainMismatch);
+    env.anchor_batch("batch-1", link("", 0, now - 300, now - 200)).await.unwrap();
+
+    // Skipping a batch breaks the linkage, and sequence numbers can't jump
+    let result = env.anchor_batch("batch-3", link("batch-2", 1, now - 200, now - 100)).await;
+    assert_game_error(result, GameError::BatchChainMismatch);
+    let result = env.anchor_batch("batch-2", link("batch-1", 2, now - 200, now - 100)).await;
+    assert_game_error(result, GameError::BatchSequenceMismatch);
+
+    // Periods are disjoint, ordered and not in the future
+    let result = env.anchor_batch("batch-2", link("batch-1", 1, now - 250, now - 100)).await;
+    assert_game_error(result, GameError::InvalidBatchPeriod);
+    let result = env.anchor_batch("batch-2", link("batch-1", 1, now - 200, now + 100)).await;
+    assert_game_error(result, GameError::InvalidBatchPeriod);
+    env.anchor_batch("batch-2", link("batch-1", 1, now - 200, now - 100)).await.unwrap();
+
+    let anchor: BatchAnchor = env.fetch(pda::batch_anchor_pda("batch-2").0).await;
+    assert_eq!(anchor.previous_batch_root, batch_root("batch-1"));
+    assert_eq!((anchor.sequence, anchor.period_start, anchor.period_end), (1, now - 200, now - 100));
+    let head: BatchHead = env.fetch(pda::batch_head_pda(&authority).0).await;
+    assert_eq!((head.head_root, head.batch_count), (batch_root("batch-2"), 2));
+}
@@ -0,0 +1,30 @@
+// Drift check for the shared rule-engine test vectors (tests/vectors/rule_vectors.json).
+// The TypeScript rule engine tests read the same file, so a change to hand hashing, run
+// validation or transcript hashing has to regenerate it:
+//   cargo run --bin gen_vectors > tests/vectors/rule_vectors.json
+
+const CHECKED_IN: &str = include_str!("vectors/rule_vectors.json");
+
+#[test]
+fn checked_in_vectors_match_the_program() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_gen_vectors"))
+        .output()
+        .expect("gen_vectors runs");
+    assert!(output.status.success());
+    let generated = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        generated == CHECKED_IN.replace("\r\n", "\n"),
+        "tests/vectors/rule_vectors.json is stale, regenerate it with `cargo run --bin gen_vectors`"
+    );
+}
+
+#[test]
+fn generated_vectors_are_deterministic() {
+    let run = || {
+        std::process::Command::new(env!("CARGO_BIN_EXE_gen_vectors"))
+            .output()
+            .expect("gen_vectors runs")
+            .stdout
+    };
+    assert_eq!(run(), run());
+}
@@ -0,0 +1,894 @@
+// Property-based tests for the packed bitfields and fixed arrays in on-chain state.
+// Run with: cargo test --test state_invariants
+
+use anchor_lang::prelude::{Clock, Pubkey};
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::AnchorDeserialize;
+use proptest::prelude::*;
+use solana_games_program::merkle;
+use solana_games_program::oracle::OraclePrice;
+use solana_games_program::state::{
+    ConfigAccount, DeckCommitment, GameLeaderboard, GameRegistry, GlobalStats, Guild, InsertOutcome, LeaderboardEntry, Match, PayloadField,
+    PayloadSchema, PotLedger, RunRules, ScrabbleBoard, UserAccount, ValidatorReputation,
+};
+use solana_games_program::validation::{self, brag, rummy};
+
+// Zeroed accounts, as they look right after `init`
+fn blank_match() -> Match {
+    let data = vec![0u8; Match::MAX_SIZE - 8];
+    Match::deserialize(&mut &data[..]).unwrap()
+}
+
+fn blank_config() -> ConfigAccount {
+    let data = vec![0u8; ConfigAccount::MAX_SIZE - 8];
+    ConfigAccount::deserialize(&mut &data[..]).unwrap()
+}
+
+fn blank_leaderboard() -> GameLeaderboard {
+    let data = vec![0u8; GameLeaderboard::MAX_SIZE - 8];
+    GameLeaderboard::deserialize(&mut &data[..]).unwrap()
+}
+
+fn blank_guild() -> Guild {
+    let data = vec![0u8; Guild::MAX_SIZE - 8];
+    Guild::deserialize(&mut &data[..]).unwrap()
+}
+
+fn blank_user() -> UserAccount {
+    let data = vec![0u8; UserAccount::MAX_SIZE - 8];
+    UserAccount::deserialize(&mut &data[..]).unwrap()
+}
+
+fn blank_stats() -> GlobalStats {
+    let data = vec![0u8; GlobalStats::MAX_SIZE - 8];
+    GlobalStats::deserialize(&mut &data[..]).unwrap()
+}
+
+fn blank_reputation() -> ValidatorReputation {
+    let data = [0u8; ValidatorReputation::MAX_SIZE - 8];
+    ValidatorReputation::deserialize(&mut &data[..]).unwrap()
+}
+
+fn blank_deck_commitment() -> DeckCommitment {
+    let data = [0u8; DeckCommitment::MAX_SIZE - 8];
+    DeckCommitment::deserialize(&mut &data[..]).unwrap()
+}
+
+fn blank_registry() -> GameRegistry {
+    let data = vec![0u8; GameRegistry::MAX_SIZE - 8];
+    GameRegistry::deserialize(&mut &data[..]).unwrap()
+}
+
+fn blank_scrabble_board() -> ScrabbleBoard {
+    let data = vec![0u8; ScrabbleBoard::MAX_SIZE - 8];
+    ScrabbleBoard::deserialize(&mut &data[..]).unwrap()
+}
+
+// Every tile is reachable from the center through orthogonal neighbours
+fn scrabble_tiles_connected(board: &ScrabbleBoard) -> bool {
+    let size = ScrabbleBoard::SIZE;
+    let occupied = board.tiles.iter().filter(|&&t| t != 0).count();
+    if occupied == 0 {
+        return true;
+    }
+    let mut seen = [false; 225];
+    let mut stack = vec![(ScrabbleBoard::CENTER, ScrabbleBoard::CENTER)];
+    let mut reached = 0;
+    while let Some((r, c)) = stack.pop() {
+        if board.cell(r, c) == 0 || seen[r * size + c] {
+            continue;
+        }
+        seen[r * size + c] = true;
+        reached += 1;
+        if r > 0 { stack.push((r - 1, c)); }
+        if r + 1 < size { stack.push((r + 1, c)); }
+        if c > 0 { stack.push((r, c - 1)); }
+        if c + 1 < size { stack.push((r, c + 1)); }
+    }
+    reached == occupied
+}
+
+fn clock_at(unix_timestamp: i64) -> Clock {
+    Clock { unix_timestamp, ..Clock::default() }
+}
+
+fn user_id(n: u16) -> [u8; 64] {
+    let mut id = [0u8; 64];
+    let s = format!("user-{}", n);
+    id[..s.len()].copy_from_slice(s.as_bytes());
+    id
+}
+
+fn entry(n: u16, score: u64) -> LeaderboardEntry {
+    LeaderboardEntry {
+        user_id: user_id(n),
+        score,
+        wins: 0,
+        games_played: 0,
+        timestamp: 0,
+        decay_loss: 0,
+        decayed_day: 0,
+    }
+}
+
+fn get_flag(m: &Match, flag: u8) -> bool {
+    match flag {
+        0 => m.floor_card_revealed(),
+        1 => m.all_players_joined(),
+        2 => m.round_scored(),
+        _ => m.ratings_recorded(),
+    }
+}
+
+fn set_flag(m: &mut Match, flag: u8, value: bool) {
+    match flag {
+        0 => m.set_floor_card_revealed(value),
+        1 => m.set_all_players_joined(value),
+        2 => m.set_round_scored(value),
+        _ => m.set_ratings_recorded(value),
+    }
+}
+
+fn assert_leaderboard_invariants(lb: &GameLeaderboard) {
+    let count = lb.entry_count as usize;
+    assert!(count <= GameLeaderboard::MAX_ENTRIES);
+
+    let entries = &lb.entries[..count];
+    for pair in entries.windows(2) {
+        assert!(pair[0].outranks(&pair[1]), "entries not in rank order");
+    }
+    for (i, e) in entries.iter().enumerate() {
+        assert!(
+            entries[i + 1..].iter().all(|other| other.user_id != e.user_id),
+            "duplicate user in leaderboard"
+        );
+        assert_eq!(lb.get_user_rank(&e.user_id) as usize, i + 1);
+    }
+    assert_eq!(lb.max_score(), entries.first().map(|e| e.score).unwrap_or(0));
+    assert_eq!(lb.min_score(), entries.last().map(|e| e.score).unwrap_or(0));
+}
+
+proptest! {
+    #[test]
+    fn declared_suit_round_trips(ops in prop::collection::vec((0usize..10, 0u8..4), 0..40)) {
+        let mut m = blank_match();
+        let mut model: [Option<u8>; 10] = [None; 10];
+
+        for (player, suit) in ops {
+            m.set_declared_suit(player, suit);
+            model[player] = Some(suit);
+
+            for (i, expected) in model.iter().enumerate() {
+                prop_assert_eq!(m.get_declared_suit(i), *expected);
+                prop_assert_eq!(m.has_declared_suit(i), expected.is_some());
+            }
+        }
+
+        for suit in 0u8..4 {
+            prop_assert_eq!(m.is_suit_locked(suit), model.contains(&Some(suit)));
+        }
+    }
+
+    #[test]
+    fn declared_suit_ignores_out_of_range(
+        packed in prop::array::uniform5(any::<u8>()),
+        player in 0usize..64,
+        suit in any::<u8>(),
+    ) {
+        prop_assume!(player >= 10 || suit > 3);
+        let mut m = blank_match();
+        m.declared_suits = packed;
+
+        m.set_declared_suit(player, suit);
+
+        prop_assert_eq!(m.declared_suits, packed);
+        prop_assert_eq!(m.get_declared_suit(player.max(10)), None);
+    }
+
+    #[test]
+    fn flags_are_independent(initial in any::<u8>(), ops in prop::collection::vec((0u8..4, any::<bool>()), 0..32)) {
+        let mut m = blank_match();
+        m.flags = initial;
+
+        for (flag, value) in ops {
+            let before = m.flags;
+            set_flag(&mut m, flag, value);
+
+            let bit = 1u8 << flag;
+            prop_assert_eq!(m.flags & !bit, before & !bit);
+            prop_assert_eq!(get_flag(&m, flag), value);
+        }
+    }
+
+    #[test]
+    fn nonce_tracking_is_per_player(ops in prop::collection::vec((0usize..16, any::<u64>()), 0..40)) {
+        let mut m = blank_match();
+        let mut model = [0u64; 10];
+
+        for (player, nonce) in ops {
+            m.set_last_nonce(player, nonce);
+            if player < 10 {
+                model[player] = nonce;
+            }
+
+            prop_assert_eq!(m.last_nonce, model);
+            prop_assert_eq!(m.get_last_nonce(player), if player < 10 { model[player] } else { 0 });
+        }
+    }
+
+    #[test]
+    fn nonce_window_accepts_each_nonce_once(nonces in prop::collection::vec(0u64..300, 0..120)) {
+        let mut m = blank_match();
+        let mut used = std::collections::HashSet::new();
+        let mut highest = 0u64;
+
+        for nonce in nonces {
+            let fresh = nonce > 0 && !used.contains(&nonce)
+                && (nonce > highest || highest - nonce <= Match::NONCE_WINDOW);
+            prop_assert_eq!(m.is_nonce_fresh(0, nonce), fresh);
+            if fresh {
+                m.record_nonce(0, nonce);
+                used.insert(nonce);
+                highest = highest.max(nonce);
+            }
+            prop_assert_eq!(m.get_last_nonce(0), highest);
+            prop_assert_eq!(m.get_last_nonce(1), 0);
+        }
+    }
+
+    #[test]
+    fn action_cooldowns_are_per_player_and_action(
+        cooldown in 0u64..8,
+        ops in prop::collection::vec((0usize..10, 0u8..10, 0u64..4), 0..40),
+    ) {
+        let mut m = blank_match();
+        let mut model = std::collections::HashMap::new();
+        let mut slot = 1u64;
+
+        for (player, action, step) in ops {
+            slot += step;
+            let accepted = m.record_action_cooldown(player, action, slot, cooldown);
+            let limited = Match::COOLDOWN_ACTIONS.contains(&action);
+            let expected = match model.get(&(player, action)) {
+                Some(&last) if limited && cooldown > 0 => slot - last >= cooldown,
+                _ => true,
+            };
+            prop_assert_eq!(accepted, expected);
+            if accepted && limited {
+                model.insert((player, action), slot);
+            }
+        }
+    }
+
+    #[test]
+    fn run_rules_wrap_only_when_allowed(
+        wraparound in any::<bool>(),
+        aces_low in any::<bool>(),
+        run_length in 3u8..=13,
+        suit in 0u8..4,
+        start in 0u8..13,
+    ) {
+        let mut bits = run_length << RunRules::RUN_LENGTH_SHIFT;
+        if wraparound { bits |= RunRules::WRAPAROUND; }
+        if aces_low { bits |= RunRules::ACES_LOW; }
+        prop_assert!(RunRules::is_valid_bits(bits));
+        let rules = RunRules::from_bits(bits);
+
+        // run_length ranks in cyclic order from `start`; rank 1 is a low Ace (card value 14)
+        let lowest = if aces_low { 1 } else { 2 };
+        let ranks: Vec<u8> = (0..run_length).map(|i| lowest + (start + i) % 13).collect();
+        let payload: Vec<u8> = ranks.iter()
+            .flat_map(|&rank| [suit, if rank == 1 { 14 } else { rank }])
+            .collect();
+        // A whole suit is a straight run whichever rank it starts from
+        let wraps = start + run_length > 13 && run_length < 13;
+
+        let parsed = validation::parse_run(&payload, rules);
+        prop_assert_eq!(parsed.is_ok(), !wraps || wraparound);
+        if let Ok(cards) = parsed {
+            let top = *ranks.iter().max().unwrap();
+            prop_assert_eq!(validation::run_suit_and_top(&cards, rules), (suit, top));
+        }
+
+        // The reveal check reads the game's whole run, not a fixed three cards
+        let mut m = blank_match();
+        m.set_committed_hand_hash(0, validation::hand_hash(&[(suit, 2)]));
+        prop_assert!(validation::validate_card_hash(&m, 0, &payload, rules).is_ok());
+        prop_assert!(validation::validate_card_hash(&m, 0, &payload[..payload.len() - 2], rules).is_err());
+
+        // Duplicates and off-suit cards never make a run
+        let mut broken = payload.clone();
+        broken[2] = broken[0] ^ 1;
+        prop_assert!(validation::parse_run(&broken, rules).is_err());
+        broken[2..4].copy_from_slice(&payload[0..2]);
+        prop_assert!(validation::parse_run(&broken, rules).is_err());
+    }
+
+    #[test]
+    fn rummy_jokers_stand_in_for_run_cards(
+        aces_low in any::<bool>(),
+        suit in 0u8..4,
+        len in 3u8..=13,
+        start in 0u8..13,
+        joker_slots in prop::collection::vec(0u8..13, 0..3),
+    ) {
+        let rules = RunRules::from_bits(if aces_low { RunRules::ACES_LOW } else { 0 });
+        let lowest = if aces_low { 1 } else { 2 };
+        let ranks: Vec<u8> = (0..len).map(|i| lowest + start.min(13 - len) + i).collect();
+        let payload: Vec<u8> = ranks.iter()
+            .flat_map(|&rank| [suit, if rank == 1 { 14 } else { rank }])
+            .collect();
+        let meld = rummy::parse_meld(&payload, rules).unwrap();
+        let points: u16 = ranks.iter().map(|&rank| rummy::rank_points(rank)).sum();
+        prop_assert_eq!((meld.kind, meld.card_count, meld.points), (rummy::MeldKind::Run, len, points));
+
+        // Jokers in place of any cards keep it a run of the same length, while naturals outnumber them
+        let mut slots: Vec<usize> = joker_slots.iter().map(|&slot| (slot % len) as usize).collect();
+        slots.sort();
+        slots.dedup();
+        let mut with_jokers = payload.clone();
+        for (joker, &slot) in slots.iter().enumerate() {
+            with_jokers[slot * 2..slot * 2 + 2].copy_from_slice(&[rummy::JOKER_SUIT, joker as u8]);
+        }
+        match rummy::parse_meld(&with_jokers, rules) {
+            Ok(meld) => {
+                prop_assert!(len as usize > 2 * slots.len());
+                prop_assert_eq!((meld.kind, meld.card_count), (rummy::MeldKind::Run, len));
+                prop_assert_eq!(meld.card_bits.count_ones(), len as u32);
+            }
+            Err(_) => prop_assert!(len as usize <= 2 * slots.len()),
+        }
+    }
+
+    #[test]
+    fn brag_hand_rank_ignores_card_order(cards in prop::sample::subsequence((0u8..52).collect::<Vec<_>>(), 3), rotate in 0usize..3) {
+        let hand: Vec<(u8, u8)> = cards.iter().map(|&card| (card / 13, card % 13 + 2)).collect();
+        let mut shuffled = hand.clone();
+        shuffled.rotate_left(rotate);
+        shuffled.swap(0, 1);
+        let (hand, shuffled) = ([hand[0], hand[1], hand[2]], [shuffled[0], shuffled[1], shuffled[2]]);
+        prop_assert_eq!(brag::hand_rank(&hand), brag::hand_rank(&shuffled));
+        prop_assert_eq!(validation::hand_hash(&hand), validation::hand_hash(&shuffled));
+
+        // Category ladder: prial of 3s > running flush A-2-3 > run > flush > pair > high card
+        let ladder = [
+            [(0, 3), (1, 3), (2, 3)],
+            [(0, 14), (0, 2), (0, 3)],
+            [(0, 14), (1, 13), (2, 12)],
+            [(0, 2), (0, 5), (0, 9)],
+            [(0, 14), (1, 14), (2, 13)],
+            [(0, 14), (1, 12), (2, 10)],
+        ];
+        prop_assert!(ladder.windows(2).all(|pair| brag::hand_rank(&pair[0]) > brag::hand_rank(&pair[1])));
+        prop_assert!(brag::hand_rank(&hand) <= brag::hand_rank(&ladder[0]));
+    }
+
+    #[test]
+    fn pot_ledger_settles_every_chip(
+        seats in prop::collection::vec((0u64..1_000, any::<bool>(), 0u32..4), 2..=10),
+        rake_bps in 0u16..=PotLedger::MAX_RAKE_BPS,
+    ) {
+        let mut pot = PotLedger::default();
+        for (seat, &(amount, folded, rank)) in seats.iter().enumerate() {
+            pot.contribute(seat, amount);
+            pot.reveal(seat, rank);
+            if folded {
+                pot.fold(seat);
+            }
+        }
+        let player_count = seats.len() as u8;
+        let total = pot.total();
+        let anyone_left = pot.active_seats(player_count).count() > 0;
+        let side_pots = pot.side_pots(player_count);
+        prop_assert_eq!(side_pots.iter().map(|p| p.amount).sum::<u64>(), if anyone_left { total } else { 0 });
+        // Layers go up in level, so each one's eligible seats are a subset of the one below
+        prop_assert!(side_pots.windows(2).all(|pair| pair[1].eligible & !pair[0].eligible == 0));
+
+        // Every chip is paid out or raked, even when the whole table folds
+        pot.settle(player_count, rake_bps);
+        let paid: u64 = pot.payouts.iter().sum();
+        prop_assert_eq!(paid + pot.rake, total);
+        prop_assert!(pot.rake <= total * rake_bps as u64 / 10_000);
+        for seat in 0..seats.len() {
+            if !anyone_left {
+                prop_assert_eq!(pot.payouts[seat], pot.contributions[seat]);
+            } else if pot.is_folded(seat) {
+                prop_assert_eq!(pot.payouts[seat], 0);
+            }
+        }
+
+        // Settling is idempotent
+        let settled = pot;
+        pot.settle(player_count, rake_bps);
+        prop_assert_eq!(pot, settled);
+    }
+
+    #[test]
+    fn hand_hash_slices_do_not_overlap(ops in prop::collection::vec((0usize..12, any::<[u8; 32]>()), 0..24)) {
+        let mut m = blank_match();
+        let mut model = [[0u8; 32]; 10];
+
+        for (player, hash) in ops {
+            m.set_committed_hand_hash(player, hash);
+            if player < 10 {
+                model[player] = hash;
+            }
+
+            for (i, expected) in model.iter().enumerate() {
+                prop_assert_eq!(&m.committed_hand_hashes[i * 32..(i + 1) * 32], &expected[..]);
+                let committed = expected.iter().any(|&b| b != 0);
+                prop_assert_eq!(m.get_committed_hand_hash(i), committed.then_some(*expected));
+            }
+            prop_assert_eq!(m.get_committed_hand_hash(player.max(10)), None);
+        }
+    }
+
+    #[test]
+    fn discard_pile_tracks_chain_and_recent_cards(cards in prop::collection::vec(any::<[u8; 32]>(), 0..40)) {
+        let mut m = blank_match();
+        let mut root = [0u8; 32];
+
+        for (i, card) in cards.iter().enumerate() {
+            m.record_discard(*card);
+            root = anchor_lang::solana_program::hash::hashv(&[&root, card]).to_bytes();
+
+            prop_assert_eq!(m.discard_pile_root, root);
+            prop_assert_eq!(m.discard_count as usize, i + 1);
+            prop_assert_eq!(m.last_discard_hash, *card);
+            let recent_start = (i + 1).saturating_sub(Match::RECENT_DISCARDS);
+            for recent in &cards[recent_start..=i] {
+                prop_assert!(m.was_recently_discarded(recent));
+            }
+        }
+
+        m.reset_round_state();
+        prop_assert_eq!(m.discard_pile_root, [0u8; 32]);
+        prop_assert!(cards.iter().all(|card| !m.was_recently_discarded(card)));
+    }
+
+    #[test]
+    fn anomaly_flags_are_sticky_and_match_counters(
+        ops in prop::collection::vec((0usize..4, 0u8..2, 0u64..3, 0u8..3, 1u64..4), 0..60),
+    ) {
+        let mut m = blank_match();
+        m.last_decline_player = 0xFF;
+        m.decline_pair = 0xFF;
+        m.last_move_signer_player = 0xFF;
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut slot = 1u64;
+
+        for (seat, signer, slot_step, action_type, nonce_step) in ops {
+            let before = m.anomaly_flags;
+            slot += slot_step;
+            let nonce = m.get_last_nonce(seat) + nonce_step;
+            m.record_move_anomalies(seat, &signers[signer as usize], slot, action_type, nonce);
+            m.set_last_nonce(seat, nonce);
+
+            prop_assert_eq!(m.anomaly_flags & before, before);
+            prop_assert_eq!(m.has_anomaly(Match::ANOMALY_SHARED_PAYER), m.shared_payer_count > 0);
+            prop_assert_eq!(
+                m.has_anomaly(Match::ANOMALY_REPEATED_DECLINES),
+                m.repeated_decline_count >= Match::ANOMALY_THRESHOLD
+            );
+            prop_assert_eq!(
+                m.has_anomaly(Match::ANOMALY_NONCE_GAPS),
+                m.nonce_gap_count >= Match::ANOMALY_THRESHOLD
+            );
+        }
+    }
+
+    #[test]
+    fn deck_accounting_flags_only_a_desync(
+        hands in prop::collection::vec(1u8..8, 2..5),
+        ops in prop::collection::vec((0usize..4, 0u8..3), 0..80),
+    ) {
+        let mut m = blank_match();
+        m.player_count = hands.len() as u8;
+        for (seat, &size) in hands.iter().enumerate() {
+            m.set_hand_size(seat, size);
+        }
+        m.cards_dealt_total = m.dealt_card_count();
+        m.deck_size = 52;
+        let mut desynced = false;
+
+        // Same hand/discard transitions as submit_move: 0 = pick up, 1 = play, 2 = decline
+        for (seat, action) in ops {
+            let seat = seat % hands.len();
+            let size = m.get_hand_size(seat);
+            match action {
+                0 if m.remaining_deck() > 0 => {
+                    m.set_hand_size(seat, size.saturating_add(1));
+                    m.record_cards_dealt(1);
+                }
+                1 => {
+                    // Playing from an empty hand is the desync the check exists for
+                    desynced |= size == 0;
+                    m.set_hand_size(seat, size.saturating_sub(1));
+                    m.record_discard([seat as u8 + 1; 32]);
+                }
+                _ => {}
+            }
+            m.check_deck_accounting();
+            prop_assert_eq!(m.deck_accounting_consistent(), !desynced);
+            prop_assert_eq!(m.has_anomaly(Match::ANOMALY_DECK_DESYNC), desynced);
+        }
+    }
+
+    #[test]
+    fn dispute_deposits_escalate_within_a_season(
+        base_gp in 1u32..1_000,
+        ops in prop::collection::vec((0u64..3, any::<bool>()), 0..40),
+    ) {
+        let mut user = blank_user();
+        let mut filed = [0u16; 3];
+        let mut active = 0u8;
+
+        for (season, file) in ops {
+            let deposit = user.dispute_deposit_for(base_gp, season);
+            let doublings = filed[season as usize].min(UserAccount::MAX_DISPUTE_DEPOSIT_DOUBLINGS);
+            prop_assert_eq!(deposit, base_gp << doublings);
+            if file {
+                // Only the latest season is counted: filing in another season starts over
+                if season != user.dispute_season_id {
+                    filed = [0; 3];
+                }
+                user.record_dispute_filed(season);
+                filed[season as usize] += 1;
+                active += 1;
+            } else {
+                user.record_dispute_resolved();
+                active = active.saturating_sub(1);
+            }
+            prop_assert_eq!(user.active_disputes, active);
+        }
+    }
+
+    #[test]
+    fn free_plays_are_capped_per_day(
+        daily_cap in 0u16..5,
+        subscribed_until_day in 0i64..6,
+        plays in prop::collection::vec(0i64..8, 0..40),
+    ) {
+        let mut user = blank_user();
+        user.subscription_tier = 1;
+        user.subscription_expiry = subscribed_until_day * 86400;
+
+        let mut days: Vec<i64> = plays;
+        days.sort();
+        let mut used_per_day = std::collections::HashMap::new();
+        for day in days {
+            let clock = clock_at(day * 86400 + 3600);
+            let used = used_per_day.entry(day).or_insert(0u16);
+            let expected = day < subscribed_until_day && *used < daily_cap;
+
+            prop_assert_eq!(user.use_free_play(daily_cap, &clock), expected);
+            if expected {
+                *used += 1;
+            }
+            prop_assert_eq!(user.free_plays_used_today(&clock), *used);
+        }
+    }
+
+    #[test]
+    fn daily_match_caps_are_per_game_and_per_day(
+        caps in prop::array::uniform3(0u32..4),
+        creations in prop::collection::vec((0u8..3, 0i64..5), 0..60),
+    ) {
+        let mut stats = blank_stats();
+        let mut creations = creations;
+        creations.sort_by_key(|&(_, day)| day);
+        let mut per_day = std::collections::HashMap::new();
+        let mut created = 0u64;
+        for (game, day) in creations {
+            let now = day * 86400 + 60;
+            let count = per_day.entry((game, day)).or_insert(0u32);
+            let cap = caps[game as usize];
+            let allowed = cap == 0 || *count < cap;
+
+            prop_assert_eq!(stats.record_match_created(game, cap, now).is_ok(), allowed);
+            if allowed {
+                *count += 1;
+                created += 1;
+            }
+            prop_assert_eq!(stats.matches_today(game, now), *count);
+            prop_assert_eq!(stats.total_matches_created, created);
+        }
+    }
+
+    #[test]
+    fn validator_reputation_stays_in_basis_point_range(
+        start in 0u16..=10_000,
+        ops in prop::collection::vec((any::<bool>(), 0u16..=10_000), 0..60),
+    ) {
+        let mut rep = blank_reputation();
+        rep.reputation_bps = start;
+
+        for (was_correct, penalty_bps) in ops {
+            let before = rep.reputation_bps;
+            rep.apply_reputation_penalty(penalty_bps);
+            prop_assert!(rep.reputation_bps <= before);
+
+            rep.update_reputation(was_correct);
+            let accuracy = rep.calculate_accuracy_bps();
+            prop_assert!(accuracy <= ValidatorReputation::MAX_REPUTATION_BPS);
+            prop_assert!(rep.reputation_bps <= ValidatorReputation::MAX_REPUTATION_BPS);
+            prop_assert_eq!(
+                accuracy as u64,
+                rep.correct_resolutions as u64 * 10_000 / rep.total_resolutions as u64
+            );
+        }
+        prop_assert_eq!(ValidatorReputation::reputation_bps_from_f64(0.73456), 7346);
+        prop_assert_eq!(ValidatorReputation::reputation_bps_from_f64(1.5), 10_000);
+    }
+
+    #[test]
+    fn scrabble_placements_keep_the_board_connected(
+        plays in prop::collection::vec(
+            (0u8..15, 0u8..15, any::<bool>(), prop::collection::vec(1u8..=26, 1..6)),
+            0..80,
+        ),
+    ) {
+        let mut board = blank_scrabble_board();
+        for (row, col, horizontal, tiles) in plays {
+            board.racks[0] = [0; 7];
+            board.racks[0][..tiles.len()].copy_from_slice(&tiles);
+            let (before, score_before) = (board.tiles, board.scores[0]);
+
+            match board.place_word(0, row, col, horizontal, &tiles) {
+                Ok(play) => {
+                    let placed = board.tiles.iter().zip(&before).filter(|(now, was)| now != was).count();
+                    prop_assert_eq!(placed, tiles.len());
+                    prop_assert!(board.racks[0].iter().all(|&t| t == 0));
+                    prop_assert!(!play.words.is_empty());
+                    prop_assert!(play.words.iter().all(|w| w.len() >= 2 && w.iter().all(u8::is_ascii_uppercase)));
+                    prop_assert_eq!(board.scores[0], score_before + play.score);
+                    prop_assert!(board.cell(ScrabbleBoard::CENTER, ScrabbleBoard::CENTER) != 0);
+                }
+                Err(_) => prop_assert_eq!(board.tiles, before),
+            }
+            prop_assert!(scrabble_tiles_connected(&board));
+        }
+    }
+
+    #[test]
+    fn guild_score_never_drops_within_a_season(ops in prop::collection::vec((0u8..3, 0u16..24, 0u64..1_000), 0..80)) {
+        let mut guild = blank_guild();
+        let mut scores = [0u64; 24];
+
+        for (op, user, delta) in ops {
+            let before = guild.guild_score;
+            match op {
+                0 if !guild.is_full() && guild.find_member(&user_id(user)).is_none() => {
+                    guild.add_member(user_id(user), scores[user as usize]);
+                }
+                1 => {
+                    guild.remove_member(&user_id(user));
+                }
+                _ => {
+                    scores[user as usize] += delta;
+                    if let Some(i) = guild.find_member(&user_id(user)) {
+                        guild.record_member_score(i, scores[user as usize]);
+                    }
+                }
+            }
+
+            prop_assert!(guild.guild_score >= before);
+            prop_assert!(guild.member_count as usize <= Guild::MAX_MEMBERS);
+            let members = &guild.member_scores[..guild.member_count as usize];
+            prop_assert!(members.iter().sum::<u64>() + guild.banked_score >= guild.guild_score);
+        }
+    }
+
+    #[test]
+    fn leaderboard_insert_keeps_invariants(ops in prop::collection::vec((0u16..160, 0u64..1_000), 0..300)) {
+        let mut lb = blank_leaderboard();
+
+        for (user, score) in ops {
+            let was_full = lb.entry_count as usize == GameLeaderboard::MAX_ENTRIES;
+            let was_present = lb.find_user(&user_id(user)).is_some();
+            let min_before = lb.min_score();
+
+            match lb.insert_entry(entry(user, score)) {
+                InsertOutcome::NotQualified => {
+                    prop_assert!(was_full && !was_present && score <= min_before);
+                    prop_assert!(lb.find_user(&user_id(user)).is_none());
+                }
+                InsertOutcome::Inserted => {
+                    prop_assert_eq!(lb.entries[lb.find_user(&user_id(user)).unwrap()].score, score);
+                }
+                InsertOutcome::Demoted(demoted) => {
+                    prop_assert!(was_full && !was_present);
+                    prop_assert!(demoted.user_id != user_id(user));
+                    prop_assert!(lb.find_user(&demoted.user_id).is_none());
+                    prop_assert!(demoted.score <= lb.min_score());
+                    prop_assert_eq!(lb.entries[lb.find_user(&user_id(user)).unwrap()].score, score);
+                }
+            }
+
+            assert_leaderboard_invariants(&lb);
+        }
+    }
+
+    #[test]
+    fn leaderboard_remove_keeps_invariants(
+        scores in prop::collection::vec(0u64..1_000, 1..120),
+        removals in prop::collection::vec(0u16..140, 0..60),
+    ) {
+        let mut lb = blank_leaderboard();
+        for (user, score) in scores.iter().enumerate() {
+            lb.insert_entry(entry(user as u16, *score));
+        }
+
+        for user in removals {
+            let was_present = lb.find_user(&user_id(user)).is_some();
+            let count_before = lb.entry_count;
+
+            prop_assert_eq!(lb.remove_entry(&user_id(user)), was_present);
+            prop_assert_eq!(lb.entry_count, count_before - was_present as u8);
+            prop_assert_eq!(lb.get_user_rank(&user_id(user)), 0);
+
+            assert_leaderboard_invariants(&lb);
+        }
+    }
+
+    #[test]
+    fn leaderboard_decay_is_once_per_period_and_keeps_order(
+        scores in prop::collection::vec((0u64..10_000, 0i64..20), 2..160),
+        steps in prop::collection::vec(0i64..15, 1..8),
+        decay_bps in 1u16..10_000,
+    ) {
+        let day = 86_400;
+        let stale_after = 7 * day;
+        let mut upper = blank_leaderboard();
+        let mut lower = blank_leaderboard();
+        for (user, (score, last_active_day)) in scores.iter().enumerate() {
+            let mut e = entry(user as u16, *score);
+            e.timestamp = last_active_day * day;
+            let shard = if user % 2 == 0 { &mut upper } else { &mut lower };
+            shard.insert_entry(e);
+        }
+        let total = upper.entry_count + lower.entry_count;
+
+        let mut now = 20 * day;
+        for step in steps {
+            now += step * day;
+            let before: Vec<LeaderboardEntry> = upper.entries[..upper.entry_count as usize]
+                .iter()
+                .chain(&lower.entries[..lower.entry_count as usize])
+                .cloned()
+                .collect();
+
+            upper.decay_stale_entries(now, stale_after, decay_bps);
+            lower.decay_stale_entries(now, stale_after, decay_bps);
+            upper.rebalance_with(&mut lower);
+
+            // Same users, nobody gained score, and a second crank in the same period is a no-op
+            prop_assert_eq!(upper.entry_count + lower.entry_count, total);
+            for old in &before {
+                let shard = if upper.find_user(&old.user_id).is_some() { &upper } else { &lower };
+                let new = &shard.entries[shard.find_user(&old.user_id).unwrap()];
+                prop_assert!(new.score <= old.score);
+                prop_assert_eq!(new.score + new.decay_loss, old.score + old.decay_loss);
+                if now - old.timestamp < stale_after {
+                    prop_assert_eq!(new.score, old.score);
+                }
+            }
+            prop_assert_eq!(upper.decay_stale_entries(now, stale_after, decay_bps), 0);
+            prop_assert_eq!(lower.decay_stale_entries(now, stale_after, decay_bps), 0);
+
+            assert_leaderboard_invariants(&upper);
+            assert_leaderboard_invariants(&lower);
+            prop_assert!(upper.min_score() >= lower.max_score());
+            if upper.entry_count > 0 && lower.entry_count > 0 {
+                prop_assert!(upper.entries[upper.entry_count as usize - 1].outranks(&lower.entries[0]));
+            }
+        }
+    }
+
+    #[test]
+    fn leaderboard_ties_order_the_same_whatever_the_insertion_order(
+        entries in prop::collection::vec((0u64..4, 0i64..4, 0u32..3), 1..100),
+    ) {
+        let entries: Vec<LeaderboardEntry> = entries
+            .iter()
+            .enumerate()
+            .map(|(user, &(score, timestamp, wins))| LeaderboardEntry {
+                timestamp,
+                wins,
+                ..entry(user as u16, score)
+            })
+            .collect();
+
+        let mut forward = blank_leaderboard();
+        let mut backward = blank_leaderboard();
+        for e in &entries {
+            forward.insert_entry(e.clone());
+        }
+        for e in entries.iter().rev() {
+            backward.insert_entry(e.clone());
+        }
+
+        let mut expected = entries.clone();
+        expected.sort_by(|a, b| a.rank_cmp(b));
+        let count = entries.len();
+        prop_assert!(forward.entries[..count] == expected[..]);
+        prop_assert!(backward.entries[..count] == expected[..]);
+        for pair in expected.windows(2) {
+            // Score, then earlier timestamp, then more wins, then user_id
+            let (a, b) = (&pair[0], &pair[1]);
+            prop_assert!(
+                a.score > b.score
+                    || (a.score == b.score && a.timestamp < b.timestamp)
+                    || (a.score == b.score && a.timestamp == b.timestamp && a.wins > b.wins)
+                    || (a.score == b.score && a.timestamp == b.timestamp && a.wins == b.wins && a.user_id < b.user_id)
+            );
+        }
+    }
+
+    #[test]
+    fn merkle_proofs_match_the_on_chain_verifiers(
+        cards in prop::collection::vec(any::<[u8; 32]>(), 1..53),
+        pick in any::<prop::sample::Index>(),
+        tamper in any::<[u8; 32]>(),
+    ) {
+        let mut deck = blank_deck_commitment();
+        let leaves: Vec<[u8; 32]> = cards
+            .iter()
+            .enumerate()
+            .map(|(i, card)| DeckCommitment::card_leaf(i as u8, card))
+            .collect();
+        deck.deck_root = merkle::root(&leaves);
+        deck.deck_size = cards.len() as u8;
+
+        for (i, card) in cards.iter().enumerate() {
+            let proof = merkle::proof(&leaves, i).unwrap();
+            prop_assert!(merkle::verify(leaves[i], i, &proof, &deck.deck_root));
+            prop_assert!(deck.verify_card(i as u8, card, &proof));
+        }
+        let i = pick.index(cards.len());
+        let proof = merkle::proof(&leaves, i).unwrap();
+        prop_assume!(tamper != cards[i]);
+        prop_assert!(!deck.verify_card(i as u8, &tamper, &proof));
+        prop_assert!(merkle::proof(&leaves, cards.len()).is_none());
+    }
+
+    #[test]
+    fn merkle_hashing_matches_the_program_hash(
+        match_id in any::<[u8; 36]>(),
+        match_hash in any::<[u8; 32]>(),
+        ended_at in any::<i64>(),
+    ) {
+        let expected = hashv(&[merkle::MATCH_RECORD_DOMAIN, &match_id, &match_hash, &ended_at.to_le_bytes()]).to_bytes();
+        prop_assert_eq!(merkle::match_record_leaf(&match_id, &match_hash, ended_at), expected);
+        prop_assert_eq!(merkle::hash_pair(&match_id[..32].try_into().unwrap(), &match_hash), hashv(&[&match_id[..32], &match_hash]).to_bytes());
+    }
+
+    #[test]
+    fn oracle_prices_are_never_read_without_a_staleness_limit(
+        staleness_seconds in -10i64..300,
+        age in 0i64..600,
+    ) {
+        let mut config = blank_config();
+        config.max_price_staleness_seconds = staleness_seconds;
+        let limit = config.max_price_staleness();
+        prop_assert!(limit > 0);
+        prop_assert_eq!(limit, if staleness_seconds > 0 { staleness_seconds } else { ConfigAccount::DEFAULT_MAX_PRICE_STALENESS_SECONDS });
+
+        let price = OraclePrice { price: 150_000_000, conf: 0, expo: -6, publish_time: 1_000 };
+        prop_assert_eq!(price.check(1_000 + age, limit, 0).is_ok(), age <= limit);
+    }
+
+    #[test]
+    fn payload_schemas_cover_every_action_type(action_type in 0u8..16, payload_len in 0usize..12) {
+        let mut registry = blank_registry();
+        registry.game_count = 1;
+        let game = &mut registry.games[0];
+        let mut fields = [0u8; 8];
+        fields[0] = PayloadField::U32 as u8;
+        game.payload_schemas[action_type as usize] = PayloadSchema { defined: true, min_len: 4, max_len: 4, fields };
+
+        let payload = vec![0u8; payload_len];
+        prop_assert!(game.get_payload_schema(action_type).is_some());
+        prop_assert_eq!(validation::validate_payload_schema(&registry, 0, action_type, &payload).is_ok(), payload_len == 4);
+    }
+}
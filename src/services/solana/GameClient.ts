@@ -22,6 +22,12 @@ export interface MatchState {
   hotUrl?: string;  // Changed from archiveTxid to hotUrl per spec Section 5
 }
 
+// PDA seeds are capped at 32 bytes; match PDAs use the first 32 bytes of the 36-char match ID
+// (mirrors match_id_seed in the Solana program)
+function matchIdSeed(matchId: string): Buffer {
+  return Buffer.from(matchId).subarray(0, 32);
+}
+
 export class GameClient {
   private anchorClient: AnchorClient;
   private pollingIntervals: Map<string, NodeJS.Timeout> = new Map();
@@ -40,7 +46,7 @@ export class GameClient {
     const matchId = crypto.randomUUID();
     
     const [matchPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('match'), Buffer.from(matchId)],
+      [Buffer.from('match'), matchIdSeed(matchId)],
       program.programId
     );
 
@@ -70,7 +76,7 @@ export class GameClient {
     const program = this.anchorClient.getProgram();
     
     const [matchPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('match'), Buffer.from(matchId)],
+      [Buffer.from('match'), matchIdSeed(matchId)],
       program.programId
     );
 
@@ -95,7 +101,7 @@ export class GameClient {
     const program = this.anchorClient.getProgram();
     
     const [matchPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('match'), Buffer.from(matchId)],
+      [Buffer.from('match'), matchIdSeed(matchId)],
       program.programId
     );
 
@@ -103,6 +109,7 @@ export class GameClient {
       .startMatch(matchId)
       .accounts({
         matchAccount: matchPda,
+        deckCommitment: null,
         authority: wallet.publicKey,
       })
       .rpc();
@@ -125,7 +132,7 @@ export class GameClient {
     const program = this.anchorClient.getProgram();
     
     const [matchPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('match'), Buffer.from(matchId)],
+      [Buffer.from('match'), matchIdSeed(matchId)],
       program.programId
     );
 
@@ -140,7 +147,7 @@ export class GameClient {
     const moveIndexBytes = Buffer.from(new Uint8Array(new BN(moveIndex).toArray('le', 4)));
 
     const [movePda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('move'), Buffer.from(matchId), moveIndexBytes],
+      [Buffer.from('move'), matchIdSeed(matchId), moveIndexBytes],
       program.programId
     );
 
@@ -165,11 +172,12 @@ export class GameClient {
     
         const state = await this.getMatchState(matchId);
         if (state) {
-          // Map numeric phase (0,1,2) to GamePhase string
+          // Map numeric phase (0,1,2,3) to GamePhase string
           const phaseMap: Record<number, GamePhase> = {
             0: GamePhase.DEALING,
             1: GamePhase.PLAYER_ACTION,
             2: GamePhase.GAME_END,
+            3: GamePhase.SHOWDOWN,
           };
           EventBus.instance.publish(new UpdateGameStateEvent({
             id: state.matchId,
@@ -194,7 +202,7 @@ export class GameClient {
     const program = this.anchorClient.getProgram();
     
     const [matchPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('match'), Buffer.from(matchId)],
+      [Buffer.from('match'), matchIdSeed(matchId)],
       program.programId
     );
 
@@ -219,7 +227,7 @@ export class GameClient {
     const program = this.anchorClient.getProgram();
     
     const [matchPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('match'), Buffer.from(matchId)],
+      [Buffer.from('match'), matchIdSeed(matchId)],
       program.programId
     );
 
@@ -279,6 +287,11 @@ export class GameClient {
       'declare_intent': 2,
       'call_showdown': 3,
       'rebuttal': 4,
+      'play_card': 5,
+      'discard': 6,
+      'resign': 7,
+      'offer_draw': 8,
+      'accept_draw': 9,
     };
     return mapping[actionType] ?? 0;
   }
@@ -433,7 +446,9 @@ export class GameClient {
     matchId: string,
     matchHash: Uint8Array,
     hotUrl?: string,
-    wallet?: { publicKey: PublicKey; signTransaction: (tx: unknown) => Promise<unknown> }
+    wallet?: { publicKey: PublicKey; signTransaction: (tx: unknown) => Promise<unknown> },
+    coldUri?: string, // Arweave tx id / IPFS CID (write-once on-chain)
+    coldContentHash?: Uint8Array // SHA-256 of the archived record (required with coldUri)
   ): Promise<TransactionSignature> {
     if (!wallet) {
       throw new Error('Wallet required for anchoring match record');
@@ -442,7 +457,7 @@ export class GameClient {
     const program = this.anchorClient.getProgram();
     
     const [matchPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('match'), Buffer.from(matchId)],
+      [Buffer.from('match'), matchIdSeed(matchId)],
       program.programId
     );
 
@@ -450,7 +465,9 @@ export class GameClient {
       .anchorMatchRecord(
         matchId,
         Array.from(matchHash),
-        hotUrl || null
+        hotUrl || null,
+        coldUri || null,
+        coldContentHash ? Array.from(coldContentHash) : null
       )
       .accounts({
         matchAccount: matchPda,